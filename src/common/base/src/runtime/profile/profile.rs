@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -48,6 +49,8 @@ pub struct Profile {
     pub title: Arc<String>,
 
     pub statistics: [AtomicUsize; std::mem::variant_count::<ProfileStatisticsName>()],
+    /// The in-memory bytes of the columns decoded by the scan, keyed by column name.
+    pub decoded_column_bytes: Mutex<BTreeMap<String, usize>>,
     pub metrics_registry: Option<Arc<ScopedRegistry>>,
     pub errors: Arc<Mutex<Vec<NodeErrorType>>>,
 }
@@ -66,6 +69,7 @@ impl Clone for Profile {
             statistics: std::array::from_fn(|idx| {
                 AtomicUsize::new(self.statistics[idx].load(Ordering::SeqCst))
             }),
+            decoded_column_bytes: Mutex::new(self.decoded_column_bytes.lock().clone()),
             errors: self.errors.clone(),
         }
     }
@@ -94,6 +98,7 @@ impl Profile {
             title,
             labels,
             statistics: Self::create_items(),
+            decoded_column_bytes: Mutex::new(BTreeMap::new()),
             metrics_registry,
             errors: Arc::new(Mutex::new(vec![])),
         }
@@ -108,6 +113,23 @@ impl Profile {
         });
     }
 
+    pub fn record_decoded_column_bytes<'a>(columns: impl Iterator<Item = (&'a str, usize)>) {
+        ThreadTracker::with(|x| match x.borrow().payload.profile.as_ref() {
+            None => {}
+            Some(profile) => {
+                let mut decoded_column_bytes = profile.decoded_column_bytes.lock();
+                for (column, value) in columns.filter(|(_, value)| *value != 0) {
+                    match decoded_column_bytes.get_mut(column) {
+                        Some(bytes) => *bytes += value,
+                        None => {
+                            decoded_column_bytes.insert(column.to_string(), value);
+                        }
+                    }
+                }
+            }
+        });
+    }
+
     pub fn load_profile(&self, name: ProfileStatisticsName) -> usize {
         self.statistics[name as usize].load(Ordering::SeqCst)
     }
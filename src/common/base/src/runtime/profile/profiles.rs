@@ -36,6 +36,8 @@ pub enum ProfileStatisticsName {
     OutputBytes,
     ScanBytes,
     ScanCacheBytes,
+    ScanMemoryCacheBytes,
+    ScanRemoteBytes,
     ScanPartitions,
     SpillWriteCount,
     SpillWriteBytes,
@@ -174,12 +176,26 @@ pub fn get_statistics_desc() -> Arc<BTreeMap<ProfileStatisticsName, ProfileDesc>
                 plain_statistics: true,
             }),
             (ProfileStatisticsName::ScanCacheBytes, ProfileDesc {
-                display_name: "bytes scanned from cache",
-                desc: "The bytes scanned from cache of query",
+                display_name: "bytes scanned from disk cache",
+                desc: "The bytes scanned from disk cache of query",
                 index: ProfileStatisticsName::ScanCacheBytes as usize,
                 unit: StatisticsUnit::Bytes,
                 plain_statistics: true,
             }),
+            (ProfileStatisticsName::ScanMemoryCacheBytes, ProfileDesc {
+                display_name: "bytes scanned from memory cache",
+                desc: "The bytes scanned from memory cache of query",
+                index: ProfileStatisticsName::ScanMemoryCacheBytes as usize,
+                unit: StatisticsUnit::Bytes,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::ScanRemoteBytes, ProfileDesc {
+                display_name: "bytes scanned from remote storage",
+                desc: "The bytes scanned from remote storage of query",
+                index: ProfileStatisticsName::ScanRemoteBytes as usize,
+                unit: StatisticsUnit::Bytes,
+                plain_statistics: true,
+            }),
             (ProfileStatisticsName::ScanPartitions, ProfileDesc {
                 display_name: "partitions scanned",
                 desc: "The partitions scanned of query",
@@ -86,6 +86,14 @@ impl From<Arc<Backtrace>> for ErrorCodeBacktrace {
     }
 }
 
+/// The node and the query fragments where an error was raised, filled when the
+/// error is sent back from a remote node of a distributed query.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ErrorOrigin {
+    pub node_id: String,
+    pub fragment_ids: Vec<usize>,
+}
+
 #[derive(Error)]
 pub struct ErrorCode {
     code: u16,
@@ -97,6 +105,7 @@ pub struct ErrorCode {
     // TODO: remove `cause` when we completely get rid of `anyhow::Error`.
     cause: Option<Box<dyn std::error::Error + Sync + Send>>,
     backtrace: Option<ErrorCodeBacktrace>,
+    origin: Option<ErrorOrigin>,
 }
 
 impl ErrorCode {
@@ -209,6 +218,36 @@ impl ErrorCode {
         self.backtrace.clone()
     }
 
+    pub fn origin(&self) -> Option<&ErrorOrigin> {
+        self.origin.as_ref()
+    }
+
+    /// Set the node and fragments where this error was raised.
+    ///
+    /// The first origin wins, an error forwarded through several nodes keeps the node that raised it.
+    pub fn set_origin(self, origin: ErrorOrigin) -> Self {
+        if self.origin.is_some() {
+            return self;
+        }
+
+        let detail = match origin.fragment_ids.is_empty() {
+            true => format!("(on node {})", origin.node_id),
+            false => format!(
+                "(while in query fragments {:?} on node {})",
+                origin.fragment_ids, origin.node_id
+            ),
+        };
+        Self {
+            origin: Some(origin),
+            ..self.add_detail_back(detail)
+        }
+    }
+
+    /// Set the origin as is, the message of a deserialized error already contains it.
+    pub(crate) fn with_origin(self, origin: Option<ErrorOrigin>) -> Self {
+        Self { origin, ..self }
+    }
+
     pub fn backtrace_str(&self) -> String {
         self.backtrace
             .as_ref()
@@ -275,6 +314,7 @@ impl ErrorCode {
             span: None,
             cause: None,
             backtrace: capture(),
+            origin: None,
         }
     }
 
@@ -287,6 +327,7 @@ impl ErrorCode {
             span: None,
             cause: None,
             backtrace: capture(),
+            origin: None,
         }
     }
 
@@ -299,6 +340,7 @@ impl ErrorCode {
             span: None,
             cause: None,
             backtrace: None,
+            origin: None,
         }
     }
 
@@ -318,6 +360,7 @@ impl ErrorCode {
             cause,
             backtrace,
             name: name.to_string(),
+            origin: None,
         }
     }
 }
@@ -379,5 +422,6 @@ impl Clone for ErrorCode {
             self.backtrace(),
         )
         .set_span(self.span())
+        .with_origin(self.origin.clone())
     }
 }
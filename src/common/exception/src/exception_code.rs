@@ -401,12 +401,19 @@ build_exceptions! {
     CurrentTransactionIsAborted(4002),
     TransactionTimeout(4003),
     InvalidSessionState(4004),
+    ReadOnlyTransaction(4005),
 
     // recluster error codes
     NoNeedToRecluster(4011),
     NoNeedToCompact(4012),
 
     RefreshTableInfoFailure(4012),
+
+    // spill error codes
+    SpillSpaceExhausted(4021),
+
+    // block verification error codes
+    CorruptedBlock(4031),
 }
 
 // Service errors [5001,6000].
@@ -29,6 +29,7 @@ impl From<ErrorCode> for FlightData {
             message: error.message(),
             span: error.span(),
             backtrace: error.backtrace_str(),
+            origin: error.origin().cloned(),
         })
         .unwrap();
 
@@ -56,7 +57,8 @@ impl TryFrom<FlightData> for ErrorCode {
                     None,
                     None,
                 )
-                .set_span(serialized_error.span)),
+                .set_span(serialized_error.span)
+                .with_origin(serialized_error.origin)),
                 _ => Ok(ErrorCode::create(
                     serialized_error.code,
                     serialized_error.name,
@@ -67,7 +69,8 @@ impl TryFrom<FlightData> for ErrorCode {
                         serialized_error.backtrace,
                     ))),
                 )
-                .set_span(serialized_error.span)),
+                .set_span(serialized_error.span)
+                .with_origin(serialized_error.origin)),
             },
         }
     }
@@ -22,6 +22,7 @@ use databend_common_ast::Span;
 use geozero::error::GeozeroError;
 
 use crate::exception::ErrorCodeBacktrace;
+use crate::exception::ErrorOrigin;
 use crate::exception_backtrace::capture;
 use crate::ErrorCode;
 
@@ -279,6 +280,8 @@ pub struct SerializedError {
     pub message: String,
     pub span: Span,
     pub backtrace: String,
+    #[serde(default)]
+    pub origin: Option<ErrorOrigin>,
 }
 
 impl Display for SerializedError {
@@ -295,6 +298,7 @@ impl From<ErrorCode> for SerializedError {
             message: e.message(),
             span: e.span(),
             backtrace: e.backtrace_str(),
+            origin: e.origin().cloned(),
         }
     }
 }
@@ -310,6 +314,7 @@ impl From<SerializedError> for ErrorCode {
             Some(ErrorCodeBacktrace::Serialized(Arc::new(se.backtrace))),
         )
         .set_span(se.span)
+        .with_origin(se.origin)
     }
 }
 
@@ -342,7 +347,8 @@ impl From<tonic::Status> for ErrorCode {
                             None,
                             None,
                         )
-                        .set_span(serialized_error.span),
+                        .set_span(serialized_error.span)
+                        .with_origin(serialized_error.origin),
                         _ => ErrorCode::create(
                             serialized_error.code,
                             serialized_error.name,
@@ -353,7 +359,8 @@ impl From<tonic::Status> for ErrorCode {
                                 serialized_error.backtrace,
                             ))),
                         )
-                        .set_span(serialized_error.span),
+                        .set_span(serialized_error.span)
+                        .with_origin(serialized_error.origin),
                     },
                 }
             }
@@ -374,6 +381,7 @@ impl From<ErrorCode> for tonic::Status {
                 str.truncate(2 * 1024);
                 str
             },
+            origin: err.origin().cloned(),
         });
 
         match error_json {
@@ -21,6 +21,7 @@ mod exception_into;
 mod with_context;
 
 pub use exception::ErrorCode;
+pub use exception::ErrorOrigin;
 pub use exception::Result;
 pub use exception::ToErrorCode;
 pub use exception_backtrace::set_backtrace;
@@ -18,6 +18,7 @@ use backtrace::Backtrace;
 use databend_common_arrow::arrow_format::flight::data::FlightData;
 use databend_common_exception::exception::ErrorCodeBacktrace;
 use databend_common_exception::ErrorCode;
+use databend_common_exception::ErrorOrigin;
 use databend_common_exception::Result;
 
 #[test]
@@ -40,3 +41,58 @@ fn test_serialize() -> Result<()> {
     assert_eq!(error_code.span(), Some((0..1).into()));
     Ok(())
 }
+
+#[test]
+fn test_serialize_with_origin() -> Result<()> {
+    let origin = ErrorOrigin {
+        node_id: String::from("node-1"),
+        fragment_ids: vec![1, 3],
+    };
+    let error_code = ErrorCode::create(
+        1,
+        "test_name",
+        String::from("test_message"),
+        String::new(),
+        None,
+        None,
+    )
+    .set_origin(origin.clone());
+
+    // the first origin is kept
+    let error_code = error_code.set_origin(ErrorOrigin {
+        node_id: String::from("node-2"),
+        fragment_ids: vec![2],
+    });
+
+    let error_code = ErrorCode::try_from(FlightData::from(error_code))?;
+    assert_eq!(1, error_code.code());
+    assert_eq!(Some(&origin), error_code.origin());
+    assert_eq!(
+        String::from("test_message\n(while in query fragments [1, 3] on node node-1)"),
+        error_code.message()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_origin_without_fragments() -> Result<()> {
+    let error_code = ErrorCode::create(
+        1,
+        "test_name",
+        String::from("test_message"),
+        String::new(),
+        None,
+        None,
+    )
+    .set_origin(ErrorOrigin {
+        node_id: String::from("node-1"),
+        fragment_ids: vec![],
+    });
+
+    let error_code = ErrorCode::try_from(FlightData::from(error_code))?;
+    assert_eq!(
+        String::from("test_message\n(on node node-1)"),
+        error_code.message()
+    );
+    Ok(())
+}
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::Debug;
 
+use databend_common_exception::Result;
+
 use crate::Datum;
 
 pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
@@ -72,6 +75,61 @@ impl Histogram {
     pub fn add_bucket(&mut self, bucket: HistogramBucket) {
         self.buckets.push(bucket);
     }
+
+    /// Estimate the number of values equal to `datum`.
+    ///
+    /// The values of the bucket covering `datum` are assumed to be evenly
+    /// distributed among the distinct values of that bucket.
+    pub fn estimate_equal(&self, datum: &Datum) -> Result<f64> {
+        for bucket in self.buckets.iter() {
+            if datum.compare(bucket.lower_bound())? == Ordering::Less {
+                // `datum` falls into the gap between two buckets.
+                return Ok(0.0);
+            }
+            if datum.compare(bucket.upper_bound())? != Ordering::Greater {
+                return Ok(bucket.value_frequency());
+            }
+        }
+        Ok(0.0)
+    }
+
+    /// Estimate the number of values less than `datum`,
+    /// or less than or equal to `datum` if `inclusive` is true.
+    ///
+    /// Buckets below `datum` are counted as a whole, the bucket covering `datum`
+    /// is linearly interpolated between its bounds if they are numeric,
+    /// otherwise half of it is taken.
+    pub fn estimate_less(&self, datum: &Datum, inclusive: bool) -> Result<f64> {
+        let mut num_less = 0.0;
+        for bucket in self.buckets.iter() {
+            if datum.compare(bucket.lower_bound())? == Ordering::Less {
+                break;
+            }
+            if datum.compare(bucket.upper_bound())? == Ordering::Greater {
+                num_less += bucket.num_values();
+                continue;
+            }
+
+            let fraction = if datum.is_numeric() && bucket.lower_bound().is_numeric() {
+                let lower = bucket.lower_bound().to_double()?;
+                let upper = bucket.upper_bound().to_double()?;
+                if upper > lower {
+                    (datum.to_double()? - lower) / (upper - lower)
+                } else {
+                    0.0
+                }
+            } else {
+                0.5
+            };
+            let mut covered = bucket.num_values() * fraction;
+            if inclusive {
+                covered += bucket.value_frequency();
+            }
+            num_less += covered.min(bucket.num_values());
+            break;
+        }
+        Ok(num_less)
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -112,6 +170,15 @@ impl HistogramBucket {
         self.num_distinct
     }
 
+    /// Average number of values for each distinct value in the bucket.
+    pub fn value_frequency(&self) -> f64 {
+        if self.num_distinct < 1.0 {
+            self.num_values
+        } else {
+            self.num_values / self.num_distinct
+        }
+    }
+
     pub fn aggregate_values(&mut self) {
         self.num_values = self.num_distinct;
     }
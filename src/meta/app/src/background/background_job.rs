@@ -213,6 +213,45 @@ impl BackgroundJobInfo {
             created_at: Utc::now(),
         }
     }
+
+    /// The job purging the files of internal stages by their lifecycle policies.
+    pub fn new_stage_lifecycle_job(job_params: BackgroundJobParams, creator: UserIdentity) -> Self {
+        Self {
+            job_status: Option::from(BackgroundJobStatus::new(&job_params)),
+            job_params: Some(job_params),
+            task_type: BackgroundTaskType::STAGE_LIFECYCLE,
+            last_updated: Some(Utc::now()),
+            message: "".to_string(),
+            creator: Some(creator),
+            created_at: Utc::now(),
+        }
+    }
+
+    /// The job moving the old blocks of tables to the cold tier by their `tiering_age_days` option.
+    pub fn new_table_tiering_job(job_params: BackgroundJobParams, creator: UserIdentity) -> Self {
+        Self {
+            job_status: Option::from(BackgroundJobStatus::new(&job_params)),
+            job_params: Some(job_params),
+            task_type: BackgroundTaskType::TABLE_TIERING,
+            last_updated: Some(Utc::now()),
+            message: "".to_string(),
+            creator: Some(creator),
+            created_at: Utc::now(),
+        }
+    }
+
+    /// The job removing the expired blocks of tables by their `ttl` option.
+    pub fn new_table_ttl_job(job_params: BackgroundJobParams, creator: UserIdentity) -> Self {
+        Self {
+            job_status: Option::from(BackgroundJobStatus::new(&job_params)),
+            job_params: Some(job_params),
+            task_type: BackgroundTaskType::TABLE_TTL,
+            last_updated: Some(Utc::now()),
+            message: "".to_string(),
+            creator: Some(creator),
+            created_at: Utc::now(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -66,6 +66,9 @@ pub enum BackgroundTaskType {
     #[default]
     COMPACTION = 0,
     VACUUM = 1,
+    TABLE_TTL = 2,
+    TABLE_TIERING = 3,
+    STAGE_LIFECYCLE = 4,
 }
 
 impl Display for BackgroundTaskType {
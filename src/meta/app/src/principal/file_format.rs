@@ -36,6 +36,7 @@ const OPT_FIELD_DELIMITER: &str = "field_delimiter";
 const OPT_RECORDE_DELIMITER: &str = "record_delimiter";
 const OPT_SKIP_HEADER: &str = "skip_header";
 const OPT_OUTPUT_HEADER: &str = "output_header";
+const OPT_OUTPUT_BOM: &str = "output_bom";
 const OPT_NAN_DISPLAY: &str = "nan_display";
 const OPT_NULL_DISPLAY: &str = "null_display";
 const OPT_ESCAPE: &str = "escape";
@@ -59,6 +60,7 @@ pub enum FileFormatParams {
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
     Orc(OrcFileFormatParams),
+    Avro(AvroFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -71,6 +73,7 @@ impl FileFormatParams {
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
             FileFormatParams::Orc(_) => StageFileFormatType::Orc,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
         }
     }
 
@@ -88,6 +91,9 @@ impl FileFormatParams {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
             StageFileFormatType::Orc => Ok(FileFormatParams::Orc(OrcFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -104,6 +110,7 @@ impl FileFormatParams {
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
             FileFormatParams::Orc(_) => StageFileCompression::None,
+            FileFormatParams::Avro(v) => v.compression,
         }
     }
 
@@ -115,6 +122,7 @@ impl FileFormatParams {
                 v.null_field_as == NullAs::FieldDefault
                     || v.missing_field_as == NullAs::FieldDefault
             }
+            FileFormatParams::Avro(v) => v.missing_field_as == NullAs::FieldDefault,
             _ => true,
         }
     }
@@ -161,6 +169,16 @@ impl FileFormatParams {
                     missing_field_as.as_deref(),
                 )?)
             }
+            StageFileFormatType::Avro => {
+                let compression = reader.take_compression()?;
+                let missing_field_as = reader.options.remove(MISSING_FIELD_AS);
+                let null_if = parse_null_if(reader.options.remove(NULL_IF))?;
+                FileFormatParams::Avro(AvroFileFormatParams::try_create(
+                    compression,
+                    missing_field_as.as_deref(),
+                    null_if,
+                )?)
+            }
             StageFileFormatType::Csv => {
                 let default = CsvFileFormatParams::default();
                 let compression = reader.take_compression()?;
@@ -190,6 +208,7 @@ impl FileFormatParams {
                     default.error_on_column_count_mismatch,
                 )?;
                 let output_header = reader.take_bool(OPT_OUTPUT_HEADER, default.output_header)?;
+                let output_bom = reader.take_bool(OPT_OUTPUT_BOM, default.output_bom)?;
                 FileFormatParams::Csv(CsvFileFormatParams {
                     compression,
                     headers,
@@ -203,6 +222,7 @@ impl FileFormatParams {
                     empty_field_as,
                     binary_format,
                     output_header,
+                    output_bom,
                     geometry_format: std::default::Default::default(),
                 })
             }
@@ -387,6 +407,8 @@ pub struct CsvFileFormatParams {
     // header
     pub headers: u64,
     pub output_header: bool,
+    // write a UTF-8 BOM at the beginning of each unloaded file
+    pub output_bom: bool,
 
     // field
     pub binary_format: BinaryFormat,
@@ -410,6 +432,7 @@ impl Default for CsvFileFormatParams {
             error_on_column_count_mismatch: true,
             empty_field_as: Default::default(),
             output_header: false,
+            output_bom: false,
             binary_format: Default::default(),
             geometry_format: GeometryDataType::default(),
         }
@@ -696,6 +719,38 @@ impl OrcFileFormatParams {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {
+    pub compression: StageFileCompression,
+    pub missing_field_as: NullAs,
+    pub null_if: Vec<String>,
+}
+
+impl AvroFileFormatParams {
+    pub fn try_create(
+        compression: StageFileCompression,
+        missing_field_as: Option<&str>,
+        null_if: Vec<String>,
+    ) -> Result<Self> {
+        let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
+        Ok(Self {
+            compression,
+            missing_field_as,
+            null_if,
+        })
+    }
+}
+
+impl Default for AvroFileFormatParams {
+    fn default() -> Self {
+        AvroFileFormatParams {
+            compression: StageFileCompression::None,
+            missing_field_as: NullAs::Error,
+            null_if: vec![],
+        }
+    }
+}
+
 impl Display for FileFormatParams {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
@@ -704,7 +759,7 @@ impl Display for FileFormatParams {
                     f,
                     "TYPE = CSV COMPRESSION = {:?} \
                      FIELD_DELIMITER = '{}' RECORD_DELIMITER = '{}' QUOTE = '{}' ESCAPE = '{}' \
-                     SKIP_HEADER= {} OUTPUT_HEADER= {} \
+                     SKIP_HEADER= {} OUTPUT_HEADER= {} OUTPUT_BOM= {} \
                      NULL_DISPLAY = '{}' NAN_DISPLAY = '{}' EMPTY_FIELD_AS = {} BINARY_FORMAT = {} \
                      ERROR_ON_COLUMN_COUNT_MISMATCH = {}",
                     params.compression,
@@ -714,6 +769,7 @@ impl Display for FileFormatParams {
                     escape_string(&params.escape),
                     params.headers,
                     params.output_header,
+                    params.output_bom,
                     escape_string(&params.null_display),
                     escape_string(&params.nan_display),
                     params.empty_field_as,
@@ -768,6 +824,13 @@ impl Display for FileFormatParams {
                     params.missing_field_as
                 )
             }
+            FileFormatParams::Avro(params) => {
+                write!(
+                    f,
+                    "TYPE = AVRO COMPRESSION = {:?} MISSING_FIELD_AS = {}",
+                    params.compression, params.missing_field_as
+                )
+            }
         }
     }
 }
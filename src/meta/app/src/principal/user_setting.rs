@@ -27,6 +27,9 @@ pub struct UserSetting {
     pub name: String,
     // The value of the setting.
     pub value: UserSettingValue,
+    // Whether the setting is locked, a locked setting can not be changed in the sessions.
+    #[serde(default)]
+    pub locked: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -92,6 +95,7 @@ impl UserSetting {
         UserSetting {
             name: name.to_string(),
             value,
+            locked: false,
         }
     }
 }
@@ -194,11 +194,9 @@ impl FromStr for StageFileFormatType {
             "XML" => Ok(StageFileFormatType::Xml),
             "JSON" => Ok(StageFileFormatType::Json),
             "ORC" => Ok(StageFileFormatType::Orc),
-            "AVRO" => Err(format!(
-                "File format type '{s}' not implemented yet', must be one of ( CSV | TSV | NDJSON | PARQUET | ORC)"
-            )),
+            "AVRO" => Ok(StageFileFormatType::Avro),
             _ => Err(format!(
-                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | ORC)"
+                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | ORC | AVRO)"
             )),
         }
     }
@@ -595,6 +593,35 @@ impl Display for CopyOptions {
     }
 }
 
+/// Retention policy of the files in an internal stage, enforced by a background job.
+///
+/// A zero value disables the corresponding limit.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, Eq, PartialEq)]
+#[serde(default)]
+pub struct StageLifecycle {
+    /// Files last modified more than `purge_after_days` days ago are removed.
+    pub purge_after_days: u64,
+    /// When the total size of the files exceeds `max_total_size` bytes,
+    /// the oldest files are removed until it fits.
+    pub max_total_size: u64,
+}
+
+impl StageLifecycle {
+    pub fn is_enabled(&self) -> bool {
+        self.purge_after_days != 0 || self.max_total_size != 0
+    }
+}
+
+impl Display for StageLifecycle {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PurgeAfterDays {}, MaxTotalSize {}",
+            self.purge_after_days, self.max_total_size
+        )
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Default, Clone, Debug, Eq, PartialEq)]
 #[serde(default)]
 pub struct StageInfo {
@@ -605,6 +632,7 @@ pub struct StageInfo {
     pub is_temporary: bool,
     pub file_format_params: FileFormatParams,
     pub copy_options: CopyOptions,
+    pub lifecycle: StageLifecycle,
     pub comment: String,
     /// TODO(xuanwo): stage doesn't have this info anymore, remove it.
     pub number_of_files: u64,
@@ -31,6 +31,7 @@ pub enum CatalogType {
     Hive = 2,
     Iceberg = 3,
     Share = 4,
+    Stage = 5,
 }
 
 impl From<databend_common_ast::ast::CatalogType> for CatalogType {
@@ -40,6 +41,7 @@ impl From<databend_common_ast::ast::CatalogType> for CatalogType {
             databend_common_ast::ast::CatalogType::Hive => CatalogType::Hive,
             databend_common_ast::ast::CatalogType::Iceberg => CatalogType::Iceberg,
             databend_common_ast::ast::CatalogType::Share => CatalogType::Share,
+            databend_common_ast::ast::CatalogType::Stage => CatalogType::Stage,
         }
     }
 }
@@ -56,6 +58,8 @@ pub enum CatalogOption {
     // Catalog option for Iceberg.
     Iceberg(IcebergCatalogOption),
     Share(ShareCatalogOption),
+    // Catalog option for stage, exposing stages as databases.
+    Stage(StageCatalogOption),
 }
 
 impl CatalogOption {
@@ -65,6 +69,7 @@ impl CatalogOption {
             CatalogOption::Hive(_) => CatalogType::Hive,
             CatalogOption::Iceberg(_) => CatalogType::Iceberg,
             CatalogOption::Share(_) => CatalogType::Share,
+            CatalogOption::Stage(_) => CatalogType::Stage,
         }
     }
 }
@@ -106,6 +111,13 @@ pub struct ShareCatalogOption {
     pub share_endpoint: String,
 }
 
+/// Option for creating a stage catalog.
+///
+/// Every stage of the tenant is listed as a database, and every directory
+/// directly under a stage is listed as a table of that database.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct StageCatalogOption {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct IcebergRestCatalogOption {
     pub uri: String,
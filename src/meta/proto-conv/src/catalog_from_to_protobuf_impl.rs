@@ -90,6 +90,9 @@ impl FromToProto for mt::CatalogOption {
             pb::catalog_option::CatalogOption::Share(v) => {
                 mt::CatalogOption::Share(mt::ShareCatalogOption::from_pb(v)?)
             }
+            pb::catalog_option::CatalogOption::Stage(v) => {
+                mt::CatalogOption::Stage(mt::StageCatalogOption::from_pb(v)?)
+            }
         })
     }
 
@@ -103,6 +106,9 @@ impl FromToProto for mt::CatalogOption {
             mt::CatalogOption::Share(v) => {
                 Some(pb::catalog_option::CatalogOption::Share(v.to_pb()?))
             }
+            mt::CatalogOption::Stage(v) => {
+                Some(pb::catalog_option::CatalogOption::Stage(v.to_pb()?))
+            }
         };
 
         Ok(pb::CatalogOption { catalog_option })
@@ -282,3 +288,25 @@ impl FromToProto for mt::ShareCatalogOption {
         })
     }
 }
+
+impl FromToProto for mt::StageCatalogOption {
+    type PB = pb::StageCatalogOption;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(Self {})
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        Ok(pb::StageCatalogOption {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+        })
+    }
+}
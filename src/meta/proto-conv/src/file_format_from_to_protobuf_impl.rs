@@ -249,6 +249,11 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::from_pb(p)?,
                 ))
             }
+            Some(pb::file_format_params::Format::Avro(p)) => {
+                Ok(mt::principal::FileFormatParams::Avro(
+                    mt::principal::AvroFileFormatParams::from_pb(p)?,
+                ))
+            }
             None => Err(Incompatible {
                 reason: "FileFormatParams.format cannot be None".to_string(),
             }),
@@ -292,6 +297,11 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::OrcFileFormatParams::to_pb(p)?,
                 )),
             }),
+            Self::Avro(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Avro(
+                    mt::principal::AvroFileFormatParams::to_pb(p)?,
+                )),
+            }),
         }
     }
 }
@@ -321,6 +331,44 @@ impl FromToProto for mt::principal::OrcFileFormatParams {
     }
 }
 
+impl FromToProto for mt::principal::AvroFileFormatParams {
+    type PB = pb::AvroFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::AvroFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        let compression = mt::principal::StageFileCompression::from_pb_enum(
+            FromPrimitive::from_i32(p.compression).ok_or_else(|| Incompatible {
+                reason: format!("invalid StageFileCompression: {}", p.compression),
+            })?,
+        )?;
+
+        mt::principal::AvroFileFormatParams::try_create(
+            compression,
+            p.missing_field_as.as_deref(),
+            p.null_if,
+        )
+        .map_err(|e| Incompatible {
+            reason: format!("{e}"),
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::AvroFileFormatParams, Incompatible> {
+        let compression =
+            mt::principal::StageFileCompression::to_pb_enum(&self.compression)? as i32;
+        Ok(pb::AvroFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            compression,
+            missing_field_as: Some(self.missing_field_as.to_string()),
+            null_if: self.null_if.clone(),
+        })
+    }
+}
+
 impl FromToProto for mt::principal::ParquetFileFormatParams {
     type PB = pb::ParquetFileFormatParams;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -506,6 +554,7 @@ impl FromToProto for mt::principal::CsvFileFormatParams {
             empty_field_as,
             binary_format,
             output_header: p.output_header,
+            output_bom: p.output_bom,
             geometry_format,
         })
     }
@@ -528,6 +577,7 @@ impl FromToProto for mt::principal::CsvFileFormatParams {
             empty_field_as: Some(self.empty_field_as.to_string()),
             binary_format: Some(self.binary_format.to_string()),
             output_header: self.output_header,
+            output_bom: self.output_bom,
             geometry_format: Some(self.geometry_format.to_string()),
         })
     }
@@ -171,6 +171,27 @@ impl FromToProto for mt::principal::CopyOptions {
     }
 }
 
+impl FromToProto for mt::principal::StageLifecycle {
+    type PB = pb::stage_info::StageLifecycle;
+    fn get_pb_ver(_p: &Self::PB) -> u64 {
+        0
+    }
+    fn from_pb(p: pb::stage_info::StageLifecycle) -> Result<Self, Incompatible>
+    where Self: Sized {
+        Ok(mt::principal::StageLifecycle {
+            purge_after_days: p.purge_after_days,
+            max_total_size: p.max_total_size,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::stage_info::StageLifecycle, Incompatible> {
+        Ok(pb::stage_info::StageLifecycle {
+            purge_after_days: self.purge_after_days,
+            max_total_size: self.max_total_size,
+        })
+    }
+}
+
 impl FromToProto for mt::principal::StageInfo {
     type PB = pb::StageInfo;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -216,6 +237,10 @@ impl FromToProto for mt::principal::StageInfo {
                     reason: "StageInfo.copy_options cannot be None".to_string(),
                 },
             )?)?,
+            lifecycle: match p.lifecycle {
+                Some(l) => mt::principal::StageLifecycle::from_pb(l)?,
+                None => mt::principal::StageLifecycle::default(),
+            },
             comment: p.comment,
             number_of_files: p.number_of_files,
             creator: match p.creator {
@@ -248,6 +273,7 @@ impl FromToProto for mt::principal::StageInfo {
                 None => None,
             },
             created_on: Some(self.created_on.to_pb()?),
+            lifecycle: Some(mt::principal::StageLifecycle::to_pb(&self.lifecycle)?),
         })
     }
 }
@@ -134,6 +134,13 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (102, "2024-07-11: Add: UserOption add must_change_password, AuthInfo.Password add need_change"),
     (103, "2024-07-31: Add: ShareMetaV2"),
     (104, "2024-08-02: Add: add share catalog into Catalog meta"),
+    (105, "2024-08-05: Add: stage.proto/StageInfo::lifecycle"),
+    (106, "2024-08-06: Add: add stage catalog into Catalog meta"),
+    (107, "2024-08-08: Add: user.proto/CsvFileFormatParams add field `output_bom`"),
+    (108, "2024-08-12: Add: user.proto/AvroFileFormatParams"),
+    (109, "2024-08-13: Add: background.proto/BackgroundTaskType::TABLE_TTL"),
+    (110, "2024-08-14: Add: background.proto/BackgroundTaskType::TABLE_TIERING"),
+    (111, "2024-08-15: Add: background.proto/BackgroundTaskType::STAGE_LIFECYCLE"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -108,3 +108,10 @@ mod v101_database_meta;
 mod v102_user_must_change_password;
 mod v103_share_meta_v2;
 mod v104_share_catalog;
+mod v105_stage_lifecycle;
+mod v106_stage_catalog;
+mod v107_csv_output_bom;
+mod v108_avro_format_params;
+mod v109_background_task_type_table_ttl;
+mod v110_background_task_type_table_tiering;
+mod v111_background_task_type_stage_lifecycle;
@@ -106,6 +106,7 @@ pub(crate) fn test_fs_stage_info() -> mt::principal::StageInfo {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "test".to_string(),
 
         number_of_files: 100,
@@ -150,6 +151,7 @@ pub(crate) fn test_s3_stage_info() -> mt::principal::StageInfo {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "test".to_string(),
         ..Default::default()
     }
@@ -190,6 +192,7 @@ pub(crate) fn test_s3_stage_info_v16() -> mt::principal::StageInfo {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "test".to_string(),
         ..Default::default()
     }
@@ -230,6 +233,7 @@ pub(crate) fn test_s3_stage_info_v14() -> mt::principal::StageInfo {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "test".to_string(),
         ..Default::default()
     }
@@ -266,6 +270,7 @@ pub(crate) fn test_gcs_stage_info() -> mt::principal::StageInfo {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "test".to_string(),
         ..Default::default()
     }
@@ -306,6 +311,7 @@ pub(crate) fn test_oss_stage_info() -> mt::principal::StageInfo {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "test".to_string(),
         ..Default::default()
     }
@@ -341,6 +347,7 @@ pub(crate) fn test_webhdfs_stage_info() -> mt::principal::StageInfo {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "test".to_string(),
         ..Default::default()
     }
@@ -377,6 +384,7 @@ pub(crate) fn test_obs_stage_info() -> mt::principal::StageInfo {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "test".to_string(),
         ..Default::default()
     }
@@ -413,6 +421,7 @@ pub(crate) fn test_cos_stage_info() -> mt::principal::StageInfo {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "test".to_string(),
         ..Default::default()
     }
@@ -853,6 +862,7 @@ pub(crate) fn test_internal_stage_info_v17() -> mt::principal::StageInfo {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "test".to_string(),
         ..Default::default()
     }
@@ -885,6 +895,7 @@ pub(crate) fn test_stage_info_v18() -> mt::principal::StageInfo {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "test".to_string(),
         ..Default::default()
     }
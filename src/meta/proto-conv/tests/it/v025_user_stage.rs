@@ -70,6 +70,7 @@ fn test_decode_v25_user_stage() -> anyhow::Result<()> {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "test".to_string(),
         number_of_files: 100,
         creator: Some(UserIdentity {
@@ -39,6 +39,7 @@ fn test_decode_v53_csv_file_format_params() -> anyhow::Result<()> {
             compression: StageFileCompression::Gzip,
             headers: 1,
             output_header: false,
+            output_bom: false,
             field_delimiter: "fd".to_string(),
             record_delimiter: "rd".to_string(),
             null_display: "\\N".to_string(),
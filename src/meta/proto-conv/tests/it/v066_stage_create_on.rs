@@ -71,6 +71,7 @@ fn test_decode_v66_stage() -> anyhow::Result<()> {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "ccc".to_string(),
         number_of_files: 100,
         creator: Some(UserIdentity {
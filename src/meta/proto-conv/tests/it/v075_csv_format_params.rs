@@ -42,6 +42,7 @@ fn test_decode_v75_csv_file_format_params() -> anyhow::Result<()> {
         compression: StageFileCompression::Gzip,
         headers: 1,
         output_header: true,
+        output_bom: false,
         field_delimiter: "fd".to_string(),
         record_delimiter: "rd".to_string(),
         null_display: "Null".to_string(),
@@ -72,6 +72,7 @@ fn test_v077_s3_remove_allow_anonymous() -> anyhow::Result<()> {
             return_failed_only: false,
             detailed_output: false,
         },
+        lifecycle: mt::principal::StageLifecycle::default(),
         comment: "test".to_string(),
         number_of_files: 100,
         creator: Some(UserIdentity {
@@ -32,6 +32,7 @@ fn test_decode_v89_geometry_output_format() -> anyhow::Result<()> {
         compression: StageFileCompression::Gzip,
         headers: 1,
         output_header: true,
+        output_bom: false,
         field_delimiter: "fd".to_string(),
         record_delimiter: "rd".to_string(),
         null_display: "Null".to_string(),
@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_meta_app::background::BackgroundJobInfo;
+use databend_common_meta_app::background::BackgroundJobParams;
+use databend_common_meta_app::background::BackgroundJobType;
+use databend_common_meta_app::background::BackgroundTaskType;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v110_background_job_table_tiering() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 11, 8, 1, 16, 144, 28, 160, 6, 110, 168, 6, 24, 24, 3, 218, 5, 23, 49, 57, 55, 48, 45,
+        48, 49, 45, 48, 49, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 160, 6, 110, 168,
+        6, 24,
+    ];
+
+    let want = || BackgroundJobInfo {
+        job_params: Some(BackgroundJobParams {
+            job_type: BackgroundJobType::INTERVAL,
+            scheduled_job_interval: Duration::from_secs(3600),
+            scheduled_job_cron: "".to_string(),
+            scheduled_job_timezone: None,
+            manual_trigger_params: None,
+        }),
+        job_status: None,
+        task_type: BackgroundTaskType::TABLE_TIERING,
+        message: "".to_string(),
+        last_updated: None,
+        creator: None,
+        created_at: Default::default(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 110, want())
+}
@@ -755,9 +755,10 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                         .iter()
                         .map(|opt| {
                             match opt {
-                                ExplainOption::Verbose => "Verbose",
-                                ExplainOption::Logical => "Logical",
-                                ExplainOption::Optimized => "Optimized",
+                                ExplainOption::Verbose => "Verbose".to_string(),
+                                ExplainOption::Logical => "Logical".to_string(),
+                                ExplainOption::Optimized => "Optimized".to_string(),
+                                ExplainOption::Format(format) => format!("Format {format}"),
                             }
                         })
                         .join(", ")
@@ -1071,6 +1072,8 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         let ids = identifiers.iter().map(|id| id.to_string()).join(",");
         let name = if set_type == SetType::SettingsGlobal {
             format!("Set SettingsGlobal {}", ids)
+        } else if set_type == SetType::SettingsGlobalLocked {
+            format!("Set SettingsGlobalLocked {}", ids)
         } else if set_type == SetType::Variable {
             format!("Set Variable {}", ids)
         } else {
@@ -1514,6 +1517,18 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::CloneOf {
+                catalog,
+                database,
+                table,
+            } => {
+                self.visit_table_ref(catalog, database, table);
+                let child = self.children.pop().unwrap();
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, 1);
+                let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1688,6 +1703,18 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::SetTTL { ttl } => {
+                self.visit_expr(ttl);
+                let ttl_child = self.children.pop().unwrap();
+                let action_name = "Action SetTTL".to_string();
+                let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                FormatTreeNode::with_children(action_format_ctx, vec![ttl_child])
+            }
+            AlterTableAction::DropTTL => {
+                let action_name = "Action DropTTL".to_string();
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1767,6 +1794,22 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_vacuum_all_tables(&mut self, stmt: &'ast VacuumAllTablesStmt) {
+        let mut children = Vec::new();
+        if let Some(database) = &stmt.database {
+            self.visit_database_ref(&stmt.catalog, database);
+            children.push(self.children.pop().unwrap());
+        }
+        let action_name = format!("Option {}", &stmt.option);
+        let action_format_ctx = AstFormatContext::new(action_name);
+        children.push(FormatTreeNode::new(action_format_ctx));
+
+        let name = "VacuumAllTables".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_analyze_table(&mut self, stmt: &'ast AnalyzeTableStmt) {
         let mut children = Vec::new();
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
@@ -3484,6 +3527,14 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            TemporalClause::TimeTravelBefore(point) => {
+                self.visit_time_travel_point(point);
+                let child = self.children.pop().unwrap();
+                let name = "TimeTravelBefore".to_string();
+                let format_ctx = AstFormatContext::with_children(name, 1);
+                let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+                self.children.push(node);
+            }
             TemporalClause::Changes(ChangesInterval {
                 at_point,
                 end_point,
@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+
+use serde_json::Map;
+use serde_json::Value;
+
+use super::FormatTreeNode;
+use crate::ParseError;
+use crate::Result;
+
+impl<T> FormatTreeNode<T>
+where T: Display + Clone
+{
+    /// Format TreeNode as a json object of `{"name", "properties", "children"}`.
+    ///
+    /// The children created by [`FormatTreeNode::property`] are the properties of the node,
+    /// the keys are in snake case, e.g. `output columns: [a (#0)]` becomes
+    /// `"output_columns": "[a (#0)]"`. The other children are the child nodes.
+    ///
+    /// Returns an error if a node has two properties of the same key.
+    pub fn format_json(&self) -> Result<Value> {
+        let mut properties = Map::new();
+        let mut children = vec![];
+        for child in &self.children {
+            match &child.property {
+                Some((key, value)) => {
+                    let key = json_key(key);
+                    if properties.contains_key(&key) {
+                        return Err(ParseError(
+                            None,
+                            format!("duplicate property `{key}` of plan node `{}`", self.payload),
+                        ));
+                    }
+                    properties.insert(key, Value::String(value.clone()));
+                }
+                None => children.push(child.format_json()?),
+            }
+        }
+
+        let mut node = Map::new();
+        node.insert("name".to_string(), Value::String(self.payload.to_string()));
+        node.insert("properties".to_string(), Value::Object(properties));
+        node.insert("children".to_string(), Value::Array(children));
+        Ok(Value::Object(node))
+    }
+}
+
+fn json_key(key: &str) -> String {
+    key.trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
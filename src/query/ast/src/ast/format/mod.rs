@@ -14,6 +14,7 @@
 
 mod ast_format;
 mod indent_format;
+mod json_format;
 mod pretty_format;
 mod syntax;
 
@@ -26,6 +27,8 @@ pub use syntax::pretty_statement;
 pub struct FormatTreeNode<T: Display + Clone = String> {
     pub payload: T,
     pub children: Vec<Self>,
+    /// The key and value of a property node, see [`FormatTreeNode::property`].
+    pub property: Option<(String, String)>,
 }
 
 impl<T> FormatTreeNode<T>
@@ -35,10 +38,28 @@ where T: Display + Clone
         Self {
             payload,
             children: vec![],
+            property: None,
         }
     }
 
     pub fn with_children(payload: T, children: Vec<Self>) -> Self {
-        Self { payload, children }
+        Self {
+            payload,
+            children,
+            property: None,
+        }
+    }
+}
+
+impl FormatTreeNode<String> {
+    /// Creates a leaf node displayed as `<key>: <value>`, the key and the value are
+    /// kept apart to be the field of the node in [`FormatTreeNode::format_json`].
+    pub fn property(key: impl Display, value: impl Display) -> Self {
+        let (key, value) = (key.to_string(), value.to_string());
+        Self {
+            payload: format!("{key}: {value}"),
+            children: vec![],
+            property: Some((key, value)),
+        }
     }
 }
@@ -31,6 +31,7 @@ use crate::ast::CreateStreamStmt;
 use crate::ast::CreateTableSource;
 use crate::ast::CreateTableStmt;
 use crate::ast::CreateViewStmt;
+use crate::ast::Identifier;
 use crate::ast::TimeTravelPoint;
 
 pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
@@ -140,23 +141,37 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
             catalog,
             database,
             table,
-        } => RcDoc::space()
-            .append(RcDoc::text("LIKE"))
-            .append(RcDoc::space())
-            .append(if let Some(catalog) = catalog {
-                RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
-            } else {
-                RcDoc::nil()
-            })
-            .append(if let Some(database) = database {
-                RcDoc::text(database.to_string()).append(RcDoc::text("."))
-            } else {
-                RcDoc::nil()
-            })
-            .append(RcDoc::text(table.to_string())),
+        } => pretty_source_table("LIKE", catalog, database, table),
+        CreateTableSource::CloneOf {
+            catalog,
+            database,
+            table,
+        } => pretty_source_table("CLONE", catalog, database, table),
     }
 }
 
+fn pretty_source_table(
+    keyword: &'static str,
+    catalog: Option<Identifier>,
+    database: Option<Identifier>,
+    table: Identifier,
+) -> RcDoc<'static> {
+    RcDoc::space()
+        .append(RcDoc::text(keyword))
+        .append(RcDoc::space())
+        .append(if let Some(catalog) = catalog {
+            RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
+        } else {
+            RcDoc::nil()
+        })
+        .append(if let Some(database) = database {
+            RcDoc::text(database.to_string()).append(RcDoc::text("."))
+        } else {
+            RcDoc::nil()
+        })
+        .append(RcDoc::text(table.to_string()))
+}
+
 pub(crate) fn pretty_alter_table(stmt: AlterTableStmt) -> RcDoc<'static> {
     RcDoc::text("ALTER TABLE")
         .append(if stmt.if_exists {
@@ -273,6 +288,10 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        AlterTableAction::SetTTL { ttl } => RcDoc::line()
+            .append(RcDoc::text("SET TTL = "))
+            .append(pretty_expr(ttl)),
+        AlterTableAction::DropTTL => RcDoc::line().append(RcDoc::text("DROP TTL")),
     }
 }
 
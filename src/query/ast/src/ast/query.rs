@@ -591,6 +591,7 @@ impl Display for ChangesInterval {
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum TemporalClause {
     TimeTravel(TimeTravelPoint),
+    TimeTravelBefore(TimeTravelPoint),
     Changes(ChangesInterval),
 }
 
@@ -600,6 +601,9 @@ impl Display for TemporalClause {
             TemporalClause::TimeTravel(point) => {
                 write!(f, "AT {}", point)?;
             }
+            TemporalClause::TimeTravelBefore(point) => {
+                write!(f, "BEFORE {}", point)?;
+            }
             TemporalClause::Changes(changes) => {
                 write!(f, "{}", changes)?;
             }
@@ -153,6 +153,7 @@ pub struct CopyIntoLocationStmt {
     pub file_format: FileFormatOptions,
     pub single: bool,
     pub max_file_size: usize,
+    pub max_files: usize,
     pub detailed_output: bool,
 }
 
@@ -173,6 +174,9 @@ impl Display for CopyIntoLocationStmt {
         }
         write!(f, " SINGLE = {}", self.single)?;
         write!(f, " MAX_FILE_SIZE = {}", self.max_file_size)?;
+        if self.max_files != 0 {
+            write!(f, " MAX_FILES = {}", self.max_files)?;
+        }
         write!(f, " DETAILED_OUTPUT = {}", self.detailed_output)?;
 
         Ok(())
@@ -185,6 +189,7 @@ impl CopyIntoLocationStmt {
             CopyIntoLocationOption::FileFormat(v) => self.file_format = v,
             CopyIntoLocationOption::Single(v) => self.single = v,
             CopyIntoLocationOption::MaxFileSize(v) => self.max_file_size = v,
+            CopyIntoLocationOption::MaxFiles(v) => self.max_files = v,
             CopyIntoLocationOption::DetailedOutput(v) => self.detailed_output = v,
         }
     }
@@ -456,6 +461,7 @@ pub enum CopyIntoTableOption {
 pub enum CopyIntoLocationOption {
     FileFormat(FileFormatOptions),
     MaxFileSize(usize),
+    MaxFiles(usize),
     Single(bool),
     DetailedOutput(bool),
 }
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
+
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
@@ -44,4 +47,22 @@ pub enum ExplainOption {
     Verbose,
     Logical,
     Optimized,
+    Format(ExplainFormat),
+}
+
+/// The output format of `EXPLAIN (FORMAT <format>)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Drive, DriveMut)]
+pub enum ExplainFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl Display for ExplainFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExplainFormat::Text => write!(f, "TEXT"),
+            ExplainFormat::Json => write!(f, "JSON"),
+        }
+    }
 }
@@ -97,6 +97,7 @@ pub enum CatalogType {
     Hive,
     Iceberg,
     Share,
+    Stage,
 }
 
 impl Display for CatalogType {
@@ -106,6 +107,7 @@ impl Display for CatalogType {
             CatalogType::Hive => write!(f, "HIVE"),
             CatalogType::Iceberg => write!(f, "ICEBERG"),
             CatalogType::Share => write!(f, "SHARE"),
+            CatalogType::Stage => write!(f, "STAGE"),
         }
     }
 }
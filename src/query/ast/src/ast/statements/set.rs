@@ -19,12 +19,14 @@ use crate::ast::Expr;
 use crate::ast::Query;
 
 // settings: set a = xxx
+// locked settings: set global locked a = xxx
 // variable: set variable a = xxx
 #[derive(Debug, Copy, Default, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub enum SetType {
     #[default]
     SettingsSession,
     SettingsGlobal,
+    SettingsGlobalLocked,
     Variable,
 }
 
@@ -37,6 +37,8 @@ pub struct CreateStageStmt {
     pub on_error: String,
     pub size_limit: usize,
     pub validation_mode: String,
+    pub purge_after_days: u64,
+    pub max_total_size: u64,
     pub comments: String,
 }
 
@@ -72,6 +74,14 @@ impl Display for CreateStageStmt {
             write!(f, " VALIDATION_MODE = {}", self.validation_mode)?;
         }
 
+        if self.purge_after_days != 0 {
+            write!(f, " PURGE_AFTER_DAYS = {}", self.purge_after_days)?;
+        }
+
+        if self.max_total_size != 0 {
+            write!(f, " MAX_TOTAL_SIZE = {}", self.max_total_size)?;
+        }
+
         if !self.comments.is_empty() {
             write!(f, " COMMENTS = '{}'", self.comments)?;
         }
@@ -30,6 +30,7 @@ use crate::ast::statements::pipe::CreatePipeStmt;
 use crate::ast::statements::task::CreateTaskStmt;
 use crate::ast::write_comma_separated_list;
 use crate::ast::CreateOption;
+use crate::ast::Expr;
 use crate::ast::Identifier;
 use crate::ast::Query;
 
@@ -144,6 +145,7 @@ pub enum Statement {
     OptimizeTable(OptimizeTableStmt),
     VacuumTable(VacuumTableStmt),
     VacuumDropTable(VacuumDropTableStmt),
+    VacuumAllTables(VacuumAllTablesStmt),
     VacuumTemporaryFiles(VacuumTemporaryFiles),
     AnalyzeTable(AnalyzeTableStmt),
     ExistsTable(ExistsTableStmt),
@@ -165,6 +167,8 @@ pub enum Statement {
     DropView(DropViewStmt),
     ShowViews(ShowViewsStmt),
     DescribeView(DescribeViewStmt),
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
 
     // Streams
     CreateStream(CreateStreamStmt),
@@ -309,7 +313,16 @@ pub enum Statement {
     AlterPipe(AlterPipeStmt),
 
     // Transactions
-    Begin,
+    Begin {
+        /// `BEGIN READ ONLY AT <timestamp>` pins every table read in the transaction
+        /// to the snapshots as of the given timestamp.
+        read_only_at: Option<Box<Expr>>,
+    },
+    /// `SET TRANSACTION SNAPSHOT <timestamp>` turns the current transaction into a
+    /// read-only one pinned to the given timestamp, like `BEGIN READ ONLY AT`.
+    SetTransactionSnapshot {
+        at: Box<Expr>,
+    },
     Commit,
     Abort,
 
@@ -391,9 +404,10 @@ impl Display for Statement {
                             .iter()
                             .map(|opt| {
                                 match opt {
-                                    ExplainOption::Verbose => "VERBOSE",
-                                    ExplainOption::Logical => "LOGICAL",
-                                    ExplainOption::Optimized => "OPTIMIZED",
+                                    ExplainOption::Verbose => "VERBOSE".to_string(),
+                                    ExplainOption::Logical => "LOGICAL".to_string(),
+                                    ExplainOption::Optimized => "OPTIMIZED".to_string(),
+                                    ExplainOption::Format(format) => format!("FORMAT {format}"),
                                 }
                             })
                             .join(", ")
@@ -494,6 +508,7 @@ impl Display for Statement {
                 write!(f, "SET ")?;
                 match *set_type {
                     SetType::SettingsGlobal => write!(f, "GLOBAL ")?,
+                    SetType::SettingsGlobalLocked => write!(f, "GLOBAL LOCKED ")?,
                     SetType::SettingsSession => {}
                     SetType::Variable => write!(f, "VARIABLE ")?,
                 }
@@ -540,7 +555,7 @@ impl Display for Statement {
                 write!(f, "UNSET ")?;
                 match *unset_type {
                     SetType::SettingsSession => write!(f, "SESSION ")?,
-                    SetType::SettingsGlobal => {}
+                    SetType::SettingsGlobal | SetType::SettingsGlobalLocked => {}
                     SetType::Variable => write!(f, "VARIABLE ")?,
                 }
                 if identifiers.len() == 1 {
@@ -595,6 +610,7 @@ impl Display for Statement {
             Statement::OptimizeTable(stmt) => write!(f, "{stmt}")?,
             Statement::VacuumTable(stmt) => write!(f, "{stmt}")?,
             Statement::VacuumDropTable(stmt) => write!(f, "{stmt}")?,
+            Statement::VacuumAllTables(stmt) => write!(f, "{stmt}")?,
             Statement::VacuumTemporaryFiles(stmt) => write!(f, "{stmt}")?,
             Statement::AnalyzeTable(stmt) => write!(f, "{stmt}")?,
             Statement::ExistsTable(stmt) => write!(f, "{stmt}")?,
@@ -612,6 +628,8 @@ impl Display for Statement {
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
             Statement::ShowViews(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
             Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
             Statement::DropStream(stmt) => write!(f, "{stmt}")?,
             Statement::ShowStreams(stmt) => write!(f, "{stmt}")?,
@@ -781,7 +799,13 @@ impl Display for Statement {
             Statement::DropConnection(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeConnection(stmt) => write!(f, "{stmt}")?,
             Statement::ShowConnections(stmt) => write!(f, "{stmt}")?,
-            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Begin { read_only_at } => {
+                write!(f, "BEGIN")?;
+                if let Some(at) = read_only_at {
+                    write!(f, " READ ONLY AT {at}")?;
+                }
+            }
+            Statement::SetTransactionSnapshot { at } => write!(f, "SET TRANSACTION SNAPSHOT {at}")?,
             Statement::Commit => write!(f, "COMMIT")?,
             Statement::Abort => write!(f, "ABORT")?,
             Statement::CreateNotification(stmt) => write!(f, "{stmt}")?,
@@ -32,6 +32,10 @@ impl Display for SystemStmt {
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub enum SystemAction {
     Backtrace(bool),
+    ReloadConfig,
+    FlushLogs,
+    DropQueryCache,
+    SyncMetadata,
 }
 
 impl Display for SystemAction {
@@ -41,6 +45,10 @@ impl Display for SystemAction {
                 true => write!(f, "ENABLE EXCEPTION_BACKTRACE"),
                 false => write!(f, "DISABLE EXCEPTION_BACKTRACE"),
             },
+            SystemAction::ReloadConfig => write!(f, "RELOAD CONFIG"),
+            SystemAction::FlushLogs => write!(f, "FLUSH LOGS"),
+            SystemAction::DropQueryCache => write!(f, "DROP QUERY CACHE"),
+            SystemAction::SyncMetadata => write!(f, "SYNC METADATA"),
         }
     }
 }
@@ -27,6 +27,7 @@ use crate::ast::write_dot_separated_list;
 use crate::ast::write_space_separated_string_map;
 use crate::ast::CreateOption;
 use crate::ast::Expr;
+use crate::ast::FileFormatOptions;
 use crate::ast::Identifier;
 use crate::ast::Query;
 use crate::ast::TableReference;
@@ -137,6 +138,7 @@ pub struct CreateTableStmt {
     pub source: Option<CreateTableSource>,
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
+    pub file_format: Option<FileFormatOptions>,
     pub cluster_by: Vec<Expr>,
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
@@ -176,6 +178,10 @@ impl Display for CreateTableStmt {
             write!(f, " {uri_location}")?;
         }
 
+        if let Some(file_format) = &self.file_format {
+            write!(f, " FILE_FORMAT = ({file_format})")?;
+        }
+
         if !self.cluster_by.is_empty() {
             write!(f, " CLUSTER BY (")?;
             write_comma_separated_list(f, &self.cluster_by)?;
@@ -229,6 +235,12 @@ pub enum CreateTableSource {
         database: Option<Identifier>,
         table: Identifier,
     },
+    /// `CLONE t`: share the source table's current snapshot, copying no data.
+    CloneOf {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+    },
 }
 
 impl Display for CreateTableSource {
@@ -251,6 +263,14 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::CloneOf {
+                catalog,
+                database,
+                table,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
+            }
         }
     }
 }
@@ -379,6 +399,10 @@ pub enum AlterTableAction {
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
+    SetTTL {
+        ttl: Expr,
+    },
+    DropTTL,
 }
 
 impl Display for AlterTableAction {
@@ -437,6 +461,12 @@ impl Display for AlterTableAction {
             AlterTableAction::FlashbackTo { point } => {
                 write!(f, "FLASHBACK TO {}", point)?;
             }
+            AlterTableAction::SetTTL { ttl } => {
+                write!(f, "SET TTL = {ttl}")?;
+            }
+            AlterTableAction::DropTTL => {
+                write!(f, "DROP TTL")?;
+            }
         };
         Ok(())
     }
@@ -560,6 +590,29 @@ impl Display for VacuumDropTableStmt {
     }
 }
 
+/// `VACUUM ALL TABLES [FROM [<catalog>.]<database>]`, vacuums every table of the
+/// database, or of the whole tenant when no database is given.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct VacuumAllTablesStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub option: VacuumTableOption,
+}
+
+impl Display for VacuumAllTablesStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "VACUUM ALL TABLES ")?;
+        if self.catalog.is_some() || self.database.is_some() {
+            write!(f, "FROM ")?;
+            write_dot_separated_list(f, self.catalog.iter().chain(&self.database))?;
+            write!(f, " ")?;
+        }
+        write!(f, "{}", &self.option)?;
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct VacuumTemporaryFiles {
     pub limit: Option<u64>,
@@ -668,6 +721,7 @@ pub enum Engine {
     Random,
     Iceberg,
     Delta,
+    External,
 }
 
 impl Display for Engine {
@@ -680,6 +734,7 @@ impl Display for Engine {
             Engine::Random => write!(f, "RANDOM"),
             Engine::Iceberg => write!(f, "ICEBERG"),
             Engine::Delta => write!(f, "DELTA"),
+            Engine::External => write!(f, "EXTERNAL"),
         }
     }
 }
@@ -692,12 +747,23 @@ pub enum CompactTarget {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct VacuumTableOption {
+    // Overrides the `data_retention_time_in_days` setting
+    #[drive(skip)]
+    pub retain: Option<Duration>,
     // Some(true) means dry run with summary option
     pub dry_run: Option<bool>,
 }
 
 impl Display for VacuumTableOption {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(retain) = &self.retain {
+            let days = Duration::from_secs(60 * 60 * 24);
+            if retain >= &days && retain.as_secs() % days.as_secs() == 0 {
+                write!(f, "RETAIN {} DAYS ", retain.as_secs() / days.as_secs())?;
+            } else {
+                write!(f, "RETAIN {} SECONDS ", retain.as_secs())?;
+            }
+        }
         if let Some(summary) = self.dry_run {
             write!(f, "DRY RUN")?;
             if summary {
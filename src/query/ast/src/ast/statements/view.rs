@@ -21,9 +21,11 @@ use derive_visitor::DriveMut;
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_dot_separated_list;
 use crate::ast::CreateOption;
+use crate::ast::Expr;
 use crate::ast::Identifier;
 use crate::ast::Query;
 use crate::ast::ShowLimit;
+use crate::ast::TypeName;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct CreateViewStmt {
@@ -32,6 +34,8 @@ pub struct CreateViewStmt {
     pub database: Option<Identifier>,
     pub view: Identifier,
     pub columns: Vec<Identifier>,
+    pub params: Vec<ViewParameter>,
+    pub secure: bool,
     pub query: Box<Query>,
 }
 
@@ -41,6 +45,9 @@ impl Display for CreateViewStmt {
         if let CreateOption::CreateOrReplace = self.create_option {
             write!(f, "OR REPLACE ")?;
         }
+        if self.secure {
+            write!(f, "SECURE ")?;
+        }
         write!(f, "VIEW ")?;
         if let CreateOption::CreateIfNotExists = self.create_option {
             write!(f, "IF NOT EXISTS ")?;
@@ -52,6 +59,11 @@ impl Display for CreateViewStmt {
                 .chain(&self.database)
                 .chain(Some(&self.view)),
         )?;
+        if !self.params.is_empty() {
+            write!(f, " (")?;
+            write_comma_separated_list(f, &self.params)?;
+            write!(f, ")")?;
+        }
         if !self.columns.is_empty() {
             write!(f, " (")?;
             write_comma_separated_list(f, &self.columns)?;
@@ -61,12 +73,32 @@ impl Display for CreateViewStmt {
     }
 }
 
+/// A parameter of a parameterized view, e.g. `p INT DEFAULT 1` in
+/// `CREATE VIEW v(p INT DEFAULT 1) AS SELECT * FROM t WHERE a = p`.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct ViewParameter {
+    pub name: Identifier,
+    pub data_type: TypeName,
+    pub default: Option<Expr>,
+}
+
+impl Display for ViewParameter {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)?;
+        if let Some(default) = &self.default {
+            write!(f, " DEFAULT {default}")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct AlterViewStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub view: Identifier,
     pub columns: Vec<Identifier>,
+    pub params: Vec<ViewParameter>,
     pub query: Box<Query>,
 }
 
@@ -80,6 +112,11 @@ impl Display for AlterViewStmt {
                 .chain(&self.database)
                 .chain(Some(&self.view)),
         )?;
+        if !self.params.is_empty() {
+            write!(f, " (")?;
+            write_comma_separated_list(f, &self.params)?;
+            write!(f, ")")?;
+        }
         if !self.columns.is_empty() {
             write!(f, " (")?;
             write_comma_separated_list(f, &self.columns)?;
@@ -113,6 +150,80 @@ impl Display for DropViewStmt {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Drive, DriveMut)]
+pub enum MaterializedViewRefreshMode {
+    /// Recompute the whole query on refresh.
+    Full,
+    /// Only apply the rows appended to the base table since the last refresh.
+    Incremental,
+}
+
+impl Display for MaterializedViewRefreshMode {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            MaterializedViewRefreshMode::Full => write!(f, "FULL"),
+            MaterializedViewRefreshMode::Incremental => write!(f, "INCREMENTAL"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CreateMaterializedViewStmt {
+    pub create_option: CreateOption,
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub view: Identifier,
+    pub refresh_mode: MaterializedViewRefreshMode,
+    pub query: Box<Query>,
+}
+
+impl Display for CreateMaterializedViewStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE ")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "MATERIALIZED VIEW ")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.view)),
+        )?;
+        write!(f, " REFRESH {} AS {}", self.refresh_mode, self.query)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct RefreshMaterializedViewStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub view: Identifier,
+    /// Force a full refresh of an incremental materialized view.
+    pub full: bool,
+}
+
+impl Display for RefreshMaterializedViewStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "REFRESH MATERIALIZED VIEW ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.view)),
+        )?;
+        if self.full {
+            write!(f, " FULL")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct ShowViewsStmt {
     pub catalog: Option<Identifier>,
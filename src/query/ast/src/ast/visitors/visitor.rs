@@ -566,6 +566,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_vacuum_drop_table(&mut self, _stmt: &'ast VacuumDropTableStmt) {}
 
+    fn visit_vacuum_all_tables(&mut self, _stmt: &'ast VacuumAllTablesStmt) {}
+
     fn visit_vacuum_temporary_files(&mut self, _stmt: &'ast VacuumTemporaryFiles) {}
 
     fn visit_analyze_table(&mut self, _stmt: &'ast AnalyzeTableStmt) {}
@@ -588,6 +590,10 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_views(&mut self, _stmt: &'ast ShowViewsStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &'ast CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &'ast RefreshMaterializedViewStmt) {}
+
     fn visit_describe_view(&mut self, _stmt: &'ast DescribeViewStmt) {}
 
     fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
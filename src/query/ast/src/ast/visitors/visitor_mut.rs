@@ -578,6 +578,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_vacuum_drop_table(&mut self, _stmt: &mut VacuumDropTableStmt) {}
 
+    fn visit_vacuum_all_tables(&mut self, _stmt: &mut VacuumAllTablesStmt) {}
+
     fn visit_vacuum_temporary_files(&mut self, _stmt: &mut VacuumTemporaryFiles) {}
 
     fn visit_analyze_table(&mut self, _stmt: &mut AnalyzeTableStmt) {}
@@ -600,6 +602,10 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_views(&mut self, _stmt: &mut ShowViewsStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &mut CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &mut RefreshMaterializedViewStmt) {}
+
     fn visit_describe_view(&mut self, _stmt: &mut DescribeViewStmt) {}
 
     fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
@@ -319,6 +319,7 @@ pub fn walk_table_reference<'a, V: Visitor<'a>>(visitor: &mut V, table_ref: &'a
 pub fn walk_temporal_clause<'a, V: Visitor<'a>>(visitor: &mut V, clause: &'a TemporalClause) {
     match clause {
         TemporalClause::TimeTravel(point) => visitor.visit_time_travel_point(point),
+        TemporalClause::TimeTravelBefore(point) => visitor.visit_time_travel_point(point),
         TemporalClause::Changes(ChangesInterval {
             at_point,
             end_point,
@@ -479,6 +480,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::OptimizeTable(stmt) => visitor.visit_optimize_table(stmt),
         Statement::VacuumTable(stmt) => visitor.visit_vacuum_table(stmt),
         Statement::VacuumDropTable(stmt) => visitor.visit_vacuum_drop_table(stmt),
+        Statement::VacuumAllTables(stmt) => visitor.visit_vacuum_all_tables(stmt),
         Statement::VacuumTemporaryFiles(stmt) => visitor.visit_vacuum_temporary_files(stmt),
         Statement::AnalyzeTable(stmt) => visitor.visit_analyze_table(stmt),
         Statement::ExistsTable(stmt) => visitor.visit_exists_table(stmt),
@@ -493,6 +495,8 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::DropView(stmt) => visitor.visit_drop_view(stmt),
         Statement::ShowViews(stmt) => visitor.visit_show_views(stmt),
         Statement::DescribeView(stmt) => visitor.visit_describe_view(stmt),
+        Statement::CreateMaterializedView(stmt) => visitor.visit_create_materialized_view(stmt),
+        Statement::RefreshMaterializedView(stmt) => visitor.visit_refresh_materialized_view(stmt),
         Statement::CreateStream(stmt) => visitor.visit_create_stream(stmt),
         Statement::DropStream(stmt) => visitor.visit_drop_stream(stmt),
         Statement::ShowStreams(stmt) => visitor.visit_show_streams(stmt),
@@ -604,7 +608,8 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::AlterNotification(stmt) => visitor.visit_alter_notification(stmt),
         Statement::DropNotification(stmt) => visitor.visit_drop_notification(stmt),
         Statement::DescribeNotification(stmt) => visitor.visit_describe_notification(stmt),
-        Statement::Begin => {}
+        Statement::Begin { .. } => {}
+        Statement::SetTransactionSnapshot { .. } => {}
         Statement::Commit => {}
         Statement::Abort => {}
         Statement::InsertMultiTable(stmt) => visitor.visit_multi_table_insert(stmt),
@@ -356,6 +356,7 @@ pub fn walk_time_travel_point_mut<V: VisitorMut>(visitor: &mut V, time: &mut Tim
 pub fn walk_temporal_clause_mut<V: VisitorMut>(visitor: &mut V, clause: &mut TemporalClause) {
     match clause {
         TemporalClause::TimeTravel(point) => visitor.visit_time_travel_point(point),
+        TemporalClause::TimeTravelBefore(point) => visitor.visit_time_travel_point(point),
         TemporalClause::Changes(ChangesInterval {
             at_point,
             end_point,
@@ -464,6 +465,7 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::OptimizeTable(stmt) => visitor.visit_optimize_table(stmt),
         Statement::VacuumTable(stmt) => visitor.visit_vacuum_table(stmt),
         Statement::VacuumDropTable(stmt) => visitor.visit_vacuum_drop_table(stmt),
+        Statement::VacuumAllTables(stmt) => visitor.visit_vacuum_all_tables(stmt),
         Statement::VacuumTemporaryFiles(stmt) => visitor.visit_vacuum_temporary_files(stmt),
         Statement::AnalyzeTable(stmt) => visitor.visit_analyze_table(stmt),
         Statement::ExistsTable(stmt) => visitor.visit_exists_table(stmt),
@@ -478,6 +480,8 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::DropView(stmt) => visitor.visit_drop_view(stmt),
         Statement::ShowViews(stmt) => visitor.visit_show_views(stmt),
         Statement::DescribeView(stmt) => visitor.visit_describe_view(stmt),
+        Statement::CreateMaterializedView(stmt) => visitor.visit_create_materialized_view(stmt),
+        Statement::RefreshMaterializedView(stmt) => visitor.visit_refresh_materialized_view(stmt),
         Statement::CreateStream(stmt) => visitor.visit_create_stream(stmt),
         Statement::DropStream(stmt) => visitor.visit_drop_stream(stmt),
         Statement::ShowStreams(stmt) => visitor.visit_show_streams(stmt),
@@ -590,7 +594,8 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::AlterPipe(_) => todo!(),
         Statement::DropPipe(_) => todo!(),
         Statement::DescribePipe(_) => todo!(),
-        Statement::Begin => {}
+        Statement::Begin { .. } => {}
+        Statement::SetTransactionSnapshot { .. } => {}
         Statement::Commit => {}
         Statement::Abort => {}
         Statement::CreateNotification(stmt) => visitor.visit_create_notification(stmt),
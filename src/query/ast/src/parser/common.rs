@@ -222,20 +222,29 @@ pub fn table_ref(i: Input) -> IResult<TableRef> {
 }
 
 pub fn set_type(i: Input) -> IResult<SetType> {
-    map(
+    let global = map(
         rule! {
-           (GLOBAL | SESSION | VARIABLE)?
+           GLOBAL ~ LOCKED?
+        },
+        |(_, locked)| match locked {
+            Some(_) => SetType::SettingsGlobalLocked,
+            None => SetType::SettingsGlobal,
+        },
+    );
+    let others = map(
+        rule! {
+           (SESSION | VARIABLE)?
         },
         |res| match res {
             Some(token) => match token.kind {
-                TokenKind::GLOBAL => SetType::SettingsGlobal,
                 TokenKind::SESSION => SetType::SettingsSession,
                 TokenKind::VARIABLE => SetType::Variable,
                 _ => unreachable!(),
             },
             None => SetType::SettingsSession,
         },
-    )(i)
+    );
+    rule!(#global | #others)(i)
 }
 
 pub fn unset_type(i: Input) -> IResult<SetType> {
@@ -110,6 +110,7 @@ fn copy_into_location(i: Input) -> IResult<Statement> {
                 file_format: Default::default(),
                 single: Default::default(),
                 max_file_size: Default::default(),
+                max_files: Default::default(),
                 detailed_output: false,
             };
             for opt in opts {
@@ -197,6 +198,10 @@ fn copy_into_location_option(i: Input) -> IResult<CopyIntoLocationOption> {
             rule! { MAX_FILE_SIZE ~ "=" ~ #literal_u64 },
             |(_, _, max_file_size)| CopyIntoLocationOption::MaxFileSize(max_file_size as usize),
         ),
+        map(
+            rule! { MAX_FILES ~ "=" ~ #literal_u64 },
+            |(_, _, max_files)| CopyIntoLocationOption::MaxFiles(max_files as usize),
+        ),
         map(
             rule! { DETAILED_OUTPUT ~ "=" ~ #literal_bool },
             |(_, _, detailed_output)| CopyIntoLocationOption::DetailedOutput(detailed_output),
@@ -23,6 +23,7 @@ use crate::ast::Literal;
 use crate::ast::SelectTarget;
 use crate::ast::Statement;
 use crate::ast::StatementWithFormat;
+use crate::ast::ViewParameter;
 use crate::parser::common::comma_separated_list0;
 use crate::parser::common::comma_separated_list1;
 use crate::parser::common::ident;
@@ -37,6 +38,7 @@ use crate::parser::input::ParseMode;
 use crate::parser::statement::insert_stmt;
 use crate::parser::statement::replace_stmt;
 use crate::parser::statement::statement;
+use crate::parser::statement::view_parameter;
 use crate::parser::token::Token;
 use crate::parser::token::TokenKind;
 use crate::parser::token::Tokenizer;
@@ -77,6 +79,13 @@ pub fn parse_comma_separated_idents(tokens: &[Token], dialect: Dialect) -> Resul
     })
 }
 
+/// Parse the parameters of a parameterized view, e.g. `p INT, q STRING DEFAULT 'a'`.
+pub fn parse_view_parameters(tokens: &[Token], dialect: Dialect) -> Result<Vec<ViewParameter>> {
+    run_parser(tokens, dialect, ParseMode::Default, false, |i| {
+        comma_separated_list1(view_parameter)(i)
+    })
+}
+
 pub fn parse_values_with_placeholder(
     tokens: &[Token],
     dialect: Dialect,
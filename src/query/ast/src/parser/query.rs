@@ -550,6 +550,13 @@ pub fn temporal_clause(i: Input) -> IResult<TemporalClause> {
         |(_, travel_point)| TemporalClause::TimeTravel(travel_point),
     );
 
+    let time_travel_before = map(
+        rule! {
+            BEFORE ~ #at_snapshot_or_ts
+        },
+        |(_, travel_point)| TemporalClause::TimeTravelBefore(travel_point),
+    );
+
     let changes = map(
         rule! {
             CHANGES ~ "(" ~ INFORMATION ~ "=>" ~ ( DEFAULT | APPEND_ONLY ) ~ ")" ~ AT ~ ^#travel_point ~ (END ~ ^#at_snapshot_or_ts)?
@@ -566,6 +573,7 @@ pub fn temporal_clause(i: Input) -> IResult<TemporalClause> {
 
     rule!(
         #time_travel
+        | #time_travel_before
         | #changes
     )(i)
 }
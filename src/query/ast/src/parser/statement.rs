@@ -696,11 +696,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     );
     let create_table = map_res(
         rule! {
-            CREATE ~ ( OR ~ ^REPLACE )? ~ TRANSIENT? ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            CREATE ~ ( OR ~ ^REPLACE )? ~ TRANSIENT? ~ EXTERNAL? ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #dot_separated_idents_1_to_3
             ~ #create_table_source?
             ~ ( #engine )?
-            ~ ( #uri_location )?
+            ~ ( LOCATION? ~ #uri_location )?
+            ~ ( #file_format_clause )?
             ~ ( CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
@@ -709,18 +710,30 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             _,
             opt_or_replace,
             opt_transient,
+            opt_external,
             _,
             opt_if_not_exists,
             (catalog, database, table),
             source,
             engine,
-            uri_location,
+            opt_uri_location,
+            opt_file_format,
             opt_cluster_by,
             opt_table_options,
             opt_as_query,
         )| {
             let create_option =
                 parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            // `CREATE EXTERNAL TABLE` is a shorthand of `CREATE TABLE ... ENGINE = EXTERNAL`.
+            let engine = match (opt_external, engine) {
+                (Some(_), None | Some(Engine::External)) => Some(Engine::External),
+                (Some(_), Some(_)) => {
+                    return Err(nom::Err::Failure(ErrorKind::Other(
+                        "CREATE EXTERNAL TABLE can not specify another table engine",
+                    )));
+                }
+                (None, engine) => engine,
+            };
             Ok(Statement::CreateTable(CreateTableStmt {
                 create_option,
                 catalog,
@@ -728,7 +741,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                 table,
                 source,
                 engine,
-                uri_location,
+                uri_location: opt_uri_location.map(|(_, uri_location)| uri_location),
+                file_format: opt_file_format,
                 cluster_by: opt_cluster_by
                     .map(|(_, _, _, exprs, _)| exprs)
                     .unwrap_or_default(),
@@ -865,6 +879,22 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             })
         },
     );
+    let vacuum_all_tables = map(
+        rule! {
+            VACUUM ~ ALL ~ TABLES ~ (FROM ~ ^#dot_separated_idents_1_to_2)? ~ #vacuum_table_option
+        },
+        |(_, _, _, database_option, option)| {
+            let (catalog, database) = database_option.map_or_else(
+                || (None, None),
+                |(_, catalog_database)| (catalog_database.0, Some(catalog_database.1)),
+            );
+            Statement::VacuumAllTables(VacuumAllTablesStmt {
+                catalog,
+                database,
+                option,
+            })
+        },
+    );
     let analyze_table = map(
         rule! {
             ANALYZE ~ TABLE ~ #dot_separated_idents_1_to_3
@@ -972,17 +1002,20 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
 
     let create_view = map_res(
         rule! {
-            CREATE ~ ( OR ~ ^REPLACE )? ~ VIEW ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            CREATE ~ ( OR ~ ^REPLACE )? ~ SECURE? ~ VIEW ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #dot_separated_idents_1_to_3
+            ~ ( "(" ~ #comma_separated_list1(view_parameter) ~ ")" )?
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ AS ~ #query
         },
         |(
             _,
             opt_or_replace,
+            opt_secure,
             _,
             opt_if_not_exists,
             (catalog, database, view),
+            opt_params,
             opt_columns,
             _,
             query,
@@ -997,6 +1030,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                 columns: opt_columns
                     .map(|(_, columns, _)| columns)
                     .unwrap_or_default(),
+                params: opt_params.map(|(_, params, _)| params).unwrap_or_default(),
+                secure: opt_secure.is_some(),
                 query: Box::new(query),
             }))
         },
@@ -1014,14 +1049,81 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             })
         },
     );
+    let create_materialized_view = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ MATERIALIZED ~ ^VIEW ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #dot_separated_idents_1_to_3
+            ~ ( REFRESH ~ ( FULL | INCREMENTAL ) )?
+            ~ AS ~ #query
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, view),
+            opt_refresh_mode,
+            _,
+            query,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            let refresh_mode = match opt_refresh_mode {
+                Some((_, mode)) if mode.kind == INCREMENTAL => {
+                    MaterializedViewRefreshMode::Incremental
+                }
+                _ => MaterializedViewRefreshMode::Full,
+            };
+            Ok(Statement::CreateMaterializedView(
+                CreateMaterializedViewStmt {
+                    create_option,
+                    catalog,
+                    database,
+                    view,
+                    refresh_mode,
+                    query: Box::new(query),
+                },
+            ))
+        },
+    );
+    let drop_materialized_view = map(
+        rule! {
+            DROP ~ MATERIALIZED ~ ^VIEW ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, (catalog, database, table))| {
+            // A materialized view is stored as a fuse table.
+            Statement::DropTable(DropTableStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                table,
+                all: false,
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ ^VIEW ~ #dot_separated_idents_1_to_3 ~ FULL?
+        },
+        |(_, _, _, (catalog, database, view), opt_full)| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+                full: opt_full.is_some(),
+            })
+        },
+    );
     let alter_view = map(
         rule! {
             ALTER ~ VIEW
             ~ #dot_separated_idents_1_to_3
+            ~ ( "(" ~ #comma_separated_list1(view_parameter) ~ ")" )?
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ AS ~ #query
         },
-        |(_, _, (catalog, database, view), opt_columns, _, query)| {
+        |(_, _, (catalog, database, view), opt_params, opt_columns, _, query)| {
             Statement::AlterView(AlterViewStmt {
                 catalog,
                 database,
@@ -1029,6 +1131,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                 columns: opt_columns
                     .map(|(_, columns, _)| columns)
                     .unwrap_or_default(),
+                params: opt_params.map(|(_, params, _)| params).unwrap_or_default(),
                 query: Box::new(query),
             })
         },
@@ -1073,9 +1176,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ~ AGGREGATING ~ INDEX
             ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #ident
-            ~ AS ~ #query
+            ~ #aggregating_index_query
         },
-        |(_, opt_or_replace, opt_async, _, _, opt_if_not_exists, index_name, _, query)| {
+        |(_, opt_or_replace, opt_async, _, _, opt_if_not_exists, index_name, query)| {
             let create_option =
                 parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
             Ok(Statement::CreateIndex(CreateIndexStmt {
@@ -1475,6 +1578,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ~ ( ON_ERROR ~ ^"=" ~ ^#ident )?
             ~ ( SIZE_LIMIT ~ ^"=" ~ ^#literal_u64 )?
             ~ ( VALIDATION_MODE ~ ^"=" ~ ^#ident )?
+            ~ ( PURGE_AFTER_DAYS ~ ^"=" ~ ^#literal_u64 )?
+            ~ ( MAX_TOTAL_SIZE ~ ^"=" ~ ^#literal_u64 )?
             ~ ( (COMMENT | COMMENTS) ~ ^"=" ~ ^#literal_string )?
         },
         |(
@@ -1488,6 +1593,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             on_error_opt,
             size_limit_opt,
             validation_mode_opt,
+            purge_after_days_opt,
+            max_total_size_opt,
             comment_opt,
         )| {
             let create_option =
@@ -1502,6 +1609,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                 validation_mode: validation_mode_opt
                     .map(|v| v.2.to_string())
                     .unwrap_or_default(),
+                purge_after_days: purge_after_days_opt.map(|v| v.2).unwrap_or_default(),
+                max_total_size: max_total_size_opt.map(|v| v.2).unwrap_or_default(),
                 comments: comment_opt.map(|v| v.2).unwrap_or_default(),
             }))
         },
@@ -2159,7 +2268,16 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
-    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let begin = map(
+        rule! { BEGIN ~ TRANSACTION? ~ ( READ ~ ONLY ~ AT ~ #expr )? },
+        |(_, _, read_only_at)| Statement::Begin {
+            read_only_at: read_only_at.map(|(_, _, _, at)| Box::new(at)),
+        },
+    );
+    let set_transaction_snapshot = map(
+        rule! { SET ~ TRANSACTION ~ SNAPSHOT ~ #expr },
+        |(_, _, _, at)| Statement::SetTransactionSnapshot { at: Box::new(at) },
+    );
     let commit = value(Statement::Commit, rule! { COMMIT });
     let abort = value(Statement::Abort, rule! { ABORT | ROLLBACK });
 
@@ -2193,8 +2311,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #show_locks : "`SHOW LOCKS [IN ACCOUNT] [WHERE ...]`"
             | #kill_stmt : "`KILL (QUERY | CONNECTION) <object_id>`"
             | #vacuum_temp_files : "VACUUM TEMPORARY FILES [RETAIN number SECONDS|DAYS] [LIMIT number]"
+            | #vacuum_all_tables : "`VACUUM ALL TABLES [FROM [<catalog>.]<database>] [RETAIN <number> {DAYS | SECONDS}] [DRY RUN | DRY RUN SUMMARY]`"
             | #set_priority: "`SET PRIORITY (HIGH | MEDIUM | LOW) <object_id>`"
-            | #system_action: "`SYSTEM (ENABLE | DISABLE) EXCEPTION_BACKTRACE`"
+            | #system_action: "`SYSTEM {(ENABLE | DISABLE) EXCEPTION_BACKTRACE | RELOAD CONFIG | FLUSH LOGS | DROP QUERY CACHE | SYNC METADATA}`"
         ),
         // database
         rule!(
@@ -2228,6 +2347,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #delete : "`DELETE FROM <table> [WHERE ...]`"
             | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
             | #begin
+            | #set_transaction_snapshot : "`SET TRANSACTION SNAPSHOT <timestamp>`"
             | #commit
             | #abort
         ),
@@ -2248,7 +2368,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #rename_table : "`RENAME TABLE [<database>.]<table> TO <new_table>`"
             | #truncate_table : "`TRUNCATE TABLE [<database>.]<table>`"
             | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
-            | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
+            | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN <number> {DAYS | SECONDS}] [DRY RUN | DRY RUN SUMMARY]`"
             | #vacuum_drop_table : "`VACUUM DROP TABLE [FROM [<catalog>.]<database>] [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
             | #analyze_table : "`ANALYZE TABLE [<database>.]<table>`"
             | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
@@ -2263,11 +2383,14 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         ),
         // view,index
         rule!(
-            #create_view : "`CREATE [OR REPLACE] VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            #create_view : "`CREATE [OR REPLACE] [SECURE] VIEW [IF NOT EXISTS] [<database>.]<view> [(<param> <type> [DEFAULT <expr>], ...)] [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
-            | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #alter_view : "`ALTER VIEW [<database>.]<view> [(<param> <type> [DEFAULT <expr>], ...)] [(<column>, ...)] AS SELECT ...`"
             | #show_views : "`SHOW [FULL] VIEWS [FROM <database>] [<show_limit>]`"
-            | #create_index: "`CREATE [OR REPLACE] AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
+            | #create_materialized_view : "`CREATE [OR REPLACE] MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> [REFRESH {FULL | INCREMENTAL}] AS SELECT ...`"
+            | #drop_materialized_view : "`DROP MATERIALIZED VIEW [IF EXISTS] [<database>.]<view>`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view> [FULL]`"
+            | #create_index: "`CREATE [OR REPLACE] AGGREGATING INDEX [IF NOT EXISTS] <index> { AS SELECT ... | ON <table> (<expr>, ...) }`"
             | #drop_index: "`DROP <index_type> INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH <index_type> INDEX <index> [LIMIT <limit>]`"
             | #create_inverted_index: "`CREATE [OR REPLACE] INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>, ...)`"
@@ -2301,6 +2424,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             #create_stage: "`CREATE [OR REPLACE] STAGE [ IF NOT EXISTS ] <stage_name>
                 [ FILE_FORMAT = ( { TYPE = { CSV | PARQUET } [ formatTypeOptions ] ) } ]
                 [ COPY_OPTIONS = ( copyOptions ) ]
+                [ PURGE_AFTER_DAYS = <days> ] [ MAX_TOTAL_SIZE = <bytes> ]
                 [ COMMENT = '<string_literal>' ]`"
             | #desc_stage: "`DESC STAGE <stage_name>`"
             | #list_stage: "`LIST @<stage_name> [pattern = '<pattern>']`"
@@ -2769,6 +2893,19 @@ pub fn rest_str(i: Input) -> IResult<(String, usize)> {
     ))
 }
 
+pub fn view_parameter(i: Input) -> IResult<ViewParameter> {
+    map(
+        rule! {
+            #ident ~ #type_name ~ ( DEFAULT ~ ^#subexpr(NOT_PREC) )?
+        },
+        |(name, data_type, opt_default)| ViewParameter {
+            name,
+            data_type,
+            default: opt_default.map(|(_, default)| default),
+        },
+    )(i)
+}
+
 pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     #[derive(Clone)]
     enum ColumnConstraint {
@@ -3270,9 +3407,77 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
     );
 
+    let clone_of = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3
+        },
+        |(_, (catalog, database, table))| CreateTableSource::CloneOf {
+            catalog,
+            database,
+            table,
+        },
+    );
+
     rule!(
         #columns
         | #like
+        | #clone_of
+    )(i)
+}
+
+/// The query of an aggregating index, either `AS SELECT ...` or the shorthand
+/// `ON t (a, SUM(b))`, which is the same as `AS SELECT a, SUM(b) FROM t GROUP BY ALL`.
+pub fn aggregating_index_query(i: Input) -> IResult<Query> {
+    let as_query = map(
+        rule! {
+            AS ~ #query
+        },
+        |(_, query)| query,
+    );
+    let on_table = map(
+        consumed(rule! {
+            ON ~ #dot_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(select_target) ~ ^")"
+        }),
+        |(span, (_, (catalog, database, table), _, select_list, _))| {
+            let span = transform_span(span.tokens);
+            Query {
+                span,
+                with: None,
+                body: SetExpr::Select(Box::new(SelectStmt {
+                    span,
+                    hints: None,
+                    distinct: false,
+                    top_n: None,
+                    select_list,
+                    from: vec![TableReference::Table {
+                        span,
+                        catalog,
+                        database,
+                        table,
+                        alias: None,
+                        temporal: None,
+                        consume: false,
+                        pivot: None,
+                        unpivot: None,
+                    }],
+                    selection: None,
+                    group_by: Some(GroupBy::All),
+                    having: None,
+                    window_list: None,
+                    qualify: None,
+                })),
+                order_by: vec![],
+                limit: vec![],
+                offset: None,
+                ignore_result: false,
+            }
+        },
+    );
+
+    rule!(
+        #as_query
+        | #on_table
     )(i)
 }
 
@@ -3484,9 +3689,24 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let set_table_ttl = map(
+        rule! {
+            SET ~ TTL ~ ^"=" ~ ^#expr
+        },
+        |(_, _, _, ttl)| AlterTableAction::SetTTL { ttl },
+    );
+
+    let drop_table_ttl = map(
+        rule! {
+            DROP ~ TTL
+        },
+        |(_, _)| AlterTableAction::DropTTL,
+    );
+
     rule!(
         #alter_table_cluster_key
         | #drop_table_cluster_key
+        | #drop_table_ttl
         | #rename_table
         | #rename_column
         | #modify_table_comment
@@ -3496,6 +3716,7 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #set_table_ttl
     )(i)
 }
 
@@ -3657,9 +3878,10 @@ pub fn vacuum_drop_table_option(i: Input) -> IResult<VacuumDropTableOption> {
 pub fn vacuum_table_option(i: Input) -> IResult<VacuumTableOption> {
     alt((map(
         rule! {
-            (DRY ~ ^RUN ~ SUMMARY?)?
+            (RETAIN ~ ^#literal_duration)? ~ (DRY ~ ^RUN ~ SUMMARY?)?
         },
-        |opt_dry_run| VacuumTableOption {
+        |(opt_retain, opt_dry_run)| VacuumTableOption {
+            retain: opt_retain.map(|(_, retain)| retain),
             dry_run: opt_dry_run.map(|dry_run| dry_run.2.is_some()),
         },
     ),))(i)
@@ -3876,9 +4098,17 @@ pub fn action(i: Input) -> IResult<SystemAction> {
         },
         |(switch, _)| SystemAction::Backtrace(switch),
     );
+    let mut reload_config = value(SystemAction::ReloadConfig, rule! { RELOAD ~ CONFIG });
+    let mut flush_logs = value(SystemAction::FlushLogs, rule! { FLUSH ~ LOGS });
+    let mut drop_query_cache = value(SystemAction::DropQueryCache, rule! { DROP ~ QUERY ~ CACHE });
+    let mut sync_metadata = value(SystemAction::SyncMetadata, rule! { SYNC ~ METADATA });
     // add other system action type here
     rule!(
         #backtrace
+        | #reload_config
+        | #flush_logs
+        | #drop_query_cache
+        | #sync_metadata
     )(i)
 }
 
@@ -3974,6 +4204,7 @@ pub fn engine(i: Input) -> IResult<Engine> {
         value(Engine::Random, rule! { RANDOM }),
         value(Engine::Iceberg, rule! { ICEBERG }),
         value(Engine::Delta, rule! { DELTA }),
+        value(Engine::External, rule! { EXTERNAL }),
     ));
 
     map(
@@ -4007,6 +4238,7 @@ pub fn catalog_type(i: Input) -> IResult<CatalogType> {
         value(CatalogType::Hive, rule! { HIVE }),
         value(CatalogType::Iceberg, rule! { ICEBERG }),
         value(CatalogType::Share, rule! { SHARE }),
+        value(CatalogType::Stage, rule! { STAGE }),
     ))(i)
 }
 
@@ -4364,7 +4596,17 @@ pub fn alter_password_action(i: Input) -> IResult<AlterPasswordAction> {
 }
 
 pub fn explain_option(i: Input) -> IResult<ExplainOption> {
-    map(
+    let format = map(
+        rule! {
+            FORMAT ~ ^( TEXT | JSON )
+        },
+        |(_, format)| match &format.kind {
+            TEXT => ExplainOption::Format(ExplainFormat::Text),
+            JSON => ExplainOption::Format(ExplainFormat::Json),
+            _ => unreachable!(),
+        },
+    );
+    let option = map(
         rule! {
             VERBOSE | LOGICAL | OPTIMIZED
         },
@@ -4374,6 +4616,10 @@ pub fn explain_option(i: Input) -> IResult<ExplainOption> {
             OPTIMIZED => ExplainOption::Optimized,
             _ => unreachable!(),
         },
+    );
+    rule!(
+        #format
+        | #option
     )(i)
 }
 
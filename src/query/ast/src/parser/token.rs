@@ -405,6 +405,8 @@ pub enum TokenKind {
     BROTLI,
     #[token("BZ2", ignore(ascii_case))]
     BZ2,
+    #[token("CACHE", ignore(ascii_case))]
+    CACHE,
     #[token("CALL", ignore(ascii_case))]
     CALL,
     #[token("CASE", ignore(ascii_case))]
@@ -419,6 +421,8 @@ pub enum TokenKind {
     CENTURY,
     #[token("CHANGES", ignore(ascii_case))]
     CHANGES,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
@@ -427,6 +431,8 @@ pub enum TokenKind {
     COMMENTS,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
+    #[token("CONFIG", ignore(ascii_case))]
+    CONFIG,
     #[token("CONNECTION", ignore(ascii_case))]
     CONNECTION,
     #[token("CONNECTIONS", ignore(ascii_case))]
@@ -575,6 +581,8 @@ pub enum TokenKind {
     EXPLAIN,
     #[token("EXPIRE", ignore(ascii_case))]
     EXPIRE,
+    #[token("EXTERNAL", ignore(ascii_case))]
+    EXTERNAL,
     #[token("EXTRACT", ignore(ascii_case))]
     EXTRACT,
     #[token("ELSEIF", ignore(ascii_case))]
@@ -603,6 +611,8 @@ pub enum TokenKind {
     FLASHBACK,
     #[token("FLOAT", ignore(ascii_case))]
     FLOAT,
+    #[token("FLUSH", ignore(ascii_case))]
+    FLUSH,
     #[token("FLOAT32", ignore(ascii_case))]
     FLOAT32,
     #[token("FLOAT64", ignore(ascii_case))]
@@ -725,10 +735,16 @@ pub enum TokenKind {
     KILL,
     #[token("LATERAL", ignore(ascii_case))]
     LATERAL,
+    #[token("LOCATION", ignore(ascii_case))]
+    LOCATION,
     #[token("LOCATION_PREFIX", ignore(ascii_case))]
     LOCATION_PREFIX,
+    #[token("LOCKED", ignore(ascii_case))]
+    LOCKED,
     #[token("LOCKS", ignore(ascii_case))]
     LOCKS,
+    #[token("LOGS", ignore(ascii_case))]
+    LOGS,
     #[token("LOGICAL", ignore(ascii_case))]
     LOGICAL,
     #[token("LOOP", ignore(ascii_case))]
@@ -762,6 +778,8 @@ pub enum TokenKind {
     MAP,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MAX_TOTAL_SIZE", ignore(ascii_case))]
+    MAX_TOTAL_SIZE,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
     #[token("MEDIUM", ignore(ascii_case))]
@@ -770,6 +788,8 @@ pub enum TokenKind {
     MEMO,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("METADATA", ignore(ascii_case))]
+    METADATA,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
@@ -824,6 +844,8 @@ pub enum TokenKind {
     ON_CREATE,
     #[token("ON_SCHEDULE", ignore(ascii_case))]
     ON_SCHEDULE,
+    #[token("ONLY", ignore(ascii_case))]
+    ONLY,
     #[token("OPTIMIZE", ignore(ascii_case))]
     OPTIMIZE,
     #[token("OPTIONS", ignore(ascii_case))]
@@ -890,6 +912,8 @@ pub enum TokenKind {
     PRIORITY,
     #[token("PURGE", ignore(ascii_case))]
     PURGE,
+    #[token("PURGE_AFTER_DAYS", ignore(ascii_case))]
+    PURGE_AFTER_DAYS,
     #[token("PUT", ignore(ascii_case))]
     PUT,
     #[token("QUARTER", ignore(ascii_case))]
@@ -954,6 +978,8 @@ pub enum TokenKind {
     PRIVILEGES,
     #[token("QUALIFY", ignore(ascii_case))]
     QUALIFY,
+    #[token("RELOAD", ignore(ascii_case))]
+    RELOAD,
     #[token("REMOVE", ignore(ascii_case))]
     REMOVE,
     #[token("RETAIN", ignore(ascii_case))]
@@ -990,6 +1016,8 @@ pub enum TokenKind {
     SECOND,
     #[token("MILLISECOND", ignore(ascii_case))]
     MILLISECOND,
+    #[token("SECURE", ignore(ascii_case))]
+    SECURE,
     #[token("SELECT", ignore(ascii_case))]
     SELECT,
     #[token("PIVOT", ignore(ascii_case))]
@@ -1108,6 +1136,8 @@ pub enum TokenKind {
     TIMEZONE_MINUTE,
     #[token("TIMEZONE", ignore(ascii_case))]
     TIMEZONE,
+    #[token("TTL", ignore(ascii_case))]
+    TTL,
     #[token("TINYINT", ignore(ascii_case))]
     TINYINT,
     #[token("TO", ignore(ascii_case))]
@@ -121,6 +121,7 @@ fn test_statement() {
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
         r#"CREATE AGGREGATING INDEX idx1 AS SELECT SUM(a), b FROM t1 WHERE b > 3 GROUP BY b;"#,
         r#"CREATE OR REPLACE AGGREGATING INDEX idx1 AS SELECT SUM(a), b FROM t1 WHERE b > 3 GROUP BY b;"#,
+        r#"CREATE AGGREGATING INDEX idx2 ON t1 (b, SUM(a));"#,
         r#"CREATE OR REPLACE INVERTED INDEX idx2 ON t1 (a, b);"#,
         r#"create table a (c decimal(38, 0))"#,
         r#"create table a (c decimal(38))"#,
@@ -135,6 +136,7 @@ fn test_statement() {
         r#"create table if not exists a.b (a string, b string, inverted index idx1 (a,b) tokenizer='chinese');"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table t clone db.t2;"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"
             create table if not exists a.b (a int) 's3://testbucket/admin/data/'
@@ -240,6 +242,7 @@ fn test_statement() {
         r#"CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' connection=(aws_key_id='1a2b3c', aws_secret_key='4x5y6z') file_format=(type = CSV, compression = GZIP record_delimiter=',')"#,
         r#"CREATE STAGE IF NOT EXISTS test_stage url='azblob://load/files/' connection=(account_name='1a2b3c' account_key='4x5y6z') file_format=(type = CSV compression = GZIP record_delimiter=',')"#,
         r#"CREATE OR REPLACE STAGE test_stage url='azblob://load/files/' connection=(account_name='1a2b3c' account_key='4x5y6z') file_format=(type = CSV compression = GZIP record_delimiter=',')"#,
+        r#"CREATE STAGE tmp_stage PURGE_AFTER_DAYS = 7 MAX_TOTAL_SIZE = 1073741824"#,
         r#"DROP STAGE abc"#,
         r#"DROP STAGE ~"#,
         r#"list @stage_a;"#,
@@ -261,6 +264,8 @@ fn test_statement() {
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
         r#"ALTER TABLE t COMMENT='t1-commnet';"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
+        r#"ALTER TABLE t SET TTL = created_at + INTERVAL 90 DAY;"#,
+        r#"ALTER TABLE t DROP TTL;"#,
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0 LIMIT 10;"#,
         r#"ALTER TABLE t ADD c int null;"#,
         r#"ALTER TABLE t ADD COLUMN c int null;"#,
@@ -283,11 +288,14 @@ fn test_statement() {
         r#"VACUUM TABLE t;"#,
         r#"VACUUM TABLE t DRY RUN;"#,
         r#"VACUUM TABLE t DRY RUN SUMMARY;"#,
+        r#"VACUUM TABLE t RETAIN 2 DAYS DRY RUN;"#,
         r#"VACUUM DROP TABLE;"#,
         r#"VACUUM DROP TABLE DRY RUN;"#,
         r#"VACUUM DROP TABLE DRY RUN SUMMARY;"#,
         r#"VACUUM DROP TABLE FROM db;"#,
         r#"VACUUM DROP TABLE FROM db LIMIT 10;"#,
+        r#"VACUUM ALL TABLES;"#,
+        r#"VACUUM ALL TABLES FROM db RETAIN 3600 SECONDS DRY RUN SUMMARY;"#,
         r#"CREATE TABLE t (a INT COMMENT 'col comment') COMMENT='table comment';"#,
         r#"GRANT CREATE, CREATE USER ON * TO 'test-grant';"#,
         r#"GRANT SELECT, CREATE ON * TO 'test-grant';"#,
@@ -437,6 +445,7 @@ fn test_statement() {
                     skip_header = 1
                 );
         "#,
+        r#"COPY INTO @my_stage FROM mytable MAX_FILE_SIZE = 1048576 MAX_FILES = 4;"#,
         r#"
             COPY INTO mytable
                 FROM 's3://mybucket/data.csv'
@@ -576,6 +585,7 @@ fn test_statement() {
         r#"SET max_threads = 10;"#,
         r#"SET max_threads = 10*2;"#,
         r#"SET global (max_threads, max_memory_usage) = (10*2, 10*4);"#,
+        r#"SET GLOBAL LOCKED max_threads = 10;"#,
         r#"UNSET max_threads;"#,
         r#"UNSET session max_threads;"#,
         r#"UNSET (max_threads, sql_dialect);"#,
@@ -833,6 +843,9 @@ fn test_statement() {
             PRIMARY KEY username
             SOURCE (mysql(host='localhost' username='root' password='1234'))
             COMMENT 'This is a comment';"#,
+        // system
+        r#"SYSTEM RELOAD CONFIG;"#,
+        r#"SYSTEM DROP QUERY CACHE;"#,
     ];
 
     for case in cases {
@@ -987,6 +1000,7 @@ fn test_query() {
         r#"select columns('abc'), columns(a -> length(a) = 3) from t"#,
         r#"select * from customer at(offset => -10 * 30)"#,
         r#"select * from customer changes(information => default) at (stream => s) order by a, b"#,
+        r#"select * from customer before (snapshot => '9828b23f74664ff3806f44bbc1925ea5')"#,
         r#"select * from customer with consume as s"#,
         r#"select * from customer inner join orders"#,
         r#"select * from customer cross join orders"#,
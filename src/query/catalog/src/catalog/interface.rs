@@ -237,6 +237,13 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
     // Get the table name by meta id.
     async fn get_table_name_by_id(&self, table_id: MetaId) -> Result<Option<String>>;
 
+    // Get the name of the database of a table by the table meta id.
+    async fn get_db_name_by_table_id(&self, _table_id: MetaId) -> Result<Option<String>> {
+        Err(ErrorCode::Unimplemented(
+            "'get_db_name_by_table_id' not implemented",
+        ))
+    }
+
     // Get one table by db and table name.
     async fn get_table(
         &self,
@@ -258,6 +258,10 @@ impl Catalog for SessionCatalog {
         self.inner.get_table_name_by_id(table_id).await
     }
 
+    async fn get_db_name_by_table_id(&self, table_id: MetaId) -> Result<Option<String>> {
+        self.inner.get_db_name_by_table_id(table_id).await
+    }
+
     // Get the db name by meta id.
     async fn get_db_name_by_id(&self, db_id: MetaId) -> Result<String> {
         self.inner.get_db_name_by_id(db_id).await
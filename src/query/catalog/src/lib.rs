@@ -24,6 +24,7 @@ pub mod query_kind;
 pub mod statistics;
 pub mod table_args;
 pub mod table_context;
+pub mod table_events;
 pub mod table_function;
 
 pub mod merge_into_join;
@@ -80,10 +80,6 @@ pub struct ParquetTableInfo {
     pub parquet_metas: Arc<Mutex<Vec<Arc<FullParquetMeta>>>>,
     #[serde(skip)]
     pub need_stats_provider: bool,
-    #[serde(skip)]
-    pub max_threads: usize,
-    #[serde(skip)]
-    pub max_memory_usage: u64,
 }
 
 impl ParquetTableInfo {
@@ -217,8 +213,6 @@ mod tests {
             compression_ratio: 0.0,
             parquet_metas: Arc::new(Mutex::new(vec![])),
             need_stats_provider: false,
-            max_threads: 1,
-            max_memory_usage: 10000,
         };
         let s = serde_json::to_string(&info).unwrap();
         let info = serde_json::from_str::<ParquetTableInfo>(&s).unwrap();
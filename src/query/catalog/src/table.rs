@@ -466,6 +466,8 @@ impl<T: ?Sized> TableExt for T where T: Table {}
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum TimeNavigation {
     TimeTravel(NavigationPoint),
+    /// The latest snapshot strictly before the given point.
+    TimeTravelBefore(NavigationPoint),
     Changes {
         append_only: bool,
         desc: String,
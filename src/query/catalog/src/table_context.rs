@@ -168,6 +168,9 @@ pub trait TableContext: Send + Sync {
     fn get_partitions_shas(&self) -> Vec<String>;
     fn get_cacheable(&self) -> bool;
     fn set_cacheable(&self, cacheable: bool);
+    /// Profiles of a query reading secure views not owned by the user are hidden.
+    fn get_hide_profiles(&self) -> bool;
+    fn set_hide_profiles(&self, hide: bool);
     fn get_can_scan_from_agg_index(&self) -> bool;
     fn set_can_scan_from_agg_index(&self, enable: bool);
     fn get_enable_sort_spill(&self) -> bool;
@@ -0,0 +1,143 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableInfo;
+use serde::Serialize;
+
+use crate::table_context::TableContext;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TableEventType {
+    TableCreated,
+    TableDropped,
+    SnapshotCommitted,
+    CompactionFinished,
+}
+
+impl Display for TableEventType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableEventType::TableCreated => write!(f, "table_created"),
+            TableEventType::TableDropped => write!(f, "table_dropped"),
+            TableEventType::SnapshotCommitted => write!(f, "snapshot_committed"),
+            TableEventType::CompactionFinished => write!(f, "compaction_finished"),
+        }
+    }
+}
+
+/// A change of a table that is published to the configured table event sinks.
+#[derive(Clone, Debug, Serialize)]
+pub struct TableEvent {
+    pub event_type: TableEventType,
+    /// Microseconds since the unix epoch.
+    pub event_time: i64,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub table_id: u64,
+    pub snapshot_location: Option<String>,
+}
+
+impl TableEvent {
+    pub fn create(
+        event_type: TableEventType,
+        tenant: &str,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        table_id: u64,
+    ) -> Self {
+        TableEvent {
+            event_type,
+            event_time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_micros() as i64,
+            tenant: tenant.to_string(),
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+            table: table.to_string(),
+            table_id,
+            snapshot_location: None,
+        }
+    }
+
+    /// Builds the event of a table changed by the query of `ctx` from its table info,
+    /// the database of the table is looked up by the table id in its catalog.
+    pub async fn from_table_info(
+        ctx: &dyn TableContext,
+        event_type: TableEventType,
+        table_info: &TableInfo,
+    ) -> Result<Self> {
+        let table_id = table_info.ident.table_id;
+        let catalog = ctx.get_catalog(table_info.catalog()).await?;
+        let database = catalog
+            .get_db_name_by_table_id(table_id)
+            .await?
+            .ok_or_else(|| {
+                ErrorCode::UnknownTableId(format!("unknown database of table id {table_id}"))
+            })?;
+        Ok(Self::create(
+            event_type,
+            ctx.get_tenant().tenant_name(),
+            table_info.catalog(),
+            &database,
+            &table_info.name,
+            table_id,
+        ))
+    }
+
+    pub fn with_snapshot_location(mut self, location: impl Into<String>) -> Self {
+        self.snapshot_location = Some(location.into());
+        self
+    }
+}
+
+/// Receives the table events, the listener must not block the caller.
+pub trait TableEventListener: Sync + Send {
+    fn on_event(&self, event: TableEvent);
+}
+
+pub struct TableEventListenerWrapper {
+    listener: Box<dyn TableEventListener>,
+}
+
+impl TableEventListenerWrapper {
+    pub fn new(listener: Box<dyn TableEventListener>) -> Self {
+        Self { listener }
+    }
+
+    pub fn init(listener: Box<dyn TableEventListener>) {
+        GlobalInstance::set(Arc::new(Self::new(listener)));
+    }
+}
+
+/// Publishes the event to the registered listener, it is a no-op if the global
+/// services have not been initiated.
+pub fn notify_table_event(event: TableEvent) {
+    if let Some(wrapper) = GlobalInstance::try_get::<Arc<TableEventListenerWrapper>>() {
+        wrapper.listener.on_event(event);
+    }
+}
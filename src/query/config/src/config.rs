@@ -1402,10 +1402,20 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "8")]
     pub max_running_queries: u64,
 
+    /// The running query slots reserved for roles, in the form of `<role>:<slots>`.
+    /// The queries whose current role has reserved slots only run in the slots of the role,
+    /// and the other queries never take them, the slots are taken from `max_running_queries`.
+    #[clap(long, value_name = "VALUE")]
+    pub reserved_query_slots: Vec<String>,
+
     /// The max total memory in bytes that can be used by this process.
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub max_server_memory_usage: u64,
 
+    /// The max total bytes of spilled data that can be written by this node, 0 means unlimited.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub max_server_spill_bytes: u64,
+
     #[clap(
         long,
         value_name = "VALUE",
@@ -1663,6 +1673,12 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE")]
     pub udf_server_allow_list: Vec<String>,
 
+    /// A list of sinks that table events are published to, besides `system.table_events`.
+    /// `http(s)://...` posts the events as json to a webhook,
+    /// `kafka+http(s)://host:port/<topic>` posts them to a topic of a Kafka REST proxy.
+    #[clap(long, value_name = "VALUE")]
+    pub table_event_sinks: Vec<String>,
+
     #[clap(long)]
     pub cloud_control_grpc_server_address: Option<String>,
 
@@ -1672,6 +1688,26 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "50")]
     pub max_cached_queries_profiles: usize,
 
+    /// Default execution timeout in seconds of SELECT statements, 0 means no limit.
+    /// Overridable by the session setting of the same name.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub select_statement_timeout_secs: u64,
+
+    /// Default execution timeout in seconds of DML statements, 0 means no limit.
+    /// Overridable by the session setting of the same name.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub dml_statement_timeout_secs: u64,
+
+    /// Default execution timeout in seconds of DDL statements, 0 means no limit.
+    /// Overridable by the session setting of the same name.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub ddl_statement_timeout_secs: u64,
+
+    /// Default time budget in seconds for retrying conflicting commits of DML statements,
+    /// 0 means the built-in budget. Overridable by the session setting of the same name.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub dml_statement_retry_budget_secs: u64,
+
     #[clap(skip)]
     pub settings: HashMap<String, SettingValue>,
 }
@@ -1700,7 +1736,9 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_tls_server_key: self.mysql_tls_server_key,
             max_active_sessions: self.max_active_sessions,
             max_running_queries: self.max_running_queries,
+            reserved_query_slots: self.reserved_query_slots,
             max_server_memory_usage: self.max_server_memory_usage,
+            max_server_spill_bytes: self.max_server_spill_bytes,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
             clickhouse_http_handler_host: self.clickhouse_http_handler_host,
             clickhouse_http_handler_port: self.clickhouse_http_handler_port,
@@ -1755,9 +1793,14 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             openai_api_version: self.openai_api_version,
             enable_udf_server: self.enable_udf_server,
             udf_server_allow_list: self.udf_server_allow_list,
+            table_event_sinks: self.table_event_sinks,
             cloud_control_grpc_server_address: self.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: self.cloud_control_grpc_timeout,
             max_cached_queries_profiles: self.max_cached_queries_profiles,
+            select_statement_timeout_secs: self.select_statement_timeout_secs,
+            dml_statement_timeout_secs: self.dml_statement_timeout_secs,
+            ddl_statement_timeout_secs: self.ddl_statement_timeout_secs,
+            dml_statement_retry_budget_secs: self.dml_statement_retry_budget_secs,
             settings: self
                 .settings
                 .into_iter()
@@ -1782,7 +1825,9 @@ impl From<InnerQueryConfig> for QueryConfig {
             mysql_tls_server_key: inner.mysql_tls_server_key,
             max_active_sessions: inner.max_active_sessions,
             max_running_queries: inner.max_running_queries,
+            reserved_query_slots: inner.reserved_query_slots,
             max_server_memory_usage: inner.max_server_memory_usage,
+            max_server_spill_bytes: inner.max_server_spill_bytes,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
 
             // clickhouse tcp is deprecated
@@ -1854,9 +1899,14 @@ impl From<InnerQueryConfig> for QueryConfig {
             openai_api_embedding_model: inner.openai_api_embedding_model,
             enable_udf_server: inner.enable_udf_server,
             udf_server_allow_list: inner.udf_server_allow_list,
+            table_event_sinks: inner.table_event_sinks,
             cloud_control_grpc_server_address: inner.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: inner.cloud_control_grpc_timeout,
             max_cached_queries_profiles: inner.max_cached_queries_profiles,
+            select_statement_timeout_secs: inner.select_statement_timeout_secs,
+            dml_statement_timeout_secs: inner.dml_statement_timeout_secs,
+            ddl_statement_timeout_secs: inner.ddl_statement_timeout_secs,
+            dml_statement_retry_budget_secs: inner.dml_statement_retry_budget_secs,
             settings: HashMap::new(),
         }
     }
@@ -2678,6 +2728,15 @@ pub struct CacheConfig {
     )]
     pub table_meta_segment_bytes: u64,
 
+    /// Max bytes of table snapshots and segments cached on local disk, under the disk cache path.
+    /// Default is 0 (disabled)
+    #[clap(
+        long = "cache-table-meta-disk-cache-bytes",
+        value_name = "VALUE",
+        default_value = "0"
+    )]
+    pub table_meta_disk_cache_bytes: u64,
+
     /// Max number of cached table block meta
     #[clap(
         long = "cache-block-meta-count",
@@ -2983,6 +3042,7 @@ mod cache_config_converters {
                 enable_table_meta_cache: value.enable_table_meta_cache,
                 table_meta_snapshot_count: value.table_meta_snapshot_count,
                 table_meta_segment_bytes: value.table_meta_segment_bytes,
+                table_meta_disk_cache_bytes: value.table_meta_disk_cache_bytes,
                 block_meta_count: value.block_meta_count,
                 table_meta_statistic_count: value.table_meta_statistic_count,
                 enable_table_index_bloom: value.enable_table_bloom_index_cache,
@@ -3010,6 +3070,7 @@ mod cache_config_converters {
                 enable_table_meta_cache: value.enable_table_meta_cache,
                 table_meta_snapshot_count: value.table_meta_snapshot_count,
                 table_meta_segment_bytes: value.table_meta_segment_bytes,
+                table_meta_disk_cache_bytes: value.table_meta_disk_cache_bytes,
                 table_meta_statistic_count: value.table_meta_statistic_count,
                 block_meta_count: value.block_meta_count,
                 enable_table_bloom_index_cache: value.enable_table_index_bloom,
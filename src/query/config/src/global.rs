@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_base::base::GlobalInstance;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
 use crate::InnerConfig;
@@ -34,4 +35,46 @@ impl GlobalConfig {
     pub fn try_get_instance() -> Option<Arc<InnerConfig>> {
         GlobalInstance::try_get()
     }
+
+    /// Reload the config from the config file, env and args.
+    ///
+    /// Only the `[query.settings]` overrides of the setting defaults are applied, they are read by
+    /// the sessions created afterwards. The other options were used to start the services, a
+    /// reload changing any of them is rejected since they still require a restart.
+    pub async fn reload() -> Result<()> {
+        let loaded = InnerConfig::load().await?;
+        let config = Self::reloaded(&Self::instance(), loaded)?;
+        Self::init(&config)
+    }
+
+    /// Returns `current` with the `[query.settings]` of `loaded`, fails if any other option of
+    /// `loaded` differs from `current`.
+    pub fn reloaded(current: &InnerConfig, mut loaded: InnerConfig) -> Result<InnerConfig> {
+        // generated for each load, not part of the config.
+        loaded.query.node_id = current.query.node_id.clone();
+        loaded.query.node_secret = current.query.node_secret.clone();
+
+        let mut config = current.clone();
+        config.query.settings = loaded.query.settings.clone();
+
+        let changed = [
+            ("query", config.query != loaded.query),
+            ("log", config.log != loaded.log),
+            ("meta", config.meta != loaded.meta),
+            ("storage", config.storage != loaded.storage),
+            ("catalogs", config.catalogs != loaded.catalogs),
+            ("cache", config.cache != loaded.cache),
+            ("background", config.background != loaded.background),
+        ]
+        .into_iter()
+        .filter_map(|(section, changed)| changed.then_some(section))
+        .collect::<Vec<_>>();
+        if !changed.is_empty() {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "only the [query.settings] can be reloaded, the options of [{}] are changed, restart the node to apply them",
+                changed.join(", ")
+            )));
+        }
+        Ok(config)
+    }
 }
@@ -166,7 +166,9 @@ pub struct QueryConfig {
     pub mysql_tls_server_key: String,
     pub max_active_sessions: u64,
     pub max_running_queries: u64,
+    pub reserved_query_slots: Vec<String>,
     pub max_server_memory_usage: u64,
+    pub max_server_spill_bytes: u64,
     pub max_memory_limit_enabled: bool,
     pub clickhouse_http_handler_host: String,
     pub clickhouse_http_handler_port: u16,
@@ -232,10 +234,18 @@ pub struct QueryConfig {
 
     pub enable_udf_server: bool,
     pub udf_server_allow_list: Vec<String>,
+    pub table_event_sinks: Vec<String>,
 
     pub cloud_control_grpc_server_address: Option<String>,
     pub cloud_control_grpc_timeout: u64,
     pub max_cached_queries_profiles: usize,
+
+    /// Default execution timeouts of SELECT, DML and DDL statements, 0 means no limit.
+    pub select_statement_timeout_secs: u64,
+    pub dml_statement_timeout_secs: u64,
+    pub ddl_statement_timeout_secs: u64,
+    /// Default time budget of commit retries of DML statements, 0 means the built-in one.
+    pub dml_statement_retry_budget_secs: u64,
     pub settings: HashMap<String, UserSettingValue>,
 }
 
@@ -254,7 +264,9 @@ impl Default for QueryConfig {
             mysql_tls_server_key: "".to_string(),
             max_active_sessions: 256,
             max_running_queries: 8,
+            reserved_query_slots: Vec::new(),
             max_server_memory_usage: 0,
+            max_server_spill_bytes: 0,
             max_memory_limit_enabled: false,
             clickhouse_http_handler_host: "127.0.0.1".to_string(),
             clickhouse_http_handler_port: 8124,
@@ -305,10 +317,15 @@ impl Default for QueryConfig {
             openai_api_embedding_model: "text-embedding-ada-002".to_string(),
             enable_udf_server: false,
             udf_server_allow_list: Vec::new(),
+            table_event_sinks: Vec::new(),
             cloud_control_grpc_server_address: None,
             cloud_control_grpc_timeout: 0,
             data_retention_time_in_days_max: 90,
             max_cached_queries_profiles: 50,
+            select_statement_timeout_secs: 0,
+            dml_statement_timeout_secs: 0,
+            ddl_statement_timeout_secs: 0,
+            dml_statement_retry_budget_secs: 0,
             settings: HashMap::new(),
         }
     }
@@ -526,6 +543,9 @@ pub struct CacheConfig {
     /// Max size(in bytes) of cached table segment
     pub table_meta_segment_bytes: u64,
 
+    /// Max size(in bytes) of table snapshots and segments cached on local disk. Set it to 0 to disable it.
+    pub table_meta_disk_cache_bytes: u64,
+
     /// Max number of cached table block meta
     pub block_meta_count: u64,
 
@@ -675,6 +695,7 @@ impl Default for CacheConfig {
             enable_table_meta_cache: true,
             table_meta_snapshot_count: 256,
             table_meta_segment_bytes: 1073741824,
+            table_meta_disk_cache_bytes: 0,
             block_meta_count: 0,
             table_meta_statistic_count: 256,
             enable_table_index_bloom: true,
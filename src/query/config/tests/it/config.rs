@@ -12,11 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::ffi::OsString;
 
 use clap::Parser;
 use databend_common_config::Config;
+use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_meta_app::principal::UserSettingValue;
 use pretty_assertions::assert_eq;
 
 /// It's required to make sure setting's default value is the same with clap.
@@ -32,3 +36,32 @@ fn test_config_default() {
         "default setting is different from default config, please check again"
     )
 }
+
+#[test]
+fn test_config_reload() {
+    let mut current = InnerConfig::default();
+    current.query.node_id = "node".to_string();
+    current.query.node_secret = "secret".to_string();
+
+    // the settings are applied, the node_id and node_secret generated by the load are kept.
+    let mut loaded = current.clone();
+    loaded.query.node_id = "reloaded_node".to_string();
+    loaded.query.node_secret = "reloaded_secret".to_string();
+    loaded.query.settings =
+        HashMap::from([("max_threads".to_string(), UserSettingValue::UInt64(8))]);
+    let reloaded = GlobalConfig::reloaded(&current, loaded).expect("settings can be reloaded");
+    assert_eq!(reloaded.query.node_id, "node");
+    assert_eq!(reloaded.query.node_secret, "secret");
+    assert_eq!(
+        reloaded.query.settings.get("max_threads"),
+        Some(&UserSettingValue::UInt64(8))
+    );
+
+    // the other options require a restart.
+    let mut loaded = current.clone();
+    loaded.query.max_active_sessions += 1;
+    loaded.cache.table_meta_snapshot_count += 1;
+    let err = GlobalConfig::reloaded(&current, loaded).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::INVALID_CONFIG);
+    assert!(err.message().contains("[query, cache]"));
+}
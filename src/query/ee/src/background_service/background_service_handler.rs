@@ -50,6 +50,9 @@ use crate::background_service::session::create_session;
 use crate::background_service::session::get_background_service_user;
 use crate::background_service::CompactionJob;
 use crate::background_service::JobScheduler;
+use crate::background_service::StageLifecycleJob;
+use crate::background_service::TableTieringJob;
+use crate::background_service::TableTtlJob;
 
 pub struct RealBackgroundService {
     conf: InnerConfig,
@@ -151,6 +154,14 @@ impl RealBackgroundService {
             .await?;
             scheduler.add_job(compactor_job).await?;
         }
+        let stage_lifecycle_job =
+            StageLifecycleJob::create(conf, meta_api.clone(), user.identity()).await?;
+        scheduler.add_job(stage_lifecycle_job).await?;
+        let table_tiering_job =
+            TableTieringJob::create(conf, meta_api.clone(), user.identity()).await?;
+        scheduler.add_job(table_tiering_job).await?;
+        let table_ttl_job = TableTtlJob::create(conf, meta_api.clone(), user.identity()).await?;
+        scheduler.add_job(table_ttl_job).await?;
 
         let rm = RealBackgroundService {
             conf: conf.clone(),
@@ -17,9 +17,18 @@ mod compaction_job;
 mod job;
 mod job_scheduler;
 mod session;
+mod stage_lifecycle_job;
+mod table_maintenance_job;
+mod table_tiering_job;
+mod table_ttl_job;
 
 pub use background_service_handler::RealBackgroundService;
 pub use compaction_job::should_continue_compaction;
 pub use compaction_job::CompactionJob;
 pub use job::Job;
 pub use job_scheduler::JobScheduler;
+pub use stage_lifecycle_job::StageLifecycleJob;
+pub use table_maintenance_job::TableMaintenance;
+pub use table_maintenance_job::TableMaintenanceJob;
+pub use table_tiering_job::TableTieringJob;
+pub use table_ttl_job::TableTtlJob;
@@ -0,0 +1,151 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_meta_api::BackgroundApi;
+use databend_common_meta_app::background::BackgroundJobIdent;
+use databend_common_meta_app::background::BackgroundJobInfo;
+use databend_common_meta_app::background::BackgroundJobParams;
+use databend_common_meta_app::background::BackgroundJobStatus;
+use databend_common_meta_app::background::CreateBackgroundJobReq;
+use databend_common_meta_app::background::GetBackgroundJobReq;
+use databend_common_meta_app::background::UpdateBackgroundJobParamsReq;
+use databend_common_meta_app::background::UpdateBackgroundJobStatusReq;
+use databend_common_meta_app::principal::StageType;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_meta_store::MetaStore;
+use databend_common_storages_stage::purge_stage_by_lifecycle;
+use databend_common_users::UserApiProvider;
+use log::error;
+use log::info;
+
+use crate::background_service::job::Job;
+
+const STAGE_LIFECYCLE_INTERVAL_SECS: u64 = 60 * 60;
+
+/// Purge the files of internal stages by their `PURGE_AFTER_DAYS` and `MAX_TOTAL_SIZE` options.
+#[derive(Clone)]
+pub struct StageLifecycleJob {
+    conf: InnerConfig,
+    meta_api: Arc<MetaStore>,
+    creator: BackgroundJobIdent,
+}
+
+#[async_trait::async_trait]
+impl Job for StageLifecycleJob {
+    async fn run(&mut self) {
+        info!(background = true, job_name :? =(&self.creator.clone()); "Stage lifecycle job started");
+        if let Err(e) = self.do_stage_lifecycle_job().await {
+            error!("stage lifecycle job failed, err: {}", e);
+        }
+    }
+
+    async fn get_info(&self) -> Result<BackgroundJobInfo> {
+        let job = self
+            .meta_api
+            .get_background_job(GetBackgroundJobReq {
+                name: self.creator.clone(),
+            })
+            .await?;
+        Ok(job.info)
+    }
+
+    fn get_name(&self) -> BackgroundJobIdent {
+        self.creator.clone()
+    }
+
+    async fn update_job_status(&mut self, status: BackgroundJobStatus) -> Result<()> {
+        self.meta_api
+            .update_background_job_status(UpdateBackgroundJobStatusReq {
+                job_name: self.creator.clone(),
+                status,
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn update_job_params(&mut self, param: BackgroundJobParams) -> Result<()> {
+        self.meta_api
+            .update_background_job_params(UpdateBackgroundJobParamsReq {
+                job_name: self.creator.clone(),
+                params: param,
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+impl StageLifecycleJob {
+    pub fn get_job_name(tenant: &str) -> String {
+        format!("{}-stage-lifecycle-job", tenant)
+    }
+
+    pub async fn create(
+        config: &InnerConfig,
+        meta_api: Arc<MetaStore>,
+        creator: UserIdentity,
+    ) -> Result<Self> {
+        let tenant = config.query.tenant_id.clone();
+        let name = Self::get_job_name(tenant.tenant_name());
+        let ident = BackgroundJobIdent::new(tenant, name);
+
+        let params = BackgroundJobParams::new_interval_job(Duration::from_secs(
+            STAGE_LIFECYCLE_INTERVAL_SECS,
+        ));
+        meta_api
+            .create_background_job(CreateBackgroundJobReq {
+                if_not_exists: true,
+                job_name: ident.clone(),
+                job_info: BackgroundJobInfo::new_stage_lifecycle_job(params, creator),
+            })
+            .await?;
+
+        Ok(Self {
+            conf: config.clone(),
+            meta_api,
+            creator: ident,
+        })
+    }
+
+    async fn do_stage_lifecycle_job(&mut self) -> Result<()> {
+        let tenant = &self.conf.query.tenant_id;
+        let stages = UserApiProvider::instance().get_stages(tenant).await?;
+        let now = Utc::now();
+        for stage in stages {
+            if stage.stage_type != StageType::Internal || !stage.lifecycle.is_enabled() {
+                continue;
+            }
+            match purge_stage_by_lifecycle(&stage, now).await {
+                Ok(removed) => {
+                    info!(
+                        "stage lifecycle job success, stage: {}, removed files: {}",
+                        stage.stage_name, removed
+                    );
+                }
+                Err(e) => {
+                    error!(
+                        "stage lifecycle job failed, stage: {}, err: {}",
+                        stage.stage_name, e
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
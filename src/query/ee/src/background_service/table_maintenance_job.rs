@@ -0,0 +1,175 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_meta_api::BackgroundApi;
+use databend_common_meta_app::background::BackgroundJobIdent;
+use databend_common_meta_app::background::BackgroundJobInfo;
+use databend_common_meta_app::background::BackgroundJobParams;
+use databend_common_meta_app::background::BackgroundJobStatus;
+use databend_common_meta_app::background::CreateBackgroundJobReq;
+use databend_common_meta_app::background::GetBackgroundJobReq;
+use databend_common_meta_app::background::UpdateBackgroundJobParamsReq;
+use databend_common_meta_app::background::UpdateBackgroundJobStatusReq;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_meta_store::MetaStore;
+use databend_common_storages_fuse::FuseTable;
+use log::error;
+use log::info;
+
+use crate::background_service::job::Job;
+use crate::background_service::session::create_session;
+
+/// A maintenance periodically applied to every Fuse table carrying a given table option.
+#[async_trait::async_trait]
+pub trait TableMaintenance: Clone + Send + Sync + 'static {
+    /// Name of the maintenance, used in the job name and in the logs, e.g. `table ttl`.
+    const NAME: &'static str;
+    /// Only the tables carrying this option are maintained.
+    const OPTION_KEY: &'static str;
+    const INTERVAL_SECS: u64;
+
+    fn job_info(params: BackgroundJobParams, creator: UserIdentity) -> BackgroundJobInfo;
+
+    /// Maintains one table, returns the number of blocks affected.
+    async fn maintain(table: &FuseTable, ctx: Arc<dyn TableContext>) -> Result<u64>;
+}
+
+#[derive(Clone)]
+pub struct TableMaintenanceJob<M: TableMaintenance> {
+    conf: InnerConfig,
+    meta_api: Arc<MetaStore>,
+    creator: BackgroundJobIdent,
+    _maintenance: PhantomData<M>,
+}
+
+#[async_trait::async_trait]
+impl<M: TableMaintenance> Job for TableMaintenanceJob<M> {
+    async fn run(&mut self) {
+        info!(background = true, job_name :? =(&self.creator.clone()); "{} job started", M::NAME);
+        if let Err(e) = self.do_maintenance_job().await {
+            error!("{} job failed, err: {}", M::NAME, e);
+        }
+    }
+
+    async fn get_info(&self) -> Result<BackgroundJobInfo> {
+        let job = self
+            .meta_api
+            .get_background_job(GetBackgroundJobReq {
+                name: self.creator.clone(),
+            })
+            .await?;
+        Ok(job.info)
+    }
+
+    fn get_name(&self) -> BackgroundJobIdent {
+        self.creator.clone()
+    }
+
+    async fn update_job_status(&mut self, status: BackgroundJobStatus) -> Result<()> {
+        self.meta_api
+            .update_background_job_status(UpdateBackgroundJobStatusReq {
+                job_name: self.creator.clone(),
+                status,
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn update_job_params(&mut self, param: BackgroundJobParams) -> Result<()> {
+        self.meta_api
+            .update_background_job_params(UpdateBackgroundJobParamsReq {
+                job_name: self.creator.clone(),
+                params: param,
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+impl<M: TableMaintenance> TableMaintenanceJob<M> {
+    pub fn get_job_name(tenant: &str) -> String {
+        format!("{}-{}-job", tenant, M::NAME.replace(' ', "-"))
+    }
+
+    pub async fn create(
+        config: &InnerConfig,
+        meta_api: Arc<MetaStore>,
+        creator: UserIdentity,
+    ) -> Result<Self> {
+        let tenant = config.query.tenant_id.clone();
+        let name = Self::get_job_name(tenant.tenant_name());
+        let ident = BackgroundJobIdent::new(tenant, name);
+
+        let params = BackgroundJobParams::new_interval_job(Duration::from_secs(M::INTERVAL_SECS));
+        meta_api
+            .create_background_job(CreateBackgroundJobReq {
+                if_not_exists: true,
+                job_name: ident.clone(),
+                job_info: M::job_info(params, creator),
+            })
+            .await?;
+
+        Ok(Self {
+            conf: config.clone(),
+            meta_api,
+            creator: ident,
+            _maintenance: PhantomData,
+        })
+    }
+
+    async fn do_maintenance_job(&mut self) -> Result<()> {
+        let session = create_session(&self.conf).await?;
+        let ctx = session.create_query_context().await?;
+        let catalog = ctx.get_default_catalog()?;
+        let tenant = ctx.get_tenant();
+        for database in catalog.list_databases(&tenant).await? {
+            for table in database.list_tables().await? {
+                if !table.options().contains_key(M::OPTION_KEY) {
+                    continue;
+                }
+                let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+                    continue;
+                };
+                match M::maintain(fuse_table, ctx.clone()).await {
+                    Ok(affected) => {
+                        info!(
+                            "{} job success, table: {}.{}, affected blocks: {}",
+                            M::NAME,
+                            database.name(),
+                            table.name(),
+                            affected
+                        );
+                    }
+                    Err(e) => {
+                        error!(
+                            "{} job failed, table: {}.{}, err: {}",
+                            M::NAME,
+                            database.name(),
+                            table.name(),
+                            e
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
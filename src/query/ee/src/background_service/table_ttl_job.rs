@@ -0,0 +1,47 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_meta_app::background::BackgroundJobInfo;
+use databend_common_meta_app::background::BackgroundJobParams;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
+
+use crate::background_service::table_maintenance_job::TableMaintenance;
+use crate::background_service::table_maintenance_job::TableMaintenanceJob;
+
+/// Remove the expired blocks of the tables with a `ttl` option.
+pub type TableTtlJob = TableMaintenanceJob<TableTtl>;
+
+#[derive(Clone)]
+pub struct TableTtl;
+
+#[async_trait::async_trait]
+impl TableMaintenance for TableTtl {
+    const NAME: &'static str = "table ttl";
+    const OPTION_KEY: &'static str = OPT_KEY_TTL;
+    const INTERVAL_SECS: u64 = 60 * 60;
+
+    fn job_info(params: BackgroundJobParams, creator: UserIdentity) -> BackgroundJobInfo {
+        BackgroundJobInfo::new_table_ttl_job(params, creator)
+    }
+
+    async fn maintain(table: &FuseTable, ctx: Arc<dyn TableContext>) -> Result<u64> {
+        table.do_expire_ttl(ctx).await
+    }
+}
@@ -29,6 +29,8 @@ use databend_common_storages_fuse::io::SnapshotLiteExtended;
 use databend_common_storages_fuse::io::SnapshotsIO;
 use databend_common_storages_fuse::io::TableMetaLocationGenerator;
 use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::FUSE_TBL_BLOCK_PREFIX;
+use databend_common_storages_fuse::FUSE_TBL_COLD_BLOCK_PREFIX;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::CompactSegmentInfo;
 
@@ -164,6 +166,33 @@ async fn get_orphan_files_to_be_purged(
     Ok(files_to_be_purged)
 }
 
+// return orphan block files to be purged, both in the hot and in the cold tier
+#[async_backtrace::framed]
+async fn get_orphan_block_files_to_be_purged(
+    fuse_table: &FuseTable,
+    referenced_files: HashSet<String>,
+    retention_time: DateTime<Utc>,
+) -> Result<Vec<String>> {
+    if referenced_files.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let table_prefix = fuse_table.meta_location_generator().prefix();
+    let mut files_to_be_purged = vec![];
+    for block_prefix in [FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_COLD_BLOCK_PREFIX] {
+        let prefix = format!("{}/{}/", table_prefix, block_prefix);
+        files_to_be_purged.extend(
+            fuse_table
+                .list_files(prefix, |location, modified| {
+                    modified <= retention_time && !referenced_files.contains(&location)
+                })
+                .await?,
+        );
+    }
+
+    Ok(files_to_be_purged)
+}
+
 #[async_backtrace::framed]
 pub async fn do_gc_orphan_files(
     fuse_table: &FuseTable,
@@ -184,6 +213,8 @@ pub async fn do_gc_orphan_files(
         start.elapsed()
     );
     ctx.set_status_info(&status);
+    // the files still referenced by clones are kept.
+    let shared_files = fuse_table.shared_file_locations(ctx.clone()).await?;
 
     // 2. Purge orphan segment files.
     // 2.1 Get orphan segment files to be purged
@@ -204,6 +235,7 @@ pub async fn do_gc_orphan_files(
         .try_purge_location_files_and_cache::<CompactSegmentInfo, _, _>(
             ctx.clone(),
             HashSet::from_iter(segment_locations_to_be_purged.into_iter()),
+            &shared_files,
         )
         .await?;
     let status = format!(
@@ -216,7 +248,8 @@ pub async fn do_gc_orphan_files(
     // 3. Purge orphan block files.
     // 3.1 Get orphan block files to be purged
     let block_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks, retention_time).await?;
+        get_orphan_block_files_to_be_purged(fuse_table, referenced_files.blocks, retention_time)
+            .await?;
     let status = format!(
         "gc orphan: read block_locations_to_be_purged:{}, cost:{:?}",
         block_locations_to_be_purged.len(),
@@ -230,6 +263,7 @@ pub async fn do_gc_orphan_files(
         .try_purge_location_files(
             ctx.clone(),
             HashSet::from_iter(block_locations_to_be_purged.into_iter()),
+            &shared_files,
         )
         .await?;
     let status = format!(
@@ -257,6 +291,7 @@ pub async fn do_gc_orphan_files(
         .try_purge_location_files(
             ctx.clone(),
             HashSet::from_iter(index_locations_to_be_purged.into_iter()),
+            &shared_files,
         )
         .await?;
     let status = format!(
@@ -310,7 +345,8 @@ pub async fn do_dry_run_orphan_files(
 
     // 3. Get purge orphan block files.
     let block_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks, retention_time).await?;
+        get_orphan_block_files_to_be_purged(fuse_table, referenced_files.blocks, retention_time)
+            .await?;
     let status = format!(
         "dry_run orphan: read block_locations_to_be_purged:{}, cost:{:?}",
         block_locations_to_be_purged.len(),
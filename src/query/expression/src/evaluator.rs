@@ -1793,6 +1793,17 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
                     );
                 }
 
+                // Keep functions like `now()` and `rand()` as is in deterministic mode, so that
+                // the plan does not depend on when it was folded.
+                if self.func_ctx.deterministic
+                    && self
+                        .fn_registry
+                        .get_property(&function.signature.name)
+                        .is_some_and(|p| p.non_deterministic)
+                {
+                    return (func_expr, func_domain);
+                }
+
                 if all_args_is_scalar {
                     let block = DataBlock::empty();
                     let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
@@ -29,6 +29,8 @@ use databend_common_exception::Result;
 use databend_common_io::GeometryDataType;
 use enum_as_inner::EnumAsInner;
 use itertools::Itertools;
+use parking_lot::Mutex;
+use rand::rngs::SmallRng;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -116,6 +118,11 @@ pub struct FunctionContext {
     pub parse_datetime_ignore_remainder: bool,
     pub enable_dst_hour_fix: bool,
     pub enable_strict_datetime_parser: bool,
+    /// Random functions use a fixed seed, and non-deterministic functions are not folded.
+    pub deterministic: bool,
+    /// The random state of the query in deterministic mode. It is seeded once per query,
+    /// and advanced by every block of random values generated.
+    pub deterministic_rng: Option<Arc<Mutex<SmallRng>>>,
 }
 
 impl Default for FunctionContext {
@@ -138,6 +145,8 @@ impl Default for FunctionContext {
             parse_datetime_ignore_remainder: false,
             enable_dst_hour_fix: false,
             enable_strict_datetime_parser: true,
+            deterministic: false,
+            deterministic_rng: None,
         }
     }
 }
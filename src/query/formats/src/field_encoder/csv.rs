@@ -33,14 +33,22 @@ use crate::FileFormatOptionsExt;
 use crate::OutputCommonSettings;
 
 pub enum StringFormatter {
-    Csv { quote_char: u8 },
-    Tsv { record_delimiter: u8 },
+    Csv {
+        quote_char: u8,
+        escape_char: Option<u8>,
+    },
+    Tsv {
+        record_delimiter: u8,
+    },
 }
 
 impl StringFormatter {
     fn write_string(&self, bytes: &[u8], buf: &mut Vec<u8>) {
         match self {
-            StringFormatter::Csv { quote_char } => write_csv_string(bytes, buf, *quote_char),
+            StringFormatter::Csv {
+                quote_char,
+                escape_char,
+            } => write_csv_string(bytes, buf, *quote_char, *escape_char),
             StringFormatter::Tsv { record_delimiter } => {
                 write_tsv_escaped_string(bytes, buf, *record_delimiter)
             }
@@ -49,17 +57,19 @@ impl StringFormatter {
 }
 
 // todo(youngsofun): support quote style
-pub fn write_csv_string(bytes: &[u8], buf: &mut Vec<u8>, quote: u8) {
+/// Write a quoted csv string, the quotes inside are doubled, or prefixed by `escape` if any
+/// (the escape char itself is escaped too in that case).
+pub fn write_csv_string(bytes: &[u8], buf: &mut Vec<u8>, quote: u8, escape: Option<u8>) {
     buf.push(quote);
     let mut start = 0;
 
     for (i, &byte) in bytes.iter().enumerate() {
-        if byte == quote {
+        if byte == quote || Some(byte) == escape {
             if start < i {
                 buf.extend_from_slice(&bytes[start..i]);
             }
-            buf.push(quote);
-            buf.push(quote);
+            buf.push(escape.unwrap_or(quote));
+            buf.push(byte);
             start = i + 1;
         }
     }
@@ -84,7 +94,7 @@ impl FieldEncoderCSV {
                 common_settings: OutputCommonSettings {
                     true_bytes: TRUE_BYTES_LOWER.as_bytes().to_vec(),
                     false_bytes: FALSE_BYTES_LOWER.as_bytes().to_vec(),
-                    null_bytes: NULL_BYTES_ESCAPE.as_bytes().to_vec(),
+                    null_bytes: params.null_display.as_bytes().to_vec(),
                     nan_bytes: params.nan_display.as_bytes().to_vec(),
                     inf_bytes: INF_BYTES_LONG.as_bytes().to_vec(),
                     timezone: options_ext.timezone,
@@ -95,6 +105,7 @@ impl FieldEncoderCSV {
             },
             string_formatter: StringFormatter::Csv {
                 quote_char: params.quote.as_bytes()[0],
+                escape_char: params.escape.as_bytes().first().copied(),
             },
         }
     }
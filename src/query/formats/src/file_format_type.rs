@@ -115,7 +115,18 @@ impl FileFormatOptionsExt {
         schema: TableSchemaRef,
         settings: &Settings,
     ) -> Result<Box<dyn OutputFormat>> {
-        let params = FileFormatParams::default_by_type(typ.typ.clone())?;
+        let mut params = FileFormatParams::default_by_type(typ.typ.clone())?;
+        if let FileFormatParams::Csv(csv_params) = &mut params {
+            csv_params.field_delimiter = settings.get_format_csv_field_delimiter()?;
+            csv_params.record_delimiter = settings.get_format_csv_record_delimiter()?;
+            csv_params.quote = settings.get_format_csv_quote()?;
+            csv_params.escape = settings.get_format_csv_escape()?;
+            csv_params.null_display = settings.get_format_csv_null_display()?;
+            csv_params.output_bom = settings.get_format_csv_output_bom()?;
+            params.check().map_err(|msg| {
+                ErrorCode::BadArguments(format!("Invalid format_csv_* settings: {msg}"))
+            })?;
+        }
         let mut options = FileFormatOptionsExt::create_from_clickhouse_format(typ, settings)?;
         options.get_output_format(schema, params)
     }
@@ -23,6 +23,8 @@ use crate::field_encoder::FieldEncoderCSV;
 use crate::output_format::OutputFormat;
 use crate::FileFormatOptionsExt;
 
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
 pub type CSVOutputFormat = CSVOutputFormatBase<false, false>;
 pub type CSVWithNamesOutputFormat = CSVOutputFormatBase<true, false>;
 pub type CSVWithNamesAndTypesOutputFormat = CSVOutputFormatBase<true, true>;
@@ -33,6 +35,8 @@ pub struct CSVOutputFormatBase<const WITH_NAMES: bool, const WITH_TYPES: bool> {
     field_delimiter: u8,
     record_delimiter: Vec<u8>,
     quote: u8,
+    escape: Option<u8>,
+    output_bom: bool,
 }
 
 impl<const WITH_NAMES: bool, const WITH_TYPES: bool> CSVOutputFormatBase<WITH_NAMES, WITH_TYPES> {
@@ -48,6 +52,8 @@ impl<const WITH_NAMES: bool, const WITH_TYPES: bool> CSVOutputFormatBase<WITH_NA
             field_delimiter: params.field_delimiter.as_bytes()[0],
             record_delimiter: params.record_delimiter.as_bytes().to_vec(),
             quote: params.quote.as_bytes()[0],
+            escape: params.escape.as_bytes().first().copied(),
+            output_bom: params.output_bom,
         }
     }
 
@@ -59,7 +65,7 @@ impl<const WITH_NAMES: bool, const WITH_TYPES: bool> CSVOutputFormatBase<WITH_NA
             if col_index != 0 {
                 buf.push(fd);
             }
-            write_csv_string(v.as_bytes(), &mut buf, self.quote);
+            write_csv_string(v.as_bytes(), &mut buf, self.quote, self.escape);
         }
 
         buf.extend_from_slice(&self.record_delimiter);
@@ -98,6 +104,9 @@ impl<const WITH_NAMES: bool, const WITH_TYPES: bool> OutputFormat
 
     fn serialize_prefix(&self) -> Result<Vec<u8>> {
         let mut buf = vec![];
+        if self.output_bom {
+            buf.extend_from_slice(UTF8_BOM);
+        }
         if WITH_NAMES {
             let names = self
                 .schema
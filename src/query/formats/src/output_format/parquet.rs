@@ -53,7 +53,7 @@ impl OutputFormat for ParquetOutputFormat {
             return Ok(vec![]);
         }
         let mut buf = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-        let _ = blocks_to_parquet(&self.schema, blocks, &mut buf, TableCompression::Zstd)?;
+        let _ = blocks_to_parquet(&self.schema, blocks, &mut buf, TableCompression::Zstd(None))?;
         Ok(buf)
     }
 }
@@ -107,7 +107,14 @@ fn test_csv_string() {
     {
         let s = "a\"\nb";
         let mut buf = vec![];
-        write_csv_string(s.as_bytes(), &mut buf, b'"');
+        write_csv_string(s.as_bytes(), &mut buf, b'"', None);
         assert_eq!(&buf, b"\"a\"\"\nb\"")
     }
+
+    {
+        let s = "a\"\\b";
+        let mut buf = vec![];
+        write_csv_string(s.as_bytes(), &mut buf, b'"', Some(b'\\'));
+        assert_eq!(&buf, b"\"a\\\"\\\\b\"")
+    }
 }
@@ -17,9 +17,11 @@ use std::collections::BTreeMap;
 use databend_common_exception::Result;
 use databend_common_expression::types::number::Int32Type;
 use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
 use databend_common_expression::FromData;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
+use databend_common_formats::ClickhouseFormatType;
 use databend_common_formats::FileFormatOptionsExt;
 use databend_common_meta_app::principal::FileFormatOptionsReader;
 use databend_common_meta_app::principal::FileFormatParams;
@@ -147,3 +149,77 @@ fn test_field_delimiter_with_ascii_control_code() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_csv_output_options() -> Result<()> {
+    let (schema, block) = gen_schema_and_block(
+        vec![
+            TableField::new(
+                "c1",
+                TableDataType::Number(NumberDataType::Int32).wrap_nullable(),
+            ),
+            TableField::new("c2", TableDataType::String.wrap_nullable()),
+        ],
+        vec![
+            Int32Type::from_opt_data(vec![Some(1i32), None]),
+            StringType::from_opt_data(vec![Some("a\"b\\"), None]),
+        ],
+    );
+
+    // COPY INTO location
+    {
+        let settings = Settings::create(Tenant::new_literal("default"));
+        let mut options = BTreeMap::<String, String>::new();
+        options.insert("type".to_string(), "csv".to_string());
+        options.insert("escape".to_string(), "\\".to_string());
+        options.insert("null_display".to_string(), "NULL".to_string());
+        options.insert("output_header".to_string(), "true".to_string());
+        options.insert("output_bom".to_string(), "true".to_string());
+        let params =
+            FileFormatParams::try_from_reader(FileFormatOptionsReader::from_map(options), false)?;
+        let mut options = FileFormatOptionsExt::create_from_settings(&settings, false)?;
+        let mut output_format = options.get_output_format(schema.clone(), params)?;
+        let mut buffer = output_format.serialize_prefix()?;
+        buffer.extend(output_format.serialize_block(&block)?);
+
+        let csv_block = String::from_utf8(buffer)?;
+        let expect = "\u{feff}\"c1\",\"c2\"\n1,\"a\\\"b\\\\\"\nNULL,NULL\n";
+        assert_eq!(&csv_block, expect);
+    }
+
+    // FORMAT clause of the clickhouse handler
+    {
+        let settings = Settings::create(Tenant::new_literal("default"));
+        settings.set_setting("format_csv_field_delimiter".to_string(), "|".to_string())?;
+        settings.set_setting(
+            "format_csv_record_delimiter".to_string(),
+            "\r\n".to_string(),
+        )?;
+        settings.set_setting("format_csv_quote".to_string(), "'".to_string())?;
+        settings.set_setting("format_csv_null_display".to_string(), "".to_string())?;
+        settings.set_setting("format_csv_output_bom".to_string(), "1".to_string())?;
+        let format = ClickhouseFormatType::parse_clickhouse_format("CSVWithNames")?;
+        let mut output_format = FileFormatOptionsExt::get_output_format_from_clickhouse_format(
+            format,
+            schema.clone(),
+            &settings,
+        )?;
+        let mut buffer = output_format.serialize_prefix()?;
+        buffer.extend(output_format.serialize_block(&block)?);
+
+        let csv_block = String::from_utf8(buffer)?;
+        let expect = "\u{feff}'c1'|'c2'\r\n1|'a\"b\\'\r\n|\r\n";
+        assert_eq!(&csv_block, expect);
+
+        settings.set_setting("format_csv_field_delimiter".to_string(), "a".to_string())?;
+        let format = ClickhouseFormatType::parse_clickhouse_format("CSV")?;
+        assert!(
+            FileFormatOptionsExt::get_output_format_from_clickhouse_format(
+                format, schema, &settings
+            )
+            .is_err()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,277 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Registration of scalar functions provided by embedders and plugins.
+//!
+//! Functions registered here are merged into [`BUILTIN_FUNCTIONS`] when the registry
+//! is built for the first time, so registration must happen at startup before any
+//! query is planned or executed.
+//!
+//! [`BUILTIN_FUNCTIONS`]: crate::BUILTIN_FUNCTIONS
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Domain;
+use databend_common_expression::EvalContext;
+use databend_common_expression::Function;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionEval;
+use databend_common_expression::FunctionProperty;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use parking_lot::Mutex;
+
+use crate::aggregates::AggregateFunctionFactory;
+use crate::ASYNC_FUNCTIONS;
+use crate::GENERAL_LAMBDA_FUNCTIONS;
+use crate::GENERAL_SEARCH_FUNCTIONS;
+use crate::GENERAL_WINDOW_FUNCTIONS;
+
+type ScalarEval = dyn Fn(&[ValueRef<AnyType>], &mut EvalContext) -> Value<AnyType> + Send + Sync;
+type ScalarCalcDomain =
+    dyn Fn(&FunctionContext, &[Domain]) -> FunctionDomain<AnyType> + Send + Sync;
+
+static PENDING_FUNCTIONS: Mutex<PendingFunctions> = Mutex::new(PendingFunctions {
+    sealed: false,
+    builders: Vec::new(),
+});
+
+/// Names of the native scalar functions, built separately from [`BUILTIN_FUNCTIONS`] so that
+/// clashes can be detected without sealing the registry.
+///
+/// [`BUILTIN_FUNCTIONS`]: crate::BUILTIN_FUNCTIONS
+static NATIVE_FUNCTION_NAMES: LazyLock<HashSet<String>> = LazyLock::new(|| {
+    crate::native_functions()
+        .registered_names()
+        .into_iter()
+        .collect()
+});
+
+fn is_native_function(name: &str) -> bool {
+    NATIVE_FUNCTION_NAMES.contains(name)
+        || AggregateFunctionFactory::instance().contains(name)
+        || GENERAL_WINDOW_FUNCTIONS.contains(&name)
+        || GENERAL_LAMBDA_FUNCTIONS.contains(&name)
+        || GENERAL_SEARCH_FUNCTIONS.contains(&name)
+        || ASYNC_FUNCTIONS.contains(&name)
+}
+
+struct PendingFunctions {
+    /// Set once the builtin registry has been built, later registrations would be lost.
+    sealed: bool,
+    builders: Vec<ScalarFunctionBuilder>,
+}
+
+/// Builder of a vectorized scalar function registered from outside of this crate.
+///
+/// ```ignore
+/// ScalarFunctionBuilder::new("my_plus_one")
+///     .signature(vec![Int64Type::data_type()], Int64Type::data_type())
+///     .eval(|args, ctx| { ... })
+///     .passthrough_nullable();
+/// ```
+#[derive(Clone)]
+pub struct ScalarFunctionBuilder {
+    name: String,
+    signatures: Vec<(Vec<DataType>, DataType)>,
+    eval: Option<Arc<ScalarEval>>,
+    calc_domain: Option<Arc<ScalarCalcDomain>>,
+    passthrough_nullable: bool,
+    property: FunctionProperty,
+}
+
+impl ScalarFunctionBuilder {
+    pub fn new(name: &str) -> Self {
+        ScalarFunctionBuilder {
+            name: name.to_lowercase(),
+            signatures: vec![],
+            eval: None,
+            calc_domain: None,
+            passthrough_nullable: false,
+            property: FunctionProperty::default(),
+        }
+    }
+
+    /// Add an overload of the function. The same eval closure is used for every overload,
+    /// it can dispatch on the argument types if needed.
+    pub fn signature(mut self, args_type: Vec<DataType>, return_type: DataType) -> Self {
+        self.signatures.push((args_type, return_type));
+        self
+    }
+
+    /// Set the vectorized evaluation of the function.
+    pub fn eval(
+        mut self,
+        eval: impl Fn(&[ValueRef<AnyType>], &mut EvalContext) -> Value<AnyType> + Send + Sync + 'static,
+    ) -> Self {
+        self.eval = Some(Arc::new(eval));
+        self
+    }
+
+    /// Set the domain calculation of the function, which lets the planner fold constants and
+    /// prune data by the output range, e.g. for monotonic functions. Without it the function
+    /// is treated as `FunctionDomain::MayThrow`.
+    pub fn calc_domain(
+        mut self,
+        calc_domain: impl Fn(&FunctionContext, &[Domain]) -> FunctionDomain<AnyType>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.calc_domain = Some(Arc::new(calc_domain));
+        self
+    }
+
+    /// Also register the nullable variant of every signature, which returns NULL
+    /// if any argument is NULL.
+    pub fn passthrough_nullable(mut self) -> Self {
+        self.passthrough_nullable = true;
+        self
+    }
+
+    /// Mark the function as non-deterministic, so it's never constant folded.
+    pub fn non_deterministic(mut self) -> Self {
+        self.property = self.property.non_deterministic();
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.name.is_empty() {
+            return Err(ErrorCode::BadArguments("Function name must not be empty"));
+        }
+        if self.signatures.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Function '{}' must have at least one signature",
+                self.name
+            )));
+        }
+        if self.eval.is_none() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Function '{}' must have an eval",
+                self.name
+            )));
+        }
+        if self.passthrough_nullable
+            && self
+                .signatures
+                .iter()
+                .any(|(args_type, _)| args_type.iter().any(|ty| ty.is_nullable_or_null()))
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "Function '{}' with passthrough nullable must not have nullable arguments",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+
+    fn build_function(&self, args_type: &[DataType], return_type: &DataType) -> Function {
+        let eval = self.eval.clone().unwrap();
+        let calc_domain = self.calc_domain.clone();
+        Function {
+            signature: FunctionSignature {
+                name: self.name.clone(),
+                args_type: args_type.to_vec(),
+                return_type: return_type.clone(),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(move |ctx, domains| match &calc_domain {
+                    Some(calc_domain) => calc_domain(ctx, domains),
+                    None => FunctionDomain::MayThrow,
+                }),
+                eval: Box::new(move |args, ctx| eval(args, ctx)),
+            },
+        }
+    }
+
+    /// Register the function into the given registry.
+    pub fn register(&self, registry: &mut FunctionRegistry) -> Result<()> {
+        self.validate()?;
+        if registry.contains(&self.name) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Function '{}' is already registered",
+                self.name
+            )));
+        }
+
+        for (args_type, return_type) in &self.signatures {
+            registry.register_function(self.build_function(args_type, return_type));
+            if self.passthrough_nullable {
+                let func = self.build_function(args_type, return_type);
+                registry.register_function(func.passthrough_nullable());
+            }
+        }
+
+        registry
+            .properties
+            .insert(self.name.clone(), self.property.clone());
+        Ok(())
+    }
+}
+
+/// Register scalar functions to be merged into the builtin function registry.
+///
+/// Must be called before the first use of `BUILTIN_FUNCTIONS`, usually at the startup
+/// of the embedding process.
+pub fn register_scalar_functions(builders: Vec<ScalarFunctionBuilder>) -> Result<()> {
+    for builder in &builders {
+        builder.validate()?;
+        if is_native_function(&builder.name) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Function '{}' conflicts with a builtin function",
+                builder.name
+            )));
+        }
+    }
+
+    let mut pending = PENDING_FUNCTIONS.lock();
+    if pending.sealed {
+        return Err(ErrorCode::Internal(
+            "Scalar functions must be registered before the function registry is initialized",
+        ));
+    }
+    for (i, builder) in builders.iter().enumerate() {
+        if pending
+            .builders
+            .iter()
+            .chain(&builders[..i])
+            .any(|b| b.name == builder.name)
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "Function '{}' is already registered",
+                builder.name
+            )));
+        }
+    }
+    pending.builders.extend(builders);
+    Ok(())
+}
+
+pub(crate) fn register(registry: &mut FunctionRegistry) {
+    let mut pending = PENDING_FUNCTIONS.lock();
+    pending.sealed = true;
+    for builder in pending.builders.drain(..) {
+        // Validity and name clashes were checked by `register_scalar_functions`.
+        let _ = builder.register(registry);
+    }
+}
@@ -23,15 +23,20 @@
 #![feature(downcast_unchecked)]
 #![feature(str_internals)]
 
+use std::sync::LazyLock;
+
 use aggregates::AggregateFunctionFactory;
-use ctor::ctor;
 use databend_common_expression::FunctionRegistry;
 
 pub mod aggregates;
 mod cast_rules;
+mod extension;
 pub mod scalars;
 pub mod srfs;
 
+pub use extension::register_scalar_functions;
+pub use extension::ScalarFunctionBuilder;
+
 pub fn is_builtin_function(name: &str) -> bool {
     BUILTIN_FUNCTIONS.contains(name)
         || AggregateFunctionFactory::instance().contains(name)
@@ -41,8 +46,7 @@ pub fn is_builtin_function(name: &str) -> bool {
         || ASYNC_FUNCTIONS.contains(&name)
 }
 
-#[ctor]
-pub static BUILTIN_FUNCTIONS: FunctionRegistry = builtin_functions();
+pub static BUILTIN_FUNCTIONS: LazyLock<FunctionRegistry> = LazyLock::new(builtin_functions);
 
 pub const ASYNC_FUNCTIONS: [&str; 1] = ["nextval"];
 
@@ -73,6 +77,13 @@ pub const GENERAL_LAMBDA_FUNCTIONS: [&str; 5] = [
 pub const GENERAL_SEARCH_FUNCTIONS: [&str; 3] = ["match", "query", "score"];
 
 fn builtin_functions() -> FunctionRegistry {
+    let mut registry = native_functions();
+    extension::register(&mut registry);
+    registry
+}
+
+/// The functions shipped with databend, without the ones registered by embedders.
+fn native_functions() -> FunctionRegistry {
     let mut registry = FunctionRegistry::empty();
 
     cast_rules::register(&mut registry);
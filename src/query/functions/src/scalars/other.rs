@@ -19,7 +19,7 @@ use std::time::Duration;
 
 use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::convert_number_size;
-use databend_common_base::base::uuid::Uuid;
+use databend_common_base::base::uuid::Builder;
 use databend_common_expression::error_to_null;
 use databend_common_expression::types::boolean::BooleanDomain;
 use databend_common_expression::types::nullable::NullableColumn;
@@ -48,6 +48,7 @@ use databend_common_expression::Column;
 use databend_common_expression::Domain;
 use databend_common_expression::EvalContext;
 use databend_common_expression::Function;
+use databend_common_expression::FunctionContext;
 use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionEval;
 use databend_common_expression::FunctionProperty;
@@ -135,6 +136,22 @@ pub fn register(registry: &mut FunctionRegistry) {
         },
     );
 
+    // Used by the flattened uncorrelated scalar subquery to check at runtime
+    // that the subquery produces at most one row.
+    registry.register_passthrough_nullable_1_arg::<NumberType<u64>, NumberType<u64>, _, _>(
+        "assert_single_row",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<NumberType<u64>, NumberType<u64>>(|count, output, ctx| {
+            if count > 1 {
+                ctx.set_error(
+                    output.len(),
+                    "Scalar subquery can't return more than one row",
+                );
+            }
+            output.push(count);
+        }),
+    );
+
     registry.register_0_arg_core::<NumberType<F64>, _, _>(
         "rand",
         |_| {
@@ -144,7 +161,7 @@ pub fn register(registry: &mut FunctionRegistry) {
             })
         },
         |ctx| {
-            let mut rng = rand::rngs::SmallRng::from_entropy();
+            let mut rng = new_rng(ctx.func_ctx);
             let rand_nums = (0..ctx.num_rows)
                 .map(|_| rng.gen::<F64>())
                 .collect::<Vec<_>>();
@@ -228,8 +245,9 @@ pub fn register(registry: &mut FunctionRegistry) {
             let mut offsets: Vec<u64> = Vec::with_capacity(ctx.num_rows);
             offsets.push(0);
 
+            let mut rng = new_rng(ctx.func_ctx);
             for _ in 0..ctx.num_rows {
-                let value = Uuid::new_v4();
+                let value = Builder::from_random_bytes(rng.gen()).into_uuid();
                 offsets.push(offsets.last().unwrap() + 36u64);
                 write!(&mut values, "{:x}", value).unwrap();
             }
@@ -239,6 +257,15 @@ pub fn register(registry: &mut FunctionRegistry) {
     );
 }
 
+fn new_rng(func_ctx: &FunctionContext) -> rand::rngs::SmallRng {
+    match &func_ctx.deterministic_rng {
+        // The generator of the block is seeded from the random state of the query,
+        // so that the values keep advancing from one block to the next.
+        Some(rng) => rand::rngs::SmallRng::seed_from_u64(rng.lock().gen()),
+        None => rand::rngs::SmallRng::from_entropy(),
+    }
+}
+
 fn register_inet_aton(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<StringType, UInt32Type, _, _>(
         "inet_aton",
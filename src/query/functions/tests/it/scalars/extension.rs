@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_expression::type_check;
+use databend_common_expression::types::number::Int64Type;
+use databend_common_expression::types::ArgType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::vectorize_1_arg;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_functions::register_scalar_functions;
+use databend_common_functions::ScalarFunctionBuilder;
+
+use super::parser;
+
+fn plus_one() -> ScalarFunctionBuilder {
+    scalar_plus_one("Plus_One")
+}
+
+fn scalar_plus_one(name: &str) -> ScalarFunctionBuilder {
+    ScalarFunctionBuilder::new(name)
+        .signature(vec![Int64Type::data_type()], Int64Type::data_type())
+        .eval(|args, ctx| {
+            let arg = args[0].try_downcast::<Int64Type>().unwrap();
+            vectorize_1_arg::<Int64Type, Int64Type>(|a, _| a + 1)(arg, ctx).upcast()
+        })
+}
+
+#[test]
+fn test_scalar_function_builder() {
+    let mut registry = FunctionRegistry::empty();
+    plus_one()
+        .passthrough_nullable()
+        .register(&mut registry)
+        .unwrap();
+    assert!(registry.contains("plus_one"));
+    assert!(plus_one().register(&mut registry).is_err());
+
+    let columns = [
+        ("a", Int64Type::from_data(vec![1i64, 2, 3])),
+        (
+            "b",
+            Int64Type::from_data_with_validity(vec![1i64, 2, 3], vec![true, false, true]),
+        ),
+    ];
+    let block = DataBlock::new(
+        columns
+            .iter()
+            .map(|(_, col)| BlockEntry::new(col.data_type(), Value::Column(col.clone())))
+            .collect(),
+        3,
+    );
+    let columns_type = columns
+        .iter()
+        .map(|(name, col)| (*name, col.data_type()))
+        .collect::<Vec<_>>();
+    let func_ctx = FunctionContext::default();
+    let evaluator = Evaluator::new(&block, &func_ctx, &registry);
+
+    for (text, expected) in [
+        ("plus_one(a)", Int64Type::from_data(vec![2i64, 3, 4])),
+        (
+            "plus_one(b)",
+            Int64Type::from_data_with_validity(vec![2i64, 3, 4], vec![true, false, true]),
+        ),
+    ] {
+        let raw_expr = parser::parse_raw_expr(text, &columns_type);
+        let expr = type_check::check(&raw_expr, &registry).unwrap();
+        let result = evaluator.run(&expr).unwrap();
+        assert_eq!(result.into_column().unwrap(), expected, "{text}");
+    }
+}
+
+#[test]
+fn test_scalar_function_builder_invalid() {
+    let mut registry = FunctionRegistry::empty();
+
+    // no signature
+    let builder = ScalarFunctionBuilder::new("f").eval(|args, _| args[0].clone().to_owned());
+    assert!(builder.register(&mut registry).is_err());
+
+    // no eval
+    let builder = ScalarFunctionBuilder::new("f")
+        .signature(vec![Int64Type::data_type()], Int64Type::data_type());
+    assert!(builder.register(&mut registry).is_err());
+
+    // nullable argument with passthrough nullable
+    let builder = plus_one()
+        .signature(
+            vec![DataType::Nullable(Box::new(Int64Type::data_type()))],
+            Int64Type::data_type(),
+        )
+        .passthrough_nullable();
+    assert!(builder.register(&mut registry).is_err());
+
+    assert!(!registry.contains("f"));
+    assert!(!registry.contains("plus_one"));
+}
+
+#[test]
+fn test_register_scalar_functions_conflict_with_builtin() {
+    for name in ["plus", "Length", "sum", "row_number"] {
+        let err = register_scalar_functions(vec![scalar_plus_one(name)]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::BAD_ARGUMENTS, "{name}");
+    }
+}
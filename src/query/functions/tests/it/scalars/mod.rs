@@ -38,6 +38,7 @@ mod cast;
 mod comparison;
 mod control;
 mod datetime;
+mod extension;
 mod geo;
 // NOTE:(everpcpc) result different on macos
 // TODO: fix this in running on linux
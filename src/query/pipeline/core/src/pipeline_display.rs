@@ -14,24 +14,63 @@
 
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::ops::Range;
 
 use crate::pipe::Pipe;
 use crate::Pipeline;
 
 impl Pipeline {
     pub fn display_indent(&self) -> impl std::fmt::Display + '_ {
-        PipelineIndentDisplayWrapper { pipeline: self }
+        PipelineIndentDisplayWrapper {
+            pipeline: self,
+            verbose: false,
+        }
+    }
+
+    /// Like `display_indent`, but also shows the ports of each pipe and the edges between them.
+    /// The input port `i` of a pipe is connected to the output port `i` of the pipe below, the
+    /// edges list which input ports each processor of the pipe reads and which output ports it writes.
+    pub fn display_indent_verbose(&self) -> impl std::fmt::Display + '_ {
+        PipelineIndentDisplayWrapper {
+            pipeline: self,
+            verbose: true,
+        }
     }
 }
 
 struct PipelineIndentDisplayWrapper<'a> {
     pipeline: &'a Pipeline,
+    verbose: bool,
 }
 
 impl<'a> PipelineIndentDisplayWrapper<'a> {
     fn pipe_name(pipe: &Pipe) -> String {
         unsafe { pipe.items[0].processor.name() }
     }
+
+    fn fmt_ports(f: &mut Formatter, ports: Range<usize>) -> std::fmt::Result {
+        match ports.len() {
+            0 => write!(f, "none"),
+            1 => write!(f, "{}", ports.start),
+            _ => write!(f, "{}-{}", ports.start, ports.end - 1),
+        }
+    }
+
+    fn fmt_edges(f: &mut Formatter, pipe: &Pipe) -> std::fmt::Result {
+        let (mut input, mut output) = (0, 0);
+        for (index, item) in pipe.items.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            let inputs = input..input + item.inputs_port.len();
+            let outputs = output..output + item.outputs_port.len();
+            (input, output) = (inputs.end, outputs.end);
+            Self::fmt_ports(f, inputs)?;
+            write!(f, " -> ")?;
+            Self::fmt_ports(f, outputs)?;
+        }
+        Ok(())
+    }
 }
 
 impl<'a> Display for PipelineIndentDisplayWrapper<'a> {
@@ -55,6 +94,16 @@ impl<'a> Display for PipelineIndentDisplayWrapper<'a> {
             } else {
                 write!(f, "Merge to {pipe_name} × {}", pipe.output_length,)?;
             }
+
+            if self.verbose {
+                write!(
+                    f,
+                    " [inputs: {}, outputs: {}, edges: ",
+                    pipe.input_length, pipe.output_length
+                )?;
+                Self::fmt_edges(f, pipe)?;
+                write!(f, "]")?;
+            }
         }
 
         Ok(())
@@ -87,6 +87,9 @@ pub struct PlanProfile {
     #[serde(skip_serializing_if = "std::collections::BTreeMap::is_empty")]
     #[serde(default)]
     pub metrics: BTreeMap<String, Vec<MetricSample>>,
+    #[serde(skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    #[serde(default)]
+    pub decoded_column_bytes: BTreeMap<String, usize>,
 
     pub errors: Vec<Arc<ErrorInfo>>,
 }
@@ -122,6 +125,7 @@ impl PlanProfile {
             labels: profile.labels.clone(),
             metrics: BTreeMap::new(),
             statistics: std::array::from_fn(|_| 0),
+            decoded_column_bytes: BTreeMap::new(),
             errors: Self::get_profile_error(profile),
         }
     }
@@ -131,6 +135,7 @@ impl PlanProfile {
             self.statistics[index] += profile.statistics[index].load(Ordering::SeqCst);
         }
 
+        self.add_decoded_column_bytes(profile.decoded_column_bytes.lock().iter());
         self.errors.extend(Self::get_profile_error(profile));
     }
 
@@ -143,6 +148,8 @@ impl PlanProfile {
             self.statistics[index] += profile.statistics[index];
         }
 
+        self.add_decoded_column_bytes(profile.decoded_column_bytes.iter());
+
         for errors in &profile.errors {
             self.errors.push(errors.clone());
         }
@@ -159,6 +166,15 @@ impl PlanProfile {
         }
     }
 
+    pub fn add_decoded_column_bytes<'a>(
+        &mut self,
+        decoded_column_bytes: impl Iterator<Item = (&'a String, &'a usize)>,
+    ) {
+        for (column, bytes) in decoded_column_bytes {
+            *self.decoded_column_bytes.entry(column.clone()).or_default() += bytes;
+        }
+    }
+
     pub fn add_metrics(&mut self, node_id: String, metrics: Vec<MetricSample>) {
         if metrics.is_empty() {
             return;
@@ -785,11 +785,16 @@ impl Compiler {
             // TODO(andylokandy: handle these statement)
             fn enter_statement(&mut self, stmt: &mut Statement) {
                 match stmt {
-                    Statement::Begin => {
+                    Statement::Begin { .. } => {
                         self.error = Some(ErrorCode::Unimplemented(
                             "BEGIN in script is not supported yet".to_string(),
                         ));
                     }
+                    Statement::SetTransactionSnapshot { .. } => {
+                        self.error = Some(ErrorCode::Unimplemented(
+                            "SET TRANSACTION SNAPSHOT in script is not supported yet".to_string(),
+                        ));
+                    }
                     Statement::Commit => {
                         self.error = Some(ErrorCode::Unimplemented(
                             "COMMIT in script is not supported yet".to_string(),
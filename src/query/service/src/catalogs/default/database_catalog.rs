@@ -324,6 +324,11 @@ impl Catalog for DatabaseCatalog {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn get_db_name_by_table_id(&self, table_id: MetaId) -> Result<Option<String>> {
+        self.mutable_catalog.get_db_name_by_table_id(table_id).await
+    }
+
     #[async_backtrace::framed]
     async fn get_db_name_by_id(&self, db_id: MetaId) -> Result<String> {
         let res = self.immutable_catalog.get_db_name_by_id(db_id).await;
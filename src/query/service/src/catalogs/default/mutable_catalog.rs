@@ -18,10 +18,13 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 use std::time::Instant;
 
+use databend_common_cache::Cache;
+use databend_common_cache::LruCache;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_config::InnerConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_api::kv_pb_api::KVPbApi;
 use databend_common_meta_api::SchemaApi;
 use databend_common_meta_api::SequenceApi;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
@@ -89,6 +92,7 @@ use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::TableIdToName;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -114,6 +118,8 @@ use databend_common_meta_types::MetaId;
 use databend_common_meta_types::SeqV;
 use fastrace::func_name;
 use log::info;
+use log::warn;
+use parking_lot::Mutex;
 
 use crate::catalogs::default::catalog_context::CatalogContext;
 use crate::databases::Database;
@@ -123,6 +129,11 @@ use crate::storages::StorageDescription;
 use crate::storages::StorageFactory;
 use crate::storages::Table;
 
+/// Number of recently resolved tables that can still be read while meta is unreachable.
+const DEGRADED_TABLE_CACHE_CAPACITY: u64 = 1024;
+
+type DegradedTableCache = LruCache<(String, String, String), TableInfo>;
+
 /// Catalog based on MetaStore
 /// - System Database NOT included
 /// - Meta data of databases are saved in meta store
@@ -133,6 +144,9 @@ pub struct MutableCatalog {
     ctx: CatalogContext,
     tenant: Tenant,
     disable_table_info_refresh: bool,
+    /// Table infos of recently resolved tables, used to plan reads from when the
+    /// meta service is unavailable.
+    degraded_tables: Arc<Mutex<DegradedTableCache>>,
 }
 
 impl Debug for MutableCatalog {
@@ -191,6 +205,7 @@ impl MutableCatalog {
             ctx,
             tenant,
             disable_table_info_refresh: false,
+            degraded_tables: Arc::new(Mutex::new(LruCache::new(DEGRADED_TABLE_CACHE_CAPACITY))),
         })
     }
 
@@ -209,6 +224,37 @@ impl MutableCatalog {
     pub(crate) fn disable_table_info_refresh(&mut self) {
         self.disable_table_info_refresh = true;
     }
+
+    /// Falls back to the last known table info if the meta service is unavailable.
+    ///
+    /// Tables served this way are read-only in effect: any mutation has to commit
+    /// its new table meta through the meta service and fails there.
+    fn get_table_degraded(
+        &self,
+        key: (String, String, String),
+        res: Result<Arc<dyn Table>>,
+    ) -> Result<Arc<dyn Table>> {
+        match res {
+            Ok(table) => {
+                let table_info = table.get_table_info().clone();
+                self.degraded_tables.lock().put(key, table_info);
+                Ok(table)
+            }
+            Err(cause) if cause.code() == ErrorCode::META_SERVICE_ERROR => {
+                let table_info = self.degraded_tables.lock().get(&key).cloned();
+                let Some(table_info) = table_info else {
+                    return Err(cause);
+                };
+
+                warn!(
+                    "Meta service is unavailable, serving table {}.{} read-only from cached table info, cause: {:?}",
+                    key.1, key.2, cause
+                );
+                self.get_table_by_info(&table_info)
+            }
+            Err(cause) => Err(cause),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -390,6 +436,15 @@ impl Catalog for MutableCatalog {
         Ok(res)
     }
 
+    #[async_backtrace::framed]
+    async fn get_db_name_by_table_id(&self, table_id: MetaId) -> Result<Option<String>> {
+        let Some(seq_name) = self.ctx.meta.get_pb(&TableIdToName { table_id }).await? else {
+            return Ok(None);
+        };
+        let res = self.ctx.meta.get_db_name_by_id(seq_name.data.db_id).await?;
+        Ok(Some(res))
+    }
+
     #[async_backtrace::framed]
     async fn get_db_name_by_id(&self, db_id: MetaId) -> Result<String> {
         let res = self.ctx.meta.get_db_name_by_id(db_id).await?;
@@ -418,8 +473,17 @@ impl Catalog for MutableCatalog {
         db_name: &str,
         table_name: &str,
     ) -> Result<Arc<dyn Table>> {
-        let db = self.get_database(tenant, db_name).await?;
-        db.get_table(table_name).await
+        let res = match self.get_database(tenant, db_name).await {
+            Ok(db) => db.get_table(table_name).await,
+            Err(cause) => Err(cause),
+        };
+
+        let key = (
+            tenant.tenant_name().to_string(),
+            db_name.to_string(),
+            table_name.to_string(),
+        );
+        self.get_table_degraded(key, res)
     }
 
     #[async_backtrace::framed]
@@ -657,3 +721,55 @@ impl Catalog for MutableCatalog {
         Ok(self.ctx.meta.drop_sequence(req).await?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use databend_common_config::GlobalConfig;
+
+    use super::*;
+    use crate::test_kits::TestFixture;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_get_table_degraded() -> Result<()> {
+        let fixture = TestFixture::setup().await?;
+        fixture.create_default_database().await?;
+        fixture.create_default_table().await?;
+        let table = fixture.latest_default_table().await?;
+
+        let conf = GlobalConfig::instance().as_ref().clone();
+        let catalog = MutableCatalog::try_create_with_config(conf).await?;
+        let key = |table_name: &str| {
+            (
+                fixture.default_tenant().tenant_name().to_string(),
+                fixture.default_db_name(),
+                table_name.to_string(),
+            )
+        };
+        let unavailable = || -> Result<Arc<dyn Table>> {
+            Err(ErrorCode::MetaServiceError("meta is unavailable"))
+        };
+
+        // never served, the meta service error is returned
+        let res = catalog.get_table_degraded(key(&fixture.default_table_name()), unavailable());
+        assert_eq!(res.unwrap_err().code(), ErrorCode::META_SERVICE_ERROR);
+
+        // served once, the last known table info is used while meta is unavailable
+        let served = catalog.get_table_degraded(key(&fixture.default_table_name()), Ok(table))?;
+        let degraded =
+            catalog.get_table_degraded(key(&fixture.default_table_name()), unavailable())?;
+        assert_eq!(degraded.get_id(), served.get_id());
+        assert_eq!(degraded.schema(), served.schema());
+
+        // the cached table info is only used for tables with the same name
+        let res = catalog.get_table_degraded(key("other"), unavailable());
+        assert_eq!(res.unwrap_err().code(), ErrorCode::META_SERVICE_ERROR);
+
+        // other errors are not masked by the cached table info
+        let res = catalog.get_table_degraded(
+            key(&fixture.default_table_name()),
+            Err(ErrorCode::UnknownTable("dropped")),
+        );
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_TABLE);
+        Ok(())
+    }
+}
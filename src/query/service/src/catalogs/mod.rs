@@ -14,6 +14,7 @@
 
 pub mod default;
 pub mod share;
+pub mod stage;
 pub use databend_common_catalog::catalog::Catalog;
 pub use databend_common_storages_hive as hive;
 pub use default::table_id_ranges::*;
@@ -21,3 +22,5 @@ pub use default::table_memory_meta::InMemoryMetas;
 pub use default::DatabaseCatalog;
 pub use share::ShareCatalog;
 pub use share::ShareCatalogCreator;
+pub use stage::StageCatalog;
+pub use stage::StageCatalogCreator;
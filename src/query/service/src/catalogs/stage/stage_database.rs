@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::database::Database;
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
+use databend_common_meta_app::schema::DatabaseIdent;
+use databend_common_meta_app::schema::DatabaseInfo;
+use databend_common_meta_app::schema::DatabaseMeta;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_storage::init_stage_operator;
+
+use super::stage_directory_table::StageDirectoryTable;
+
+/// A stage seen as a database of the stage catalog.
+#[derive(Clone, Debug)]
+pub struct StageDatabase {
+    stage_info: StageInfo,
+    info: DatabaseInfo,
+}
+
+impl StageDatabase {
+    pub fn create(tenant: Tenant, stage_info: StageInfo) -> Self {
+        let info = DatabaseInfo {
+            ident: DatabaseIdent::default(),
+            name_ident: DatabaseNameIdent::new(tenant, &stage_info.stage_name),
+            meta: DatabaseMeta {
+                engine: "STAGE".to_string(),
+                created_on: stage_info.created_on,
+                updated_on: stage_info.created_on,
+                comment: stage_info.comment.clone(),
+                ..Default::default()
+            },
+        };
+
+        Self { stage_info, info }
+    }
+}
+
+#[async_trait::async_trait]
+impl Database for StageDatabase {
+    fn name(&self) -> &str {
+        self.info.name_ident.database_name()
+    }
+
+    fn get_db_info(&self) -> &DatabaseInfo {
+        &self.info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table(&self, table_name: &str) -> Result<Arc<dyn Table>> {
+        StageDirectoryTable::try_create(self.stage_info.clone(), table_name).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tables(&self) -> Result<Vec<Arc<dyn Table>>> {
+        let operator = init_stage_operator(&self.stage_info)?;
+        let entries = operator.list("/").await?;
+
+        let mut tables = vec![];
+        for entry in entries {
+            if !entry.metadata().is_dir() {
+                continue;
+            }
+            let table_name = entry.path().trim_matches('/');
+            if table_name.is_empty() {
+                continue;
+            }
+            match self.get_table(table_name).await {
+                Ok(table) => tables.push(table),
+                // Directories without any file of a known format are not tables.
+                Err(err) if err.code() == ErrorCode::UNKNOWN_TABLE => continue,
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(tables)
+    }
+}
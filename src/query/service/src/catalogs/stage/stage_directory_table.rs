@@ -0,0 +1,337 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourceInfo;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::ParquetReadOptions;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::query_kind::QueryKind;
+use databend_common_catalog::table::ColumnStatisticsProvider;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TableStatistics;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::StageFileInfo;
+use databend_common_storage::StageFilesInfo;
+use databend_common_storages_orc::OrcTable;
+use databend_common_storages_parquet::ParquetRSTable;
+use databend_common_storages_stage::infer_ndjson_schema;
+use databend_common_storages_stage::StageTable;
+use databend_storages_common_table_meta::table::ChangeType;
+use opendal::Operator;
+
+// Number of files listed under a directory to find one of a known format.
+const MAX_PROBED_FILES: usize = 100;
+// Bytes read from the first file to infer the columns of NDJSON, CSV and TSV directories.
+const SAMPLE_BYTES: u64 = 1024 * 1024;
+// Number of NDJSON records used to infer the schema.
+const SAMPLE_RECORDS: usize = 1000;
+
+/// A directory directly under a stage, seen as a table of the stage catalog.
+///
+/// The format is detected from the extension of the first recognized file, and only the
+/// files with that extension are read. Parquet and ORC directories use the schema of their
+/// first file, NDJSON columns are inferred from sampled records, and CSV/TSV directories
+/// expose one nullable string column per field of the first record. The file format options
+/// of the stage apply if they are of the detected format, the columns of CSV/TSV directories
+/// are named after the header if the stage skips one, and `c<N>` otherwise.
+pub struct StageDirectoryTable {
+    info: TableInfo,
+    inner: Arc<dyn Table>,
+}
+
+impl StageDirectoryTable {
+    #[async_backtrace::framed]
+    pub async fn try_create(stage_info: StageInfo, name: &str) -> Result<Arc<dyn Table>> {
+        let operator = init_stage_operator(&stage_info)?;
+        let path = format!("{name}/");
+
+        let files = StageFilesInfo {
+            path: path.clone(),
+            files: None,
+            pattern: None,
+        }
+        .list(&operator, 1, Some(MAX_PROBED_FILES))
+        .await?;
+        let Some((first_file, extension, format_type)) = files.into_iter().find_map(|file| {
+            let (extension, format_type) = detect_format(&file.path)?;
+            Some((file, extension, format_type))
+        }) else {
+            return Err(ErrorCode::UnknownTable(format!(
+                "cannot find table {} from stage {}: no file of a supported format under '{}'",
+                name, stage_info.stage_name, path
+            )));
+        };
+
+        let database = stage_info.stage_name.clone();
+        let mut stage_info = stage_info;
+        if stage_info.file_format_params.get_type() != format_type {
+            stage_info.file_format_params = FileFormatParams::default_by_type(format_type)?;
+        }
+        let files_info = StageFilesInfo {
+            path,
+            files: None,
+            pattern: Some(format!(".*[.]{extension}")),
+        };
+
+        let inner = match format_type {
+            StageFileFormatType::Parquet => {
+                ParquetRSTable::create(
+                    stage_info,
+                    files_info,
+                    ParquetReadOptions::default(),
+                    None,
+                    QueryKind::Query,
+                )
+                .await?
+            }
+            StageFileFormatType::Orc => {
+                let schema = TableSchema::empty();
+                OrcTable::try_create(stage_table_info(stage_info, files_info, schema, true)).await?
+            }
+            StageFileFormatType::NdJson => {
+                let data = read_sample(&operator, &first_file).await?;
+                let schema = infer_ndjson_schema(&data, SAMPLE_RECORDS)?;
+                StageTable::try_create(stage_table_info(stage_info, files_info, schema, false))?
+            }
+            StageFileFormatType::Csv | StageFileFormatType::Tsv => {
+                let data = read_sample(&operator, &first_file).await?;
+                let (mut reader, headers) = match &stage_info.file_format_params {
+                    FileFormatParams::Csv(params) => (
+                        csv_reader(&params.field_delimiter, &params.quote, &params.escape),
+                        params.headers,
+                    ),
+                    FileFormatParams::Tsv(params) => (
+                        csv_reader(&params.field_delimiter, &params.quote, &params.escape),
+                        params.headers,
+                    ),
+                    _ => unreachable!("the format params are of the detected format"),
+                };
+                let first_record = read_record(&mut reader, &data);
+                let fields = first_record
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| {
+                        let name = if headers > 0 {
+                            field.clone()
+                        } else {
+                            format!("c{}", i + 1)
+                        };
+                        TableField::new(
+                            &name,
+                            TableDataType::Nullable(Box::new(TableDataType::String)),
+                        )
+                    })
+                    .collect();
+                let schema = TableSchema::new(fields);
+                StageTable::try_create(stage_table_info(stage_info, files_info, schema, false))?
+            }
+            _ => unreachable!("detect_format only returns supported formats"),
+        };
+
+        let info = TableInfo {
+            ident: TableIdent::new(0, 0),
+            desc: format!("'{}'.'{}'", database, name),
+            name: name.to_string(),
+            meta: TableMeta {
+                schema: inner.schema(),
+                engine: "STAGE".to_string(),
+                created_on: first_file.last_modified,
+                updated_on: first_file.last_modified,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(StageDirectoryTable { info, inner }))
+    }
+}
+
+fn stage_table_info(
+    stage_info: StageInfo,
+    files_info: StageFilesInfo,
+    schema: TableSchema,
+    is_select: bool,
+) -> StageTableInfo {
+    StageTableInfo {
+        schema: Arc::new(schema),
+        stage_info,
+        files_info,
+        files_to_copy: None,
+        duplicated_files_detected: vec![],
+        is_select,
+        default_values: None,
+    }
+}
+
+/// Detect the format of a file by its extension, returning the extension as is.
+fn detect_format(path: &str) -> Option<(String, StageFileFormatType)> {
+    let file_name = path.rsplit('/').next()?;
+    let (_, extension) = file_name.rsplit_once('.')?;
+    let format_type = match extension.to_lowercase().as_str() {
+        "parquet" => StageFileFormatType::Parquet,
+        "orc" => StageFileFormatType::Orc,
+        "csv" => StageFileFormatType::Csv,
+        "tsv" => StageFileFormatType::Tsv,
+        "ndjson" | "jsonl" | "json" => StageFileFormatType::NdJson,
+        _ => return None,
+    };
+    Some((extension.to_string(), format_type))
+}
+
+fn csv_reader(delimiter: &str, quote: &str, escape: &str) -> csv_core::Reader {
+    let mut builder = csv_core::ReaderBuilder::new();
+    builder.delimiter(delimiter.as_bytes().first().copied().unwrap_or(b','));
+    match quote.as_bytes().first() {
+        Some(quote) => builder.quote(*quote),
+        None => builder.quoting(false),
+    };
+    if let Some(escape) = escape.as_bytes().first() {
+        builder.escape(Some(*escape));
+    }
+    builder.build()
+}
+
+/// Reads the fields of the first record, quoted fields may contain delimiters and newlines.
+fn read_record(reader: &mut csv_core::Reader, mut data: &[u8]) -> Vec<String> {
+    let mut fields = vec![];
+    let mut field = vec![0; data.len()];
+    let mut len = 0;
+    loop {
+        let (result, n_in, n_out) = reader.read_field(data, &mut field[len..]);
+        data = &data[n_in..];
+        len += n_out;
+        match result {
+            csv_core::ReadFieldResult::InputEmpty => {}
+            csv_core::ReadFieldResult::OutputFull => field.resize(field.len() * 2 + 1, 0),
+            csv_core::ReadFieldResult::Field { record_end } => {
+                fields.push(String::from_utf8_lossy(&field[..len]).to_string());
+                len = 0;
+                if record_end {
+                    break;
+                }
+            }
+            csv_core::ReadFieldResult::End => break,
+        }
+    }
+    fields
+}
+
+async fn read_sample(operator: &Operator, file: &StageFileInfo) -> Result<Vec<u8>> {
+    let end = file.size.min(SAMPLE_BYTES);
+    let mut data = operator.read_with(&file.path).range(0..end).await?.to_vec();
+    // drop the last line if it was cut by the sample size
+    if end < file.size {
+        if let Some(pos) = data.iter().rposition(|b| *b == b'\n') {
+            data.truncate(pos);
+        }
+    }
+    Ok(data)
+}
+
+#[async_trait::async_trait]
+impl Table for StageDirectoryTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+
+    fn get_data_source_info(&self) -> DataSourceInfo {
+        self.inner.get_data_source_info()
+    }
+
+    fn is_local(&self) -> bool {
+        self.inner.is_local()
+    }
+
+    fn support_column_projection(&self) -> bool {
+        self.inner.support_column_projection()
+    }
+
+    fn support_prewhere(&self) -> bool {
+        self.inner.support_prewhere()
+    }
+
+    fn has_exact_total_row_count(&self) -> bool {
+        self.inner.has_exact_total_row_count()
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.inner.read_partitions(ctx, push_downs, dry_run).await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        put_cache: bool,
+    ) -> Result<()> {
+        self.inner.read_data(ctx, plan, pipeline, put_cache)
+    }
+
+    async fn table_statistics(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        require_fresh: bool,
+        change_type: Option<ChangeType>,
+    ) -> Result<Option<TableStatistics>> {
+        self.inner
+            .table_statistics(ctx, require_fresh, change_type)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn column_statistics_provider(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<Box<dyn ColumnStatisticsProvider>> {
+        self.inner.column_statistics_provider(ctx).await
+    }
+
+    fn is_stage_table(&self) -> bool {
+        true
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+}
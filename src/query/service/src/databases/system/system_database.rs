@@ -29,6 +29,7 @@ use databend_common_storages_system::CachesTable;
 use databend_common_storages_system::CatalogsTable;
 use databend_common_storages_system::ClusteringHistoryTable;
 use databend_common_storages_system::ClustersTable;
+use databend_common_storages_system::ColumnStatisticsTable;
 use databend_common_storages_system::ColumnsTable;
 use databend_common_storages_system::ConfigsTable;
 use databend_common_storages_system::ContributorsTable;
@@ -49,10 +50,12 @@ use databend_common_storages_system::PasswordPoliciesTable;
 use databend_common_storages_system::ProcessesTable;
 use databend_common_storages_system::QueriesProfilingTable;
 use databend_common_storages_system::QueryCacheTable;
+use databend_common_storages_system::QueryFragmentsTable;
 use databend_common_storages_system::QueryLogTable;
 use databend_common_storages_system::RolesTable;
 use databend_common_storages_system::SettingsTable;
 use databend_common_storages_system::StagesTable;
+use databend_common_storages_system::TableEventTable;
 use databend_common_storages_system::TableFunctionsTable;
 use databend_common_storages_system::TablesTableWithHistory;
 use databend_common_storages_system::TablesTableWithoutHistory;
@@ -106,6 +109,7 @@ impl SystemDatabase {
             MallocStatsTable::create(sys_db_meta.next_table_id()),
             MallocStatsTotalsTable::create(sys_db_meta.next_table_id()),
             ColumnsTable::create(sys_db_meta.next_table_id()),
+            ColumnStatisticsTable::create(sys_db_meta.next_table_id()),
             UsersTable::create(sys_db_meta.next_table_id()),
             Arc::new(QueryLogTable::create(
                 sys_db_meta.next_table_id(),
@@ -115,6 +119,10 @@ impl SystemDatabase {
                 sys_db_meta.next_table_id(),
                 config.query.max_query_log_size,
             )),
+            Arc::new(TableEventTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
@@ -131,6 +139,7 @@ impl SystemDatabase {
             TasksTable::create(sys_db_meta.next_table_id()),
             TaskHistoryTable::create(sys_db_meta.next_table_id()),
             QueriesProfilingTable::create(sys_db_meta.next_table_id()),
+            QueryFragmentsTable::create(sys_db_meta.next_table_id()),
             LocksTable::create(sys_db_meta.next_table_id()),
             VirtualColumnsTable::create(sys_db_meta.next_table_id()),
             PasswordPoliciesTable::create(sys_db_meta.next_table_id()),
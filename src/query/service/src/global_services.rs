@@ -31,6 +31,7 @@ use databend_common_storage::ShareTableConfig;
 use databend_common_storages_hive::HiveCreator;
 use databend_common_storages_iceberg::IcebergCreator;
 use databend_common_storages_system::ProfilesLogQueue;
+use databend_common_storages_system::QueryFragmentsLogQueue;
 use databend_common_tracing::GlobalLogger;
 use databend_common_users::builtin::BuiltIn;
 use databend_common_users::RoleCacheManager;
@@ -42,14 +43,18 @@ use crate::builtin::BuiltinUDFs;
 use crate::builtin::BuiltinUsers;
 use crate::catalogs::DatabaseCatalog;
 use crate::catalogs::ShareCatalogCreator;
+use crate::catalogs::StageCatalogCreator;
 use crate::clusters::ClusterDiscovery;
+use crate::interpreters::TableEventNotifier;
 use crate::locks::LockManager;
 #[cfg(feature = "enable_queries_executor")]
 use crate::pipelines::executor::GlobalQueriesExecutor;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::servers::http::v1::ResultSpool;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionManager;
+use crate::spillers::SpillSpaceManager;
 
 pub struct GlobalServices;
 
@@ -102,16 +107,21 @@ impl GlobalServices {
                 (CatalogType::Iceberg, Arc::new(IcebergCreator)),
                 (CatalogType::Hive, Arc::new(HiveCreator)),
                 (CatalogType::Share, Arc::new(ShareCatalogCreator)),
+                (CatalogType::Stage, Arc::new(StageCatalogCreator)),
             ];
 
             CatalogManager::init(config, Arc::new(default_catalog), catalog_creator).await?;
         }
 
-        QueriesQueueManager::init(config.query.max_running_queries as usize)?;
+        QueriesQueueManager::init(
+            config.query.max_running_queries as usize,
+            &config.query.reserved_query_slots,
+        )?;
         HttpQueryManager::init(config).await?;
         DataExchangeManager::init()?;
         SessionManager::init(config)?;
         LockManager::init()?;
+        SpillSpaceManager::init(config)?;
         AuthMgr::init(config)?;
 
         // Init user manager.
@@ -154,6 +164,9 @@ impl GlobalServices {
         }
 
         ProfilesLogQueue::init(config.query.max_cached_queries_profiles);
+        QueryFragmentsLogQueue::init(config.query.max_cached_queries_profiles);
+        TableEventNotifier::init(config)?;
+        ResultSpool::start_cleanup();
 
         #[cfg(feature = "enable_queries_executor")]
         {
@@ -29,6 +29,7 @@ use databend_common_meta_app::principal::StageType;
 use databend_common_meta_app::principal::UserGrantSet;
 use databend_common_meta_app::principal::UserPrivilegeSet;
 use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_meta_app::schema::CatalogType;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::SeqV;
 use databend_common_sql::binder::MutationType;
@@ -295,6 +296,22 @@ impl PrivilegeAccess {
 
         match self.ctx.get_catalog(catalog_name).await {
             Ok(catalog) => {
+                // the databases of a stage catalog are stages, reading their tables
+                // requires the READ privilege on the stage.
+                if catalog.info().catalog_type() == CatalogType::Stage {
+                    let privilege = match privilege {
+                        UserPrivilegeType::Select => UserPrivilegeType::Read,
+                        _ => UserPrivilegeType::Write,
+                    };
+                    return self
+                        .validate_access(
+                            &GrantObject::Stage(db_name.to_string()),
+                            privilege,
+                            false,
+                            false,
+                        )
+                        .await;
+                }
                 if catalog.exists_table_function(table_name) {
                     return self.validate_table_function_access(table_name).await;
                 }
@@ -655,7 +672,7 @@ impl PrivilegeAccess {
             InsertInputSource::Stage(plan) => {
                 self.check(ctx, plan).await?;
             }
-            InsertInputSource::Values(_) => {}
+            InsertInputSource::Values(_) | InsertInputSource::Stream(_) => {}
         }
         Ok(())
     }
@@ -923,6 +940,10 @@ impl AccessChecker for PrivilegeAccess {
             }
             Plan::CreateTable(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?;
+                // a clone reads all the data of its source table.
+                if let Some((catalog, database, table)) = &plan.clone_source {
+                    self.validate_table_access(catalog, database, table, UserPrivilegeType::Select, false, false).await?;
+                }
                 if let Some(query) = &plan.as_select {
                     self.check(ctx, query).await?;
                 }
@@ -993,6 +1014,10 @@ impl AccessChecker for PrivilegeAccess {
             Plan::VacuumDropTable(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Super, false).await?
             }
+            Plan::VacuumAllTables(plan) => match &plan.database {
+                Some(database) => self.validate_db_access(&plan.catalog, database, UserPrivilegeType::Super, false).await?,
+                None => self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false).await?,
+            },
             Plan::VacuumTemporaryFiles(_) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false).await?
             }
@@ -1106,6 +1131,13 @@ impl AccessChecker for PrivilegeAccess {
             Plan::CreateDynamicTable(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?;
             }
+            Plan::CreateMaterializedView(plan) => {
+                let plan = &plan.create_table_plan;
+                self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?;
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.view, UserPrivilegeType::Insert, false, false).await?;
+            }
             Plan::CreateUser(_) => {
                 self.validate_access(
                     &GrantObject::Global,
@@ -1151,10 +1183,29 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Grant,false, false)
                     .await?;
             }
-            Plan::Set(_) | Plan::Unset(_) | Plan::Kill(_) | Plan::SetPriority(_) | Plan::System(_) => {
+            Plan::Set(_) | Plan::Unset(_) | Plan::Kill(_) | Plan::SetPriority(_) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
                     .await?;
             }
+            Plan::System(plan) => {
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
+                    .await?;
+                // The Super privilege is granted per tenant, the actions changing the state of
+                // the whole node are reserved to the users configured on the node.
+                if plan.action.is_node_global() {
+                    let current_user = self.ctx.get_current_user()?;
+                    if UserApiProvider::instance()
+                        .get_configured_user(&current_user.name)
+                        .is_none()
+                    {
+                        return Err(ErrorCode::PermissionDenied(format!(
+                            "Permission denied: SYSTEM {:?} is only allowed for the users configured on the node, not for user {}",
+                            plan.action,
+                            &current_user.identity().display(),
+                        )));
+                    }
+                }
+            }
 
             Plan::RenameDatabase(_)
             | Plan::RevertTable(_)
@@ -1249,7 +1300,8 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
-            Plan::Begin => {}
+            Plan::Begin { .. } => {}
+            Plan::SetTransactionSnapshot { .. } => {}
             Plan::Commit => {}
             Plan::Abort => {}
             Plan::ExecuteImmediate(_) => {}
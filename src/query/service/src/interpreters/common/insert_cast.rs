@@ -0,0 +1,246 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Expr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+/// How a column produced by the source of an INSERT is converted into the column
+/// of the target table.
+///
+/// | source type              | target type                | kind         |
+/// |--------------------------|----------------------------|--------------|
+/// | `T`                      | `T`                        | Identical    |
+/// | `T`, `NULL`              | `T NULL`                   | Widening     |
+/// | smaller integer/float    | larger integer/float       | Widening     |
+/// | integer, `DECIMAL(p, s)` | fitting `DECIMAL(p', s')`  | Widening     |
+/// | `DATE`                   | `TIMESTAMP`                | Widening     |
+/// | any type                 | `STRING`, `VARIANT`        | Widening     |
+/// | `ARRAY`, `MAP`, `TUPLE`  | same shape                 | by elements  |
+/// | `T NULL`                 | `T`                        | Narrowing    |
+/// | other castable types     |                            | Narrowing    |
+/// | not castable             |                            | Incompatible |
+///
+/// Widening never fails; narrowing is checked per value when the data is inserted,
+/// e.g. `INT64 -> INT32` fails only for values out of range of `INT32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InsertCastKind {
+    Identical,
+    Widening,
+    Narrowing,
+    Incompatible,
+}
+
+impl InsertCastKind {
+    pub fn of(src: &DataType, dest: &DataType) -> InsertCastKind {
+        if src == dest {
+            return InsertCastKind::Identical;
+        }
+        match (src, dest) {
+            (DataType::Null, DataType::Nullable(_)) => InsertCastKind::Widening,
+            (DataType::Nullable(src), DataType::Nullable(dest)) => Self::of(src, dest),
+            (src, DataType::Nullable(dest)) => Self::of(src, dest).max(InsertCastKind::Widening),
+            (DataType::Nullable(src), dest) => Self::of(src, dest).max(InsertCastKind::Narrowing),
+            (DataType::Number(src), DataType::Number(dest)) if src.can_lossless_cast_to(*dest) => {
+                InsertCastKind::Widening
+            }
+            (DataType::Number(src), DataType::Decimal(dest)) => {
+                match src.get_decimal_properties() {
+                    Some(src) if src.scale == 0 => {
+                        let dest = dest.size();
+                        if dest.precision - dest.scale >= src.precision {
+                            InsertCastKind::Widening
+                        } else {
+                            InsertCastKind::Narrowing
+                        }
+                    }
+                    _ => InsertCastKind::Narrowing,
+                }
+            }
+            (DataType::Decimal(src), DataType::Decimal(dest)) => {
+                let (src, dest) = (src.size(), dest.size());
+                if dest.scale >= src.scale
+                    && dest.precision - dest.scale >= src.precision - src.scale
+                {
+                    InsertCastKind::Widening
+                } else {
+                    InsertCastKind::Narrowing
+                }
+            }
+            (DataType::Date, DataType::Timestamp) => InsertCastKind::Widening,
+            (DataType::EmptyArray, DataType::Array(_)) => InsertCastKind::Widening,
+            (DataType::EmptyMap, DataType::Map(_)) => InsertCastKind::Widening,
+            (DataType::Array(src), DataType::Array(dest)) => Self::of(src, dest),
+            (DataType::Map(src), DataType::Map(dest)) => Self::of(src, dest),
+            (DataType::Tuple(src), DataType::Tuple(dest)) if src.len() == dest.len() => src
+                .iter()
+                .zip(dest.iter())
+                .map(|(src, dest)| Self::of(src, dest))
+                .max()
+                .unwrap_or(InsertCastKind::Identical),
+            _ if Self::castable(src, dest) => match dest {
+                DataType::String | DataType::Variant => InsertCastKind::Widening,
+                _ => InsertCastKind::Narrowing,
+            },
+            _ => InsertCastKind::Incompatible,
+        }
+    }
+
+    // The same check as the cast of `TransformCastSchema`, which does the conversion.
+    fn castable(src: &DataType, dest: &DataType) -> bool {
+        let expr = Expr::ColumnRef {
+            span: None,
+            id: 0,
+            data_type: src.clone(),
+            display_name: String::new(),
+        };
+        check_cast(None, false, expr, dest, &BUILTIN_FUNCTIONS).is_ok()
+    }
+}
+
+/// Check that every column of the INSERT source can be inserted into the target column,
+/// returns whether a cast is needed.
+pub fn check_insert_cast(select_schema: &DataSchema, insert_schema: &DataSchema) -> Result<bool> {
+    let mut cast_needed = false;
+    for (select_field, insert_field) in select_schema
+        .fields()
+        .iter()
+        .zip(insert_schema.fields().iter())
+    {
+        match InsertCastKind::of(select_field.data_type(), insert_field.data_type()) {
+            InsertCastKind::Identical => {}
+            InsertCastKind::Widening | InsertCastKind::Narrowing => cast_needed = true,
+            InsertCastKind::Incompatible => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Cannot insert column {} ({}) into column {} ({}), the types are incompatible",
+                    select_field.name(),
+                    select_field.data_type(),
+                    insert_field.name(),
+                    insert_field.data_type(),
+                )));
+            }
+        }
+    }
+    Ok(cast_needed || select_schema != insert_schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_expression::types::DecimalDataType;
+    use databend_common_expression::types::DecimalSize;
+    use databend_common_expression::types::NumberDataType;
+    use databend_common_expression::DataField;
+
+    use super::*;
+
+    fn int(ty: NumberDataType) -> DataType {
+        DataType::Number(ty)
+    }
+
+    fn decimal(precision: u8, scale: u8) -> DataType {
+        let size = DecimalSize { precision, scale };
+        DataType::Decimal(DecimalDataType::Decimal128(size))
+    }
+
+    fn nullable(ty: DataType) -> DataType {
+        DataType::Nullable(Box::new(ty))
+    }
+
+    #[test]
+    fn test_insert_cast_kind() {
+        use InsertCastKind::*;
+        use NumberDataType::*;
+
+        let cases = [
+            (int(Int32), int(Int32), Identical),
+            (int(Int32), int(Int64), Widening),
+            (int(UInt8), int(Int16), Widening),
+            (int(Float32), int(Float64), Widening),
+            (int(Int64), int(Int32), Narrowing),
+            (int(Int8), int(UInt8), Narrowing),
+            (int(Float64), int(Int64), Narrowing),
+            (int(Int32), nullable(int(Int64)), Widening),
+            (DataType::Null, nullable(int(Int32)), Widening),
+            (nullable(int(Int32)), nullable(int(Int64)), Widening),
+            (nullable(int(Int32)), int(Int32), Narrowing),
+            (nullable(int(Int32)), int(Int64), Narrowing),
+            (int(Int16), decimal(20, 2), Widening),
+            (int(Int64), decimal(10, 0), Narrowing),
+            (decimal(10, 2), decimal(12, 4), Widening),
+            (decimal(10, 2), decimal(10, 1), Narrowing),
+            (decimal(10, 2), decimal(10, 4), Narrowing),
+            (DataType::Date, DataType::Timestamp, Widening),
+            (DataType::Timestamp, DataType::Date, Narrowing),
+            (int(Int32), DataType::String, Widening),
+            (DataType::String, int(Int32), Narrowing),
+            (
+                DataType::Array(Box::new(int(Int32))),
+                DataType::Array(Box::new(int(Int64))),
+                Widening,
+            ),
+            (
+                DataType::Array(Box::new(int(Int64))),
+                DataType::Array(Box::new(int(Int32))),
+                Narrowing,
+            ),
+            (
+                DataType::Tuple(vec![int(Int32), DataType::String]),
+                DataType::Tuple(vec![int(Int64), DataType::String]),
+                Widening,
+            ),
+            (
+                DataType::Array(Box::new(int(Int32))),
+                int(Int64),
+                Incompatible,
+            ),
+        ];
+        for (src, dest, expected) in cases {
+            assert_eq!(InsertCastKind::of(&src, &dest), expected, "{src} -> {dest}");
+        }
+    }
+
+    #[test]
+    fn test_check_insert_cast() {
+        let schema = |types: Vec<DataType>| {
+            DataSchema::new(
+                types
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, ty)| DataField::new(&format!("c{i}"), ty))
+                    .collect(),
+            )
+        };
+        let target = schema(vec![int(NumberDataType::Int64), DataType::String]);
+
+        let identical = schema(vec![int(NumberDataType::Int64), DataType::String]);
+        assert!(!check_insert_cast(&identical, &target).unwrap());
+
+        let widening = schema(vec![int(NumberDataType::Int32), DataType::String]);
+        assert!(check_insert_cast(&widening, &target).unwrap());
+
+        let narrowing = schema(vec![int(NumberDataType::Int64), nullable(DataType::String)]);
+        assert!(check_insert_cast(&narrowing, &target).unwrap());
+
+        let incompatible = schema(vec![
+            DataType::Array(Box::new(int(NumberDataType::Int64))),
+            DataType::String,
+        ]);
+        let err = check_insert_cast(&incompatible, &target).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::BAD_ARGUMENTS);
+    }
+}
@@ -13,20 +13,25 @@
 // limitations under the License.
 
 mod grant;
+mod insert_cast;
 mod metrics;
 mod notification;
 mod query_log;
 mod stream;
 mod table;
+mod table_events;
 mod task;
 mod util;
 
 pub use grant::validate_grant_object_exists;
+pub use insert_cast::check_insert_cast;
+pub use insert_cast::InsertCastKind;
 pub use notification::get_notification_client_config;
 pub use query_log::InterpreterQueryLog;
 pub use stream::dml_build_update_stream_req;
 pub use stream::query_build_update_stream_req;
 pub use table::check_referenced_computed_columns;
+pub use table_events::TableEventNotifier;
 pub use task::get_task_client_config;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
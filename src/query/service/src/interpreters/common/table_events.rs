@@ -0,0 +1,134 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table_events::TableEvent;
+use databend_common_catalog::table_events::TableEventListener;
+use databend_common_catalog::table_events::TableEventListenerWrapper;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_storages_system::TableEventLogElement;
+use databend_common_storages_system::TableEventQueue;
+use log::warn;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Url;
+
+enum TableEventSink {
+    /// Posts each event as a json object.
+    Webhook(Url),
+    /// Posts each event as a record of the topic to a Kafka REST proxy,
+    /// keyed by the table id so that the events of a table stay in order.
+    Kafka(Url),
+}
+
+impl TableEventSink {
+    fn parse(sink: &str) -> Result<Self> {
+        let invalid = |reason: &str| {
+            ErrorCode::InvalidConfig(format!("invalid table event sink '{sink}': {reason}"))
+        };
+        if let Some(proxy) = sink.strip_prefix("kafka+") {
+            let mut url = Url::parse(proxy).map_err(|e| invalid(&e.to_string()))?;
+            let topic = url.path().trim_matches('/').to_string();
+            if topic.is_empty() || topic.contains('/') {
+                return Err(invalid("expect kafka+http(s)://host:port/<topic>"));
+            }
+            url.set_path(&format!("/topics/{topic}"));
+            return Ok(TableEventSink::Kafka(url));
+        }
+        let url = Url::parse(sink).map_err(|e| invalid(&e.to_string()))?;
+        match url.scheme() {
+            "http" | "https" => Ok(TableEventSink::Webhook(url)),
+            scheme => Err(invalid(&format!("unsupported scheme {scheme}"))),
+        }
+    }
+
+    async fn send(&self, client: &reqwest::Client, event: &TableEvent) -> Result<()> {
+        let (url, content_type, body) = match self {
+            TableEventSink::Webhook(url) => (url, "application/json", serde_json::json!(event)),
+            TableEventSink::Kafka(url) => (
+                url,
+                "application/vnd.kafka.json.v2+json",
+                serde_json::json!({
+                    "records": [{ "key": event.table_id.to_string(), "value": event }]
+                }),
+            ),
+        };
+        let response = client
+            .post(url.clone())
+            .header(CONTENT_TYPE, content_type)
+            .body(body.to_string())
+            .send()
+            .await
+            .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(ErrorCode::Internal(format!(
+                "{url} responded with {}",
+                response.status()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Records the table events in `system.table_events`, and publishes them to the
+/// sinks of `query.table_event_sinks` in background, so that the commits are never
+/// blocked or failed by a slow or unavailable sink.
+pub struct TableEventNotifier {
+    client: reqwest::Client,
+    sinks: Arc<Vec<TableEventSink>>,
+}
+
+impl TableEventNotifier {
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        let sinks = config
+            .query
+            .table_event_sinks
+            .iter()
+            .map(|sink| TableEventSink::parse(sink))
+            .collect::<Result<Vec<_>>>()?;
+        TableEventListenerWrapper::init(Box::new(TableEventNotifier {
+            client: reqwest::Client::new(),
+            sinks: Arc::new(sinks),
+        }));
+        Ok(())
+    }
+}
+
+impl TableEventListener for TableEventNotifier {
+    fn on_event(&self, event: TableEvent) {
+        let element = TableEventLogElement {
+            event: event.clone(),
+        };
+        if let Err(e) = TableEventQueue::instance().and_then(|queue| queue.append_data(element)) {
+            warn!("failed to record table event {:?}: {}", event, e);
+        }
+
+        if self.sinks.is_empty() {
+            return;
+        }
+        let client = self.client.clone();
+        let sinks = self.sinks.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            for sink in sinks.iter() {
+                if let Err(e) = sink.send(&client, &event).await {
+                    warn!("failed to publish table event {:?}: {}", event, e);
+                }
+            }
+        });
+    }
+}
@@ -26,6 +26,7 @@ use databend_enterprise_vacuum_handler::get_vacuum_handler;
 use opendal::Buffer;
 
 use crate::sessions::QueryContext;
+use crate::spillers::SpillSpaceManager;
 
 pub fn hook_vacuum_temp_files(query_ctx: &Arc<QueryContext>) -> Result<()> {
     let tenant = query_ctx.get_tenant();
@@ -42,6 +43,7 @@ pub fn hook_vacuum_temp_files(query_ctx: &Arc<QueryContext>) -> Result<()> {
             .is_ok()
     {
         let handler = get_vacuum_handler();
+        let query_id = query_ctx.get_id();
 
         let _ = GlobalIORuntime::instance().block_on(async move {
             let removed_files = handler
@@ -52,6 +54,11 @@ pub fn hook_vacuum_temp_files(query_ctx: &Arc<QueryContext>) -> Result<()> {
                 )
                 .await;
 
+            // All the spilled files of the query are removed, release its spill space.
+            if matches!(removed_files, Ok(res) if res < vacuum_limit as usize) {
+                SpillSpaceManager::instance().release_query(&query_id);
+            }
+
             if vacuum_limit != 0 && matches!(removed_files, Ok(res) if res == vacuum_limit as usize)
             {
                 // Have not been removed files
@@ -86,6 +86,7 @@ impl Interpreter for ShowCreateCatalogInterpreter {
                     op.provider, op.share_name, op.share_endpoint,
                 ),
             ),
+            CatalogOption::Stage(_) => (String::from("stage"), String::new()),
         };
 
         let block = DataBlock::new(
@@ -21,9 +21,13 @@ use databend_common_expression::infer_table_schema;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_common_pipeline_core::ExecutionInfo;
+use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_sql::executor::physical_plans::CopyIntoLocation;
+use databend_common_sql::executor::physical_plans::Exchange;
+use databend_common_sql::executor::physical_plans::FragmentKind;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_storage::StageFilesInfo;
+use databend_common_storages_stage::SumSummaryTransform;
 use log::debug;
 use log::info;
 
@@ -79,9 +83,12 @@ impl CopyIntoLocationInterpreter {
         Ok((select_interpreter, update_stream_meta))
     }
 
-    /// Build a pipeline for local copy into stage.
+    /// Build a pipeline for copy into stage.
+    ///
+    /// If the query is distributed, the files are written by every node of the cluster,
+    /// and the coordinator only merges the summaries of the unloaded files.
     #[async_backtrace::framed]
-    async fn build_local_copy_into_stage_pipeline(
+    async fn build_copy_into_stage_pipeline(
         &self,
         stage: &StageInfo,
         path: &str,
@@ -92,6 +99,15 @@ impl CopyIntoLocationInterpreter {
         let query_result_schema = query_interpreter.get_result_schema();
         let table_schema = infer_table_schema(&query_result_schema)?;
 
+        let (query_physical_plan, distributed) = match query_physical_plan {
+            PhysicalPlan::Exchange(Exchange {
+                input,
+                kind: FragmentKind::Merge,
+                ..
+            }) if self.plan.enable_distributed => (*input, true),
+            plan => (plan, false),
+        };
+
         let mut physical_plan = PhysicalPlan::CopyIntoLocation(Box::new(CopyIntoLocation {
             plan_id: 0,
             input: Box::new(query_physical_plan),
@@ -112,12 +128,30 @@ impl CopyIntoLocationInterpreter {
             },
         }));
 
+        if distributed {
+            physical_plan = PhysicalPlan::Exchange(Exchange {
+                plan_id: 0,
+                input: Box::new(physical_plan),
+                kind: FragmentKind::Merge,
+                keys: Vec::new(),
+                allow_adjust_parallelism: true,
+                ignore_exchange: false,
+            });
+        }
+
         let mut next_plan_id = 0;
         physical_plan.adjust_plan_id(&mut next_plan_id);
-        Ok((
-            build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?,
-            update_stream_meta_req,
-        ))
+        let mut build_res =
+            build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
+
+        if distributed && !stage.copy_options.detailed_output {
+            // Each node reports the summary of its own files.
+            build_res.main_pipeline.try_resize(1)?;
+            build_res
+                .main_pipeline
+                .add_accumulating_transformer(SumSummaryTransform::default);
+        }
+        Ok((build_res, update_stream_meta_req))
     }
 }
 
@@ -141,11 +175,7 @@ impl Interpreter for CopyIntoLocationInterpreter {
         }
 
         let (mut pipeline_build_result, update_stream_reqs) = self
-            .build_local_copy_into_stage_pipeline(
-                &self.plan.stage,
-                &self.plan.path,
-                &self.plan.from,
-            )
+            .build_copy_into_stage_pipeline(&self.plan.stage, &self.plan.path, &self.plan.from)
             .await?;
 
         // We are going to consuming streams, which are all of the default catalog
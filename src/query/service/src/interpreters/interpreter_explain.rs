@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use databend_common_ast::ast::ExplainFormat;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::FormatTreeNode;
 use databend_common_catalog::table_context::TableContext;
@@ -27,6 +28,7 @@ use databend_common_expression::FromData;
 use databend_common_pipeline_core::always_callback;
 use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_pipeline_core::ExecutionInfo;
+use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::binder::ExplainConfig;
 use databend_common_sql::optimizer::ColumnSet;
 use databend_common_sql::plans::Mutation;
@@ -196,7 +198,7 @@ impl Interpreter for ExplainInterpreter {
                     _ => PipelineBuildResult::create(),
                 };
 
-                Self::format_pipeline(&pipeline)
+                Self::format_pipeline(&pipeline, self.config.verbose)
             }
 
             ExplainKind::Fragments => match &self.plan {
@@ -257,6 +259,11 @@ impl ExplainInterpreter {
     }
 
     pub fn explain_plan(&self, plan: &Plan) -> Result<Vec<DataBlock>> {
+        if self.config.format == ExplainFormat::Json {
+            let result = plan.format_json(self.config.verbose)?;
+            let formatted_plan = StringType::from_data(vec![result]);
+            return Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])]);
+        }
         let result = plan.format_indent(self.config.verbose)?;
         let line_split_result: Vec<&str> = result.lines().collect();
         let formatted_plan = StringType::from_data(line_split_result);
@@ -283,26 +290,33 @@ impl ExplainInterpreter {
             if let Some(v) = cache_reader.check_cache().await? {
                 // Construct a format tree for result cache reading
                 let children = vec![
-                    FormatTreeNode::new(format!("SQL: {}", v.sql)),
-                    FormatTreeNode::new(format!("Number of rows: {}", v.num_rows)),
-                    FormatTreeNode::new(format!("Result size: {}", v.result_size)),
+                    FormatTreeNode::property("SQL", v.sql),
+                    FormatTreeNode::property("Number of rows", v.num_rows),
+                    FormatTreeNode::property("Result size", v.result_size),
                 ];
 
                 let format_tree =
                     FormatTreeNode::with_children("ReadQueryResultCache".to_string(), children);
 
-                let result = format_tree.format_pretty()?;
-                let line_split_result: Vec<&str> = result.lines().collect();
-                let formatted_plan = StringType::from_data(line_split_result);
-                return Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])]);
+                return self.format_tree(&format_tree);
             }
         }
 
-        let result = plan
-            .format(metadata.clone(), Default::default())?
-            .format_pretty()?;
-        let line_split_result: Vec<&str> = result.lines().collect();
-        let formatted_plan = StringType::from_data(line_split_result);
+        let format_tree = plan.format(metadata.clone(), Default::default())?;
+        self.format_tree(&format_tree)
+    }
+
+    /// Formats the plan tree as lines of text, or as a single row of json with `FORMAT JSON`.
+    fn format_tree(&self, format_tree: &FormatTreeNode) -> Result<Vec<DataBlock>> {
+        let formatted_plan = match self.config.format {
+            ExplainFormat::Text => {
+                let result = format_tree.format_pretty()?;
+                StringType::from_data(result.lines().collect::<Vec<_>>())
+            }
+            ExplainFormat::Json => {
+                StringType::from_data(vec![format_tree.format_json()?.to_string()])
+            }
+        };
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 
@@ -317,10 +331,17 @@ impl ExplainInterpreter {
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 
-    fn format_pipeline(build_res: &PipelineBuildResult) -> Vec<DataBlock> {
+    fn format_pipeline(build_res: &PipelineBuildResult, verbose: bool) -> Vec<DataBlock> {
+        let display = |pipeline: &Pipeline| {
+            if verbose {
+                pipeline.display_indent_verbose().to_string()
+            } else {
+                pipeline.display_indent().to_string()
+            }
+        };
         let mut blocks = Vec::with_capacity(1 + build_res.sources_pipelines.len());
         // Format root pipeline
-        let line_split_result = format!("{}", build_res.main_pipeline.display_indent())
+        let line_split_result = display(&build_res.main_pipeline)
             .lines()
             .map(|l| l.to_string())
             .collect::<Vec<_>>();
@@ -328,7 +349,7 @@ impl ExplainInterpreter {
         blocks.push(DataBlock::new_from_columns(vec![column]));
         // Format child pipelines
         for pipeline in build_res.sources_pipelines.iter() {
-            let line_split_result = format!("\n{}", pipeline.display_indent())
+            let line_split_result = format!("\n{}", display(pipeline))
                 .lines()
                 .map(|l| l.to_string())
                 .collect::<Vec<_>>();
@@ -350,7 +371,9 @@ impl ExplainInterpreter {
             .build(&s_expr, required)
             .await?;
 
-        let root_fragment = Fragmenter::try_create(ctx.clone())?.build_fragment(&plan)?;
+        let root_fragment = Fragmenter::try_create(ctx.clone())?
+            .build_fragment(&plan)
+            .await?;
 
         let mut fragments_actions = QueryFragmentsActions::create(ctx.clone());
         root_fragment.get_actions(ctx, &mut fragments_actions)?;
@@ -450,7 +473,9 @@ impl ExplainInterpreter {
         let mutation: Mutation = s_expr.plan().clone().try_into()?;
         let interpreter = MutationInterpreter::try_create(self.ctx.clone(), s_expr, schema)?;
         let plan = interpreter.build_physical_plan(&mutation, None).await?;
-        let root_fragment = Fragmenter::try_create(self.ctx.clone())?.build_fragment(&plan)?;
+        let root_fragment = Fragmenter::try_create(self.ctx.clone())?
+            .build_fragment(&plan)
+            .await?;
 
         let mut fragments_actions = QueryFragmentsActions::create(self.ctx.clone());
         root_fragment.get_actions(self.ctx.clone(), &mut fragments_actions)?;
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_ast::ast::ExplainKind;
 use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::query_kind::QueryKind;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::binder::ExplainConfig;
@@ -63,6 +64,7 @@ use crate::interpreters::interpreter_tasks_show::ShowTasksInterpreter;
 use crate::interpreters::interpreter_txn_abort::AbortInterpreter;
 use crate::interpreters::interpreter_txn_begin::BeginInterpreter;
 use crate::interpreters::interpreter_txn_commit::CommitInterpreter;
+use crate::interpreters::interpreter_txn_set_snapshot::SetTransactionSnapshotInterpreter;
 use crate::interpreters::interpreter_view_describe::DescribeViewInterpreter;
 use crate::interpreters::AlterUserInterpreter;
 use crate::interpreters::CreateShareEndpointInterpreter;
@@ -73,6 +75,7 @@ use crate::interpreters::DropStreamInterpreter;
 use crate::interpreters::DropUserInterpreter;
 use crate::interpreters::SetRoleInterpreter;
 use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
 use crate::sql::plans::Plan;
 
 /// InterpreterFactory is the entry of Interpreter.
@@ -95,9 +98,39 @@ impl InterpreterFactory {
                 }
                 _ => e,
             })?;
+        Self::apply_statement_timeout(&ctx, plan)?;
         Self::get_inner(ctx, plan)
     }
 
+    /// Use the timeout of the statement class when `max_execute_time_in_seconds`
+    /// is not set for the query.
+    fn apply_statement_timeout(ctx: &Arc<QueryContext>, plan: &Plan) -> Result<()> {
+        let settings = ctx.get_settings();
+        if settings.get_max_execute_time_in_seconds()? != 0 {
+            return Ok(());
+        }
+
+        let timeout = match plan.kind() {
+            QueryKind::Query | QueryKind::Explain => {
+                settings.get_select_statement_timeout_secs()?
+            }
+            QueryKind::Insert
+            | QueryKind::Update
+            | QueryKind::CopyIntoTable
+            | QueryKind::CopyIntoLocation => settings.get_dml_statement_timeout_secs()?,
+            QueryKind::Unknown | QueryKind::Other if plan.is_ddl() => {
+                settings.get_ddl_statement_timeout_secs()?
+            }
+            // Statements of no class, e.g. SHOW, SET or VACUUM, only follow max_execute_time_in_seconds.
+            QueryKind::Unknown | QueryKind::Other => 0,
+        };
+
+        if timeout != 0 {
+            settings.set_max_execute_time_in_seconds(timeout)?;
+        }
+        Ok(())
+    }
+
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
         match plan {
             Plan::Query {
@@ -260,6 +293,9 @@ impl InterpreterFactory {
             Plan::VacuumDropTable(vacuum_drop_table) => Ok(Arc::new(
                 VacuumDropTablesInterpreter::try_create(ctx, *vacuum_drop_table.clone())?,
             )),
+            Plan::VacuumAllTables(vacuum_all_tables) => Ok(Arc::new(
+                VacuumAllTablesInterpreter::try_create(ctx, *vacuum_all_tables.clone())?,
+            )),
             Plan::VacuumTemporaryFiles(vacuum_temporary_files) => Ok(Arc::new(
                 VacuumTemporaryFilesInterpreter::try_create(ctx, *vacuum_temporary_files.clone())?,
             )),
@@ -303,6 +339,20 @@ impl InterpreterFactory {
             // dynamic tables
             Plan::CreateDynamicTable(_) => Err(ErrorCode::Unimplemented("todo")),
 
+            // Materialized views
+            Plan::CreateMaterializedView(create_materialized_view) => {
+                Ok(Arc::new(CreateMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *create_materialized_view.clone(),
+                )?))
+            }
+            Plan::RefreshMaterializedView(refresh_materialized_view) => {
+                Ok(Arc::new(RefreshMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *refresh_materialized_view.clone(),
+                )?))
+            }
+
             // Indexes
             Plan::CreateIndex(index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
                 ctx,
@@ -567,7 +617,13 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::ShowConnections(_) => Ok(Arc::new(ShowConnectionsInterpreter::try_create(ctx)?)),
-            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Begin { read_only_at } => Ok(Arc::new(BeginInterpreter::try_create(
+                ctx,
+                *read_only_at,
+            )?)),
+            Plan::SetTransactionSnapshot { at } => Ok(Arc::new(
+                SetTransactionSnapshotInterpreter::try_create(ctx, *at)?,
+            )),
             Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
             Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
             Plan::CreateNotification(p) => Ok(Arc::new(CreateNotificationInterpreter::try_create(
@@ -33,10 +33,12 @@ use databend_common_sql::NameResolutionContext;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_insert_cast;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::pipelines::BlockStreamSource;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
 use crate::pipelines::RawValueSource;
@@ -68,9 +70,8 @@ impl InsertInterpreter {
             )));
         }
 
-        // check if cast needed
-        let cast_needed = select_schema.as_ref() != &DataSchema::from(output_schema.as_ref());
-        Ok(cast_needed)
+        // check if cast needed, and the types can be converted
+        check_insert_cast(&select_schema, &DataSchema::from(output_schema.as_ref()))
     }
 }
 
@@ -119,6 +120,16 @@ impl Interpreter for InsertInterpreter {
                     1,
                 )?;
             }
+            InsertInputSource::Stream(receiver) => {
+                build_res.main_pipeline.add_source(
+                    |output| {
+                        let inner =
+                            BlockStreamSource::try_create(receiver, self.plan.dest_schema())?;
+                        AsyncSourcer::create(self.ctx.clone(), output, inner)
+                    },
+                    1,
+                )?;
+            }
             InsertInputSource::Values(InsertValue::RawValues { data, start }) => {
                 build_res.main_pipeline.add_source(
                     |output| {
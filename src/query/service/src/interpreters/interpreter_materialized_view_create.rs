@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::CreateMaterializedViewPlan;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+
+use crate::interpreters::interpreter_materialized_view_refresh::refresh_materialized_view;
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan.create_table_plan;
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+        if plan.create_option == CreateOption::CreateIfNotExists
+            && catalog
+                .exists_table(&tenant, &plan.database, &plan.table)
+                .await?
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        CreateTableInterpreter::try_create(self.ctx.clone(), plan.clone())?
+            .execute2()
+            .await?;
+
+        // Register the view in its base table, so that the queries of the base table
+        // can be rewritten to read the view.
+        if let Some((database, name)) = &self.plan.base_table {
+            let base_table = catalog.get_table(&tenant, database, name).await?;
+            let view = format!("{}.{}", plan.database, plan.table);
+            let mut views = base_table
+                .options()
+                .get(OPT_KEY_MATERIALIZED_VIEWS)
+                .map(|views| views.split(',').map(str::to_string).collect::<Vec<_>>())
+                .unwrap_or_default();
+            if !views.contains(&view) {
+                views.push(view);
+                let req = UpsertTableOptionReq {
+                    table_id: base_table.get_id(),
+                    seq: MatchSeq::Exact(base_table.get_table_info().ident.seq),
+                    options: HashMap::from([(
+                        OPT_KEY_MATERIALIZED_VIEWS.to_string(),
+                        Some(views.join(",")),
+                    )]),
+                };
+                catalog.upsert_table_option(&tenant, database, req).await?;
+            }
+        }
+
+        refresh_materialized_view(&self.ctx, &plan.catalog, &plan.database, &plan.table, true)
+            .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
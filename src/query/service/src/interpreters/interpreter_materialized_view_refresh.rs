@@ -0,0 +1,338 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_ast::ast::ChangesInterval;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TemporalClause;
+use databend_common_ast::ast::TimeTravelPoint;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaId;
+use databend_common_sql::check_incremental_refresh;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::RefreshMaterializedViewPlan;
+use databend_common_sql::with_base_table_temporal;
+use databend_common_sql::IncrementalRefresh;
+use databend_common_sql::Planner;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE;
+use futures::TryStreamExt;
+use log::info;
+
+use crate::interpreters::interpreter_txn_commit::ClearTxnManagerGuard;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+}
+
+impl RefreshMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshMaterializedViewPlan) -> Result<Self> {
+        Ok(RefreshMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "RefreshMaterializedViewInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        refresh_materialized_view(
+            &self.ctx,
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.view,
+            self.plan.full,
+        )
+        .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+/// Brings the materialized view up to date with the current snapshot of its base table.
+///
+/// Views over a single fuse table are refreshed to a pinned snapshot of the base table,
+/// the snapshot is recorded in the view so that the next incremental refresh only reads
+/// the rows appended since then, and so that queries are only rewritten to read the view
+/// while the base table stays at the same snapshot.
+#[async_backtrace::framed]
+pub(crate) async fn refresh_materialized_view(
+    ctx: &Arc<QueryContext>,
+    catalog_name: &str,
+    database: &str,
+    view: &str,
+    full: bool,
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog(catalog_name).await?;
+    let table = catalog.get_table(&tenant, database, view).await?;
+    let options = table.options();
+    let Some(query) = options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY) else {
+        return Err(ErrorCode::TableEngineNotSupported(format!(
+            "{database}.{view} is not a materialized view"
+        )));
+    };
+    let tokens = tokenize_sql(query)?;
+    let (stmt, _) = parse_sql(&tokens, ctx.get_settings().get_sql_dialect()?)?;
+    let Statement::Query(query) = stmt else {
+        return Err(ErrorCode::Internal(format!(
+            "invalid query of materialized view {database}.{view}"
+        )));
+    };
+
+    let incremental = options
+        .get(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE)
+        .is_some_and(|mode| mode == "incremental");
+    let last_snapshot = options.get(OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT);
+    let base_table = match options.get(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID) {
+        Some(table_id) => resolve_base_table(ctx, &query, table_id.parse()?).await?,
+        None => None,
+    };
+
+    // The view is up to date as of the start of the refresh at least.
+    let refreshed_on = Utc::now().timestamp();
+    let target = format!("`{database}`.`{view}`");
+    let sql = match (&base_table, last_snapshot) {
+        (Some(base), Some(last)) if base.snapshot_id == *last && !full => {
+            info!("materialized view {database}.{view} is up to date");
+            None
+        }
+        (Some(base), Some(last))
+            if incremental && !full && base.is_append_only(ctx, last).await? =>
+        {
+            let delta = with_base_table_temporal(
+                &query,
+                TemporalClause::Changes(ChangesInterval {
+                    append_only: true,
+                    at_point: TimeTravelPoint::Snapshot(last.clone()),
+                    end_point: Some(TimeTravelPoint::Snapshot(base.snapshot_id.clone())),
+                }),
+            );
+            Some(match check_incremental_refresh(&query)? {
+                IncrementalRefresh::Append => format!("INSERT INTO {target} {delta}"),
+                IncrementalRefresh::Merge(rollups) => {
+                    merge_sql(table.as_ref(), &target, &delta, &rollups)
+                }
+            })
+        }
+        (Some(base), _) => {
+            let query = with_base_table_temporal(
+                &query,
+                TemporalClause::TimeTravel(TimeTravelPoint::Snapshot(base.snapshot_id.clone())),
+            );
+            Some(format!("INSERT OVERWRITE {target} {query}"))
+        }
+        (None, _) => Some(format!("INSERT OVERWRITE {target} {query}")),
+    };
+    // The data of the view and the snapshot of the base table it is refreshed to are
+    // committed in one meta transaction. If the refresh runs in an explicit transaction,
+    // they are committed by the transaction.
+    let txn_mgr = ctx.txn_mgr();
+    let explicit_txn = txn_mgr.lock().is_active();
+    let _guard = (!explicit_txn).then(|| {
+        txn_mgr.lock().begin();
+        ClearTxnManagerGuard(txn_mgr.clone())
+    });
+
+    if let Some(sql) = sql {
+        info!("refresh materialized view {database}.{view}: {sql}");
+        execute_sql(ctx, &sql).await?;
+    }
+
+    if let Some(base) = base_table {
+        // The view table mutated by the refresh is read from the transaction buffer.
+        let table = catalog.get_table(&tenant, database, view).await?;
+        let table_info = table.get_table_info();
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.options.extend([
+            (
+                OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT.to_string(),
+                base.snapshot_id,
+            ),
+            (
+                OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT_LOCATION.to_string(),
+                base.snapshot_location,
+            ),
+            (
+                OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON.to_string(),
+                refreshed_on.to_string(),
+            ),
+        ]);
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+        };
+        catalog.update_single_table_meta(req, table_info).await?;
+    }
+
+    if !explicit_txn {
+        let req = {
+            let mut txn_mgr = txn_mgr.lock();
+            let req = txn_mgr.req();
+            txn_mgr.set_auto_commit();
+            req
+        };
+        if !req.update_table_metas.is_empty() {
+            catalog.update_multi_table_meta(req).await?;
+        }
+    }
+    Ok(())
+}
+
+/// The current snapshot of the base table of a materialized view.
+struct BaseTable {
+    database: String,
+    name: String,
+    snapshot_id: String,
+    snapshot_location: String,
+}
+
+impl BaseTable {
+    /// Whether the base table has only been appended to since the given snapshot,
+    /// rows deleted or updated since then can not be merged into the view incrementally.
+    async fn is_append_only(&self, ctx: &Arc<QueryContext>, since: &str) -> Result<bool> {
+        let changes = ChangesInterval {
+            append_only: false,
+            at_point: TimeTravelPoint::Snapshot(since.to_string()),
+            end_point: Some(TimeTravelPoint::Snapshot(self.snapshot_id.clone())),
+        };
+        let sql = format!(
+            "SELECT COUNT(*) FROM `{}`.`{}` {changes} WHERE change$action = 'DELETE'",
+            self.database, self.name
+        );
+        let blocks = execute_sql(ctx, &sql).await?;
+        let deleted = blocks
+            .first()
+            .and_then(|block| block.get_by_offset(0).value.index(0));
+        Ok(matches!(
+            deleted,
+            None | Some(ScalarRef::Number(NumberScalar::UInt64(0)))
+        ))
+    }
+}
+
+/// Resolves the base table from the query of the view, `None` if the base table
+/// is still empty.
+async fn resolve_base_table(
+    ctx: &Arc<QueryContext>,
+    query: &Query,
+    table_id: MetaId,
+) -> Result<Option<BaseTable>> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&query.to_string()).await?;
+    let Plan::Query { metadata, .. } = plan else {
+        unreachable!()
+    };
+    let entry = metadata
+        .read()
+        .tables()
+        .iter()
+        .find(|entry| entry.table().get_id() == table_id)
+        .cloned();
+    let Some(entry) = entry else {
+        return Err(ErrorCode::UnknownTable(
+            "the base table of materialized view has been dropped or replaced",
+        ));
+    };
+    let table = entry.table();
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?;
+    let location = fuse_table.snapshot_loc().await?;
+    Ok(snapshot
+        .zip(location)
+        .map(|(snapshot, location)| BaseTable {
+            database: entry.database().to_string(),
+            name: entry.name().to_string(),
+            snapshot_id: snapshot.snapshot_id.simple().to_string(),
+            snapshot_location: location,
+        }))
+}
+
+/// Merges the aggregated changes of the base table into the aggregated rows of the view,
+/// by rolling up both of them again on the group keys.
+fn merge_sql(
+    view: &dyn Table,
+    target: &str,
+    delta: &Query,
+    rollups: &[Option<&'static str>],
+) -> String {
+    let schema = view.schema();
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| format!("`{}`", field.name()))
+        .collect::<Vec<_>>();
+    let select_list = columns
+        .iter()
+        .zip(rollups)
+        .map(|(column, rollup)| match rollup {
+            Some(rollup) => format!("{rollup}({column}) AS {column}"),
+            None => column.clone(),
+        })
+        .collect::<Vec<_>>();
+    let group_by = columns
+        .iter()
+        .zip(rollups)
+        .filter(|(_, rollup)| rollup.is_none())
+        .map(|(column, _)| column.clone())
+        .collect::<Vec<_>>();
+    let group_by = if group_by.is_empty() {
+        String::new()
+    } else {
+        format!(" GROUP BY {}", group_by.join(", "))
+    };
+    format!(
+        "INSERT OVERWRITE {target} SELECT {} FROM (SELECT * FROM {target} UNION ALL {delta}) AS merged{group_by}",
+        select_list.join(", ")
+    )
+}
+
+async fn execute_sql(ctx: &Arc<QueryContext>, sql: &str) -> Result<Vec<DataBlock>> {
+    let ctx = ctx.get_current_session().create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx.clone()).await?;
+    stream.try_collect::<Vec<_>>().await
+}
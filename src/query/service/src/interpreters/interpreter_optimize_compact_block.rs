@@ -17,6 +17,9 @@ use std::sync::Arc;
 
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::table_events::notify_table_event;
+use databend_common_catalog::table_events::TableEvent;
+use databend_common_catalog::table_events::TableEventType;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_pipeline_core::ExecutionInfo;
@@ -89,6 +92,36 @@ impl Interpreter for OptimizeCompactBlockInterpreter {
                     build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan)
                         .await?;
                 build_res.main_pipeline.add_lock_guard(lock_guard);
+
+                let table_id = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .get_id();
+                let txn_mgr = self.ctx.txn_mgr();
+                let tenant = self.ctx.get_tenant();
+                let event_table = (catalog.clone(), database.clone(), table.clone());
+                build_res
+                    .main_pipeline
+                    .set_on_finished(move |info: &ExecutionInfo| {
+                        if info.res.is_ok() {
+                            let mut txn_mgr = txn_mgr.lock();
+                            if txn_mgr.is_active() {
+                                txn_mgr.add_compacted_table(table_id);
+                            } else {
+                                let (catalog, database, table) = &event_table;
+                                notify_table_event(TableEvent::create(
+                                    TableEventType::CompactionFinished,
+                                    tenant.tenant_name(),
+                                    catalog,
+                                    database,
+                                    table,
+                                    table_id,
+                                ));
+                            }
+                        }
+                        Ok(())
+                    });
             }
             Err(e) => {
                 if e.code() != ErrorCode::NO_NEED_TO_COMPACT {
@@ -16,6 +16,9 @@ use std::sync::Arc;
 
 use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::table::TableExt;
+use databend_common_catalog::table_events::notify_table_event;
+use databend_common_catalog::table_events::TableEvent;
+use databend_common_catalog::table_events::TableEventType;
 use databend_common_exception::Result;
 use databend_common_sql::plans::OptimizeCompactSegmentPlan;
 
@@ -72,6 +75,22 @@ impl Interpreter for OptimizeCompactSegmentInterpreter {
         table
             .compact_segments(self.ctx.clone(), self.plan.num_segment_limit)
             .await?;
+        let is_active = self.ctx.txn_mgr().lock().is_active();
+        if is_active {
+            self.ctx
+                .txn_mgr()
+                .lock()
+                .add_compacted_table(table.get_id());
+        } else {
+            notify_table_event(TableEvent::create(
+                TableEventType::CompactionFinished,
+                self.ctx.get_tenant().tenant_name(),
+                &self.plan.catalog,
+                &self.plan.database,
+                &self.plan.table,
+                table.get_id(),
+            ));
+        }
 
         drop(lock_guard);
         Ok(PipelineBuildResult::create())
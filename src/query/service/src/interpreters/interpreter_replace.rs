@@ -411,6 +411,9 @@ impl ReplaceInterpreter {
                 }
                 _ => unreachable!("plan in InsertInputSource::Stag must be CopyIntoTable"),
             },
+            InsertInputSource::Stream(_) => Err(ErrorCode::Unimplemented(
+                "REPLACE INTO from a data stream is not supported",
+            )),
         }
     }
 
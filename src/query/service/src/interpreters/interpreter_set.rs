@@ -53,6 +53,9 @@ impl SetInterpreter {
         let settings = self.ctx.get_shared_settings();
 
         match is_global {
+            true if self.set.set_type == SetType::SettingsGlobalLocked => {
+                settings.set_locked_global_setting(var, value).await
+            }
             true => settings.set_global_setting(var, value).await,
             false => settings.set_setting(var, value),
         }
@@ -201,7 +204,9 @@ impl Interpreter for SetInterpreter {
         }
 
         match &self.set.set_type {
-            SetType::SettingsGlobal => self.execute_settings(scalars, true).await?,
+            SetType::SettingsGlobal | SetType::SettingsGlobalLocked => {
+                self.execute_settings(scalars, true).await?
+            }
             SetType::SettingsSession => self.execute_settings(scalars, false).await?,
             SetType::Variable => self.execute_variables(scalars).await?,
         }
@@ -16,10 +16,18 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
+use databend_common_config::GlobalConfig;
 use databend_common_exception::set_backtrace;
 use databend_common_exception::Result;
 use databend_common_sql::plans::SystemAction;
 use databend_common_sql::plans::SystemPlan;
+use databend_common_storage::DataOperator;
+use databend_common_storages_result_cache::gen_result_cache_prefix;
+use databend_common_storages_result_cache::ResultCacheMetaManager;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_cache_manager::CacheManager;
+use log::info;
+use log::warn;
 
 use crate::clusters::ClusterHelper;
 use crate::interpreters::Interpreter;
@@ -64,7 +72,9 @@ impl Interpreter for SystemActionInterpreter {
     #[async_backtrace::framed]
     #[fastrace::trace]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        if self.proxy_to_cluster {
+        // The query result cache is kept in the meta service and shared by the whole cluster,
+        // there is no need to drop it on every node.
+        if self.proxy_to_cluster && self.plan.action != SystemAction::DropQueryCache {
             let cluster = self.ctx.get_cluster();
             let mut message = HashMap::with_capacity(cluster.nodes.len());
             for node_info in &cluster.nodes {
@@ -84,6 +94,34 @@ impl Interpreter for SystemActionInterpreter {
             SystemAction::Backtrace(switch) => {
                 set_backtrace(switch);
             }
+            SystemAction::ReloadConfig => {
+                GlobalConfig::reload().await?;
+                info!("config reloaded by query {}", self.ctx.get_id());
+            }
+            SystemAction::FlushLogs => {
+                log::logger().flush();
+                fastrace::flush();
+            }
+            SystemAction::DropQueryCache => {
+                let meta_client = UserApiProvider::instance().get_meta_store_client();
+                let result_cache_mgr = ResultCacheMetaManager::create(meta_client, 0);
+                let prefix = gen_result_cache_prefix(self.ctx.get_tenant().tenant_name());
+                let removed = result_cache_mgr.remove_all(&prefix).await?;
+
+                let operator = DataOperator::instance().operator();
+                for value in removed {
+                    // The entries are already removed, a leftover file is not visible anymore.
+                    if let Err(e) = operator.delete(&value.location).await {
+                        warn!(
+                            "failed to remove result cache file {}: {}",
+                            value.location, e
+                        );
+                    }
+                }
+            }
+            SystemAction::SyncMetadata => {
+                CacheManager::instance().clear_table_meta_caches();
+            }
         }
         Ok(PipelineBuildResult::create())
     }
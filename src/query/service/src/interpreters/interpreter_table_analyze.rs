@@ -311,6 +311,7 @@ fn remove_exchange(plan: PhysicalPlan) -> PhysicalPlan {
                 agg_funcs: plan.agg_funcs,
                 before_group_by_schema: plan.before_group_by_schema,
                 limit: plan.limit,
+                sort_based: plan.sort_based,
                 group_by_display: plan.group_by_display,
                 stat_info: plan.stat_info,
             }),
@@ -19,6 +19,9 @@ use std::sync::LazyLock;
 
 use chrono::Utc;
 use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_catalog::table_events::notify_table_event;
+use databend_common_catalog::table_events::TableEvent;
+use databend_common_catalog::table_events::TableEventType;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -43,14 +46,18 @@ use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_types::MatchSeq;
 use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_sql::field_default_value;
+use databend_common_sql::parse_ttl_expr;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_storages_fuse::io::MetaReaders;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_CHECKSUM;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
+use databend_common_storages_fuse::FUSE_OPT_KEY_TIERING_AGE_DAYS;
 use databend_common_storages_share::remove_share_table_info;
 use databend_common_storages_share::save_share_spec;
 use databend_common_users::RoleCacheManager;
@@ -60,6 +67,7 @@ use databend_storages_common_cache::LoadParams;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
+use databend_storages_common_table_meta::table::TableCompression;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_COMMENT;
@@ -67,11 +75,16 @@ use databend_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_SEED;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION_LEVEL;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use log::error;
 use log::info;
 
@@ -277,6 +290,9 @@ impl CreateTableInterpreter {
 
         let db_name = self.plan.database.clone();
         let table_name = self.plan.table.clone();
+        let catalog_name = self.plan.catalog.clone();
+        let (event_db_name, event_table_name) = (db_name.clone(), table_name.clone());
+        let event_tenant = self.ctx.get_tenant();
 
         // Add a callback to restore table visibility upon successful insert pipeline completion.
         // As there might be previous on_finish callbacks(e.g. refresh/compact/re-cluster hooks) which
@@ -314,6 +330,14 @@ impl CreateTableInterpreter {
                         info!("create {} as select failed. {:?}", qualified_table_name, e);
                         e
                     })?;
+                    notify_table_event(TableEvent::create(
+                        TableEventType::TableCreated,
+                        event_tenant.tenant_name(),
+                        &catalog_name,
+                        &event_db_name,
+                        &event_table_name,
+                        table_id,
+                    ));
                 }
 
                 Ok(())
@@ -359,6 +383,34 @@ impl CreateTableInterpreter {
 
         let reply = catalog.create_table(req.clone()).await?;
 
+        // a table created by `CREATE TABLE ... CLONE` shares files with its source,
+        // register them so that the source does not purge them.
+        if self.plan.clone_source.is_some()
+            && self.plan.options.contains_key(OPT_KEY_SNAPSHOT_LOCATION)
+        {
+            let table = catalog
+                .get_table(
+                    &self.ctx.get_tenant(),
+                    &self.plan.database,
+                    &self.plan.table,
+                )
+                .await?;
+            if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+                fuse_table.register_shared_files(self.ctx.clone()).await?;
+            }
+        }
+
+        if reply.new_table || self.plan.create_option == CreateOption::CreateOrReplace {
+            notify_table_event(TableEvent::create(
+                TableEventType::TableCreated,
+                self.ctx.get_tenant().tenant_name(),
+                &self.plan.catalog,
+                &self.plan.database,
+                &self.plan.table,
+                reply.table_id,
+            ));
+        }
+
         // grant the ownership of the table to the current role, the above req.table_meta.owner could be removed in future.
         if let Some(current_role) = self.ctx.get_current_role() {
             let tenant = self.ctx.get_tenant();
@@ -443,15 +495,22 @@ impl CreateTableInterpreter {
 
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
+        is_valid_ttl(self.ctx.clone(), &table_meta.options, schema.clone())?;
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
         // check random seed
         is_valid_random_seed(&table_meta.options)?;
+        is_valid_tiering_age_days(&table_meta.options)?;
+        is_valid_block_checksum(&table_meta.options)?;
+        is_valid_compression_level(&table_meta.options)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
-            if !is_valid_create_opt(&key) {
+            // `snapshot_location` is set by the binder for `CREATE TABLE ... CLONE`.
+            let is_clone_snapshot =
+                self.plan.clone_source.is_some() && key == OPT_KEY_SNAPSHOT_LOCATION;
+            if !is_valid_create_opt(&key) && !is_clone_snapshot {
                 error!("invalid opt for fuse table in create table statement");
                 return Err(ErrorCode::TableOptionInvalid(format!(
                     "table option {key} is invalid for create table statement",
@@ -498,13 +557,17 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_TIERING_AGE_DAYS);
+    r.insert(FUSE_OPT_KEY_BLOCK_CHECKSUM);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
+    r.insert(OPT_KEY_TABLE_COMPRESSION_LEVEL);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
+    r.insert(OPT_KEY_TTL);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -515,6 +578,10 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
 
     r.insert(OPT_KEY_RANDOM_SEED);
 
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID);
+
     r.insert("transient");
     r
 });
@@ -578,6 +645,64 @@ pub fn is_valid_change_tracking(options: &BTreeMap<String, String>) -> Result<()
     Ok(())
 }
 
+pub fn is_valid_tiering_age_days(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_TIERING_AGE_DAYS) {
+        value.parse::<u64>().map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid {FUSE_OPT_KEY_TIERING_AGE_DAYS} option, expect a number of days, got '{value}'"
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_block_checksum(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_BLOCK_CHECKSUM) {
+        value.parse::<bool>().map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid {FUSE_OPT_KEY_BLOCK_CHECKSUM} option, expect 'true' or 'false', got '{value}'"
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_compression_level(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_TABLE_COMPRESSION_LEVEL) {
+        let level = value.parse::<i32>().map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid {OPT_KEY_TABLE_COMPRESSION_LEVEL} option, expect an integer, got '{value}'"
+            ))
+        })?;
+        let compression = options
+            .get(OPT_KEY_TABLE_COMPRESSION)
+            .map(String::as_str)
+            .unwrap_or_default();
+        TableCompression::try_from(compression)?.with_level(level)?;
+        // The native format always uses the default level.
+        if options
+            .get(OPT_KEY_STORAGE_FORMAT)
+            .is_some_and(|format| format.eq_ignore_ascii_case("native"))
+        {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "{OPT_KEY_TABLE_COMPRESSION_LEVEL} is not supported by the native storage format"
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_ttl(
+    ctx: Arc<QueryContext>,
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_TTL) {
+        parse_ttl_expr(ctx, schema, value)?;
+    }
+    Ok(())
+}
+
 pub fn is_valid_random_seed(options: &BTreeMap<String, String>) -> Result<()> {
     if let Some(value) = options.get(OPT_KEY_RANDOM_SEED) {
         value.parse::<u64>()?;
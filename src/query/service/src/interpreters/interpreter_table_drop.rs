@@ -15,6 +15,9 @@
 use std::sync::Arc;
 
 use databend_common_catalog::table::TableExt;
+use databend_common_catalog::table_events::notify_table_event;
+use databend_common_catalog::table_events::TableEvent;
+use databend_common_catalog::table_events::TableEventType;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_management::RoleApi;
@@ -126,6 +129,14 @@ impl Interpreter for DropTableInterpreter {
                 db_id: db.get_db_info().ident.db_id,
             })
             .await?;
+        notify_table_event(TableEvent::create(
+            TableEventType::TableDropped,
+            tenant.tenant_name(),
+            catalog_name,
+            db_name,
+            tbl_name,
+            table_id,
+        ));
 
         // we should do `drop ownership` after actually drop table, otherwise when we drop the ownership,
         // but the table still exists, in the interval maybe some unexpected things will happen.
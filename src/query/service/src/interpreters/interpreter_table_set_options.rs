@@ -29,10 +29,14 @@ use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use log::error;
 
+use super::interpreter_table_create::is_valid_block_checksum;
 use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
+use super::interpreter_table_create::is_valid_compression_level;
 use super::interpreter_table_create::is_valid_create_opt;
 use super::interpreter_table_create::is_valid_row_per_block;
+use super::interpreter_table_create::is_valid_tiering_age_days;
+use super::interpreter_table_create::is_valid_ttl;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -66,6 +70,10 @@ impl Interpreter for SetOptionsInterpreter {
         is_valid_block_per_segment(&self.plan.set_options)?;
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
+        // check tiering_age_days
+        is_valid_tiering_age_days(&self.plan.set_options)?;
+        // check block_checksum
+        is_valid_block_checksum(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.contains_key(OPT_KEY_STORAGE_FORMAT) {
@@ -92,6 +100,9 @@ impl Interpreter for SetOptionsInterpreter {
             }
             options_map.insert(key, Some(table_option.1.clone()));
         }
+        for key in self.plan.unset_options.iter() {
+            options_map.insert(key.to_lowercase(), None);
+        }
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
         let database = self.plan.database.as_str();
         let table_name = self.plan.table.as_str();
@@ -117,6 +128,15 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check ttl.
+        is_valid_ttl(self.ctx.clone(), &self.plan.set_options, table.schema())?;
+        // check compression_level against the compression of the table after the change.
+        let mut new_options = table.options().clone();
+        new_options.extend(self.plan.set_options.clone());
+        for key in self.plan.unset_options.iter() {
+            new_options.remove(&key.to_lowercase());
+        }
+        is_valid_compression_level(&new_options)?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
@@ -26,9 +26,12 @@ use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
 use databend_common_expression::Value;
+use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_sql::plans::ShowCreateTablePlan;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_storages_view::view_table::is_secure_view;
+use databend_common_storages_view::view_table::PARAMETERS;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_internal_opt_key;
@@ -87,13 +90,22 @@ impl Interpreter for ShowCreateTableInterpreter {
                 .unwrap_or(false),
         };
 
-        let create_query = Self::show_create_query(
-            catalog.as_ref(),
-            &self.plan.database,
-            table.as_ref(),
-            &settings,
-        )
-        .await?;
+        let create_query = if is_secure_view(table.options()) && !self.is_owner(&table).await? {
+            // Hide the definition of a secure view from non-owners.
+            format!(
+                "CREATE SECURE VIEW `{}`.`{}`",
+                self.plan.database,
+                table.name()
+            )
+        } else {
+            Self::show_create_query(
+                catalog.as_ref(),
+                &self.plan.database,
+                table.as_ref(),
+                &settings,
+            )
+            .await?
+        };
 
         let block = DataBlock::new(
             vec![
@@ -114,6 +126,26 @@ impl Interpreter for ShowCreateTableInterpreter {
 }
 
 impl ShowCreateTableInterpreter {
+    async fn is_owner(&self, table: &Arc<dyn Table>) -> Result<bool> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+        let db_id = catalog
+            .get_database(&tenant, &self.plan.database)
+            .await?
+            .get_db_info()
+            .ident
+            .db_id;
+        let object = OwnershipObject::Table {
+            catalog_name: self.plan.catalog.clone(),
+            db_id,
+            table_id: table.get_id(),
+        };
+        self.ctx
+            .get_current_session()
+            .has_ownership(&object, false)
+            .await
+    }
+
     pub async fn show_create_query(
         catalog: &dyn Catalog,
         database: &str,
@@ -251,7 +283,8 @@ impl ShowCreateTableInterpreter {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
         }
 
-        if !hide_options_in_show_create_table || engine == "ICEBERG" || engine == "DELTA" {
+        let is_datalake = engine == "ICEBERG" || engine == "DELTA";
+        if !hide_options_in_show_create_table || is_datalake {
             table_create_sql.push_str({
                 let mut opts = table_info.options().iter().collect::<Vec<_>>();
                 opts.sort_by_key(|(k, _)| *k);
@@ -264,12 +297,17 @@ impl ShowCreateTableInterpreter {
             });
         }
 
-        if engine != "ICEBERG" && engine != "DELTA" {
+        if !is_datalake {
             if let Some(sp) = &table_info.meta.storage_params {
                 table_create_sql.push_str(format!(" LOCATION = '{}'", sp).as_str());
             }
         }
 
+        // Only parquet files are supported by EXTERNAL tables for now.
+        if engine == "EXTERNAL" {
+            table_create_sql.push_str(" FILE_FORMAT = (TYPE = PARQUET)");
+        }
+
         if !table_info.meta.comment.is_empty() {
             table_create_sql.push_str(format!(" COMMENT = '{}'", table_info.meta.comment).as_str());
         }
@@ -279,9 +317,19 @@ impl ShowCreateTableInterpreter {
     fn show_create_view_query(table: &dyn Table, database: &str) -> Result<String> {
         let name = table.name();
         let view_create_sql = if let Some(query) = table.options().get(QUERY) {
+            let parameters = table
+                .options()
+                .get(PARAMETERS)
+                .map(|parameters| format!(" ({parameters})"))
+                .unwrap_or_default();
+            let secure = if is_secure_view(table.options()) {
+                "SECURE "
+            } else {
+                ""
+            };
             Ok(format!(
-                "CREATE VIEW `{}`.`{}` AS {}",
-                database, name, query
+                "CREATE {}VIEW `{}`.`{}`{} AS {}",
+                secure, database, name, parameters, query
             ))
         } else {
             Err(ErrorCode::Internal(
@@ -14,8 +14,11 @@
 
 use std::sync::Arc;
 
+use chrono::DateTime;
 use chrono::Duration;
+use chrono::Utc;
 use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::UInt64Type;
@@ -23,6 +26,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_license::license::Feature::Vacuum;
 use databend_common_license::license_manager::get_license_manager;
+use databend_common_sql::plans::VacuumTableOption;
 use databend_common_sql::plans::VacuumTablePlan;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::FUSE_TBL_BLOCK_PREFIX;
@@ -95,6 +99,20 @@ impl VacuumTableInterpreter {
     }
 }
 
+/// The files of the snapshots older than the returned time are purged, it is given by
+/// the `RETAIN` clause, or the `data_retention_time_in_days` setting otherwise.
+pub(crate) fn vacuum_retention_time(
+    ctx: &QueryContext,
+    option: &VacuumTableOption,
+) -> Result<DateTime<Utc>> {
+    let duration = match option.retain {
+        Some(retain) => Duration::from_std(retain)
+            .map_err(|e| ErrorCode::BadArguments(format!("invalid RETAIN duration: {e}")))?,
+        None => Duration::days(ctx.get_settings().get_data_retention_time_in_days()? as i64),
+    };
+    Ok(Utc::now() - duration)
+}
+
 #[async_trait::async_trait]
 impl Interpreter for VacuumTableInterpreter {
     fn name(&self) -> &str {
@@ -124,10 +142,7 @@ impl Interpreter for VacuumTableInterpreter {
         // check mutability
         table.check_mutable()?;
 
-        let duration = Duration::days(ctx.get_settings().get_data_retention_time_in_days()? as i64);
-
-        let retention_time = chrono::Utc::now() - duration;
-        let ctx = self.ctx.clone();
+        let retention_time = vacuum_retention_time(&ctx, &self.plan.option)?;
 
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         let handler = get_vacuum_handler();
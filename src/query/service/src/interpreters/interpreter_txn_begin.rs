@@ -14,6 +14,8 @@
 
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_exception::Result;
 use databend_common_storages_fuse::TableContext;
 use databend_storages_common_txn::TxnManagerRef;
@@ -23,12 +25,14 @@ use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 pub struct BeginInterpreter {
     txn_manager: TxnManagerRef,
+    read_only_at: Option<DateTime<Utc>>,
 }
 
 impl BeginInterpreter {
-    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+    pub fn try_create(ctx: Arc<QueryContext>, read_only_at: Option<DateTime<Utc>>) -> Result<Self> {
         Ok(Self {
             txn_manager: ctx.txn_mgr(),
+            read_only_at,
         })
     }
 }
@@ -49,7 +53,10 @@ impl Interpreter for BeginInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        self.txn_manager.lock().begin();
+        match self.read_only_at {
+            Some(at) => self.txn_manager.lock().begin_read_only(at),
+            None => self.txn_manager.lock().begin(),
+        }
         Ok(PipelineBuildResult::create())
     }
 }
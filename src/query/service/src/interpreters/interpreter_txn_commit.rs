@@ -14,12 +14,17 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::table_events::notify_table_event;
+use databend_common_catalog::table_events::TableEvent;
+use databend_common_catalog::table_events::TableEventType;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_storages_fuse::TableContext;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_txn::TxnManagerRef;
 use log::error;
 use log::info;
+use log::warn;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -73,6 +78,18 @@ impl Interpreter for CommitInterpreter {
                     .collect::<Vec<_>>();
                 (table_descriptions, stream_descriptions)
             };
+            let committed_tables = req
+                .update_table_metas
+                .iter()
+                .map(|(req, table_info)| {
+                    let snapshot_location = req
+                        .new_table_meta
+                        .options
+                        .get(OPT_KEY_SNAPSHOT_LOCATION)
+                        .cloned();
+                    (table_info.clone(), snapshot_location)
+                })
+                .collect::<Vec<_>>();
 
             let mismatched_tids = {
                 self.ctx.txn_mgr().lock().set_auto_commit();
@@ -105,6 +122,41 @@ impl Interpreter for CommitInterpreter {
                     return Err(ErrorCode::TableVersionMismatched(err_msg));
                 }
             }
+
+            // the table events of the transaction are published only after it is committed
+            let compacted_tables = self.ctx.txn_mgr().lock().compacted_tables();
+            for (table_info, snapshot_location) in committed_tables {
+                let mut event = match TableEvent::from_table_info(
+                    self.ctx.as_ref(),
+                    TableEventType::SnapshotCommitted,
+                    &table_info,
+                )
+                .await
+                {
+                    Ok(event) => event,
+                    Err(e) => {
+                        warn!(
+                            "COMMIT: failed to build the table event of {}: {}",
+                            table_info.desc, e
+                        );
+                        continue;
+                    }
+                };
+                if let Some(location) = snapshot_location {
+                    event = event.with_snapshot_location(location);
+                }
+                let compaction = compacted_tables
+                    .contains(&table_info.ident.table_id)
+                    .then(|| TableEvent {
+                        event_type: TableEventType::CompactionFinished,
+                        snapshot_location: None,
+                        ..event.clone()
+                    });
+                notify_table_event(event);
+                if let Some(compaction) = compaction {
+                    notify_table_event(compaction);
+                }
+            }
             let need_purge_files = self.ctx.txn_mgr().lock().need_purge_files();
             for (stage_info, files) in need_purge_files {
                 PipelineBuilder::try_purge_files(self.ctx.clone(), &stage_info, &files).await;
@@ -114,7 +166,7 @@ impl Interpreter for CommitInterpreter {
     }
 }
 
-struct ClearTxnManagerGuard(TxnManagerRef);
+pub(crate) struct ClearTxnManagerGuard(pub(crate) TxnManagerRef);
 
 impl Drop for ClearTxnManagerGuard {
     fn drop(&mut self) {
@@ -153,7 +153,8 @@ impl Interpreter for UnSetInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         match self.set.unset_type {
             databend_common_ast::ast::SetType::SettingsSession
-            | databend_common_ast::ast::SetType::SettingsGlobal => {
+            | databend_common_ast::ast::SetType::SettingsGlobal
+            | databend_common_ast::ast::SetType::SettingsGlobalLocked => {
                 self.execute_unset_settings().await?
             }
             databend_common_ast::ast::SetType::Variable => self.execute_unset_variables().await?,
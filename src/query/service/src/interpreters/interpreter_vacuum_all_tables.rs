@@ -0,0 +1,166 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::runtime::execute_futures_in_parallel;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_license::license::Feature::Vacuum;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_sql::plans::VacuumAllTablesPlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_enterprise_vacuum_handler::get_vacuum_handler;
+use log::info;
+use log::warn;
+
+use crate::interpreters::interpreter_table_vacuum::vacuum_retention_time;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct VacuumAllTablesInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: VacuumAllTablesPlan,
+}
+
+impl VacuumAllTablesInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: VacuumAllTablesPlan) -> Result<Self> {
+        Ok(VacuumAllTablesInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for VacuumAllTablesInterpreter {
+    fn name(&self) -> &str {
+        "VacuumAllTablesInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Vacuum)?;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let databases = match &self.plan.database {
+            Some(database) => vec![catalog.get_database(&tenant, database).await?],
+            None => catalog.list_databases(&tenant).await?,
+        };
+
+        let retention_time = vacuum_retention_time(&self.ctx, &self.plan.option)?;
+        let dry_run = self.plan.option.dry_run.is_some();
+        let handler = get_vacuum_handler();
+        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
+
+        let mut table_names = vec![];
+        let mut files = vec![];
+        let mut file_sizes = vec![];
+        let mut total_files = vec![];
+        let mut total_sizes = vec![];
+        for database in databases {
+            for table in database.list_tables().await? {
+                // read-only and attached tables do not own the files they reference
+                if table.check_mutable().is_err()
+                    || FuseTable::is_table_attached(&table.get_table_info().meta.options)
+                {
+                    continue;
+                }
+                let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+                    continue;
+                };
+                let table_name = format!("{}.{}", database.name(), table.name());
+                let purge_files = match handler
+                    .do_vacuum(fuse_table, self.ctx.clone(), retention_time, dry_run)
+                    .await
+                {
+                    Ok(purge_files) => purge_files,
+                    Err(e) => {
+                        // keep going, a table failing to vacuum should not block the others
+                        warn!("vacuum table {} failed: {}", table_name, e);
+                        continue;
+                    }
+                };
+                info!("vacuum table {} done", table_name);
+
+                let Some(purge_files) = purge_files else {
+                    table_names.push(table_name);
+                    continue;
+                };
+                let operator = fuse_table.get_operator();
+                let stats = execute_futures_in_parallel(
+                    purge_files.into_iter().map(|file| {
+                        let operator = operator.clone();
+                        async move {
+                            let stat = operator.stat(&file).await;
+                            (file, stat)
+                        }
+                    }),
+                    max_threads,
+                    max_threads * 2,
+                    "vacuum-stat-worker".to_owned(),
+                )
+                .await?;
+                let mut purge_files = Vec::with_capacity(stats.len());
+                let mut sizes = Vec::with_capacity(stats.len());
+                for (file, stat) in stats {
+                    match stat {
+                        Ok(metadata) => {
+                            purge_files.push(file);
+                            sizes.push(metadata.content_length());
+                        }
+                        // the file may be gone since it was listed, e.g. removed by a concurrent vacuum
+                        Err(e) => warn!("vacuum table {} stat {} failed: {}", table_name, file, e),
+                    }
+                }
+                // when `purge_files` is some, it means `dry_run` is some, so safe to unwrap()
+                if self.plan.option.dry_run.unwrap() {
+                    table_names.push(table_name);
+                    total_files.push(purge_files.len() as u64);
+                    total_sizes.push(sizes.into_iter().sum::<u64>());
+                } else {
+                    table_names.extend(std::iter::repeat(table_name).take(purge_files.len()));
+                    files.extend(purge_files);
+                    file_sizes.extend(sizes);
+                }
+            }
+        }
+
+        let columns = match self.plan.option.dry_run {
+            None => vec![StringType::from_data(table_names)],
+            Some(true) => vec![
+                StringType::from_data(table_names),
+                UInt64Type::from_data(total_files),
+                UInt64Type::from_data(total_sizes),
+            ],
+            Some(false) => vec![
+                StringType::from_data(table_names),
+                StringType::from_data(files),
+                UInt64Type::from_data(file_sizes),
+            ],
+        };
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(columns)])
+    }
+}
@@ -14,6 +14,7 @@
 
 use std::cmp::min;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use chrono::Duration;
@@ -31,6 +32,7 @@ use databend_common_meta_app::schema::GcDroppedTableReq;
 use databend_common_meta_app::schema::ListDroppedTableReq;
 use databend_common_meta_app::schema::TableInfoFilter;
 use databend_common_sql::plans::VacuumDropTablePlan;
+use databend_common_storages_fuse::FuseTable;
 use databend_enterprise_vacuum_handler::get_vacuum_handler;
 use log::info;
 
@@ -151,10 +153,50 @@ impl Interpreter for VacuumDropTablesInterpreter {
 
         // TODO buggy, table as catalog obj should be allowed to drop
         // also drop ids
-        // filter out read-only tables
+        // filter out read-only tables, and attached tables whose data is not owned by them
         let tables = tables
             .into_iter()
-            .filter(|tbl| !tbl.as_ref().is_read_only())
+            .filter(|tbl| {
+                !tbl.as_ref().is_read_only()
+                    && !FuseTable::is_table_attached(&tbl.get_table_info().meta.options)
+            })
+            .collect::<Vec<_>>();
+
+        // tables whose files are still referenced by a clone are kept (both data and meta),
+        // they will be vacuumed once the clone is gone.
+        let mut tables_to_vacuum = Vec::with_capacity(tables.len());
+        let mut retained_table_ids = HashSet::new();
+        for table in tables {
+            if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+                let shared = fuse_table.shared_file_locations(ctx.clone()).await?;
+                if !shared.is_empty() {
+                    info!(
+                        "vacuum drop table {:?} skipped, {} files are still referenced by clones",
+                        table.name(),
+                        shared.len()
+                    );
+                    retained_table_ids.insert(table.get_id());
+                    continue;
+                }
+            }
+            tables_to_vacuum.push(table);
+        }
+        let tables = tables_to_vacuum;
+        let retained_db_ids = drop_ids
+            .iter()
+            .filter_map(|id| match id {
+                DroppedId::Table(db_id, table_id, _) if retained_table_ids.contains(table_id) => {
+                    Some(*db_id)
+                }
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+        let drop_ids = drop_ids
+            .into_iter()
+            .filter(|id| match id {
+                DroppedId::Db(db_id, _) => !retained_db_ids.contains(db_id),
+                DroppedId::Table(_, table_id, _) => !retained_table_ids.contains(table_id),
+            })
             .collect::<Vec<_>>();
 
         let handler = get_vacuum_handler();
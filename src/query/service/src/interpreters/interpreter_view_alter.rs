@@ -21,6 +21,7 @@ use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::AlterViewPlan;
 use databend_common_sql::Planner;
+use databend_common_storages_view::view_table::PARAMETERS;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -59,14 +60,17 @@ impl Interpreter for AlterViewInterpreter {
             let subquery = if self.plan.column_names.is_empty() {
                 self.plan.subquery.clone()
             } else {
-                let mut planner = Planner::new(self.ctx.clone());
-                let (plan, _) = planner.plan_sql(&self.plan.subquery.clone()).await?;
-                if plan.schema().fields().len() != self.plan.column_names.len() {
-                    return Err(ErrorCode::BadDataArrayLength(format!(
-                        "column name length mismatch, expect {}, got {}",
-                        plan.schema().fields().len(),
-                        self.plan.column_names.len(),
-                    )));
+                // The query of a parameterized view has been checked by the binder.
+                if self.plan.parameters.is_none() {
+                    let mut planner = Planner::new(self.ctx.clone());
+                    let (plan, _) = planner.plan_sql(&self.plan.subquery.clone()).await?;
+                    if plan.schema().fields().len() != self.plan.column_names.len() {
+                        return Err(ErrorCode::BadDataArrayLength(format!(
+                            "column name length mismatch, expect {}, got {}",
+                            plan.schema().fields().len(),
+                            self.plan.column_names.len(),
+                        )));
+                    }
                 }
                 format!(
                     "select * from ({}) {}({})",
@@ -76,6 +80,7 @@ impl Interpreter for AlterViewInterpreter {
                 )
             };
             options.insert("query".to_string(), Some(subquery));
+            options.insert(PARAMETERS.to_string(), self.plan.parameters.clone());
 
             let req = UpsertTableOptionReq {
                 table_id: tbl.get_id(),
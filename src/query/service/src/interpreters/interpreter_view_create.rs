@@ -17,14 +17,21 @@ use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_sql::plans::CreateViewPlan;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::Planner;
+use databend_common_storages_view::view_table::PARAMETERS;
 use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::SECURE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -58,45 +65,54 @@ impl Interpreter for CreateViewInterpreter {
         let tenant = self.ctx.get_tenant();
         let table_function = catalog.list_table_functions();
         let mut options = BTreeMap::new();
-        let mut planner = Planner::new(self.ctx.clone());
-        let (plan, _) = planner.plan_sql(&self.plan.subquery.clone()).await?;
-        match plan.clone() {
-            Plan::Query { metadata, .. } => {
-                let metadata = metadata.read().clone();
-                for table in metadata.tables() {
-                    let database_name = table.database();
-                    let table_name = table.name();
-                    if !catalog
-                        .exists_table(&tenant, database_name, table_name)
-                        .await?
-                        && !table_function.contains(&table_name.to_string())
-                        && !table.table().is_stage_table()
-                    {
-                        return Err(databend_common_exception::ErrorCode::UnknownTable(format!(
-                            "VIEW QUERY: table `{}`.`{}` not exists in catalog '{}'",
-                            database_name,
-                            table_name,
-                            &catalog.name()
-                        )));
+        // The query of a parameterized view can't be planned without the
+        // arguments, it has been checked by the binder.
+        if self.plan.parameters.is_none() {
+            let mut planner = Planner::new(self.ctx.clone());
+            let (plan, _) = planner.plan_sql(&self.plan.subquery).await?;
+            match plan.clone() {
+                Plan::Query { metadata, .. } => {
+                    let metadata = metadata.read().clone();
+                    for table in metadata.tables() {
+                        let database_name = table.database();
+                        let table_name = table.name();
+                        if !catalog
+                            .exists_table(&tenant, database_name, table_name)
+                            .await?
+                            && !table_function.contains(&table_name.to_string())
+                            && !table.table().is_stage_table()
+                        {
+                            return Err(databend_common_exception::ErrorCode::UnknownTable(
+                                format!(
+                                    "VIEW QUERY: table `{}`.`{}` not exists in catalog '{}'",
+                                    database_name,
+                                    table_name,
+                                    &catalog.name()
+                                ),
+                            ));
+                        }
                     }
                 }
+                _ => {
+                    // This logic will never be used, because of QUERY parse as query
+                    return Err(ErrorCode::Unimplemented("create view only support Query"));
+                }
             }
-            _ => {
-                // This logic will never be used, because of QUERY parse as query
-                return Err(ErrorCode::Unimplemented("create view only support Query"));
-            }
-        }
 
-        let subquery = if self.plan.column_names.is_empty() {
-            self.plan.subquery.clone()
-        } else {
-            if plan.schema().fields().len() != self.plan.column_names.len() {
+            if !self.plan.column_names.is_empty()
+                && plan.schema().fields().len() != self.plan.column_names.len()
+            {
                 return Err(ErrorCode::BadDataArrayLength(format!(
                     "column name length mismatch, expect {}, got {}",
                     plan.schema().fields().len(),
                     self.plan.column_names.len(),
                 )));
             }
+        }
+
+        let subquery = if self.plan.column_names.is_empty() {
+            self.plan.subquery.clone()
+        } else {
             format!(
                 "select * from ({}) {}({})",
                 self.plan.subquery,
@@ -105,6 +121,12 @@ impl Interpreter for CreateViewInterpreter {
             )
         };
         options.insert(QUERY.to_string(), subquery);
+        if let Some(parameters) = &self.plan.parameters {
+            options.insert(PARAMETERS.to_string(), parameters.clone());
+        }
+        if self.plan.secure {
+            options.insert(SECURE.to_string(), "true".to_string());
+        }
 
         let plan = CreateTableReq {
             create_option: self.plan.create_option,
@@ -120,7 +142,28 @@ impl Interpreter for CreateViewInterpreter {
             },
             as_dropped: false,
         };
-        catalog.create_table(plan).await?;
+        let reply = catalog.create_table(plan).await?;
+
+        // The definition of a secure view is only visible to its owner,
+        // so grant the ownership of the view to the current role.
+        if self.plan.secure
+            && (reply.new_table || self.plan.create_option == CreateOption::CreateOrReplace)
+        {
+            if let Some(current_role) = self.ctx.get_current_role() {
+                let role_api = UserApiProvider::instance().role_api(&tenant);
+                role_api
+                    .grant_ownership(
+                        &OwnershipObject::Table {
+                            catalog_name: self.plan.catalog.clone(),
+                            db_id: reply.db_id,
+                            table_id: reply.table_id,
+                        },
+                        &current_role.name,
+                    )
+                    .await?;
+                RoleCacheManager::instance().invalidate_cache(&tenant);
+            }
+        }
 
         Ok(PipelineBuildResult::create())
     }
@@ -48,6 +48,8 @@ mod interpreter_index_refresh;
 mod interpreter_insert;
 mod interpreter_insert_multi_table;
 mod interpreter_kill;
+mod interpreter_materialized_view_create;
+mod interpreter_materialized_view_refresh;
 mod interpreter_metrics;
 mod interpreter_mutation;
 mod interpreter_network_policies_show;
@@ -127,6 +129,7 @@ mod interpreter_tasks_show;
 mod interpreter_txn_abort;
 mod interpreter_txn_begin;
 mod interpreter_txn_commit;
+mod interpreter_txn_set_snapshot;
 mod interpreter_unset;
 mod interpreter_use_database;
 mod interpreter_user_alter;
@@ -138,6 +141,7 @@ mod interpreter_user_stage_remove;
 mod interpreter_user_udf_alter;
 mod interpreter_user_udf_create;
 mod interpreter_user_udf_drop;
+mod interpreter_vacuum_all_tables;
 mod interpreter_vacuum_drop_tables;
 mod interpreter_vacuum_temporary_files;
 mod interpreter_view_alter;
@@ -152,6 +156,7 @@ mod util;
 
 pub use access::ManagementModeAccess;
 pub use common::InterpreterQueryLog;
+pub use common::TableEventNotifier;
 pub use hook::HookOperator;
 pub use interpreter::interpreter_plan_sql;
 pub use interpreter::Interpreter;
@@ -174,6 +179,8 @@ pub use interpreter_index_refresh::RefreshIndexInterpreter;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_insert_multi_table::InsertMultiTableInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_materialized_view_create::CreateMaterializedViewInterpreter;
+pub use interpreter_materialized_view_refresh::RefreshMaterializedViewInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
 pub use interpreter_mutation::MutationInterpreter;
 pub use interpreter_network_policies_show::ShowNetworkPoliciesInterpreter;
@@ -247,6 +254,7 @@ pub use interpreter_user_stage_remove::RemoveUserStageInterpreter;
 pub use interpreter_user_udf_alter::AlterUserUDFScript;
 pub use interpreter_user_udf_create::CreateUserUDFScript;
 pub use interpreter_user_udf_drop::DropUserUDFScript;
+pub use interpreter_vacuum_all_tables::VacuumAllTablesInterpreter;
 pub use interpreter_vacuum_drop_tables::VacuumDropTablesInterpreter;
 pub use interpreter_vacuum_temporary_files::VacuumTemporaryFilesInterpreter;
 pub use interpreter_view_alter::AlterViewInterpreter;
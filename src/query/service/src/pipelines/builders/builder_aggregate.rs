@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::with_hash_method;
 use databend_common_expression::with_mappedhash_method;
@@ -23,6 +25,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::HashMethodKind;
 use databend_common_expression::HashTableConfig;
+use databend_common_expression::SortColumnDescription;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::query_spill_prefix;
@@ -40,11 +43,13 @@ use crate::pipelines::processors::transforms::aggregator::AggregateInjector;
 use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
 use crate::pipelines::processors::transforms::aggregator::FinalSingleStateAggregator;
 use crate::pipelines::processors::transforms::aggregator::PartialSingleStateAggregator;
+use crate::pipelines::processors::transforms::aggregator::ProgressiveResultPublisher;
 use crate::pipelines::processors::transforms::aggregator::TransformAggregateSpillWriter;
 use crate::pipelines::processors::transforms::aggregator::TransformExpandGroupingSets;
 use crate::pipelines::processors::transforms::aggregator::TransformGroupBySpillWriter;
 use crate::pipelines::processors::transforms::aggregator::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::aggregator::TransformPartialGroupBy;
+use crate::pipelines::processors::transforms::aggregator::TransformSortedAggregate;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -116,8 +121,12 @@ impl PipelineBuilder {
         )?;
 
         if params.group_columns.is_empty() {
+            let flush_interval = match self.settings.get_progressive_aggregate_interval_secs()? {
+                0 => None,
+                secs => Some(Duration::from_secs(secs)),
+            };
             return self.main_pipeline.try_add_accumulating_transformer(|| {
-                PartialSingleStateAggregator::try_new(&params)
+                PartialSingleStateAggregator::try_new(&params, flush_interval)
             });
         }
 
@@ -212,6 +221,10 @@ impl PipelineBuilder {
     }
 
     pub(crate) fn build_aggregate_final(&mut self, aggregate: &AggregateFinal) -> Result<()> {
+        if aggregate.sort_based {
+            return self.build_sorted_aggregate(aggregate);
+        }
+
         let max_block_size = self.settings.get_max_block_size()?;
         let enable_experimental_aggregate_hashtable = self
             .settings
@@ -230,9 +243,22 @@ impl PipelineBuilder {
         if params.group_columns.is_empty() {
             self.build_pipeline(&aggregate.input)?;
             self.main_pipeline.try_resize(1)?;
+
+            let interval = self.settings.get_progressive_aggregate_interval_secs()?;
+            let projection = self
+                .ctx
+                .get_progressive_result_projection(aggregate.plan_id);
             self.main_pipeline.add_transform(|input, output| {
+                let publisher = match (interval, projection.clone()) {
+                    (0, _) | (_, None) => None,
+                    (secs, Some(projection)) => Some(ProgressiveResultPublisher::create(
+                        self.ctx.clone(),
+                        projection,
+                        Duration::from_secs(secs),
+                    )),
+                };
                 Ok(ProcessorPtr::create(
-                    FinalSingleStateAggregator::try_create(input, output, &params)?,
+                    FinalSingleStateAggregator::try_create(input, output, &params, publisher)?,
                 ))
             })?;
 
@@ -283,6 +309,51 @@ impl PipelineBuilder {
         }
     }
 
+    /// Sorts the input of the partial aggregation by the group keys and aggregates it in
+    /// one pass, the partial aggregation itself is skipped.
+    fn build_sorted_aggregate(&mut self, aggregate: &AggregateFinal) -> Result<()> {
+        let PhysicalPlan::AggregatePartial(partial) = aggregate.input.as_ref() else {
+            return Err(ErrorCode::Internal(format!(
+                "invalid input of sort based aggregation: {}",
+                aggregate.input.name(),
+            )));
+        };
+
+        let max_block_size = self.settings.get_max_block_size()?;
+        let params = Self::build_aggregator_params(
+            aggregate.before_group_by_schema.clone(),
+            &aggregate.group_by,
+            &aggregate.agg_funcs,
+            false,
+            false,
+            max_block_size as usize,
+            None,
+        )?;
+
+        self.build_pipeline(&partial.input)?;
+
+        let schema = aggregate.before_group_by_schema.clone();
+        let nulls_first = !self.settings.get_sql_dialect()?.is_null_biggest();
+        let sort_desc = params
+            .group_columns
+            .iter()
+            .map(|&offset| SortColumnDescription {
+                offset,
+                asc: true,
+                nulls_first,
+                is_nullable: schema.field(offset).is_nullable(),
+            })
+            .collect();
+        self.build_sort_pipeline(schema, sort_desc, None, None)?;
+
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(TransformSortedAggregate::try_create(
+                input, output, &params,
+            )?))
+        })
+    }
+
     pub fn build_aggregator_params(
         input_schema: DataSchemaRef,
         group_by: &[IndexType],
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_base::base::tokio::sync::Barrier;
@@ -22,6 +24,7 @@ use databend_common_sql::executor::physical_plans::HashJoin;
 use databend_common_sql::executor::physical_plans::MaterializedCte;
 use databend_common_sql::executor::physical_plans::RangeJoin;
 use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::plans::JoinType;
 use databend_common_sql::ColumnBinding;
 use databend_common_sql::IndexType;
 
@@ -96,20 +99,59 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    /// Collect the signatures of the hash join build sides which occur more than once in `plan`.
+    /// Only used for local plans, the hash tables of a distributed plan are never shared.
+    pub fn collect_shared_hash_join_keys(plan: &PhysicalPlan) -> HashSet<String> {
+        fn collect(plan: &PhysicalPlan, counts: &mut HashMap<String, usize>) {
+            if let PhysicalPlan::HashJoin(join) = plan {
+                if let Some(key) = shared_hash_join_key(join) {
+                    *counts.entry(key).or_default() += 1;
+                }
+            }
+            for child in plan.children() {
+                collect(child, counts);
+            }
+        }
+
+        let mut counts = HashMap::new();
+        collect(plan, &mut counts);
+        counts
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(key, _)| key)
+            .collect()
+    }
+
     pub(crate) fn build_join(&mut self, join: &HashJoin) -> Result<()> {
         // for merge into target table as build side.
         let (enable_merge_into_optimization, merge_into_is_distributed) =
             self.merge_into_get_optimization_flag(join);
 
+        let shared_key = match enable_merge_into_optimization {
+            true => None,
+            false => shared_hash_join_key(join).filter(|key| self.ctx.is_shared_hash_join_key(key)),
+        };
+        if let Some(key) = &shared_key {
+            if let Some(state) = self.ctx.get_shared_hash_join_state(key) {
+                // The same build side has been built by another join of this query,
+                // probe its hash table directly.
+                return self.build_join_probe(join, state);
+            }
+        }
+
         let state = self.build_join_state(
             join,
             merge_into_is_distributed,
             enable_merge_into_optimization,
+            shared_key.is_some(),
         )?;
         if let Some((build_cache_index, _)) = join.build_side_cache_info {
             self.hash_join_states
                 .insert(build_cache_index, state.clone());
         }
+        if let Some(key) = &shared_key {
+            self.ctx.set_shared_hash_join_state(key, state.clone());
+        }
         self.expand_build_side_pipeline(&join.build, join, state.clone())?;
         self.build_join_probe(join, state)
     }
@@ -119,6 +161,7 @@ impl PipelineBuilder {
         join: &HashJoin,
         merge_into_is_distributed: bool,
         enable_merge_into_optimization: bool,
+        shared_build_side: bool,
     ) -> Result<Arc<HashJoinState>> {
         HashJoinState::try_create(
             self.ctx.clone(),
@@ -129,6 +172,7 @@ impl PipelineBuilder {
             merge_into_is_distributed,
             enable_merge_into_optimization,
             join.build_side_cache_info.clone(),
+            shared_build_side,
         )
     }
 
@@ -300,3 +344,53 @@ impl PipelineBuilder {
         Ok(())
     }
 }
+
+/// The signature of the build side of `join`, two joins with the same signature build
+/// identical hash tables. Returns `None` if the hash table of `join` can't be shared,
+/// e.g. the build side isn't a plain table scan or the probe phase mutates the build state.
+fn shared_hash_join_key(join: &HashJoin) -> Option<String> {
+    if !matches!(
+        join.join_type,
+        JoinType::Inner | JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti
+    ) || !join.non_equi_conditions.is_empty()
+        || join.from_correlated_subquery
+        || join.single_to_inner.is_some()
+        || join.need_hold_hash_table
+        || join.build_side_cache_info.is_some()
+    {
+        return None;
+    }
+    let PhysicalPlan::TableScan(scan) = join.build.as_ref() else {
+        return None;
+    };
+
+    // The table index and the column names differ between two scans of the same table,
+    // they don't affect the content of the hash table.
+    let mut source = scan.source.as_ref().clone();
+    source.table_index = 0;
+    let mut build_keys = serde_json::to_value(&join.build_keys).ok()?;
+    strip_expr_names(&mut build_keys);
+
+    serde_json::to_string(&(
+        source,
+        &scan.internal_column,
+        build_keys,
+        &join.is_null_equal,
+        &join.build_projections,
+        &join.probe_to_build,
+        &join.join_type,
+    ))
+    .ok()
+}
+
+fn strip_expr_names(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.remove("span");
+            map.remove("display_name");
+            map.values_mut().for_each(strip_expr_names);
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(strip_expr_names),
+        _ => {}
+    }
+}
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_ast::parser::parse_comma_separated_exprs;
 use databend_common_ast::parser::tokenize_sql;
+use databend_common_base::base::tokio::sync::mpsc::Receiver;
 use databend_common_base::base::tokio::sync::Semaphore;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -42,6 +43,7 @@ use databend_common_sql::executor::physical_plans::ReplaceAsyncSourcer;
 use databend_common_sql::executor::physical_plans::ReplaceDeduplicate;
 use databend_common_sql::executor::physical_plans::ReplaceInto;
 use databend_common_sql::executor::physical_plans::ReplaceSelectCtx;
+use databend_common_sql::plans::InsertBlockReceiver;
 use databend_common_sql::plans::InsertValue;
 use databend_common_sql::BindContext;
 use databend_common_sql::Metadata;
@@ -387,6 +389,48 @@ impl PipelineBuilder {
     }
 }
 
+/// Source of the data blocks pushed by the client, the bounded channel
+/// makes the client wait while the pipeline is busy.
+pub struct BlockStreamSource {
+    receiver: Receiver<Result<DataBlock>>,
+    schema: DataSchemaRef,
+}
+
+impl BlockStreamSource {
+    pub fn try_create(receiver: &InsertBlockReceiver, schema: DataSchemaRef) -> Result<Self> {
+        let receiver = receiver
+            .lock()
+            .take()
+            .ok_or_else(|| ErrorCode::Internal("the insert data stream has been consumed"))?;
+        Ok(Self { receiver, schema })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for BlockStreamSource {
+    const NAME: &'static str = "BlockStreamSource";
+    const SKIP_EMPTY_DATA_BLOCK: bool = true;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        match self.receiver.recv().await {
+            Some(block) => {
+                let block = block?;
+                if block.num_columns() != self.schema.num_fields() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "expect {} columns, but got {}",
+                        self.schema.num_fields(),
+                        block.num_columns()
+                    )));
+                }
+                Ok(Some(block))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
 pub struct ValueSource {
     rows: Arc<Vec<Vec<Scalar>>>,
     schema: DataSchemaRef,
@@ -769,6 +769,10 @@ impl RunningGraph {
                             plan_profile.statistics[index] +=
                                 profile.statistics[index].fetch_min(0, Ordering::SeqCst);
                         }
+
+                        let decoded_column_bytes =
+                            std::mem::take(&mut *profile.decoded_column_bytes.lock());
+                        plan_profile.add_decoded_column_bytes(decoded_column_bytes.iter());
                     }
                     Entry::Vacant(v) => {
                         let plan_profile = v.insert(PlanProfile::create(profile));
@@ -778,6 +782,10 @@ impl RunningGraph {
                                 profile.statistics[index].fetch_min(0, Ordering::SeqCst);
                         }
 
+                        let decoded_column_bytes =
+                            std::mem::take(&mut *profile.decoded_column_bytes.lock());
+                        plan_profile.add_decoded_column_bytes(decoded_column_bytes.iter());
+
                         let node_id = node_id.as_ref();
                         let metrics_registry = profile.metrics_registry.as_ref();
                         if let Some((id, metrics_registry)) = node_id.zip(metrics_registry) {
@@ -796,6 +804,22 @@ impl RunningGraph {
         plans_profile
     }
 
+    /// The ids of the plans whose processors recorded an error, without resetting the profiles.
+    pub fn get_failed_plan_ids(&self) -> Vec<u32> {
+        let mut plan_ids = self
+            .0
+            .graph
+            .node_weights()
+            .filter_map(|x| x.tracking_payload.profile.as_deref())
+            .filter(|profile| !profile.errors.lock().is_empty())
+            .filter_map(|profile| profile.plan_id)
+            .collect::<Vec<_>>();
+
+        plan_ids.sort();
+        plan_ids.dedup();
+        plan_ids
+    }
+
     pub fn interrupt_running_nodes(&self) {
         unsafe {
             for node_index in self.0.graph.node_indices() {
@@ -286,6 +286,13 @@ impl PipelineExecutor {
         }
     }
 
+    pub fn get_failed_plan_ids(&self) -> Vec<u32> {
+        match self {
+            PipelineExecutor::QueryPipelineExecutor(executor) => executor.get_failed_plan_ids(),
+            PipelineExecutor::QueriesPipelineExecutor(v) => v.graph.get_failed_plan_ids(),
+        }
+    }
+
     pub fn change_priority(&self, priority: u8) {
         match self {
             PipelineExecutor::QueryPipelineExecutor(_) => {
@@ -470,6 +470,10 @@ impl QueryPipelineExecutor {
             false => self.graph.fetch_profiling(None),
         }
     }
+
+    pub fn get_failed_plan_ids(&self) -> Vec<u32> {
+        self.graph.get_failed_plan_ids()
+    }
 }
 
 impl Drop for QueryPipelineExecutor {
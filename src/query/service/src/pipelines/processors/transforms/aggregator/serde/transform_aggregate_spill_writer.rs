@@ -44,6 +44,7 @@ use crate::pipelines::processors::transforms::aggregator::HashTablePayload;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
 use crate::sessions::QueryContext;
+use crate::spillers::SpillSpaceManager;
 
 pub struct TransformAggregateSpillWriter<Method: HashMethodBounds> {
     ctx: Arc<QueryContext>,
@@ -253,6 +254,9 @@ pub fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
         let mut write_bytes = 0;
 
         if !write_data.is_empty() {
+            SpillSpaceManager::instance()
+                .reserve_buckets(&ctx, &write_data)
+                .await?;
             let mut writer = operator
                 .writer_with(&location)
                 .chunk(8 * 1024 * 1024)
@@ -350,6 +354,9 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
         let mut write_bytes = 0;
 
         if !write_data.is_empty() {
+            SpillSpaceManager::instance()
+                .reserve_buckets(&ctx, &write_data)
+                .await?;
             let mut writer = operator
                 .writer_with(&location)
                 .chunk(8 * 1024 * 1024)
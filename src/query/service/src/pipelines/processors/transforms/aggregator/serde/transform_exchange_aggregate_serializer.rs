@@ -66,6 +66,7 @@ use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
 use crate::servers::flight::v1::exchange::serde::serialize_block;
 use crate::servers::flight::v1::exchange::ExchangeShuffleMeta;
 use crate::sessions::QueryContext;
+use crate::spillers::SpillSpaceManager;
 
 pub struct TransformExchangeAggregateSerializer<Method: HashMethodBounds> {
     ctx: Arc<QueryContext>,
@@ -296,6 +297,9 @@ fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
             let instant = Instant::now();
 
             let mut write_bytes = 0;
+            SpillSpaceManager::instance()
+                .reserve_buckets(&ctx, &write_data)
+                .await?;
             let mut writer = operator
                 .writer_with(&location)
                 .chunk(8 * 1024 * 1024)
@@ -417,6 +421,9 @@ fn spilling_aggregate_payload<Method: HashMethodBounds>(
             let instant = Instant::now();
 
             let mut write_bytes = 0;
+            SpillSpaceManager::instance()
+                .reserve_buckets(&ctx, &write_data)
+                .await?;
             let mut writer = operator
                 .writer_with(&location)
                 .chunk(8 * 1024 * 1024)
@@ -69,6 +69,7 @@ use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
 use crate::servers::flight::v1::exchange::serde::serialize_block;
 use crate::servers::flight::v1::exchange::ExchangeShuffleMeta;
 use crate::sessions::QueryContext;
+use crate::spillers::SpillSpaceManager;
 
 pub struct TransformExchangeGroupBySerializer<Method: HashMethodBounds> {
     ctx: Arc<QueryContext>,
@@ -350,6 +351,9 @@ fn agg_spilling_group_by_payload<Method: HashMethodBounds>(
 
         if !write_data.is_empty() {
             let mut write_bytes = 0;
+            SpillSpaceManager::instance()
+                .reserve_buckets(&ctx, &write_data)
+                .await?;
             let mut writer = operator
                 .writer_with(&location)
                 .chunk(8 * 1024 * 1024)
@@ -469,6 +473,9 @@ fn spilling_group_by_payload<Method: HashMethodBounds>(
 
         if !write_data.is_empty() {
             let mut write_bytes = 0;
+            SpillSpaceManager::instance()
+                .reserve_buckets(&ctx, &write_data)
+                .await?;
             let mut writer = operator
                 .writer_with(&location)
                 .chunk(8 * 1024 * 1024)
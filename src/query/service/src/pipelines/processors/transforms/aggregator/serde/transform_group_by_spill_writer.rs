@@ -43,6 +43,7 @@ use crate::pipelines::processors::transforms::aggregator::HashTablePayload;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
 use crate::sessions::QueryContext;
+use crate::spillers::SpillSpaceManager;
 
 pub struct TransformGroupBySpillWriter<Method: HashMethodBounds> {
     ctx: Arc<QueryContext>,
@@ -251,6 +252,9 @@ pub fn agg_spilling_group_by_payload<Method: HashMethodBounds>(
 
         let mut write_bytes = 0;
         if !write_data.is_empty() {
+            SpillSpaceManager::instance()
+                .reserve_buckets(&ctx, &write_data)
+                .await?;
             let mut writer = operator
                 .writer_with(&location)
                 .chunk(8 * 1024 * 1024)
@@ -344,6 +348,9 @@ pub fn spilling_group_by_payload<Method: HashMethodBounds>(
 
         let mut write_bytes = 0;
         if !write_data.is_empty() {
+            SpillSpaceManager::instance()
+                .reserve_buckets(&ctx, &write_data)
+                .await?;
             let mut writer = operator
                 .writer_with(&location)
                 .chunk(8 * 1024 * 1024)
@@ -15,6 +15,8 @@
 use std::alloc::Layout;
 use std::borrow::BorrowMut;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 use std::vec;
 
 use bumpalo::Bump;
@@ -39,6 +41,7 @@ use databend_common_pipeline_transforms::processors::AccumulatingTransform;
 use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
 
 use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+use crate::sessions::QueryContext;
 
 /// SELECT COUNT | SUM FROM table;
 pub struct PartialSingleStateAggregator {
@@ -47,10 +50,18 @@ pub struct PartialSingleStateAggregator {
     places: Vec<StateAddr>,
     arg_indices: Vec<Vec<usize>>,
     funcs: Vec<AggregateFunctionRef>,
+    /// If set, the states accumulated so far are flushed downstream at this interval,
+    /// so that the final aggregator is able to publish progressive results.
+    flush_interval: Option<Duration>,
+    last_flushed: Instant,
+    has_unflushed: bool,
 }
 
 impl PartialSingleStateAggregator {
-    pub fn try_new(params: &Arc<AggregatorParams>) -> Result<Self> {
+    pub fn try_new(
+        params: &Arc<AggregatorParams>,
+        flush_interval: Option<Duration>,
+    ) -> Result<Self> {
         assert!(!params.offsets_aggregate_states.is_empty());
 
         let arena = Bump::new();
@@ -76,8 +87,58 @@ impl PartialSingleStateAggregator {
             places,
             funcs: params.aggregate_functions.clone(),
             arg_indices: params.aggregate_functions_arguments.clone(),
+            flush_interval,
+            last_flushed: Instant::now(),
+            has_unflushed: false,
         })
     }
+
+    fn serialize_states(&self) -> Result<DataBlock> {
+        let mut columns = Vec::with_capacity(self.funcs.len());
+
+        for (idx, func) in self.funcs.iter().enumerate() {
+            let place = self.places[idx];
+
+            let mut data = Vec::with_capacity(4);
+            func.serialize(place, &mut data)?;
+            columns.push(BlockEntry::new(
+                DataType::Binary,
+                Value::Scalar(Scalar::Binary(data)),
+            ));
+        }
+
+        Ok(DataBlock::new(columns, 1))
+    }
+
+    fn drop_states(&self) {
+        for (place, func) in self.places.iter().zip(self.funcs.iter()) {
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(*place) }
+            }
+        }
+    }
+
+    /// Flush the states accumulated since the last flush and start over with fresh states.
+    /// Merging all the flushed states produces the same result as merging the whole input.
+    fn try_flush(&mut self) -> Result<Vec<DataBlock>> {
+        let Some(interval) = self.flush_interval else {
+            return Ok(vec![]);
+        };
+
+        if !self.has_unflushed || self.last_flushed.elapsed() < interval {
+            return Ok(vec![]);
+        }
+
+        let block = self.serialize_states()?;
+        self.drop_states();
+        for (place, func) in self.places.iter().zip(self.funcs.iter()) {
+            func.init_state(*place);
+        }
+
+        self.last_flushed = Instant::now();
+        self.has_unflushed = false;
+        Ok(vec![block])
+    }
 }
 
 impl AccumulatingTransform for PartialSingleStateAggregator {
@@ -107,37 +168,56 @@ impl AccumulatingTransform for PartialSingleStateAggregator {
             }
         }
 
-        Ok(vec![])
+        self.has_unflushed = true;
+        self.try_flush()
     }
 
     fn on_finish(&mut self, generate_data: bool) -> Result<Vec<DataBlock>> {
         let mut generate_data_block = vec![];
 
         if generate_data {
-            let mut columns = Vec::with_capacity(self.funcs.len());
+            generate_data_block = vec![self.serialize_states()?];
+        }
 
-            for (idx, func) in self.funcs.iter().enumerate() {
-                let place = self.places[idx];
+        // destroy states
+        self.drop_states();
 
-                let mut data = Vec::with_capacity(4);
-                func.serialize(place, &mut data)?;
-                columns.push(BlockEntry::new(
-                    DataType::Binary,
-                    Value::Scalar(Scalar::Binary(data)),
-                ));
-            }
+        Ok(generate_data_block)
+    }
+}
 
-            generate_data_block = vec![DataBlock::new(columns, 1)];
-        }
+/// Publishes the intermediate result of the root aggregation to the query context.
+pub struct ProgressiveResultPublisher {
+    ctx: Arc<QueryContext>,
+    /// Projection from the aggregation output to the result set.
+    projection: Vec<usize>,
+    interval: Duration,
+    last_published: Instant,
+}
 
-        // destroy states
-        for (place, func) in self.places.iter().zip(self.funcs.iter()) {
-            if func.need_manual_drop_state() {
-                unsafe { func.drop_state(*place) }
-            }
+impl ProgressiveResultPublisher {
+    pub fn create(ctx: Arc<QueryContext>, projection: Vec<usize>, interval: Duration) -> Self {
+        ProgressiveResultPublisher {
+            ctx,
+            projection,
+            interval,
+            last_published: Instant::now(),
         }
+    }
 
-        Ok(generate_data_block)
+    fn is_due(&self) -> bool {
+        self.last_published.elapsed() >= self.interval
+    }
+
+    fn publish(&mut self, block: DataBlock) {
+        let columns = self
+            .projection
+            .iter()
+            .map(|index| block.get_by_offset(*index).clone())
+            .collect::<Vec<_>>();
+        self.ctx
+            .set_progressive_result(DataBlock::new(columns, block.num_rows()));
+        self.last_published = Instant::now();
     }
 }
 
@@ -148,6 +228,7 @@ pub struct FinalSingleStateAggregator {
     to_merge_data: Vec<Vec<Column>>,
     funcs: Vec<AggregateFunctionRef>,
     offsets_aggregate_states: Vec<usize>,
+    publisher: Option<ProgressiveResultPublisher>,
 }
 
 impl FinalSingleStateAggregator {
@@ -155,6 +236,7 @@ impl FinalSingleStateAggregator {
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         params: &Arc<AggregatorParams>,
+        publisher: Option<ProgressiveResultPublisher>,
     ) -> Result<Box<dyn Processor>> {
         assert!(!params.offsets_aggregate_states.is_empty());
 
@@ -172,6 +254,7 @@ impl FinalSingleStateAggregator {
                 funcs: params.aggregate_functions.clone(),
                 to_merge_data: vec![vec![]; params.aggregate_functions.len()],
                 offsets_aggregate_states: params.offsets_aggregate_states.clone(),
+                publisher,
             },
         ))
     }
@@ -188,6 +271,41 @@ impl FinalSingleStateAggregator {
             })
             .collect()
     }
+
+    fn merge_result(&self) -> Result<DataBlock> {
+        let mut aggr_values = {
+            let mut builders = vec![];
+            for func in &self.funcs {
+                let data_type = func.return_type()?;
+                builders.push(ColumnBuilder::with_capacity(&data_type, 1));
+            }
+            builders
+        };
+
+        let main_places = self.new_places();
+        for (index, func) in self.funcs.iter().enumerate() {
+            let main_place = main_places[index];
+            for col in self.to_merge_data[index].iter() {
+                func.batch_merge_single(main_place, col)?;
+            }
+            let array = aggr_values[index].borrow_mut();
+            func.merge_result(main_place, array)?;
+        }
+
+        let mut columns = Vec::with_capacity(self.funcs.len());
+        for builder in aggr_values {
+            columns.push(builder.build());
+        }
+
+        // destroy states
+        for (place, func) in main_places.iter().zip(self.funcs.iter()) {
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(*place) }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(columns))
+    }
 }
 
 impl AccumulatingTransform for FinalSingleStateAggregator {
@@ -201,6 +319,13 @@ impl AccumulatingTransform for FinalSingleStateAggregator {
                 let binary_array = block.get_by_offset(index).value.as_column().unwrap();
                 self.to_merge_data[index].push(binary_array.clone());
             }
+
+            if matches!(&self.publisher, Some(publisher) if publisher.is_due()) {
+                let block = self.merge_result()?;
+                if let Some(publisher) = self.publisher.as_mut() {
+                    publisher.publish(block);
+                }
+            }
         }
 
         Ok(vec![])
@@ -210,38 +335,7 @@ impl AccumulatingTransform for FinalSingleStateAggregator {
         let mut generate_data_block = vec![];
 
         if generate_data {
-            let mut aggr_values = {
-                let mut builders = vec![];
-                for func in &self.funcs {
-                    let data_type = func.return_type()?;
-                    builders.push(ColumnBuilder::with_capacity(&data_type, 1));
-                }
-                builders
-            };
-
-            let main_places = self.new_places();
-            for (index, func) in self.funcs.iter().enumerate() {
-                let main_place = main_places[index];
-                for col in self.to_merge_data[index].iter() {
-                    func.batch_merge_single(main_place, col)?;
-                }
-                let array = aggr_values[index].borrow_mut();
-                func.merge_result(main_place, array)?;
-            }
-
-            let mut columns = Vec::with_capacity(self.funcs.len());
-            for builder in aggr_values {
-                columns.push(builder.build());
-            }
-
-            // destroy states
-            for (place, func) in main_places.iter().zip(self.funcs.iter()) {
-                if func.need_manual_drop_state() {
-                    unsafe { func.drop_state(*place) }
-                }
-            }
-
-            generate_data_block = vec![DataBlock::new_from_columns(columns)];
+            generate_data_block = vec![self.merge_result()?];
         }
 
         Ok(generate_data_block)
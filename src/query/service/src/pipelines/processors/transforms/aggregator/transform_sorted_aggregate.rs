@@ -0,0 +1,226 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use databend_common_exception::Result;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::InputColumns;
+use databend_common_expression::Scalar;
+use databend_common_functions::aggregates::AggregateFunctionRef;
+use databend_common_functions::aggregates::StateAddr;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
+
+use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+
+/// Aggregates the input sorted by the group keys: the rows of a group are consecutive,
+/// so only the states of the current group are kept, and the groups are output in the
+/// order of the group keys.
+///
+/// The output has the layout of the final aggregation: the aggregate results, then the group keys.
+pub struct TransformSortedAggregate {
+    #[allow(dead_code)]
+    arena: Bump,
+    places: Vec<StateAddr>,
+    funcs: Vec<AggregateFunctionRef>,
+    arg_indices: Vec<Vec<usize>>,
+    group_columns: Vec<usize>,
+    max_block_size: usize,
+
+    // The group keys of the current group, None before the first row.
+    current_keys: Option<Vec<Scalar>>,
+    agg_builders: Vec<ColumnBuilder>,
+    key_builders: Vec<ColumnBuilder>,
+}
+
+impl TransformSortedAggregate {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        params: &Arc<AggregatorParams>,
+    ) -> Result<Box<dyn Processor>> {
+        let arena = Bump::new();
+        let mut places = Vec::with_capacity(params.aggregate_functions.len());
+        if let Some(layout) = params.layout {
+            let place: StateAddr = arena.alloc_layout(layout).into();
+            for (idx, func) in params.aggregate_functions.iter().enumerate() {
+                let arg_place = place.next(params.offsets_aggregate_states[idx]);
+                func.init_state(arg_place);
+                places.push(arg_place);
+            }
+        }
+
+        let mut transform = TransformSortedAggregate {
+            arena,
+            places,
+            funcs: params.aggregate_functions.clone(),
+            arg_indices: params.aggregate_functions_arguments.clone(),
+            group_columns: params.group_columns.clone(),
+            max_block_size: params.max_block_size,
+            current_keys: None,
+            agg_builders: vec![],
+            key_builders: vec![],
+        };
+        transform.agg_builders = transform.new_agg_builders(params)?;
+        transform.key_builders = transform.new_key_builders(params);
+
+        Ok(AccumulatingTransformer::create(input, output, transform))
+    }
+
+    fn new_agg_builders(&self, params: &AggregatorParams) -> Result<Vec<ColumnBuilder>> {
+        params
+            .aggregate_functions
+            .iter()
+            .map(|func| {
+                let data_type = func.return_type()?;
+                Ok(ColumnBuilder::with_capacity(
+                    &data_type,
+                    self.max_block_size,
+                ))
+            })
+            .collect()
+    }
+
+    fn new_key_builders(&self, params: &AggregatorParams) -> Vec<ColumnBuilder> {
+        params
+            .group_data_types
+            .iter()
+            .map(|data_type| ColumnBuilder::with_capacity(data_type, self.max_block_size))
+            .collect()
+    }
+
+    fn is_current_group(&self, keys: &[Column], row: usize) -> bool {
+        match &self.current_keys {
+            None => false,
+            Some(current) => current
+                .iter()
+                .zip(keys.iter())
+                .all(|(current, key)| key.index(row) == Some(current.as_ref())),
+        }
+    }
+
+    /// Output the result of the current group, and reset the states for the next group.
+    fn finish_group(&mut self) -> Result<()> {
+        let Some(keys) = self.current_keys.take() else {
+            return Ok(());
+        };
+        for ((func, place), builder) in self
+            .funcs
+            .iter()
+            .zip(self.places.iter())
+            .zip(self.agg_builders.iter_mut())
+        {
+            func.merge_result(*place, builder)?;
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(*place) }
+            }
+            func.init_state(*place);
+        }
+        for (key, builder) in keys.iter().zip(self.key_builders.iter_mut()) {
+            builder.push(key.as_ref());
+        }
+        Ok(())
+    }
+
+    fn take_output(&mut self) -> DataBlock {
+        let columns = self
+            .agg_builders
+            .iter_mut()
+            .chain(self.key_builders.iter_mut())
+            .map(|builder| {
+                let empty = ColumnBuilder::with_capacity(&builder.data_type(), self.max_block_size);
+                std::mem::replace(builder, empty).build()
+            })
+            .collect();
+        DataBlock::new_from_columns(columns)
+    }
+
+    fn output_rows(&self) -> usize {
+        self.key_builders.first().map_or(0, |builder| builder.len())
+    }
+}
+
+impl AccumulatingTransform for TransformSortedAggregate {
+    const NAME: &'static str = "TransformSortedAggregate";
+
+    fn transform(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
+        let block = block.convert_to_full();
+        let keys = self
+            .group_columns
+            .iter()
+            .map(|index| block.get_by_offset(*index).value.clone())
+            .map(|value| value.into_column().unwrap())
+            .collect::<Vec<_>>();
+
+        let mut blocks = vec![];
+        let mut start = 0;
+        while start < block.num_rows() {
+            if !self.is_current_group(&keys, start) {
+                self.finish_group()?;
+                if self.output_rows() >= self.max_block_size {
+                    blocks.push(self.take_output());
+                }
+                let current = keys
+                    .iter()
+                    .map(|key| key.index(start).unwrap().to_owned())
+                    .collect();
+                self.current_keys = Some(current);
+            }
+
+            // The rows of the current group in this block.
+            let mut end = start + 1;
+            while end < block.num_rows() && self.is_current_group(&keys, end) {
+                end += 1;
+            }
+            let rows = block.slice(start..end);
+            for ((func, place), args) in self
+                .funcs
+                .iter()
+                .zip(self.places.iter())
+                .zip(self.arg_indices.iter())
+            {
+                let columns = InputColumns::new_block_proxy(args.as_slice(), &rows);
+                func.accumulate(*place, columns, None, rows.num_rows())?;
+            }
+            start = end;
+        }
+
+        Ok(blocks)
+    }
+
+    fn on_finish(&mut self, generate_data: bool) -> Result<Vec<DataBlock>> {
+        let mut blocks = vec![];
+        if generate_data {
+            self.finish_group()?;
+            if self.output_rows() > 0 {
+                blocks.push(self.take_output());
+            }
+        }
+
+        // destroy states
+        for (place, func) in self.places.iter().zip(self.funcs.iter()) {
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(*place) }
+            }
+        }
+        Ok(blocks)
+    }
+}
@@ -34,7 +34,6 @@ pub struct MarkJoinDesc {
 
 pub struct HashJoinDesc {
     pub(crate) build_keys: Vec<Expr>,
-    pub(crate) probe_keys: Vec<Expr>,
     pub(crate) is_null_equal: Vec<bool>,
     pub(crate) join_type: JoinType,
     pub(crate) single_to_inner: Option<JoinType>,
@@ -61,11 +60,6 @@ impl HashJoinDesc {
             .iter()
             .map(|k| k.as_expr(&BUILTIN_FUNCTIONS))
             .collect();
-        let probe_keys: Vec<Expr> = join
-            .probe_keys
-            .iter()
-            .map(|k| k.as_expr(&BUILTIN_FUNCTIONS))
-            .collect();
 
         let probe_keys_rt: Vec<Option<(Expr<String>, IndexType)>> = join
             .probe_keys_rt
@@ -80,7 +74,6 @@ impl HashJoinDesc {
         Ok(HashJoinDesc {
             join_type: join.join_type.clone(),
             build_keys,
-            probe_keys,
             is_null_equal: join.is_null_equal.clone(),
             other_predicate,
             marker_join_desc: MarkJoinDesc {
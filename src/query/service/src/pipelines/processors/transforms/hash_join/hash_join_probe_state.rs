@@ -32,6 +32,7 @@ use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::HashMethod;
 use databend_common_expression::HashMethodKind;
@@ -93,6 +94,9 @@ pub struct HashJoinProbeState {
     pub(crate) mark_scan_map_lock: Mutex<()>,
     /// Hash method
     pub(crate) hash_method: HashMethodKind,
+    /// Probe keys of this join. They are kept here instead of being read from
+    /// `hash_join_state` because a shared build side may serve several joins.
+    pub(crate) probe_keys: Vec<Expr>,
 
     /// Spill related states
     /// Record spill workers
@@ -135,6 +139,10 @@ impl HashJoinProbeState {
             })
             .collect::<Vec<_>>();
         let method = DataBlock::choose_hash_method_with_types(&hash_key_types, false)?;
+        let probe_keys = probe_keys
+            .iter()
+            .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+            .collect();
         Ok(HashJoinProbeState {
             ctx,
             func_ctx,
@@ -151,6 +159,7 @@ impl HashJoinProbeState {
             merge_into_final_partial_unmodified_scan_tasks: RwLock::new(VecDeque::new()),
             mark_scan_map_lock: Mutex::new(()),
             hash_method: method,
+            probe_keys,
             spill_partitions: Default::default(),
             barrier_count: AtomicUsize::new(0),
         })
@@ -206,7 +215,7 @@ impl HashJoinProbeState {
         } else {
             Evaluator::new(&input, &probe_state.func_ctx, &BUILTIN_FUNCTIONS)
         };
-        let probe_keys = &self.hash_join_state.hash_join_desc.probe_keys;
+        let probe_keys = &self.probe_keys;
         let mut keys_columns = probe_keys
             .iter()
             .map(|expr| {
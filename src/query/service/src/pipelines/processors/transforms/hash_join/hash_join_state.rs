@@ -141,6 +141,7 @@ impl HashJoinState {
         merge_into_is_distributed: bool,
         enable_merge_into_optimization: bool,
         build_side_cache_info: Option<(usize, HashMap<IndexType, usize>)>,
+        shared_build_side: bool,
     ) -> Result<Arc<HashJoinState>> {
         if matches!(
             hash_join_desc.join_type,
@@ -151,7 +152,9 @@ impl HashJoinState {
         let (build_done_watcher, _build_done_dummy_receiver) = watch::channel(0);
         let (continue_build_watcher, _continue_build_dummy_receiver) = watch::channel(false);
         let mut enable_spill = false;
-        if ctx.get_settings().get_join_spilling_memory_ratio()? != 0 {
+        // A hash table shared by several joins must stay in memory, because the
+        // spill protocol coordinates the build and probe processors of one join only.
+        if !shared_build_side && ctx.get_settings().get_join_spilling_memory_ratio()? != 0 {
             enable_spill = true;
         }
         let column_map = if let Some((_, column_map)) = build_side_cache_info {
@@ -58,7 +58,7 @@ impl ProbeSpillState {
         hashes: &mut Vec<u64>,
     ) -> Result<()> {
         let func_ctx = self.probe_state.ctx.get_function_context()?;
-        let keys = &self.probe_state.hash_join_state.hash_join_desc.probe_keys;
+        let keys = &self.probe_state.probe_keys;
         get_hashes(
             &func_ctx,
             block,
@@ -26,6 +26,7 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_transforms::processors::Transform;
 use databend_common_pipeline_transforms::processors::Transformer;
 
+use crate::interpreters::common::InsertCastKind;
 use crate::pipelines::processors::InputPort;
 use crate::pipelines::processors::OutputPort;
 use crate::pipelines::processors::ProcessorPtr;
@@ -35,6 +36,7 @@ pub struct TransformCastSchema {
     insert_schema: DataSchemaRef,
     select_schema: DataSchemaRef,
     exprs: Vec<Expr>,
+    kinds: Vec<InsertCastKind>,
 }
 
 impl TransformCastSchema
@@ -59,12 +61,19 @@ where Self: Transform
                 check_cast(None, false, expr, to.data_type(), &BUILTIN_FUNCTIONS)
             })
             .collect::<Result<Vec<_>>>()?;
+        let kinds = select_schema
+            .fields()
+            .iter()
+            .zip(insert_schema.fields().iter())
+            .map(|(from, to)| InsertCastKind::of(from.data_type(), to.data_type()))
+            .collect();
 
         Ok(Self {
             func_ctx,
             insert_schema,
             select_schema,
             exprs,
+            kinds,
         })
     }
 
@@ -90,20 +99,33 @@ impl Transform for TransformCastSchema {
     fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
         let mut columns = Vec::with_capacity(self.exprs.len());
         let evaluator = Evaluator::new(&data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
-        for (i, (field, expr)) in self
+        for (i, ((field, expr), kind)) in self
             .insert_schema
             .fields()
             .iter()
             .zip(self.exprs.iter())
+            .zip(self.kinds.iter())
             .enumerate()
         {
+            if *kind == InsertCastKind::Identical {
+                columns.push(data_block.get_by_offset(i).clone());
+                continue;
+            }
             let value = evaluator.run(expr).map_err(|err| {
+                let from = &self.select_schema.fields[i];
+                // Only a narrowing cast fails on the values which don't fit in the target type,
+                // a widening cast failing is not a data error.
+                let reason = match kind {
+                    InsertCastKind::Narrowing => ", the value can't be converted without loss",
+                    _ => "",
+                };
                 let msg = format!(
-                    "fail to auto cast column {} ({}) to column {} ({})",
-                    self.select_schema.fields[i].name(),
-                    self.select_schema.fields[i].data_type(),
+                    "fail to auto cast column {} ({}) to column {} ({}){}",
+                    from.name(),
+                    from.data_type(),
                     field.name(),
                     field.data_type(),
+                    reason,
                 );
                 err.add_message(msg)
             })?;
@@ -306,6 +306,7 @@ async fn create_memory_table_for_cte_scan(
                 field_comments: vec![],
                 cluster_key: None,
                 as_select: None,
+                clone_source: None,
                 inverted_indexes: None,
             };
             let create_table_interpreter =
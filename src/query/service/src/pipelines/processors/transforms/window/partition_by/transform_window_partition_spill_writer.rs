@@ -40,6 +40,7 @@ use super::WindowPartitionMeta;
 use crate::pipelines::processors::transforms::window::partition_by::SpillingWindowPayloads;
 use crate::pipelines::processors::transforms::window::partition_by::PARTITION_COUNT;
 use crate::sessions::QueryContext;
+use crate::spillers::SpillSpaceManager;
 
 pub struct TransformWindowPartitionSpillWriter {
     ctx: Arc<QueryContext>,
@@ -226,6 +227,9 @@ pub fn spilling_window_payload(
 
         let mut write_bytes = 0;
         if !write_data.is_empty() {
+            SpillSpaceManager::instance()
+                .reserve_buckets(&ctx, &write_data)
+                .await?;
             let mut writer = operator
                 .writer_with(&location)
                 .chunk(8 * 1024 * 1024)
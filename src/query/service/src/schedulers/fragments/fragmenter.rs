@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
@@ -31,17 +33,25 @@ use databend_common_sql::executor::physical_plans::ReplaceInto;
 use databend_common_sql::executor::physical_plans::TableScan;
 use databend_common_sql::executor::physical_plans::UnionAll;
 use databend_common_sql::executor::PhysicalPlanReplacer;
+use databend_common_sql::plans::JoinType;
+use futures_util::StreamExt;
 
 use crate::clusters::ClusterHelper;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::PipelineBuilder;
 use crate::schedulers::fragments::plan_fragment::FragmentType;
 use crate::schedulers::PlanFragment;
 use crate::servers::flight::v1::exchange::BroadcastExchange;
 use crate::servers::flight::v1::exchange::DataExchange;
 use crate::servers::flight::v1::exchange::MergeExchange;
 use crate::servers::flight::v1::exchange::ShuffleDataExchange;
+use crate::servers::flight::v1::exchange::ShuffleSalt;
+use crate::servers::flight::v1::scatter::HotKeySampler;
 use crate::sessions::QueryContext;
 use crate::sql::executor::physical_plans::Mutation;
 use crate::sql::executor::PhysicalPlan;
+use crate::stream::PullingExecutorStream;
 
 /// Visitor to split a `PhysicalPlan` into fragments.
 pub struct Fragmenter {
@@ -49,6 +59,11 @@ pub struct Fragmenter {
     fragments: Vec<PlanFragment>,
     query_id: String,
     state: State,
+    /// The hot keys of the hash joins whose inputs are shuffled with salted partitions,
+    /// by the plan ids of the joins.
+    salted_joins: HashMap<u32, HashSet<u64>>,
+    /// The salt of the exchange being replaced, set by its parent hash join.
+    exchange_salt: Option<ShuffleSalt>,
 }
 
 /// A state to track if is visiting a source fragment, useful when building fragments.
@@ -77,6 +92,8 @@ impl Fragmenter {
             fragments: vec![],
             state: State::Other,
             query_id,
+            salted_joins: HashMap::new(),
+            exchange_salt: None,
         })
     }
 
@@ -117,7 +134,18 @@ impl Fragmenter {
         }
     }
 
-    pub fn build_fragment(mut self, plan: &PhysicalPlan) -> Result<PlanFragment> {
+    #[async_backtrace::framed]
+    pub async fn build_fragment(mut self, plan: &PhysicalPlan) -> Result<PlanFragment> {
+        if self.ctx.get_settings().get_join_skew_salt_buckets()? > 1 {
+            let mut joins = vec![];
+            Self::collect_salted_joins(plan, true, &mut joins);
+            for join in joins {
+                let hot_keys = self.sample_hot_keys(join).await?;
+                if !hot_keys.is_empty() {
+                    self.salted_joins.insert(join.plan_id, hot_keys);
+                }
+            }
+        }
         let root = self.replace(plan)?;
         let mut root_fragment = PlanFragment {
             plan: root,
@@ -132,6 +160,105 @@ impl Fragmenter {
         Ok(root_fragment)
     }
 
+    /// Collect the hash joins which may shuffle skewed keys of the probe side to several nodes.
+    ///
+    /// Salting breaks the hash distribution of the join output, so it's only allowed if the
+    /// output reaches an exchange or the root through operators that don't depend on it.
+    fn collect_salted_joins<'a>(
+        plan: &'a PhysicalPlan,
+        distribution_free: bool,
+        joins: &mut Vec<&'a HashJoin>,
+    ) {
+        let distribution_free = match plan {
+            PhysicalPlan::Exchange(_) | PhysicalPlan::ExchangeSink(_) => true,
+            PhysicalPlan::Filter(_)
+            | PhysicalPlan::EvalScalar(_)
+            | PhysicalPlan::ProjectSet(_)
+            | PhysicalPlan::Udf(_)
+            | PhysicalPlan::RowFetch(_)
+            | PhysicalPlan::Limit(_)
+            | PhysicalPlan::Sort(_)
+            | PhysicalPlan::AggregatePartial(_) => distribution_free,
+            PhysicalPlan::HashJoin(join) => {
+                if distribution_free
+                    && !join.need_hold_hash_table
+                    && matches!(
+                        join.join_type,
+                        JoinType::Inner
+                            | JoinType::Left
+                            | JoinType::LeftSemi
+                            | JoinType::LeftAnti
+                            | JoinType::LeftSingle
+                    )
+                    && Self::is_hash_exchange(&join.build)
+                    && Self::is_hash_exchange(&join.probe)
+                {
+                    joins.push(join);
+                }
+                false
+            }
+            _ => false,
+        };
+        for child in plan.children() {
+            Self::collect_salted_joins(child, distribution_free, joins);
+        }
+    }
+
+    fn is_hash_exchange(plan: &PhysicalPlan) -> bool {
+        matches!(plan, PhysicalPlan::Exchange(exchange) if exchange.kind == FragmentKind::Normal && !exchange.keys.is_empty())
+    }
+
+    /// Sample the first `join_skew_sample_rows` rows of the probe side of `join` on the local
+    /// node, and return the hashes of the join keys which take at least
+    /// `join_skew_hot_key_percent` of the sampled rows. Only a probe side which scans a table
+    /// without another exchange is sampled, otherwise there are no hot keys.
+    #[async_backtrace::framed]
+    async fn sample_hot_keys(&self, join: &HashJoin) -> Result<HashSet<u64>> {
+        let PhysicalPlan::Exchange(exchange) = join.probe.as_ref() else {
+            return Ok(HashSet::new());
+        };
+        if !Self::can_sample(&exchange.input) {
+            return Ok(HashSet::new());
+        }
+
+        let settings = self.ctx.get_settings();
+        let sample_rows = settings.get_join_skew_sample_rows()?;
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        let mut build_res = PipelineBuilder::create(
+            self.ctx.get_function_context()?,
+            settings.clone(),
+            ctx.clone(),
+            vec![],
+        )
+        .finalize(&exchange.input)?;
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let executor = PipelinePullingExecutor::from_pipelines(
+            build_res,
+            ExecutorSettings::try_create(ctx.clone())?,
+        )?;
+
+        let mut sampler =
+            HotKeySampler::try_create(self.ctx.get_function_context()?, &exchange.keys)?;
+        let mut stream = PullingExecutorStream::create(executor)?;
+        while sampler.sampled_rows() < sample_rows {
+            let Some(data_block) = stream.next().await else {
+                break;
+            };
+            sampler.sample(&data_block?, sample_rows)?;
+        }
+        Ok(sampler.hot_keys(settings.get_join_skew_hot_key_percent()?))
+    }
+
+    fn can_sample(plan: &PhysicalPlan) -> bool {
+        match plan {
+            PhysicalPlan::TableScan(_) => true,
+            PhysicalPlan::Filter(_) | PhysicalPlan::EvalScalar(_) => {
+                plan.children().all(Self::can_sample)
+            }
+            _ => false,
+        }
+    }
+
     fn resolve_fragment_connection(fragment: &mut PlanFragment) {
         for source_fragment in fragment.source_fragments.iter_mut() {
             if let PhysicalPlan::ExchangeSink(ExchangeSink {
@@ -207,11 +334,28 @@ impl PhysicalPlanReplacer for Fragmenter {
     }
 
     fn replace_hash_join(&mut self, plan: &HashJoin) -> Result<PhysicalPlan> {
+        let salt = match self.salted_joins.get(&plan.plan_id) {
+            Some(hot_keys) => Some((
+                self.ctx.get_settings().get_join_skew_salt_buckets()?,
+                hot_keys.clone(),
+            )),
+            None => None,
+        };
+
         let mut fragments = vec![];
+        if let Some((buckets, hot_keys)) = &salt {
+            self.exchange_salt = Some(ShuffleSalt::Replicate {
+                buckets: *buckets,
+                hot_keys: hot_keys.clone(),
+            });
+        }
         let build_input = self.replace(plan.build.as_ref())?;
 
         // Consume current fragments to prevent them being consumed by `probe_input`.
         fragments.append(&mut self.fragments);
+        if let Some((buckets, hot_keys)) = salt {
+            self.exchange_salt = Some(ShuffleSalt::Spread { buckets, hot_keys });
+        }
         let probe_input = self.replace(plan.probe.as_ref())?;
         fragments.append(&mut self.fragments);
         self.fragments = fragments;
@@ -270,6 +414,8 @@ impl PhysicalPlanReplacer for Fragmenter {
     }
 
     fn replace_exchange(&mut self, plan: &Exchange) -> Result<PhysicalPlan> {
+        let salt = self.exchange_salt.take();
+
         // Recursively rewrite input
         let input = self.replace(plan.input.as_ref())?;
         let input_schema = input.output_schema()?;
@@ -304,7 +450,10 @@ impl PhysicalPlanReplacer for Fragmenter {
             State::Recluster => FragmentType::Recluster,
         };
         self.state = State::Other;
-        let exchange = Self::get_exchange(self.ctx.clone(), &plan)?;
+        let mut exchange = Self::get_exchange(self.ctx.clone(), &plan)?;
+        if let Some(DataExchange::ShuffleDataExchange(exchange)) = &mut exchange {
+            exchange.salt = salt;
+        }
 
         let mut source_fragment = PlanFragment {
             plan,
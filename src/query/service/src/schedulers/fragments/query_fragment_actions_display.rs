@@ -20,6 +20,7 @@ use databend_common_sql::MetadataRef;
 use crate::schedulers::QueryFragmentActions;
 use crate::schedulers::QueryFragmentsActions;
 use crate::servers::flight::v1::exchange::DataExchange;
+use crate::servers::flight::v1::exchange::ShuffleSalt;
 
 impl QueryFragmentsActions {
     pub fn display_indent<'a>(&'a self, metadata: &'a MetadataRef) -> impl Display + '_ {
@@ -71,7 +72,21 @@ impl<'a> Display for QueryFragmentActionsWrap<'a> {
             match data_exchange {
                 DataExchange::Merge(_) => writeln!(f, "  DataExchange: Merge")?,
                 DataExchange::Broadcast(_) => writeln!(f, "  DataExchange: Broadcast")?,
-                DataExchange::ShuffleDataExchange(_) => writeln!(f, "  DataExchange: Shuffle")?,
+                DataExchange::ShuffleDataExchange(exchange) => match &exchange.salt {
+                    None => writeln!(f, "  DataExchange: Shuffle")?,
+                    Some(ShuffleSalt::Replicate { buckets, hot_keys }) => writeln!(
+                        f,
+                        "  DataExchange: Shuffle(salted, replicate {} hot keys to {} buckets)",
+                        hot_keys.len(),
+                        buckets
+                    )?,
+                    Some(ShuffleSalt::Spread { buckets, hot_keys }) => writeln!(
+                        f,
+                        "  DataExchange: Shuffle(salted, spread {} hot keys over {} buckets)",
+                        hot_keys.len(),
+                        buckets
+                    )?,
+                },
             }
         }
 
@@ -35,6 +35,9 @@ pub async fn build_query_pipeline(
     plan: &PhysicalPlan,
     ignore_result: bool,
 ) -> Result<PipelineBuildResult> {
+    if !ignore_result {
+        register_progressive_result(ctx, result_columns, plan)?;
+    }
     let mut build_res = build_query_pipeline_without_render_result_set(ctx, plan).await?;
     if matches!(plan, PhysicalPlan::UnionAll { .. }) {
         // Union doesn't need to add extra processor to project the result.
@@ -52,6 +55,35 @@ pub async fn build_query_pipeline(
     Ok(build_res)
 }
 
+/// Only an aggregation without GROUP BY at the root of the plan can publish
+/// progressive results, because its intermediate result is always one row
+/// that maps to the result set directly.
+fn register_progressive_result(
+    ctx: &Arc<QueryContext>,
+    result_columns: &[ColumnBinding],
+    plan: &PhysicalPlan,
+) -> Result<()> {
+    if ctx
+        .get_settings()
+        .get_progressive_aggregate_interval_secs()?
+        == 0
+    {
+        return Ok(());
+    }
+
+    if let PhysicalPlan::AggregateFinal(aggregate) = plan {
+        if aggregate.group_by.is_empty() {
+            let input_schema = plan.output_schema()?;
+            let projection = result_columns
+                .iter()
+                .map(|column| input_schema.index_of(column.index.to_string().as_str()))
+                .collect::<Result<Vec<_>>>()?;
+            ctx.set_progressive_result_plan(aggregate.plan_id, projection);
+        }
+    }
+    Ok(())
+}
+
 #[async_backtrace::framed]
 pub async fn build_query_pipeline_without_render_result_set(
     ctx: &Arc<QueryContext>,
@@ -71,6 +103,13 @@ pub async fn build_local_pipeline(
     ctx: &Arc<QueryContext>,
     plan: &PhysicalPlan,
 ) -> Result<PipelineBuildResult> {
+    let settings = ctx.get_settings();
+    // Only a local pipeline shares the hash tables of repeated join build sides, the
+    // fragments of a distributed plan are built separately and build every hash table.
+    if settings.get_enable_shared_hash_join_build()? {
+        ctx.set_shared_hash_join_keys(PipelineBuilder::collect_shared_hash_join_keys(plan));
+    }
+
     let pipeline = PipelineBuilder::create(
         ctx.get_function_context()?,
         ctx.get_settings(),
@@ -79,7 +118,6 @@ pub async fn build_local_pipeline(
     );
     let mut build_res = pipeline.finalize(plan)?;
 
-    let settings = ctx.get_settings();
     build_res.set_max_threads(settings.get_max_threads()? as usize);
     Ok(build_res)
 }
@@ -92,7 +130,7 @@ pub async fn build_distributed_pipeline(
 ) -> Result<PipelineBuildResult> {
     let fragmenter = Fragmenter::try_create(ctx.clone())?;
 
-    let root_fragment = fragmenter.build_fragment(plan)?;
+    let root_fragment = fragmenter.build_fragment(plan).await?;
     let mut fragments_actions = QueryFragmentsActions::create(ctx.clone());
     root_fragment.get_actions(ctx.clone(), &mut fragments_actions)?;
 
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use databend_common_expression::RemoteExpr;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -35,6 +37,8 @@ impl DataExchange {
 pub struct ShuffleDataExchange {
     pub destination_ids: Vec<String>,
     pub shuffle_keys: Vec<RemoteExpr>,
+    /// Salted repartitioning of skewed keys, only set for the two inputs of a hash join.
+    pub salt: Option<ShuffleSalt>,
 }
 
 impl ShuffleDataExchange {
@@ -42,10 +46,29 @@ impl ShuffleDataExchange {
         DataExchange::ShuffleDataExchange(ShuffleDataExchange {
             destination_ids,
             shuffle_keys,
+            salt: None,
         })
     }
 }
 
+/// A hot key is shuffled to one of the `buckets` salted partitions of the key, other keys
+/// go to the first salted partition. The build side of a join replicates the rows of a hot key
+/// to all its salted partitions, so the probe side is free to spread them over these partitions.
+/// The hot keys are the hashes of the join keys which are skewed in a sample of the probe side.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum ShuffleSalt {
+    /// Replicate the rows of the hot keys to all the salted partitions of their key.
+    Replicate {
+        buckets: usize,
+        hot_keys: HashSet<u64>,
+    },
+    /// Spread the rows of the hot keys over the salted partitions of their key.
+    Spread {
+        buckets: usize,
+        hot_keys: HashSet<u64>,
+    },
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MergeExchange {
     pub destination_id: String,
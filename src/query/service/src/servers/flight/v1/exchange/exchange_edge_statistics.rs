@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Instant;
+
+use chrono::Utc;
+use databend_common_storages_system::QueryFragmentsLogElement;
+use databend_common_storages_system::QueryFragmentsLogQueue;
+use log::warn;
+
+use crate::servers::flight::v1::packets::DataPacket;
+
+/// Rows and bytes moved over the local end of an exchange edge, and how long it was active.
+///
+/// The writer of an edge is the sink of the fragment on the sending node, so it is active from
+/// the start of the fragment's pipeline until all the output of the fragment is sent. The reader
+/// is active from the start of reading until the edge is drained.
+///
+/// The statistics are appended to `system.query_fragments` when the exchange
+/// reader or writer owning them is dropped.
+pub struct ExchangeEdgeStatistics {
+    tenant: String,
+    query_id: String,
+    fragment_id: usize,
+    source: String,
+    destination: String,
+    is_sender: bool,
+    rows: usize,
+    bytes: usize,
+    started: Option<(i64, Instant)>,
+    finished: Option<Instant>,
+}
+
+impl ExchangeEdgeStatistics {
+    pub fn create(
+        tenant: &str,
+        query_id: &str,
+        fragment_id: usize,
+        source: &str,
+        destination: &str,
+        is_sender: bool,
+    ) -> ExchangeEdgeStatistics {
+        ExchangeEdgeStatistics {
+            tenant: tenant.to_string(),
+            query_id: query_id.to_string(),
+            fragment_id,
+            source: source.to_string(),
+            destination: destination.to_string(),
+            is_sender,
+            rows: 0,
+            bytes: 0,
+            started: None,
+            finished: None,
+        }
+    }
+
+    pub fn start(&mut self) {
+        if self.started.is_none() {
+            self.started = Some((Utc::now().timestamp_micros(), Instant::now()));
+        }
+    }
+
+    pub fn finish(&mut self) {
+        if self.finished.is_none() {
+            self.finished = Some(Instant::now());
+        }
+    }
+
+    pub fn record(&mut self, packet: &DataPacket) {
+        self.start();
+        self.bytes += packet.bytes_size();
+        if let DataPacket::FragmentData(data) = packet {
+            self.rows += data.num_rows().unwrap_or_default();
+        }
+    }
+}
+
+impl Drop for ExchangeEdgeStatistics {
+    fn drop(&mut self) {
+        // The edge was never active, e.g. the query was aborted before the fragment started.
+        let Some((start_time, started)) = self.started else {
+            return;
+        };
+        // The query was aborted before the edge finished.
+        let finished = self.finished.unwrap_or_else(Instant::now);
+
+        let (rows, bytes) = (self.rows as u64, self.bytes as u64);
+        let element = QueryFragmentsLogElement {
+            tenant: self.tenant.clone(),
+            query_id: self.query_id.clone(),
+            fragment_id: self.fragment_id as u64,
+            source_node: self.source.clone(),
+            destination_node: self.destination.clone(),
+            rows_sent: if self.is_sender { rows } else { 0 },
+            bytes_sent: if self.is_sender { bytes } else { 0 },
+            rows_received: if self.is_sender { 0 } else { rows },
+            bytes_received: if self.is_sender { 0 } else { bytes },
+            start_time,
+            wall_time_ms: finished.duration_since(started).as_millis() as u64,
+        };
+
+        match QueryFragmentsLogQueue::instance() {
+            Ok(queue) => {
+                if let Err(cause) = queue.append_data(element) {
+                    warn!("Cannot log exchange edge statistics, cause: {:?}", cause);
+                }
+            }
+            Err(cause) => warn!("Cannot log exchange edge statistics, cause: {:?}", cause),
+        }
+    }
+}
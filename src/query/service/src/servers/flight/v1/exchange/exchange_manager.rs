@@ -30,6 +30,7 @@ use databend_common_base::runtime::Thread;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
+use databend_common_exception::ErrorOrigin;
 use databend_common_exception::Result;
 use databend_common_grpc::ConnectionFactory;
 use databend_common_pipeline_core::ExecutionInfo;
@@ -708,10 +709,18 @@ impl QueryCoordinator {
             );
         }
 
+        let local_id = query_context.get_cluster().local_id();
         for fragment in &fragments.fragments {
             let fragment_id = fragment.fragment_id;
             if let Some(coordinator) = self.fragments_coordinator.get_mut(&fragment_id) {
-                coordinator.prepare_pipeline(query_context.clone())?;
+                coordinator
+                    .prepare_pipeline(query_context.clone())
+                    .map_err(|cause| {
+                        cause.set_origin(ErrorOrigin {
+                            node_id: local_id.clone(),
+                            fragment_ids: vec![fragment_id],
+                        })
+                    })?;
             }
         }
 
@@ -800,23 +809,42 @@ impl QueryCoordinator {
         let max_threads = info.query_ctx.get_settings().get_max_threads()?;
         let mut pipelines = Vec::with_capacity(self.fragments_coordinator.len());
 
+        let local_id = info.query_ctx.get_cluster().local_id();
+        let fragment_origin = |fragment_id: usize| ErrorOrigin {
+            node_id: local_id.clone(),
+            fragment_ids: vec![fragment_id],
+        };
+
+        // Map every plan to its fragment, an error raised by a processor is tagged with the
+        // fragment of the plan that failed rather than with every fragment of the query.
+        let mut plan_fragments = HashMap::new();
         let mut params = Vec::with_capacity(self.fragments_coordinator.len());
-        for coordinator in self.fragments_coordinator.values() {
+        for (fragment_id, coordinator) in self.fragments_coordinator.iter() {
+            collect_plan_ids(
+                &coordinator.physical_plan,
+                *fragment_id,
+                &mut plan_fragments,
+            );
+
             params.push(
-                coordinator.create_exchange_params(
-                    info,
-                    coordinator
-                        .pipeline_build_res
-                        .as_ref()
-                        .map(|x| x.exchange_injector.clone())
-                        .ok_or_else(|| {
-                            ErrorCode::Internal("Pipeline build result is none, It's a bug")
-                        })?,
-                )?,
+                coordinator
+                    .create_exchange_params(
+                        info,
+                        coordinator
+                            .pipeline_build_res
+                            .as_ref()
+                            .map(|x| x.exchange_injector.clone())
+                            .ok_or_else(|| {
+                                ErrorCode::Internal("Pipeline build result is none, It's a bug")
+                            })?,
+                    )
+                    .map_err(|cause| cause.set_origin(fragment_origin(*fragment_id)))?,
             );
         }
 
-        for ((_, coordinator), params) in self.fragments_coordinator.iter_mut().zip(params) {
+        for ((fragment_id, coordinator), params) in
+            self.fragments_coordinator.iter_mut().zip(params)
+        {
             if let Some(mut build_res) = coordinator.pipeline_build_res.take() {
                 build_res.set_max_threads(max_threads as usize);
 
@@ -825,7 +853,8 @@ impl QueryCoordinator {
                 }
 
                 // Add exchange data publisher.
-                ExchangeSink::via(&info.query_ctx, &params, &mut build_res.main_pipeline)?;
+                ExchangeSink::via(&info.query_ctx, &params, &mut build_res.main_pipeline)
+                    .map_err(|cause| cause.set_origin(fragment_origin(*fragment_id)))?;
 
                 if !build_res.main_pipeline.is_complete_pipeline()? {
                     return Err(ErrorCode::Internal("Logical error, It's a bug"));
@@ -870,7 +899,22 @@ impl QueryCoordinator {
 
         Thread::named_spawn(Some(String::from("Distributed-Executor")), move || {
             let _g = span.set_local_parent();
-            statistics_sender.shutdown(executor.execute().err());
+            let error = executor.execute().err().map(|cause| {
+                let mut fragment_ids = executor
+                    .get_inner()
+                    .get_failed_plan_ids()
+                    .iter()
+                    .filter_map(|plan_id| plan_fragments.get(plan_id).copied())
+                    .collect::<Vec<_>>();
+                fragment_ids.sort();
+                fragment_ids.dedup();
+
+                cause.set_origin(ErrorOrigin {
+                    node_id: local_id,
+                    fragment_ids,
+                })
+            });
+            statistics_sender.shutdown(error);
             query_ctx
                 .get_exchange_manager()
                 .on_finished_query(&query_id);
@@ -880,6 +924,14 @@ impl QueryCoordinator {
     }
 }
 
+fn collect_plan_ids(plan: &PhysicalPlan, fragment_id: usize, plans: &mut HashMap<u32, usize>) {
+    plans.insert(plan.get_id(), fragment_id);
+
+    for child in plan.children() {
+        collect_plan_ids(child, fragment_id, plans);
+    }
+}
+
 struct FragmentCoordinator {
     initialized: bool,
     fragment_id: usize,
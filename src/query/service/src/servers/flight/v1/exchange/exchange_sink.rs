@@ -82,6 +82,8 @@ impl ExchangeSink {
                 pipeline.add_pipe(Pipe::create(1, 0, vec![create_writer_item(
                     senders.remove(0),
                     params.ignore_exchange,
+                    ctx.get_tenant().tenant_name(),
+                    &params.query_id,
                     &params.destination_id,
                     params.fragment_id,
                     &ctx.get_cluster().local_id(),
@@ -99,6 +101,8 @@ impl ExchangeSink {
                     items.push(create_writer_item(
                         sender,
                         false,
+                        ctx.get_tenant().tenant_name(),
+                        &params.query_id,
                         destination_id,
                         params.fragment_id,
                         &ctx.get_cluster().local_id(),
@@ -29,6 +29,7 @@ use databend_common_pipeline_sinks::AsyncSinker;
 use databend_common_pipeline_sinks::Sink;
 use databend_common_pipeline_sinks::Sinker;
 
+use super::exchange_edge_statistics::ExchangeEdgeStatistics;
 use crate::servers::flight::v1::exchange::serde::ExchangeSerializeMeta;
 use crate::servers::flight::FlightSender;
 
@@ -37,12 +38,15 @@ pub struct ExchangeWriterSink {
     source: String,
     destination: String,
     fragment: usize,
+    statistics: ExchangeEdgeStatistics,
 }
 
 impl ExchangeWriterSink {
     pub fn create(
         input: Arc<InputPort>,
         flight_sender: FlightSender,
+        tenant: &str,
+        query_id: &str,
         source_id: &str,
         destination_id: &str,
         fragment_id: usize,
@@ -52,6 +56,14 @@ impl ExchangeWriterSink {
             source: source_id.to_string(),
             destination: destination_id.to_string(),
             fragment: fragment_id,
+            statistics: ExchangeEdgeStatistics::create(
+                tenant,
+                query_id,
+                fragment_id,
+                source_id,
+                destination_id,
+                true,
+            ),
         })
     }
 }
@@ -60,8 +72,15 @@ impl ExchangeWriterSink {
 impl AsyncSink for ExchangeWriterSink {
     const NAME: &'static str = "ExchangeWriterSink";
 
+    #[async_backtrace::framed]
+    async fn on_start(&mut self) -> Result<()> {
+        self.statistics.start();
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     async fn on_finish(&mut self) -> Result<()> {
+        self.statistics.finish();
         self.flight_sender.close();
         Ok(())
     }
@@ -81,6 +100,7 @@ impl AsyncSink for ExchangeWriterSink {
         let mut bytes = 0;
         for packet in serialize_meta.packet {
             bytes += packet.bytes_size();
+            self.statistics.record(&packet);
             if let Err(error) = self.flight_sender.send(packet).await {
                 if error.code() == ErrorCode::ABORTED_QUERY {
                     return Ok(true);
@@ -140,6 +160,8 @@ impl Sink for IgnoreExchangeSink {
 pub fn create_writer_item(
     exchange: FlightSender,
     ignore: bool,
+    tenant: &str,
+    query_id: &str,
     destination_id: &str,
     fragment_id: usize,
     source_id: &str,
@@ -151,6 +173,8 @@ pub fn create_writer_item(
             false => ProcessorPtr::create(ExchangeWriterSink::create(
                 input.clone(),
                 exchange,
+                tenant,
+                query_id,
                 source_id,
                 destination_id,
                 fragment_id,
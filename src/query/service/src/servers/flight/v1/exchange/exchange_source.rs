@@ -78,6 +78,8 @@ pub fn via_exchange_source(
             ExchangeSourceReader::create(
                 output.clone(),
                 flight_exchange,
+                ctx.get_tenant().tenant_name(),
+                &params.query_id,
                 &destination_id,
                 &ctx.get_cluster().local_id(),
                 params.fragment_id,
@@ -27,6 +27,7 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::PipeItem;
 use log::info;
 
+use super::exchange_edge_statistics::ExchangeEdgeStatistics;
 use crate::servers::flight::v1::exchange::serde::ExchangeDeserializeMeta;
 use crate::servers::flight::v1::packets::DataPacket;
 use crate::servers::flight::FlightReceiver;
@@ -39,12 +40,15 @@ pub struct ExchangeSourceReader {
     source: String,
     destination: String,
     fragment: usize,
+    statistics: ExchangeEdgeStatistics,
 }
 
 impl ExchangeSourceReader {
     pub fn create(
         output: Arc<OutputPort>,
         flight_receiver: FlightReceiver,
+        tenant: &str,
+        query_id: &str,
         source: &str,
         destination: &str,
         fragment: usize,
@@ -57,6 +61,14 @@ impl ExchangeSourceReader {
             finished: AtomicBool::new(false),
             output_data: vec![],
             fragment,
+            statistics: ExchangeEdgeStatistics::create(
+                tenant,
+                query_id,
+                fragment,
+                source,
+                destination,
+                false,
+            ),
         }))
     }
 }
@@ -114,9 +126,11 @@ impl Processor for ExchangeSourceReader {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
+        self.statistics.start();
         if self.output_data.is_empty() {
             let mut dictionaries = Vec::new();
             while let Some(output_data) = self.flight_receiver.recv().await? {
+                self.statistics.record(&output_data);
                 if !matches!(&output_data, DataPacket::Dictionary(_)) {
                     dictionaries.push(output_data);
                     self.output_data = dictionaries;
@@ -127,6 +141,7 @@ impl Processor for ExchangeSourceReader {
             }
 
             // assert!(dictionaries.is_empty());
+            self.statistics.finish();
         }
 
         if !self.finished.swap(true, Ordering::SeqCst) {
@@ -157,6 +172,8 @@ impl Processor for ExchangeSourceReader {
 
 pub fn create_reader_item(
     flight_receiver: FlightReceiver,
+    tenant: &str,
+    query_id: &str,
     source: &str,
     destination: &str,
     fragment: usize,
@@ -166,6 +183,8 @@ pub fn create_reader_item(
         ExchangeSourceReader::create(
             output.clone(),
             flight_receiver,
+            tenant,
+            query_id,
             source,
             destination,
             fragment,
@@ -63,6 +63,8 @@ impl ExchangeTransform {
                         false => create_writer_item(
                             sender,
                             false,
+                            ctx.get_tenant().tenant_name(),
+                            &params.query_id,
                             destination_id,
                             params.fragment_id,
                             &ctx.get_cluster().local_id(),
@@ -77,6 +79,8 @@ impl ExchangeTransform {
                         nodes_source += 1;
                         items.push(create_reader_item(
                             receiver,
+                            ctx.get_tenant().tenant_name(),
+                            &params.query_id,
                             &destination_id,
                             &params.executor_id,
                             params.fragment_id,
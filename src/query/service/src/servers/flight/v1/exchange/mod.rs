@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod data_exchange;
+mod exchange_edge_statistics;
 mod exchange_injector;
 mod exchange_manager;
 mod exchange_params;
@@ -33,6 +34,7 @@ pub use data_exchange::BroadcastExchange;
 pub use data_exchange::DataExchange;
 pub use data_exchange::MergeExchange;
 pub use data_exchange::ShuffleDataExchange;
+pub use data_exchange::ShuffleSalt;
 pub use exchange_injector::DefaultExchangeInjector;
 pub use exchange_injector::ExchangeInjector;
 pub use exchange_manager::DataExchangeManager;
@@ -24,6 +24,7 @@ use databend_common_arrow::arrow_format::flight::data::FlightData;
 use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetricValues;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_io::prelude::BinaryRead;
 use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::MutationStatus;
@@ -44,6 +45,11 @@ impl FragmentData {
     pub fn create(meta: Vec<u8>, data: FlightData) -> FragmentData {
         FragmentData { meta, data }
     }
+
+    pub fn num_rows(&self) -> Result<usize> {
+        let mut row_count_meta = &self.get_meta()[..std::mem::size_of::<u32>()];
+        Ok(row_count_meta.read_scalar::<u32>()? as usize)
+    }
 }
 
 impl Debug for FragmentData {
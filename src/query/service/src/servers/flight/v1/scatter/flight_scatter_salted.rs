@@ -0,0 +1,188 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::servers::flight::v1::exchange::ShuffleSalt;
+use crate::servers::flight::v1::scatter::flight_scatter::FlightScatter;
+use crate::servers::flight::v1::scatter::flight_scatter_hash::get_hash_values;
+
+/// Hash scatter with salted partitions for the inputs of a hash join, see [`ShuffleSalt`].
+pub struct SaltedHashFlightScatter {
+    func_ctx: FunctionContext,
+    hash_key: Vec<Expr>,
+    scatter_size: usize,
+    buckets: usize,
+    hot_keys: HashSet<u64>,
+    replicate: bool,
+    next_salt: AtomicUsize,
+}
+
+impl SaltedHashFlightScatter {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        hash_keys: Vec<RemoteExpr>,
+        scatter_size: usize,
+        salt: &ShuffleSalt,
+    ) -> Result<Box<dyn FlightScatter>> {
+        let (buckets, hot_keys, replicate) = match salt {
+            ShuffleSalt::Replicate { buckets, hot_keys } => (*buckets, hot_keys.clone(), true),
+            ShuffleSalt::Spread { buckets, hot_keys } => (*buckets, hot_keys.clone(), false),
+        };
+
+        Ok(Box::new(SaltedHashFlightScatter {
+            func_ctx,
+            hash_key: hash_key_exprs(&hash_keys)?,
+            scatter_size,
+            buckets: buckets.max(1),
+            hot_keys,
+            replicate,
+            next_salt: AtomicUsize::new(0),
+        }))
+    }
+
+    fn partition(&self, hash: u64, salt: usize) -> usize {
+        let mut hasher = DefaultHasher::default();
+        hasher.write_u64(hash);
+        hasher.write_usize(salt);
+        (hasher.finish() % self.scatter_size as u64) as usize
+    }
+}
+
+impl FlightScatter for SaltedHashFlightScatter {
+    fn execute(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        let hashes = row_hashes(&self.func_ctx, &self.hash_key, &data_block)?;
+        let mut indices = vec![Vec::<u32>::new(); self.scatter_size];
+
+        let mut partitions = Vec::with_capacity(self.buckets);
+        for (row, hash) in hashes.iter().enumerate() {
+            if !self.hot_keys.contains(hash) {
+                indices[self.partition(*hash, 0)].push(row as u32);
+            } else if self.replicate {
+                partitions.clear();
+                for salt in 0..self.buckets {
+                    let partition = self.partition(*hash, salt);
+                    if !partitions.contains(&partition) {
+                        partitions.push(partition);
+                        indices[partition].push(row as u32);
+                    }
+                }
+            } else {
+                let salt = self.next_salt.fetch_add(1, Ordering::Relaxed) % self.buckets;
+                indices[self.partition(*hash, salt)].push(row as u32);
+            }
+        }
+
+        let block_meta = data_block.get_meta();
+        let mut res = Vec::with_capacity(self.scatter_size);
+        for indices in indices {
+            let block = data_block.take(&indices, &mut None)?;
+            res.push(block.add_meta(block_meta.cloned())?);
+        }
+        Ok(res)
+    }
+}
+
+/// Counts the hashes of the join keys of sampled rows to find the hot keys of a salted shuffle.
+pub struct HotKeySampler {
+    func_ctx: FunctionContext,
+    hash_key: Vec<Expr>,
+    sampled_rows: usize,
+    counts: HashMap<u64, usize>,
+}
+
+impl HotKeySampler {
+    pub fn try_create(func_ctx: FunctionContext, hash_keys: &[RemoteExpr]) -> Result<Self> {
+        Ok(HotKeySampler {
+            func_ctx,
+            hash_key: hash_key_exprs(hash_keys)?,
+            sampled_rows: 0,
+            counts: HashMap::new(),
+        })
+    }
+
+    pub fn sampled_rows(&self) -> usize {
+        self.sampled_rows
+    }
+
+    /// Sample the rows of `data_block` until `sample_rows` rows are sampled in total.
+    pub fn sample(&mut self, data_block: &DataBlock, sample_rows: usize) -> Result<()> {
+        let hashes = row_hashes(&self.func_ctx, &self.hash_key, data_block)?;
+        for hash in hashes
+            .iter()
+            .take(sample_rows.saturating_sub(self.sampled_rows))
+        {
+            *self.counts.entry(*hash).or_default() += 1;
+            self.sampled_rows += 1;
+        }
+        Ok(())
+    }
+
+    /// The hashes of the keys which take at least `hot_key_percent` of the sampled rows.
+    pub fn hot_keys(self, hot_key_percent: u64) -> HashSet<u64> {
+        let threshold = (self.sampled_rows as u64 * hot_key_percent).div_ceil(100) as usize;
+        self.counts
+            .into_iter()
+            .filter(|(_, count)| *count >= threshold)
+            .map(|(hash, _)| hash)
+            .collect()
+    }
+}
+
+fn hash_key_exprs(hash_keys: &[RemoteExpr]) -> Result<Vec<Expr>> {
+    hash_keys
+        .iter()
+        .map(|key| {
+            check_function(
+                None,
+                "siphash",
+                &[],
+                &[key.as_expr(&BUILTIN_FUNCTIONS)],
+                &BUILTIN_FUNCTIONS,
+            )
+        })
+        .collect()
+}
+
+/// The hash of the join keys of each row, the same on the build and probe sides.
+fn row_hashes(
+    func_ctx: &FunctionContext,
+    hash_key: &[Expr],
+    data_block: &DataBlock,
+) -> Result<Vec<u64>> {
+    let evaluator = Evaluator::new(data_block, func_ctx, &BUILTIN_FUNCTIONS);
+    let num_rows = data_block.num_rows();
+    let mut hashes = vec![DefaultHasher::default(); num_rows];
+    for expr in hash_key {
+        let values = get_hash_values(evaluator.run(expr)?, num_rows, 0)?;
+        for (hash, value) in hashes.iter_mut().zip(values.iter()) {
+            hash.write_u64(*value);
+        }
+    }
+    Ok(hashes.into_iter().map(|hash| hash.finish()).collect())
+}
@@ -18,6 +18,10 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
+use arrow_array::RecordBatch;
+use arrow_flight::decode::FlightRecordBatchStream;
+use arrow_flight::error::FlightError;
+use arrow_flight::flight_descriptor::DescriptorType;
 use arrow_flight::FlightData;
 use arrow_flight::SchemaAsIpc;
 use arrow_ipc::writer;
@@ -30,19 +34,24 @@ use bytes::Bytes;
 use databend_common_base::base::tokio;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
 use databend_common_sql::get_query_kind;
+use databend_common_sql::plans::InsertInputSource;
+use databend_common_sql::plans::InsertValue;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
 use databend_common_storages_fuse::TableContext;
 use futures::Stream;
 use futures::StreamExt;
+use parking_lot::Mutex;
 use prost::bytes;
 use serde::Deserialize;
 use serde::Serialize;
 use tonic::Status;
+use tonic::Streaming;
 
 use super::status;
 use super::DoGetStream;
@@ -77,6 +86,10 @@ static DATA_HEADER_PROGRESS: LazyLock<Bytes> = LazyLock::new(|| {
     Bytes::copy_from_slice(fbb.finished_data())
 });
 
+/// The number of blocks buffered between the `DoExchange` stream and the insert pipeline,
+/// the client stops being read when the buffer is full.
+const INSERT_STREAM_BUFFER_SIZE: usize = 4;
+
 impl FlightSqlServiceImpl {
     pub(crate) fn schema_to_flight_data(data_schema: DataSchema) -> FlightData {
         let arrow_schema = ArrowSchema::from(&data_schema);
@@ -151,6 +164,133 @@ impl FlightSqlServiceImpl {
         Ok(affected_rows as i64)
     }
 
+    /// Insert the arrow record batches pushed by `DoExchange`.
+    ///
+    /// The flight descriptor of the first message is the insert statement without values,
+    /// e.g. `INSERT INTO t (a, b) VALUES`, and the following messages are the schema
+    /// and the record batches to insert.
+    #[async_backtrace::framed]
+    pub(super) async fn exchange_insert(
+        &self,
+        session: Arc<Session>,
+        mut stream: Streaming<FlightData>,
+    ) -> Result<i64> {
+        let first = match stream.next().await {
+            Some(data) => data.map_err(|e| ErrorCode::BadBytes(e.to_string()))?,
+            None => return Err(ErrorCode::BadArguments("DoExchange stream is empty")),
+        };
+        let query = match &first.flight_descriptor {
+            Some(descriptor) if descriptor.r#type == DescriptorType::Cmd as i32 => {
+                String::from_utf8(descriptor.cmd.to_vec())?
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "the first message of DoExchange must carry the insert statement as the command of flight descriptor",
+                ));
+            }
+        };
+        info!("exchange_insert with query = {query}");
+
+        let (mut plan, plan_extras) = self.plan_sql(&session, &query).await?;
+        let (sender, receiver) = tokio::sync::mpsc::channel(INSERT_STREAM_BUFFER_SIZE);
+        let dest_schema = match &mut plan {
+            Plan::Insert(insert)
+                if matches!(
+                    &insert.source,
+                    InsertInputSource::Values(InsertValue::RawValues { data, .. })
+                        if data.trim().trim_end_matches(';').is_empty()
+                ) =>
+            {
+                insert.source = InsertInputSource::Stream(Arc::new(Mutex::new(Some(receiver))));
+                insert.dest_schema()
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "DoExchange expects `INSERT INTO <table> [(<columns>)] VALUES` without values, but got: {query}"
+                )));
+            }
+        };
+
+        // The first message may carry the schema together with the descriptor.
+        let first = (!first.data_header.is_empty()).then_some(Ok(first));
+        let stream = futures::stream::iter(first)
+            .chain(stream)
+            .map(|data| data.map_err(FlightError::Tonic));
+        let mut batches = FlightRecordBatchStream::new_from_flight_data(stream);
+        databend_common_base::runtime::spawn(async move {
+            while let Some(batch) = batches.next().await {
+                let block = batch
+                    .map_err(|e| ErrorCode::BadBytes(format!("decode record batch failed: {e}")))
+                    .and_then(|batch| Self::record_batch_to_block(&batch, &dest_schema));
+                let is_err = block.is_err();
+                // Stop reading the client if the insert pipeline is finished or aborted.
+                if sender.send(block).await.is_err() || is_err {
+                    break;
+                }
+            }
+        });
+
+        self.execute_update(session, &plan, &plan_extras).await
+    }
+
+    /// Convert the record batch into a block of the insert schema, the fields are matched by position.
+    fn record_batch_to_block(batch: &RecordBatch, dest_schema: &DataSchema) -> Result<DataBlock> {
+        let schema = DataSchema::try_from(batch.schema().as_ref())?;
+        if schema.num_fields() != dest_schema.num_fields() {
+            return Err(ErrorCode::BadArguments(format!(
+                "record batch has {} fields, but the insert statement expects {}",
+                schema.num_fields(),
+                dest_schema.num_fields()
+            )));
+        }
+
+        let mut columns = Vec::with_capacity(batch.num_columns());
+        for ((array, field), dest_field) in batch
+            .columns()
+            .iter()
+            .zip(schema.fields())
+            .zip(dest_schema.fields())
+        {
+            if !field.name().eq_ignore_ascii_case(dest_field.name()) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "record batch field `{}` does not match column `{}`",
+                    field.name(),
+                    dest_field.name()
+                )));
+            }
+            let column = Column::from_arrow_rs(array.clone(), field.data_type())?;
+            let (src_type, dest_type) = (field.data_type(), dest_field.data_type());
+            let column = if src_type == dest_type {
+                column
+            } else if dest_type.is_nullable() && src_type == &dest_type.remove_nullable() {
+                column.wrap_nullable(None)
+            } else if src_type.is_nullable() && &src_type.remove_nullable() == dest_type {
+                // Arrow fields are nullable by default, accept them if there is no null.
+                match column {
+                    Column::Nullable(box nullable) if nullable.validity.unset_bits() == 0 => {
+                        nullable.column
+                    }
+                    _ => {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "column `{}` is not nullable, but the record batch contains NULL",
+                            dest_field.name()
+                        )));
+                    }
+                }
+            } else {
+                return Err(ErrorCode::BadArguments(format!(
+                    "record batch field `{}` has type {}, but column `{}` is {}",
+                    field.name(),
+                    src_type,
+                    dest_field.name(),
+                    dest_type
+                )));
+            };
+            columns.push(column);
+        }
+        Ok(DataBlock::new_from_columns(columns))
+    }
+
     pub async fn execute_query(
         &self,
         session: Arc<Session>,
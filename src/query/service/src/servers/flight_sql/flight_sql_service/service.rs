@@ -54,6 +54,7 @@ use arrow_flight::sql::ProstMessageExt;
 use arrow_flight::sql::SqlInfo;
 use arrow_flight::sql::TicketStatementQuery;
 use arrow_flight::Action;
+use arrow_flight::FlightData;
 use arrow_flight::FlightDescriptor;
 use arrow_flight::FlightEndpoint;
 use arrow_flight::FlightInfo;
@@ -521,6 +522,24 @@ impl FlightSqlService for FlightSqlServiceImpl {
         Ok(res)
     }
 
+    // called by arrow clients to push record batches into a table.
+    #[async_backtrace::framed]
+    async fn do_exchange_fallback(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<<Self as FlightService>::DoExchangeStream>, Status> {
+        let session = self.get_session(&request)?;
+        let record_count = self
+            .exchange_insert(session, request.into_inner())
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+
+        let result = DoPutUpdateResult { record_count };
+        let flight_data = FlightData::new().with_app_metadata(result.encode_to_vec());
+        let stream = futures::stream::once(async move { Ok(flight_data) });
+        Ok(Response::new(Box::pin(stream)))
+    }
+
     #[async_backtrace::framed]
     async fn do_put_prepared_statement_query(
         &self,
@@ -12,37 +12,62 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_stream::stream;
 use databend_common_base::base::mask_connection_info;
 use databend_common_base::headers::HEADER_QUERY_ID;
 use databend_common_base::headers::HEADER_QUERY_PAGE_ROWS;
 use databend_common_base::headers::HEADER_QUERY_STATE;
 use databend_common_base::runtime::drop_guard;
 use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::infer_table_schema;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_formats::output_format::ArrowOutputFormat;
+use databend_common_formats::output_format::OutputFormat;
+use databend_common_formats::output_format::ParquetOutputFormat;
+use databend_common_formats::FileFormatOptionsExt;
 use databend_common_metrics::http::metrics_incr_http_response_errors_count;
 use fastrace::full_name;
 use fastrace::prelude::*;
+use futures::TryStreamExt;
 use highway::HighwayHash;
+use http::header::ACCEPT;
+use http::HeaderMap;
 use http::StatusCode;
 use log::error;
 use log::info;
 use log::warn;
 use poem::error::Error as PoemError;
+use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
 use poem::get;
+use poem::middleware::Compression;
 use poem::post;
 use poem::web::Json;
 use poem::web::Path;
+use poem::Body;
 use poem::EndpointExt;
 use poem::IntoResponse;
+use poem::Response;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
+use uuid::Uuid;
 
+use super::query::get_spooled_response;
 use super::query::ExecuteStateKind;
+use super::query::HttpQuery;
 use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
+use super::query::PageFormat;
+use super::query::PaginationConf;
 use super::query::RemoveReason;
+use super::query::ResultFormat;
 use crate::servers::http::middleware::MetricsMiddleware;
 use crate::servers::http::v1::query::Progresses;
 use crate::servers::http::v1::HttpQueryContext;
@@ -50,6 +75,8 @@ use crate::servers::http::v1::HttpQueryManager;
 use crate::servers::http::v1::HttpSessionConf;
 use crate::servers::http::v1::StringBlock;
 use crate::sessions::QueryAffect;
+use crate::sessions::Session;
+use crate::sessions::SessionType;
 
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
@@ -127,6 +154,13 @@ pub struct QueryResponse {
     pub has_result_set: Option<bool>,
     pub schema: Vec<QueryResponseField>,
     pub data: Vec<Vec<Option<String>>>,
+    /// Only set when the request asks for `"page_format": "columns"`, `data` is empty then.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<Vec<Option<String>>>>,
+    /// Intermediate result of a running aggregation, it is NOT final and only
+    /// returned while the query is running, the final result is always in `data`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progressive_data: Option<Vec<Vec<Option<String>>>>,
     pub affect: Option<QueryAffect>,
 
     pub stats: QueryStats,
@@ -183,9 +217,20 @@ impl QueryResponse {
             running_time_ms: state.running_time_ms,
         };
         let rows = data.data.len();
+        let progressive_data = match (is_final, state.state) {
+            (false, ExecuteStateKind::Running) => r.progressive_data.map(|d| d.into()),
+            _ => None,
+        };
+
+        let (data, columns) = match r.page_format {
+            PageFormat::Rows => (data.into(), None),
+            PageFormat::Columns => (vec![], Some(data.into_columns(state.schema.len()))),
+        };
 
         Json(QueryResponse {
-            data: data.into(),
+            data,
+            columns,
+            progressive_data,
             state: state.state,
             schema: state.schema.clone(),
             session_id: Some(session_id),
@@ -223,7 +268,7 @@ async fn query_final_handler(
     ctx: &HttpQueryContext,
     Path(query_id): Path<String>,
 ) -> PoemResult<impl IntoResponse> {
-    ctx.check_node_id(&query_id)?;
+    let routed = ctx.check_node_id(&query_id);
     let root = get_http_tracing_span(full_name!(), ctx, &query_id);
     let _t = SlowRequestLogTracker::new(ctx);
     async {
@@ -233,22 +278,31 @@ async fn query_final_handler(
             make_final_uri(&query_id)
         );
         let http_query_manager = HttpQueryManager::instance();
-        match http_query_manager
-            .remove_query(
-                &query_id,
-                RemoveReason::Finished,
-                ErrorCode::ClosedQuery("closed by client"),
-            )
-            .await
-        {
+        let query = match routed {
+            Ok(_) => {
+                http_query_manager
+                    .remove_query(
+                        &query_id,
+                        RemoveReason::Finished,
+                        ErrorCode::ClosedQuery("closed by client"),
+                    )
+                    .await
+            }
+            Err(_) => None,
+        };
+        match query {
             Some(query) => {
                 let mut response = query.get_response_state_only().await;
+                query.remove_result_spool().await;
                 // it is safe to set these 2 fields to None, because client now check for null/None first.
                 response.session = None;
                 response.state.affect = None;
                 Ok(QueryResponse::from_internal(query_id, response, true))
             }
-            None => Err(query_id_not_found(&query_id, &ctx.node_id)),
+            None => match try_get_spooled_response(ctx, &query_id, None).await {
+                Some(response) => Ok(QueryResponse::from_internal(query_id, response, true)),
+                None => Err(not_found_error(routed, &query_id, &ctx.node_id)),
+            },
         }
     }
     .in_span(root)
@@ -318,13 +372,17 @@ async fn query_page_handler(
     ctx: &HttpQueryContext,
     Path((query_id, page_no)): Path<(String, usize)>,
 ) -> PoemResult<impl IntoResponse> {
-    ctx.check_node_id(&query_id)?;
+    let routed = ctx.check_node_id(&query_id);
     let root = get_http_tracing_span(full_name!(), ctx, &query_id);
     let _t = SlowRequestLogTracker::new(ctx);
 
     async {
         let http_query_manager = HttpQueryManager::instance();
-        match http_query_manager.get_query(&query_id) {
+        let query = match routed {
+            Ok(_) => http_query_manager.get_query(&query_id),
+            Err(_) => None,
+        };
+        match query {
             Some(query) => {
                 if let Some(reason) = query.check_removed() {
                     Err(query_id_removed(&query_id, reason))
@@ -337,7 +395,11 @@ async fn query_page_handler(
                     Ok(QueryResponse::from_internal(query_id, resp, false))
                 }
             }
-            None => Err(query_id_not_found(&query_id, &ctx.node_id)),
+            // the node of the query may be restarted or gone, try the spooled result.
+            None => match try_get_spooled_response(ctx, &query_id, Some(page_no)).await {
+                Some(resp) => Ok(QueryResponse::from_internal(query_id, resp, false)),
+                None => Err(not_found_error(routed, &query_id, &ctx.node_id)),
+            },
         }
     }
     .in_span(root)
@@ -348,7 +410,8 @@ async fn query_page_handler(
 #[async_backtrace::framed]
 pub(crate) async fn query_handler(
     ctx: &HttpQueryContext,
-    Json(req): Json<HttpQueryRequest>,
+    headers: &HeaderMap,
+    Json(mut req): Json<HttpQueryRequest>,
 ) -> PoemResult<impl IntoResponse> {
     let root = get_http_tracing_span(full_name!(), ctx, &ctx.query_id);
     let _t = SlowRequestLogTracker::new(ctx);
@@ -358,12 +421,22 @@ pub(crate) async fn query_handler(
         info!("http query new request{}: {:}", agent, mask_connection_info(&format!("{:?}", req)));
         let http_query_manager = HttpQueryManager::instance();
         let sql = req.sql.clone();
+        if req.result_format.is_none() {
+            req.result_format = headers
+                .get(ACCEPT)
+                .and_then(|v| v.to_str().ok())
+                .and_then(ResultFormat::from_accept_header);
+        }
+        let result_format = req.result_format.unwrap_or_default();
 
         let query = http_query_manager
             .try_create_query(ctx, req.clone())
             .await
             .map_err(|err| err.display_with_sql(&sql));
         match query {
+            Ok(query) if result_format != ResultFormat::Json => {
+                query_binary_result(ctx, query, result_format).await
+            }
             Ok(query) => {
                 query.update_expire_time(true).await;
                 // tmp workaround to tolerant old clients
@@ -396,10 +469,349 @@ pub(crate) async fn query_handler(
         .await
 }
 
+/// Stream the whole result of the query in a binary format instead of the json pages.
+///
+/// The query is removed once the result is drained, so the session state changed by the query
+/// is not returned to the client.
+#[async_backtrace::framed]
+async fn query_binary_result(
+    ctx: &HttpQueryContext,
+    query: Arc<HttpQuery>,
+    result_format: ResultFormat,
+) -> PoemResult<Response> {
+    query.update_expire_time(true).await;
+    let first_block = query.next_result_block().await;
+    query.update_expire_time(false).await;
+
+    let schema = match &first_block {
+        Some(first_block) => {
+            // the names are taken from the plan, the types from the blocks.
+            let state = query.get_state().await;
+            let fields = state
+                .schema
+                .iter()
+                .zip(first_block.columns())
+                .map(|(field, entry)| DataField::new(&field.name, entry.data_type.clone()))
+                .collect::<Vec<_>>();
+            DataSchemaRefExt::create(fields)
+        }
+        None => {
+            // an empty result still gets a payload with the schema, unless the query failed.
+            let state = query.get_stopped_state().await;
+            if let Some(err) = state.error {
+                remove_binary_result_query(&query).await;
+                metrics_incr_http_response_errors_count(err.name(), err.code());
+                ctx.set_fail();
+                return Err(PoemError::from_string(
+                    err.message(),
+                    StatusCode::BAD_REQUEST,
+                ));
+            }
+            query.get_result_schema().await
+        }
+    };
+    let schema = infer_table_schema(&schema).map_err(InternalServerError)?;
+    let mut output_format: Box<dyn OutputFormat> = match result_format {
+        ResultFormat::Arrow => {
+            Box::new(ArrowOutputFormat::try_create(schema).map_err(InternalServerError)?)
+        }
+        _ => {
+            let options_ext =
+                FileFormatOptionsExt::create_from_settings(&ctx.session.get_settings(), true)
+                    .map_err(InternalServerError)?;
+            Box::new(ParquetOutputFormat::create(schema, &options_ext))
+        }
+    };
+
+    let query_id = query.id.clone();
+    let stream = stream! {
+        if let Some(first_block) = first_block {
+            yield output_format.serialize_block(&first_block);
+        }
+        loop {
+            query.update_expire_time(true).await;
+            let block = query.next_result_block().await;
+            // the query expires if the client stops reading the result.
+            query.update_expire_time(false).await;
+            match block {
+                Some(block) => yield output_format.serialize_block(&block),
+                None => break,
+            }
+        }
+
+        let state = query.get_stopped_state().await;
+        remove_binary_result_query(&query).await;
+        match state.error {
+            // abort the response, so the client does not take a truncated result as complete.
+            Some(err) => {
+                metrics_incr_http_response_errors_count(err.name(), err.code());
+                yield Err(err)
+            }
+            None => yield output_format.finalize(),
+        }
+    };
+    let stream = stream.map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err));
+    Ok(Body::from_bytes_stream(stream)
+        .with_content_type(result_format.content_type())
+        .with_header(HEADER_QUERY_ID, query_id)
+        .into_response())
+}
+
+async fn remove_binary_result_query(query: &HttpQuery) {
+    HttpQueryManager::instance()
+        .remove_query(
+            &query.id,
+            RemoveReason::Finished,
+            ErrorCode::ClosedQuery("closed by the end of the result"),
+        )
+        .await;
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct QueryBatchRequest {
+    pub session: Option<HttpSessionConf>,
+    pub statements: Vec<String>,
+    /// Run the statements in one transaction, which is rolled back if any statement fails.
+    #[serde(default)]
+    pub transactional: bool,
+    #[serde(default)]
+    pub pagination: PaginationConf,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QueryBatchResult {
+    pub id: String,
+    pub sql: String,
+    pub state: ExecuteStateKind,
+    pub error: Option<QueryError>,
+    pub warnings: Vec<String>,
+    pub schema: Vec<QueryResponseField>,
+    pub data: Vec<Vec<Option<String>>>,
+    pub affect: Option<QueryAffect>,
+    pub stats: QueryStats,
+}
+
+impl QueryBatchResult {
+    fn fail_to_start_sql(id: String, sql: &str, err: ErrorCode) -> Self {
+        metrics_incr_http_response_errors_count(err.name(), err.code());
+        QueryBatchResult {
+            id,
+            sql: sql.to_string(),
+            state: ExecuteStateKind::Failed,
+            error: Some(QueryError::from_error_code(err)),
+            warnings: vec![],
+            schema: vec![],
+            data: vec![],
+            affect: None,
+            stats: QueryStats::default(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QueryBatchResponse {
+    /// Only set if the server side session is kept by `keep_server_session_secs`.
+    pub session_id: Option<String>,
+    pub session: Option<HttpSessionConf>,
+    /// Results of the executed statements, the batch stops at the first failed one.
+    pub results: Vec<QueryBatchResult>,
+    pub error: Option<QueryError>,
+}
+
+/// Execute the statements one by one in the same session, and reply all the results at once.
+#[poem::handler]
+#[async_backtrace::framed]
+async fn query_batch_handler(
+    ctx: &HttpQueryContext,
+    Json(req): Json<QueryBatchRequest>,
+) -> PoemResult<impl IntoResponse> {
+    let root = get_http_tracing_span(full_name!(), ctx, &ctx.query_id);
+    let _t = SlowRequestLogTracker::new(ctx);
+
+    async {
+        info!(
+            "http query batch new request: {} statements, transactional = {}",
+            req.statements.len(),
+            req.transactional
+        );
+        if req.statements.is_empty() {
+            return Err(PoemError::from_string(
+                "no statements in the batch",
+                StatusCode::BAD_REQUEST,
+            ));
+        }
+
+        let http_query_manager = HttpQueryManager::instance();
+        let session = ctx.upgrade_session(SessionType::HTTPQuery)?;
+
+        let mut statements = req
+            .statements
+            .iter()
+            .map(|sql| (sql.as_str(), true))
+            .collect::<Vec<_>>();
+        if req.transactional {
+            statements.insert(0, ("BEGIN", false));
+            statements.push(("COMMIT", false));
+        }
+
+        // the session conf of the request is only applied by the first statement,
+        // the following ones run in the same server side session.
+        let mut session_conf = req.session.clone();
+        let mut last_session_conf = req.session.clone();
+        let mut results = vec![];
+        let mut error = None;
+        for (sql, is_reported) in statements {
+            let (result, conf) =
+                execute_batch_statement(ctx, &session, sql, session_conf.take(), &req.pagination)
+                    .await;
+            last_session_conf = conf.or(last_session_conf);
+            let failed = result.error.clone();
+            if is_reported {
+                results.push(result);
+            }
+            if let Some(err) = failed {
+                error = Some(err);
+                break;
+            }
+        }
+
+        if error.is_some() && req.transactional {
+            let (result, conf) =
+                execute_batch_statement(ctx, &session, "ROLLBACK", None, &req.pagination).await;
+            if let Some(err) = result.error {
+                warn!("http query batch fail to rollback: {:?}", err);
+            }
+            last_session_conf = conf.or(last_session_conf);
+        }
+
+        let keep_server_session_secs = req
+            .session
+            .as_ref()
+            .and_then(|conf| conf.keep_server_session_secs);
+        let session_id = match keep_server_session_secs {
+            Some(secs) if secs > 0 => {
+                http_query_manager
+                    .add_session(session.clone(), Duration::from_secs(secs))
+                    .await;
+                Some(session.get_id())
+            }
+            _ => {
+                http_query_manager.kill_session(&session.get_id());
+                None
+            }
+        };
+        let session_conf = last_session_conf.map(|conf| HttpSessionConf {
+            keep_server_session_secs,
+            ..conf
+        });
+
+        if error.is_some() {
+            ctx.set_fail();
+        }
+        Ok(Json(QueryBatchResponse {
+            session_id,
+            session: session_conf,
+            results,
+            error,
+        }))
+    }
+    .in_span(root)
+    .await
+}
+
+#[async_backtrace::framed]
+async fn execute_batch_statement(
+    ctx: &HttpQueryContext,
+    session: &Arc<Session>,
+    sql: &str,
+    session_conf: Option<HttpSessionConf>,
+    pagination: &PaginationConf,
+) -> (QueryBatchResult, Option<HttpSessionConf>) {
+    // each statement is a separate query, with its own query id.
+    let mut ctx = ctx.clone();
+    ctx.query_id = Uuid::new_v4().to_string();
+
+    match try_execute_batch_statement(&ctx, session, sql, session_conf, pagination).await {
+        Ok(res) => res,
+        Err(err) => {
+            error!("http query batch fail to execute sql, error: {:?}", err);
+            let err = err.display_with_sql(sql);
+            let result = QueryBatchResult::fail_to_start_sql(ctx.query_id, sql, err);
+            (result, None)
+        }
+    }
+}
+
+#[async_backtrace::framed]
+async fn try_execute_batch_statement(
+    ctx: &HttpQueryContext,
+    session: &Arc<Session>,
+    sql: &str,
+    session_conf: Option<HttpSessionConf>,
+    pagination: &PaginationConf,
+) -> Result<(QueryBatchResult, Option<HttpSessionConf>)> {
+    let http_query_manager = HttpQueryManager::instance();
+
+    // keep the session alive until the statement attaches to it.
+    let result_timeout_secs = session
+        .get_settings()
+        .get_http_handler_result_timeout_secs()?;
+    http_query_manager
+        .add_session(session.clone(), Duration::from_secs(result_timeout_secs))
+        .await;
+
+    let req = HttpQueryRequest {
+        session_id: Some(session.get_id()),
+        session: session_conf,
+        sql: sql.to_string(),
+        pagination: pagination.clone(),
+        string_fields: true,
+        stage_attachment: None,
+        result_format: None,
+    };
+    let query = http_query_manager.try_create_query(ctx, req).await?;
+
+    let max_result_bytes = session
+        .get_settings()
+        .get_http_handler_batch_result_max_bytes()?;
+    query.update_expire_time(true).await;
+    let response = query.get_full_response(max_result_bytes).await;
+    http_query_manager
+        .remove_query(
+            &query.id,
+            RemoveReason::Finished,
+            ErrorCode::ClosedQuery("closed by client"),
+        )
+        .await;
+    query.remove_result_spool().await;
+
+    let (data, response) = response?;
+    let state = response.state;
+    if let Some(err) = &state.error {
+        metrics_incr_http_response_errors_count(err.name(), err.code());
+    }
+    let result = QueryBatchResult {
+        id: query.id.clone(),
+        sql: sql.to_string(),
+        state: state.state,
+        error: state.error.map(QueryError::from_error_code),
+        warnings: state.warnings,
+        schema: state.schema,
+        data,
+        affect: state.affect,
+        stats: QueryStats {
+            progresses: state.progresses,
+            running_time_ms: state.running_time_ms,
+        },
+    };
+    Ok((result, response.session))
+}
+
 pub fn query_route() -> Route {
     // Note: endpoints except /v1/query may change without notice, use uris in response instead
     let rules = [
         ("/", post(query_handler)),
+        ("/batch", post(query_batch_handler)),
         ("/:id", get(query_state_handler)),
         ("/:id/page/:page_no", get(query_page_handler)),
         (
@@ -414,7 +826,13 @@ pub fn query_route() -> Route {
 
     let mut route = Route::new();
     for (path, endpoint) in rules.into_iter() {
-        route = route.at(path, endpoint.with(MetricsMiddleware::new(path)));
+        // result pages are compressed if the client sends `Accept-Encoding` (gzip, zstd, ...)
+        route = route.at(
+            path,
+            endpoint
+                .with(MetricsMiddleware::new(path))
+                .with(Compression::default()),
+        );
     }
     route
 }
@@ -433,6 +851,26 @@ fn query_id_not_found(query_id: &str, node_id: &str) -> PoemError {
     )
 }
 
+fn not_found_error(routed: PoemResult<()>, query_id: &str, node_id: &str) -> PoemError {
+    match routed {
+        Ok(_) => query_id_not_found(query_id, node_id),
+        Err(e) => e,
+    }
+}
+
+async fn try_get_spooled_response(
+    ctx: &HttpQueryContext,
+    query_id: &str,
+    page_no: Option<usize>,
+) -> Option<HttpQueryResponseInternal> {
+    get_spooled_response(ctx, query_id, page_no)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("{}: fail to get spooled result: {}", query_id, e);
+            None
+        })
+}
+
 fn query_id_to_trace_id(query_id: &str) -> TraceId {
     let [hash_high, hash_low] = highway::PortableHash::default().hash128(query_id.as_bytes());
     TraceId(((hash_high as u128) << 64) + (hash_low as u128))
@@ -23,6 +23,8 @@ pub use http_query_handlers::make_final_uri;
 pub use http_query_handlers::make_page_uri;
 pub use http_query_handlers::make_state_uri;
 pub use http_query_handlers::query_route;
+pub use http_query_handlers::QueryBatchResponse;
+pub use http_query_handlers::QueryBatchResult;
 pub use http_query_handlers::QueryError;
 pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
@@ -33,6 +35,10 @@ pub use query::ExpiringState;
 pub use query::HttpQueryContext;
 pub use query::HttpQueryManager;
 pub use query::HttpSessionConf;
+pub use query::PageFormat;
+pub use query::ResultFormat;
+pub use query::ResultSpool;
+pub use query::ResultSpoolMeta;
 pub use stage::upload_to_stage;
 pub use stage::UploadToStageResponse;
 pub(crate) use string_block::StringBlock;
@@ -15,12 +15,14 @@
 use std::sync::Arc;
 use std::time::SystemTime;
 
+use databend_common_base::base::tokio::sync::watch;
 use databend_common_base::base::tokio::sync::RwLock;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::CatchUnwindFuture;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_settings::Settings;
@@ -116,6 +118,7 @@ pub struct ExecuteRunning {
     // mainly used to get progress for now
     ctx: Arc<QueryContext>,
     schema: Vec<QueryResponseField>,
+    result_schema: DataSchemaRef,
     has_result_set: bool,
     #[allow(dead_code)]
     queue_guard: AcquireQueueGuard,
@@ -123,6 +126,7 @@ pub struct ExecuteRunning {
 
 pub struct ExecuteStopped {
     pub schema: Vec<QueryResponseField>,
+    pub result_schema: DataSchemaRef,
     pub has_result_set: Option<bool>,
     pub stats: Progresses,
     pub affect: Option<QueryAffect>,
@@ -135,6 +139,8 @@ pub struct ExecuteStopped {
 pub struct Executor {
     pub query_id: String,
     pub state: ExecuteState,
+    // set to true once the state changes to Stopped.
+    pub stopped: watch::Sender<bool>,
 }
 
 // ExecutorSessionState is used to record the session state when the query is stopped.
@@ -162,6 +168,14 @@ impl ExecutorSessionState {
 }
 
 impl Executor {
+    pub fn create(query_id: String, ctx: Arc<QueryContext>) -> Self {
+        Executor {
+            query_id,
+            state: Starting(ExecuteStarting { ctx }),
+            stopped: watch::channel(false).0,
+        }
+    }
+
     pub fn get_response_state(&self) -> ResponseState {
         let (exe_state, err) = self.state.extract();
         ResponseState {
@@ -183,6 +197,15 @@ impl Executor {
         }
     }
 
+    /// The schema of the result set, empty if the statement has no result set.
+    pub fn get_result_schema(&self) -> DataSchemaRef {
+        match &self.state {
+            Starting(_) => Arc::new(DataSchema::empty()),
+            Running(r) => r.result_schema.clone(),
+            Stopped(f) => f.result_schema.clone(),
+        }
+    }
+
     pub fn has_result_set(&self) -> Option<bool> {
         match &self.state {
             Starting(_) => None,
@@ -215,6 +238,14 @@ impl Executor {
         }
     }
 
+    /// The intermediate result published by a running aggregation, if any.
+    pub fn get_progressive_result(&self) -> Option<DataBlock> {
+        match &self.state {
+            Running(r) => r.ctx.get_progressive_result(),
+            _ => None,
+        }
+    }
+
     pub fn get_session_state(&self) -> ExecutorSessionState {
         match &self.state {
             Starting(r) => ExecutorSessionState::new(r.ctx.get_current_session()),
@@ -244,7 +275,8 @@ impl Executor {
     pub async fn start_to_stop(this: &Arc<RwLock<Executor>>, state: ExecuteState) {
         let mut guard = this.write().await;
         if let Starting(_) = &guard.state {
-            guard.state = state
+            guard.state = state;
+            guard.stopped.send_replace(true);
         }
     }
     #[async_backtrace::framed]
@@ -285,6 +317,7 @@ impl Executor {
                 guard.state = Stopped(Box::new(ExecuteStopped {
                     stats: Default::default(),
                     schema: vec![],
+                    result_schema: Arc::new(DataSchema::empty()),
                     has_result_set: None,
                     reason,
                     session_state: ExecutorSessionState::new(s.ctx.get_current_session()),
@@ -304,6 +337,7 @@ impl Executor {
                 guard.state = Stopped(Box::new(ExecuteStopped {
                     stats: Progresses::from_context(&r.ctx),
                     schema: r.schema.clone(),
+                    result_schema: r.result_schema.clone(),
                     has_result_set: Some(r.has_result_set),
                     reason,
                     session_state: ExecutorSessionState::new(r.ctx.get_current_session()),
@@ -312,8 +346,9 @@ impl Executor {
                     affect: r.ctx.get_affect(),
                 }))
             }
-            Stopped(_) => {}
+            Stopped(_) => return,
         }
+        guard.stopped.send_replace(true);
     }
 }
 
@@ -355,17 +390,19 @@ impl ExecuteState {
 
         let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
         let has_result_set = plan.has_result_set();
-        let schema = if has_result_set {
+        let result_schema = if has_result_set {
             // check has_result_set first for safety
-            QueryResponseField::from_schema(plan.schema())
+            plan.schema()
         } else {
-            vec![]
+            Arc::new(DataSchema::empty())
         };
+        let schema = QueryResponseField::from_schema(result_schema.clone());
         let running_state = ExecuteRunning {
             session,
             ctx: ctx.clone(),
             queue_guard,
             schema,
+            result_schema,
             has_result_set,
         };
         info!("http query change state to Running");
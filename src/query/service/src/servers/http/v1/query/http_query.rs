@@ -30,6 +30,8 @@ use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_metrics::http::metrics_incr_http_response_errors_count;
 use databend_common_settings::ScopeLevel;
@@ -45,23 +47,26 @@ use serde::Serialize;
 use super::HttpQueryContext;
 use super::RemoveReason;
 use crate::servers::http::v1::http_query_handlers::QueryResponseField;
-use crate::servers::http::v1::query::execute_state::ExecuteStarting;
 use crate::servers::http::v1::query::execute_state::ExecuteStopped;
 use crate::servers::http::v1::query::execute_state::ExecutorSessionState;
 use crate::servers::http::v1::query::execute_state::Progresses;
 use crate::servers::http::v1::query::expirable::Expirable;
 use crate::servers::http::v1::query::expirable::ExpiringState;
+use crate::servers::http::v1::query::page_manager::Page;
 use crate::servers::http::v1::query::sized_spsc::sized_spsc;
 use crate::servers::http::v1::query::ExecuteState;
 use crate::servers::http::v1::query::ExecuteStateKind;
 use crate::servers::http::v1::query::Executor;
 use crate::servers::http::v1::query::PageManager;
 use crate::servers::http::v1::query::ResponseData;
+use crate::servers::http::v1::query::ResultSpool;
+use crate::servers::http::v1::query::ResultSpoolMeta;
 use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::servers::http::v1::QueryError;
 use crate::servers::http::v1::QueryResponse;
 use crate::servers::http::v1::QueryStats;
+use crate::servers::http::v1::StringBlock;
 use crate::sessions::QueryAffect;
 use crate::sessions::Session;
 use crate::sessions::SessionType;
@@ -81,6 +86,9 @@ pub struct HttpQueryRequest {
     #[serde(default = "default_as_true")]
     pub string_fields: bool,
     pub stage_attachment: Option<StageAttachmentConf>,
+    /// Falls back to the `Accept` header of the request if not specified.
+    #[serde(default)]
+    pub result_format: Option<ResultFormat>,
 }
 
 impl HttpQueryRequest {
@@ -114,6 +122,8 @@ impl HttpQueryRequest {
             kill_uri: None,
             error: Some(QueryError::from_error_code(err)),
             has_result_set: None,
+            columns: None,
+            progressive_data: None,
         })
     }
 }
@@ -127,6 +137,7 @@ impl Debug for HttpQueryRequest {
             .field("pagination", &self.pagination)
             .field("string_fields", &self.string_fields)
             .field("stage_attachment", &self.stage_attachment)
+            .field("result_format", &self.result_format)
             .finish()
     }
 }
@@ -147,6 +158,53 @@ fn default_wait_time_secs() -> u32 {
     DEFAULT_WAIT_TIME_SECS
 }
 
+/// Layout of the values in a result page.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PageFormat {
+    /// `data` is a list of rows.
+    #[default]
+    Rows,
+    /// `columns` is a list of columns, `data` is left empty.
+    /// It avoids repeating the row brackets and compresses better for wide results.
+    Columns,
+}
+
+/// Format of the query result.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResultFormat {
+    /// The result is paginated json, see [`PageFormat`].
+    #[default]
+    Json,
+    /// The whole result is returned as an Arrow IPC stream in the response of the query.
+    Arrow,
+    /// The whole result is returned as a parquet file in the response of the query.
+    Parquet,
+}
+
+impl ResultFormat {
+    pub fn from_accept_header(accept: &str) -> Option<Self> {
+        accept
+            .split(',')
+            .map(|media_type| media_type.split(';').next().unwrap_or_default().trim())
+            .find_map(|media_type| match media_type {
+                "application/json" => Some(ResultFormat::Json),
+                "application/vnd.apache.arrow.stream" => Some(ResultFormat::Arrow),
+                "application/vnd.apache.parquet" => Some(ResultFormat::Parquet),
+                _ => None,
+            })
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ResultFormat::Json => "application/json",
+            ResultFormat::Arrow => "application/vnd.apache.arrow.stream",
+            ResultFormat::Parquet => "application/vnd.apache.parquet",
+        }
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct PaginationConf {
     #[serde(default = "default_wait_time_secs")]
@@ -155,6 +213,8 @@ pub struct PaginationConf {
     pub(crate) max_rows_in_buffer: usize,
     #[serde(default = "default_max_rows_per_page")]
     pub(crate) max_rows_per_page: usize,
+    #[serde(default)]
+    pub(crate) page_format: PageFormat,
 }
 
 impl Default for PaginationConf {
@@ -163,6 +223,7 @@ impl Default for PaginationConf {
             wait_time_secs: DEFAULT_WAIT_TIME_SECS,
             max_rows_in_buffer: DEFAULT_MAX_ROWS_IN_BUFFER,
             max_rows_per_page: DEFAULT_MAX_ROWS_PER_PAGE,
+            page_format: PageFormat::default(),
         }
     }
 }
@@ -231,6 +292,8 @@ pub struct ResponseState {
 
 pub struct HttpQueryResponseInternal {
     pub data: Option<ResponseData>,
+    pub page_format: PageFormat,
+    pub progressive_data: Option<StringBlock>,
     pub session_id: String,
     pub session: Option<HttpSessionConf>,
     pub state: ResponseState,
@@ -257,6 +320,8 @@ pub struct HttpQuery {
     request: HttpQueryRequest,
     state: Arc<RwLock<Executor>>,
     page_manager: Arc<TokioMutex<PageManager>>,
+    format_settings: Arc<parking_lot::RwLock<Option<FormatSettings>>>,
+    result_spool: Option<Arc<ResultSpool>>,
     expire_state: Arc<parking_lot::Mutex<ExpireState>>,
     /// The timeout for the query result polling. In the normal case, the client driver
     /// should fetch the paginated result in a timely manner, and the interval should not
@@ -410,6 +475,17 @@ impl HttpQuery {
         let deduplicate_label = &ctx.deduplicate_label;
         let user_agent = &ctx.user_agent;
         let query_id = ctx.query_id.clone();
+        // the binary results are not paginated, so there is nothing to spool.
+        let is_paginated = matches!(request.result_format, None | Some(ResultFormat::Json));
+        let result_spool = match settings.get_http_result_spool_ttl_secs()? {
+            _ if !is_paginated => None,
+            0 => None,
+            ttl_secs => Some(Arc::new(ResultSpool::create(
+                session.get_current_tenant().tenant_name(),
+                &query_id,
+                Duration::from_secs(ttl_secs),
+            ))),
+        };
 
         session.set_client_host(ctx.client_host.clone());
 
@@ -454,10 +530,7 @@ impl HttpQuery {
 
         let (block_sender, block_receiver) = sized_spsc(request.pagination.max_rows_in_buffer);
 
-        let state = Arc::new(RwLock::new(Executor {
-            query_id: query_id.clone(),
-            state: ExecuteState::Starting(ExecuteStarting { ctx: ctx.clone() }),
-        }));
+        let state = Arc::new(RwLock::new(Executor::create(query_id.clone(), ctx.clone())));
         let block_sender_closer = block_sender.closer();
         let state_clone = state.clone();
         let ctx_clone = ctx.clone();
@@ -505,11 +578,27 @@ impl HttpQuery {
             .in_span(span),
         )?;
 
-        let data = Arc::new(TokioMutex::new(PageManager::new(
-            request.pagination.max_rows_per_page,
-            block_receiver,
-            format_settings,
-        )));
+        let data = Arc::new(TokioMutex::new(
+            PageManager::new(
+                request.pagination.max_rows_per_page,
+                block_receiver,
+                format_settings.clone(),
+            )
+            .with_spool(result_spool.clone()),
+        ));
+
+        if let Some(spool) = result_spool.clone() {
+            let page_manager = data.clone();
+            let state = state.clone();
+            let page_format = request.pagination.page_format;
+            http_query_runtime_instance
+                .runtime()
+                .try_spawn(async move {
+                    if let Err(e) = spool_result(spool, page_manager, state, page_format).await {
+                        warn!("http query fail to spool result: {}", e);
+                    }
+                })?;
+        }
 
         let query = HttpQuery {
             id: query_id,
@@ -518,6 +607,8 @@ impl HttpQuery {
             request,
             state,
             page_manager: data,
+            format_settings,
+            result_spool,
             result_timeout_secs,
             expire_state: Arc::new(parking_lot::Mutex::new(ExpireState::Working)),
             is_txn_mgr_saved: AtomicBool::new(false),
@@ -532,9 +623,12 @@ impl HttpQuery {
         let data = Some(self.get_page(page_no).await?);
         let state = self.get_state().await;
         let session = self.get_response_session().await;
+        let progressive_data = self.get_progressive_data().await;
 
         Ok(HttpQueryResponseInternal {
             data,
+            page_format: self.request.pagination.page_format,
+            progressive_data,
             state,
             session: Some(session),
             node_id: self.node_id.clone(),
@@ -546,9 +640,12 @@ impl HttpQuery {
     pub async fn get_response_state_only(&self) -> HttpQueryResponseInternal {
         let state = self.get_state().await;
         let session = self.get_response_session().await;
+        let progressive_data = self.get_progressive_data().await;
 
         HttpQueryResponseInternal {
             data: None,
+            page_format: self.request.pagination.page_format,
+            progressive_data,
             session_id: self.session_id.clone(),
             node_id: self.node_id.clone(),
             state,
@@ -556,12 +653,84 @@ impl HttpQuery {
         }
     }
 
+    /// Fetch all the pages of the result and wait for the query to stop, used when
+    /// the whole result is returned in one response, like the statements of a batch.
+    ///
+    /// The query is killed once the buffered result exceeds `max_result_bytes`, 0 means no limit.
+    #[async_backtrace::framed]
+    pub async fn get_full_response(
+        &self,
+        max_result_bytes: usize,
+    ) -> Result<(Vec<Vec<Option<String>>>, HttpQueryResponseInternal)> {
+        let mut rows = vec![];
+        let mut result_bytes = 0;
+        let mut page_no = 0;
+        loop {
+            let data = self.get_page(page_no).await?;
+            for row in Vec::<Vec<Option<String>>>::from(data.page.data) {
+                result_bytes += row.iter().flatten().map(|v| v.len()).sum::<usize>();
+                rows.push(row);
+            }
+            if max_result_bytes > 0 && result_bytes > max_result_bytes {
+                let err = ErrorCode::BadArguments(format!(
+                    "result of the statement exceeds {} bytes, which is limited by the setting http_handler_batch_result_max_bytes",
+                    max_result_bytes
+                ));
+                self.kill(err.clone()).await;
+                return Err(err);
+            }
+            match data.next_page_no {
+                Some(next_page_no) => page_no = next_page_no,
+                None => break,
+            }
+        }
+
+        self.wait_for_stop().await;
+        Ok((rows, self.get_response_state_only().await))
+    }
+
+    /// Receive the next block of the result, used by the binary result formats instead of
+    /// the pages. Returns None once the result is drained or the query failed.
+    #[async_backtrace::framed]
+    pub async fn next_result_block(&self) -> Option<DataBlock> {
+        self.page_manager.lock().await.recv_block().await
+    }
+
+    /// Wait for the query to stop, the state may be updated a little later than the end of
+    /// the result.
+    #[async_backtrace::framed]
+    pub async fn get_stopped_state(&self) -> ResponseState {
+        self.wait_for_stop().await;
+        self.get_state().await
+    }
+
+    #[async_backtrace::framed]
+    async fn wait_for_stop(&self) {
+        let mut stopped = self.state.read().await.stopped.subscribe();
+        // the sender lives as long as the query, so the wait only ends once it is stopped.
+        let _ = stopped.wait_for(|stopped| *stopped).await;
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_result_schema(&self) -> DataSchemaRef {
+        self.state.read().await.get_result_schema()
+    }
+
     #[async_backtrace::framed]
-    async fn get_state(&self) -> ResponseState {
+    pub(crate) async fn get_state(&self) -> ResponseState {
         let state = self.state.read().await;
         state.get_response_state()
     }
 
+    /// Intermediate result of the running query, only available when
+    /// `progressive_aggregate_interval_secs` is enabled.
+    #[async_backtrace::framed]
+    async fn get_progressive_data(&self) -> Option<StringBlock> {
+        let block = self.state.read().await.get_progressive_result()?;
+        let format_settings = self.format_settings.read().clone()?;
+        StringBlock::new(&block, &format_settings).ok()
+    }
+
     #[async_backtrace::framed]
     async fn get_response_session(&self) -> HttpSessionConf {
         let keep_server_session_secs = self
@@ -626,11 +795,21 @@ impl HttpQuery {
             .await?;
         let response = ResponseData {
             page,
-            next_page_no: page_manager.next_page_no(),
+            next_page_no: page_manager.next_page_no(page_no),
         };
         Ok(response)
     }
 
+    /// Remove the spooled result once the client finished the query.
+    #[async_backtrace::framed]
+    pub async fn remove_result_spool(&self) {
+        if let Some(spool) = &self.result_spool {
+            if let Err(e) = spool.remove().await {
+                warn!("http query fail to remove spooled result: {}", e);
+            }
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn kill(&self, reason: ErrorCode) {
         // the query will be removed from the query manager before the session is dropped.
@@ -697,3 +876,102 @@ impl HttpQuery {
         }
     }
 }
+
+/// Spool all the pages ahead of the client, the spooled result is published
+/// only if the query succeeded. The published result expires after the ttl of
+/// the spool and is then removed by the periodic cleanup of the spools.
+#[async_backtrace::framed]
+async fn spool_result(
+    spool: Arc<ResultSpool>,
+    page_manager: Arc<TokioMutex<PageManager>>,
+    state: Arc<RwLock<Executor>>,
+    page_format: PageFormat,
+) -> Result<()> {
+    let poll_interval = Duration::from_millis(100);
+    loop {
+        let has_new_page = {
+            let mut page_manager = page_manager.lock().await;
+            if !page_manager.is_spooling() || page_manager.is_end() {
+                break;
+            }
+            let page_no = page_manager.total_pages();
+            let page = page_manager.get_a_page(page_no, &Wait::Async).await?;
+            page.data.num_rows() > 0
+        };
+        if !has_new_page {
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    let mut stopped = state.read().await.stopped.subscribe();
+    let _ = stopped.wait_for(|stopped| *stopped).await;
+    let response_state = state.read().await.get_response_state();
+
+    let num_pages = {
+        let page_manager = page_manager.lock().await;
+        page_manager
+            .is_spooling()
+            .then_some(page_manager.total_pages())
+    };
+    // don't hold the query until the spool expires.
+    drop(page_manager);
+    drop(state);
+    match num_pages {
+        Some(num_pages) if response_state.state == ExecuteStateKind::Succeeded => {
+            spool
+                .finish(ResultSpoolMeta {
+                    schema: response_state.schema,
+                    page_format,
+                    num_pages,
+                })
+                .await
+        }
+        _ => spool.remove().await,
+    }
+}
+
+/// Get the response from the result spool, used when the query is not on this node.
+/// `page_no` is None for the `/final` request, which removes the spooled result.
+#[async_backtrace::framed]
+pub(crate) async fn get_spooled_response(
+    ctx: &HttpQueryContext,
+    query_id: &str,
+    page_no: Option<usize>,
+) -> Result<Option<HttpQueryResponseInternal>> {
+    let tenant = ctx.session.get_current_tenant();
+    let spool = ResultSpool::create(tenant.tenant_name(), query_id, Duration::ZERO);
+    let Some(meta) = spool.get_meta().await? else {
+        return Ok(None);
+    };
+    let data = match page_no {
+        Some(page_no) if page_no < meta.num_pages => Some(ResponseData {
+            page: Page {
+                data: spool.read_page(page_no).await?,
+            },
+            next_page_no: (page_no + 1 < meta.num_pages).then_some(page_no + 1),
+        }),
+        Some(_) => return Ok(None),
+        None => {
+            spool.remove().await?;
+            None
+        }
+    };
+    Ok(Some(HttpQueryResponseInternal {
+        data,
+        page_format: meta.page_format,
+        progressive_data: None,
+        session_id: ctx.session.get_id(),
+        session: None,
+        node_id: ctx.node_id.clone(),
+        state: ResponseState {
+            has_result_set: Some(true),
+            schema: meta.schema,
+            running_time_ms: 0,
+            progresses: Progresses::default(),
+            state: ExecuteStateKind::Succeeded,
+            affect: None,
+            error: None,
+            warnings: vec![],
+        },
+    }))
+}
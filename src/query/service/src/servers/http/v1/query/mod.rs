@@ -19,6 +19,7 @@ mod http_query;
 mod http_query_context;
 mod http_query_manager;
 mod page_manager;
+mod result_spool;
 pub mod sized_spsc;
 
 pub(crate) use execute_state::ExecuteState;
@@ -27,12 +28,19 @@ pub(crate) use execute_state::Executor;
 pub use execute_state::Progresses;
 pub use expirable::ExpiringState;
 pub use expiring_map::ExpiringMap;
+pub(crate) use http_query::get_spooled_response;
+pub use http_query::HttpQuery;
 pub use http_query::HttpQueryRequest;
 pub use http_query::HttpQueryResponseInternal;
 pub use http_query::HttpSessionConf;
+pub use http_query::PageFormat;
+pub use http_query::PaginationConf;
+pub use http_query::ResultFormat;
 pub use http_query_context::HttpQueryContext;
 pub use http_query_manager::HttpQueryManager;
 pub(crate) use http_query_manager::RemoveReason;
 pub use page_manager::PageManager;
 pub use page_manager::ResponseData;
 pub use page_manager::Wait;
+pub use result_spool::ResultSpool;
+pub use result_spool::ResultSpoolMeta;
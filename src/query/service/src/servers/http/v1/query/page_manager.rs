@@ -23,8 +23,10 @@ use databend_common_expression::DataBlock;
 use databend_common_io::prelude::FormatSettings;
 use log::debug;
 use log::info;
+use log::warn;
 use parking_lot::RwLock;
 
+use crate::servers::http::v1::query::result_spool::ResultSpool;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelReceiver;
 use crate::servers::http::v1::string_block::block_to_strings;
 use crate::servers::http::v1::StringBlock;
@@ -55,6 +57,7 @@ pub struct PageManager {
     row_buffer: VecDeque<Vec<Option<String>>>,
     block_receiver: SizedChannelReceiver<DataBlock>,
     format_settings: Arc<RwLock<Option<FormatSettings>>>,
+    spool: Option<Arc<ResultSpool>>,
 }
 
 impl PageManager {
@@ -73,11 +76,33 @@ impl PageManager {
             block_receiver,
             max_rows_per_page,
             format_settings,
+            spool: None,
         }
     }
 
-    pub fn next_page_no(&mut self) -> Option<usize> {
-        if self.end {
+    pub fn with_spool(mut self, spool: Option<Arc<ResultSpool>>) -> Self {
+        self.spool = spool;
+        self
+    }
+
+    /// False if the spool is not enabled or failed to write a page.
+    pub fn is_spooling(&self) -> bool {
+        self.spool.is_some()
+    }
+
+    pub fn is_end(&self) -> bool {
+        self.end
+    }
+
+    pub fn total_pages(&self) -> usize {
+        self.total_pages
+    }
+
+    pub fn next_page_no(&mut self, page_no: usize) -> Option<usize> {
+        if page_no + 1 < self.total_pages {
+            // the pages are spooled ahead of the client.
+            Some(page_no + 1)
+        } else if self.end {
             None
         } else {
             Some(self.total_pages)
@@ -94,6 +119,7 @@ impl PageManager {
                 self.total_rows += num_row;
                 let page = Page { data: block };
                 if num_row > 0 {
+                    self.spool_page(page_no, &page).await;
                     self.total_pages += 1;
                     self.last_page = Some(page.clone());
                 }
@@ -116,12 +142,27 @@ impl PageManager {
                 .as_ref()
                 .ok_or_else(|| ErrorCode::Internal("last_page is None"))?
                 .clone())
+        } else if let Some(spool) = self.spool.as_ref().filter(|_| page_no < next_no) {
+            // the page may be spooled ahead of the client.
+            Ok(Page {
+                data: spool.read_page(page_no).await?,
+            })
         } else {
             let message = format!("wrong page number {}", page_no,);
             Err(ErrorCode::HttpNotFound(message))
         }
     }
 
+    #[async_backtrace::framed]
+    async fn spool_page(&mut self, page_no: usize, page: &Page) {
+        if let Some(spool) = &self.spool {
+            if let Err(e) = spool.write_page(page_no, &page.data).await {
+                warn!("http query fail to spool page {page_no}, stop spooling: {e}");
+                self.spool = None;
+            }
+        }
+    }
+
     fn append_block(
         &mut self,
         rows: &mut Vec<Vec<Option<String>>>,
@@ -217,6 +258,14 @@ impl PageManager {
         Ok((block, end))
     }
 
+    /// Receive a block of the result directly, bypassing the pages.
+    #[async_backtrace::framed]
+    pub async fn recv_block(&mut self) -> Option<DataBlock> {
+        let block = self.block_receiver.recv().await;
+        self.end = block.is_none();
+        block
+    }
+
     #[async_backtrace::framed]
     pub async fn detach(&mut self) {
         self.block_receiver.close();
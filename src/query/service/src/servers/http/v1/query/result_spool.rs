@@ -0,0 +1,225 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_exception::Result;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::UpsertKV;
+use databend_common_storage::DataOperator;
+use databend_common_users::UserApiProvider;
+use log::info;
+use log::warn;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::servers::http::v1::http_query_handlers::QueryResponseField;
+use crate::servers::http::v1::query::PageFormat;
+use crate::servers::http::v1::StringBlock;
+
+const RESULT_SPOOL_PREFIX: &str = "_result_spool";
+const RESULT_SPOOL_EXPIRE_AT: &str = "_expire_at";
+const RESULT_SPOOL_CLEANUP_INTERVAL: Duration = Duration::from_secs(600);
+
+/// Written to the meta service once all the pages of a query are spooled,
+/// so a result set is only served from the spool when it is complete.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResultSpoolMeta {
+    pub schema: Vec<QueryResponseField>,
+    pub page_format: PageFormat,
+    pub num_pages: usize,
+}
+
+/// Persists the result pages of a http query to the object store, keyed by query id,
+/// so that any node can serve the pages, even after the coordinator is restarted.
+///
+/// The meta expires after `ttl`, the pages are removed by `/final` or once expired.
+/// Each spool keeps an `_expire_at` file next to its pages, which is refreshed on every
+/// write, the expired pages of all the spools, including the ones left by a restarted node,
+/// are removed by the periodic sweep of [`ResultSpool::start_cleanup`].
+pub struct ResultSpool {
+    operator: Operator,
+    meta_store: Arc<MetaStore>,
+    meta_key: String,
+    location: String,
+    ttl: Duration,
+}
+
+impl ResultSpool {
+    pub fn create(tenant: &str, query_id: &str, ttl: Duration) -> Self {
+        ResultSpool {
+            operator: DataOperator::instance().operator(),
+            meta_store: UserApiProvider::instance().get_meta_store_client(),
+            meta_key: format!("{RESULT_SPOOL_PREFIX}/{tenant}/{query_id}"),
+            location: format!("{RESULT_SPOOL_PREFIX}/{tenant}/{query_id}/"),
+            ttl,
+        }
+    }
+
+    fn page_location(&self, page_no: usize) -> String {
+        format!("{}{}.json", self.location, page_no)
+    }
+
+    /// Push the expire time of the spool to `ttl` from now.
+    #[async_backtrace::framed]
+    async fn touch(&self) -> Result<()> {
+        let expire_at = unix_secs() + self.ttl.as_secs();
+        self.operator
+            .write(
+                &format!("{}{RESULT_SPOOL_EXPIRE_AT}", self.location),
+                expire_at.to_string(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn write_page(&self, page_no: usize, page: &StringBlock) -> Result<()> {
+        self.touch().await?;
+        let data = serde_json::to_vec(&page.data)?;
+        self.operator
+            .write(&self.page_location(page_no), data)
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read_page(&self, page_no: usize) -> Result<StringBlock> {
+        let data = self.operator.read(&self.page_location(page_no)).await?;
+        Ok(StringBlock {
+            data: serde_json::from_slice(&data.to_vec())?,
+        })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn finish(&self, meta: ResultSpoolMeta) -> Result<()> {
+        self.touch().await?;
+        let value = serde_json::to_vec(&meta)?;
+        self.meta_store
+            .upsert_kv(UpsertKV {
+                key: self.meta_key.clone(),
+                seq: MatchSeq::GE(0),
+                value: Operation::Update(value),
+                value_meta: Some(MetaSpec::new_ttl(self.ttl)),
+            })
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_meta(&self) -> Result<Option<ResultSpoolMeta>> {
+        match self.meta_store.get_kv(&self.meta_key).await? {
+            None => Ok(None),
+            Some(SeqV { data, .. }) => Ok(Some(serde_json::from_slice(&data)?)),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn remove(&self) -> Result<()> {
+        self.meta_store
+            .upsert_kv(UpsertKV::new(
+                &self.meta_key,
+                MatchSeq::GE(0),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        self.operator.remove_all(&self.location).await?;
+        Ok(())
+    }
+
+    /// Remove the spooled results which are expired and no longer published in the meta
+    /// service on startup and then periodically.
+    pub fn start_cleanup() {
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                match Self::remove_expired().await {
+                    Ok(0) => {}
+                    Ok(n) => info!("removed {} expired spooled http query results", n),
+                    Err(e) => warn!("fail to remove expired spooled http query results: {}", e),
+                }
+                sleep(RESULT_SPOOL_CLEANUP_INTERVAL).await;
+            }
+        });
+    }
+
+    #[async_backtrace::framed]
+    pub async fn remove_expired() -> Result<usize> {
+        let operator = DataOperator::instance().operator();
+        let meta_store = UserApiProvider::instance().get_meta_store_client();
+        let now = unix_secs();
+        let mut removed = 0;
+        let entries = operator
+            .list_with(&format!("{RESULT_SPOOL_PREFIX}/"))
+            .recursive(true)
+            .await?;
+        for entry in entries {
+            // `_result_spool/{tenant}/{query_id}/_expire_at`
+            let Some(location) = entry.path().strip_suffix(RESULT_SPOOL_EXPIRE_AT) else {
+                continue;
+            };
+            // keep going, a spool failing to be removed should not block the others
+            match Self::remove_if_expired(&operator, &meta_store, location, now).await {
+                Ok(true) => removed += 1,
+                Ok(false) => {}
+                Err(e) => warn!(
+                    "fail to remove spooled http query result {}: {}",
+                    location, e
+                ),
+            }
+        }
+        Ok(removed)
+    }
+
+    #[async_backtrace::framed]
+    async fn remove_if_expired(
+        operator: &Operator,
+        meta_store: &MetaStore,
+        location: &str,
+        now: u64,
+    ) -> Result<bool> {
+        let expire_at = operator
+            .read(&format!("{location}{RESULT_SPOOL_EXPIRE_AT}"))
+            .await?
+            .to_vec();
+        let expired = String::from_utf8_lossy(&expire_at)
+            .parse::<u64>()
+            .map_or(true, |expire_at| expire_at <= now);
+        let meta_key = location.trim_end_matches('/');
+        if !expired || meta_store.get_kv(meta_key).await?.is_some() {
+            return Ok(false);
+        }
+        operator.remove_all(location).await?;
+        Ok(true)
+    }
+}
+
+fn unix_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs()
+}
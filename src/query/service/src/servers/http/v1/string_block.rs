@@ -98,6 +98,17 @@ impl StringBlock {
         self.data.is_empty()
     }
 
+    /// Transpose the rows into `num_columns` columns.
+    pub fn into_columns(self, num_columns: usize) -> Vec<Vec<Option<String>>> {
+        let mut columns = vec![Vec::with_capacity(self.data.len()); num_columns];
+        for row in self.data {
+            for (column, value) in columns.iter_mut().zip(row) {
+                column.push(value);
+            }
+        }
+        columns
+    }
+
     pub fn as_data<'n>(&'n self, null_as: &'n str) -> Vec<Vec<&'n str>> {
         self.data
             .iter()
@@ -202,6 +202,9 @@ impl MySQLFederated {
             ),
             // mysqldump.
             (Regex::new("(?i)^(SET SESSION(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET character_set_client(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET @saved_cs_client(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET @MYSQLDUMP_TEMP_LOG_BIN(.*))").unwrap(), None),
             (Regex::new("(?i)^(SET SQL_QUOTE_SHOW_CREATE(.*))").unwrap(), None),
             (Regex::new("(?i)^(LOCK TABLES(.*))").unwrap(), None),
             (Regex::new("(?i)^(UNLOCK TABLES(.*))").unwrap(), None),
@@ -235,14 +238,10 @@ impl MySQLFederated {
                 None,
             ),
             (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW VARIABLES(.*))").unwrap(), None),
-            // mysqldump 5.7.16
-            (Regex::new("(?i)^(/\\*!40100 SET(.*) \\*/)$").unwrap(), None),
-            (Regex::new("(?i)^(/\\*!40103 SET(.*) \\*/)$").unwrap(), None),
-            (Regex::new("(?i)^(/\\*!40111 SET(.*) \\*/)$").unwrap(), None),
-            (Regex::new("(?i)^(/\\*!40101 SET(.*) \\*/)$").unwrap(), None),
-            (Regex::new("(?i)^(/\\*!40014 SET(.*) \\*/)$").unwrap(), None),
-            (Regex::new("(?i)^(/\\*!40000 SET(.*) \\*/)$").unwrap(), None),
-            (Regex::new("(?i)^(/\\*!40000 ALTER(.*) \\*/)$").unwrap(), None),
+            // mysqldump, versioned comments like `/*!50503 SET NAMES utf8mb4 */`
+            // and `/*!40000 ALTER TABLE `t` DISABLE KEYS */`.
+            (Regex::new("(?i)^(/\\*![0-9]{5} SET(.*)\\*/)$").unwrap(), None),
+            (Regex::new("(?i)^(/\\*![0-9]{5} ALTER TABLE(.*) (DISABLE|ENABLE) KEYS ?\\*/)$").unwrap(), None),
         ]
             });
 
@@ -28,6 +28,7 @@ use std::time::Instant;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
+use chrono::DateTime;
 use chrono::Utc;
 use chrono_tz::Tz;
 use dashmap::mapref::multiple::RefMulti;
@@ -71,6 +72,7 @@ use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::GrantObject;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
+use databend_common_meta_app::principal::StageFileCompression;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
@@ -89,6 +91,7 @@ use databend_common_pipeline_core::LockGuard;
 use databend_common_settings::Settings;
 use databend_common_sql::IndexType;
 use databend_common_storage::CopyStatus;
+use databend_common_storage::init_stage_operator;
 use databend_common_storage::DataOperator;
 use databend_common_storage::FileStatus;
 use databend_common_storage::MultiTableInsertStatus;
@@ -100,9 +103,13 @@ use databend_common_storages_delta::DeltaTable;
 use databend_common_storages_fuse::TableContext;
 use databend_common_storages_iceberg::IcebergTable;
 use databend_common_storages_orc::OrcTable;
+use databend_common_storages_parquet::ExternalTable;
 use databend_common_storages_parquet::ParquetRSTable;
 use databend_common_storages_result_cache::ResultScan;
+use databend_common_storages_stage::infer_ndjson_schema;
+use databend_common_storages_stage::read_ndjson_sample;
 use databend_common_storages_stage::StageTable;
+use databend_common_storages_stage::NDJSON_OVERFLOW_COLUMN;
 use databend_common_users::GrantObjectVisibilityChecker;
 use databend_common_users::UserApiProvider;
 use databend_storages_common_table_meta::meta::Location;
@@ -118,6 +125,7 @@ use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
 use crate::locks::LockManager;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::HashJoinState;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
@@ -132,6 +140,10 @@ use crate::storages::Table;
 const MYSQL_VERSION: &str = "8.0.26";
 const CLICKHOUSE_VERSION: &str = "8.12.14";
 const COPIED_FILES_FILTER_BATCH_SIZE: usize = 1000;
+// Bytes read from the first NDJSON file of a stage to infer its columns.
+const NDJSON_SAMPLE_BYTES: u64 = 1024 * 1024;
+// Number of NDJSON records used to infer the columns.
+const NDJSON_SAMPLE_RECORDS: usize = 1000;
 
 #[derive(Clone)]
 pub struct QueryContext {
@@ -330,6 +342,53 @@ impl QueryContext {
     pub fn clear_tables_cache(&self) {
         self.shared.clear_tables_cache()
     }
+
+    /// Allow the root aggregation `plan_id` to publish progressive results,
+    /// `projection` maps its output columns to the columns of the result set.
+    pub fn set_progressive_result_plan(&self, plan_id: u32, projection: Vec<usize>) {
+        *self.shared.progressive_result_plan.write() = Some((plan_id, projection));
+    }
+
+    /// Get the result set projection if `plan_id` is allowed to publish progressive results.
+    pub fn get_progressive_result_projection(&self, plan_id: u32) -> Option<Vec<usize>> {
+        match self.shared.progressive_result_plan.read().as_ref() {
+            Some((id, projection)) if *id == plan_id => Some(projection.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn set_progressive_result(&self, block: DataBlock) {
+        *self.shared.progressive_result.write() = Some(block);
+    }
+
+    pub fn get_progressive_result(&self) -> Option<DataBlock> {
+        self.shared.progressive_result.read().clone()
+    }
+
+    /// Register the signatures of hash join build sides which can be shared in this query.
+    pub fn set_shared_hash_join_keys(&self, keys: HashSet<String>) {
+        *self.shared.shared_hash_join_states.write() =
+            keys.into_iter().map(|key| (key, None)).collect();
+    }
+
+    pub fn is_shared_hash_join_key(&self, key: &str) -> bool {
+        self.shared.shared_hash_join_states.read().contains_key(key)
+    }
+
+    pub fn get_shared_hash_join_state(&self, key: &str) -> Option<Arc<HashJoinState>> {
+        self.shared
+            .shared_hash_join_states
+            .read()
+            .get(key)
+            .cloned()
+            .flatten()
+    }
+
+    pub fn set_shared_hash_join_state(&self, key: &str, state: Arc<HashJoinState>) {
+        if let Some(slot) = self.shared.shared_hash_join_states.write().get_mut(key) {
+            *slot = Some(state);
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -519,6 +578,14 @@ impl TableContext for QueryContext {
         self.shared.cacheable.store(cacheable, Ordering::Release);
     }
 
+    fn get_hide_profiles(&self) -> bool {
+        self.shared.hide_profiles.load(Ordering::Acquire)
+    }
+
+    fn set_hide_profiles(&self, hide: bool) {
+        self.shared.hide_profiles.store(hide, Ordering::Release);
+    }
+
     fn get_can_scan_from_agg_index(&self) -> bool {
         self.shared.can_scan_from_agg_index.load(Ordering::Acquire)
     }
@@ -699,7 +766,13 @@ impl TableContext for QueryContext {
 
         let tz = settings.get_timezone()?;
         let tz = TzFactory::instance().get_by_name(&tz)?;
-        let now = Utc::now();
+        let deterministic = settings.get_deterministic()?;
+        // Time dependent functions are pinned to the Unix epoch in deterministic mode.
+        let now = if deterministic {
+            DateTime::<Utc>::default()
+        } else {
+            Utc::now()
+        };
         let numeric_cast_option = settings.get_numeric_cast_option()?;
         let rounding_mode = numeric_cast_option.as_str() == "rounding";
         let disable_variant_check = settings.get_disable_variant_check()?;
@@ -729,6 +802,8 @@ impl TableContext for QueryContext {
             parse_datetime_ignore_remainder,
             enable_dst_hour_fix,
             enable_strict_datetime_parser,
+            deterministic,
+            deterministic_rng: deterministic.then(|| self.shared.deterministic_rng.clone()),
         })
     }
 
@@ -1182,6 +1257,7 @@ impl TableContext for QueryContext {
                 let table = DeltaTable::load(sp).await?;
                 DeltaTable::get_meta(&table).await
             }
+            "external" => ExternalTable::infer_schema(sp).await,
             // TODO: iceberg doesn't support load from storage directly.
             _ => Err(ErrorCode::Internal("unsupported datalake type {}")),
         }
@@ -1215,7 +1291,6 @@ impl TableContext for QueryContext {
                     files_info,
                     read_options,
                     files_to_copy,
-                    self.get_settings(),
                     self.get_query_kind(),
                 )
                 .await
@@ -1233,7 +1308,42 @@ impl TableContext for QueryContext {
                 };
                 OrcTable::try_create(info).await
             }
-            FileFormatParams::NdJson(..) => {
+            FileFormatParams::NdJson(ref params)
+                if max_column_position == 0
+                    && self.get_settings().get_enable_ndjson_column_inference()? =>
+            {
+                if params.compression != StageFileCompression::None {
+                    return Err(ErrorCode::BadArguments(
+                        "inferring the columns of compressed NDJSON files is not supported",
+                    ));
+                }
+                let operator = init_stage_operator(&stage_info)?;
+                let first_file = files_info.first_file(&operator).await?;
+                let data = read_ndjson_sample(&operator, &first_file, NDJSON_SAMPLE_BYTES).await?;
+                let inferred = infer_ndjson_schema(&data, NDJSON_SAMPLE_RECORDS)?;
+
+                // The values may not fit the sampled types, every column is nullable.
+                let mut fields = inferred
+                    .fields()
+                    .iter()
+                    .map(|field| TableField::new(field.name(), field.data_type().wrap_nullable()))
+                    .collect::<Vec<_>>();
+                fields.push(TableField::new(
+                    NDJSON_OVERFLOW_COLUMN,
+                    TableDataType::Variant.wrap_nullable(),
+                ));
+                let info = StageTableInfo {
+                    schema: Arc::new(TableSchema::new(fields)),
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    duplicated_files_detected: vec![],
+                    is_select: true,
+                    default_values: None,
+                };
+                StageTable::try_create(info)
+            }
+            FileFormatParams::NdJson(..) | FileFormatParams::Avro(..) => {
                 let schema = Arc::new(TableSchema::new(vec![TableField::new(
                     "_$1", // TODO: this name should be in visible
                     TableDataType::Variant,
@@ -36,6 +36,7 @@ use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
@@ -54,12 +55,16 @@ use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_users::UserApiProvider;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
 use uuid::Uuid;
 
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::HashJoinState;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
+use crate::spillers::SpillSpaceManager;
 use crate::storages::Table;
 
 type DatabaseAndTable = (String, String, String);
@@ -114,6 +119,7 @@ pub struct QueryContextShared {
     /// partitions_sha for each table in the query. Not empty only when enabling query result cache.
     pub(in crate::sessions) partitions_shas: Arc<RwLock<Vec<String>>>,
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
+    pub(in crate::sessions) hide_profiles: Arc<AtomicBool>,
     pub(in crate::sessions) can_scan_from_agg_index: Arc<AtomicBool>,
     pub(in crate::sessions) num_fragmented_block_hint: Arc<AtomicU64>,
     pub(in crate::sessions) enable_sort_spill: Arc<AtomicBool>,
@@ -135,8 +141,23 @@ pub struct QueryContextShared {
     pub(in crate::sessions) query_cache_metrics: DataCacheMetrics,
 
     pub(in crate::sessions) query_queued_duration: Arc<RwLock<Duration>>,
+
+    /// The plan id of the root aggregation allowed to publish progressive results,
+    /// and the projection from its output to the result set.
+    pub(in crate::sessions) progressive_result_plan: Arc<RwLock<Option<(u32, Vec<usize>)>>>,
+    /// The latest intermediate (non-final) result published by the root aggregation.
+    pub(in crate::sessions) progressive_result: Arc<RwLock<Option<DataBlock>>>,
+    /// Hash join build sides that occur more than once in the plan, keyed by their
+    /// build signature. The value is the hash table state once the first join built it.
+    pub(in crate::sessions) shared_hash_join_states:
+        Arc<RwLock<HashMap<String, Option<Arc<HashJoinState>>>>>,
+    /// The random state of the query when the `deterministic` setting is on.
+    pub(in crate::sessions) deterministic_rng: Arc<Mutex<SmallRng>>,
 }
 
+/// Seed of the random functions when the `deterministic` setting is on.
+const DETERMINISTIC_SEED: u64 = 0;
+
 impl QueryContextShared {
     pub fn try_create(
         session: Arc<Session>,
@@ -172,6 +193,7 @@ impl QueryContextShared {
             mutation_status: Arc::new(Default::default()),
             partitions_shas: Arc::new(RwLock::new(vec![])),
             cacheable: Arc::new(AtomicBool::new(true)),
+            hide_profiles: Arc::new(AtomicBool::new(false)),
             can_scan_from_agg_index: Arc::new(AtomicBool::new(true)),
             num_fragmented_block_hint: Arc::new(AtomicU64::new(0)),
             enable_sort_spill: Arc::new(AtomicBool::new(true)),
@@ -188,6 +210,10 @@ impl QueryContextShared {
             merge_into_join: Default::default(),
             multi_table_insert_status: Default::default(),
             query_queued_duration: Arc::new(RwLock::new(Duration::from_secs(0))),
+            progressive_result_plan: Arc::new(RwLock::new(None)),
+            progressive_result: Arc::new(RwLock::new(None)),
+            shared_hash_join_states: Default::default(),
+            deterministic_rng: Arc::new(Mutex::new(SmallRng::seed_from_u64(DETERMINISTIC_SEED))),
         }))
     }
 
@@ -598,6 +624,10 @@ impl QueryContextShared {
     }
 
     pub fn get_query_profiles(&self) -> Vec<PlanProfile> {
+        // the profiles reveal the plan of the secure views read by the query.
+        if self.hide_profiles.load(Ordering::Acquire) {
+            return vec![];
+        }
         if let Some(executor) = self.executor.read().upgrade() {
             self.add_query_profiles(&executor.fetch_profiling(false));
         }
@@ -629,7 +659,9 @@ impl Drop for QueryContextShared {
             // to avoid returning the query_id of the current statement.
             self.session
                 .session_ctx
-                .update_query_ids_results(self.init_query_id.read().clone(), None)
+                .update_query_ids_results(self.init_query_id.read().clone(), None);
+            // Both on the coordinator and on the nodes running the fragments of the query.
+            SpillSpaceManager::instance().finish_query(&self.init_query_id.read());
         })
     }
 }
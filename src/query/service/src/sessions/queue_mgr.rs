@@ -63,6 +63,12 @@ pub trait QueueData: Send + Sync + 'static {
 
     fn need_acquire_to_queue(&self) -> bool;
 
+    /// The label of the slots reserved for the data, it acquires the shared slots
+    /// if its label has no reserved slots.
+    fn label(&self) -> Option<String> {
+        None
+    }
+
     fn enter_wait_pending(&self) {}
 
     fn exit_wait_pending(&self, _wait_time: Duration) {}
@@ -77,13 +83,18 @@ pub(crate) struct Inner<Data: QueueData> {
 
 pub struct QueueManager<Data: QueueData> {
     semaphore: Arc<Semaphore>,
+    reserved: HashMap<String, Arc<Semaphore>>,
     queue: Mutex<HashMap<Data::Key, Inner<Data>>>,
 }
 
 impl<Data: QueueData> QueueManager<Data> {
-    pub fn init(permits: usize) -> Result<()> {
-        info!("queue manager permits: {:?}", permits);
-        GlobalInstance::set(Self::create(permits));
+    pub fn init(permits: usize, reserved_slots: &[String]) -> Result<()> {
+        let reserved = parse_reserved_slots(reserved_slots)?;
+        info!(
+            "queue manager permits: {:?}, reserved permits: {:?}",
+            permits, reserved
+        );
+        GlobalInstance::set(Self::create_with_reserved(permits, reserved)?);
         Ok(())
     }
 
@@ -91,15 +102,36 @@ impl<Data: QueueData> QueueManager<Data> {
         GlobalInstance::get::<Arc<Self>>()
     }
 
-    pub fn create(mut permits: usize) -> Arc<QueueManager<Data>> {
+    pub fn create(permits: usize) -> Arc<QueueManager<Data>> {
+        Self::create_with_reserved(permits, HashMap::new()).unwrap()
+    }
+
+    /// Creates the queue with some of the permits reserved for labels, the reserved
+    /// permits are only acquired by the data of the label, and the rest are shared.
+    pub fn create_with_reserved(
+        mut permits: usize,
+        reserved: HashMap<String, usize>,
+    ) -> Result<Arc<QueueManager<Data>>> {
         if permits == 0 {
             permits = usize::MAX >> 4;
         }
 
-        Arc::new(QueueManager {
+        let total_reserved = reserved.values().sum::<usize>();
+        if total_reserved >= permits {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "reserved query slots {} must be less than max running queries {}",
+                total_reserved, permits
+            )));
+        }
+
+        Ok(Arc::new(QueueManager {
             queue: Mutex::new(HashMap::new()),
-            semaphore: Arc::new(Semaphore::new(permits)),
-        })
+            semaphore: Arc::new(Semaphore::new(permits - total_reserved)),
+            reserved: reserved
+                .into_iter()
+                .map(|(label, permits)| (label, Arc::new(Semaphore::new(permits))))
+                .collect(),
+        }))
     }
 
     /// The length of the queue.
@@ -132,9 +164,14 @@ impl<Data: QueueData> QueueManager<Data> {
     pub async fn acquire(self: &Arc<Self>, data: Data) -> Result<AcquireQueueGuard> {
         if data.need_acquire_to_queue() {
             let timeout = data.timeout();
+            let semaphore = data
+                .label()
+                .and_then(|label| self.reserved.get(&label))
+                .unwrap_or(&self.semaphore)
+                .clone();
             let future = AcquireQueueFuture::create(
                 Arc::new(data),
-                tokio::time::timeout(timeout, self.semaphore.clone().acquire_owned()),
+                tokio::time::timeout(timeout, semaphore.acquire_owned()),
                 self.clone(),
             );
             let start_time = SystemTime::now();
@@ -198,6 +235,30 @@ impl<Data: QueueData> QueueManager<Data> {
     }
 }
 
+/// Parses the reserved slots of roles in the form of `<role>:<slots>`.
+fn parse_reserved_slots(reserved_slots: &[String]) -> Result<HashMap<String, usize>> {
+    let mut reserved = HashMap::with_capacity(reserved_slots.len());
+    for item in reserved_slots {
+        let parsed = item
+            .rsplit_once(':')
+            .and_then(|(label, slots)| Some((label.trim(), slots.trim().parse::<usize>().ok()?)))
+            .filter(|(label, slots)| !label.is_empty() && *slots > 0);
+        let Some((label, slots)) = parsed else {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "invalid reserved query slots '{}', expect <role>:<slots>",
+                item
+            )));
+        };
+        if reserved.insert(label.to_string(), slots).is_some() {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "duplicated reserved query slots of role '{}'",
+                label
+            )));
+        }
+    }
+    Ok(reserved)
+}
+
 pub struct AcquireQueueGuard {
     #[allow(dead_code)]
     permit: Option<OwnedSemaphorePermit>,
@@ -303,6 +364,7 @@ pub struct QueryEntry {
     pub user_info: UserInfo,
     pub timeout: Duration,
     pub need_acquire_to_queue: bool,
+    pub label: Option<String>,
 }
 
 impl QueryEntry {
@@ -323,6 +385,9 @@ impl QueryEntry {
                 0 => Duration::from_secs(60 * 60 * 24 * 365 * 35),
                 timeout => Duration::from_secs(timeout),
             },
+            // the reserved slots are bound to roles, which are granted by the administrators,
+            // so the queries can't take the slots of others.
+            label: ctx.get_current_role().map(|role| role.name),
         })
     }
 
@@ -390,6 +455,7 @@ impl QueryEntry {
             | Plan::OptimizeCompactSegment(_)
             | Plan::OptimizeCompactBlock { .. }
             | Plan::VacuumTable(_)
+            | Plan::VacuumAllTables(_)
             | Plan::VacuumTemporaryFiles(_)
             | Plan::RefreshIndex(_)
             | Plan::TruncateTable(_) => {
@@ -435,6 +501,10 @@ impl QueueData for QueryEntry {
         self.need_acquire_to_queue
     }
 
+    fn label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
     fn enter_wait_pending(&self) {
         self.ctx.set_status_info("resources scheduling");
     }
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod spill_space_manager;
 mod spiller;
 mod spiller_buffer;
 
+pub use spill_space_manager::SpillSpaceManager;
 pub use spiller::Spiller;
 pub use spiller::SpillerConfig;
 pub use spiller::SpillerType;
@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_storage::DataOperator;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::sessions::QueryContext;
+
+struct QuerySpillUsage {
+    location_prefix: String,
+    bytes: u64,
+    // Set once the query is finished (or killed), the spilled files are evictable from then on.
+    finished_at: Option<Instant>,
+}
+
+/// Node-level accounting of the spilled data.
+///
+/// Every spill writer reserves the bytes it is going to write before writing them. Once the
+/// node reaches `max_server_spill_bytes`, the spilled files left by finished or killed queries
+/// are removed (oldest first), and if that does not free enough space, the query fails with
+/// `SpillSpaceExhausted` instead of filling up the storage.
+pub struct SpillSpaceManager {
+    max_bytes: u64,
+    used_bytes: Mutex<u64>,
+    queries: Mutex<HashMap<String, QuerySpillUsage>>,
+}
+
+impl SpillSpaceManager {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(SpillSpaceManager {
+            max_bytes: conf.query.max_server_spill_bytes,
+            used_bytes: Mutex::new(0),
+            queries: Mutex::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<SpillSpaceManager> {
+        GlobalInstance::get()
+    }
+
+    pub fn used_bytes(&self) -> u64 {
+        *self.used_bytes.lock()
+    }
+
+    /// Reserve `bytes` of spill space for the query of `ctx`.
+    #[async_backtrace::framed]
+    pub async fn reserve(&self, ctx: &Arc<QueryContext>, bytes: u64) -> Result<()> {
+        // unlimited, no need to account the spilled data.
+        if self.max_bytes == 0 {
+            return Ok(());
+        }
+
+        if !self.try_reserve(bytes) {
+            self.evict_finished(bytes).await;
+            if !self.try_reserve(bytes) {
+                return Err(ErrorCode::SpillSpaceExhausted(format!(
+                    "spill space exhausted on this node, used {} bytes, requested {} bytes, max_server_spill_bytes is {} bytes",
+                    self.used_bytes(),
+                    bytes,
+                    self.max_bytes
+                )));
+            }
+        }
+
+        let query_id = ctx.get_id();
+        let mut queries = self.queries.lock();
+        queries
+            .entry(query_id.clone())
+            .or_insert_with(|| QuerySpillUsage {
+                location_prefix: query_spill_prefix(ctx.get_tenant().tenant_name(), &query_id),
+                bytes: 0,
+                finished_at: None,
+            })
+            .bytes += bytes;
+        Ok(())
+    }
+
+    /// Reserve the spill space of the serialized buckets about to be written.
+    #[async_backtrace::framed]
+    pub async fn reserve_buckets(
+        &self,
+        ctx: &Arc<QueryContext>,
+        write_data: &[Vec<Vec<u8>>],
+    ) -> Result<()> {
+        let bytes = write_data
+            .iter()
+            .flatten()
+            .map(|data| data.len())
+            .sum::<usize>();
+        self.reserve(ctx, bytes as u64).await
+    }
+
+    /// Mark the spilled data of the query as evictable, called on every node once the query
+    /// context of the query is dropped, i.e. all the fragments of the query on the node are done.
+    pub fn finish_query(&self, query_id: &str) {
+        if let Some(usage) = self.queries.lock().get_mut(query_id) {
+            usage.finished_at = Some(Instant::now());
+        }
+    }
+
+    /// Release the reservations of the query, called once its spilled files are removed.
+    pub fn release_query(&self, query_id: &str) {
+        let Some(usage) = self.queries.lock().remove(query_id) else {
+            return;
+        };
+        let mut used_bytes = self.used_bytes.lock();
+        *used_bytes = used_bytes.saturating_sub(usage.bytes);
+    }
+
+    fn try_reserve(&self, bytes: u64) -> bool {
+        let mut used_bytes = self.used_bytes.lock();
+        if *used_bytes + bytes > self.max_bytes {
+            return false;
+        }
+        *used_bytes += bytes;
+        true
+    }
+
+    async fn evict_finished(&self, required_bytes: u64) {
+        let mut finished = {
+            let queries = self.queries.lock();
+            queries
+                .iter()
+                .filter_map(|(id, usage)| usage.finished_at.map(|at| (at, id.clone())))
+                .collect::<Vec<_>>()
+        };
+        finished.sort();
+
+        let operator = DataOperator::instance().operator();
+        for (_, query_id) in finished {
+            if self.used_bytes() + required_bytes <= self.max_bytes {
+                break;
+            }
+
+            let Some(location_prefix) = self
+                .queries
+                .lock()
+                .get(&query_id)
+                .map(|usage| usage.location_prefix.clone())
+            else {
+                continue;
+            };
+
+            if let Err(e) = operator.remove_all(&format!("{}/", location_prefix)).await {
+                warn!("failed to evict spilled files of query {}: {}", query_id, e);
+                continue;
+            }
+
+            self.release_query(&query_id);
+            info!("evicted spilled files of query {}", query_id);
+        }
+    }
+}
@@ -33,6 +33,7 @@ use databend_common_expression::DataBlock;
 use opendal::Operator;
 
 use crate::sessions::QueryContext;
+use crate::spillers::spill_space_manager::SpillSpaceManager;
 use crate::spillers::spiller_buffer::SpillerBuffer;
 
 /// Spiller type, currently only supports HashJoin
@@ -149,11 +150,6 @@ impl Spiller {
         let location = format!("{}/{}", self.config.location_prefix, unique_name);
         let mut write_bytes = 0;
 
-        let mut writer = self
-            .operator
-            .writer_with(&location)
-            .chunk(8 * 1024 * 1024)
-            .await?;
         let columns = data.columns().to_vec();
         let mut columns_data = Vec::with_capacity(columns.len());
         for column in columns.into_iter() {
@@ -171,6 +167,15 @@ impl Spiller {
             columns_data.push(column_data);
         }
 
+        SpillSpaceManager::instance()
+            .reserve(&self.ctx, write_bytes as u64)
+            .await?;
+
+        let mut writer = self
+            .operator
+            .writer_with(&location)
+            .chunk(8 * 1024 * 1024)
+            .await?;
         for data in columns_data.into_iter() {
             writer.write(data).await?;
         }
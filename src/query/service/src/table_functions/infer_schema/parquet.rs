@@ -26,6 +26,7 @@ use databend_common_expression::types::UInt64Type;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::StageFileCompression;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_meta_app::principal::StageType;
 use databend_common_pipeline_core::processors::OutputPort;
@@ -36,11 +37,19 @@ use databend_common_sql::binder::resolve_file_location;
 use databend_common_storage::init_stage_operator;
 use databend_common_storage::read_parquet_schema_async_rs;
 use databend_common_storage::StageFilesInfo;
+use databend_common_storages_stage::infer_avro_schema;
+use databend_common_storages_stage::infer_ndjson_schema;
+use databend_common_storages_stage::read_ndjson_sample;
 use opendal::Scheme;
 
 use crate::table_functions::infer_schema::infer_schema_table::INFER_SCHEMA;
 use crate::table_functions::infer_schema::table_args::InferSchemaArgsParsed;
 
+// Only the head of a NDJSON file is read to sample its records.
+const NDJSON_SAMPLE_BYTES: u64 = 16 * 1024 * 1024;
+// The schema of an Avro file is stored in its header, which is expected to fit in this size.
+const AVRO_HEADER_BYTES: u64 = 1024 * 1024;
+
 pub(crate) struct ParquetInferSchemaSource {
     is_finished: bool,
     ctx: Arc<dyn TableContext>,
@@ -140,9 +149,32 @@ impl AsyncSource for ParquetInferSchemaSource {
                 .await?;
                 TableSchema::try_from(&arrow_schema)?
             }
+            StageFileFormatType::NdJson => {
+                if file_format_params.compression() != StageFileCompression::None {
+                    return Err(ErrorCode::BadArguments(
+                        "infer_schema does not support compressed NDJSON files",
+                    ));
+                }
+                let data = read_ndjson_sample(&operator, &first_file, NDJSON_SAMPLE_BYTES).await?;
+                infer_ndjson_schema(&data, self.args_parsed.max_records)?
+            }
+            StageFileFormatType::Avro => {
+                if file_format_params.compression() != StageFileCompression::None {
+                    return Err(ErrorCode::BadArguments(
+                        "infer_schema does not support compressed Avro files",
+                    ));
+                }
+                let end = first_file.size.min(AVRO_HEADER_BYTES);
+                let data = operator
+                    .read_with(&first_file.path)
+                    .range(0..end)
+                    .await?
+                    .to_vec();
+                infer_avro_schema(&data)?
+            }
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, NDJSON and Avro",
                 ));
             }
         };
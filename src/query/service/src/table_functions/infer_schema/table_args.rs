@@ -18,12 +18,16 @@ use databend_common_exception::Result;
 use databend_common_storage::StageFilesInfo;
 use databend_common_storages_fuse::table_functions::string_value;
 
+// Number of records sampled from a NDJSON file when `max_records` is not given.
+const DEFAULT_MAX_RECORDS: usize = 1000;
+
 #[derive(Clone)]
 pub(crate) struct InferSchemaArgsParsed {
     pub(crate) location: String,
     pub(crate) connection_name: Option<String>,
     pub(crate) file_format: Option<String>,
     pub(crate) files_info: StageFilesInfo,
+    pub(crate) max_records: usize,
 }
 
 impl InferSchemaArgsParsed {
@@ -33,6 +37,7 @@ impl InferSchemaArgsParsed {
         let mut location = None;
         let mut connection_name = None;
         let mut file_format = None;
+        let mut max_records = DEFAULT_MAX_RECORDS;
         let mut files_info = StageFilesInfo {
             path: "".to_string(),
             files: None,
@@ -53,6 +58,17 @@ impl InferSchemaArgsParsed {
                 "file_format" => {
                     file_format = Some(string_value(v)?);
                 }
+                "max_records" => {
+                    max_records = v
+                        .as_number()
+                        .and_then(|n| n.integer_to_i128())
+                        .filter(|n| *n > 0)
+                        .ok_or_else(|| {
+                            ErrorCode::BadArguments(
+                                "max_records of infer_schema must be a positive integer",
+                            )
+                        })? as usize;
+                }
                 _ => {
                     return Err(ErrorCode::BadArguments(format!(
                         "unknown param {} for infer_schema",
@@ -70,6 +86,7 @@ impl InferSchemaArgsParsed {
             connection_name,
             file_format,
             files_info,
+            max_records,
         })
     }
 }
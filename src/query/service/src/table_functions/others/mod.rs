@@ -14,12 +14,14 @@
 
 mod execute_background_job;
 mod license_info;
+mod query_statistics;
 mod suggested_background_compaction_tasks;
 mod suggested_background_tasks;
 mod tenant_quota;
 
 pub use execute_background_job::ExecuteBackgroundJobTable;
 pub use license_info::LicenseInfoTable;
+pub use query_statistics::QueryStatisticsTable;
 pub use suggested_background_tasks::SuggestedBackgroundTasksSource;
 pub use suggested_background_tasks::SuggestedBackgroundTasksTable;
 pub use tenant_quota::TenantQuotaTable;
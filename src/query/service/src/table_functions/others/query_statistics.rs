@@ -0,0 +1,259 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_function::TableFunction;
+use databend_common_exception::ErrorCode;
+pub use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_storages_factory::Table;
+use databend_common_storages_system::LogType;
+use databend_common_storages_system::ProfilesLogQueue;
+use databend_common_storages_system::QueryLogQueue;
+
+/// `query_statistics(query_id)` returns the resource usage of a finished query of this node,
+/// e.g. `SELECT * FROM query_statistics(last_query_id())`.
+///
+/// Only the queries of the current tenant are visible, and only the queries of the current
+/// user unless the user has the global SUPER privilege.
+pub struct QueryStatisticsTable {
+    query_id: String,
+    table_info: TableInfo,
+}
+
+impl QueryStatisticsTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(table_func_name, Some(1))?;
+        let query_id = args[0].as_string().cloned().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Expected string argument for {}, but got {}",
+                table_func_name, args[0]
+            ))
+        })?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: String::from("query_statistics"),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: String::from(table_func_name),
+                // Assuming that created_on is unnecessary for function table,
+                // we could make created_on fixed to pass test_shuffle_action_try_into.
+                created_on: DateTime::from_timestamp(0, 0).unwrap(),
+                updated_on: DateTime::from_timestamp(0, 0).unwrap(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(QueryStatisticsTable {
+            query_id,
+            table_info,
+        }))
+    }
+
+    fn schema() -> TableSchemaRef {
+        let number = || TableDataType::Number(NumberDataType::UInt64);
+        TableSchemaRefExt::create(vec![
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("scan_rows", number()),
+            TableField::new("scan_bytes", number()),
+            TableField::new("result_rows", number()),
+            TableField::new("result_bytes", number()),
+            TableField::new("cpu_time_ms", number()),
+            TableField::new("memory_usage", number()),
+            TableField::new("query_duration_ms", number()),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for QueryStatisticsTable {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        // dummy statistics
+        Ok((PartStatistics::new_exact(1, 1, 1, 1), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![Scalar::String(
+            self.query_id.clone(),
+        )]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| QueryStatisticsSource::create(ctx.clone(), output, self.query_id.clone()),
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+struct QueryStatisticsSource {
+    ctx: Arc<dyn TableContext>,
+    query_id: String,
+    finished: bool,
+}
+
+impl QueryStatisticsSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        query_id: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, QueryStatisticsSource {
+            ctx,
+            query_id,
+            finished: false,
+        })
+    }
+
+    fn cpu_time_ms(&self) -> Result<u64> {
+        let profiles_queue = ProfilesLogQueue::instance()?;
+        let data = profiles_queue.data.read();
+        let cpu_time_ns: usize = data
+            .event_queue
+            .iter()
+            .flatten()
+            .filter(|element| element.query_id == self.query_id)
+            .flat_map(|element| element.profiles.iter())
+            .map(|profile| profile.statistics[ProfileStatisticsName::CpuTime as usize])
+            .sum();
+        Ok(cpu_time_ns as u64 / 1_000_000)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for QueryStatisticsSource {
+    const NAME: &'static str = "query_statistics";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finished {
+            return Ok(None);
+        }
+        self.finished = true;
+
+        let tenant = self.ctx.get_tenant();
+        let user = self.ctx.get_current_user()?;
+        let is_admin = self
+            .ctx
+            .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super, false)
+            .await
+            .is_ok();
+
+        // Only the log of a finished query carries its statistics.
+        let query_log = {
+            let query_log_queue = QueryLogQueue::instance()?;
+            let data = query_log_queue.data.read();
+            data.event_queue
+                .iter()
+                .flatten()
+                .find(|element| {
+                    element.query_id == self.query_id
+                        && !matches!(element.log_type, LogType::Start)
+                        && element.tenant_id == tenant.tenant_name()
+                        && (is_admin || element.sql_user == user.name)
+                })
+                .cloned()
+        };
+        let Some(query_log) = query_log else {
+            return Ok(None);
+        };
+
+        let cpu_time_ms = self.cpu_time_ms()?;
+        Ok(Some(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![query_log.query_id]),
+            UInt64Type::from_data(vec![query_log.scan_rows]),
+            UInt64Type::from_data(vec![query_log.scan_bytes]),
+            UInt64Type::from_data(vec![query_log.result_rows]),
+            UInt64Type::from_data(vec![query_log.result_bytes]),
+            UInt64Type::from_data(vec![cpu_time_ms]),
+            UInt64Type::from_data(vec![query_log.memory_usage]),
+            UInt64Type::from_data(vec![query_log.query_duration_ms.max(0) as u64]),
+        ])))
+    }
+}
+
+impl TableFunction for QueryStatisticsTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
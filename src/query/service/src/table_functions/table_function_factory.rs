@@ -31,6 +31,7 @@ use parking_lot::RwLock;
 
 use super::ExecuteBackgroundJobTable;
 use super::LicenseInfoTable;
+use super::QueryStatisticsTable;
 use super::SuggestedBackgroundTasksTable;
 use super::TenantQuotaTable;
 use crate::catalogs::SYS_TBL_FUC_ID_END;
@@ -220,6 +221,11 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(LicenseInfoTable::create)),
         );
 
+        creators.insert(
+            "query_statistics".to_string(),
+            (next_id(), Arc::new(QueryStatisticsTable::create)),
+        );
+
         creators.insert(
             "suggested_background_tasks".to_string(),
             (next_id(), Arc::new(SuggestedBackgroundTasksTable::create)),
@@ -20,6 +20,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use databend_common_sql::BloomIndexColumns;
+use databend_common_storages_fuse::io::column_checksums;
 use databend_common_storages_fuse::io::serialize_block;
 use databend_common_storages_fuse::io::TableMetaLocationGenerator;
 use databend_common_storages_fuse::io::WriteSettings;
@@ -76,11 +77,12 @@ impl<'a> BlockWriter<'a> {
 
         let mut buf = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
         let col_metas = serialize_block(&write_settings, schema, block, &mut buf)?;
+        let col_checksums = column_checksums(&col_metas, &buf);
         let file_size = buf.len() as u64;
 
         data_accessor.write(&location.0, buf).await?;
 
-        let block_meta = BlockMeta::new(
+        let mut block_meta = BlockMeta::new(
             row_count,
             block_size,
             file_size,
@@ -94,6 +96,7 @@ impl<'a> BlockWriter<'a> {
             Compression::Lz4Raw,
             Some(Utc::now()),
         );
+        block_meta.col_checksums = col_checksums;
         Ok((block_meta, meta))
     }
 
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::VecDeque;
+use std::path::Path;
 use std::str;
 use std::sync::Arc;
 
@@ -22,6 +23,7 @@ use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use databend_common_catalog::cluster_info::Cluster;
 use databend_common_catalog::table::AppendMode;
 use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::binary::BinaryColumnBuilder;
@@ -35,12 +37,14 @@ use databend_common_expression::Column;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::FromData;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_license::license_manager::LicenseManager;
@@ -67,6 +71,7 @@ use jsonb::Object as JsonbObject;
 use jsonb::Value as JsonbValue;
 use log::info;
 use parking_lot::Mutex;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use uuid::Uuid;
 
 use crate::clusters::ClusterDiscovery;
@@ -347,6 +352,7 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            clone_source: None,
             cluster_key: Some("(id)".to_string()),
             inverted_indexes: None,
         }
@@ -372,6 +378,7 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
             inverted_indexes: None,
         }
@@ -408,6 +415,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
             inverted_indexes: None,
         }
@@ -444,6 +452,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
             inverted_indexes: None,
         }
@@ -489,6 +498,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
             inverted_indexes: None,
         }
@@ -887,6 +897,84 @@ impl TestFixture {
         let executor = InterpreterFactory::get(ctx.clone(), &plan).await?;
         executor.execute(ctx).await
     }
+
+    /// Load the fixture files in `dir` into the default database, which becomes the current
+    /// database of the default session:
+    /// - `<name>.sql`: statements separated by `;`, executed in the order of the file names.
+    /// - `<name>.parquet`: rows appended to the table `<name>` once all the `.sql` files are
+    ///   executed, the schema of the file must match the table.
+    pub async fn load_fixtures(&self, dir: impl AsRef<Path>) -> Result<()> {
+        self.create_default_database().await?;
+        self.default_session
+            .set_current_database(self.default_db_name());
+
+        let mut files = std::fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        files.sort();
+
+        let has_extension = |path: &Path, extension: &str| {
+            path.extension().and_then(|ext| ext.to_str()) == Some(extension)
+        };
+
+        for path in files.iter().filter(|path| has_extension(path, "sql")) {
+            let content = std::fs::read_to_string(path)?;
+            for sql in content.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+                self.execute_command(sql).await.map_err(|e| {
+                    e.add_message_back(format!(" (while loading fixture {:?})", path))
+                })?;
+            }
+        }
+
+        for path in files.iter().filter(|path| has_extension(path, "parquet")) {
+            self.load_parquet_fixture(path).await?;
+        }
+        Ok(())
+    }
+
+    async fn load_parquet_fixture(&self, path: &Path) -> Result<()> {
+        let table_name = path
+            .file_stem()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| ErrorCode::BadArguments(format!("invalid fixture file {:?}", path)))?;
+        let table = self
+            .default_ctx
+            .get_catalog(CATALOG_DEFAULT)
+            .await?
+            .get_table(
+                &self.default_tenant(),
+                self.default_db_name().as_str(),
+                table_name,
+            )
+            .await?;
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(path)?)?;
+        let file_schema = TableSchema::try_from(reader.schema().as_ref())?;
+        let table_schema = table.schema().remove_computed_fields();
+        let file_fields = file_schema
+            .fields()
+            .iter()
+            .map(|f| (f.name(), f.data_type()))
+            .collect::<Vec<_>>();
+        let table_fields = table_schema
+            .fields()
+            .iter()
+            .map(|f| (f.name(), f.data_type()))
+            .collect::<Vec<_>>();
+        if file_fields != table_fields {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "schema of fixture {:?} does not match table {}, file: {:?}, table: {:?}",
+                path, table_name, file_fields, table_fields
+            )));
+        }
+
+        let data_schema = DataSchema::from(&table_schema);
+        let blocks = reader
+            .build()?
+            .map(|batch| Ok(DataBlock::from_record_batch(&data_schema, &batch?)?.0))
+            .collect::<Result<Vec<_>>>()?;
+        self.append_commit_blocks(table, blocks, false, true).await
+    }
 }
 
 fn gen_db_name(prefix: &str) -> String {
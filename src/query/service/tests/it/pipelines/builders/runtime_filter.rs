@@ -92,6 +92,7 @@ async fn join_build_state(
         false,
         true,
         None,
+        false,
     )?;
     let build_state = HashJoinBuildState::try_create(
         ctx.clone(),
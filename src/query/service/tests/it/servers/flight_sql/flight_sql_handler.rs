@@ -16,12 +16,22 @@
 
 use std::fs;
 use std::io::Write;
+use std::sync::Arc;
 
+use arrow_array::Int32Array;
 use arrow_array::RecordBatch;
+use arrow_array::StringArray;
 use arrow_cast::pretty::pretty_format_batches;
+use arrow_flight::encode::FlightDataEncoderBuilder;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::DoPutUpdateResult;
+use arrow_flight::FlightData;
+use arrow_flight::FlightDescriptor;
 use arrow_schema::ArrowError;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
 use databend_common_base::base::tokio;
 use databend_common_base::runtime::Runtime;
 use databend_common_config::InnerConfig;
@@ -32,9 +42,11 @@ use databend_common_meta_app::principal::PasswordHashMethod;
 use databend_query::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
+use futures::StreamExt;
 use futures::TryStreamExt;
 use goldenfile::Mint;
 use log::debug;
+use prost::Message;
 use tempfile::NamedTempFile;
 use tokio::net::UnixListener;
 use tokio::net::UnixStream;
@@ -42,6 +54,7 @@ use tokio_stream::wrappers::UnixListenerStream;
 use tonic::transport::Channel;
 use tonic::transport::Endpoint;
 use tonic::transport::Server;
+use tonic::Request;
 use tower::service_fn;
 
 const TEST_USER: &str = "test_user";
@@ -75,6 +88,35 @@ async fn run_query(
     Ok(res)
 }
 
+async fn exchange_insert(
+    client: &mut FlightSqlServiceClient<Channel>,
+    token: &str,
+    sql: &str,
+    batch: RecordBatch,
+) -> std::result::Result<String, ArrowError> {
+    let flight_data = FlightDataEncoderBuilder::new()
+        .with_flight_descriptor(Some(FlightDescriptor::new_cmd(sql.to_string())))
+        .build(futures::stream::iter(vec![Ok(batch)]))
+        .map(|data| data.unwrap());
+    let mut request = Request::new(flight_data);
+    request
+        .metadata_mut()
+        .insert("authorization", format!("Bearer {token}").parse().unwrap());
+    let response = client
+        .inner_mut()
+        .do_exchange(request)
+        .await
+        .map_err(|e| ArrowError::IpcError(e.to_string()))?;
+    let results: Vec<FlightData> = response
+        .into_inner()
+        .try_collect()
+        .await
+        .map_err(|e| ArrowError::IpcError(e.to_string()))?;
+    let result = DoPutUpdateResult::decode(results[0].app_metadata.clone())
+        .map_err(|e| ArrowError::IpcError(e.to_string()))?;
+    Ok(result.record_count.to_string())
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -140,6 +182,43 @@ async fn test_query() -> Result<()> {
                 };
                 writeln!(file, "{}", res).unwrap();
             }
+
+            // push record batches by DoExchange
+            let schema = Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ]));
+            let batch = RecordBatch::try_new(schema, vec![
+                Arc::new(Int32Array::from(vec![3, 4])),
+                Arc::new(StringArray::from(vec![Some("z"), None])),
+            ])
+            .unwrap();
+            let sql = "insert into test1 values";
+            writeln!(file, "---------- Input ----------").unwrap();
+            writeln!(file, "do_exchange: {}", sql).unwrap();
+            writeln!(file, "---------- Output ---------").unwrap();
+            let res = match exchange_insert(
+                &mut client,
+                std::str::from_utf8(&token).unwrap(),
+                sql,
+                batch,
+            )
+            .await
+            {
+                Ok(s) => s,
+                Err(e) => format!("{e:?}"),
+            };
+            writeln!(file, "{}", res).unwrap();
+
+            let case = "select * from test1 order by a";
+            writeln!(file, "---------- Input ----------").unwrap();
+            writeln!(file, "{}", case).unwrap();
+            writeln!(file, "---------- Output ---------").unwrap();
+            let res = match run_query(&mut client, case).await {
+                Ok(s) => s,
+                Err(e) => format!("{e:?}"),
+            };
+            writeln!(file, "{}", res).unwrap();
         };
         tokio::pin!(serve_future);
 
@@ -17,6 +17,7 @@ use std::fs::File;
 use std::io::Read;
 use std::time::Duration;
 
+use arrow_ipc::reader::StreamReader;
 use base64::engine::general_purpose;
 use base64::prelude::*;
 use databend_common_base::base::get_free_tcp_port;
@@ -25,6 +26,10 @@ use databend_common_config::UserAuthConfig;
 use databend_common_config::UserConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::PasswordHashMethod;
 use databend_common_users::CustomClaims;
 use databend_common_users::EnsureUser;
@@ -32,12 +37,18 @@ use databend_query::auth::AuthMgr;
 use databend_query::servers::http::middleware::get_client_ip;
 use databend_query::servers::http::middleware::HTTPSessionEndpoint;
 use databend_query::servers::http::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::v1::make_final_uri;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::query_route;
+use databend_query::servers::http::v1::string_block::StringBlock;
 use databend_query::servers::http::v1::ExecuteStateKind;
 use databend_query::servers::http::v1::HttpSessionConf;
+use databend_query::servers::http::v1::PageFormat;
+use databend_query::servers::http::v1::QueryBatchResponse;
 use databend_query::servers::http::v1::QueryError;
 use databend_query::servers::http::v1::QueryResponse;
+use databend_query::servers::http::v1::ResultSpool;
+use databend_query::servers::http::v1::ResultSpoolMeta;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::sessions::QueryAffect;
@@ -1806,3 +1817,395 @@ async fn test_null_response() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_progressive_aggregate_result() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let sql = "select count(*), sum(number) from numbers(1000000)";
+    let json = serde_json::json!({"sql": sql.to_string(), "session": {"settings": {"progressive_aggregate_interval_secs": "1"}}});
+    let mut req = TestHttpQueryRequest::new(json);
+    let resp = req.fetch_total().await?;
+    assert!(resp.error().is_none(), "{:?}", resp.error());
+
+    let data = resp.data();
+    assert_eq!(data.len(), 1);
+    assert_eq!(data[0][0].clone().unwrap_or_default(), "1000000");
+    assert_eq!(data[0][1].clone().unwrap_or_default(), "499999500000");
+    assert!(resp.last().1.progressive_data.is_none());
+
+    // a query running for much longer than the test publishes its intermediate result
+    let ep = create_endpoint().await?;
+    let rows = 100_000_000_000_u64;
+    let sql = format!("select count(*), max(number) from numbers({rows})");
+    let json = serde_json::json!({"sql": sql, "pagination": {"wait_time_secs": 1}, "session": {"settings": {"progressive_aggregate_interval_secs": "1"}}});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+
+    let kill_uri = result.kill_uri.clone().unwrap();
+    let mut next_uri = result.next_uri.clone();
+    let mut progressive_data = result.progressive_data.clone();
+    for _ in 0..30 {
+        if progressive_data.is_some() {
+            break;
+        }
+        let (status, result) = get_uri_checked(&ep, next_uri.as_ref().unwrap()).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", result);
+        assert!(result.error.is_none(), "{:?}", result);
+        // the final result is not sent before the query finishes
+        assert!(result.data.is_empty(), "{:?}", result);
+        next_uri = result.next_uri.clone();
+        progressive_data = result.progressive_data.clone();
+    }
+
+    let progressive_data = progressive_data.expect("no progressive result was published");
+    assert_eq!(progressive_data.len(), 1);
+    let values = progressive_data[0]
+        .iter()
+        .map(|v| v.as_deref().unwrap().parse::<u64>().unwrap())
+        .collect::<Vec<_>>();
+    let (count, max) = (values[0], values[1]);
+    assert!(count > 0 && count < rows, "{:?}", progressive_data);
+    assert!(max >= count - 1 && max < rows, "{:?}", progressive_data);
+
+    let response = get_uri(&ep, &kill_uri).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_page_format_columns() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let sql = "select number, number * 2 from numbers(3)";
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 5, "page_format": "columns"}});
+    let (status, result) = post_json(&json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert!(result.data.is_empty());
+    let to_column = |values: &[&str]| {
+        values
+            .iter()
+            .map(|v| Some(v.to_string()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        result.columns,
+        Some(vec![
+            to_column(&["0", "1", "2"]),
+            to_column(&["0", "2", "4"])
+        ])
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_page_compression() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let ep = create_endpoint().await?;
+
+    let json = serde_json::json!({"sql": "select * from numbers(1000)", "pagination": {"wait_time_secs": 5}});
+    for encoding in ["gzip", "zstd"] {
+        let response = ep
+            .call(
+                Request::builder()
+                    .uri("/v1/query".parse().unwrap())
+                    .method(Method::POST)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .header(header::ACCEPT_ENCODING, encoding)
+                    .typed_header(headers::Authorization::basic("root", ""))
+                    .body(serde_json::to_vec(&json)?),
+            )
+            .await
+            .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response
+                .headers()
+                .get(header::CONTENT_ENCODING)
+                .and_then(|v| v.to_str().ok()),
+            Some(encoding)
+        );
+    }
+
+    // no `Accept-Encoding`, no compression
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result.data.len(), 1000);
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_result_spool() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let ep = create_endpoint().await?;
+
+    let sql = "select * from numbers(10)";
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 3, "max_rows_per_page": 2}, "session": { "settings": {"http_result_spool_ttl_secs": "60"}}});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    let query_id = result.id.clone();
+    let final_uri = result.final_uri.clone().unwrap();
+    let mut rows = result.data.clone();
+    let mut next_uri = result.next_uri.clone();
+    while let Some(uri) = next_uri {
+        let (status, result) = get_uri_checked(&ep, &uri).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", result);
+        assert!(result.error.is_none(), "{:?}", result);
+        rows.extend(result.data.clone());
+        next_uri = result
+            .next_uri
+            .clone()
+            .filter(|uri| !uri.ends_with("/final"));
+    }
+    let expected = (0..10)
+        .map(|i| vec![Some(i.to_string())])
+        .collect::<Vec<_>>();
+    assert_eq!(rows, expected);
+
+    // pages already acked are served from the spool
+    let (status, result) = get_uri_checked(&ep, &make_page_uri(&query_id, 1)).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.data, expected[2..4].to_vec());
+
+    check_final(&ep, &final_uri).await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_result_spool_from_other_node() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let ep = create_endpoint().await?;
+
+    // spooled by another node, or by this node before it was restarted,
+    // so the query is unknown to this node.
+    let query_id = "spooled_by_other_node";
+    let spool = ResultSpool::create("test", query_id, Duration::from_secs(60));
+    let format = FormatSettings::default();
+    for (page_no, rows) in [[0u64, 1], [2, 3]].into_iter().enumerate() {
+        let block = DataBlock::new_from_columns(vec![UInt64Type::from_data(rows.to_vec())]);
+        spool
+            .write_page(page_no, &StringBlock::new(&block, &format)?)
+            .await?;
+    }
+    spool
+        .finish(ResultSpoolMeta {
+            schema: vec![],
+            page_format: PageFormat::Rows,
+            num_pages: 2,
+        })
+        .await?;
+
+    let (status, result) = get_uri_checked(&ep, &make_page_uri(query_id, 1)).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.data, vec![
+        vec![Some("2".to_string())],
+        vec![Some("3".to_string())]
+    ]);
+
+    check_final(&ep, &make_final_uri(query_id)).await?;
+    assert!(spool.get_meta().await?.is_none());
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_result_spool_cleanup() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    // left by a restarted node: expired and never published to the meta service
+    let expired = ResultSpool::create("test_tenant", "expired_query", Duration::ZERO);
+    expired.write_page(0, &StringBlock::empty()).await?;
+    let alive = ResultSpool::create("test_tenant", "alive_query", Duration::from_secs(60));
+    alive.write_page(0, &StringBlock::empty()).await?;
+
+    assert_eq!(ResultSpool::remove_expired().await?, 1);
+    assert!(expired.read_page(0).await.is_err());
+    assert!(alive.read_page(0).await?.is_empty());
+
+    alive.remove().await?;
+    assert_eq!(ResultSpool::remove_expired().await?, 0);
+    Ok(())
+}
+
+async fn post_batch_to_endpoint(
+    ep: &EndpointType,
+    json: &serde_json::Value,
+) -> Result<(StatusCode, QueryBatchResponse)> {
+    let response = ep
+        .call(
+            Request::builder()
+                .uri("/v1/query/batch".parse().unwrap())
+                .method(Method::POST)
+                .header(header::CONTENT_TYPE, "application/json")
+                .typed_header(headers::Authorization::basic("root", ""))
+                .body(serde_json::to_vec(json)?),
+        )
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    let status = response.status();
+    let body = response.into_body().into_string().await.unwrap();
+    let result = serde_json::from_str::<QueryBatchResponse>(&body)
+        .map_err(|e| ErrorCode::Internal(format!("body ='{}', error='{:?}'", body, e)))?;
+    Ok((status, result))
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_query_batch() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let ep = create_endpoint().await?;
+
+    let json = serde_json::json!({"statements": [
+        "create table t_batch(a int)",
+        "insert into t_batch values (1), (2)",
+        "select a from t_batch order by a",
+        "select * from t_batch_not_exists",
+        "insert into t_batch values (3)",
+    ]});
+    let (status, result) = post_batch_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    // stops at the first failed statement
+    assert_eq!(result.results.len(), 4, "{:?}", result);
+    assert_eq!(
+        result.results[2].data,
+        vec![vec![Some("1".to_string())], vec![Some("2".to_string())]]
+    );
+    assert_eq!(result.results[3].state, ExecuteStateKind::Failed);
+    assert_eq!(result.error.unwrap().code, 1025);
+
+    // the statements run in the same session
+    let json = serde_json::json!({"statements": [
+        "use system",
+        "select currentDatabase()",
+    ]});
+    let (status, result) = post_batch_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(
+        result.results[1].data,
+        vec![vec![Some("system".to_string())]]
+    );
+    assert_eq!(result.session.unwrap().database, Some("system".to_string()));
+
+    // the transaction is rolled back if any statement fails
+    let json = serde_json::json!({"transactional": true, "statements": [
+        "insert into t_batch values (3)",
+        "select * from t_batch_not_exists",
+    ]});
+    let (status, result) = post_batch_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.results.len(), 2, "{:?}", result);
+    assert!(result.error.is_some());
+    assert_eq!(
+        result.session.unwrap().txn_state,
+        Some(TxnState::AutoCommit)
+    );
+
+    let json = serde_json::json!({"transactional": true, "statements": [
+        "insert into t_batch values (4)",
+        "select count(*) from t_batch",
+    ]});
+    let (status, result) = post_batch_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.results[1].data, vec![vec![Some("3".to_string())]]);
+
+    // the statement fails once its result exceeds the limit
+    let json = serde_json::json!({
+        "session": {"settings": {"http_handler_batch_result_max_bytes": "100"}},
+        "pagination": {"max_rows_per_page": 10},
+        "statements": [
+            "select repeat('a', 10) from numbers(100)",
+            "select 1",
+        ]
+    });
+    let (status, result) = post_batch_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.results.len(), 1, "{:?}", result);
+    assert_eq!(result.results[0].state, ExecuteStateKind::Failed);
+    assert!(result.results[0].data.is_empty());
+    let error = result.error.unwrap();
+    assert_eq!(error.code, ErrorCode::BAD_ARGUMENTS, "{:?}", error);
+    assert!(error.message.contains("exceeds 100 bytes"), "{:?}", error);
+    Ok(())
+}
+
+async fn post_json_for_bytes(
+    ep: &EndpointType,
+    json: &serde_json::Value,
+    headers: HeaderMap,
+) -> Result<(StatusCode, HeaderMap, Vec<u8>)> {
+    let mut req = Request::builder()
+        .uri("/v1/query".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .typed_header(headers::Authorization::basic("root", ""))
+        .body(serde_json::to_vec(json)?);
+    req.headers_mut().extend(headers.into_iter());
+
+    let response = ep.call(req).await.unwrap_or_else(|err| err.into_response());
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = response.into_body().into_vec().await.unwrap();
+    Ok((status, headers, body))
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_binary_result_format() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let ep = create_endpoint().await?;
+    let sql = "select number, number::string as s from numbers(5)";
+
+    // Arrow IPC stream requested by the Accept header
+    let json = serde_json::json!({ "sql": sql });
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::ACCEPT,
+        HeaderValue::from_static("application/vnd.apache.arrow.stream"),
+    );
+    let (status, resp_headers, body) = post_json_for_bytes(&ep, &json, headers).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        resp_headers.get(header::CONTENT_TYPE).unwrap(),
+        "application/vnd.apache.arrow.stream"
+    );
+    let reader = StreamReader::try_new(std::io::Cursor::new(body), None)?;
+    let schema = reader.schema();
+    let names = schema.fields().iter().map(|f| f.name()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["number", "s"]);
+    let batches = reader.collect::<std::result::Result<Vec<_>, _>>()?;
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 5);
+
+    // Parquet requested in the request body
+    let json = serde_json::json!({ "sql": sql, "result_format": "parquet" });
+    let (status, _, body) = post_json_for_bytes(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.starts_with(b"PAR1") && body.ends_with(b"PAR1"));
+
+    // the error is returned before the result starts
+    let json = serde_json::json!({ "sql": "select * from t_not_exists", "result_format": "arrow" });
+    let (status, _, body) = post_json_for_bytes(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(String::from_utf8(body)?.contains("t_not_exists"));
+
+    // an empty result still returns the schema
+    let sql = "select number, number::string as s from numbers(5) where number > 10";
+    let json = serde_json::json!({ "sql": sql, "result_format": "arrow" });
+    let (status, _, body) = post_json_for_bytes(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK);
+    let reader = StreamReader::try_new(std::io::Cursor::new(body), None)?;
+    let schema = reader.schema();
+    let names = schema.fields().iter().map(|f| f.name()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["number", "s"]);
+    let batches = reader.collect::<std::result::Result<Vec<_>, _>>()?;
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+
+    let json = serde_json::json!({ "sql": sql, "result_format": "parquet" });
+    let (status, _, body) = post_json_for_bytes(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.starts_with(b"PAR1") && body.ends_with(b"PAR1"));
+
+    Ok(())
+}
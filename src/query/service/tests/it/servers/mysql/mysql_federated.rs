@@ -65,5 +65,26 @@ fn test_mysql_federated() -> Result<()> {
         }
     }
 
+    // mysqldump
+    {
+        let queries = [
+            "/*!50503 SET NAMES utf8mb4 */",
+            "/*!80000 SET @OLD_SQL_REQUIRE_PRIMARY_KEY=@@SQL_REQUIRE_PRIMARY_KEY */",
+            "/*!40000 ALTER TABLE `t1` DISABLE KEYS */",
+            "/*!40000 ALTER TABLE `t1` ENABLE KEYS */",
+            "SET @saved_cs_client     = @@character_set_client",
+            "SET character_set_client = utf8mb4",
+            "SET @MYSQLDUMP_TEMP_LOG_BIN = @@SESSION.SQL_LOG_BIN",
+            "LOCK TABLES `t1` WRITE",
+            "UNLOCK TABLES",
+        ];
+        for query in queries {
+            assert!(federated.check(query).is_some(), "{query}");
+        }
+
+        let query = "/*!40000 ALTER TABLE `t1` ADD COLUMN c INT */";
+        assert!(federated.check(query).is_none());
+    }
+
     Ok(())
 }
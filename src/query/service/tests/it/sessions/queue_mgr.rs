@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
@@ -25,6 +26,8 @@ use databend_query::interpreters::InterpreterFactory;
 use databend_query::sessions::QueryEntry;
 use databend_query::sessions::QueueData;
 use databend_query::sessions::QueueManager;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
 use databend_query::test_kits::TestFixture;
 use log::error;
 
@@ -206,6 +209,78 @@ async fn test_list_acquire() -> Result<()> {
     Ok(())
 }
 
+#[derive(Debug)]
+struct LabeledTestData(String, Option<&'static str>);
+
+impl QueueData for LabeledTestData {
+    type Key = String;
+
+    fn get_key(&self) -> Self::Key {
+        self.0.clone()
+    }
+
+    fn remove_error_message(key: Option<Self::Key>) -> ErrorCode {
+        ErrorCode::Internal(format!("{:?}", key))
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(1000)
+    }
+
+    fn need_acquire_to_queue(&self) -> bool {
+        true
+    }
+
+    fn label(&self) -> Option<String> {
+        self.1.map(str::to_string)
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_reserved_acquire() -> Result<()> {
+    let reserved = HashMap::from([("etl".to_string(), 1)]);
+    let queue = QueueManager::<LabeledTestData>::create_with_reserved(2, reserved)?;
+    let wait = Duration::from_millis(500);
+
+    // The shared slot is taken by an unlabeled query.
+    let _shared = queue
+        .acquire(LabeledTestData("shared".to_string(), None))
+        .await?;
+    let blocked = LabeledTestData("unlabeled".to_string(), None);
+    assert!(
+        tokio::time::timeout(wait, queue.acquire(blocked))
+            .await
+            .is_err()
+    );
+    let blocked = LabeledTestData("unreserved".to_string(), Some("interactive"));
+    assert!(
+        tokio::time::timeout(wait, queue.acquire(blocked))
+            .await
+            .is_err()
+    );
+
+    // The reserved slot is still available for the label, but only once.
+    let _reserved = queue
+        .acquire(LabeledTestData("etl_1".to_string(), Some("etl")))
+        .await?;
+    let blocked = LabeledTestData("etl_2".to_string(), Some("etl"));
+    assert!(
+        tokio::time::timeout(wait, queue.acquire(blocked))
+            .await
+            .is_err()
+    );
+
+    assert!(
+        QueueManager::<LabeledTestData>::create_with_reserved(
+            2,
+            HashMap::from([("etl".to_string(), 2)])
+        )
+        .is_err()
+    );
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_heavy_actions() -> Result<()> {
     struct Query {
@@ -350,3 +425,34 @@ async fn test_heavy_actions() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_entry_label() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    for sql in ["create role etl", "create table t1(a int)"] {
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _extras) = planner.plan_sql(sql).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let _ = interpreter.execute(ctx.clone()).await?;
+    }
+
+    // The reserved slots are taken by the current role, not a setting of the session.
+    let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    let user_info = session.get_current_user()?;
+    session
+        .set_authed_user(user_info, Some("etl".to_string()))
+        .await?;
+    let ctx = session.create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, extras) = planner.plan_sql("insert into t1 values(1)").await?;
+    let query_entry = QueryEntry::create(&ctx, &plan, &extras)?;
+    assert_eq!(query_entry.label(), Some("etl".to_string()));
+    assert!(
+        ctx.get_settings()
+            .set_setting("query_label".to_string(), "etl".to_string())
+            .is_err()
+    );
+
+    Ok(())
+}
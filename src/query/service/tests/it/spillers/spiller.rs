@@ -14,6 +14,7 @@
 
 use databend_common_base::base::tokio;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::Int32Type;
@@ -24,9 +25,11 @@ use databend_common_expression::FromData;
 use databend_common_expression::ScalarRef;
 use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_storage::DataOperator;
+use databend_query::spillers::SpillSpaceManager;
 use databend_query::spillers::Spiller;
 use databend_query::spillers::SpillerConfig;
 use databend_query::spillers::SpillerType;
+use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -72,3 +75,54 @@ async fn test_spill_with_partition() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_spill_space_exhausted() -> Result<()> {
+    let mut config = ConfigBuilder::create().build();
+    config.query.max_server_spill_bytes = 16;
+    let fixture = TestFixture::setup_with_config(&config).await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let tenant = ctx.get_tenant();
+    let spiller_config =
+        SpillerConfig::create(query_spill_prefix(tenant.tenant_name(), &ctx.get_id()));
+    let operator = DataOperator::instance().operator();
+
+    let mut spiller = Spiller::create(ctx, operator, spiller_config, SpillerType::HashJoinBuild)?;
+
+    let data =
+        DataBlock::new_from_columns(vec![Int32Type::from_data((0..100).collect::<Vec<_>>())]);
+
+    let res = spiller.spill_with_partition(0_u8, data).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::SPILL_SPACE_EXHAUSTED);
+    assert!(spiller.partition_location.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_spill_space_released() -> Result<()> {
+    let mut config = ConfigBuilder::create().build();
+    config.query.max_server_spill_bytes = 1024;
+    let fixture = TestFixture::setup_with_config(&config).await?;
+    let manager = SpillSpaceManager::instance();
+
+    // the spilled files of the query are removed by the cleanup after the query
+    let ctx = fixture.new_query_ctx().await?;
+    manager.reserve_buckets(&ctx, &[vec![vec![0; 100]]]).await?;
+    assert_eq!(manager.used_bytes(), 100);
+    manager.release_query(&ctx.get_id());
+    assert_eq!(manager.used_bytes(), 0);
+
+    // the reservations of a query become evictable once its context is dropped
+    let ctx = fixture.new_query_ctx().await?;
+    manager
+        .reserve_buckets(&ctx, &[vec![vec![0; 1000]]])
+        .await?;
+    drop(ctx);
+    let ctx = fixture.new_query_ctx().await?;
+    manager.reserve_buckets(&ctx, &[vec![vec![0; 500]]]).await?;
+    assert_eq!(manager.used_bytes(), 500);
+
+    Ok(())
+}
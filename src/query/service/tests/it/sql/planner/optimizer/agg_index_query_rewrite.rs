@@ -83,6 +83,7 @@ fn create_table_plan(fixture: &TestFixture, format: &str) -> CreateTablePlan {
         .into(),
         field_comments: vec![],
         as_select: None,
+        clone_source: None,
         cluster_key: None,
         inverted_indexes: None,
     }
@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_base::base::tokio;
+use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_sql::optimizer::SExpr;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::RelOperator;
+use databend_common_sql::Binder;
+use databend_common_sql::Metadata;
+use databend_common_sql::NameResolutionContext;
+use databend_query::test_kits::TestFixture;
+use parking_lot::RwLock;
+
+async fn bind_sql(ctx: Arc<dyn TableContext>, sql: &str) -> Result<SExpr> {
+    let settings = ctx.get_settings();
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let binder = Binder::new(
+        ctx.clone(),
+        CatalogManager::instance(),
+        name_resolution_ctx,
+        metadata,
+    );
+    let tokens = tokenize_sql(sql)?;
+    let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+    match binder.bind(&stmt).await? {
+        Plan::Query { s_expr, .. } => Ok(*s_expr),
+        _ => unreachable!("{sql} is not a query"),
+    }
+}
+
+fn has_materialized_cte(s_expr: &SExpr) -> bool {
+    matches!(s_expr.plan(), RelOperator::MaterializedCte(_))
+        || s_expr.children().any(has_materialized_cte)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_auto_materialize_cte() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    let settings = ctx.get_settings();
+    settings.set_setting(
+        "materialize_cte_min_references".to_string(),
+        "2".to_string(),
+    )?;
+
+    let cases = [
+        // Aggregating 1000 rows costs far more than scanning the 1000 groups twice.
+        (
+            "with t as (select number as a from numbers(1000) group by a) \
+             select * from t t1, t t2 where t1.a = t2.a",
+            true,
+        ),
+        // A projection is as cheap to recompute as to read back.
+        (
+            "with t as (select number + 1 as a from numbers(1000)) \
+             select * from t t1, t t2 where t1.a = t2.a",
+            false,
+        ),
+        // The join fans out to more rows than it reads, writing them costs more than rejoining.
+        (
+            "with t as (select n1.number as a from numbers(10) n1, numbers(10) n2) \
+             select * from t t1, t t2 where t1.a = t2.a",
+            false,
+        ),
+        // Referenced only once.
+        (
+            "with t as (select number as a from numbers(1000) group by a) select * from t",
+            false,
+        ),
+    ];
+    for (sql, expected) in cases {
+        let s_expr = bind_sql(ctx.clone(), sql).await?;
+        assert_eq!(has_materialized_cte(&s_expr), expected, "{sql}");
+    }
+
+    settings.set_setting(
+        "materialize_cte_min_references".to_string(),
+        "0".to_string(),
+    )?;
+    let s_expr = bind_sql(ctx.clone(), cases[0].0).await?;
+    assert!(!has_materialized_cte(&s_expr));
+
+    Ok(())
+}
@@ -324,6 +324,10 @@ fn build_test_segment_info(
     }
     assert_eq!(num_number_columns + num_string_columns, col_stats.len());
 
+    let col_checksums = (0..num_string_columns + num_number_columns)
+        .map(|id| (id as ColumnId, 0))
+        .collect::<HashMap<_, _>>();
+
     let location_gen = TableMetaLocationGenerator::with_prefix("/root/12345/67890".to_owned());
 
     let (block_location, block_uuid) = location_gen.gen_block_location();
@@ -340,6 +344,7 @@ fn build_test_segment_info(
         inverted_index_size: None,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        col_checksums,
     };
 
     let block_metas = (0..num_blocks_per_seg)
@@ -12,11 +12,25 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::sync::Arc;
+
+use chrono::Utc;
 use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_query::sessions::TableContext;
+use databend_query::storages::fuse::io::MetaReaders;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
+use databend_query::storages::fuse::FuseTable;
+use databend_query::storages::fuse::FUSE_TBL_BLOCK_PREFIX;
+use databend_query::storages::fuse::FUSE_TBL_COLD_BLOCK_PREFIX;
 use databend_query::test_kits::TestFixture;
+use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_cache_manager::CacheManager;
+use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
 use futures_util::TryStreamExt;
@@ -33,6 +47,233 @@ fn test_meta_locations() -> Result<()> {
     let uuid = Uuid::new_v4();
     let snapshot_loc = locs.snapshot_location_from_uuid(&uuid, TableSnapshot::VERSION)?;
     assert!(snapshot_loc.starts_with(test_prefix));
+    let cold_loc = TableMetaLocationGenerator::gen_cold_block_location(&path);
+    assert!(cold_loc.starts_with(test_prefix));
+    assert_ne!(cold_loc, path);
+    assert_eq!(
+        TableMetaLocationGenerator::gen_hot_block_location(&cold_loc),
+        path
+    );
+    assert_eq!(
+        TableMetaLocationGenerator::gen_virtual_block_location(&cold_loc),
+        TableMetaLocationGenerator::gen_virtual_block_location(&path)
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tier_blocks_to_cold_tier() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+
+    fixture
+        .execute_command(&format!(
+            "create table {db}.{tbl}(a int) storage_format = Parquet tiering_age_days = 30"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.{tbl} values(1),(2),(3)"))
+        .await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let operator = fuse_table.get_operator();
+    let table_prefix = fuse_table.meta_location_generator().prefix();
+    let hot_prefix = format!("{}/{}/", table_prefix, FUSE_TBL_BLOCK_PREFIX);
+    let cold_prefix = format!("{}/{}/", table_prefix, FUSE_TBL_COLD_BLOCK_PREFIX);
+
+    // the block is younger than 30 days
+    let ctx: Arc<dyn TableContext> = fixture.new_query_ctx().await?;
+    assert_eq!(fuse_table.do_tier_blocks(ctx.clone()).await?, 0);
+    assert!(operator.list(&cold_prefix).await?.is_empty());
+
+    let moved = fuse_table
+        .do_tier_blocks_created_before(ctx.clone(), Utc::now())
+        .await?;
+    assert_eq!(moved, 1);
+    assert_eq!(operator.list(&cold_prefix).await?.len(), 1);
+
+    // the current snapshot references the cold location only
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let locations = query_block_locations(&fixture, &db, &tbl).await?;
+    assert_eq!(locations.len(), 1);
+    assert!(locations[0].starts_with(&cold_prefix));
+
+    // blocks already in the cold tier are not moved again
+    let ctx: Arc<dyn TableContext> = fixture.new_query_ctx().await?;
+    let moved = fuse_table
+        .do_tier_blocks_created_before(ctx.clone(), Utc::now())
+        .await?;
+    assert_eq!(moved, 0);
+
+    // the hot copy goes away with the snapshot referencing it
+    let snapshot_files = fuse_table.list_snapshot_files().await?;
+    fuse_table
+        .do_purge(&ctx, snapshot_files, None, true, false)
+        .await?;
+    assert!(operator.list(&hot_prefix).await?.is_empty());
+    assert_eq!(operator.list(&cold_prefix).await?.len(), 1);
+
+    let blocks = fixture
+        .execute_query(&format!("select sum(a) from {db}.{tbl}"))
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let sum = blocks[0]
+        .get_by_offset(0)
+        .value
+        .index(0)
+        .unwrap()
+        .to_owned();
+    assert_eq!(sum.to_string(), "6");
+    Ok(())
+}
+
+async fn query_block_locations(fixture: &TestFixture, db: &str, tbl: &str) -> Result<Vec<String>> {
+    let blocks = fixture
+        .execute_query(&format!(
+            "select block_location from fuse_block('{db}', '{tbl}')"
+        ))
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let mut locations = vec![];
+    for block in blocks {
+        for row in 0..block.num_rows() {
+            if let Some(ScalarRef::String(location)) = block.get_by_offset(0).value.index(row) {
+                locations.push(location.to_string());
+            }
+        }
+    }
+    Ok(locations)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_read_corrupted_block() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+
+    fixture
+        .execute_command(&format!(
+            "create table {db}.{tbl}(a int) storage_format = Parquet"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.{tbl} values(1),(2),(3)"))
+        .await?;
+
+    // flip a byte inside the column chunk, right after the parquet magic number
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let operator = fuse_table.get_operator();
+    let block_prefix = format!(
+        "{}/{}/",
+        fuse_table.meta_location_generator().prefix(),
+        FUSE_TBL_BLOCK_PREFIX
+    );
+    let blocks = operator.list(&block_prefix).await?;
+    assert_eq!(blocks.len(), 1);
+    let location = blocks[0].path();
+    let mut data = operator.read(location).await?.to_vec();
+    data[4] ^= 0xff;
+    operator.write(location, data).await?;
+
+    let res = fixture
+        .execute_query(&format!("select sum(a) from {db}.{tbl}"))
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await;
+    let err = res.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::CORRUPTED_BLOCK);
+    assert!(err.message().contains(location));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_block_checksum_table_option() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+
+    for (option, recorded) in [("", true), ("block_checksum = 'false'", false)] {
+        fixture
+            .execute_command(&format!("drop table if exists {db}.{tbl}"))
+            .await?;
+        fixture
+            .execute_command(&format!("create table {db}.{tbl}(a int) {option}"))
+            .await?;
+        fixture
+            .execute_command(&format!("insert into {db}.{tbl} values(1),(2),(3)"))
+            .await?;
+
+        let table = fixture.latest_default_table().await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+        let segment_reader =
+            MetaReaders::segment_info_reader(fuse_table.get_operator(), table.schema());
+        let params = LoadParams {
+            location: snapshot.segments[0].0.clone(),
+            len_hint: None,
+            ver: SegmentInfo::VERSION,
+            put_cache: false,
+        };
+        let segment = SegmentInfo::try_from(segment_reader.read(&params).await?)?;
+        assert_eq!(segment.blocks.len(), 1);
+        assert_eq!(!segment.blocks[0].col_checksums.is_empty(), recorded);
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_meta_disk_cache() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let mut config = InnerConfig::default();
+    config.cache.disk_cache_config.path = dir.path().to_string_lossy().to_string();
+    config.cache.table_meta_disk_cache_bytes = 1024 * 1024;
+    let fixture = TestFixture::setup_with_config(&config).await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+
+    fixture
+        .execute_command(&format!("create table {db}.{tbl}(a int)"))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.{tbl} values(1),(2),(3)"))
+        .await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let operator = fuse_table.get_operator();
+    let location = fuse_table.snapshot_loc().await?.unwrap();
+    let params = LoadParams {
+        location: location.clone(),
+        len_hint: None,
+        ver: TableSnapshot::VERSION,
+        put_cache: true,
+    };
+
+    let reader = MetaReaders::table_snapshot_reader(operator.clone());
+    let snapshot = reader.read(&params).await?;
+    let disk_cache = CacheManager::instance()
+        .get_table_meta_disk_cache()
+        .unwrap();
+    assert!(disk_cache.contains_key(&format!("{}-{}", location, TableSnapshot::VERSION)));
+
+    // evict the in-memory snapshot and remove the file, the snapshot can only come from disk
+    let memory_cache = CacheManager::instance().get_table_snapshot_cache().unwrap();
+    assert!(memory_cache.evict(&location));
+    operator.delete(&location).await?;
+
+    let cached = reader.read(&params).await?;
+    assert_eq!(snapshot.snapshot_id, cached.snapshot_id);
+    assert_eq!(snapshot.segments, cached.segments);
     Ok(())
 }
 
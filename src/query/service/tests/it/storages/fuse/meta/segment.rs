@@ -0,0 +1,167 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::ColumnId;
+use databend_common_expression::Scalar;
+use databend_storages_common_table_meta::meta::testing::MetaEncoding;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::ClusterStatistics;
+use databend_storages_common_table_meta::meta::ColumnMeta;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::CompactSegmentInfo;
+use databend_storages_common_table_meta::meta::Compression;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::SingleColumnMeta;
+use databend_storages_common_table_meta::meta::Statistics;
+use databend_storages_common_table_meta::meta::Versioned;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The block meta of a v4 segment, as it was before `col_checksums` was added.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct BlockMetaBeforeChecksums {
+    row_count: u64,
+    block_size: u64,
+    file_size: u64,
+    col_stats: HashMap<ColumnId, ColumnStatistics>,
+    col_metas: HashMap<ColumnId, ColumnMeta>,
+    cluster_stats: Option<ClusterStatistics>,
+    location: Location,
+    bloom_filter_index_location: Option<Location>,
+    #[serde(default)]
+    bloom_filter_index_size: u64,
+    inverted_index_size: Option<u64>,
+    compression: Compression,
+    create_on: Option<DateTime<Utc>>,
+}
+
+fn new_block_meta() -> BlockMeta {
+    let col_metas = (0..2)
+        .map(|id| {
+            let meta = SingleColumnMeta {
+                offset: id * 100,
+                len: 100,
+                num_values: 10,
+            };
+            (id as ColumnId, ColumnMeta::Parquet(meta))
+        })
+        .collect::<HashMap<_, _>>();
+    let col_stats = (0..2)
+        .map(|id| {
+            let stat = ColumnStatistics::new(
+                Scalar::Number(NumberScalar::Int32(0)),
+                Scalar::Number(NumberScalar::Int32(9)),
+                0,
+                40,
+                Some(10),
+            );
+            (id as ColumnId, stat)
+        })
+        .collect::<HashMap<_, _>>();
+    BlockMeta::new(
+        10,
+        80,
+        200,
+        col_stats,
+        col_metas,
+        None,
+        ("1/2/_b/block.parquet".to_string(), 2),
+        Some(("1/2/_i_b_v2/block.parquet".to_string(), 2)),
+        50,
+        None,
+        Compression::Lz4Raw,
+        Some(Utc::now()),
+    )
+}
+
+fn block_meta_before_checksums(meta: &BlockMeta) -> BlockMetaBeforeChecksums {
+    BlockMetaBeforeChecksums {
+        row_count: meta.row_count,
+        block_size: meta.block_size,
+        file_size: meta.file_size,
+        col_stats: meta.col_stats.clone(),
+        col_metas: meta.col_metas.clone(),
+        cluster_stats: meta.cluster_stats.clone(),
+        location: meta.location.clone(),
+        bloom_filter_index_location: meta.bloom_filter_index_location.clone(),
+        bloom_filter_index_size: meta.bloom_filter_index_size,
+        inverted_index_size: meta.inverted_index_size,
+        compression: meta.compression,
+        create_on: meta.create_on,
+    }
+}
+
+// Lay out the blocks and summary the same way as `SegmentInfo::to_bytes`, without compression.
+fn encode_segment_v4<T: Serialize>(blocks: &[T], summary: &Statistics) -> Vec<u8> {
+    let blocks = rmp_serde::to_vec_named(blocks).unwrap();
+    let summary = rmp_serde::to_vec_named(summary).unwrap();
+
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&SegmentInfo::VERSION.to_le_bytes());
+    buf.push(MetaEncoding::MessagePack as u8);
+    // MetaCompression::None
+    buf.push(0);
+    buf.extend_from_slice(&(blocks.len() as u64).to_le_bytes());
+    buf.extend_from_slice(&(summary.len() as u64).to_le_bytes());
+    buf.extend(blocks);
+    buf.extend(summary);
+    buf
+}
+
+#[test]
+fn test_decode_segment_v4_written_before_checksums() -> Result<()> {
+    let expected = new_block_meta();
+    let fixture = encode_segment_v4(
+        &[block_meta_before_checksums(&expected)],
+        &Statistics::default(),
+    );
+
+    let segment = SegmentInfo::from_slice(&fixture)?;
+    assert_eq!(segment.format_version, SegmentInfo::VERSION);
+    assert_eq!(segment.blocks.len(), 1);
+    assert_eq!(segment.blocks[0].as_ref(), &expected);
+    assert!(segment.blocks[0].col_checksums.is_empty());
+
+    let compact = CompactSegmentInfo::from_slice(&fixture)?;
+    let blocks = compact.block_metas()?;
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].as_ref(), &expected);
+    Ok(())
+}
+
+#[test]
+fn test_segment_v4_with_checksums_read_by_older_nodes() -> Result<()> {
+    let mut meta = new_block_meta();
+    meta.col_checksums = HashMap::from([(0, 0x1234_5678), (1, 0x9abc_def0)]);
+
+    // nodes that are not upgraded yet skip the unknown `col_checksums` key
+    let bytes = rmp_serde::to_vec_named(&vec![Arc::new(meta.clone())]).unwrap();
+    let blocks: Vec<BlockMetaBeforeChecksums> = rmp_serde::from_slice(&bytes).unwrap();
+    assert_eq!(blocks, vec![block_meta_before_checksums(&meta)]);
+
+    // upgraded nodes keep the checksums
+    let segment = SegmentInfo::new(vec![Arc::new(meta.clone())], Statistics::default());
+    let segment = SegmentInfo::from_slice(&segment.to_bytes()?)?;
+    assert_eq!(segment.blocks[0].as_ref(), &meta);
+    Ok(())
+}
@@ -512,6 +512,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_hide_profiles(&self) -> bool {
+        todo!()
+    }
+
+    fn set_hide_profiles(&self, _: bool) {
+        todo!()
+    }
+
     fn get_can_scan_from_agg_index(&self) -> bool {
         todo!()
     }
@@ -28,6 +28,7 @@ use databend_common_expression::FieldIndex;
 use databend_common_expression::Scalar;
 use databend_common_storage::ColumnNode;
 use databend_common_storage::ColumnNodes;
+use databend_common_storages_fuse::operations::adjust_scan_parallelism;
 use databend_common_storages_fuse::FuseBlockPartInfo;
 use databend_query::storages::fuse::FuseTable;
 use databend_query::test_kits::*;
@@ -149,6 +150,25 @@ fn test_to_partitions() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_adjust_scan_parallelism() {
+    const MB: usize = 1024 * 1024;
+
+    // tiny scans use a single stream.
+    assert_eq!(adjust_scan_parallelism(16, MB, 1, 0, 16 * MB), 1);
+    // the width grows with the bytes to read, up to max_threads.
+    assert_eq!(adjust_scan_parallelism(16, 40 * MB, 1, 0, 16 * MB), 3);
+    assert_eq!(adjust_scan_parallelism(16, 1024 * MB, 1, 0, 16 * MB), 16);
+    // unknown bytes or disabled sizing use all the threads.
+    assert_eq!(adjust_scan_parallelism(16, 0, 1, 0, 16 * MB), 16);
+    assert_eq!(adjust_scan_parallelism(16, MB, 1, 0, 0), 16);
+    // bounded by the min and max settings.
+    assert_eq!(adjust_scan_parallelism(16, MB, 4, 0, 16 * MB), 4);
+    assert_eq!(adjust_scan_parallelism(16, 1024 * MB, 1, 32, 16 * MB), 32);
+    assert_eq!(adjust_scan_parallelism(16, 1024 * MB, 1, 8, 16 * MB), 8);
+    assert_eq!(adjust_scan_parallelism(16, MB, 64, 8, 16 * MB), 8);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_fuse_table_exact_statistic() -> Result<()> {
     let fixture = TestFixture::setup().await?;
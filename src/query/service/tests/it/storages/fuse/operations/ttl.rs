@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_storages_fuse::FuseTable;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::*;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_expire_ttl() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+
+    let catalog = fixture.default_catalog_name();
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("create table {db}.t(id int, ts timestamp)"))
+        .await?;
+    // one block fully expired, one block with a single live row, one block fully alive
+    fixture
+        .execute_command(&format!(
+            "insert into {db}.t values (1, '2000-01-01 00:00:00'), (2, '2000-01-02 00:00:00')"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "insert into {db}.t values (3, '2000-01-01 00:00:00'), (4, now())"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values (5, now())"))
+        .await?;
+    // rows without a TTL value never expire
+    fixture
+        .execute_command(&format!(
+            "insert into {db}.t values (6, '2000-01-01 00:00:00'), (7, null)"
+        ))
+        .await?;
+
+    // no ttl, nothing to remove
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx.get_table(&catalog, &db, "t").await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert_eq!(fuse_table.do_expire_ttl(ctx.clone()).await?, 0);
+
+    fixture
+        .execute_command(&format!(
+            "alter table {db}.t set ttl = ts + interval 30 day"
+        ))
+        .await?;
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx.get_table(&catalog, &db, "t").await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert_eq!(fuse_table.do_expire_ttl(ctx.clone()).await?, 1);
+
+    let count = query_count(
+        fixture
+            .execute_query(&format!("select count(*) from {db}.t"))
+            .await?,
+    )
+    .await?;
+    assert_eq!(count, 5);
+
+    // expired rows living along with live rows are kept until the block expires
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx.get_table(&catalog, &db, "t").await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert_eq!(fuse_table.do_expire_ttl(ctx.clone()).await?, 0);
+
+    Ok(())
+}
@@ -106,6 +106,7 @@ async fn test_block_pruner() -> Result<()> {
         .into(),
         field_comments: vec![],
         as_select: None,
+        clone_source: None,
         cluster_key: None,
         inverted_indexes: None,
     };
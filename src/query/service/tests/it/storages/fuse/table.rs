@@ -13,9 +13,22 @@
 //  limitations under the License.
 
 use std::default::Default;
+use std::fs::File;
+use std::path::Path;
 
 use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_sql::executor::table_read_plan::ToReadDataSourcePlan;
 use databend_query::storages::fuse::FuseTable;
@@ -23,6 +36,7 @@ use databend_query::stream::ReadDataBlockStream;
 use databend_query::test_kits::*;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use futures::TryStreamExt;
+use parquet::arrow::ArrowWriter;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn test_fuse_table_normal_case() -> Result<()> {
@@ -151,3 +165,77 @@ fn test_parse_storage_prefix() -> Result<()> {
     assert_eq!(format!("{}/{}", db_id, tbl_id), prefix);
     Ok(())
 }
+
+fn write_parquet_fixture(path: &Path, schema: &TableSchema, block: DataBlock) -> Result<()> {
+    let batch = block.to_record_batch(schema)?;
+    let mut writer = ArrowWriter::try_new(File::create(path)?, batch.schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_load_fixtures() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let dir = tempfile::tempdir()?;
+
+    std::fs::write(
+        dir.path().join("t_fixture.sql"),
+        "create table t_fixture(a int not null, b string not null);\n\
+         create table t_fixture_empty(a int);\n",
+    )?;
+    let schema = TableSchemaRefExt::create(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("b", TableDataType::String),
+    ]);
+    let block = DataBlock::new_from_columns(vec![
+        Int32Type::from_data(vec![1, 2, 3]),
+        StringType::from_data(vec!["x", "y", "z"]),
+    ]);
+    write_parquet_fixture(&dir.path().join("t_fixture.parquet"), &schema, block)?;
+
+    fixture.load_fixtures(dir.path()).await?;
+
+    let expected = vec![
+        "+----------+----------+----------+",
+        "| Column 0 | Column 1 | Column 2 |",
+        "+----------+----------+----------+",
+        "| 3        | 6        | 0        |",
+        "+----------+----------+----------+",
+    ];
+    expects_ok(
+        "load_fixtures",
+        fixture
+            .execute_query(
+                "select count(*), sum(a), (select count(*) from t_fixture_empty) from t_fixture",
+            )
+            .await,
+        expected,
+    )
+    .await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_load_fixtures_schema_mismatch() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let dir = tempfile::tempdir()?;
+
+    std::fs::write(
+        dir.path().join("t_fixture.sql"),
+        "create table t_fixture(a int not null)",
+    )?;
+    let schema = TableSchemaRefExt::create(vec![TableField::new(
+        "a",
+        TableDataType::Number(NumberDataType::Int64),
+    )]);
+    let block = DataBlock::new_from_columns(vec![Int64Type::from_data(vec![1, 2, 3])]);
+    write_parquet_fixture(&dir.path().join("t_fixture.parquet"), &schema, block)?;
+
+    expects_err(
+        "load_fixtures_schema_mismatch",
+        ErrorCode::TABLE_SCHEMA_MISMATCH,
+        fixture.load_fixtures(dir.path()).await,
+    );
+    Ok(())
+}
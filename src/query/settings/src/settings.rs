@@ -36,6 +36,8 @@ pub enum ScopeLevel {
     Default,
     Local,
     Global,
+    // Global setting locked by the tenant admin, it can not be changed in the sessions.
+    Locked,
     Session,
 }
 
@@ -51,6 +53,9 @@ impl Debug for ScopeLevel {
             ScopeLevel::Global => {
                 write!(f, "GLOBAL")
             }
+            ScopeLevel::Locked => {
+                write!(f, "LOCKED")
+            }
             ScopeLevel::Session => {
                 write!(f, "SESSION")
             }
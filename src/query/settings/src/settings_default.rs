@@ -49,13 +49,17 @@ pub enum SettingMode {
 pub enum SettingRange {
     Numeric(RangeInclusive<u64>),
     String(Vec<String>),
+    // Comma-separated values, each one of the strings.
+    StringList(Vec<String>),
 }
 
 impl Display for SettingRange {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             SettingRange::Numeric(range) => write!(f, "[{}, {}]", range.start(), range.end()),
-            SettingRange::String(values) => write!(f, "{:?}", values),
+            SettingRange::String(values) | SettingRange::StringList(values) => {
+                write!(f, "{:?}", values)
+            }
         }
     }
 }
@@ -95,6 +99,35 @@ impl SettingRange {
             _ => Err(ErrorCode::BadArguments("Expected string range".to_string())),
         }
     }
+
+    /// Checks if each comma-separated item of a string value is within the string list range,
+    /// the items are matched ignoring case and underscores, e.g. `eliminate_sort` is `EliminateSort`.
+    pub fn is_within_string_list_range(&self, value: &str) -> Result<String> {
+        match self {
+            SettingRange::StringList(values) => {
+                let normalize = |s: &str| s.replace('_', "").to_lowercase();
+                let items = value
+                    .split(',')
+                    .map(|item| item.trim())
+                    .filter(|item| !item.is_empty())
+                    .map(|item| {
+                        let normalized = normalize(item);
+                        match values.iter().find(|s| normalize(s) == normalized) {
+                            Some(s) => Ok(s.as_str()),
+                            None => Err(ErrorCode::WrongValueForVariable(format!(
+                                "Value {} is not within the allowed values {:}",
+                                item, self
+                            ))),
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(items.join(","))
+            }
+            _ => Err(ErrorCode::BadArguments(
+                "Expected string list range".to_string(),
+            )),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -165,6 +198,24 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(1..=1024)),
                 }),
+                ("min_scan_parallelism", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Sets the minimum number of parallel streams to scan a table in a query.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=1024)),
+                }),
+                ("max_scan_parallelism", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum number of parallel streams to scan a table in a query, 0 means max_threads.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1024)),
+                }),
+                ("scan_bytes_per_thread", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(16 * 1024 * 1024),
+                    desc: "Sets the estimated bytes to read by each stream of a table scan in a query, the scan of fewer bytes uses fewer streams. 0 disables it.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("storage_io_min_bytes_for_seek", DefaultSettingValue {
                     value: UserSettingValue::UInt64(48),
                     desc: "Sets the minimum byte size of data that must be read from storage in a single I/O operation \
@@ -186,7 +237,7 @@ impl DefaultSettings {
                 }),
                 ("http_handler_result_timeout_secs", DefaultSettingValue {
                     value: {
-                        let result_timeout_secs = global_conf.map(|conf| conf.query.http_handler_result_timeout_secs)
+                        let result_timeout_secs = global_conf.as_ref().map(|conf| conf.query.http_handler_result_timeout_secs)
                             .unwrap_or(60);
                         UserSettingValue::UInt64(result_timeout_secs)
                     },
@@ -194,6 +245,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("http_handler_batch_result_max_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64 * 1024 * 1024),
+                    desc: "Sets the maximum byte size of the result of each statement in a http query batch, the statement fails if exceeded. Setting it to 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("storage_read_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1024 * 1024),
                     desc: "Sets the byte size of the buffer used for reading data into memory.",
@@ -272,12 +329,36 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("disabled_optimizer_rules", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_string()),
+                    desc: "Comma-separated names of the optimizer rules to skip, e.g. 'PushDownFilterJoin,EliminateSort', used to diagnose plan regressions.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::StringList(vec!["NormalizeScalarFilter".into(), "PushDownFilterAggregate".into(), "PushDownFilterEvalScalar".into(), "PushDownFilterUnion".into(), "PushDownFilterJoin".into(), "PushDownFilterScan".into(), "PushDownFilterSort".into(), "PushDownFilterProjectSet".into(), "PushDownFilterUdf".into(), "PushDownFilterWindow".into(), "PushDownLimitUnion".into(), "PushDownLimitOuterJoin".into(), "PushDownLimitEvalScalar".into(), "PushDownLimitSort".into(), "PushDownLimitWindow".into(), "PushDownLimitAggregate".into(), "PushDownLimitScan".into(), "PushDownSortScan".into(), "SemiToInnerJoin".into(), "OuterJoinToInnerJoin".into(), "EliminateEvalScalar".into(), "EliminateFilter".into(), "EliminateSort".into(), "MergeEvalScalar".into(), "MergeFilter".into(), "SplitAggregate".into(), "FoldCountAggregate".into(), "PushDownPrewhere".into(), "TryApplyAggIndex".into(), "CommuteJoin".into(), "CommuteJoinBaseTable".into(), "LeftExchangeJoin".into(), "EagerAggregation".into()])),
+                }),
                 ("join_spilling_memory_ratio", DefaultSettingValue {
                     value: UserSettingValue::UInt64(60),
                     desc: "Sets the maximum memory ratio in bytes that hash join can use before spilling data to storage during query execution, 0 is unlimited",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=100)),
                 }),
+                ("join_skew_salt_buckets", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the number of salted partitions a skewed join key can be spread over when shuffling in cluster mode, 0 or 1 disables salting.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=64)),
+                }),
+                ("join_skew_sample_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(8192),
+                    desc: "Sets the number of probe rows sampled when planning a distributed join to detect skewed join keys.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=1048576)),
+                }),
+                ("join_skew_hot_key_percent", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(10),
+                    desc: "Sets the percentage of sampled probe rows a join key must take to be salted.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=100)),
+                }),
                 ("join_spilling_bytes_threshold_per_proc", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum amount of memory in bytes that one join processor can use before spilling data to storage during query execution, 0 is unlimited.",
@@ -314,6 +395,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("materialize_cte_min_references", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Materializes a CTE without the MATERIALIZED keyword if it is referenced at least this many times in the query and its estimated cost to compute for every reference exceeds the cost to compute it once, write and read back its rows. Setting it to 0 disables it.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("inlist_to_join_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1024),
                     desc: "Set the threshold for converting IN list to JOIN.",
@@ -326,12 +413,42 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_shared_hash_join_build", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Builds the hash table of a repeated join build side once and shares it across the joins of a query, only for queries not executed in cluster mode.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("max_execute_time_in_seconds", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum query execution time in seconds. Setting it to 0 means no limit.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("select_statement_timeout_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(global_conf.as_ref().map(|conf| conf.query.select_statement_timeout_secs).unwrap_or(0)),
+                    desc: "Sets the maximum execution time in seconds of SELECT statements when max_execute_time_in_seconds is not set. Setting it to 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("dml_statement_timeout_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(global_conf.as_ref().map(|conf| conf.query.dml_statement_timeout_secs).unwrap_or(0)),
+                    desc: "Sets the maximum execution time in seconds of DML statements when max_execute_time_in_seconds is not set. Setting it to 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("ddl_statement_timeout_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(global_conf.as_ref().map(|conf| conf.query.ddl_statement_timeout_secs).unwrap_or(0)),
+                    desc: "Sets the maximum execution time in seconds of DDL statements (CREATE, ALTER, DROP ...) when max_execute_time_in_seconds is not set. Setting it to 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("dml_statement_retry_budget_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(global_conf.as_ref().map(|conf| conf.query.dml_statement_retry_budget_secs).unwrap_or(0)),
+                    desc: "Sets the time budget in seconds for retrying conflicting commits of DML statements. Setting it to 0 uses the built-in budget.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("collation", DefaultSettingValue {
                     value: UserSettingValue::String("utf8".to_owned()),
                     desc: "Sets the character collation. Available values include \"utf8\".",
@@ -356,6 +473,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("semi_join_reduction_ratio", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Pre-filters the probe side of a hash shuffle join with the broadcast join keys of the build side, if the estimated probe side rows are at least this many times the build side rows. Setting it to 0 disables the semi-join reduction.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("storage_fetch_part_num", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2),
                     desc: "Sets the number of partitions that are fetched in parallel from storage during query execution.",
@@ -386,6 +509,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_plan_cache", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables reusing the optimized plans of repeated queries while the versions of the tables they read are unchanged.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("query_result_cache_max_bytes", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1048576), // 1MB
                     desc: "Sets the maximum byte size of cache for a single query result.",
@@ -478,12 +607,30 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("sorted_group_by_output", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Produces the output of GROUP BY sorted by the group keys when the query has no ORDER BY, which makes the results deterministic. The aggregation sorts its input by the group keys instead of hashing it when it keeps most of the rows, an ORDER BY on the group keys is free then.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("deterministic", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Makes query results reproducible: rand() and uuid() draw from a random state seeded once per query with a fixed seed, GROUP BY output is sorted by the group keys, and time dependent functions such as now() return the Unix epoch.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("lazy_read_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1000),
                     desc: "Sets the maximum LIMIT in a query to enable lazy read optimization. Setting it to 0 disables the optimization.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("lazy_read_wide_columns_only", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Only defers the variable-length and nested columns in lazy read optimization, the fixed-size columns are read in the first phase.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("parquet_fast_read_bytes", DefaultSettingValue {
                     value: UserSettingValue::UInt64(16 * 1024 * 1024),
                     desc: "Parquet file with smaller size will be read as a whole file, instead of column by column. Default value: 16MB",
@@ -565,12 +712,30 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_materialized_view_rewrite", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables rewriting aggregate queries to read up-to-date materialized views of their tables.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("materialized_view_max_staleness_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Allows rewriting queries to read materialized views that are behind their base table, if they were refreshed within the given seconds. 0 means only up-to-date views are used.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("enable_compact_after_write", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables compact after write(copy/insert/replace-into/merge-into), need more memory.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_incremental_table_statistics", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables merging the NDV sketches of appended blocks into the table statistics when the append is committed.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("auto_compaction_imperfect_blocks_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(25),
                     desc: "Threshold for triggering auto compaction. This occurs when the number of imperfect blocks in a snapshot exceeds this value after write operations.",
@@ -667,6 +832,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_ndjson_column_inference", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Queries NDJSON files in a stage as typed columns inferred from the sampled records of the first file, the values that don't fit them go to the `_overflow` VARIANT column.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_experimental_aggregate_hashtable", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables experimental aggregate hashtable",
@@ -789,6 +960,12 @@ impl DefaultSettings {
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
 
+                ("enable_block_checksum_verification", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables verifying the checksums of the column chunks read from the fuse table blocks",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_auto_fix_missing_bloom_index", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables auto fix missing bloom index",
@@ -818,6 +995,54 @@ impl DefaultSettings {
                     desc: "Format NULL as str in query api response",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("format_csv_field_delimiter", DefaultSettingValue {
+                    value: UserSettingValue::String(",".to_owned()),
+                    desc: "Sets the field delimiter of the CSV output of the FORMAT clause in the clickhouse http handler.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("format_csv_record_delimiter", DefaultSettingValue {
+                    value: UserSettingValue::String("\n".to_owned()),
+                    desc: "Sets the record delimiter of the CSV output of the FORMAT clause in the clickhouse http handler.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("format_csv_quote", DefaultSettingValue {
+                    value: UserSettingValue::String("\"".to_owned()),
+                    desc: "Sets the quote char of the CSV output of the FORMAT clause in the clickhouse http handler.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["\"".into(), "'".into(), "`".into()])),
+                }),
+                ("format_csv_escape", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "Sets the escape char of the quotes in the CSV output of the FORMAT clause in the clickhouse http handler, the quotes are doubled if empty.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["".into(), "\\".into()])),
+                }),
+                ("format_csv_null_display", DefaultSettingValue {
+                    value: UserSettingValue::String("\\N".to_owned()),
+                    desc: "Sets how NULL is displayed in the CSV output of the FORMAT clause in the clickhouse http handler.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("format_csv_output_bom", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Writes a UTF-8 BOM at the beginning of the CSV output of the FORMAT clause in the clickhouse http handler.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("progressive_aggregate_interval_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the interval in seconds at which a running aggregation without GROUP BY publishes its intermediate (non-final) result to the query api. Setting it to 0 means disabled.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=3600)),
+                }),
+                ("http_result_spool_ttl_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the time-to-live in seconds of the http query results spooled to the object store, which can be served by any node. Setting it to 0 means disabled.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=7 * 24 * 3600)),
                 })
             ]);
 
@@ -934,6 +1159,12 @@ impl DefaultSettings {
                         // value is the standard value of the setting.
                         let value = range.is_within_string_range(&v)?;
 
+                        Ok((k, UserSettingValue::String(value)))
+                    }
+                    // String list range.
+                    SettingRange::StringList(_) => {
+                        let value = range.is_within_string_list_range(&v)?;
+
                         Ok((k, UserSettingValue::String(value)))
                     }
                 }
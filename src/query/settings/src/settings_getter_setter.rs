@@ -110,10 +110,23 @@ impl Settings {
 
     pub fn set_setting(&self, k: String, v: String) -> Result<()> {
         DefaultSettings::check_setting_mode(&k, SettingMode::Write)?;
+        self.check_setting_not_locked(&k)?;
 
         unsafe { self.unchecked_set_setting(k, v) }
     }
 
+    fn check_setting_not_locked(&self, k: &str) -> Result<()> {
+        match self.changes.get(k) {
+            Some(change) if matches!(change.level, ScopeLevel::Locked) => {
+                Err(ErrorCode::PermissionDenied(format!(
+                    "Setting {:?} is locked by the tenant, it can not be changed in the session",
+                    k
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
     unsafe fn unchecked_set_setting(&self, k: String, v: String) -> Result<()> {
         let (key, value) = DefaultSettings::convert_value(k.clone(), v)?;
 
@@ -136,6 +149,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_auto_fix_missing_bloom_index")? != 0)
     }
 
+    pub fn get_enable_block_checksum_verification(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_block_checksum_verification")? != 0)
+    }
+
     // Get max_block_size.
     pub fn get_max_block_size(&self) -> Result<u64> {
         self.try_get_u64("max_block_size")
@@ -197,6 +214,18 @@ impl Settings {
         }
     }
 
+    pub fn get_min_scan_parallelism(&self) -> Result<u64> {
+        self.try_get_u64("min_scan_parallelism")
+    }
+
+    pub fn get_max_scan_parallelism(&self) -> Result<u64> {
+        self.try_get_u64("max_scan_parallelism")
+    }
+
+    pub fn get_scan_bytes_per_thread(&self) -> Result<u64> {
+        self.try_get_u64("scan_bytes_per_thread")
+    }
+
     pub fn get_storage_io_min_bytes_for_seek(&self) -> Result<u64> {
         self.try_get_u64("storage_io_min_bytes_for_seek")
     }
@@ -210,6 +239,26 @@ impl Settings {
         self.try_get_u64("max_execute_time_in_seconds")
     }
 
+    pub fn set_max_execute_time_in_seconds(&self, val: u64) -> Result<()> {
+        self.try_set_u64("max_execute_time_in_seconds", val)
+    }
+
+    pub fn get_select_statement_timeout_secs(&self) -> Result<u64> {
+        self.try_get_u64("select_statement_timeout_secs")
+    }
+
+    pub fn get_dml_statement_timeout_secs(&self) -> Result<u64> {
+        self.try_get_u64("dml_statement_timeout_secs")
+    }
+
+    pub fn get_ddl_statement_timeout_secs(&self) -> Result<u64> {
+        self.try_get_u64("ddl_statement_timeout_secs")
+    }
+
+    pub fn get_dml_statement_retry_budget_secs(&self) -> Result<u64> {
+        self.try_get_u64("dml_statement_retry_budget_secs")
+    }
+
     // Get flight client timeout.
     pub fn get_flight_client_timeout(&self) -> Result<u64> {
         self.try_get_u64("flight_client_timeout")
@@ -270,10 +319,31 @@ impl Settings {
         Ok(self.unchecked_try_get_u64("disable_join_reorder")? != 0)
     }
 
+    pub fn get_disabled_optimizer_rules(&self) -> Result<Vec<String>> {
+        Ok(self
+            .try_get_string("disabled_optimizer_rules")?
+            .split(',')
+            .map(|rule| rule.trim().to_string())
+            .filter(|rule| !rule.is_empty())
+            .collect())
+    }
+
     pub fn get_join_spilling_memory_ratio(&self) -> Result<usize> {
         Ok(self.try_get_u64("join_spilling_memory_ratio")? as usize)
     }
 
+    pub fn get_join_skew_salt_buckets(&self) -> Result<usize> {
+        Ok(self.try_get_u64("join_skew_salt_buckets")? as usize)
+    }
+
+    pub fn get_join_skew_sample_rows(&self) -> Result<usize> {
+        Ok(self.try_get_u64("join_skew_sample_rows")? as usize)
+    }
+
+    pub fn get_join_skew_hot_key_percent(&self) -> Result<u64> {
+        self.try_get_u64("join_skew_hot_key_percent")
+    }
+
     pub fn get_join_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
         Ok(self.try_get_u64("join_spilling_bytes_threshold_per_proc")? as usize)
     }
@@ -294,6 +364,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_bloom_runtime_filter")? != 0)
     }
 
+    pub fn get_enable_shared_hash_join_build(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_shared_hash_join_build")? != 0)
+    }
+
     pub fn get_prefer_broadcast_join(&self) -> Result<bool> {
         Ok(self.try_get_u64("prefer_broadcast_join")? != 0)
     }
@@ -302,6 +376,10 @@ impl Settings {
         Ok(self.try_get_u64("enforce_broadcast_join")? != 0)
     }
 
+    pub fn get_semi_join_reduction_ratio(&self) -> Result<u64> {
+        self.try_get_u64("semi_join_reduction_ratio")
+    }
+
     pub fn get_disable_merge_into_join_reorder(&self) -> Result<bool> {
         Ok(self.try_get_u64("disable_merge_into_join_reorder")? != 0)
     }
@@ -314,6 +392,10 @@ impl Settings {
         Ok(self.try_get_u64("max_cte_recursive_depth")? as usize)
     }
 
+    pub fn get_materialize_cte_min_references(&self) -> Result<u64> {
+        self.try_get_u64("materialize_cte_min_references")
+    }
+
     pub fn get_sql_dialect(&self) -> Result<Dialect> {
         match self.try_get_string("sql_dialect")?.to_lowercase().as_str() {
             "hive" => Ok(Dialect::Hive),
@@ -355,6 +437,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_query_result_cache")? != 0)
     }
 
+    pub fn get_enable_plan_cache(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_plan_cache")? != 0)
+    }
+
     pub fn get_query_result_cache_max_bytes(&self) -> Result<usize> {
         Ok(self.try_get_u64("query_result_cache_max_bytes")? as usize)
     }
@@ -367,6 +453,10 @@ impl Settings {
         self.try_get_u64("http_handler_result_timeout_secs")
     }
 
+    pub fn get_http_handler_batch_result_max_bytes(&self) -> Result<usize> {
+        Ok(self.try_get_u64("http_handler_batch_result_max_bytes")? as usize)
+    }
+
     pub fn get_query_result_cache_ttl_secs(&self) -> Result<u64> {
         self.try_get_u64("query_result_cache_ttl_secs")
     }
@@ -411,6 +501,14 @@ impl Settings {
         Ok(self.try_get_u64("efficiently_memory_group_by")? == 1)
     }
 
+    pub fn get_sorted_group_by_output(&self) -> Result<bool> {
+        Ok(self.try_get_u64("sorted_group_by_output")? != 0)
+    }
+
+    pub fn get_deterministic(&self) -> Result<bool> {
+        Ok(self.try_get_u64("deterministic")? != 0)
+    }
+
     pub fn get_enable_experimental_aggregate_hashtable(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_experimental_aggregate_hashtable")? == 1)
     }
@@ -419,6 +517,10 @@ impl Settings {
         self.try_get_u64("lazy_read_threshold")
     }
 
+    pub fn get_lazy_read_wide_columns_only(&self) -> Result<bool> {
+        Ok(self.try_get_u64("lazy_read_wide_columns_only")? != 0)
+    }
+
     pub fn set_parquet_fast_read_bytes(&self, value: u64) -> Result<()> {
         self.try_set_u64("parquet_fast_read_bytes", value)
     }
@@ -496,10 +598,22 @@ impl Settings {
         Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
     }
 
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_view_rewrite")? != 0)
+    }
+
+    pub fn get_materialized_view_max_staleness_secs(&self) -> Result<u64> {
+        self.try_get_u64("materialized_view_max_staleness_secs")
+    }
+
     pub fn get_enable_compact_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_compact_after_write")? != 0)
     }
 
+    pub fn get_enable_incremental_table_statistics(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_incremental_table_statistics")? != 0)
+    }
+
     pub fn get_auto_compaction_imperfect_blocks_threshold(&self) -> Result<u64> {
         self.try_get_u64("auto_compaction_imperfect_blocks_threshold")
     }
@@ -569,6 +683,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_parquet_prewhere")? != 0)
     }
 
+    pub fn get_enable_ndjson_column_inference(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_ndjson_column_inference")? != 0)
+    }
+
     pub fn get_numeric_cast_option(&self) -> Result<String> {
         self.try_get_string("numeric_cast_option")
     }
@@ -677,4 +795,36 @@ impl Settings {
     pub fn get_format_null_as_str(&self) -> Result<bool> {
         Ok(self.try_get_u64("format_null_as_str")? == 1)
     }
+
+    pub fn get_format_csv_field_delimiter(&self) -> Result<String> {
+        self.try_get_string("format_csv_field_delimiter")
+    }
+
+    pub fn get_format_csv_record_delimiter(&self) -> Result<String> {
+        self.try_get_string("format_csv_record_delimiter")
+    }
+
+    pub fn get_format_csv_quote(&self) -> Result<String> {
+        self.try_get_string("format_csv_quote")
+    }
+
+    pub fn get_format_csv_escape(&self) -> Result<String> {
+        self.try_get_string("format_csv_escape")
+    }
+
+    pub fn get_format_csv_null_display(&self) -> Result<String> {
+        self.try_get_string("format_csv_null_display")
+    }
+
+    pub fn get_format_csv_output_bom(&self) -> Result<bool> {
+        Ok(self.try_get_u64("format_csv_output_bom")? == 1)
+    }
+
+    pub fn get_progressive_aggregate_interval_secs(&self) -> Result<u64> {
+        self.try_get_u64("progressive_aggregate_interval_secs")
+    }
+
+    pub fn get_http_result_spool_ttl_secs(&self) -> Result<u64> {
+        self.try_get_u64("http_result_spool_ttl_secs")
+    }
 }
@@ -50,18 +50,40 @@ impl Settings {
 
     #[async_backtrace::framed]
     pub async fn set_global_setting(&self, k: String, v: String) -> Result<()> {
+        self.upsert_global_setting(k, v, false).await
+    }
+
+    /// Set the global setting and lock it, the sessions of the tenant can not override it.
+    #[async_backtrace::framed]
+    pub async fn set_locked_global_setting(&self, k: String, v: String) -> Result<()> {
+        self.upsert_global_setting(k, v, true).await
+    }
+
+    async fn upsert_global_setting(&self, k: String, v: String, locked: bool) -> Result<()> {
         let (key, value) = DefaultSettings::convert_value(k.clone(), v)?;
         self.changes.insert(key.clone(), ChangeValue {
             value: value.clone(),
-            level: ScopeLevel::Global,
+            level: Self::global_level(locked),
         });
 
+        let setting = UserSetting {
+            name: key,
+            value,
+            locked,
+        };
         UserApiProvider::instance()
-            .set_setting(&self.tenant, UserSetting { name: key, value })
+            .set_setting(&self.tenant, setting)
             .await?;
         Ok(())
     }
 
+    fn global_level(locked: bool) -> ScopeLevel {
+        match locked {
+            true => ScopeLevel::Locked,
+            false => ScopeLevel::Global,
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn load_changes(&self) -> Result<()> {
         self.load_config_changes()?;
@@ -95,6 +117,7 @@ impl Settings {
         for global_setting in global_settings {
             let name = global_setting.name;
             let val = global_setting.value.as_string();
+            let level = Self::global_level(global_setting.locked);
 
             self.changes
                 .insert(name.clone(), match default_settings.settings.get(&name) {
@@ -105,11 +128,11 @@ impl Settings {
                     }
                     Some(default_setting_value) => match &default_setting_value.value {
                         UserSettingValue::UInt64(_) => ChangeValue {
-                            level: ScopeLevel::Global,
+                            level,
                             value: UserSettingValue::UInt64(val.parse::<u64>()?),
                         },
                         UserSettingValue::String(_) => ChangeValue {
-                            level: ScopeLevel::Global,
+                            level,
                             value: UserSettingValue::String(val.clone()),
                         },
                     },
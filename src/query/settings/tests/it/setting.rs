@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use dashmap::DashMap;
 use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
+use databend_common_meta_app::principal::UserSettingValue;
 use databend_common_meta_app::tenant::Tenant;
+use databend_common_settings::ChangeValue;
+use databend_common_settings::ScopeLevel;
 use databend_common_settings::Settings;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -98,6 +102,52 @@ async fn test_set_settings() {
         let expect = "WrongValueForVariable. Code: 2803, Text = Value xx is not within the allowed values [\"None\", \"LZ4\", \"ZSTD\"].";
         assert_eq!(expect, format!("{}", result.unwrap_err()));
     }
+
+    // String list out of range.
+    {
+        // Ok, the names are normalized.
+        settings
+            .set_setting(
+                "disabled_optimizer_rules".to_string(),
+                "eliminate_sort, PushDownFilterJoin,".to_string(),
+            )
+            .unwrap();
+        assert_eq!(
+            vec![
+                "EliminateSort".to_string(),
+                "PushDownFilterJoin".to_string()
+            ],
+            settings.get_disabled_optimizer_rules().unwrap()
+        );
+
+        // Ok, no rule is disabled.
+        settings
+            .set_setting("disabled_optimizer_rules".to_string(), "".to_string())
+            .unwrap();
+        assert!(settings.get_disabled_optimizer_rules().unwrap().is_empty());
+
+        // Error, the previous value is kept.
+        settings
+            .set_setting(
+                "disabled_optimizer_rules".to_string(),
+                "EliminateSort".to_string(),
+            )
+            .unwrap();
+        let result = settings.set_setting(
+            "disabled_optimizer_rules".to_string(),
+            "EliminateSort,no_such_rule".to_string(),
+        );
+        let err = result.unwrap_err();
+        assert_eq!(err.code(), 2803);
+        assert!(
+            err.message()
+                .starts_with("Value no_such_rule is not within the allowed values")
+        );
+        assert_eq!(
+            vec!["EliminateSort".to_string()],
+            settings.get_disabled_optimizer_rules().unwrap()
+        );
+    }
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -113,6 +163,28 @@ async fn test_set_global_settings() {
     assert_eq!(expect, format!("{}", result.unwrap_err()));
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_set_locked_settings() {
+    let settings = Settings::create(Tenant::new_literal("test"));
+    let changes = DashMap::new();
+    changes.insert("max_threads".to_string(), ChangeValue {
+        level: ScopeLevel::Locked,
+        value: UserSettingValue::UInt64(2),
+    });
+    unsafe { settings.unchecked_apply_changes(&changes) };
+
+    let result = settings.set_setting("max_threads".to_string(), "4".to_string());
+    let expect = "PermissionDenied. Code: 1063, Text = Setting \"max_threads\" is locked by the tenant, it can not be changed in the session.";
+    assert_eq!(expect, format!("{}", result.unwrap_err()));
+    assert_eq!(2, settings.get_max_threads().unwrap());
+
+    // Not locked settings can still be changed.
+    settings
+        .set_setting("max_block_size".to_string(), "1000".to_string())
+        .unwrap();
+    assert_eq!(1000, settings.get_max_block_size().unwrap());
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_set_data_retention_time_in_days() {
     // Init.
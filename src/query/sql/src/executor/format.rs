@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use databend_common_ast::ast::FormatTreeNode;
+use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::format_byte_size;
 use databend_common_base::runtime::profile::get_statistics_desc;
 use databend_common_catalog::plan::PartStatistics;
@@ -23,6 +24,7 @@ use databend_common_exception::Result;
 use databend_common_expression::DataSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_core::processors::PlanProfile;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
 use itertools::Itertools;
 
 use super::physical_plans::AddStreamColumn;
@@ -157,7 +159,7 @@ impl PhysicalPlan {
                     children,
                 ))
             }
-            PhysicalPlan::CteScan(cte_scan) => cte_scan_to_format_tree(cte_scan),
+            PhysicalPlan::CteScan(cte_scan) => cte_scan_to_format_tree(cte_scan, &HashMap::new()),
             PhysicalPlan::MaterializedCte(materialized_cte) => {
                 let left_child = materialized_cte.left.format_join(metadata)?;
                 let right_child = materialized_cte.right.format_join(metadata)?;
@@ -259,22 +261,21 @@ fn to_format_tree(
         }
         PhysicalPlan::MutationOrganize(plan) => format_merge_into_organize(plan, metadata, profs),
         PhysicalPlan::AddStreamColumn(plan) => format_add_stream_column(plan, metadata, profs),
-        PhysicalPlan::CteScan(plan) => cte_scan_to_format_tree(plan),
+        PhysicalPlan::CteScan(plan) => cte_scan_to_format_tree(plan, profs),
         PhysicalPlan::RecursiveCteScan(_) => {
             Ok(FormatTreeNode::new("RecursiveCTEScan".to_string()))
         }
         PhysicalPlan::MaterializedCte(plan) => {
             materialized_cte_to_format_tree(plan, metadata, profs)
         }
-        PhysicalPlan::ConstantTableScan(plan) => constant_table_scan_to_format_tree(plan, metadata),
+        PhysicalPlan::ConstantTableScan(plan) => {
+            constant_table_scan_to_format_tree(plan, metadata, profs)
+        }
         PhysicalPlan::ExpressionScan(plan) => expression_scan_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::CacheScan(plan) => cache_scan_to_format_tree(plan, metadata),
+        PhysicalPlan::CacheScan(plan) => cache_scan_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Duplicate(plan) => {
             let mut children = Vec::new();
-            children.push(FormatTreeNode::new(format!(
-                "Duplicate data to {} branch",
-                plan.n
-            )));
+            children.push(FormatTreeNode::property("output branches", plan.n));
             append_profile_info(&mut children, profs, plan.plan_id);
             children.push(to_format_tree(&plan.input, metadata, profs)?);
             Ok(FormatTreeNode::with_children(
@@ -290,13 +291,12 @@ fn to_format_tree(
             let mut children = Vec::new();
             for (i, predicate) in plan.predicates.iter().enumerate() {
                 if let Some(predicate) = predicate {
-                    children.push(FormatTreeNode::new(format!(
-                        "branch {}: {}",
-                        i,
-                        predicate.as_expr(&BUILTIN_FUNCTIONS).sql_display()
-                    )));
+                    children.push(FormatTreeNode::property(
+                        format!("branch {}", i),
+                        predicate.as_expr(&BUILTIN_FUNCTIONS).sql_display(),
+                    ));
                 } else {
-                    children.push(FormatTreeNode::new(format!("branch {}: None", i)));
+                    children.push(FormatTreeNode::property(format!("branch {}", i), "None"));
                 }
             }
             append_profile_info(&mut children, profs, plan.plan_id);
@@ -313,17 +313,16 @@ fn to_format_tree(
             }
             for (i, eval_scalar) in plan.eval_scalars.iter().enumerate() {
                 if let Some(eval_scalar) = eval_scalar {
-                    children.push(FormatTreeNode::new(format!(
-                        "branch {}: {}",
-                        i,
+                    children.push(FormatTreeNode::property(
+                        format!("branch {}", i),
                         eval_scalar
                             .remote_exprs
                             .iter()
                             .map(|x| x.as_expr(&BUILTIN_FUNCTIONS).sql_display())
-                            .join(", ")
-                    )));
+                            .join(", "),
+                    ));
                 } else {
-                    children.push(FormatTreeNode::new(format!("branch {}: None", i)));
+                    children.push(FormatTreeNode::property(format!("branch {}", i), "None"));
                 }
             }
             append_profile_info(&mut children, profs, plan.plan_id);
@@ -367,13 +366,25 @@ fn append_profile_info(
     if let Some(prof) = profs.get(&plan_id) {
         for (_, desc) in get_statistics_desc().iter() {
             if prof.statistics[desc.index] != 0 {
-                children.push(FormatTreeNode::new(format!(
-                    "{}: {}",
+                children.push(FormatTreeNode::property(
                     desc.display_name.to_lowercase(),
-                    desc.human_format(prof.statistics[desc.index])
-                )));
+                    desc.human_format(prof.statistics[desc.index]),
+                ));
             }
         }
+
+        if !prof.decoded_column_bytes.is_empty() {
+            let decoded_column_bytes = prof
+                .decoded_column_bytes
+                .iter()
+                .map(|(column, bytes)| format!("{}: {}", column, convert_byte_size(*bytes as f64)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            children.push(FormatTreeNode::property(
+                "bytes decoded by column",
+                decoded_column_bytes,
+            ));
+        }
     }
 }
 
@@ -402,12 +413,15 @@ fn format_merge_into(
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
     let table_entry = metadata.table(merge_into.target_table_index).clone();
-    let target_table = vec![FormatTreeNode::new(format!(
-        "target table: [catalog: {}] [database: {}] [table: {}]",
-        table_entry.catalog(),
-        table_entry.database(),
-        table_entry.name()
-    ))];
+    let target_table = vec![FormatTreeNode::property(
+        "target table",
+        format!(
+            "[catalog: {}] [database: {}] [table: {}]",
+            table_entry.catalog(),
+            table_entry.database(),
+            table_entry.name()
+        ),
+    )];
     let target_schema = table_entry.table().schema_with_stream();
 
     let merge_into_organize: &PhysicalPlan = &merge_into.input;
@@ -605,12 +619,24 @@ fn table_scan_to_format_tree(
         .as_ref()
         .and_then(|extras| extras.agg_index.as_ref());
 
+    let materialized_view = plan.table_index.and_then(|table_index| {
+        metadata
+            .table(table_index)
+            .table()
+            .options()
+            .get(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+            .cloned()
+    });
+
     let mut children = vec![
-        FormatTreeNode::new(format!("table: {table_name}")),
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, false)
-        )),
+        FormatTreeNode::property("table", table_name),
+        FormatTreeNode::property(
+            "output columns",
+            format!(
+                "[{}]",
+                format_output_columns(plan.output_schema()?, metadata, false)
+            ),
+        ),
     ];
 
     // Part stats.
@@ -618,15 +644,13 @@ fn table_scan_to_format_tree(
     // Push downs.
     let push_downs = match virtual_columns {
         Some(virtual_columns) => {
-            format!(
-                "push downs: [filters: [{filters}], limit: {limit}, virtual_columns: [{virtual_columns}]]"
-            )
+            format!("[filters: [{filters}], limit: {limit}, virtual_columns: [{virtual_columns}]]")
         }
         None => {
-            format!("push downs: [filters: [{filters}], limit: {limit}]")
+            format!("[filters: [{filters}], limit: {limit}]")
         }
     };
-    children.push(FormatTreeNode::new(push_downs));
+    children.push(FormatTreeNode::property("push downs", push_downs));
     // Aggregating index
     if let Some(agg_index) = agg_index {
         let (_, agg_index_sql, _) = metadata
@@ -636,9 +660,10 @@ fn table_scan_to_format_tree(
             .find(|(index, _, _)| *index == agg_index.index_id)
             .unwrap();
 
-        children.push(FormatTreeNode::new(format!(
-            "aggregating index: [{agg_index_sql}]"
-        )));
+        children.push(FormatTreeNode::property(
+            "aggregating index",
+            format!("[{agg_index_sql}]"),
+        ));
 
         let agg_sel = agg_index
             .selection
@@ -650,11 +675,18 @@ fn table_scan_to_format_tree(
             .as_ref()
             .map(|f| f.as_expr(&BUILTIN_FUNCTIONS).sql_display());
         let text = if let Some(f) = agg_filter {
-            format!("rewritten query: [selection: [{agg_sel}], filter: {f}]")
+            format!("[selection: [{agg_sel}], filter: {f}]")
         } else {
-            format!("rewritten query: [selection: [{agg_sel}]]")
+            format!("[selection: [{agg_sel}]]")
         };
-        children.push(FormatTreeNode::new(text));
+        children.push(FormatTreeNode::property("rewritten query", text));
+    }
+    // Materialized view
+    if let Some(query) = materialized_view {
+        children.push(FormatTreeNode::property(
+            "materialized view",
+            format!("[{query}]"),
+        ));
     }
 
     if let Some(info) = &plan.stat_info {
@@ -670,14 +702,19 @@ fn table_scan_to_format_tree(
     ))
 }
 
-fn cte_scan_to_format_tree(plan: &CteScan) -> Result<FormatTreeNode<String>> {
-    let mut children = vec![FormatTreeNode::new(format!(
-        "CTE index: {}, sub index: {}",
-        plan.cte_idx.0, plan.cte_idx.1
-    ))];
+fn cte_scan_to_format_tree(
+    plan: &CteScan,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::property("CTE index", plan.cte_idx.0),
+        FormatTreeNode::property("sub index", plan.cte_idx.1),
+    ];
     let items = plan_stats_info_to_format_tree(&plan.stat);
     children.extend(items);
 
+    append_profile_info(&mut children, profs, plan.plan_id);
+
     Ok(FormatTreeNode::with_children(
         "CTEScan".to_string(),
         children,
@@ -687,16 +724,26 @@ fn cte_scan_to_format_tree(plan: &CteScan) -> Result<FormatTreeNode<String>> {
 fn constant_table_scan_to_format_tree(
     plan: &ConstantTableScan,
     metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = Vec::with_capacity(plan.values.len() + 1);
-    children.push(FormatTreeNode::new(format!(
-        "output columns: [{}]",
-        format_output_columns(plan.output_schema()?, metadata, true)
-    )));
+    children.push(FormatTreeNode::property(
+        "output columns",
+        format!(
+            "[{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        ),
+    ));
     for (i, value) in plan.values.iter().enumerate() {
         let column = value.iter().map(|val| format!("{val}")).join(", ");
-        children.push(FormatTreeNode::new(format!("column {}: [{}]", i, column)));
+        children.push(FormatTreeNode::property(
+            format!("column {}", i),
+            format!("[{}]", column),
+        ));
     }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
     Ok(FormatTreeNode::with_children(
         "ConstantTableScan".to_string(),
         children,
@@ -709,18 +756,26 @@ fn expression_scan_to_format_tree(
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = Vec::with_capacity(plan.values.len() + 1);
-    children.push(FormatTreeNode::new(format!(
-        "output columns: [{}]",
-        format_output_columns(plan.output_schema()?, metadata, true)
-    )));
+    children.push(FormatTreeNode::property(
+        "output columns",
+        format!(
+            "[{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        ),
+    ));
     for (i, value) in plan.values.iter().enumerate() {
         let column = value
             .iter()
             .map(|val| val.as_expr(&BUILTIN_FUNCTIONS).sql_display())
             .join(", ");
-        children.push(FormatTreeNode::new(format!("column {}: [{}]", i, column)));
+        children.push(FormatTreeNode::property(
+            format!("column {}", i),
+            format!("[{}]", column),
+        ));
     }
 
+    append_profile_info(&mut children, profs, plan.plan_id);
+
     children.push(to_format_tree(&plan.input, metadata, profs)?);
 
     Ok(FormatTreeNode::with_children(
@@ -732,25 +787,31 @@ fn expression_scan_to_format_tree(
 fn cache_scan_to_format_tree(
     plan: &CacheScan,
     metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = Vec::with_capacity(2);
-    children.push(FormatTreeNode::new(format!(
-        "output columns: [{}]",
-        format_output_columns(plan.output_schema()?, metadata, true)
-    )));
+    children.push(FormatTreeNode::property(
+        "output columns",
+        format!(
+            "[{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        ),
+    ));
 
     match &plan.cache_source {
         CacheSource::HashJoinBuild((cache_index, column_indexes)) => {
             let mut column_indexes = column_indexes.clone();
             column_indexes.sort();
-            children.push(FormatTreeNode::new(format!("cache index: {}", cache_index)));
-            children.push(FormatTreeNode::new(format!(
-                "column indexes: {:?}",
-                column_indexes
-            )));
+            children.push(FormatTreeNode::property("cache index", cache_index));
+            children.push(FormatTreeNode::property(
+                "column indexes",
+                format!("{:?}", column_indexes),
+            ));
         }
     }
 
+    append_profile_info(&mut children, profs, plan.plan_id);
+
     Ok(FormatTreeNode::with_children(
         "CacheScan".to_string(),
         children,
@@ -768,11 +829,14 @@ fn filter_to_format_tree(
         .map(|pred| pred.as_expr(&BUILTIN_FUNCTIONS).sql_display())
         .join(", ");
     let mut children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!("filters: [{filter}]")),
+        FormatTreeNode::property(
+            "output columns",
+            format!(
+                "[{}]",
+                format_output_columns(plan.output_schema()?, metadata, true)
+            ),
+        ),
+        FormatTreeNode::property("filters", format!("[{filter}]")),
     ];
 
     if let Some(info) = &plan.stat_info {
@@ -805,11 +869,14 @@ fn eval_scalar_to_format_tree(
         .collect::<Vec<_>>()
         .join(", ");
     let mut children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!("expressions: [{scalars}]")),
+        FormatTreeNode::property(
+            "output columns",
+            format!(
+                "[{}]",
+                format_output_columns(plan.output_schema()?, metadata, true)
+            ),
+        ),
+        FormatTreeNode::property("expressions", format!("[{scalars}]")),
     ];
 
     if let Some(info) = &plan.stat_info {
@@ -832,10 +899,13 @@ fn async_function_to_format_tree(
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    let mut children = vec![FormatTreeNode::new(format!(
-        "output columns: [{}]",
-        format_output_columns(plan.output_schema()?, metadata, true)
-    ))];
+    let mut children = vec![FormatTreeNode::property(
+        "output columns",
+        format!(
+            "[{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        ),
+    )];
 
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
@@ -884,11 +954,14 @@ fn aggregate_expand_to_format_tree(
         .join(", ");
 
     let mut children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!("grouping sets: [{sets}]")),
+        FormatTreeNode::property(
+            "output columns",
+            format!(
+                "[{}]",
+                format_output_columns(plan.output_schema()?, metadata, true)
+            ),
+        ),
+        FormatTreeNode::property("grouping sets", format!("[{sets}]")),
     ];
 
     if let Some(info) = &plan.stat_info {
@@ -924,8 +997,8 @@ fn aggregate_partial_to_format_tree(
         .join(", ");
 
     let mut children = vec![
-        FormatTreeNode::new(format!("group by: [{group_by}]")),
-        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+        FormatTreeNode::property("group by", format!("[{group_by}]")),
+        FormatTreeNode::property("aggregate functions", format!("[{agg_funcs}]")),
     ];
 
     if let Some(info) = &plan.stat_info {
@@ -966,19 +1039,26 @@ fn aggregate_final_to_format_tree(
         .join(", ");
 
     let mut children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!("group by: [{group_by}]")),
-        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+        FormatTreeNode::property(
+            "output columns",
+            format!(
+                "[{}]",
+                format_output_columns(plan.output_schema()?, metadata, true)
+            ),
+        ),
+        FormatTreeNode::property("group by", format!("[{group_by}]")),
+        FormatTreeNode::property("aggregate functions", format!("[{agg_funcs}]")),
     ];
 
     if let Some(limit) = &plan.limit {
-        let items = FormatTreeNode::new(format!("limit: {limit}"));
+        let items = FormatTreeNode::property("limit", limit);
         children.push(items);
     }
 
+    if plan.sort_based {
+        children.push(FormatTreeNode::property("sort based", true));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -1024,18 +1104,21 @@ fn window_to_format_tree(
     };
 
     let mut children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!("aggregate function: [{func}]")),
-        FormatTreeNode::new(format!("partition by: [{partition_by}]")),
-        FormatTreeNode::new(format!("order by: [{order_by}]")),
-        FormatTreeNode::new(format!("frame: [{frame}]")),
+        FormatTreeNode::property(
+            "output columns",
+            format!(
+                "[{}]",
+                format_output_columns(plan.output_schema()?, metadata, true)
+            ),
+        ),
+        FormatTreeNode::property("aggregate function", format!("[{func}]")),
+        FormatTreeNode::property("partition by", format!("[{partition_by}]")),
+        FormatTreeNode::property("order by", format!("[{order_by}]")),
+        FormatTreeNode::property("frame", format!("[{frame}]")),
     ];
 
     if let Some(limit) = plan.limit {
-        children.push(FormatTreeNode::new(format!("limit: [{limit}]")))
+        children.push(FormatTreeNode::property("limit", format!("[{limit}]")))
     }
 
     append_profile_info(&mut children, profs, plan.plan_id);
@@ -1072,11 +1155,14 @@ fn sort_to_format_tree(
         .join(", ");
 
     let mut children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!("sort keys: [{sort_keys}]")),
+        FormatTreeNode::property(
+            "output columns",
+            format!(
+                "[{}]",
+                format_output_columns(plan.output_schema()?, metadata, true)
+            ),
+        ),
+        FormatTreeNode::property("sort keys", format!("[{sort_keys}]")),
     ];
 
     if let Some(info) = &plan.stat_info {
@@ -1107,11 +1193,14 @@ fn window_partition_to_format_tree(
         .join(", ");
 
     let mut children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!("hash keys: [{partition_by}]")),
+        FormatTreeNode::property(
+            "output columns",
+            format!(
+                "[{}]",
+                format_output_columns(plan.output_schema()?, metadata, true)
+            ),
+        ),
+        FormatTreeNode::property("hash keys", format!("[{partition_by}]")),
     ];
 
     if let Some(info) = &plan.stat_info {
@@ -1135,16 +1224,19 @@ fn limit_to_format_tree(
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!(
-            "limit: {}",
+        FormatTreeNode::property(
+            "output columns",
+            format!(
+                "[{}]",
+                format_output_columns(plan.output_schema()?, metadata, true)
+            ),
+        ),
+        FormatTreeNode::property(
+            "limit",
             plan.limit
-                .map_or("NONE".to_string(), |limit| limit.to_string())
-        )),
-        FormatTreeNode::new(format!("offset: {}", plan.offset)),
+                .map_or("NONE".to_string(), |limit| limit.to_string()),
+        ),
+        FormatTreeNode::property("offset", plan.offset),
     ];
 
     if let Some(info) = &plan.stat_info {
@@ -1169,14 +1261,17 @@ fn row_fetch_to_format_tree(
     let fields_to_fetch = projected_schema.fields();
 
     let mut children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!(
-            "columns to fetch: [{}]",
-            fields_to_fetch.iter().map(|f| f.name()).join(", ")
-        )),
+        FormatTreeNode::property(
+            "output columns",
+            format!(
+                "[{}]",
+                format_output_columns(plan.output_schema()?, metadata, true)
+            ),
+        ),
+        FormatTreeNode::property(
+            "columns to fetch",
+            format!("[{}]", fields_to_fetch.iter().map(|f| f.name()).join(", ")),
+        ),
     ];
 
     if let Some(info) = &plan.stat_info {
@@ -1229,13 +1324,19 @@ fn range_join_to_format_tree(
     right_child.payload = format!("{}(Right)", right_child.payload);
 
     let mut children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!("join type: {}", plan.join_type)),
-        FormatTreeNode::new(format!("range join conditions: [{range_join_conditions}]")),
-        FormatTreeNode::new(format!("other conditions: [{other_conditions}]")),
+        FormatTreeNode::property(
+            "output columns",
+            format!(
+                "[{}]",
+                format_output_columns(plan.output_schema()?, metadata, true)
+            ),
+        ),
+        FormatTreeNode::property("join type", plan.join_type),
+        FormatTreeNode::property(
+            "range join conditions",
+            format!("[{range_join_conditions}]"),
+        ),
+        FormatTreeNode::property("other conditions", format!("[{other_conditions}]")),
     ];
 
     if let Some(info) = &plan.stat_info {
@@ -1288,24 +1389,27 @@ fn hash_join_to_format_tree(
     probe_child.payload = format!("{}(Probe)", probe_child.payload);
 
     let mut children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!("join type: {}", plan.join_type)),
-        FormatTreeNode::new(format!("build keys: [{build_keys}]")),
-        FormatTreeNode::new(format!("probe keys: [{probe_keys}]")),
-        FormatTreeNode::new(format!("filters: [{filters}]")),
+        FormatTreeNode::property(
+            "output columns",
+            format!(
+                "[{}]",
+                format_output_columns(plan.output_schema()?, metadata, true)
+            ),
+        ),
+        FormatTreeNode::property("join type", plan.join_type),
+        FormatTreeNode::property("build keys", format!("[{build_keys}]")),
+        FormatTreeNode::property("probe keys", format!("[{probe_keys}]")),
+        FormatTreeNode::property("filters", format!("[{filters}]")),
     ];
 
     if let Some((cache_index, column_map)) = &plan.build_side_cache_info {
         let mut column_indexes = column_map.keys().collect::<Vec<_>>();
         column_indexes.sort();
-        children.push(FormatTreeNode::new(format!("cache index: {}", cache_index)));
-        children.push(FormatTreeNode::new(format!(
-            "cache columns: {:?}",
-            column_indexes
-        )));
+        children.push(FormatTreeNode::property("cache index", cache_index));
+        children.push(FormatTreeNode::property(
+            "cache columns",
+            format!("{:?}", column_indexes),
+        ));
     }
 
     if let Some(info) = &plan.stat_info {
@@ -1330,11 +1434,14 @@ fn exchange_to_format_tree(
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
     Ok(FormatTreeNode::with_children("Exchange".to_string(), vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!("exchange type: {}", match plan.kind {
+        FormatTreeNode::property(
+            "output columns",
+            format!(
+                "[{}]",
+                format_output_columns(plan.output_schema()?, metadata, true)
+            ),
+        ),
+        FormatTreeNode::property("exchange type", match plan.kind {
             FragmentKind::Init => "Init-Partition".to_string(),
             FragmentKind::Normal => format!(
                 "Hash({})",
@@ -1346,7 +1453,7 @@ fn exchange_to_format_tree(
             ),
             FragmentKind::Expansive => "Broadcast".to_string(),
             FragmentKind::Merge => "Merge".to_string(),
-        })),
+        }),
         to_format_tree(&plan.input, metadata, profs)?,
     ]))
 }
@@ -1356,10 +1463,13 @@ fn union_all_to_format_tree(
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    let mut children = vec![FormatTreeNode::new(format!(
-        "output columns: [{}]",
-        format_output_columns(plan.output_schema()?, metadata, true)
-    ))];
+    let mut children = vec![FormatTreeNode::property(
+        "output columns",
+        format!(
+            "[{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        ),
+    )];
 
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
@@ -1385,10 +1495,10 @@ fn union_all_to_format_tree(
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let read_size = format_byte_size(info.read_bytes);
     let mut items = vec![
-        FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
-        FormatTreeNode::new(format!("read size: {}", read_size)),
-        FormatTreeNode::new(format!("partitions total: {}", info.partitions_total)),
-        FormatTreeNode::new(format!("partitions scanned: {}", info.partitions_scanned)),
+        FormatTreeNode::property("read rows", info.read_rows),
+        FormatTreeNode::property("read size", read_size),
+        FormatTreeNode::property("partitions total", info.partitions_total),
+        FormatTreeNode::property("partitions scanned", info.partitions_scanned),
     ];
 
     // format is like "pruning stats: [segments: <range pruning: x to y>, blocks: <range pruning: x to y>]"
@@ -1448,35 +1558,38 @@ fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<S
             pruning_description += &format!("blocks: <{}>", blocks_pruning_description);
         }
 
-        items.push(FormatTreeNode::new(format!(
-            "pruning stats: [{}]",
-            pruning_description
-        )));
+        items.push(FormatTreeNode::property(
+            "pruning stats",
+            format!("[{}]", pruning_description),
+        ));
     }
 
     items
 }
 
 fn plan_stats_info_to_format_tree(info: &PlanStatsInfo) -> Vec<FormatTreeNode<String>> {
-    vec![FormatTreeNode::new(format!(
-        "estimated rows: {0:.2}",
-        info.estimated_rows
-    ))]
+    vec![FormatTreeNode::property(
+        "estimated rows",
+        format!("{0:.2}", info.estimated_rows),
+    )]
 }
 
 fn exchange_source_to_format_tree(
     plan: &ExchangeSource,
     metadata: &Metadata,
 ) -> Result<FormatTreeNode<String>> {
-    let mut children = vec![FormatTreeNode::new(format!(
-        "output columns: [{}]",
-        format_output_columns(plan.output_schema()?, metadata, true)
-    ))];
+    let mut children = vec![FormatTreeNode::property(
+        "output columns",
+        format!(
+            "[{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        ),
+    )];
 
-    children.push(FormatTreeNode::new(format!(
-        "source fragment: [{}]",
-        plan.source_fragment_id
-    )));
+    children.push(FormatTreeNode::property(
+        "source fragment",
+        format!("[{}]", plan.source_fragment_id),
+    ));
 
     Ok(FormatTreeNode::with_children(
         "ExchangeSource".to_string(),
@@ -1489,15 +1602,18 @@ fn exchange_sink_to_format_tree(
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    let mut children = vec![FormatTreeNode::new(format!(
-        "output columns: [{}]",
-        format_output_columns(plan.output_schema()?, metadata, true)
-    ))];
+    let mut children = vec![FormatTreeNode::property(
+        "output columns",
+        format!(
+            "[{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        ),
+    )];
 
-    children.push(FormatTreeNode::new(format!(
-        "destination fragment: [{}]",
-        plan.destination_fragment_id
-    )));
+    children.push(FormatTreeNode::property(
+        "destination fragment",
+        format!("[{}]", plan.destination_fragment_id),
+    ));
 
     children.push(to_format_tree(&plan.input, metadata, profs)?);
 
@@ -1537,10 +1653,13 @@ fn project_set_to_format_tree(
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    let mut children = vec![FormatTreeNode::new(format!(
-        "output columns: [{}]",
-        format_output_columns(plan.output_schema()?, metadata, true)
-    ))];
+    let mut children = vec![FormatTreeNode::property(
+        "output columns",
+        format!(
+            "[{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        ),
+    )];
 
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
@@ -1549,14 +1668,14 @@ fn project_set_to_format_tree(
 
     append_profile_info(&mut children, profs, plan.plan_id);
 
-    children.extend(vec![FormatTreeNode::new(format!(
-        "set returning functions: {}",
+    children.extend(vec![FormatTreeNode::property(
+        "set returning functions",
         plan.srf_exprs
             .iter()
             .map(|(expr, _)| expr.clone().as_expr(&BUILTIN_FUNCTIONS).sql_display())
             .collect::<Vec<_>>()
-            .join(", ")
-    ))]);
+            .join(", "),
+    )]);
 
     children.extend(vec![to_format_tree(&plan.input, metadata, profs)?]);
 
@@ -1571,10 +1690,13 @@ fn udf_to_format_tree(
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    let mut children = vec![FormatTreeNode::new(format!(
-        "output columns: [{}]",
-        format_output_columns(plan.output_schema()?, metadata, true)
-    ))];
+    let mut children = vec![FormatTreeNode::property(
+        "output columns",
+        format!(
+            "[{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        ),
+    )];
 
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
@@ -1583,8 +1705,8 @@ fn udf_to_format_tree(
 
     append_profile_info(&mut children, profs, plan.plan_id);
 
-    children.extend(vec![FormatTreeNode::new(format!(
-        "udf functions: {}",
+    children.extend(vec![FormatTreeNode::property(
+        "udf functions",
         plan.udf_funcs
             .iter()
             .map(|func| {
@@ -1592,8 +1714,8 @@ fn udf_to_format_tree(
                 format!("{}({})", func.func_name, arg_exprs)
             })
             .collect::<Vec<_>>()
-            .join(", ")
-    ))]);
+            .join(", "),
+    )]);
 
     children.extend(vec![to_format_tree(&plan.input, metadata, profs)?]);
 
@@ -1605,14 +1727,18 @@ fn materialized_cte_to_format_tree(
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    let children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
+    let mut children = vec![FormatTreeNode::property(
+        "output columns",
+        format!(
+            "[{}]",
             format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        to_format_tree(&plan.left, metadata, profs)?,
-        to_format_tree(&plan.right, metadata, profs)?,
-    ];
+        ),
+    )];
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.left, metadata, profs)?);
+    children.push(to_format_tree(&plan.right, metadata, profs)?);
     Ok(FormatTreeNode::with_children(
         "MaterializedCTE".to_string(),
         children,
@@ -214,6 +214,7 @@ pub trait PhysicalPlanReplacer {
             group_by_display: plan.group_by_display.clone(),
             stat_info: plan.stat_info.clone(),
             limit: plan.limit,
+            sort_based: plan.sort_based,
         }))
     }
 
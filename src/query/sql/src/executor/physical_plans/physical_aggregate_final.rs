@@ -30,6 +30,7 @@ use crate::executor::physical_plans::AggregatePartial;
 use crate::executor::physical_plans::Exchange;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::plans::AggregateMode;
 use crate::plans::DummyTableScan;
@@ -46,6 +47,9 @@ pub struct AggregateFinal {
     pub agg_funcs: Vec<AggregateFunctionDesc>,
     pub before_group_by_schema: DataSchemaRef,
     pub limit: Option<usize>,
+    /// Aggregate the input sorted by the group keys instead of hashing it,
+    /// the output is then ordered by the group keys.
+    pub sort_based: bool,
 
     pub group_by_display: Vec<String>,
 
@@ -359,6 +363,7 @@ impl PhysicalPlanBuilder {
                     PhysicalPlan::AggregatePartial(ref partial) => {
                         let before_group_by_schema = partial.input.output_schema()?;
                         let limit = agg.limit;
+                        let sort_based = self.is_sort_based_aggregate(s_expr, &agg, &stat_info)?;
                         PhysicalPlan::AggregateFinal(AggregateFinal {
                             plan_id: 0,
                             group_by_display: partial.group_by_display.clone(),
@@ -369,6 +374,7 @@ impl PhysicalPlanBuilder {
 
                             stat_info: Some(stat_info),
                             limit,
+                            sort_based,
                         })
                     }
 
@@ -389,6 +395,7 @@ impl PhysicalPlanBuilder {
 
                             stat_info: Some(stat_info),
                             limit,
+                            sort_based: false,
                         })
                     }
 
@@ -407,4 +414,28 @@ impl PhysicalPlanBuilder {
 
         Ok(result)
     }
+
+    /// Hashing barely pays off when the aggregation keeps most of the rows, sorting the
+    /// input by the group keys then costs about the same and yields the groups in order,
+    /// which makes an ORDER BY on the group keys free.
+    fn is_sort_based_aggregate(
+        &self,
+        s_expr: &SExpr,
+        agg: &crate::plans::Aggregate,
+        stat_info: &PlanStatsInfo,
+    ) -> Result<bool> {
+        if !self.ctx.get_settings().get_sorted_group_by_output()?
+            || agg.group_items.is_empty()
+            || agg.grouping_sets.is_some()
+            || agg.limit.is_some()
+        {
+            return Ok(false);
+        }
+
+        // The child of the final aggregate is the partial one.
+        let input_rows = RelExpr::with_s_expr(s_expr.child(0)?.child(0)?)
+            .derive_cardinality()?
+            .cardinality;
+        Ok(input_rows > 0.0 && stat_info.estimated_rows * 2.0 >= input_rows)
+    }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
@@ -23,15 +24,24 @@ use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::RemoteExpr;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 
+use crate::binder::ColumnBindingBuilder;
+use crate::binder::Visibility;
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::physical_plan::PhysicalPlan;
 use crate::executor::physical_plan_builder::PhysicalPlanBuilder;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::SExpr;
+use crate::plans::walk_expr;
+use crate::plans::walk_expr_mut;
+use crate::plans::BoundColumnRef;
+use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::ProjectSet;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::Visitor;
+use crate::plans::VisitorMut;
 use crate::IndexType;
 use crate::TypeCheck;
 
@@ -83,22 +93,28 @@ impl PhysicalPlanBuilder {
     ) -> Result<PhysicalPlan> {
         // 1. Prune unused Columns.
         let column_projections = required.clone();
-        let mut used = vec![];
         // Only keep columns needed by parent plan.
-        for s in eval_scalar.items.iter() {
-            if !required.contains(&s.index) {
-                continue;
-            }
-            used.push(s.clone());
+        let mut used = eval_scalar
+            .items
+            .iter()
+            .filter(|s| required.contains(&s.index))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut child = s_expr.child(0)?.clone();
+        if let RelOperator::Filter(filter) = child.plan() {
+            // Evaluate the expressions shared with the filter only once.
+            (used, child) = self.extract_common_subexprs(used, filter, &child)?;
+        }
+        for s in used.iter() {
             s.scalar.used_columns().iter().for_each(|c| {
                 required.insert(*c);
             })
         }
         // 2. Build physical plan.
         if used.is_empty() {
-            self.build(s_expr.child(0)?, required).await
+            self.build(&child, required).await
         } else {
-            let child = s_expr.child(0)?;
+            let child = &child;
             let input = if let RelOperator::ProjectSet(project_set) = child.plan() {
                 let new_project_set =
                     self.prune_flatten_columns(eval_scalar, project_set, &required);
@@ -171,6 +187,87 @@ impl PhysicalPlanBuilder {
         }))
     }
 
+    // Extract the sub-expressions that are both in the projection and the filter below it
+    // into an `EvalScalar` under the filter, so that they are evaluated only once.
+    // Only the first predicate is considered, because the others are evaluated lazily
+    // on the rows selected by the previous ones.
+    fn extract_common_subexprs(
+        &mut self,
+        items: Vec<ScalarItem>,
+        filter: &Filter,
+        filter_expr: &SExpr,
+    ) -> Result<(Vec<ScalarItem>, SExpr)> {
+        let mut candidates = HashSet::new();
+        if let Some(predicate) = filter.predicates.first() {
+            collect_common_subexpr_candidates(predicate, &mut candidates);
+        }
+        if candidates.is_empty() {
+            return Ok((items, filter_expr.clone()));
+        }
+
+        let mut finder = CommonSubexprFinder {
+            candidates: &candidates,
+            found: vec![],
+        };
+        for item in items.iter() {
+            finder.visit(&item.scalar)?;
+        }
+        if finder.found.is_empty() {
+            return Ok((items, filter_expr.clone()));
+        }
+
+        let mut shared_items = Vec::with_capacity(finder.found.len());
+        let mut replacements = HashMap::with_capacity(finder.found.len());
+        for scalar in finder.found {
+            let data_type = scalar.data_type()?;
+            let index = self.metadata.write().add_derived_column(
+                "common_subexpr".to_string(),
+                data_type.clone(),
+                Some(scalar.clone()),
+            );
+            let column = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: ColumnBindingBuilder::new(
+                    "common_subexpr".to_string(),
+                    index,
+                    Box::new(data_type),
+                    Visibility::Visible,
+                )
+                .build(),
+            });
+            shared_items.push(ScalarItem {
+                scalar: scalar.clone(),
+                index,
+            });
+            replacements.insert(scalar, column);
+        }
+
+        let mut replacer = CommonSubexprReplacer {
+            replacements: &replacements,
+        };
+        let mut items = items;
+        for item in items.iter_mut() {
+            replacer.visit(&mut item.scalar)?;
+        }
+        let mut filter = filter.clone();
+        for predicate in filter.predicates.iter_mut() {
+            replacer.visit(predicate)?;
+        }
+
+        let shared_eval_scalar = SExpr::create_unary(
+            Arc::new(
+                crate::plans::EvalScalar {
+                    items: shared_items,
+                }
+                .into(),
+            ),
+            Arc::new(filter_expr.child(0)?.clone()),
+        );
+        let filter_expr =
+            SExpr::create_unary(Arc::new(filter.into()), Arc::new(shared_eval_scalar));
+        Ok((items, filter_expr))
+    }
+
     // The flatten function returns a tuple, which contains 6 columns.
     // Only keep columns required by parent plan, other columns can be pruned
     // to reduce the memory usage.
@@ -213,3 +310,68 @@ impl PhysicalPlanBuilder {
         project_set
     }
 }
+
+// The arguments of these functions may be evaluated lazily, so they can't be evaluated ahead.
+const LAZY_FUNCTIONS: [&str; 6] = ["if", "multi_if", "and", "or", "and_filters", "or_filters"];
+
+fn collect_common_subexpr_candidates(scalar: &ScalarExpr, candidates: &mut HashSet<ScalarExpr>) {
+    let arguments = match scalar {
+        ScalarExpr::FunctionCall(func) if LAZY_FUNCTIONS.contains(&func.func_name.as_str()) => {
+            vec![]
+        }
+        ScalarExpr::FunctionCall(func) => func.arguments.iter().collect(),
+        ScalarExpr::CastExpr(cast) => vec![cast.argument.as_ref()],
+        _ => return,
+    };
+    if is_deterministic(scalar) && !scalar.used_columns().is_empty() {
+        candidates.insert(scalar.clone());
+    }
+    for argument in arguments {
+        collect_common_subexpr_candidates(argument, candidates);
+    }
+}
+
+fn is_deterministic(scalar: &ScalarExpr) -> bool {
+    match scalar {
+        ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_) => true,
+        ScalarExpr::FunctionCall(func) => {
+            !BUILTIN_FUNCTIONS
+                .get_property(&func.func_name)
+                .is_some_and(|p| p.non_deterministic)
+                && func.arguments.iter().all(is_deterministic)
+        }
+        ScalarExpr::CastExpr(cast) => is_deterministic(&cast.argument),
+        _ => false,
+    }
+}
+
+struct CommonSubexprFinder<'a> {
+    candidates: &'a HashSet<ScalarExpr>,
+    found: Vec<ScalarExpr>,
+}
+
+impl<'a> Visitor<'a> for CommonSubexprFinder<'_> {
+    fn visit(&mut self, expr: &'a ScalarExpr) -> Result<()> {
+        if self.candidates.contains(expr) {
+            if !self.found.contains(expr) {
+                self.found.push(expr.clone());
+            }
+            return Ok(());
+        }
+        walk_expr(self, expr)
+    }
+}
+
+struct CommonSubexprReplacer<'a> {
+    replacements: &'a HashMap<ScalarExpr, ScalarExpr>,
+}
+
+impl VisitorMut<'_> for CommonSubexprReplacer<'_> {
+    fn visit(&mut self, expr: &mut ScalarExpr) -> Result<()> {
+        if let Some(column) = self.replacements.get(expr) {
+            *expr = column.clone();
+            return Ok(());
+        }
+        walk_expr_mut(self, expr)
+    }
+}
@@ -151,6 +151,11 @@ impl PhysicalPlanBuilder {
             }));
         }
 
+        // The sort based aggregation already yields the groups ordered by the group keys.
+        if sort.after_exchange.is_none() && self.is_sorted_by_aggregate(&input_plan, &order_by)? {
+            return Ok(input_plan);
+        }
+
         // 2. Build physical plan.
         Ok(PhysicalPlan::Sort(Sort {
             plan_id: 0,
@@ -162,4 +167,27 @@ impl PhysicalPlanBuilder {
             stat_info: Some(stat_info),
         }))
     }
+
+    fn is_sorted_by_aggregate(&self, plan: &PhysicalPlan, order_by: &[SortDesc]) -> Result<bool> {
+        let mut plan = plan;
+        let aggregate = loop {
+            match plan {
+                PhysicalPlan::Filter(filter) => plan = &filter.input,
+                PhysicalPlan::EvalScalar(eval_scalar) => plan = &eval_scalar.input,
+                PhysicalPlan::AggregateFinal(aggregate) if aggregate.sort_based => {
+                    break aggregate;
+                }
+                _ => return Ok(false),
+            }
+        };
+
+        let nulls_first = !self.ctx.get_settings().get_sql_dialect()?.is_null_biggest();
+        Ok(order_by.len() <= aggregate.group_by.len()
+            && order_by
+                .iter()
+                .zip(aggregate.group_by.iter())
+                .all(|(desc, index)| {
+                    desc.order_by == *index && desc.asc && desc.nulls_first == nulls_first
+                }))
+    }
 }
@@ -14,7 +14,9 @@
 
 use std::collections::btree_map;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::hash::Hash;
+use std::sync::Arc;
 
 use dashmap::DashMap;
 use databend_common_ast::ast::Identifier;
@@ -133,6 +135,11 @@ pub struct BindContext {
     /// It's used to check if the view has a loop dependency.
     pub view_info: Option<(String, String)>,
 
+    /// If current binding table is a parameterized view, the constant arguments of its
+    /// parameters by their normalized names, bound in the context of the caller. The columns
+    /// of the view query take precedence over them.
+    pub view_arguments: Option<Arc<HashMap<String, ScalarExpr>>>,
+
     /// Set-returning functions in current context.
     /// The key is the `Expr::to_string` of the function.
     pub srfs: DashMap<String, ScalarExpr>,
@@ -173,6 +180,7 @@ impl BindContext {
             cte_map_ref: Box::default(),
             in_grouping: false,
             view_info: None,
+            view_arguments: None,
             srfs: DashMap::new(),
             inverted_index_map: Box::default(),
             expr_context: ExprContext::default(),
@@ -192,6 +200,7 @@ impl BindContext {
             cte_map_ref: parent.cte_map_ref.clone(),
             in_grouping: false,
             view_info: None,
+            view_arguments: None,
             srfs: DashMap::new(),
             inverted_index_map: Box::default(),
             expr_context: ExprContext::default(),
@@ -370,6 +379,17 @@ impl BindContext {
                 return;
             }
 
+            // the parameters of a view hide the columns outside of the view.
+            if database.is_none()
+                && table.is_none()
+                && bind_context
+                    .view_arguments
+                    .as_ref()
+                    .is_some_and(|arguments| arguments.contains_key(column))
+            {
+                break;
+            }
+
             if let Some(ref parent) = bind_context.parent {
                 bind_context = parent;
             } else {
@@ -378,6 +398,21 @@ impl BindContext {
         }
     }
 
+    /// The argument of the parameter `name` of the parameterized view being bound, the lookup
+    /// stops at the boundary of the view.
+    pub fn view_argument(&self, name: &str) -> Option<&ScalarExpr> {
+        let mut bind_context: &BindContext = self;
+        loop {
+            if let Some(arguments) = &bind_context.view_arguments {
+                return arguments.get(name);
+            }
+            if bind_context.view_info.is_some() {
+                return None;
+            }
+            bind_context = bind_context.parent.as_ref()?;
+        }
+    }
+
     pub fn match_column_binding(
         database: Option<&str>,
         table: Option<&str>,
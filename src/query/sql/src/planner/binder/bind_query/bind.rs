@@ -15,13 +15,19 @@
 use std::sync::Arc;
 
 use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::With;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use derive_visitor::Drive;
+use derive_visitor::Visitor;
+use parking_lot::RwLock;
 
 use crate::binder::CteInfo;
+use crate::optimizer::estimate_cost;
 use crate::optimizer::SExpr;
 use crate::planner::binder::scalar::ScalarBinder;
 use crate::planner::binder::BindContext;
@@ -30,6 +36,7 @@ use crate::plans::BoundColumnRef;
 use crate::plans::ScalarExpr;
 use crate::plans::Sort;
 use crate::plans::SortItem;
+use crate::Metadata;
 
 impl Binder {
     pub(crate) fn bind_query(
@@ -39,6 +46,7 @@ impl Binder {
     ) -> Result<(SExpr, BindContext)> {
         // Initialize cte map.
         self.init_cte(bind_context, &query.with)?;
+        self.auto_materialize_ctes(bind_context, query)?;
 
         // Extract limit and offset from query.
         let (limit, offset) = self.extract_limit_and_offset(query)?;
@@ -97,6 +105,96 @@ impl Binder {
         Ok(())
     }
 
+    /// Materialize the CTEs referenced at least `materialize_cte_min_references` times if computing
+    /// them once and sharing the result is estimated to be cheaper than computing them for each
+    /// reference. The other CTEs are still inlined, as each reference can push down its own filters.
+    fn auto_materialize_ctes(
+        &mut self,
+        bind_context: &mut BindContext,
+        query: &Query,
+    ) -> Result<()> {
+        let Some(with) = &query.with else {
+            return Ok(());
+        };
+        let min_references = self
+            .ctx
+            .get_settings()
+            .get_materialize_cte_min_references()?;
+        if with.recursive || min_references == 0 {
+            return Ok(());
+        }
+
+        let mut collector = TableNameCollector::default();
+        query.drive(&mut collector);
+        for cte in with.ctes.iter().filter(|cte| !cte.materialized) {
+            let cte_name = self.normalize_identifier(&cte.alias.name).name;
+            let references = collector
+                .tables
+                .iter()
+                .filter(|table| self.normalize_identifier(table).name == cte_name)
+                .count();
+            if (references as u64) < min_references {
+                continue;
+            }
+            if !self.is_cheaper_to_materialize(bind_context, &cte_name, &cte.query, references)? {
+                continue;
+            }
+            if let Some(cte_info) = self.ctes_map.get_mut(&cte_name) {
+                cte_info.materialized = true;
+            }
+            if let Some(cte_info) = bind_context.cte_map_ref.get_mut(&cte_name) {
+                cte_info.materialized = true;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compare the estimated costs of computing a CTE for each of its `references`, and of
+    /// computing it once, writing its rows to the materialized buffer and reading them back for
+    /// each reference. The CTE is kept inlined if its cost can't be estimated.
+    fn is_cheaper_to_materialize(
+        &self,
+        bind_context: &BindContext,
+        cte_name: &str,
+        query: &Query,
+        references: usize,
+    ) -> Result<bool> {
+        // Bind the CTE apart, so the metadata of the statement is not changed. The CTEs it reads
+        // are estimated as inlined.
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        let mut binder = Binder::new(
+            self.ctx.clone(),
+            self.catalogs.clone(),
+            self.name_resolution_ctx.clone(),
+            metadata.clone(),
+        );
+        binder.ctes_map = self.ctes_map.clone();
+        let mut parent = bind_context.clone();
+        for cte_info in binder
+            .ctes_map
+            .values_mut()
+            .chain(parent.cte_map_ref.values_mut())
+        {
+            cte_info.materialized = false;
+        }
+        let mut cte_bind_context = BindContext::with_parent(Box::new(parent));
+        cte_bind_context.cte_name = Some(cte_name.to_string());
+        let (s_expr, _) = binder.bind_query(&mut cte_bind_context, query)?;
+
+        let ctx = self.ctx.clone();
+        let estimate = databend_common_base::runtime::block_on(async move {
+            estimate_cost(ctx, metadata, &s_expr).await
+        })?;
+        let Some((cost, cardinality)) = estimate else {
+            return Ok(false);
+        };
+        let references = references as f64;
+        let inlined = cost.0 * references;
+        let materialized = cost.0 + cardinality * (references + 1.0);
+        Ok(materialized < inlined)
+    }
+
     pub(crate) fn bind_query_order_by(
         &mut self,
         bind_context: &mut BindContext,
@@ -161,3 +259,24 @@ impl Binder {
         ))
     }
 }
+
+/// Collects the unqualified table names referenced in a query.
+#[derive(Default, Visitor)]
+#[visitor(TableReference(enter))]
+struct TableNameCollector {
+    tables: Vec<Identifier>,
+}
+
+impl TableNameCollector {
+    fn enter_table_reference(&mut self, table_ref: &TableReference) {
+        if let TableReference::Table {
+            catalog: None,
+            database: None,
+            table,
+            ..
+        } = table_ref
+        {
+            self.tables.push(table.clone());
+        }
+    }
+}
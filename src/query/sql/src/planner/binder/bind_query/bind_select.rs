@@ -230,6 +230,13 @@ impl Binder {
                 &mut scalar_items,
                 s_expr,
             )?;
+        } else if !stmt.distinct
+            && from_context.windows.window_functions.is_empty()
+            && !from_context.aggregate_info.group_items.is_empty()
+            && (self.ctx.get_settings().get_sorted_group_by_output()?
+                || self.ctx.get_settings().get_deterministic()?)
+        {
+            s_expr = self.bind_group_keys_order(&from_context, s_expr)?;
         }
 
         s_expr = self.bind_projection(&mut from_context, &projections, &scalar_items, s_expr)?;
@@ -245,6 +245,7 @@ impl Binder {
             // If there are outer columns in right child, then the join is a correlated lateral join
             let mut decorrelator =
                 SubqueryRewriter::new(self.ctx.clone(), self.metadata.clone(), Some(self.clone()));
+            decorrelator.outer_plan = Some(left_child.clone());
             right_child = decorrelator.flatten_plan(
                 &right_child,
                 &right_prop.outer_columns,
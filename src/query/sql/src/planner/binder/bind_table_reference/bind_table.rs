@@ -12,23 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::TemporalClause;
 use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::parse_view_parameters;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::Span;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TimeNavigation;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_storages_view::view_table::is_secure_view;
+use databend_common_storages_view::view_table::PARAMETERS;
 use databend_common_storages_view::view_table::QUERY;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
 use databend_storages_common_table_meta::table::get_change_type;
 
 use crate::binder::util::TableIdentifier;
 use crate::binder::Binder;
 use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
 use crate::BindContext;
+use crate::ScalarBinder;
 
 impl Binder {
     /// Bind a base table.
@@ -190,51 +207,15 @@ impl Binder {
         }
 
         match table_meta.engine() {
-            "VIEW" => {
-                // TODO(leiysky): this check is error-prone,
-                // we should find a better way to do this.
-                Self::check_view_dep(bind_context, &database, &table_name)?;
-                let query = table_meta
-                    .options()
-                    .get(QUERY)
-                    .ok_or_else(|| ErrorCode::Internal("Invalid VIEW object"))?;
-                let tokens = tokenize_sql(query.as_str())?;
-                let (stmt, _) = parse_sql(&tokens, self.dialect)?;
-                // For view, we need use a new context to bind it.
-                let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
-                new_bind_context.view_info = Some((database.clone(), table_name));
-                if let Statement::Query(query) = &stmt {
-                    self.metadata.write().add_table(
-                        catalog,
-                        database.clone(),
-                        table_meta,
-                        table_name_alias,
-                        false,
-                        false,
-                        false,
-                        false,
-                    );
-                    let (s_expr, mut new_bind_context) =
-                        self.bind_query(&mut new_bind_context, query)?;
-                    if let Some(alias) = alias {
-                        // view maybe has alias, e.g. select v1.col1 from v as v1;
-                        new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
-                    } else {
-                        // e.g. select v0.c0 from v0;
-                        for column in new_bind_context.columns.iter_mut() {
-                            column.database_name = None;
-                            column.table_name = Some(self.normalize_identifier(table).name);
-                        }
-                    }
-                    new_bind_context.parent = Some(Box::new(bind_context.clone()));
-                    Ok((s_expr, new_bind_context))
-                } else {
-                    Err(
-                        ErrorCode::Internal(format!("Invalid VIEW object: {}", table_meta.name()))
-                            .set_span(*span),
-                    )
-                }
-            }
+            "VIEW" => self.bind_view(
+                bind_context,
+                span,
+                table_identifier,
+                table_meta,
+                alias,
+                &[],
+                &[],
+            ),
             _ => {
                 let table_index = self.metadata.write().add_table(
                     catalog,
@@ -257,6 +238,248 @@ impl Binder {
         }
     }
 
+    /// Bind a view, `params` and `named_params` are the arguments of a parameterized view,
+    /// e.g. `SELECT * FROM v(3)`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn bind_view(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        table_identifier: TableIdentifier,
+        table_meta: Arc<dyn Table>,
+        alias: &Option<TableAlias>,
+        params: &[Expr],
+        named_params: &[(Identifier, Expr)],
+    ) -> Result<(SExpr, BindContext)> {
+        let (catalog, database, table_name, table_name_alias) = (
+            table_identifier.catalog_name(),
+            table_identifier.database_name(),
+            table_identifier.table_name(),
+            table_identifier.table_name_alias(),
+        );
+        // TODO(leiysky): this check is error-prone,
+        // we should find a better way to do this.
+        Self::check_view_dep(bind_context, &database, &table_name)?;
+        let query = table_meta
+            .options()
+            .get(QUERY)
+            .ok_or_else(|| ErrorCode::Internal("Invalid VIEW object"))?;
+        let tokens = tokenize_sql(query.as_str())?;
+        let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+        // For view, we need use a new context to bind it.
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        new_bind_context.view_info = Some((database.clone(), table_name.clone()));
+        new_bind_context.view_arguments =
+            self.bind_view_parameters(bind_context, span, &table_meta, params, named_params)?;
+        let secure = is_secure_view(table_meta.options());
+        let hidden = secure && !self.is_secure_view_owner(&catalog, &database, &table_meta)?;
+        if let Statement::Query(query) = stmt {
+            self.metadata.write().add_table(
+                catalog,
+                database.clone(),
+                table_meta,
+                table_name_alias,
+                false,
+                false,
+                false,
+                false,
+            );
+            let (mut s_expr, mut new_bind_context) =
+                self.bind_query(&mut new_bind_context, &query)?;
+            if secure {
+                s_expr = self.bind_secure_view_barrier(s_expr, &mut new_bind_context)?;
+            }
+            if hidden {
+                // The plan and the profiles of the query reveal the definition of the view,
+                // the plan is not cached as it is only checked on binding.
+                self.metadata
+                    .write()
+                    .add_hidden_secure_view(format!("`{database}`.`{table_name}`"));
+                self.ctx.set_hide_profiles(true);
+                self.ctx.set_cacheable(false);
+            }
+            if let Some(alias) = alias {
+                // view maybe has alias, e.g. select v1.col1 from v as v1;
+                new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+            } else {
+                // e.g. select v0.c0 from v0;
+                for column in new_bind_context.columns.iter_mut() {
+                    column.database_name = None;
+                    column.table_name = Some(table_name.clone());
+                }
+            }
+            new_bind_context.parent = Some(Box::new(bind_context.clone()));
+            Ok((s_expr, new_bind_context))
+        } else {
+            Err(
+                ErrorCode::Internal(format!("Invalid VIEW object: {}", table_meta.name()))
+                    .set_span(*span),
+            )
+        }
+    }
+
+    // Returns true if the current user owns the secure view, a view without owner belongs to
+    // `account_admin`, the same as `SHOW CREATE TABLE`.
+    fn is_secure_view_owner(
+        &self,
+        catalog: &str,
+        database: &str,
+        view: &Arc<dyn Table>,
+    ) -> Result<bool> {
+        databend_common_base::runtime::block_on(async move {
+            let tenant = self.ctx.get_tenant();
+            let db_id = self
+                .ctx
+                .get_catalog(catalog)
+                .await?
+                .get_database(&tenant, database)
+                .await?
+                .get_db_info()
+                .ident
+                .db_id;
+            let object = OwnershipObject::Table {
+                catalog_name: catalog.to_string(),
+                db_id,
+                table_id: view.get_id(),
+            };
+            let owner = RoleCacheManager::instance()
+                .find_object_owner(&tenant, &object)
+                .await?
+                .unwrap_or_else(|| BUILTIN_ROLE_ACCOUNT_ADMIN.to_string());
+            let roles = self.ctx.get_all_effective_roles().await?;
+            Ok(roles.iter().any(|role| role.name == owner))
+        })
+    }
+
+    // Project the output columns of a secure view into new columns, the `EvalScalar` acts as an
+    // optimization barrier so that the predicates of the outer query are not pushed into the view,
+    // which could leak the rows filtered out by the view through errors.
+    fn bind_secure_view_barrier(
+        &mut self,
+        s_expr: SExpr,
+        bind_context: &mut BindContext,
+    ) -> Result<SExpr> {
+        let mut items = Vec::with_capacity(bind_context.columns.len());
+        let mut indices = HashSet::with_capacity(bind_context.columns.len());
+        for column in bind_context.columns.iter_mut() {
+            let index = self.metadata.write().add_derived_column(
+                column.column_name.clone(),
+                *column.data_type.clone(),
+                None,
+            );
+            items.push(ScalarItem {
+                scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: column.clone(),
+                }),
+                index,
+            });
+            indices.insert(index);
+            column.index = index;
+        }
+        self.metadata.write().add_secure_view_columns(indices);
+        Ok(SExpr::create_unary(
+            Arc::new(EvalScalar { items }.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
+    // Match the arguments to the parameters of a parameterized view, an argument is cast to
+    // the type of its parameter.
+    fn bind_view_parameters(
+        &self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        table_meta: &Arc<dyn Table>,
+        params: &[Expr],
+        named_params: &[(Identifier, Expr)],
+    ) -> Result<Option<Arc<HashMap<String, ScalarExpr>>>> {
+        let Some(parameters) = table_meta.options().get(PARAMETERS) else {
+            if !params.is_empty() || !named_params.is_empty() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "View `{}` does not accept arguments",
+                    table_meta.name()
+                ))
+                .set_span(*span));
+            }
+            return Ok(None);
+        };
+
+        let tokens = tokenize_sql(parameters.as_str())?;
+        let parameters = parse_view_parameters(&tokens, self.dialect)?;
+        if params.len() > parameters.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "View `{}` accepts {} arguments, but got {}",
+                table_meta.name(),
+                parameters.len(),
+                params.len()
+            ))
+            .set_span(*span));
+        }
+        let parameter_names = parameters
+            .iter()
+            .map(|parameter| self.normalize_identifier(&parameter.name).name)
+            .collect::<Vec<_>>();
+        for (ident, _) in named_params {
+            let name = self.normalize_identifier(ident).name;
+            if !parameter_names.contains(&name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "View `{}` has no parameter named `{name}`",
+                    table_meta.name()
+                ))
+                .set_span(ident.span));
+            }
+        }
+
+        // The arguments are bound in the context of the caller, so a name in an argument
+        // can't be captured by the columns of the view query.
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let mut arguments = HashMap::with_capacity(parameters.len());
+        for (idx, (parameter, name)) in parameters.into_iter().zip(parameter_names).enumerate() {
+            let argument = params
+                .get(idx)
+                .or_else(|| {
+                    named_params
+                        .iter()
+                        .find(|(ident, _)| self.normalize_identifier(ident).name == name)
+                        .map(|(_, argument)| argument)
+                })
+                .cloned()
+                .or(parameter.default)
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "Missing argument for parameter `{name}` of view `{}`",
+                        table_meta.name()
+                    ))
+                    .set_span(*span)
+                })?;
+            let argument_span = argument.span();
+            let (argument, _) = scalar_binder.bind(&Expr::Cast {
+                span: argument_span,
+                expr: Box::new(argument),
+                target_type: parameter.data_type,
+                pg_style: false,
+            })?;
+            if !matches!(argument, ScalarExpr::ConstantExpr(_)) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Argument for parameter `{name}` of view `{}` must be a constant",
+                    table_meta.name()
+                ))
+                .set_span(argument_span));
+            }
+            arguments.insert(name, argument);
+        }
+        Ok(Some(Arc::new(arguments)))
+    }
+
     pub(crate) fn check_view_dep(
         bind_context: &BindContext,
         database: &str,
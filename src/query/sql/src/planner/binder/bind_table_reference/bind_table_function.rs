@@ -41,6 +41,7 @@ use databend_common_users::UserApiProvider;
 
 use crate::binder::scalar::ScalarBinder;
 use crate::binder::table_args::bind_table_args;
+use crate::binder::util::TableIdentifier;
 use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
@@ -115,6 +116,37 @@ impl Binder {
             );
         }
 
+        // A parameterized view in the current database, e.g. `SELECT * FROM v(3)`.
+        if !func_name.name.eq_ignore_ascii_case("result_scan")
+            && !self
+                .catalogs
+                .get_default_catalog(self.ctx.txn_mgr())?
+                .list_table_functions()
+                .contains(&func_name.name)
+        {
+            let table_identifier = TableIdentifier::new(self, &None, &None, name, alias);
+            if let Ok(table_meta) = self.resolve_data_source(
+                self.ctx.get_tenant().tenant_name(),
+                table_identifier.catalog_name().as_str(),
+                table_identifier.database_name().as_str(),
+                table_identifier.table_name().as_str(),
+                None,
+                self.ctx.clone().get_abort_checker(),
+            ) {
+                if table_meta.engine() == "VIEW" {
+                    return self.bind_view(
+                        bind_context,
+                        span,
+                        table_identifier,
+                        table_meta,
+                        alias,
+                        params,
+                        named_params,
+                    );
+                }
+            }
+        }
+
         let mut scalar_binder = ScalarBinder::new(
             bind_context,
             self.ctx.clone(),
@@ -17,16 +17,21 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
 
+use chrono::DateTime;
+use chrono::Utc;
 use chrono_tz::Tz;
 use databend_common_ast::ast::format_statement;
+use databend_common_ast::ast::Expr as AExpr;
 use databend_common_ast::ast::Hint;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TimeTravelPoint;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::query_kind::QueryKind;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -132,6 +137,8 @@ impl<'a> Binder {
         let mut bind_context = BindContext::new();
         let plan = self.bind_statement(&mut bind_context, stmt).await?;
         self.bind_query_index(&mut bind_context, &plan).await?;
+        self.bind_materialized_views(&mut bind_context, &plan)
+            .await?;
         self.ctx.set_status_info(&format!(
             "bind stmt to plan done, time used: {:?}",
             start.elapsed()
@@ -187,6 +194,7 @@ impl<'a> Binder {
 
             Statement::ExplainAnalyze { query } => {
                 let plan = self.bind_statement(bind_context, query).await?;
+                self.check_explain_secure_views()?;
                 Plan::ExplainAnalyze { plan: Box::new(plan) }
             }
 
@@ -267,6 +275,7 @@ impl<'a> Binder {
             Statement::OptimizeTable(stmt) => self.bind_optimize_table(bind_context, stmt).await?,
             Statement::VacuumTable(stmt) => self.bind_vacuum_table(bind_context, stmt).await?,
             Statement::VacuumDropTable(stmt) => self.bind_vacuum_drop_table(bind_context, stmt).await?,
+            Statement::VacuumAllTables(stmt) => self.bind_vacuum_all_tables(bind_context, stmt).await?,
             Statement::VacuumTemporaryFiles(stmt) => self.bind_vacuum_temporary_files(bind_context, stmt).await?,
             Statement::AnalyzeTable(stmt) => self.bind_analyze_table(stmt).await?,
             Statement::ExistsTable(stmt) => self.bind_exists_table(stmt).await?,
@@ -281,6 +290,12 @@ impl<'a> Binder {
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
             Statement::ShowViews(stmt) => self.bind_show_views(bind_context, stmt).await?,
             Statement::DescribeView(stmt) => self.bind_describe_view(stmt).await?,
+            Statement::CreateMaterializedView(stmt) => {
+                self.bind_create_materialized_view(stmt).await?
+            }
+            Statement::RefreshMaterializedView(stmt) => {
+                self.bind_refresh_materialized_view(stmt).await?
+            }
 
             // Indexes
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
@@ -610,7 +625,10 @@ impl<'a> Binder {
             Statement::DropSequence(stmt) => {
                 self.bind_drop_sequence(stmt).await?
             }
-            Statement::Begin => Plan::Begin,
+            Statement::Begin { read_only_at } => self.bind_begin(bind_context, read_only_at)?,
+            Statement::SetTransactionSnapshot { at } => Plan::SetTransactionSnapshot {
+                at: self.bind_transaction_snapshot(bind_context, at)?,
+            },
             Statement::Commit => Plan::Commit,
             Statement::Abort => Plan::Abort,
             Statement::ExecuteImmediate(stmt) => self.bind_execute_immediate(stmt).await?,
@@ -635,9 +653,43 @@ impl<'a> Binder {
             }
         }
 
+        if self.ctx.txn_mgr().lock().read_only_at().is_some() {
+            // Consuming a stream advances its offset, so it is a write as well.
+            let consume = self.metadata.read().tables().iter().any(|t| t.is_consume());
+            if !plan.is_read_only() || consume {
+                return Err(ErrorCode::ReadOnlyTransaction(
+                    "only read-only statements are allowed in a read-only transaction",
+                ));
+            }
+        }
+
         Ok(plan)
     }
 
+    fn bind_begin(
+        &self,
+        bind_context: &mut BindContext,
+        read_only_at: &Option<Box<AExpr>>,
+    ) -> Result<Plan> {
+        let read_only_at = match read_only_at {
+            Some(at) => Some(self.bind_transaction_snapshot(bind_context, at)?),
+            None => None,
+        };
+        Ok(Plan::Begin { read_only_at })
+    }
+
+    fn bind_transaction_snapshot(
+        &self,
+        bind_context: &mut BindContext,
+        at: &AExpr,
+    ) -> Result<DateTime<Utc>> {
+        let point = TimeTravelPoint::Timestamp(Box::new(at.clone()));
+        match self.resolve_data_travel_point(bind_context, &point)? {
+            NavigationPoint::TimePoint(time_point) => Ok(time_point),
+            _ => unreachable!("timestamp travel point must resolve to a time point"),
+        }
+    }
+
     pub(crate) fn normalize_identifier(&self, ident: &Identifier) -> Identifier {
         normalize_identifier(ident, &self.name_resolution_ctx)
     }
@@ -73,6 +73,7 @@ impl<'a> Binder {
             stage: Box::new(stage_info),
             path,
             from: Box::new(query),
+            enable_distributed: false,
         }))
     }
 
@@ -92,6 +93,10 @@ impl<'a> Binder {
             if stmt.max_file_size != 0 {
                 stage.copy_options.max_file_size = stmt.max_file_size;
             }
+            // max_files, the target number of the unloaded files.
+            if stmt.max_files != 0 {
+                stage.copy_options.max_files = stmt.max_files;
+            }
             stage.copy_options.single = stmt.single;
             stage.copy_options.detailed_output = stmt.detailed_output;
         }
@@ -38,6 +38,7 @@ use databend_common_meta_app::schema::IcebergCatalogOption;
 use databend_common_meta_app::schema::IcebergHmsCatalogOption;
 use databend_common_meta_app::schema::IcebergRestCatalogOption;
 use databend_common_meta_app::schema::ShareCatalogOption;
+use databend_common_meta_app::schema::StageCatalogOption;
 use databend_common_meta_app::storage::StorageParams;
 
 use crate::binder::parse_storage_params_from_uri;
@@ -171,6 +172,10 @@ impl Binder {
                 let opt = parse_share_catalog(options.clone())?;
                 CatalogOption::Share(opt)
             }
+            CatalogType::Stage => {
+                let opt = parse_stage_catalog(options.clone())?;
+                CatalogOption::Stage(opt)
+            }
         };
 
         Ok(CatalogMeta {
@@ -274,3 +279,13 @@ fn parse_share_catalog(mut options: BTreeMap<String, String>) -> Result<ShareCat
         share_endpoint,
     })
 }
+
+fn parse_stage_catalog(options: BTreeMap<String, String>) -> Result<StageCatalogOption> {
+    if let Some(key) = options.keys().next() {
+        return Err(ErrorCode::InvalidArgument(format!(
+            "unexpected field for stage catalog: {key}"
+        )));
+    }
+
+    Ok(StageCatalogOption {})
+}
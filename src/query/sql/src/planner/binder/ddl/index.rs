@@ -56,6 +56,7 @@ use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
 use crate::plans::RefreshTableIndexPlan;
 use crate::AggregatingIndexChecker;
+use crate::AggregatingIndexGroupByAllRewriter;
 use crate::AggregatingIndexRewriter;
 use crate::BindContext;
 use crate::MetadataRef;
@@ -206,6 +207,10 @@ impl Binder {
             sync_creation,
         } = stmt;
 
+        // `CREATE AGGREGATING INDEX idx ON t (...)` groups by all non-aggregate targets.
+        let mut query = query.clone();
+        query.drive_mut(&mut AggregatingIndexGroupByAllRewriter);
+
         // check if query support index
         {
             let mut agg_index_checker = AggregatingIndexChecker::default();
@@ -224,7 +229,6 @@ impl Binder {
         // pass checker, rewrite aggregate function
         // we will extract all agg function that select targets have
         // and rewrite some agg functions like `avg`.
-        let mut query = query.clone();
         // TODO(ariesdevil): unify the checker and rewriter.
         let mut agg_index_rewritter = AggregatingIndexRewriter::new(self.dialect);
         query.drive_mut(&mut agg_index_rewritter);
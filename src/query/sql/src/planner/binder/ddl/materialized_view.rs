@@ -0,0 +1,328 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+use databend_common_ast::ast::CreateMaterializedViewStmt;
+use databend_common_ast::ast::CreateTableStmt;
+use databend_common_ast::ast::Engine;
+use databend_common_ast::ast::ExplainKind;
+use databend_common_ast::ast::MaterializedViewRefreshMode;
+use databend_common_ast::ast::RefreshMaterializedViewStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use derive_visitor::DriveMut;
+use itertools::Itertools;
+use log::warn;
+
+use crate::binder::Binder;
+use crate::check_incremental_refresh;
+use crate::plans::CreateMaterializedViewPlan;
+use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::BindContext;
+use crate::ColumnEntry;
+use crate::MaterializedViewEntry;
+use crate::TableEntry;
+use crate::ViewRewriter;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            create_option,
+            catalog,
+            database,
+            view,
+            refresh_mode,
+            query,
+        } = stmt;
+
+        let (_, database_name, _) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let mut query = *query.clone();
+        let mut visitor = ViewRewriter {
+            current_database: database_name,
+        };
+        query.drive_mut(&mut visitor);
+        if *refresh_mode == MaterializedViewRefreshMode::Incremental {
+            check_incremental_refresh(&query)?;
+        }
+
+        // The result of the view is kept in a fuse table with the schema of the query,
+        // the table is filled by the first refresh.
+        let create_table_stmt = CreateTableStmt {
+            create_option: create_option.clone(),
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: view.clone(),
+            source: None,
+            engine: Some(Engine::Fuse),
+            uri_location: None,
+            file_format: None,
+            cluster_by: vec![],
+            table_options: BTreeMap::new(),
+            as_query: Some(Box::new(query.clone())),
+            transient: false,
+        };
+        let Plan::CreateTable(mut create_table_plan) =
+            self.bind_create_table(&create_table_stmt).await?
+        else {
+            unreachable!()
+        };
+        create_table_plan.as_select = None;
+
+        let base_tables = self
+            .metadata
+            .read()
+            .tables()
+            .iter()
+            .unique_by(|entry| entry.table().get_id())
+            .map(|entry| {
+                (
+                    entry.database().to_string(),
+                    entry.name().to_string(),
+                    entry.table(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let base_table = match base_tables.as_slice() {
+            [(database, name, table)] if table.engine() == "FUSE" => {
+                if *refresh_mode == MaterializedViewRefreshMode::Incremental
+                    && !table.change_tracking_enabled()
+                {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "materialized view can not be refreshed incrementally: \
+                         please enable the change tracking of table {database}.{name} by \
+                         `ALTER TABLE {database}.{name} SET OPTIONS(change_tracking = true)`"
+                    )));
+                }
+                create_table_plan.options.insert(
+                    OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID.to_string(),
+                    table.get_id().to_string(),
+                );
+                Some((database.clone(), name.clone()))
+            }
+            _ if *refresh_mode == MaterializedViewRefreshMode::Incremental => {
+                return Err(ErrorCode::SemanticError(
+                    "materialized view can not be refreshed incrementally: the view must read from a single fuse table",
+                ));
+            }
+            _ => None,
+        };
+
+        create_table_plan.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            query.to_string(),
+        );
+        create_table_plan.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE.to_string(),
+            refresh_mode.to_string().to_lowercase(),
+        );
+
+        Ok(Plan::CreateMaterializedView(Box::new(
+            CreateMaterializedViewPlan {
+                create_table_plan: *create_table_plan,
+                base_table,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+            full,
+        } = stmt;
+
+        let (catalog, database, view) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+
+        Ok(Plan::RefreshMaterializedView(Box::new(
+            RefreshMaterializedViewPlan {
+                catalog,
+                database,
+                view,
+                full: *full,
+            },
+        )))
+    }
+
+    /// Binds the up-to-date (or fresh enough, see `materialized_view_max_staleness_secs`)
+    /// materialized views of the tables read by the query, the optimizer may rewrite the
+    /// query to read the views instead.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_materialized_views(
+        &mut self,
+        bind_context: &mut BindContext,
+        plan: &Plan,
+    ) -> Result<()> {
+        // Only queries are rewritten, the refresh of a view must read its base table.
+        let metadata = match plan {
+            Plan::Query { metadata, .. } => metadata,
+            Plan::Explain { kind, plan, .. } if matches!(kind, ExplainKind::Plan) => {
+                match &**plan {
+                    Plan::Query { metadata, .. } => metadata,
+                    _ => return Ok(()),
+                }
+            }
+            _ => return Ok(()),
+        };
+        if !self
+            .ctx
+            .get_settings()
+            .get_enable_materialized_view_rewrite()?
+        {
+            return Ok(());
+        }
+
+        let tables = metadata.read().tables().to_vec();
+        for table_entry in tables {
+            let table = table_entry.table();
+            let Some(views) = table.options().get(OPT_KEY_MATERIALIZED_VIEWS) else {
+                continue;
+            };
+            let mut materialized_views = vec![];
+            for view in views.split(',') {
+                let Some((database, name)) = view.split_once('.') else {
+                    continue;
+                };
+                match self
+                    .bind_materialized_view(bind_context, &table_entry, database, name)
+                    .await
+                {
+                    Ok(Some(materialized_view)) => materialized_views.push(materialized_view),
+                    Ok(None) => {}
+                    Err(e) => warn!("skip materialized view {view}: {e}"),
+                }
+            }
+            if !materialized_views.is_empty() {
+                metadata
+                    .write()
+                    .add_materialized_views(table_entry.index(), materialized_views);
+            }
+        }
+        Ok(())
+    }
+
+    async fn bind_materialized_view(
+        &mut self,
+        bind_context: &BindContext,
+        base_table: &TableEntry,
+        database: &str,
+        name: &str,
+    ) -> Result<Option<MaterializedViewEntry>> {
+        let view = self
+            .ctx
+            .get_table(base_table.catalog(), database, name)
+            .await?;
+        let options = view.options();
+        if options.get(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID)
+            != Some(&base_table.table().get_id().to_string())
+        {
+            return Ok(None);
+        }
+        // The view is used if it is refreshed to the snapshot read by the query,
+        // or if it was refreshed within `materialized_view_max_staleness_secs`.
+        if options.get(OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT_LOCATION)
+            != base_table.table().options().get(OPT_KEY_SNAPSHOT_LOCATION)
+        {
+            let max_staleness = self
+                .ctx
+                .get_settings()
+                .get_materialized_view_max_staleness_secs()?;
+            let refreshed_on = options
+                .get(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON)
+                .and_then(|v| v.parse::<i64>().ok());
+            let Some(refreshed_on) = refreshed_on else {
+                return Ok(None);
+            };
+            let staleness = Utc::now().timestamp().saturating_sub(refreshed_on);
+            if max_staleness == 0 || staleness > max_staleness as i64 {
+                return Ok(None);
+            }
+        }
+        let Some(query) = options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY) else {
+            return Ok(None);
+        };
+        let tokens = tokenize_sql(query)?;
+        let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+        let Statement::Query(query) = &stmt else {
+            return Ok(None);
+        };
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let (query, query_context) = self.bind_query(&mut new_bind_context, query)?;
+        let query_columns = query_context
+            .columns
+            .iter()
+            .map(|column| column.index)
+            .collect::<Vec<_>>();
+
+        // The view is marked as the source of view, reading it requires no more
+        // privileges than reading its base table.
+        let table_index = self.metadata.write().add_table(
+            base_table.catalog().to_string(),
+            database.to_string(),
+            view,
+            None,
+            true,
+            false,
+            false,
+            false,
+        );
+        let (scan, _) = self.bind_base_table(bind_context, database, table_index, None)?;
+        let scan_columns = self
+            .metadata
+            .read()
+            .columns_by_table_index(table_index)
+            .iter()
+            .filter_map(|column| match column {
+                ColumnEntry::BaseTableColumn(column) if column.path_indices.is_none() => {
+                    Some(column.column_index)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if query_columns.len() != scan_columns.len() {
+            return Ok(None);
+        }
+
+        Ok(Some(MaterializedViewEntry {
+            name: format!("{database}.{name}"),
+            query,
+            query_columns,
+            scan,
+            scan_columns,
+        }))
+    }
+}
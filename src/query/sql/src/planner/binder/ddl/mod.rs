@@ -20,6 +20,7 @@ mod data_mask;
 mod database;
 mod dynamic_table;
 mod index;
+mod materialized_view;
 mod network_policy;
 mod notification;
 mod password_policy;
@@ -23,6 +23,8 @@ use databend_common_meta_app::principal::FileFormatOptionsReader;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::principal::StageLifecycle;
+use databend_common_meta_app::principal::StageType;
 use databend_common_storage::init_operator;
 
 use super::super::copy_into_table::resolve_stage_location;
@@ -62,6 +64,8 @@ impl Binder {
             on_error,
             size_limit,
             validation_mode: _,
+            purge_after_days,
+            max_total_size,
             comments: _,
         } = stmt;
 
@@ -115,6 +119,19 @@ impl Binder {
             stage_info.copy_options.size_limit = *size_limit;
         }
 
+        // Lifecycle options, only the files of internal stages are owned by databend.
+        if *purge_after_days != 0 || *max_total_size != 0 {
+            if stage_info.stage_type != StageType::Internal {
+                return Err(ErrorCode::SemanticError(
+                    "PURGE_AFTER_DAYS and MAX_TOTAL_SIZE are only supported for internal stages",
+                ));
+            }
+            stage_info.lifecycle = StageLifecycle {
+                purge_after_days: *purge_after_days,
+                max_total_size: *max_total_size,
+            };
+        }
+
         Ok(Plan::CreateStage(Box::new(CreateStagePlan {
             create_option: create_option.clone().into(),
             tenant: self.ctx.get_tenant(),
@@ -48,6 +48,7 @@ use databend_common_ast::ast::TruncateTableStmt;
 use databend_common_ast::ast::TypeName;
 use databend_common_ast::ast::UndropTableStmt;
 use databend_common_ast::ast::UriLocation;
+use databend_common_ast::ast::VacuumAllTablesStmt;
 use databend_common_ast::ast::VacuumDropTableStmt;
 use databend_common_ast::ast::VacuumTableStmt;
 use databend_common_ast::ast::VacuumTemporaryFiles;
@@ -73,19 +74,27 @@ use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::TableIndex;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_storages_common_table_meta::table::is_internal_opt_key;
 use databend_storages_common_table_meta::table::is_reserved_opt_key;
+use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use databend_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
+use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use derive_visitor::DriveMut;
 use log::debug;
 
@@ -129,6 +138,7 @@ use crate::plans::SetOptionsPlan;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UndropTablePlan;
+use crate::plans::VacuumAllTablesPlan;
 use crate::plans::VacuumDropTableOption;
 use crate::plans::VacuumDropTablePlan;
 use crate::plans::VacuumTableOption;
@@ -422,6 +432,7 @@ impl Binder {
             transient,
             engine,
             uri_location,
+            file_format,
         } = stmt;
 
         let (catalog, database, table) =
@@ -440,7 +451,7 @@ impl Binder {
         }
 
         let (mut storage_params, part_prefix) = match (uri_location, engine) {
-            (Some(uri), Engine::Fuse) => {
+            (Some(uri), Engine::Fuse | Engine::External) => {
                 let mut uri = UriLocation {
                     protocol: uri.protocol.clone(),
                     name: uri.name.clone(),
@@ -459,7 +470,7 @@ impl Binder {
                 DataOperator::try_create(&sp).await?;
 
                 // Path ends with "/" means it's a directory.
-                let fp = if uri.path.ends_with('/') {
+                let fp = if engine == Engine::Fuse && uri.path.ends_with('/') {
                     uri.part_prefix.clone()
                 } else {
                     "".to_string()
@@ -468,17 +479,87 @@ impl Binder {
                 (Some(sp), fp)
             }
             (Some(uri), _) => Err(ErrorCode::BadArguments(format!(
-                "Incorrect CREATE query: CREATE TABLE with external location is only supported for FUSE engine, but got {:?} for {:?}",
+                "Incorrect CREATE query: CREATE TABLE with external location is only supported for FUSE and EXTERNAL engine, but got {:?} for {:?}",
                 engine, uri
             )))?,
             _ => (None, "".to_string()),
         };
+        if file_format.is_some() && engine != Engine::External {
+            return Err(ErrorCode::BadArguments(format!(
+                "Incorrect CREATE query: FILE_FORMAT is only supported for EXTERNAL engine, but got {:?}",
+                engine
+            )));
+        }
 
         // If table is TRANSIENT, set a flag in table option
         if *transient {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
         }
 
+        // `CREATE TABLE t2 LIKE t1` also copies the table options and the cluster key of `t1`,
+        // options specified explicitly take precedence.
+        //
+        // `CREATE TABLE t2 CLONE t1` does the same, and additionally points `t2` at the current
+        // snapshot of `t1`, so the two tables share segments and blocks until either is mutated.
+        let mut like_cluster_key = None;
+        let mut clone_source = None;
+        if let Some(
+            CreateTableSource::Like {
+                catalog: like_catalog,
+                database: like_database,
+                table: like_table,
+            }
+            | CreateTableSource::CloneOf {
+                catalog: like_catalog,
+                database: like_database,
+                table: like_table,
+            },
+        ) = source
+        {
+            let is_clone = matches!(source, Some(CreateTableSource::CloneOf { .. }));
+            let (like_catalog, like_database, like_table) =
+                self.normalize_object_identifier_triple(like_catalog, like_database, like_table);
+            let like_table = self
+                .ctx
+                .get_table(&like_catalog, &like_database, &like_table)
+                .await?;
+            if like_table
+                .engine()
+                .eq_ignore_ascii_case(&engine.to_string())
+            {
+                for (key, value) in like_table.options() {
+                    if is_inherited_like_opt_key(key) {
+                        options.entry(key.clone()).or_insert_with(|| value.clone());
+                    }
+                }
+                like_cluster_key = like_table.get_table_info().meta.default_cluster_key.clone();
+            }
+            if is_clone {
+                if engine != Engine::Fuse || !like_table.engine().eq_ignore_ascii_case("FUSE") {
+                    return Err(ErrorCode::TableEngineNotSupported(format!(
+                        "CREATE TABLE ... CLONE only supports FUSE tables, but the source table {} uses engine {}",
+                        like_table.name(),
+                        like_table.engine()
+                    )));
+                }
+                if as_query.is_some() || uri_location.is_some() {
+                    return Err(ErrorCode::BadArguments(
+                        "CREATE TABLE ... CLONE can not be used with AS SELECT or an external location",
+                    ));
+                }
+                // A source table that has never been written has no snapshot yet,
+                // in which case the clone simply starts out empty.
+                if let Some(snapshot_location) = like_table.options().get(OPT_KEY_SNAPSHOT_LOCATION)
+                {
+                    options.insert(
+                        OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
+                        snapshot_location.clone(),
+                    );
+                }
+                clone_source = Some((like_catalog, like_database, like_table.name().to_string()));
+            }
+        }
+
         // todo(geometry): remove this when geometry stable.
         if let Some(CreateTableSource::Columns(cols, _)) = &source {
             if cols
@@ -565,8 +646,29 @@ impl Binder {
                         engine_options.insert(OPT_KEY_ENGINE_META.to_lowercase().to_string(), meta);
                         (Arc::new(table_schema), vec![], None)
                     }
+                    Engine::External => {
+                        if let Some(file_format) = file_format {
+                            let params = self.try_resolve_file_format(file_format).await?;
+                            if !matches!(params, FileFormatParams::Parquet(_)) {
+                                return Err(ErrorCode::BadArguments(format!(
+                                    "Incorrect CREATE query: EXTERNAL table only supports parquet files, but got FILE_FORMAT = ({file_format})",
+                                )));
+                            }
+                        }
+                        let Some(sp) = &storage_params else {
+                            return Err(ErrorCode::BadArguments(
+                                "Incorrect CREATE query: EXTERNAL table requires LOCATION '<uri>'",
+                            ));
+                        };
+                        // the schema and the partition columns are inferred from the files once,
+                        // files added later must have the same layout.
+                        let (table_schema, meta) =
+                            self.ctx.load_datalake_schema("external", sp).await?;
+                        engine_options.insert(OPT_KEY_ENGINE_META.to_lowercase().to_string(), meta);
+                        (Arc::new(table_schema), vec![], None)
+                    }
                     _ => Err(ErrorCode::BadArguments(
-                        "Incorrect CREATE query: required list of column descriptions or AS section or SELECT or ICEBERG/DELTA table engine",
+                        "Incorrect CREATE query: required list of column descriptions or AS section or SELECT or ICEBERG/DELTA/EXTERNAL table engine",
                     ))?,
                 }
             }
@@ -640,7 +742,7 @@ impl Binder {
                 .analyze_cluster_keys(cluster_by, schema.clone())
                 .await?;
             if keys.is_empty() {
-                None
+                like_cluster_key
             } else {
                 Some(format!("({})", keys.join(", ")))
             }
@@ -668,6 +770,7 @@ impl Binder {
             } else {
                 None
             },
+            clone_source,
             inverted_indexes,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
@@ -740,6 +843,7 @@ impl Binder {
             field_comments: vec![],
             cluster_key: None,
             as_select: None,
+            clone_source: None,
             inverted_indexes: None,
         })))
     }
@@ -1042,11 +1146,30 @@ impl Binder {
             AlterTableAction::SetOptions { set_options } => {
                 Ok(Plan::SetOptions(Box::new(SetOptionsPlan {
                     set_options: set_options.clone(),
+                    unset_options: vec![],
+                    catalog,
+                    database,
+                    table,
+                })))
+            }
+            AlterTableAction::SetTTL { ttl } => {
+                let mut set_options = BTreeMap::new();
+                set_options.insert(OPT_KEY_TTL.to_string(), ttl.to_string());
+                Ok(Plan::SetOptions(Box::new(SetOptionsPlan {
+                    set_options,
+                    unset_options: vec![],
                     catalog,
                     database,
                     table,
                 })))
             }
+            AlterTableAction::DropTTL => Ok(Plan::SetOptions(Box::new(SetOptionsPlan {
+                set_options: BTreeMap::new(),
+                unset_options: vec![OPT_KEY_TTL.to_string()],
+                catalog,
+                database,
+                table,
+            }))),
         }
     }
 
@@ -1208,6 +1331,7 @@ impl Binder {
             self.normalize_object_identifier_triple(catalog, database, table);
 
         let option = VacuumTableOption {
+            retain: option.retain,
             dry_run: option.dry_run,
         };
         Ok(Plan::VacuumTable(Box::new(VacuumTablePlan {
@@ -1252,6 +1376,37 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_vacuum_all_tables(
+        &mut self,
+        _bind_context: &mut BindContext,
+        stmt: &VacuumAllTablesStmt,
+    ) -> Result<Plan> {
+        let VacuumAllTablesStmt {
+            catalog,
+            database,
+            option,
+        } = stmt;
+
+        let catalog = catalog
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name);
+
+        let option = VacuumTableOption {
+            retain: option.retain,
+            dry_run: option.dry_run,
+        };
+        Ok(Plan::VacuumAllTables(Box::new(VacuumAllTablesPlan {
+            catalog,
+            database,
+            option,
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_vacuum_temporary_files(
         &mut self,
@@ -1522,6 +1677,11 @@ impl Binder {
                 catalog,
                 database,
                 table,
+            }
+            | CreateTableSource::CloneOf {
+                catalog,
+                database,
+                table,
             } => {
                 let (catalog, database, table) =
                     self.normalize_object_identifier_triple(catalog, database, table);
@@ -1676,3 +1836,21 @@ impl Binder {
             .unwrap_or(true)
     }
 }
+
+/// Whether the table option is copied by `CREATE TABLE ... LIKE`, the options that
+/// belong to the data of the source table (like snapshot location) are not copied.
+fn is_inherited_like_opt_key(key: &str) -> bool {
+    !is_reserved_opt_key(key)
+        && !is_internal_opt_key(key)
+        && ![
+            OPT_KEY_SNAPSHOT_LOCATION,
+            OPT_KEY_STORAGE_PREFIX,
+            OPT_KEY_TABLE_ATTACHED_DATA_URI,
+            OPT_KEY_CHANGE_TRACKING,
+            OPT_KEY_LOCATION,
+            OPT_KEY_CONNECTION_NAME,
+            OPT_KEY_ENGINE,
+            "transient",
+        ]
+        .contains(&key.to_lowercase().as_str())
+}
@@ -12,16 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use databend_common_ast::ast::AlterViewStmt;
 use databend_common_ast::ast::CreateViewStmt;
 use databend_common_ast::ast::DescribeViewStmt;
 use databend_common_ast::ast::DropViewStmt;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Query;
 use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowViewsStmt;
+use databend_common_ast::ast::ViewParameter;
+use databend_common_ast::parser::parse_view_parameters;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_storages_view::view_table::PARAMETERS;
 use derive_visitor::DriveMut;
 use log::debug;
 
@@ -34,6 +45,7 @@ use crate::plans::DropViewPlan;
 use crate::plans::Plan;
 use crate::plans::RewriteKind;
 use crate::BindContext;
+use crate::ScalarBinder;
 use crate::SelectBuilder;
 use crate::ViewRewriter;
 
@@ -49,6 +61,8 @@ impl Binder {
             database,
             view,
             columns,
+            params,
+            secure,
             query,
         } = stmt;
         let mut query = *query.clone();
@@ -65,6 +79,8 @@ impl Binder {
         query.drive_mut(&mut visitor);
         let subquery = format!("{}", query);
 
+        let parameters = self.check_view_parameters(params, &query, &column_names)?;
+
         let plan = CreateViewPlan {
             create_option: create_option.clone().into(),
             tenant,
@@ -73,6 +89,8 @@ impl Binder {
             view_name,
             column_names,
             subquery,
+            parameters,
+            secure: *secure,
         };
         Ok(Plan::CreateView(plan.into()))
     }
@@ -87,6 +105,7 @@ impl Binder {
             database,
             view,
             columns,
+            params,
             query,
         } = stmt;
 
@@ -104,6 +123,25 @@ impl Binder {
         query.drive_mut(&mut visitor);
         let subquery = format!("{}", query);
 
+        // Keep the parameters of the view if the statement does not redefine them.
+        let parameters = if params.is_empty() {
+            let table = self
+                .ctx
+                .get_table(&catalog, &database, &view_name)
+                .await
+                .ok();
+            match table.and_then(|table| table.options().get(PARAMETERS).cloned()) {
+                Some(parameters) => {
+                    let tokens = tokenize_sql(&parameters)?;
+                    parse_view_parameters(&tokens, self.dialect)?
+                }
+                None => vec![],
+            }
+        } else {
+            params.clone()
+        };
+        let parameters = self.check_view_parameters(&parameters, &query, &column_names)?;
+
         let plan = AlterViewPlan {
             tenant,
             catalog,
@@ -111,10 +149,73 @@ impl Binder {
             view_name,
             column_names,
             subquery,
+            parameters,
         };
         Ok(Plan::AlterView(plan.into()))
     }
 
+    /// Checks the query of a parameterized view by binding it with the parameters
+    /// as typed NULLs, and returns the parameters to be stored in the view options.
+    fn check_view_parameters(
+        &mut self,
+        params: &[ViewParameter],
+        query: &Query,
+        column_names: &[String],
+    ) -> Result<Option<String>> {
+        if params.is_empty() {
+            return Ok(None);
+        }
+        let mut bind_context = BindContext::new();
+        let mut arguments = HashMap::with_capacity(params.len());
+        for param in params {
+            let name = normalize_identifier(&param.name, &self.name_resolution_ctx).name;
+            let mut scalar_binder = ScalarBinder::new(
+                &mut bind_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+                self.m_cte_bound_ctx.clone(),
+                self.ctes_map.clone(),
+            );
+            let (argument, _) = scalar_binder.bind(&Expr::Cast {
+                span: param.name.span,
+                expr: Box::new(Expr::Literal {
+                    span: None,
+                    value: Literal::Null,
+                }),
+                target_type: param.data_type.clone().wrap_nullable(),
+                pg_style: false,
+            })?;
+            if arguments.insert(name.clone(), argument).is_some() {
+                return Err(
+                    ErrorCode::SemanticError(format!("Duplicate view parameter: {name}"))
+                        .set_span(param.name.span),
+                );
+            }
+        }
+
+        // The parameters are resolved after the columns of the view query,
+        // so a column with the same name as a parameter is not shadowed.
+        bind_context.view_arguments = Some(Arc::new(arguments));
+        let (_, bind_context) = self.bind_query(&mut bind_context, query)?;
+        if !column_names.is_empty() && bind_context.columns.len() != column_names.len() {
+            return Err(ErrorCode::BadDataArrayLength(format!(
+                "column name length mismatch, expect {}, got {}",
+                bind_context.columns.len(),
+                column_names.len(),
+            )));
+        }
+
+        Ok(Some(
+            params
+                .iter()
+                .map(|param| param.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        ))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_view(
         &mut self,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::ExplainFormat;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::ExplainOption;
 use databend_common_ast::ast::Statement;
@@ -28,12 +29,14 @@ pub struct ExplainConfig {
     pub verbose: bool,
     pub logical: bool,
     pub optimized: bool,
+    pub format: ExplainFormat,
 }
 
 struct ExplainConfigBuilder {
     verbose: bool,
     logical: bool,
     optimized: bool,
+    format: ExplainFormat,
 }
 
 impl ExplainConfigBuilder {
@@ -42,6 +45,7 @@ impl ExplainConfigBuilder {
             verbose: false,
             logical: false,
             optimized: false,
+            format: ExplainFormat::Text,
         }
     }
 
@@ -53,6 +57,7 @@ impl ExplainConfigBuilder {
                 self.logical = true;
                 self.optimized = true;
             }
+            ExplainOption::Format(format) => self.format = *format,
         }
 
         self
@@ -63,6 +68,7 @@ impl ExplainConfigBuilder {
             verbose: self.verbose,
             logical: self.logical,
             optimized: self.optimized,
+            format: self.format,
         }
     }
 }
@@ -96,6 +102,11 @@ impl Binder {
 
         // Validate the configuration
         validate_explain_config(kind, &config)?;
+        if config.format == ExplainFormat::Json && !matches!(inner, Statement::Query(_)) {
+            return Err(ErrorCode::SyntaxException(
+                "FORMAT JSON option is only supported for EXPLAIN SELECT statement".to_string(),
+            ));
+        }
 
         let plan = match kind {
             ExplainKind::Ast(formatted_stmt) => Plan::ExplainAst {
@@ -104,15 +115,31 @@ impl Binder {
             ExplainKind::Syntax(formatted_sql) => Plan::ExplainSyntax {
                 formatted_sql: formatted_sql.clone(),
             },
-            _ => Plan::Explain {
-                kind: kind.clone(),
-                config,
-                plan: Box::new(self.bind_statement(bind_context, inner).await?),
-            },
+            _ => {
+                let plan = self.bind_statement(bind_context, inner).await?;
+                self.check_explain_secure_views()?;
+                Plan::Explain {
+                    kind: kind.clone(),
+                    config,
+                    plan: Box::new(plan),
+                }
+            }
         };
 
         Ok(plan)
     }
+
+    // The plan of a query reveals the definition of the secure views it reads,
+    // which is only visible to the owners of the views.
+    pub(crate) fn check_explain_secure_views(&self) -> Result<()> {
+        match self.metadata.read().hidden_secure_views().first() {
+            Some(view) => Err(ErrorCode::PermissionDenied(format!(
+                "Permission denied: the plan of the query reading secure view {} is only visible to the owner of the view",
+                view
+            ))),
+            None => Ok(()),
+        }
+    }
 }
 
 fn validate_explain_config(kind: &ExplainKind, config: &ExplainConfig) -> Result<()> {
@@ -146,5 +173,15 @@ fn validate_explain_config(kind: &ExplainKind, config: &ExplainConfig) -> Result
         ));
     }
 
+    if !matches!(
+        kind,
+        ExplainKind::Plan | ExplainKind::Raw | ExplainKind::Optimized
+    ) && config.format == ExplainFormat::Json
+    {
+        return Err(ErrorCode::SyntaxException(
+            "FORMAT JSON option is only supported for EXPLAIN SELECT statement".to_string(),
+        ));
+    }
+
     Ok(())
 }
@@ -26,6 +26,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::type_check::common_super_type;
 use databend_common_expression::types::DataType;
+use databend_common_expression::TableDataType;
 use databend_common_expression::ROW_ID_COLUMN_ID;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -595,7 +596,20 @@ impl Binder {
         // add previous(subquery) stored non_lazy_columns to non_lazy_cols
         non_lazy_cols.extend(metadata.non_lazy_columns());
 
-        let lazy_cols = select_cols.difference(&non_lazy_cols).copied().collect();
+        let mut lazy_cols: HashSet<IndexType> =
+            select_cols.difference(&non_lazy_cols).copied().collect();
+
+        // Fetching by row ids is random access, it only pays off for wide columns.
+        // The narrow columns are read together with the filter and sort columns.
+        if self.ctx.get_settings().get_lazy_read_wide_columns_only()? {
+            lazy_cols.retain(|col| match metadata.column(*col) {
+                ColumnEntry::BaseTableColumn(column) => is_wide_column_type(&column.data_type),
+                _ => true,
+            });
+            if lazy_cols.is_empty() {
+                return Ok(());
+            }
+        }
         metadata.add_lazy_columns(lazy_cols);
 
         // Single table, the table index is 0.
@@ -611,3 +625,18 @@ impl Binder {
         Ok(())
     }
 }
+
+/// Variable-length and nested types, which are expensive to read for all the rows.
+fn is_wide_column_type(data_type: &TableDataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        TableDataType::String
+            | TableDataType::Binary
+            | TableDataType::Variant
+            | TableDataType::Bitmap
+            | TableDataType::Geometry
+            | TableDataType::Array(_)
+            | TableDataType::Map(_)
+            | TableDataType::Tuple { .. }
+    )
+}
@@ -250,6 +250,33 @@ impl Binder {
         Ok(new_expr)
     }
 
+    /// Sort the output of GROUP BY by the group keys, used by the `sorted_group_by_output` setting.
+    pub fn bind_group_keys_order(&self, from_context: &BindContext, child: SExpr) -> Result<SExpr> {
+        let nulls_first = !self.ctx.get_settings().get_sql_dialect()?.is_null_biggest();
+        let items = from_context
+            .aggregate_info
+            .group_items
+            .iter()
+            .map(|item| SortItem {
+                index: item.index,
+                asc: true,
+                nulls_first,
+            })
+            .collect();
+
+        let sort_plan = Sort {
+            items,
+            limit: None,
+            after_exchange: None,
+            pre_projection: None,
+            window_partition: vec![],
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(sort_plan.into()),
+            Arc::new(child),
+        ))
+    }
+
     #[allow(clippy::only_used_in_recursion)]
     pub(crate) fn rewrite_scalar_with_replacement<F>(
         &self,
@@ -25,10 +25,13 @@ impl Binder {
     #[async_backtrace::framed]
     pub(super) async fn bind_system(&mut self, stmt: &SystemStmt) -> Result<Plan> {
         let SystemStmt { action } = stmt;
-        match action {
-            AstSystemAction::Backtrace(switch) => Ok(Plan::System(Box::new(SystemPlan {
-                action: SystemAction::Backtrace(*switch),
-            }))),
-        }
+        let action = match action {
+            AstSystemAction::Backtrace(switch) => SystemAction::Backtrace(*switch),
+            AstSystemAction::ReloadConfig => SystemAction::ReloadConfig,
+            AstSystemAction::FlushLogs => SystemAction::FlushLogs,
+            AstSystemAction::DropQueryCache => SystemAction::DropQueryCache,
+            AstSystemAction::SyncMetadata => SystemAction::SyncMetadata,
+        };
+        Ok(Plan::System(Box::new(SystemPlan { action })))
     }
 }
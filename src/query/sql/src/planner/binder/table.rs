@@ -202,6 +202,7 @@ impl Binder {
             cte_map_ref: Box::default(),
             in_grouping: false,
             view_info: None,
+            view_arguments: None,
             srfs: Default::default(),
             inverted_index_map: Box::default(),
             expr_context: ExprContext::default(),
@@ -497,6 +498,14 @@ impl Binder {
 
             if let Some(desc) = navigation {
                 table_meta = table_meta.navigate_to(desc, abort_checker).await?;
+            } else if table_meta.engine() == "FUSE" {
+                // Inside `BEGIN READ ONLY AT <timestamp>`, every table is read
+                // as of the pinned timestamp to get a consistent view.
+                let read_only_at = self.ctx.txn_mgr().lock().read_only_at();
+                if let Some(time_point) = read_only_at {
+                    let desc = TimeNavigation::TimeTravel(NavigationPoint::TimePoint(time_point));
+                    table_meta = table_meta.navigate_to(&desc, abort_checker).await?;
+                }
             }
             Ok(table_meta)
         })
@@ -512,6 +521,10 @@ impl Binder {
                 let point = self.resolve_data_travel_point(bind_context, point)?;
                 Ok(Some(TimeNavigation::TimeTravel(point)))
             }
+            Some(TemporalClause::TimeTravelBefore(point)) => {
+                let point = self.resolve_data_travel_point(bind_context, point)?;
+                Ok(Some(TimeNavigation::TimeTravelBefore(point)))
+            }
             Some(TemporalClause::Changes(interval)) => {
                 let end = match &interval.end_point {
                     Some(tp) => Some(self.resolve_data_travel_point(bind_context, tp)?),
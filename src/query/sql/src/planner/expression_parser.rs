@@ -14,6 +14,9 @@
 
 use std::sync::Arc;
 
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
 use databend_common_ast::ast::Expr as AExpr;
 use databend_common_ast::parser::parse_comma_separated_exprs;
 use databend_common_ast::parser::tokenize_sql;
@@ -48,6 +51,7 @@ use parking_lot::RwLock;
 use crate::binder::wrap_cast;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::ExprContext;
+use crate::normalize_identifier;
 use crate::planner::binder::BindContext;
 use crate::planner::semantic::NameResolutionContext;
 use crate::planner::semantic::TypeChecker;
@@ -335,6 +339,100 @@ pub fn parse_computed_expr_to_string(
     Ok(format!("{:#}", ast))
 }
 
+/// Parses a table TTL of the form `<column> + INTERVAL <n> <unit>`.
+///
+/// Returns the name of the column and the expression computing the expiration time of a row.
+/// The column is bound at offset 0, so the expression can be evaluated against that column
+/// alone, e.g. against the max value of a block.
+pub fn parse_ttl_expr(
+    ctx: Arc<dyn TableContext>,
+    table_schema: TableSchemaRef,
+    ttl: &str,
+) -> Result<(String, Expr)> {
+    let settings = Settings::create(Tenant::new_literal("dummy"));
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let sql_dialect = ctx.get_settings().get_sql_dialect()?;
+    let tokens = tokenize_sql(ttl)?;
+    let mut asts = parse_comma_separated_exprs(&tokens, sql_dialect)?;
+    if asts.len() != 1 {
+        return Err(ErrorCode::TableOptionInvalid(format!(
+            "invalid ttl `{ttl}`, expected a single expression"
+        )));
+    }
+    let ast = asts.remove(0);
+
+    // The expiration time has to grow with the column, so that the max value of the column
+    // in a block tells if all the rows of the block are expired.
+    let column = match &ast {
+        AExpr::BinaryOp {
+            op: BinaryOperator::Plus,
+            left,
+            right,
+            ..
+        } if matches!(right.as_ref(), AExpr::Interval { .. }) => match left.as_ref() {
+            AExpr::ColumnRef {
+                column:
+                    ColumnRef {
+                        database: None,
+                        table: None,
+                        column: ColumnID::Name(ident),
+                    },
+                ..
+            } => Some(normalize_identifier(ident, &name_resolution_ctx).name),
+            _ => None,
+        },
+        _ => None,
+    }
+    .ok_or_else(|| {
+        ErrorCode::TableOptionInvalid(format!(
+            "invalid ttl `{ttl}`, expected `<column> + INTERVAL <n> <unit>`"
+        ))
+    })?;
+
+    let field = table_schema.field_with_name(&column)?;
+    let data_type = DataType::from(field.data_type());
+    if !matches!(
+        data_type.remove_nullable(),
+        DataType::Date | DataType::Timestamp
+    ) {
+        return Err(ErrorCode::TableOptionInvalid(format!(
+            "invalid ttl `{ttl}`, column `{column}` should be of type DATE or TIMESTAMP, but got {data_type}"
+        )));
+    }
+
+    let mut bind_context = BindContext::new();
+    let mut metadata = Metadata::default();
+    bind_context.add_column_binding(
+        ColumnBindingBuilder::new(
+            field.name().clone(),
+            0,
+            Box::new(data_type),
+            Visibility::Visible,
+        )
+        .build(),
+    );
+    metadata.add_base_table_column(
+        field.name().clone(),
+        field.data_type().clone(),
+        0,
+        None,
+        None,
+        None,
+        None,
+    );
+    let mut type_checker = TypeChecker::try_create(
+        &mut bind_context,
+        ctx,
+        &name_resolution_ctx,
+        Arc::new(RwLock::new(metadata)),
+        &[],
+        false,
+    )?;
+    let (scalar, _) = *type_checker.resolve(&ast)?;
+    let expr = scalar.as_expr()?.project_column_ref(|col| col.index);
+    Ok((column, expr))
+}
+
 pub fn parse_lambda_expr(
     ctx: Arc<dyn TableContext>,
     mut bind_context: BindContext,
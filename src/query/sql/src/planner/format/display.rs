@@ -201,9 +201,9 @@ impl<'a, I: IdHumanizer<ColumnId = IndexType>, O: OperatorHumanizer<I, Output =
             .collect::<Vec<_>>();
 
         vec![
-            FormatTreeNode::new(format!("output columns: [{}]", output_columns.join(", "))),
-            FormatTreeNode::new(format!("outer columns: [{}]", outer_columns.join(", "))),
-            FormatTreeNode::new(format!("used columns: [{}]", used_columns.join(", "))),
+            FormatTreeNode::property("output columns", format!("[{}]", output_columns.join(", "))),
+            FormatTreeNode::property("outer columns", format!("[{}]", outer_columns.join(", "))),
+            FormatTreeNode::property("used columns", format!("[{}]", used_columns.join(", "))),
         ]
     }
 
@@ -232,8 +232,8 @@ impl<'a, I: IdHumanizer<ColumnId = IndexType>, O: OperatorHumanizer<I, Output =
             .collect::<Vec<_>>();
 
         Ok(vec![
-            FormatTreeNode::new(format!("cardinality: {}", cardinality)),
-            FormatTreeNode::new(format!("precise cardinality: {}", precise_cardinality)),
+            FormatTreeNode::property("cardinality", cardinality),
+            FormatTreeNode::property("precise cardinality", precise_cardinality),
             FormatTreeNode::with_children("statistics".to_string(), column_stats),
         ])
     }
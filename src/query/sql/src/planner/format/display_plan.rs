@@ -24,6 +24,24 @@ use crate::plans::Mutation;
 use crate::plans::Plan;
 
 impl Plan {
+    /// Formats the logical plan of a query as json, see [`FormatTreeNode::format_json`].
+    pub fn format_json(&self, verbose: bool) -> Result<String> {
+        match self {
+            Plan::Query {
+                s_expr, metadata, ..
+            } => {
+                let metadata = &*metadata.read();
+                Ok(s_expr
+                    .to_format_tree(metadata, verbose)?
+                    .format_json()?
+                    .to_string())
+            }
+            _ => Err(ErrorCode::Unimplemented(
+                "FORMAT JSON is only supported for query plans",
+            )),
+        }
+    }
+
     pub fn format_indent(&self, verbose: bool) -> Result<String> {
         match self {
             Plan::Query {
@@ -77,6 +95,7 @@ impl Plan {
             Plan::OptimizeCompactBlock { .. } => Ok("OptimizeCompactBlock".to_string()),
             Plan::VacuumTable(_) => Ok("VacuumTable".to_string()),
             Plan::VacuumDropTable(_) => Ok("VacuumDropTable".to_string()),
+            Plan::VacuumAllTables(_) => Ok("VacuumAllTables".to_string()),
             Plan::VacuumTemporaryFiles(_) => Ok("VacuumTemporaryFiles".to_string()),
             Plan::AnalyzeTable(_) => Ok("AnalyzeTable".to_string()),
             Plan::ExistsTable(_) => Ok("ExistsTable".to_string()),
@@ -93,6 +112,8 @@ impl Plan {
 
             // Dynamic Tables
             Plan::CreateDynamicTable(_) => Ok("CreateDynamicTable".to_string()),
+            Plan::CreateMaterializedView(_) => Ok("CreateMaterializedView".to_string()),
+            Plan::RefreshMaterializedView(_) => Ok("RefreshMaterializedView".to_string()),
 
             // Indexes
             Plan::CreateIndex(_) => Ok("CreateIndex".to_string()),
@@ -192,7 +213,8 @@ impl Plan {
             Plan::DescConnection(_) => Ok("DescConnection".to_string()),
             Plan::DropConnection(_) => Ok("DropConnection".to_string()),
             Plan::ShowConnections(_) => Ok("ShowConnections".to_string()),
-            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Begin { .. } => Ok("Begin".to_string()),
+            Plan::SetTransactionSnapshot { .. } => Ok("SetTransactionSnapshot".to_string()),
             Plan::Commit => Ok("commit".to_string()),
             Plan::Abort => Ok("Abort".to_string()),
 
@@ -255,16 +277,13 @@ fn format_merge_into(s_expr: &SExpr) -> Result<String> {
     );
 
     let target_build_optimization = false;
-    let target_build_optimization_format = FormatTreeNode::new(format!(
-        "target_build_optimization: {}",
-        target_build_optimization
-    ));
-    let distributed_format =
-        FormatTreeNode::new(format!("distributed: {}", merge_into.distributed));
-    let can_try_update_column_only_format = FormatTreeNode::new(format!(
-        "can_try_update_column_only: {}",
-        merge_into.can_try_update_column_only
-    ));
+    let target_build_optimization_format =
+        FormatTreeNode::property("target_build_optimization", target_build_optimization);
+    let distributed_format = FormatTreeNode::property("distributed", merge_into.distributed);
+    let can_try_update_column_only_format = FormatTreeNode::property(
+        "can_try_update_column_only",
+        merge_into.can_try_update_column_only,
+    );
     // add matched clauses
     let mut matched_children = Vec::with_capacity(merge_into.matched_evaluators.len());
     let taregt_schema = table_entry.table().schema_with_stream();
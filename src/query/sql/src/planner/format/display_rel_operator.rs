@@ -160,37 +160,37 @@ fn scan_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType>
     op: &Scan,
 ) -> FormatTreeNode {
     FormatTreeNode::with_children("Scan".to_string(), vec![
-        FormatTreeNode::new(format!(
-            "table: {}",
-            id_humanizer.humanize_table_id(op.table_index)
-        )),
-        FormatTreeNode::new(format!(
-            "filters: [{}]",
-            op.push_down_predicates.as_ref().map_or_else(
-                || "".to_string(),
-                |predicates| { predicates.iter().map(format_scalar).join(", ") },
+        FormatTreeNode::property("table", id_humanizer.humanize_table_id(op.table_index)),
+        FormatTreeNode::property(
+            "filters",
+            format!(
+                "[{}]",
+                op.push_down_predicates.as_ref().map_or_else(
+                    || "".to_string(),
+                    |predicates| { predicates.iter().map(format_scalar).join(", ") },
+                )
             ),
-        )),
-        FormatTreeNode::new(format!(
-            "order by: [{}]",
-            op.order_by.as_ref().map_or_else(
-                || "".to_string(),
-                |items| items
-                    .iter()
-                    .map(|item| format!(
-                        "{} (#{}) {}",
-                        id_humanizer.humanize_column_id(item.index),
-                        item.index,
-                        if item.asc { "ASC" } else { "DESC" }
-                    ))
-                    .collect::<Vec<String>>()
-                    .join(", "),
+        ),
+        FormatTreeNode::property(
+            "order by",
+            format!(
+                "[{}]",
+                op.order_by.as_ref().map_or_else(
+                    || "".to_string(),
+                    |items| items
+                        .iter()
+                        .map(|item| format!(
+                            "{} (#{}) {}",
+                            id_humanizer.humanize_column_id(item.index),
+                            item.index,
+                            if item.asc { "ASC" } else { "DESC" }
+                        ))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                )
             ),
-        )),
-        FormatTreeNode::new(format!(
-            "limit: {}",
-            op.limit.map_or("NONE".to_string(), |l| l.to_string())
-        )),
+        ),
+        FormatTreeNode::property("limit", op.limit.map_or("NONE".to_string(), |l| l.to_string())),
     ])
 }
 
@@ -238,9 +238,9 @@ fn join_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType>
         .join(", ");
 
     FormatTreeNode::with_children(format_join(op), vec![
-        FormatTreeNode::new(format!("build keys: [{}]", build_keys)),
-        FormatTreeNode::new(format!("probe keys: [{}]", probe_keys)),
-        FormatTreeNode::new(format!("other filters: [{}]", join_filters)),
+        FormatTreeNode::property("build keys", format!("[{}]", build_keys)),
+        FormatTreeNode::property("probe keys", format!("[{}]", probe_keys)),
+        FormatTreeNode::property("other filters", format!("[{}]", join_filters)),
     ])
 }
 
@@ -261,8 +261,8 @@ fn aggregate_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = Index
         .collect::<Vec<String>>()
         .join(", ");
     FormatTreeNode::with_children(format_aggregate(op), vec![
-        FormatTreeNode::new(format!("group items: [{}]", group_items)),
-        FormatTreeNode::new(format!("aggregate functions: [{}]", agg_funcs)),
+        FormatTreeNode::property("group items", format!("[{}]", group_items)),
+        FormatTreeNode::property("aggregate functions", format!("[{}]", agg_funcs)),
     ])
 }
 
@@ -287,10 +287,10 @@ fn window_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexTyp
     let frame = op.frame.to_string();
 
     FormatTreeNode::with_children("Window".to_string(), vec![
-        FormatTreeNode::new(format!("aggregate function: {}", op.function.func_name())),
-        FormatTreeNode::new(format!("partition items: [{}]", partition_by_items)),
-        FormatTreeNode::new(format!("order by items: [{}]", order_by_items)),
-        FormatTreeNode::new(format!("frame: [{}]", frame)),
+        FormatTreeNode::property("aggregate function", op.function.func_name()),
+        FormatTreeNode::property("partition items", format!("[{}]", partition_by_items)),
+        FormatTreeNode::property("order by items", format!("[{}]", order_by_items)),
+        FormatTreeNode::property("frame", format!("[{}]", frame)),
     ])
 }
 
@@ -304,10 +304,10 @@ fn filter_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexTyp
         .map(format_scalar)
         .collect::<Vec<String>>()
         .join(", ");
-    FormatTreeNode::with_children("Filter".to_string(), vec![FormatTreeNode::new(format!(
-        "filters: [{}]",
-        scalars
-    ))])
+    FormatTreeNode::with_children("Filter".to_string(), vec![FormatTreeNode::property(
+        "filters",
+        format!("[{}]", scalars),
+    )])
 }
 
 fn eval_scalar_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType>>(
@@ -321,8 +321,9 @@ fn eval_scalar_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = Ind
         .map(|item| format!("{} AS (#{})", format_scalar(&item.scalar), item.index))
         .collect::<Vec<String>>()
         .join(", ");
-    FormatTreeNode::with_children("EvalScalar".to_string(), vec![FormatTreeNode::new(
-        format!("scalars: [{}]", scalars),
+    FormatTreeNode::with_children("EvalScalar".to_string(), vec![FormatTreeNode::property(
+        "scalars",
+        format!("[{}]", scalars),
     )])
 }
 
@@ -346,8 +347,8 @@ fn sort_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType>
     let limit = op.limit.map_or("NONE".to_string(), |l| l.to_string());
 
     FormatTreeNode::with_children("Sort".to_string(), vec![
-        FormatTreeNode::new(format!("sort keys: [{}]", scalars)),
-        FormatTreeNode::new(format!("limit: [{}]", limit)),
+        FormatTreeNode::property("sort keys", format!("[{}]", scalars)),
+        FormatTreeNode::property("limit", format!("[{}]", limit)),
     ])
 }
 
@@ -357,8 +358,8 @@ fn limit_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType
 ) -> FormatTreeNode {
     let limit = op.limit.unwrap_or_default();
     FormatTreeNode::with_children("Limit".to_string(), vec![
-        FormatTreeNode::new(format!("limit: [{}]", limit)),
-        FormatTreeNode::new(format!("offset: [{}]", op.offset)),
+        FormatTreeNode::property("limit", format!("[{}]", limit)),
+        FormatTreeNode::property("offset", format!("[{}]", op.offset)),
     ])
 }
 
@@ -368,13 +369,16 @@ fn exchange_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexT
 ) -> FormatTreeNode {
     match op {
         Exchange::Hash(keys) => {
-            FormatTreeNode::with_children(format_exchange(op), vec![FormatTreeNode::new(format!(
-                "Exchange(Hash): keys: [{}]",
-                keys.iter()
-                    .map(format_scalar)
-                    .collect::<Vec<String>>()
-                    .join(", ")
-            ))])
+            FormatTreeNode::with_children(format_exchange(op), vec![FormatTreeNode::property(
+                "keys",
+                format!(
+                    "[{}]",
+                    keys.iter()
+                        .map(format_scalar)
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+            )])
         }
         _ => FormatTreeNode::with_children(format_exchange(op), vec![]),
     }
@@ -33,6 +33,7 @@ use databend_common_expression::TableField;
 use parking_lot::RwLock;
 
 use crate::optimizer::SExpr;
+use crate::plans::RelOperator;
 use crate::ScalarExpr;
 
 /// Planner use [`usize`] as it's index type.
@@ -70,9 +71,15 @@ pub struct Metadata {
     /// we need add cols that inner query required to non_lazy_columns
     /// to prevent these cols to be pruned.
     non_lazy_columns: HashSet<IndexType>,
+    /// Output columns of secure views, predicates on them must not be pushed into the view.
+    secure_view_columns: HashSet<IndexType>,
+    /// Secure views read by the query which are not owned by the current user.
+    hidden_secure_views: Vec<String>,
     /// Mappings from table index to _row_id column index.
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    /// Mappings from table index to the materialized views of the table.
+    materialized_views: HashMap<IndexType, Vec<MaterializedViewEntry>>,
     max_column_position: usize, // for CSV
 }
 
@@ -178,6 +185,35 @@ impl Metadata {
         &self.non_lazy_columns
     }
 
+    pub fn add_secure_view_columns(&mut self, indices: HashSet<IndexType>) {
+        debug_assert!(indices.iter().all(|i| *i < self.columns.len()));
+        self.secure_view_columns.extend(indices);
+    }
+
+    pub fn is_secure_view_column(&self, index: IndexType) -> bool {
+        self.secure_view_columns.contains(&index)
+    }
+
+    pub fn add_hidden_secure_view(&mut self, name: String) {
+        self.hidden_secure_views.push(name);
+    }
+
+    pub fn hidden_secure_views(&self) -> &[String] {
+        &self.hidden_secure_views
+    }
+
+    /// Returns true if the operator projects the output columns of a secure view,
+    /// no operator of the outer query can be moved below it.
+    pub fn is_secure_view_barrier(&self, plan: &RelOperator) -> bool {
+        match plan {
+            RelOperator::EvalScalar(eval_scalar) => eval_scalar
+                .items
+                .iter()
+                .any(|item| self.is_secure_view_column(item.index)),
+            _ => false,
+        }
+    }
+
     pub fn set_table_row_id_index(&mut self, table_index: IndexType, row_id_index: IndexType) {
         self.table_row_id_index.insert(table_index, row_id_index);
     }
@@ -325,6 +361,24 @@ impl Metadata {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
 
+    pub fn add_materialized_views(
+        &mut self,
+        table_index: IndexType,
+        materialized_views: Vec<MaterializedViewEntry>,
+    ) {
+        self.materialized_views
+            .entry(table_index)
+            .or_default()
+            .extend(materialized_views);
+    }
+
+    pub fn get_materialized_views(&self, table_index: IndexType) -> &[MaterializedViewEntry] {
+        self.materialized_views
+            .get(&table_index)
+            .map(|v| v.as_slice())
+            .unwrap_or_default()
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn add_table(
         &mut self,
@@ -454,6 +508,22 @@ impl Metadata {
     }
 }
 
+/// A materialized view that is up to date with its base table, or stale within the
+/// allowed bound, queries of the base table can be rewritten to read the view instead.
+#[derive(Clone, Debug)]
+pub struct MaterializedViewEntry {
+    /// `<database>.<view>`
+    pub name: String,
+    /// The bound query of the view.
+    pub query: SExpr,
+    /// The output columns of the query, in the order of the columns of the view.
+    pub query_columns: Vec<IndexType>,
+    /// The scan of the table that keeps the result of the view.
+    pub scan: SExpr,
+    /// The columns of the scan, in the same order as `query_columns`.
+    pub scan_columns: Vec<IndexType>,
+}
+
 #[derive(Clone)]
 pub struct TableEntry {
     catalog: String,
@@ -15,6 +15,7 @@
 mod bloom_index;
 mod format;
 mod metadata;
+mod plan_cache;
 #[allow(clippy::module_inception)]
 mod planner;
 mod semantic;
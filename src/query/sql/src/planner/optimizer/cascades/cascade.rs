@@ -80,6 +80,13 @@ impl CascadesOptimizer {
         })
     }
 
+    pub fn with_disabled_rules(mut self, disabled_rules: &RuleSet) -> Self {
+        for rule_id in disabled_rules.iter() {
+            self.explore_rule_set.remove(&rule_id);
+        }
+        self
+    }
+
     fn init(&mut self, expression: SExpr) -> Result<()> {
         self.memo.init(expression)?;
 
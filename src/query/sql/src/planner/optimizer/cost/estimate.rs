@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+
+use super::Cost;
+use super::CostModel;
+use super::DefaultCostModel;
+use crate::optimizer::statistics::CollectStatisticsOptimizer;
+use crate::optimizer::Memo;
+use crate::optimizer::SExpr;
+use crate::MetadataRef;
+
+/// Estimate the cost of computing an unoptimized plan with the default cost model, from the
+/// statistics of the tables it reads, and the number of rows it outputs.
+///
+/// Returns `None` if the plan contains an operator the cost model can't handle.
+pub async fn estimate_cost(
+    ctx: Arc<dyn TableContext>,
+    metadata: MetadataRef,
+    s_expr: &SExpr,
+) -> Result<Option<(Cost, f64)>> {
+    let s_expr = CollectStatisticsOptimizer::new(ctx.clone(), metadata)
+        .run(s_expr)
+        .await?;
+    let mut memo = Memo::create();
+    if memo.init(s_expr).is_err() {
+        return Ok(None);
+    }
+
+    let cost_model = DefaultCostModel::new(ctx)?;
+    let mut cost = Cost(0.0);
+    for group in memo.groups.iter() {
+        for m_expr in group.m_exprs.iter() {
+            match cost_model.compute_cost(&memo, m_expr) {
+                Ok(m_expr_cost) => cost += m_expr_cost,
+                Err(_) => return Ok(None),
+            }
+        }
+    }
+    let cardinality = memo.root().map_or(0.0, |root| root.stat_info.cardinality);
+    Ok(Some((cost, cardinality)))
+}
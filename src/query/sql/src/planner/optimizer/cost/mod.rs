@@ -15,8 +15,10 @@
 #[allow(clippy::module_inception)]
 mod cost;
 mod cost_model;
+mod estimate;
 
 pub use cost::Cost;
 pub use cost::CostContext;
 pub use cost::CostModel;
 pub use cost_model::DefaultCostModel;
+pub use estimate::estimate_cost;
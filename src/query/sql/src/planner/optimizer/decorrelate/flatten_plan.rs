@@ -19,9 +19,13 @@ use std::sync::Arc;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
 
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
+use crate::binder::WindowOrderByInfo;
 use crate::optimizer::decorrelate::subquery_rewriter::FlattenInfo;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
@@ -31,25 +35,36 @@ use crate::plans::Aggregate;
 use crate::plans::AggregateFunction;
 use crate::plans::AggregateMode;
 use crate::plans::BoundColumnRef;
+use crate::plans::ConstantExpr;
 use crate::plans::EvalScalar;
 use crate::plans::ExpressionScan;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinEquiCondition;
 use crate::plans::JoinType;
+use crate::plans::LagLeadFunction;
+use crate::plans::Limit;
+use crate::plans::NthValueFunction;
 use crate::plans::ProjectSet;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Sort;
+use crate::plans::SortItem;
 use crate::plans::SrfItem;
 use crate::plans::UnionAll;
 use crate::plans::Window;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowFuncType;
 use crate::BaseTableColumn;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
 use crate::IndexType;
+use crate::Metadata;
 use crate::TableInternalColumn;
 use crate::VirtualColumn;
 
@@ -67,99 +82,20 @@ impl SubqueryRewriter {
             if !need_cross_join {
                 return Ok(plan.clone());
             }
-            // Construct a Scan plan by correlated columns.
-            // Finally, generate a cross join, so we finish flattening the subquery.
-            let mut metadata = self.metadata.write();
-            // Currently, we don't support left plan's from clause contains subquery.
-            // Such as: select t2.a from (select a + 1 as a from t) as t2 where (select sum(a) from t as t1 where t1.a < t2.a) = 1;
-            let table_index = metadata
-                .table_index_by_column_indexes(correlated_columns)
-                .unwrap();
-            let mut data_types = Vec::with_capacity(correlated_columns.len());
-            let mut scalar_items = vec![];
-            let mut scan_columns = ColumnSet::new();
-            for correlated_column in correlated_columns.iter() {
-                let column_entry = metadata.column(*correlated_column).clone();
-                let name = column_entry.name();
-                let data_type = column_entry.data_type();
-                data_types.push(data_type.clone());
-                let derived_col = metadata.add_derived_column(name.to_string(), data_type, None);
-                self.derived_columns.insert(*correlated_column, derived_col);
-                if let ColumnEntry::DerivedColumn(derived_column) = &column_entry {
-                    if let Some(scalar) = &derived_column.scalar_expr {
-                        // Replace columns in `scalar` to derived columns.
-                        let mut scalar = scalar.clone();
-                        for col in scalar.used_columns().iter() {
-                            if let Some(new_col) = self.derived_columns.get(col) {
-                                scalar.replace_column(*col, *new_col)?;
-                            } else {
-                                scan_columns.insert(*col);
-                            }
-                        }
-                        scalar_items.push(ScalarItem {
-                            scalar,
-                            index: derived_col,
-                        });
-                    }
-                } else {
-                    scan_columns.insert(derived_col);
-                }
-            }
-            let mut scan = SExpr::create_leaf(Arc::new(
-                Scan {
-                    table_index,
-                    columns: scan_columns,
-                    ..Default::default()
-                }
-                .into(),
-            ));
-            if !scalar_items.is_empty() {
-                // Wrap `EvalScalar` to `scan`.
-                scan = SExpr::create_unary(
-                    Arc::new(
-                        EvalScalar {
-                            items: scalar_items,
-                        }
-                        .into(),
-                    ),
-                    Arc::new(scan),
-                );
-            }
-            if self.ctx.get_cluster().is_empty() {
-                // Wrap logical get with distinct to eliminate duplicates rows.
-                let mut group_items = Vec::with_capacity(self.derived_columns.len());
-                for (index, column_index) in self.derived_columns.values().cloned().enumerate() {
-                    group_items.push(ScalarItem {
-                        scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
-                            span: None,
-                            column: ColumnBindingBuilder::new(
-                                "".to_string(),
-                                column_index,
-                                Box::new(data_types[index].clone()),
-                                Visibility::Visible,
-                            )
-                            .table_index(Some(table_index))
-                            .build(),
-                        }),
-                        index: column_index,
-                    });
+            // Build the domain of correlated columns, which is the distinct values of them.
+            // Prefer scanning the outer table, if the correlated columns can't be produced
+            // by a single table (e.g. aggregated or joined columns), take them from the outer plan.
+            let domain = match self.outer_plan.clone() {
+                Some(outer_plan)
+                    if !self.can_scan_correlated_columns(correlated_columns)
+                        && outer_plan_contains(&outer_plan, correlated_columns)? =>
+                {
+                    self.build_outer_plan_domain(&outer_plan, correlated_columns)?
                 }
-                scan = SExpr::create_unary(
-                    Arc::new(
-                        Aggregate {
-                            mode: AggregateMode::Initial,
-                            group_items,
-                            aggregate_functions: vec![],
-                            from_distinct: false,
-                            limit: None,
-                            grouping_sets: None,
-                        }
-                        .into(),
-                    ),
-                    Arc::new(scan),
-                );
-            }
+                _ => self.build_scan_domain(correlated_columns)?,
+            };
 
+            // Finally, generate a cross join, so we finish flattening the subquery.
             let cross_join = Join {
                 equi_conditions: JoinEquiCondition::new_conditions(vec![], vec![], vec![]),
                 non_equi_conditions: vec![],
@@ -175,7 +111,7 @@ impl SubqueryRewriter {
 
             return Ok(SExpr::create_binary(
                 Arc::new(cross_join),
-                Arc::new(scan),
+                Arc::new(domain),
                 Arc::new(plan.clone()),
             ));
         }
@@ -220,9 +156,13 @@ impl SubqueryRewriter {
                 need_cross_join,
             ),
 
-            RelOperator::Limit(_) => {
-                self.flatten_limit(plan, correlated_columns, flatten_info, need_cross_join)
-            }
+            RelOperator::Limit(limit) => self.flatten_limit(
+                plan,
+                limit,
+                correlated_columns,
+                flatten_info,
+                need_cross_join,
+            ),
 
             RelOperator::UnionAll(op) => {
                 self.flatten_union_all(op, plan, correlated_columns, flatten_info, need_cross_join)
@@ -242,6 +182,192 @@ impl SubqueryRewriter {
         }
     }
 
+    // Construct a Scan plan by correlated columns.
+    fn build_scan_domain(&mut self, correlated_columns: &ColumnSet) -> Result<SExpr> {
+        let mut metadata = self.metadata.write();
+        let table_index = metadata
+            .table_index_by_column_indexes(correlated_columns)
+            .ok_or_else(|| {
+                ErrorCode::Internal("Correlated columns don't belong to any table")
+            })?;
+        let mut data_types = Vec::with_capacity(correlated_columns.len());
+        let mut scalar_items = vec![];
+        let mut scan_columns = ColumnSet::new();
+        for correlated_column in correlated_columns.iter() {
+            let column_entry = metadata.column(*correlated_column).clone();
+            let name = column_entry.name();
+            let data_type = column_entry.data_type();
+            data_types.push(data_type.clone());
+            let derived_col = metadata.add_derived_column(name.to_string(), data_type, None);
+            self.derived_columns.insert(*correlated_column, derived_col);
+            if let ColumnEntry::DerivedColumn(derived_column) = &column_entry {
+                if let Some(scalar) = &derived_column.scalar_expr {
+                    // Replace columns in `scalar` to derived columns.
+                    let mut scalar = scalar.clone();
+                    for col in scalar.used_columns().iter() {
+                        if let Some(new_col) = self.derived_columns.get(col) {
+                            scalar.replace_column(*col, *new_col)?;
+                        } else {
+                            scan_columns.insert(*col);
+                        }
+                    }
+                    scalar_items.push(ScalarItem {
+                        scalar,
+                        index: derived_col,
+                    });
+                }
+            } else {
+                scan_columns.insert(derived_col);
+            }
+        }
+        let mut scan = SExpr::create_leaf(Arc::new(
+            Scan {
+                table_index,
+                columns: scan_columns,
+                ..Default::default()
+            }
+            .into(),
+        ));
+        if !scalar_items.is_empty() {
+            // Wrap `EvalScalar` to `scan`.
+            scan = SExpr::create_unary(
+                Arc::new(
+                    EvalScalar {
+                        items: scalar_items,
+                    }
+                    .into(),
+                ),
+                Arc::new(scan),
+            );
+        }
+        if self.ctx.get_cluster().is_empty() {
+            // Wrap logical get with distinct to eliminate duplicates rows.
+            let mut group_items = Vec::with_capacity(self.derived_columns.len());
+            for (index, column_index) in self.derived_columns.values().cloned().enumerate() {
+                group_items.push(ScalarItem {
+                    scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
+                        span: None,
+                        column: ColumnBindingBuilder::new(
+                            "".to_string(),
+                            column_index,
+                            Box::new(data_types[index].clone()),
+                            Visibility::Visible,
+                        )
+                        .table_index(Some(table_index))
+                        .build(),
+                    }),
+                    index: column_index,
+                });
+            }
+            scan = SExpr::create_unary(
+                Arc::new(
+                    Aggregate {
+                        mode: AggregateMode::Initial,
+                        group_items,
+                        aggregate_functions: vec![],
+                        from_distinct: false,
+                        limit: None,
+                        grouping_sets: None,
+                    }
+                    .into(),
+                ),
+                Arc::new(scan),
+            );
+        }
+        Ok(scan)
+    }
+
+    fn can_scan_correlated_columns(&self, correlated_columns: &ColumnSet) -> bool {
+        let metadata = self.metadata.read();
+        let Some(table_index) = metadata.table_index_by_column_indexes(correlated_columns) else {
+            return false;
+        };
+        let is_table_column = |index: &IndexType| {
+            matches!(
+                metadata.column(*index),
+                ColumnEntry::BaseTableColumn(column) if column.table_index == table_index
+            )
+        };
+        correlated_columns
+            .iter()
+            .all(|index| match metadata.column(*index) {
+                ColumnEntry::DerivedColumn(DerivedColumn {
+                    scalar_expr: Some(scalar),
+                    ..
+                }) => scalar
+                    .used_columns()
+                    .iter()
+                    .all(|col| is_table_column(col) || self.derived_columns.contains_key(col)),
+                _ => is_table_column(index),
+            })
+    }
+
+    // Construct the domain by the distinct correlated columns of the outer plan.
+    fn build_outer_plan_domain(
+        &mut self,
+        outer_plan: &SExpr,
+        correlated_columns: &ColumnSet,
+    ) -> Result<SExpr> {
+        let mut correlated_columns = correlated_columns.iter().copied().collect::<Vec<_>>();
+        correlated_columns.sort();
+
+        let mut metadata = self.metadata.write();
+        let mut scalar_items = Vec::with_capacity(correlated_columns.len());
+        let mut group_items = Vec::with_capacity(correlated_columns.len());
+        for correlated_column in correlated_columns {
+            let column_entry = metadata.column(correlated_column).clone();
+            let name = column_entry.name();
+            let data_type = column_entry.data_type();
+            let derived_col = metadata.add_derived_column(name.clone(), data_type.clone(), None);
+            self.derived_columns.insert(correlated_column, derived_col);
+
+            let column_ref = |index: IndexType| {
+                ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: ColumnBindingBuilder::new(
+                        name.clone(),
+                        index,
+                        Box::new(data_type.clone()),
+                        Visibility::Visible,
+                    )
+                    .build(),
+                })
+            };
+            scalar_items.push(ScalarItem {
+                scalar: column_ref(correlated_column),
+                index: derived_col,
+            });
+            group_items.push(ScalarItem {
+                scalar: column_ref(derived_col),
+                index: derived_col,
+            });
+        }
+
+        let eval_scalar = SExpr::create_unary(
+            Arc::new(
+                EvalScalar {
+                    items: scalar_items,
+                }
+                .into(),
+            ),
+            Arc::new(outer_plan.clone()),
+        );
+        Ok(SExpr::create_unary(
+            Arc::new(
+                Aggregate {
+                    mode: AggregateMode::Initial,
+                    group_items,
+                    aggregate_functions: vec![],
+                    from_distinct: false,
+                    limit: None,
+                    grouping_sets: None,
+                }
+                .into(),
+            ),
+            Arc::new(eval_scalar),
+        ))
+    }
+
     fn flatten_eval_scalar(
         &mut self,
         plan: &SExpr,
@@ -670,23 +796,141 @@ impl SubqueryRewriter {
     fn flatten_limit(
         &mut self,
         plan: &SExpr,
+        limit: &Limit,
         correlated_columns: &ColumnSet,
         flatten_info: &mut FlattenInfo,
         need_cross_join: bool,
     ) -> Result<SExpr> {
-        // Currently, we don't support limit contain subquery.
         let flatten_plan = self.flatten_plan(
             plan.child(0)?,
             correlated_columns,
             flatten_info,
             need_cross_join,
         )?;
-        Ok(SExpr::create_unary(
-            Arc::new(plan.plan().clone()),
+
+        let partition_by = self.correlated_partition_items(correlated_columns);
+        if partition_by.is_empty() {
+            return Ok(SExpr::create_unary(
+                Arc::new(plan.plan().clone()),
+                Arc::new(flatten_plan),
+            ));
+        }
+
+        // The limit applies to the rows of every outer row, not to all the rows of the flattened plan.
+        // Number the rows of every outer row in the order of the sort below the limit if there is one,
+        // and keep the rows within the offset and the limit.
+        let sort_items = match plan.child(0)?.plan() {
+            RelOperator::Sort(sort) => sort.items.clone(),
+            _ => vec![],
+        };
+        let (row_number, order_by) = {
+            let mut metadata = self.metadata.write();
+            let row_number = metadata.add_derived_column(
+                "row_number".to_string(),
+                DataType::Number(NumberDataType::UInt64),
+                None,
+            );
+            let order_by = sort_items
+                .iter()
+                .map(|item| WindowOrderByInfo {
+                    order_by_item: ScalarItem {
+                        scalar: column_ref(&metadata, item.index),
+                        index: item.index,
+                    },
+                    asc: Some(item.asc),
+                    nulls_first: Some(item.nulls_first),
+                })
+                .collect::<Vec<_>>();
+            (row_number, order_by)
+        };
+
+        let mut window_sort_items = partition_by
+            .iter()
+            .map(|item| SortItem {
+                index: item.index,
+                asc: true,
+                nulls_first: false,
+            })
+            .collect::<Vec<_>>();
+        window_sort_items.extend(sort_items);
+        let window_sort = SExpr::create_unary(
+            Arc::new(
+                Sort {
+                    items: window_sort_items,
+                    limit: None,
+                    after_exchange: None,
+                    pre_projection: None,
+                    window_partition: partition_by.clone(),
+                }
+                .into(),
+            ),
             Arc::new(flatten_plan),
+        );
+        let window = SExpr::create_unary(
+            Arc::new(
+                Window {
+                    span: None,
+                    index: row_number,
+                    function: WindowFuncType::RowNumber,
+                    arguments: vec![],
+                    partition_by,
+                    order_by,
+                    frame: WindowFuncFrame {
+                        units: WindowFuncFrameUnits::Rows,
+                        start_bound: WindowFuncFrameBound::Preceding(None),
+                        end_bound: WindowFuncFrameBound::CurrentRow,
+                    },
+                    limit: None,
+                }
+                .into(),
+            ),
+            Arc::new(window_sort),
+        );
+
+        let row_number = column_ref(&self.metadata.read(), row_number);
+        let bound = |func_name: &str, value: usize| {
+            ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: func_name.to_string(),
+                params: vec![],
+                arguments: vec![
+                    row_number.clone(),
+                    ScalarExpr::ConstantExpr(ConstantExpr {
+                        span: None,
+                        value: Scalar::Number(NumberScalar::UInt64(value as u64)),
+                    }),
+                ],
+            })
+        };
+        let mut predicates = vec![];
+        if limit.offset > 0 {
+            predicates.push(bound("gt", limit.offset));
+        }
+        if let Some(n) = limit.limit {
+            predicates.push(bound("lte", limit.offset + n));
+        }
+        if predicates.is_empty() {
+            return Ok(window);
+        }
+        Ok(SExpr::create_unary(
+            Arc::new(Filter { predicates }.into()),
+            Arc::new(window),
         ))
     }
 
+    // The derived columns of the correlated columns, which identify the outer row of every row.
+    fn correlated_partition_items(&self, correlated_columns: &ColumnSet) -> Vec<ScalarItem> {
+        let metadata = self.metadata.read();
+        correlated_columns
+            .iter()
+            .filter_map(|column| self.derived_columns.get(column))
+            .map(|derived_column| ScalarItem {
+                scalar: column_ref(&metadata, *derived_column),
+                index: *derived_column,
+            })
+            .collect()
+    }
+
     fn flatten_window(
         &mut self,
         plan: &SExpr,
@@ -694,18 +938,62 @@ impl SubqueryRewriter {
         correlated_columns: &ColumnSet,
         flatten_info: &mut FlattenInfo,
     ) -> Result<SExpr> {
-        if op
-            .used_columns()?
-            .iter()
-            .any(|index| correlated_columns.contains(index))
-        {
-            return Err(ErrorCode::Internal(
-                "correlated columns in window functions not supported",
-            ));
-        }
         let flatten_plan =
             self.flatten_plan(plan.child(0)?, correlated_columns, flatten_info, true)?;
-        let mut partition_by = op.partition_by.clone();
+
+        // Correlated columns used by the window are replaced by their derived columns.
+        let function = match &op.function {
+            WindowFuncType::Aggregate(agg) => {
+                let scalar = ScalarExpr::AggregateFunction(agg.clone());
+                match self.flatten_scalar(&scalar, correlated_columns)? {
+                    ScalarExpr::AggregateFunction(agg) => WindowFuncType::Aggregate(agg),
+                    _ => return Err(ErrorCode::Internal("Invalid window aggregate function")),
+                }
+            }
+            WindowFuncType::LagLead(func) => WindowFuncType::LagLead(LagLeadFunction {
+                is_lag: func.is_lag,
+                arg: Box::new(self.flatten_scalar(&func.arg, correlated_columns)?),
+                offset: func.offset,
+                default: match &func.default {
+                    Some(default) => {
+                        Some(Box::new(self.flatten_scalar(default, correlated_columns)?))
+                    }
+                    None => None,
+                },
+                return_type: func.return_type.clone(),
+            }),
+            WindowFuncType::NthValue(func) => WindowFuncType::NthValue(NthValueFunction {
+                n: func.n,
+                arg: Box::new(self.flatten_scalar(&func.arg, correlated_columns)?),
+                return_type: func.return_type.clone(),
+            }),
+            function => function.clone(),
+        };
+        let mut arguments = Vec::with_capacity(op.arguments.len());
+        for item in op.arguments.iter() {
+            arguments.push(ScalarItem {
+                scalar: self.flatten_scalar(&item.scalar, correlated_columns)?,
+                index: item.index,
+            });
+        }
+        let mut order_by = Vec::with_capacity(op.order_by.len());
+        for item in op.order_by.iter() {
+            order_by.push(WindowOrderByInfo {
+                order_by_item: ScalarItem {
+                    scalar: self.flatten_scalar(&item.order_by_item.scalar, correlated_columns)?,
+                    index: item.order_by_item.index,
+                },
+                asc: item.asc,
+                nulls_first: item.nulls_first,
+            });
+        }
+        let mut partition_by = Vec::with_capacity(op.partition_by.len());
+        for item in op.partition_by.iter() {
+            partition_by.push(ScalarItem {
+                scalar: self.flatten_scalar(&item.scalar, correlated_columns)?,
+                index: item.index,
+            });
+        }
         for derived_column in self.derived_columns.values() {
             let column_binding = {
                 let metadata = self.metadata.read();
@@ -745,10 +1033,10 @@ impl SubqueryRewriter {
                 Window {
                     span: op.span,
                     index: op.index,
-                    function: op.function.clone(),
-                    arguments: op.arguments.clone(),
+                    function,
+                    arguments,
                     partition_by,
-                    order_by: op.order_by.clone(),
+                    order_by,
                     frame: op.frame.clone(),
                     limit: op.limit,
                 }
@@ -809,3 +1097,22 @@ impl SubqueryRewriter {
         Ok(plan.clone())
     }
 }
+
+fn column_ref(metadata: &Metadata, index: IndexType) -> ScalarExpr {
+    let column_entry = metadata.column(index);
+    ScalarExpr::BoundColumnRef(BoundColumnRef {
+        span: None,
+        column: ColumnBindingBuilder::new(
+            column_entry.name(),
+            index,
+            Box::new(column_entry.data_type()),
+            Visibility::Visible,
+        )
+        .build(),
+    })
+}
+
+fn outer_plan_contains(outer_plan: &SExpr, columns: &ColumnSet) -> Result<bool> {
+    let prop = RelExpr::with_s_expr(outer_plan).derive_relational_prop()?;
+    Ok(columns.is_subset(&prop.output_columns))
+}
@@ -74,6 +74,8 @@ pub struct SubqueryRewriter {
     pub(crate) metadata: MetadataRef,
     pub(crate) derived_columns: HashMap<IndexType, IndexType>,
     pub(crate) binder: Option<Binder>,
+    // The input of the operator which contains the correlated subquery being decorrelated.
+    pub(crate) outer_plan: Option<SExpr>,
 }
 
 impl SubqueryRewriter {
@@ -83,6 +85,7 @@ impl SubqueryRewriter {
             metadata,
             derived_columns: Default::default(),
             binder,
+            outer_plan: None,
         }
     }
 
@@ -262,12 +265,15 @@ impl SubqueryRewriter {
                         is_conjunctive_predicate,
                     )?
                 } else {
-                    self.try_decorrelate_subquery(
+                    let outer_plan = self.outer_plan.replace(s_expr.clone());
+                    let res = self.try_decorrelate_subquery(
                         s_expr,
                         &subquery,
                         &mut flatten_info,
                         is_conjunctive_predicate,
-                    )?
+                    );
+                    self.outer_plan = outer_plan;
+                    res?
                 };
 
                 // If we unnest the subquery into a simple join, then we can replace the
@@ -642,17 +648,15 @@ impl SubqueryRewriter {
         .into();
 
         // For some cases, empty result set will be occur, we should return null instead of empty set.
-        // So let wrap an expression: `if(count()=0, null, any(subquery.output_column)`
+        // So let wrap an expression: `if(assert_single_row(count())=0, null, any(subquery.output_column)`,
+        // `assert_single_row` will raise an error at runtime if the subquery returns more than one row.
         let count_func = ScalarExpr::AggregateFunction(AggregateFunction {
             func_name: "count".to_string(),
             distinct: false,
             params: vec![],
-            args: vec![ScalarExpr::BoundColumnRef(BoundColumnRef {
-                span: None,
-                column: subquery.output_column.clone(),
-            })],
+            args: vec![],
             return_type: Box::new(DataType::Number(NumberDataType::UInt64)),
-            display_name: "count".to_string(),
+            display_name: "count(*)".to_string(),
         });
         let any_func = ScalarExpr::AggregateFunction(AggregateFunction {
             func_name: "any".to_string(),
@@ -734,12 +738,18 @@ impl SubqueryRewriter {
             )
             .build(),
         });
+        let check_func = ScalarExpr::FunctionCall(FunctionCall {
+            span: subquery.span,
+            func_name: "assert_single_row".to_string(),
+            params: vec![],
+            arguments: vec![count_col_ref],
+        });
         let eq_func = ScalarExpr::FunctionCall(FunctionCall {
             span: None,
             func_name: "eq".to_string(),
             params: vec![],
             arguments: vec![
-                count_col_ref,
+                check_func,
                 ScalarExpr::ConstantExpr(ConstantExpr {
                     span: None,
                     value: Scalar::Number(NumberScalar::UInt8(0)),
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 
+use super::semi_join_reduction::SemiJoinReductionOptimizer;
 use super::sort_and_limit::SortAndLimitPushDownOptimizer;
 use crate::optimizer::property::require_property;
 use crate::optimizer::Distribution;
@@ -30,7 +31,14 @@ pub fn optimize_distributed_query(ctx: Arc<dyn TableContext>, s_expr: &SExpr) ->
     let required = RequiredProperty {
         distribution: Distribution::Any,
     };
-    let result = require_property(ctx, &required, s_expr)?;
+    let mut result = require_property(ctx.clone(), &required, s_expr)?;
+
+    let semi_join_reduction_ratio = ctx.get_settings().get_semi_join_reduction_ratio()?;
+    if semi_join_reduction_ratio > 0 {
+        let semi_join_reduction_optimizer =
+            SemiJoinReductionOptimizer::create(semi_join_reduction_ratio);
+        result = semi_join_reduction_optimizer.optimize(&result)?;
+    }
 
     let sort_and_limit_optimizer = SortAndLimitPushDownOptimizer::create();
     let mut result = sort_and_limit_optimizer.optimize(&result)?;
@@ -15,8 +15,10 @@
 #[allow(clippy::module_inception)]
 mod distributed;
 mod distributed_merge;
+mod semi_join_reduction;
 mod sort_and_limit;
 
 pub use distributed::optimize_distributed_query;
 pub use distributed_merge::MergeOptimizer;
+pub use semi_join_reduction::SemiJoinReductionOptimizer;
 pub use sort_and_limit::SortAndLimitPushDownOptimizer;
@@ -0,0 +1,165 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateMode;
+use crate::plans::Exchange;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+
+/// Pre-filter the probe side of a hash shuffle join with the join keys of the build side,
+/// so that the probe rows without a match are dropped before being shuffled.
+///
+/// Input:
+///   Join
+///   ├── Exchange(Hash)
+///   │   └── Probe
+///   └── Exchange(Hash)
+///       └── Build
+/// Output:
+///   Join
+///   ├── Exchange(Hash)
+///   │   └── Join(LeftSemi)
+///   │       ├── Probe
+///   │       └── Exchange(Broadcast)
+///   │           └── Aggregate(Final, group by build keys)
+///   │               └── Aggregate(Partial, group by build keys)
+///   │                   └── Build
+///   └── Exchange(Hash)
+///       └── Build
+///
+/// Only the distinct join keys of the build rows on each node are broadcast, not the build rows.
+/// The build side is still evaluated twice, so the rewrite is only applied if the estimated rows
+/// of the probe side are at least `ratio` times of the build side, and the build keys are columns.
+pub struct SemiJoinReductionOptimizer {
+    ratio: f64,
+}
+
+impl SemiJoinReductionOptimizer {
+    pub fn create(ratio: u64) -> Self {
+        Self {
+            ratio: ratio as f64,
+        }
+    }
+
+    #[recursive::recursive]
+    pub fn optimize(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let mut replaced_children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children.iter() {
+            let new_child = self.optimize(child)?;
+            replaced_children.push(Arc::new(new_child));
+        }
+        let new_sexpr = s_expr.replace_children(replaced_children);
+        self.apply(&new_sexpr)
+    }
+
+    fn apply(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let RelOperator::Join(join) = s_expr.plan.as_ref() else {
+            return Ok(s_expr.clone());
+        };
+        if !matches!(join.join_type, JoinType::Inner | JoinType::LeftSemi)
+            || join.equi_conditions.is_empty()
+            || join.has_null_equi_condition()
+        {
+            return Ok(s_expr.clone());
+        }
+
+        let probe_exchange = s_expr.child(0)?;
+        let build_exchange = s_expr.child(1)?;
+        if !matches!(
+            probe_exchange.plan.as_ref(),
+            RelOperator::Exchange(Exchange::Hash(_))
+        ) || !matches!(
+            build_exchange.plan.as_ref(),
+            RelOperator::Exchange(Exchange::Hash(_))
+        ) {
+            return Ok(s_expr.clone());
+        }
+
+        let probe = probe_exchange.child(0)?;
+        let build = build_exchange.child(0)?;
+        let probe_cardinality = RelExpr::with_s_expr(probe)
+            .derive_cardinality()?
+            .cardinality;
+        let build_cardinality = RelExpr::with_s_expr(build)
+            .derive_cardinality()?
+            .cardinality;
+        if probe_cardinality < build_cardinality * self.ratio {
+            return Ok(s_expr.clone());
+        }
+
+        let mut group_items: Vec<ScalarItem> = Vec::with_capacity(join.equi_conditions.len());
+        for condition in join.equi_conditions.iter() {
+            let ScalarExpr::BoundColumnRef(column) = &condition.right else {
+                return Ok(s_expr.clone());
+            };
+            let index = column.column.index;
+            if group_items.iter().all(|item| item.index != index) {
+                group_items.push(ScalarItem {
+                    scalar: condition.right.clone(),
+                    index,
+                });
+            }
+        }
+        let distinct_keys = |mode| Aggregate {
+            mode,
+            group_items: group_items.clone(),
+            aggregate_functions: vec![],
+            from_distinct: true,
+            limit: None,
+            grouping_sets: None,
+        };
+        let build_keys = SExpr::create_unary(
+            Arc::new(distinct_keys(AggregateMode::Final).into()),
+            Arc::new(SExpr::create_unary(
+                Arc::new(distinct_keys(AggregateMode::Partial).into()),
+                Arc::new(build.clone()),
+            )),
+        );
+
+        let reduction = Join {
+            equi_conditions: join.equi_conditions.clone(),
+            non_equi_conditions: vec![],
+            join_type: JoinType::LeftSemi,
+            marker_index: None,
+            from_correlated_subquery: false,
+            need_hold_hash_table: false,
+            is_lateral: false,
+            single_to_inner: None,
+            build_side_cache_info: None,
+        };
+        let reduced_probe = SExpr::create_binary(
+            Arc::new(reduction.into()),
+            Arc::new(probe.clone()),
+            Arc::new(SExpr::create_unary(
+                Arc::new(Exchange::Broadcast.into()),
+                Arc::new(build_keys),
+            )),
+        );
+        let probe_exchange = probe_exchange.replace_children(vec![Arc::new(reduced_probe)]);
+        Ok(s_expr.replace_children(vec![
+            Arc::new(probe_exchange),
+            Arc::new(build_exchange.clone()),
+        ]))
+    }
+}
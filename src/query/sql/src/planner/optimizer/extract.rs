@@ -24,6 +24,7 @@ use crate::optimizer::SExpr;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
+use crate::Metadata;
 
 /// A matcher used to describe a pattern to be matched.
 pub enum Matcher {
@@ -91,6 +92,33 @@ impl Matcher {
         }
     }
 
+    /// Check if the pattern matched on the `SExpr` reaches through a secure view barrier,
+    /// that is the barrier is matched below the root of the pattern or together with its
+    /// children. A rule applied on such a match could move the outer operators into the view.
+    pub fn crosses_secure_view(&self, s_expr: &SExpr, metadata: &Metadata) -> bool {
+        self.crosses_barrier(s_expr, metadata, true)
+    }
+
+    #[recursive::recursive]
+    fn crosses_barrier(&self, s_expr: &SExpr, metadata: &Metadata, is_root: bool) -> bool {
+        let children = match self {
+            Matcher::MatchOp { children, .. } | Matcher::MatchFn { children, .. } => children,
+            Matcher::Leaf => return false,
+        };
+        if metadata.is_secure_view_barrier(s_expr.plan())
+            && (!is_root || children.iter().any(|child| !matches!(child, Matcher::Leaf)))
+        {
+            return true;
+        }
+        s_expr
+            .children
+            .iter()
+            .zip(children.iter())
+            .any(|(child_expr, child_matcher)| {
+                child_matcher.crosses_barrier(child_expr, metadata, false)
+            })
+    }
+
     /// Check if the `RelOperator` can be matched by the `Matcher`.
     pub fn matches_op(&self, op: &RelOperator) -> bool {
         match self {
@@ -0,0 +1,199 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::split_conjunctions;
+use crate::optimizer::SExpr;
+use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::FunctionCall;
+use crate::plans::RelOperator;
+use crate::ScalarExpr;
+
+// The FoldConstantOptimizer folds the constant expressions and simplifies the predicates of filters,
+// so that the later rules, e.g. pushing down filters and pruning, see canonical predicates:
+// - Fold the deterministic expressions of constants, e.g. `a > 1 + 1` => `a > 2`
+// - Simplify the tautologies, e.g. `a AND true` => `a`, `a OR true` => `true`, `NOT NOT a` => `a`
+// - Flatten the nested casts, e.g. `CAST(CAST(a AS INT) AS INT)` => `CAST(a AS INT)`
+// - Normalize the comparisons with the constant on the right, e.g. `1 < a` => `a > 1`
+pub struct FoldConstantOptimizer {
+    func_ctx: FunctionContext,
+}
+
+impl FoldConstantOptimizer {
+    pub fn new(func_ctx: FunctionContext) -> Self {
+        FoldConstantOptimizer { func_ctx }
+    }
+
+    #[recursive::recursive]
+    pub fn run(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            children.push(Arc::new(self.run(child)?));
+        }
+        let s_expr = s_expr.replace_children(children);
+
+        if let RelOperator::Filter(filter) = s_expr.plan() {
+            let mut filter = filter.clone();
+            let mut predicates = Vec::with_capacity(filter.predicates.len());
+            for predicate in filter.predicates.iter() {
+                predicates.extend(split_conjunctions(&self.fold(predicate)?));
+            }
+            filter.predicates = predicates;
+            return Ok(s_expr.replace_plan(Arc::new(filter.into())));
+        }
+        Ok(s_expr)
+    }
+
+    fn fold(&self, scalar: &ScalarExpr) -> Result<ScalarExpr> {
+        match scalar {
+            ScalarExpr::FunctionCall(func) => {
+                let arguments = func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.fold(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                self.simplify_function(FunctionCall {
+                    arguments,
+                    ..func.clone()
+                })
+            }
+            ScalarExpr::CastExpr(cast) => {
+                let argument = self.fold(&cast.argument)?;
+                if argument.data_type()? == *cast.target_type {
+                    return Ok(argument);
+                }
+                if let ScalarExpr::CastExpr(inner) = &argument {
+                    if inner.target_type == cast.target_type && inner.is_try == cast.is_try {
+                        return Ok(argument);
+                    }
+                }
+                self.fold_constant(
+                    CastExpr {
+                        argument: Box::new(argument),
+                        ..cast.clone()
+                    }
+                    .into(),
+                )
+            }
+            _ => Ok(scalar.clone()),
+        }
+    }
+
+    fn simplify_function(&self, mut func: FunctionCall) -> Result<ScalarExpr> {
+        match func.func_name.as_str() {
+            "and" | "and_filters" => {
+                if func.arguments.iter().any(|arg| is_boolean(arg, false)) {
+                    return Ok(boolean(false));
+                }
+                func.arguments.retain(|arg| !is_boolean(arg, true));
+                return Ok(match func.arguments.len() {
+                    0 => boolean(true),
+                    1 => func.arguments.remove(0),
+                    _ => func.into(),
+                });
+            }
+            "or" => {
+                if func.arguments.iter().any(|arg| is_boolean(arg, true)) {
+                    return Ok(boolean(true));
+                }
+                func.arguments.retain(|arg| !is_boolean(arg, false));
+                return Ok(match func.arguments.len() {
+                    0 => boolean(false),
+                    1 => func.arguments.remove(0),
+                    _ => func.into(),
+                });
+            }
+            "not" => {
+                if let [ScalarExpr::FunctionCall(inner)] = func.arguments.as_slice() {
+                    if inner.func_name == "not" && inner.arguments.len() == 1 {
+                        return Ok(inner.arguments[0].clone());
+                    }
+                }
+            }
+            "eq" | "noteq" | "lt" | "lte" | "gt" | "gte" => {
+                if let [ScalarExpr::ConstantExpr(_), right] = func.arguments.as_slice() {
+                    if !matches!(right, ScalarExpr::ConstantExpr(_)) {
+                        func.arguments.swap(0, 1);
+                        func.func_name = match func.func_name.as_str() {
+                            "lt" => "gt",
+                            "lte" => "gte",
+                            "gt" => "lt",
+                            "gte" => "lte",
+                            name => name,
+                        }
+                        .to_string();
+                    }
+                }
+            }
+            _ => {}
+        }
+        self.fold_constant(func.into())
+    }
+
+    fn fold_constant(&self, scalar: ScalarExpr) -> Result<ScalarExpr> {
+        let foldable = match &scalar {
+            ScalarExpr::FunctionCall(func) => func
+                .arguments
+                .iter()
+                .all(|arg| matches!(arg, ScalarExpr::ConstantExpr(_))),
+            ScalarExpr::CastExpr(cast) => matches!(*cast.argument, ScalarExpr::ConstantExpr(_)),
+            _ => false,
+        };
+        if !foldable {
+            return Ok(scalar);
+        }
+        // Leave the expressions which can not be lowered as they are, e.g. unknown functions.
+        let Ok(expr) = scalar.as_expr() else {
+            return Ok(scalar);
+        };
+        if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return Ok(scalar);
+        }
+        match ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS) {
+            (Expr::Constant { scalar: value, .. }, _) => Ok(ConstantExpr {
+                span: scalar.span(),
+                value,
+            }
+            .into()),
+            _ => Ok(scalar),
+        }
+    }
+}
+
+fn is_boolean(scalar: &ScalarExpr, value: bool) -> bool {
+    matches!(
+        scalar,
+        ScalarExpr::ConstantExpr(ConstantExpr {
+            value: Scalar::Boolean(v),
+            ..
+        }) if *v == value
+    )
+}
+
+fn boolean(value: bool) -> ScalarExpr {
+    ConstantExpr {
+        span: None,
+        value: Scalar::Boolean(value),
+    }
+    .into()
+}
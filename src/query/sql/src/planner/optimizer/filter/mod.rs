@@ -13,11 +13,13 @@
 // limitations under the License.
 
 mod deduplicate_join_condition;
+mod fold_constant;
 mod infer_filter;
 mod normalize_disjunctive_filter;
 mod pull_up_filter;
 
 pub use deduplicate_join_condition::DeduplicateJoinConditionOptimizer;
+pub use fold_constant::FoldConstantOptimizer;
 pub use infer_filter::InferFilterOptimizer;
 pub use infer_filter::JoinProperty;
 pub use normalize_disjunctive_filter::NormalizeDisjunctiveFilterOptimizer;
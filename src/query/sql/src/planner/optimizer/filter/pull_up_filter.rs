@@ -19,6 +19,7 @@ use databend_common_exception::Result;
 use crate::binder::split_conjunctions;
 use crate::optimizer::filter::InferFilterOptimizer;
 use crate::optimizer::filter::NormalizeDisjunctiveFilterOptimizer;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
@@ -71,7 +72,9 @@ impl PullUpFilterOptimizer {
             RelOperator::Join(join) if !join.is_lateral && !join.has_null_equi_condition() => {
                 self.pull_up_join(s_expr, join)
             }
-            RelOperator::EvalScalar(eval_scalar) => self.pull_up_eval_scalar(s_expr, eval_scalar),
+            RelOperator::EvalScalar(eval_scalar) if !self.is_secure_view_barrier(s_expr) => {
+                self.pull_up_eval_scalar(s_expr, eval_scalar)
+            }
             RelOperator::MaterializedCte(_) => Ok(s_expr.clone()),
             _ => self.pull_up_others(s_expr),
         }
@@ -100,6 +103,13 @@ impl PullUpFilterOptimizer {
         let mut right_pull_up = PullUpFilterOptimizer::new(self.metadata.clone());
         let mut left = left_pull_up.pull_up(s_expr.child(0)?)?;
         let mut right = right_pull_up.pull_up(s_expr.child(1)?)?;
+        if left_need_pull_up && !right_need_pull_up {
+            let derived = Self::derive_predicates(&left_pull_up.predicates, join, &left, &right)?;
+            right_pull_up.predicates.extend(derived);
+        } else if right_need_pull_up && !left_need_pull_up {
+            let derived = Self::derive_predicates(&right_pull_up.predicates, join, &right, &left)?;
+            left_pull_up.predicates.extend(derived);
+        }
         if left_need_pull_up {
             for predicate in left_pull_up.predicates {
                 self.predicates.extend(split_conjunctions(&predicate));
@@ -138,6 +148,58 @@ impl PullUpFilterOptimizer {
         Ok(s_expr.replace_children(vec![Arc::new(left), Arc::new(right)]))
     }
 
+    // Derive the predicates of the child whose filters are not pulled up (`to_child`) from the
+    // predicates of the other child (`from_child`) and the join conditions, e.g. `a.id = 10` and
+    // `a.id = b.id` derive `b.id = 10`. The rows of `to_child` that fail the derived predicates
+    // can never match, so they can be filtered out before the join.
+    fn derive_predicates(
+        predicates: &[ScalarExpr],
+        join: &Join,
+        from_child: &SExpr,
+        to_child: &SExpr,
+    ) -> Result<Vec<ScalarExpr>> {
+        if join.equi_conditions.is_empty() {
+            return Ok(vec![]);
+        }
+        let from_columns = RelExpr::with_s_expr(from_child)
+            .derive_relational_prop()?
+            .output_columns
+            .clone();
+        let mut all_predicates = predicates.to_vec();
+        // The conditions in ON clause that only use the columns of `from_child`,
+        // e.g. `a.id = 10` in `a LEFT JOIN b ON a.id = b.id AND a.id = 10`.
+        for predicate in join.non_equi_conditions.iter() {
+            let used_columns = predicate.used_columns();
+            if !used_columns.is_empty() && used_columns.is_subset(&from_columns) {
+                all_predicates.extend(split_conjunctions(predicate));
+            }
+        }
+        if all_predicates.is_empty() {
+            return Ok(vec![]);
+        }
+        for condition in join.equi_conditions.iter() {
+            all_predicates.push(ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "eq".to_string(),
+                params: vec![],
+                arguments: vec![condition.left.clone(), condition.right.clone()],
+            }));
+        }
+        let output_columns = RelExpr::with_s_expr(to_child)
+            .derive_relational_prop()?
+            .output_columns
+            .clone();
+        let derived = InferFilterOptimizer::new(None)
+            .run(all_predicates)?
+            .into_iter()
+            .filter(|predicate| {
+                let used_columns = predicate.used_columns();
+                !used_columns.is_empty() && used_columns.is_subset(&output_columns)
+            })
+            .collect();
+        Ok(derived)
+    }
+
     fn pull_up_eval_scalar(&mut self, s_expr: &SExpr, eval_scalar: &EvalScalar) -> Result<SExpr> {
         let child = self.pull_up(s_expr.child(0)?)?;
         let mut eval_scalar = eval_scalar.clone();
@@ -148,6 +210,11 @@ impl PullUpFilterOptimizer {
         Ok(s_expr.replace_children(vec![Arc::new(child)]))
     }
 
+    // Filters inside a secure view must not be pulled up and mixed with the outer predicates.
+    fn is_secure_view_barrier(&self, s_expr: &SExpr) -> bool {
+        self.metadata.read().is_secure_view_barrier(s_expr.plan())
+    }
+
     pub fn pull_up_others(&mut self, s_expr: &SExpr) -> Result<SExpr> {
         let mut children = Vec::with_capacity(s_expr.arity());
         for child in s_expr.children() {
@@ -25,6 +25,7 @@ use crate::optimizer::rule::RulePtr;
 use crate::optimizer::rule::TransformResult;
 use crate::plans::RelOperator;
 use crate::IndexType;
+use crate::MetadataRef;
 
 /// `MExpr` is abbreviation of multiple expression, which is the representation of relational
 /// expressions inside `Memo`.
@@ -77,6 +78,7 @@ impl MExpr {
     pub fn apply_rule(
         &self,
         memo: &Memo,
+        metadata: &MetadataRef,
         rule: &RulePtr,
         transform_state: &mut TransformResult,
     ) -> Result<()> {
@@ -88,6 +90,10 @@ impl MExpr {
         for pattern in rule.matchers() {
             let exprs = extractor.extract(memo, self, pattern)?;
             for expr in exprs.iter() {
+                // the operators of the outer query are never moved into a secure view.
+                if pattern.crosses_secure_view(expr, &metadata.read()) {
+                    continue;
+                }
                 rule.apply(expr, transform_state)?;
             }
             if !exprs.is_empty() {
@@ -0,0 +1,502 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use log::info;
+
+use crate::binder::wrap_cast;
+use crate::optimizer::SExpr;
+use crate::plans::walk_expr_mut;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::SubqueryExpr;
+use crate::plans::VisitorMut;
+use crate::rollup_aggregate_function;
+use crate::ColumnBindingBuilder;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MaterializedViewEntry;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::Visibility;
+
+/// Rewrites an aggregation over a filtered scan to read a materialized view of the
+/// scanned table, if the view has the same filter and its group keys cover the group
+/// keys of the aggregation.
+///
+/// If the group keys are the same, the aggregated rows are read from the view as is,
+/// otherwise the rows of the view are rolled up again on the group keys of the query.
+pub struct MaterializedViewRewriteOptimizer {
+    metadata: MetadataRef,
+}
+
+/// An aggregation over a filtered scan of a table, with all the expressions resolved
+/// to the columns of the table so that they can be compared between plans.
+struct AggregateShape {
+    table_index: IndexType,
+    predicates: Vec<ScalarExpr>,
+    group_items: Vec<ScalarExpr>,
+    aggregate_functions: Vec<AggregateFunction>,
+}
+
+#[derive(PartialEq)]
+enum ViewOutput {
+    GroupItem(ScalarExpr),
+    AggregateFunction(AggregateFunction),
+    Other,
+}
+
+impl MaterializedViewRewriteOptimizer {
+    pub fn new(metadata: MetadataRef) -> Self {
+        MaterializedViewRewriteOptimizer { metadata }
+    }
+
+    pub fn run(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            let child = self.run(child)?;
+            children.push(Arc::new(child));
+        }
+        let s_expr = s_expr.replace_children(children);
+        if let RelOperator::Aggregate(_) = s_expr.plan.as_ref() {
+            if let Some(rewritten) = self.try_rewrite(&s_expr)? {
+                return Ok(rewritten);
+            }
+        }
+        Ok(s_expr)
+    }
+
+    fn try_rewrite(&self, s_expr: &SExpr) -> Result<Option<SExpr>> {
+        let (shape, views) = {
+            let metadata = self.metadata.read();
+            let Some(shape) = analyze_aggregate(&metadata, s_expr)? else {
+                return Ok(None);
+            };
+            let views = metadata.get_materialized_views(shape.table_index).to_vec();
+            (shape, views)
+        };
+        if views.is_empty() {
+            return Ok(None);
+        }
+
+        let metadata = self.metadata.read().clone();
+        let aggregate: Aggregate = s_expr.plan().clone().try_into()?;
+        for view in views.iter() {
+            if let Some(rewritten) =
+                self.try_rewrite_with_view(&metadata, &aggregate, &shape, view)?
+            {
+                info!("rewrite query to read materialized view {}", view.name);
+                return Ok(Some(rewritten));
+            }
+        }
+        Ok(None)
+    }
+
+    fn try_rewrite_with_view(
+        &self,
+        metadata: &Metadata,
+        aggregate: &Aggregate,
+        shape: &AggregateShape,
+        view: &MaterializedViewEntry,
+    ) -> Result<Option<SExpr>> {
+        let Some((view_shape, outputs)) = analyze_view(metadata, view)? else {
+            return Ok(None);
+        };
+        if shape.predicates.len() != view_shape.predicates.len()
+            || !shape
+                .predicates
+                .iter()
+                .all(|predicate| view_shape.predicates.contains(predicate))
+        {
+            return Ok(None);
+        }
+        // The group keys of the view must be kept in the view.
+        if !view_shape
+            .group_items
+            .iter()
+            .all(|group_item| outputs.contains(&ViewOutput::GroupItem(group_item.clone())))
+        {
+            return Ok(None);
+        }
+
+        let position = |output: ViewOutput| outputs.iter().position(|o| *o == output);
+        let Some(group_columns) = shape
+            .group_items
+            .iter()
+            .map(|group_item| position(ViewOutput::GroupItem(group_item.clone())))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Ok(None);
+        };
+        let Some(aggregate_columns) = shape
+            .aggregate_functions
+            .iter()
+            .map(|function| position(ViewOutput::AggregateFunction(function.clone())))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Ok(None);
+        };
+
+        let same_group_items = view_shape
+            .group_items
+            .iter()
+            .all(|group_item| shape.group_items.contains(group_item));
+        let scan = Arc::new(view.scan.clone());
+        if same_group_items {
+            // Each row of the view is a row of the aggregation.
+            let mut items = Vec::with_capacity(group_columns.len() + aggregate_columns.len());
+            for (group_item, position) in aggregate.group_items.iter().zip(group_columns) {
+                let column = column_ref(metadata, view.scan_columns[position]);
+                items.push(ScalarItem {
+                    scalar: cast_if_needed(column, &group_item.scalar.data_type()?)?,
+                    index: group_item.index,
+                });
+            }
+            for (function, position) in aggregate.aggregate_functions.iter().zip(aggregate_columns)
+            {
+                let column = column_ref(metadata, view.scan_columns[position]);
+                items.push(ScalarItem {
+                    scalar: cast_if_needed(column, &function.scalar.data_type()?)?,
+                    index: function.index,
+                });
+            }
+            return Ok(Some(SExpr::create_unary(
+                Arc::new(EvalScalar { items }.into()),
+                scan,
+            )));
+        }
+
+        // Roll up the rows of the view on the group keys of the query.
+        let mut group_items = Vec::with_capacity(group_columns.len());
+        let mut pre_items = Vec::with_capacity(group_columns.len());
+        for (group_item, position) in aggregate.group_items.iter().zip(group_columns) {
+            let data_type = group_item.scalar.data_type()?;
+            let column = column_ref(metadata, view.scan_columns[position]);
+            pre_items.push(ScalarItem {
+                scalar: cast_if_needed(column, &data_type)?,
+                index: group_item.index,
+            });
+            group_items.push(ScalarItem {
+                scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: ColumnBindingBuilder::new(
+                        metadata.column(group_item.index).name(),
+                        group_item.index,
+                        Box::new(data_type),
+                        Visibility::Visible,
+                    )
+                    .build(),
+                }),
+                index: group_item.index,
+            });
+        }
+
+        let mut aggregate_functions = Vec::with_capacity(aggregate_columns.len());
+        let mut casts = vec![];
+        for (item, position) in aggregate.aggregate_functions.iter().zip(aggregate_columns) {
+            let ScalarExpr::AggregateFunction(function) = &item.scalar else {
+                return Ok(None);
+            };
+            let Some(rollup) = rollup_aggregate_function(&function.func_name) else {
+                return Ok(None);
+            };
+            let column = column_ref(metadata, view.scan_columns[position]);
+            let return_type = AggregateFunctionFactory::instance()
+                .get(rollup, vec![], vec![column.data_type()?])?
+                .return_type()?;
+            let rollup_function = AggregateFunction {
+                func_name: rollup.to_string(),
+                distinct: false,
+                params: vec![],
+                display_name: format!("{rollup}({})", function.display_name),
+                args: vec![column],
+                return_type: Box::new(return_type.clone()),
+            };
+            if return_type == *function.return_type {
+                aggregate_functions.push(ScalarItem {
+                    scalar: ScalarExpr::AggregateFunction(rollup_function),
+                    index: item.index,
+                });
+                continue;
+            }
+            // Groups are never empty, the rollup only differs in nullability then.
+            if aggregate.group_items.is_empty()
+                || return_type.remove_nullable() != function.return_type.remove_nullable()
+            {
+                return Ok(None);
+            }
+            let index = self.metadata.write().add_derived_column(
+                rollup_function.display_name.clone(),
+                return_type.clone(),
+                None,
+            );
+            let column = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: ColumnBindingBuilder::new(
+                    rollup_function.display_name.clone(),
+                    index,
+                    Box::new(return_type),
+                    Visibility::Visible,
+                )
+                .build(),
+            });
+            aggregate_functions.push(ScalarItem {
+                scalar: ScalarExpr::AggregateFunction(rollup_function),
+                index,
+            });
+            casts.push(ScalarItem {
+                scalar: wrap_cast(&column, &function.return_type),
+                index: item.index,
+            });
+        }
+
+        let mut s_expr = if pre_items.is_empty() {
+            (*scan).clone()
+        } else {
+            SExpr::create_unary(Arc::new(EvalScalar { items: pre_items }.into()), scan)
+        };
+        s_expr = SExpr::create_unary(
+            Arc::new(
+                Aggregate {
+                    mode: AggregateMode::Initial,
+                    group_items,
+                    aggregate_functions,
+                    from_distinct: false,
+                    limit: None,
+                    grouping_sets: None,
+                }
+                .into(),
+            ),
+            Arc::new(s_expr),
+        );
+        if !casts.is_empty() {
+            s_expr = SExpr::create_unary(
+                Arc::new(EvalScalar { items: casts }.into()),
+                Arc::new(s_expr),
+            );
+        }
+        Ok(Some(s_expr))
+    }
+}
+
+/// Analyzes the aggregation of the form `Aggregate -> [EvalScalar] -> [Filter] -> Scan`.
+fn analyze_aggregate(metadata: &Metadata, s_expr: &SExpr) -> Result<Option<AggregateShape>> {
+    let RelOperator::Aggregate(aggregate) = s_expr.plan() else {
+        return Ok(None);
+    };
+    if aggregate.mode != AggregateMode::Initial || aggregate.grouping_sets.is_some() {
+        return Ok(None);
+    }
+
+    let mut derived_columns = HashMap::new();
+    let mut predicates = vec![];
+    let mut child = s_expr.child(0)?;
+    let scan = loop {
+        match child.plan() {
+            RelOperator::EvalScalar(eval_scalar) => {
+                for item in &eval_scalar.items {
+                    if !matches!(&item.scalar, ScalarExpr::BoundColumnRef(column) if column.column.index == item.index)
+                    {
+                        derived_columns.insert(item.index, item.scalar.clone());
+                    }
+                }
+            }
+            RelOperator::Filter(filter) => predicates.extend(filter.predicates.iter().cloned()),
+            RelOperator::Scan(scan) => break scan,
+            _ => return Ok(None),
+        }
+        child = child.child(0)?;
+    };
+    if scan.change_type.is_some()
+        || scan.push_down_predicates.is_some()
+        || scan.prewhere.is_some()
+        || scan.agg_index.is_some()
+        || scan.limit.is_some()
+    {
+        return Ok(None);
+    }
+
+    let mut resolver = ColumnResolver {
+        metadata,
+        table_index: scan.table_index,
+        derived_columns: &derived_columns,
+        resolved: true,
+    };
+    let mut resolve = |scalar: &ScalarExpr| -> Result<Option<ScalarExpr>> {
+        let mut scalar = scalar.clone();
+        resolver.resolved = true;
+        resolver.visit(&mut scalar)?;
+        Ok(resolver.resolved.then_some(scalar))
+    };
+
+    let mut shape = AggregateShape {
+        table_index: scan.table_index,
+        predicates: Vec::with_capacity(predicates.len()),
+        group_items: Vec::with_capacity(aggregate.group_items.len()),
+        aggregate_functions: Vec::with_capacity(aggregate.aggregate_functions.len()),
+    };
+    for predicate in &predicates {
+        let Some(predicate) = resolve(predicate)? else {
+            return Ok(None);
+        };
+        shape.predicates.push(predicate);
+    }
+    for group_item in &aggregate.group_items {
+        let Some(group_item) = resolve(&group_item.scalar)? else {
+            return Ok(None);
+        };
+        shape.group_items.push(group_item);
+    }
+    for item in &aggregate.aggregate_functions {
+        let Some(ScalarExpr::AggregateFunction(mut function)) = resolve(&item.scalar)? else {
+            return Ok(None);
+        };
+        function.display_name = String::new();
+        shape.aggregate_functions.push(function);
+    }
+    Ok(Some(shape))
+}
+
+/// Analyzes the query of a materialized view of the form `[EvalScalar] -> Aggregate -> ...`,
+/// and what each column of the view keeps.
+fn analyze_view(
+    metadata: &Metadata,
+    view: &MaterializedViewEntry,
+) -> Result<Option<(AggregateShape, Vec<ViewOutput>)>> {
+    let mut projections = HashMap::new();
+    let mut s_expr = &view.query;
+    while let RelOperator::EvalScalar(eval_scalar) = s_expr.plan() {
+        for item in &eval_scalar.items {
+            if let ScalarExpr::BoundColumnRef(column) = &item.scalar {
+                if column.column.index != item.index {
+                    projections.insert(item.index, column.column.index);
+                }
+            }
+        }
+        s_expr = s_expr.child(0)?;
+    }
+    let RelOperator::Aggregate(aggregate) = s_expr.plan() else {
+        return Ok(None);
+    };
+    let Some(shape) = analyze_aggregate(metadata, s_expr)? else {
+        return Ok(None);
+    };
+
+    let outputs = view
+        .query_columns
+        .iter()
+        .map(|index| {
+            let mut index = *index;
+            while let Some(projected) = projections.get(&index) {
+                index = *projected;
+            }
+            if let Some(position) = aggregate
+                .group_items
+                .iter()
+                .position(|item| item.index == index)
+            {
+                ViewOutput::GroupItem(shape.group_items[position].clone())
+            } else if let Some(position) = aggregate
+                .aggregate_functions
+                .iter()
+                .position(|item| item.index == index)
+            {
+                ViewOutput::AggregateFunction(shape.aggregate_functions[position].clone())
+            } else {
+                ViewOutput::Other
+            }
+        })
+        .collect();
+    Ok(Some((shape, outputs)))
+}
+
+fn column_ref(metadata: &Metadata, index: IndexType) -> ScalarExpr {
+    let column = metadata.column(index);
+    ScalarExpr::BoundColumnRef(BoundColumnRef {
+        span: None,
+        column: ColumnBindingBuilder::new(
+            column.name(),
+            index,
+            Box::new(column.data_type()),
+            Visibility::Visible,
+        )
+        .table_index(column.table_index())
+        .build(),
+    })
+}
+
+fn cast_if_needed(scalar: ScalarExpr, data_type: &DataType) -> Result<ScalarExpr> {
+    if scalar.data_type()? == *data_type {
+        Ok(scalar)
+    } else {
+        Ok(wrap_cast(&scalar, data_type))
+    }
+}
+
+/// Resolves the derived columns to their expressions and the columns of the table
+/// to their names, so that the expressions of different plans over the same table
+/// can be compared.
+struct ColumnResolver<'a> {
+    metadata: &'a Metadata,
+    table_index: IndexType,
+    derived_columns: &'a HashMap<IndexType, ScalarExpr>,
+    resolved: bool,
+}
+
+impl<'a> VisitorMut<'a> for ColumnResolver<'_> {
+    fn visit(&mut self, expr: &'a mut ScalarExpr) -> Result<()> {
+        if let ScalarExpr::BoundColumnRef(column) = expr {
+            if let Some(derived) = self.derived_columns.get(&column.column.index) {
+                *expr = derived.clone();
+                return self.visit(expr);
+            }
+        }
+        walk_expr_mut(self, expr)
+    }
+
+    fn visit_bound_column_ref(&mut self, column: &'a mut BoundColumnRef) -> Result<()> {
+        match self.metadata.column(column.column.index) {
+            ColumnEntry::BaseTableColumn(base_column)
+                if base_column.table_index == self.table_index
+                    && base_column.path_indices.is_none() =>
+            {
+                column.column = ColumnBindingBuilder::new(
+                    base_column.column_name.clone(),
+                    0,
+                    Box::new(DataType::from(&base_column.data_type)),
+                    Visibility::Visible,
+                )
+                .build();
+            }
+            _ => self.resolved = false,
+        }
+        Ok(())
+    }
+
+    fn visit_subquery_expr(&mut self, _subquery: &'a mut SubqueryExpr) -> Result<()> {
+        self.resolved = false;
+        Ok(())
+    }
+}
@@ -24,6 +24,7 @@ mod group;
 mod hyper_dp;
 mod join;
 mod m_expr;
+mod materialized_view;
 mod memo;
 #[allow(clippy::module_inception)]
 mod optimizer;
@@ -34,6 +35,7 @@ mod statistics;
 mod util;
 
 pub use cascades::CascadesOptimizer;
+pub use cost::estimate_cost;
 pub use decorrelate::FlattenInfo;
 pub use decorrelate::SubqueryRewriter;
 pub use extract::PatternExtractor;
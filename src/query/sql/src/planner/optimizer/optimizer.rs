@@ -33,14 +33,17 @@ use crate::optimizer::decorrelate::decorrelate_subquery;
 use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::distributed::SortAndLimitPushDownOptimizer;
 use crate::optimizer::filter::DeduplicateJoinConditionOptimizer;
+use crate::optimizer::filter::FoldConstantOptimizer;
 use crate::optimizer::filter::PullUpFilterOptimizer;
 use crate::optimizer::hyper_dp::DPhpy;
 use crate::optimizer::join::SingleToInnerOptimizer;
+use crate::optimizer::materialized_view::MaterializedViewRewriteOptimizer;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::statistics::CollectStatisticsOptimizer;
 use crate::optimizer::util::contains_local_table_scan;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
+use crate::optimizer::RuleSet;
 use crate::optimizer::SExpr;
 use crate::optimizer::DEFAULT_REWRITE_RULES;
 use crate::plans::CopyIntoLocationPlan;
@@ -65,6 +68,7 @@ pub struct OptimizerContext {
     enable_join_reorder: bool,
     enable_dphyp: bool,
     enable_merge_into_join_reorder: bool,
+    disabled_rules: RuleSet,
 }
 
 impl OptimizerContext {
@@ -77,6 +81,7 @@ impl OptimizerContext {
             enable_join_reorder: true,
             enable_dphyp: true,
             enable_merge_into_join_reorder: true,
+            disabled_rules: RuleSet::create(),
         }
     }
 
@@ -99,6 +104,11 @@ impl OptimizerContext {
         self.enable_merge_into_join_reorder = enable;
         self
     }
+
+    pub fn with_disabled_rules(mut self, disabled_rules: RuleSet) -> Self {
+        self.disabled_rules = disabled_rules;
+        self
+    }
 }
 
 /// A recursive optimizer that will apply the given rules recursively.
@@ -135,13 +145,16 @@ impl<'a> RecursiveOptimizer<'a> {
     fn apply_transform_rules(&self, s_expr: &SExpr, rules: &[RuleID]) -> Result<SExpr> {
         let mut s_expr = s_expr.clone();
         for rule_id in rules {
+            if self.ctx.disabled_rules.contains(rule_id) {
+                continue;
+            }
             let rule = RuleFactory::create_rule(*rule_id, self.ctx.metadata.clone())?;
             let mut state = TransformResult::new();
-            if rule
-                .matchers()
-                .iter()
-                .any(|matcher| matcher.matches(&s_expr))
-                && !s_expr.applied_rule(&rule.id())
+            // the operators of the outer query are never moved into a secure view.
+            if rule.matchers().iter().any(|matcher| {
+                matcher.matches(&s_expr)
+                    && !matcher.crosses_secure_view(&s_expr, &self.ctx.metadata.read())
+            }) && !s_expr.applied_rule(&rule.id())
             {
                 s_expr.set_applied_rule(&rule.id());
                 rule.apply(&s_expr, &mut state)?;
@@ -211,11 +224,25 @@ pub async fn optimize(opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan> {
         Plan::ExplainAnalyze { plan } => Ok(Plan::ExplainAnalyze {
             plan: Box::new(Box::pin(optimize(opt_ctx, *plan)).await?),
         }),
-        Plan::CopyIntoLocation(CopyIntoLocationPlan { stage, path, from }) => {
+        Plan::CopyIntoLocation(CopyIntoLocationPlan {
+            stage, path, from, ..
+        }) => {
+            // Each node writes its own files, unless all the data goes into a single file.
+            let enable_distributed = opt_ctx.enable_distributed_optimization
+                && !stage.copy_options.single
+                && opt_ctx
+                    .table_ctx
+                    .get_settings()
+                    .get_enable_distributed_copy()?;
+            info!(
+                "after optimization enable_distributed_copy? : {}",
+                enable_distributed
+            );
             Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
                 stage,
                 path,
                 from: Box::new(Box::pin(optimize(opt_ctx, *from)).await?),
+                enable_distributed,
             }))
         }
         Plan::CopyIntoTable(mut plan) if !plan.no_file_to_copy => {
@@ -318,6 +345,9 @@ pub async fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Res
         )?;
     }
 
+    // Rewrite aggregations to read up-to-date materialized views of the tables.
+    s_expr = MaterializedViewRewriteOptimizer::new(opt_ctx.metadata.clone()).run(&s_expr)?;
+
     // Collect statistics for each leaf node in SExpr.
     s_expr = CollectStatisticsOptimizer::new(opt_ctx.table_ctx.clone(), opt_ctx.metadata.clone())
         .run(&s_expr)
@@ -329,6 +359,9 @@ pub async fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Res
     // Pull up and infer filter.
     s_expr = PullUpFilterOptimizer::new(opt_ctx.metadata.clone()).run(&s_expr)?;
 
+    // Fold constants and simplify the filters, so that the rewrite rules see canonical predicates.
+    s_expr = FoldConstantOptimizer::new(opt_ctx.table_ctx.get_function_context()?).run(&s_expr)?;
+
     // Run default rewrite rules
     s_expr = RecursiveOptimizer::new(&DEFAULT_REWRITE_RULES, &opt_ctx).run(&s_expr)?;
 
@@ -354,7 +387,8 @@ pub async fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Res
         opt_ctx.metadata.clone(),
         dphyp_optimized,
         enable_distributed_query,
-    )?;
+    )?
+    .with_disabled_rules(&opt_ctx.disabled_rules);
 
     if opt_ctx.enable_join_reorder {
         s_expr =
@@ -414,6 +448,9 @@ async fn get_optimized_memo(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Res
         .run(&s_expr)
         .await?;
 
+    // Fold constants and simplify the filters, so that the rewrite rules see canonical predicates.
+    s_expr = FoldConstantOptimizer::new(opt_ctx.table_ctx.get_function_context()?).run(&s_expr)?;
+
     // Run default rewrite rules
     s_expr = RecursiveOptimizer::new(&DEFAULT_REWRITE_RULES, &opt_ctx).run(&s_expr)?;
 
@@ -432,7 +469,8 @@ async fn get_optimized_memo(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Res
         opt_ctx.metadata.clone(),
         dphyp_optimized,
         enable_distributed_query,
-    )?;
+    )?
+    .with_disabled_rules(&opt_ctx.disabled_rules);
     cascades.optimize(s_expr)?;
 
     Ok(cascades.memo)
@@ -395,6 +395,23 @@ impl<'a> SelectivityEstimator<'a> {
             return Ok(DEFAULT_SELECTIVITY);
         }
         let col_hist = col_hist.unwrap();
+        if col_hist.accuracy && col_hist.num_values() > 0.0 && col_hist.num_buckets() > 0 {
+            // The histogram is collected by `ANALYZE`, so it reflects the real
+            // distribution of the column and we can interpolate inside the bucket.
+            let type_comparable = col_hist.buckets[0]
+                .lower_bound()
+                .type_comparable(const_datum);
+            if type_comparable {
+                return Self::compute_histogram_comparison_selectivity(
+                    comparison_op,
+                    const_datum,
+                    update,
+                    column_ref,
+                    column_stat,
+                    updated_column_indexes,
+                );
+            }
+        }
         let (mut num_greater, new_min, new_max) = match comparison_op {
             ComparisonOp::GT | ComparisonOp::GTE => {
                 let new_min = const_datum.clone();
@@ -460,6 +477,48 @@ impl<'a> SelectivityEstimator<'a> {
 
         Ok(selectivity)
     }
+
+    fn compute_histogram_comparison_selectivity(
+        comparison_op: &ComparisonOp,
+        const_datum: &Datum,
+        update: bool,
+        column_ref: &BoundColumnRef,
+        column_stat: &mut ColumnStat,
+        updated_column_indexes: &mut HashSet<IndexType>,
+    ) -> Result<f64> {
+        // Safe to unwrap, the caller has checked the histogram exists.
+        let col_hist = column_stat.histogram.as_ref().unwrap();
+        let num_values = col_hist.num_values();
+        let (selectivity, new_min, new_max) = match comparison_op {
+            ComparisonOp::LT | ComparisonOp::LTE => {
+                let inclusive = *comparison_op == ComparisonOp::LTE;
+                let num_less = col_hist.estimate_less(const_datum, inclusive)?;
+                (
+                    num_less / num_values,
+                    column_stat.min.clone(),
+                    const_datum.clone(),
+                )
+            }
+            ComparisonOp::GT | ComparisonOp::GTE => {
+                let inclusive = *comparison_op == ComparisonOp::GT;
+                let num_less = col_hist.estimate_less(const_datum, inclusive)?;
+                (
+                    1.0 - num_less / num_values,
+                    const_datum.clone(),
+                    column_stat.max.clone(),
+                )
+            }
+            _ => unreachable!(),
+        };
+        let selectivity = selectivity.clamp(0.0, 1.0);
+
+        if update {
+            update_statistic(column_stat, new_min, new_max, selectivity)?;
+            updated_column_indexes.insert(column_ref.column.index);
+        }
+
+        Ok(selectivity)
+    }
 }
 
 // TODO(andylokandy): match on non-null boolean only once we have constant folding in the optimizer.
@@ -517,6 +576,16 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
         }
     }
 
+    if let (Some(constant_datum), Some(histogram)) = (datum, &column_stat.histogram) {
+        // Only the histogram collected by `ANALYZE` is more precise than NDV,
+        // the others are derived from NDV with uniform distribution.
+        if histogram.accuracy && histogram.num_values() > 0.0 {
+            if let Ok(num_equal) = histogram.estimate_equal(constant_datum) {
+                return (num_equal / histogram.num_values()).min(1.0);
+            }
+        }
+    }
+
     if column_stat.ndv == 0.0 {
         0.0
     } else {
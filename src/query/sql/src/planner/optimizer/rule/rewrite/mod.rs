@@ -22,12 +22,14 @@ mod rule_fold_count_aggregate;
 mod rule_merge_eval_scalar;
 mod rule_merge_filter;
 mod rule_normalize_scalar;
+mod rule_outer_join_to_inner_join;
 mod rule_push_down_filter_aggregate;
 mod rule_push_down_filter_eval_scalar;
 mod rule_push_down_filter_join;
 mod rule_push_down_filter_project_set;
 mod rule_push_down_filter_scan;
 mod rule_push_down_filter_sort;
+mod rule_push_down_filter_udf;
 mod rule_push_down_filter_union;
 mod rule_push_down_filter_window;
 mod rule_push_down_limit_aggregate;
@@ -51,6 +53,7 @@ pub use rule_fold_count_aggregate::RuleFoldCountAggregate;
 pub use rule_merge_eval_scalar::RuleMergeEvalScalar;
 pub use rule_merge_filter::RuleMergeFilter;
 pub use rule_normalize_scalar::RuleNormalizeScalarFilter;
+pub use rule_outer_join_to_inner_join::RuleOuterJoinToInnerJoin;
 pub use rule_push_down_filter_aggregate::RulePushDownFilterAggregate;
 pub use rule_push_down_filter_eval_scalar::RulePushDownFilterEvalScalar;
 pub use rule_push_down_filter_join::try_push_down_filter_join;
@@ -58,6 +61,7 @@ pub use rule_push_down_filter_join::RulePushDownFilterJoin;
 pub use rule_push_down_filter_project_set::RulePushDownFilterProjectSet;
 pub use rule_push_down_filter_scan::RulePushDownFilterScan;
 pub use rule_push_down_filter_sort::RulePushDownFilterSort;
+pub use rule_push_down_filter_udf::RulePushDownFilterUdf;
 pub use rule_push_down_filter_union::RulePushDownFilterUnion;
 pub use rule_push_down_filter_window::RulePushDownFilterWindow;
 pub use rule_push_down_limit_aggregate::RulePushDownLimitAggregate;
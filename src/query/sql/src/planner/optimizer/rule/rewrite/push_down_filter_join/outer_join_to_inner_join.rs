@@ -42,20 +42,43 @@ use crate::ScalarExpr;
 use crate::TypeCheck;
 
 pub fn outer_join_to_inner_join(s_expr: &SExpr, metadata: MetadataRef) -> Result<(SExpr, bool)> {
-    let mut join: Join = s_expr.child(0)?.plan().clone().try_into()?;
-    if !join.join_type.is_outer_join() {
+    let filter: Filter = s_expr.plan().clone().try_into()?;
+    let join_s_expr = s_expr.child(0)?;
+    let Some(join) = eliminate_outer_join(join_s_expr, &filter.predicates, metadata)? else {
         return Ok((s_expr.clone(), false));
+    };
+
+    let result = SExpr::create_unary(
+        Arc::new(filter.into()),
+        Arc::new(SExpr::create_binary(
+            Arc::new(join.into()),
+            Arc::new(join_s_expr.child(0)?.clone()),
+            Arc::new(join_s_expr.child(1)?.clone()),
+        )),
+    );
+
+    Ok((result, true))
+}
+
+/// Simplify the outer join `join_s_expr` with the null-rejecting `predicates` applied on its output,
+/// returns the simplified join or `None` if the join type is not changed.
+pub fn eliminate_outer_join(
+    join_s_expr: &SExpr,
+    predicates: &[ScalarExpr],
+    metadata: MetadataRef,
+) -> Result<Option<Join>> {
+    let mut join: Join = join_s_expr.plan().clone().try_into()?;
+    if !join.join_type.is_outer_join() {
+        return Ok(None);
     }
 
-    let filter: Filter = s_expr.plan().clone().try_into()?;
-    let join_s_expr = s_expr.child(0)?;
     let join_rel_expr = RelExpr::with_s_expr(join_s_expr);
 
     let mut can_filter_left_null = false;
     let mut can_filter_right_null = false;
     let left_prop = join_rel_expr.derive_relational_prop_child(0)?;
     let right_prop = join_rel_expr.derive_relational_prop_child(1)?;
-    for predicate in &filter.predicates {
+    for predicate in predicates {
         let pred = JoinPredicate::new(predicate, &left_prop, &right_prop);
         match pred {
             JoinPredicate::Left(_)
@@ -104,7 +127,7 @@ pub fn outer_join_to_inner_join(s_expr: &SExpr, metadata: MetadataRef) -> Result
     join.join_type =
         eliminate_outer_join_type(join.join_type, can_filter_left_null, can_filter_right_null);
     if join.join_type == original_join_type {
-        return Ok((s_expr.clone(), false));
+        return Ok(None);
     }
 
     if matches!(
@@ -115,16 +138,7 @@ pub fn outer_join_to_inner_join(s_expr: &SExpr, metadata: MetadataRef) -> Result
         join.single_to_inner = Some(original_join_type);
     }
 
-    let result = SExpr::create_unary(
-        Arc::new(filter.into()),
-        Arc::new(SExpr::create_binary(
-            Arc::new(join.into()),
-            Arc::new(join_s_expr.child(0)?.clone()),
-            Arc::new(join_s_expr.child(1)?.clone()),
-        )),
-    );
-
-    Ok((result, true))
+    Ok(Some(join))
 }
 
 fn eliminate_outer_join_type(
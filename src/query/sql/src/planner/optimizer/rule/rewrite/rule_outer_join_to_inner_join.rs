@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::optimizer::extract::Matcher;
+use crate::optimizer::rule::rewrite::push_down_filter_join::eliminate_outer_join;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::FunctionCall;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// Convert an outer join into an inner join if the conditions of its parent join
+/// reject the NULLs produced for its nullable side, e.g.
+/// `(t1 LEFT JOIN t2 ON t1.a = t2.a) JOIN t3 ON t2.b = t3.b`.
+/// Filters above the outer join are handled by `RulePushDownFilterJoin`.
+pub struct RuleOuterJoinToInnerJoin {
+    id: RuleID,
+    matchers: Vec<Matcher>,
+    metadata: MetadataRef,
+}
+
+impl RuleOuterJoinToInnerJoin {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::OuterJoinToInnerJoin,
+            // Join
+            // |  \
+            // *   *
+            matchers: vec![Matcher::MatchOp {
+                op_type: RelOp::Join,
+                children: vec![Matcher::Leaf, Matcher::Leaf],
+            }],
+            metadata,
+        }
+    }
+
+    /// Returns the predicates the parent join applies on the rows of the child `idx`,
+    /// or `None` if the rows that fail them may still appear in the join output.
+    fn null_rejecting_predicates(join: &Join, idx: usize) -> Option<Vec<ScalarExpr>> {
+        let rejects_unmatched = match join.join_type {
+            JoinType::Inner => true,
+            JoinType::Left => idx == 1,
+            JoinType::Right => idx == 0,
+            JoinType::LeftSemi | JoinType::RightSemi => true,
+            _ => false,
+        };
+        if !rejects_unmatched || join.is_lateral || join.from_correlated_subquery {
+            return None;
+        }
+
+        let mut predicates = join
+            .equi_conditions
+            .iter()
+            .filter(|condition| !condition.is_null_equal)
+            .map(|condition| {
+                let argument = if idx == 0 {
+                    condition.left.clone()
+                } else {
+                    condition.right.clone()
+                };
+                ScalarExpr::FunctionCall(FunctionCall {
+                    span: None,
+                    func_name: "is_not_null".to_string(),
+                    params: vec![],
+                    arguments: vec![argument],
+                })
+            })
+            .collect::<Vec<_>>();
+        predicates.extend(join.non_equi_conditions.iter().cloned());
+        Some(predicates)
+    }
+}
+
+impl Rule for RuleOuterJoinToInnerJoin {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let join: Join = s_expr.plan().clone().try_into()?;
+
+        let mut children = vec![s_expr.child(0)?.clone(), s_expr.child(1)?.clone()];
+        let mut changed = false;
+        for (idx, child) in children.iter_mut().enumerate() {
+            if !matches!(child.plan(), RelOperator::Join(_)) {
+                continue;
+            }
+            let Some(predicates) = Self::null_rejecting_predicates(&join, idx) else {
+                continue;
+            };
+            if predicates.is_empty() {
+                continue;
+            }
+            if let Some(child_join) =
+                eliminate_outer_join(child, &predicates, self.metadata.clone())?
+            {
+                *child = SExpr::create_binary(
+                    Arc::new(child_join.into()),
+                    Arc::new(child.child(0)?.clone()),
+                    Arc::new(child.child(1)?.clone()),
+                );
+                changed = true;
+            }
+        }
+
+        if changed {
+            let [left, right] = <[SExpr; 2]>::try_from(children).unwrap();
+            let mut result =
+                SExpr::create_binary(Arc::new(join.into()), Arc::new(left), Arc::new(right));
+            result.set_applied_rule(&self.id);
+            state.add_result(result);
+        }
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::optimizer::extract::Matcher;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::Filter;
+use crate::plans::RelOp;
+use crate::plans::Udf;
+
+/// Push the predicates which do not use the results of the udf below it.
+///
+/// Views are bound inline, so the body of a view is already part of the query tree and
+/// filters on it are pushed down like in a subquery. A udf in the view body used to stop them.
+///
+/// Input:   Filter
+///           \
+///            Udf
+///             \
+///              *
+///
+/// Output:
+/// (1)      Udf
+///           \
+///            Filter
+///             \
+///              *
+///
+/// (2)
+///          Filter(remaining)
+///           \
+///            Udf
+///             \
+///              Filter(pushed down)
+///               \
+///                *
+pub struct RulePushDownFilterUdf {
+    id: RuleID,
+    matchers: Vec<Matcher>,
+}
+
+impl RulePushDownFilterUdf {
+    pub fn new() -> Self {
+        Self {
+            id: RuleID::PushDownFilterUdf,
+            matchers: vec![Matcher::MatchOp {
+                op_type: RelOp::Filter,
+                children: vec![Matcher::MatchOp {
+                    op_type: RelOp::Udf,
+                    children: vec![Matcher::Leaf],
+                }],
+            }],
+        }
+    }
+}
+
+impl Rule for RulePushDownFilterUdf {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let filter: Filter = s_expr.plan().clone().try_into()?;
+        let udf_expr = s_expr.child(0)?;
+        let udf: Udf = udf_expr.plan().clone().try_into()?;
+        let udf_child_prop = RelExpr::with_s_expr(udf_expr).derive_relational_prop_child(0)?;
+        let mut pushed_down_predicates = vec![];
+        let mut remaining_predicates = vec![];
+        for pred in filter.predicates.into_iter() {
+            let pred_used_columns = pred.used_columns();
+            if pred_used_columns.is_subset(&udf_child_prop.output_columns) {
+                pushed_down_predicates.push(pred);
+            } else {
+                remaining_predicates.push(pred)
+            }
+        }
+        if !pushed_down_predicates.is_empty() {
+            let pushed_down_filter = Filter {
+                predicates: pushed_down_predicates,
+            };
+            let mut result = if remaining_predicates.is_empty() {
+                SExpr::create_unary(
+                    Arc::new(udf.into()),
+                    Arc::new(SExpr::create_unary(
+                        Arc::new(pushed_down_filter.into()),
+                        Arc::new(udf_expr.child(0)?.clone()),
+                    )),
+                )
+            } else {
+                let remaining_filter = Filter {
+                    predicates: remaining_predicates,
+                };
+                SExpr::create_unary(
+                    Arc::new(remaining_filter.into()),
+                    Arc::new(SExpr::create_unary(
+                        Arc::new(udf.into()),
+                        Arc::new(SExpr::create_unary(
+                            Arc::new(pushed_down_filter.into()),
+                            Arc::new(udf_expr.child(0)?.clone()),
+                        )),
+                    )),
+                )
+            };
+            result.set_applied_rule(&self.id);
+            state.add_result(result);
+        }
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
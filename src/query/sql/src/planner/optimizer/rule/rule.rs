@@ -14,11 +14,14 @@
 
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::str::FromStr;
 use std::sync::LazyLock;
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use num_derive::FromPrimitive;
 use num_derive::ToPrimitive;
+use num_traits::FromPrimitive;
 
 use crate::optimizer::extract::Matcher;
 use crate::optimizer::rule::TransformResult;
@@ -44,7 +47,9 @@ pub static DEFAULT_REWRITE_RULES: LazyLock<Vec<RuleID>> = LazyLock::new(|| {
         RuleID::PushDownFilterSort,
         RuleID::PushDownFilterEvalScalar,
         RuleID::PushDownFilterJoin,
+        RuleID::OuterJoinToInnerJoin,
         RuleID::PushDownFilterProjectSet,
+        RuleID::PushDownFilterUdf,
         RuleID::SemiToInnerJoin,
         RuleID::FoldCountAggregate,
         RuleID::TryApplyAggIndex,
@@ -71,6 +76,7 @@ pub trait Rule {
 
 // If add a new rule, please add it to the operator's corresponding `transformation_candidate_rules`
 // Such as `PushDownFilterAggregate` is related to `Filter` operator.
+// And add its name to the range of the `disabled_optimizer_rules` setting.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, FromPrimitive, ToPrimitive)]
 pub enum RuleID {
     // Rewrite rules
@@ -82,6 +88,7 @@ pub enum RuleID {
     PushDownFilterScan,
     PushDownFilterSort,
     PushDownFilterProjectSet,
+    PushDownFilterUdf,
     PushDownFilterWindow,
     PushDownLimitUnion,
     PushDownLimitOuterJoin,
@@ -92,6 +99,7 @@ pub enum RuleID {
     PushDownLimitScan,
     PushDownSortScan,
     SemiToInnerJoin,
+    OuterJoinToInnerJoin,
     EliminateEvalScalar,
     EliminateFilter,
     EliminateSort,
@@ -118,6 +126,7 @@ impl Display for RuleID {
             RuleID::PushDownFilterScan => write!(f, "PushDownFilterScan"),
             RuleID::PushDownFilterSort => write!(f, "PushDownFilterSort"),
             RuleID::PushDownFilterProjectSet => write!(f, "PushDownFilterProjectSet"),
+            RuleID::PushDownFilterUdf => write!(f, "PushDownFilterUdf"),
             RuleID::PushDownLimitUnion => write!(f, "PushDownLimitUnion"),
             RuleID::PushDownLimitOuterJoin => write!(f, "PushDownLimitOuterJoin"),
             RuleID::PushDownLimitEvalScalar => write!(f, "PushDownLimitEvalScalar"),
@@ -144,6 +153,21 @@ impl Display for RuleID {
             RuleID::EagerAggregation => write!(f, "EagerAggregation"),
             RuleID::TryApplyAggIndex => write!(f, "TryApplyAggIndex"),
             RuleID::SemiToInnerJoin => write!(f, "SemiToInnerJoin"),
+            RuleID::OuterJoinToInnerJoin => write!(f, "OuterJoinToInnerJoin"),
         }
     }
 }
+
+impl FromStr for RuleID {
+    type Err = ErrorCode;
+
+    /// Parse the rule name, both `PushDownFilterJoin` and `push_down_filter_join` are accepted.
+    fn from_str(name: &str) -> Result<Self> {
+        let normalize = |name: &str| name.replace('_', "").to_lowercase();
+        let normalized = normalize(name.trim());
+        (0..)
+            .map_while(RuleID::from_u32)
+            .find(|id| normalize(&id.to_string()) == normalized)
+            .ok_or_else(|| ErrorCode::BadArguments(format!("Unknown optimizer rule: {name}")))
+    }
+}
@@ -26,6 +26,7 @@ use crate::optimizer::extract::Matcher;
 use crate::optimizer::rule::Rule;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::ColumnSet;
+use crate::optimizer::ColumnStatSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -450,6 +451,19 @@ impl Rule for RuleEagerAggregation {
             }
         }
 
+        // Cost check: an eager aggregation is only worth it if it shrinks the input of the join.
+        for idx in 0..2 {
+            if can_eager[idx] {
+                let rel_expr = RelExpr::with_s_expr(&join_expr.children[idx]);
+                let stat_info = rel_expr.derive_cardinality()?;
+                can_eager[idx] = is_eager_aggregation_beneficial(
+                    stat_info.cardinality,
+                    &stat_info.statistics.column_stats,
+                    &group_columns_set[idx],
+                );
+            }
+        }
+
         let can_push_down = [
             !eager_aggregations[0].is_empty() && can_eager[0],
             !eager_aggregations[1].is_empty() && can_eager[1],
@@ -1169,6 +1183,27 @@ impl Rule for RuleEagerAggregation {
     }
 }
 
+/// An eager aggregation has to reduce the rows of its input at least by this factor.
+const EAGER_AGGREGATION_MIN_REDUCTION: f64 = 2.0;
+
+/// Estimate the number of groups of an eager aggregation from the NDV of its group columns,
+/// and check that it reduces `cardinality` enough to pay for the extra aggregation.
+/// If the NDV of a group column is unknown, leave the decision to the cost model.
+fn is_eager_aggregation_beneficial(
+    cardinality: f64,
+    column_stats: &ColumnStatSet,
+    group_columns: &ColumnSet,
+) -> bool {
+    let mut groups = 1.0;
+    for column in group_columns.iter() {
+        match column_stats.get(column) {
+            Some(stat) => groups *= stat.ndv.max(1.0),
+            None => return true,
+        }
+    }
+    groups * EAGER_AGGREGATION_MIN_REDUCTION <= cardinality
+}
+
 // In the current implementation, if an aggregation function can be eager,
 // then it needs to satisfy the following constraints:
 // (1) The args.len() must equal to 1 if func_name is not "count".
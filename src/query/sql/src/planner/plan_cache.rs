@@ -0,0 +1,619 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use databend_common_ast::ast::format_statement;
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::JoinCondition;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::Span;
+use databend_common_cache::Cache;
+use databend_common_cache::LruCache;
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::Scalar;
+use databend_common_meta_app::schema::TableIdent;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut as AstVisitorMut;
+use itertools::Itertools;
+use log::info;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+use crate::optimizer::SExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::Operator;
+use crate::plans::Plan;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::SubqueryExpr;
+use crate::plans::VisitorMut;
+use crate::plans::WindowFuncType;
+use crate::resolve_literal_scalar;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+use crate::PlanExtras;
+
+const PLAN_CACHE_CAPACITY: u64 = 1024;
+
+static PLAN_CACHE: LazyLock<Mutex<LruCache<String, Arc<PlanCacheEntry>>>> =
+    LazyLock::new(|| Mutex::new(LruCache::new(PLAN_CACHE_CAPACITY)));
+
+/// Replaces the literals compared with expressions in the `WHERE` and `ON` clauses by
+/// parameters, so that the queries only differing in these literals share the same key.
+///
+/// The other literals are kept in the key, they may name the output columns, or be
+/// matched between the clauses, e.g. `SELECT a > 1 ... GROUP BY a > 1`. So are the
+/// literals of the subqueries in the select list, which also name the output columns.
+#[derive(AstVisitorMut)]
+#[visitor(SelectTarget(enter, exit), SelectStmt(enter), JoinCondition(enter))]
+struct LiteralNormalizer {
+    select_target_depth: usize,
+    params: Vec<(Span, Literal)>,
+}
+
+impl LiteralNormalizer {
+    fn enter_select_target(&mut self, _: &mut SelectTarget) {
+        self.select_target_depth += 1;
+    }
+
+    fn exit_select_target(&mut self, _: &mut SelectTarget) {
+        self.select_target_depth -= 1;
+    }
+
+    fn enter_select_stmt(&mut self, stmt: &mut SelectStmt) {
+        if let Some(selection) = &mut stmt.selection {
+            self.parameterize(selection);
+        }
+    }
+
+    fn enter_join_condition(&mut self, condition: &mut JoinCondition) {
+        if let JoinCondition::On(expr) = condition {
+            self.parameterize(expr);
+        }
+    }
+
+    fn parameterize(&mut self, expr: &mut Expr) {
+        if self.select_target_depth > 0 {
+            return;
+        }
+        let mut parameterizer = ComparisonParameterizer {
+            subquery_depth: 0,
+            params: std::mem::take(&mut self.params),
+        };
+        expr.drive_mut(&mut parameterizer);
+        self.params = parameterizer.params;
+    }
+}
+
+/// Replaces the literal operands of the comparisons in an expression by parameters,
+/// the subqueries are left to the [`LiteralNormalizer`].
+#[derive(AstVisitorMut)]
+#[visitor(Query(enter, exit), Expr(enter))]
+struct ComparisonParameterizer {
+    subquery_depth: usize,
+    params: Vec<(Span, Literal)>,
+}
+
+impl ComparisonParameterizer {
+    fn enter_query(&mut self, _: &mut Query) {
+        self.subquery_depth += 1;
+    }
+
+    fn exit_query(&mut self, _: &mut Query) {
+        self.subquery_depth -= 1;
+    }
+
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        if self.subquery_depth > 0 {
+            return;
+        }
+        if let Expr::BinaryOp {
+            op:
+                BinaryOperator::Eq
+                | BinaryOperator::NotEq
+                | BinaryOperator::Gt
+                | BinaryOperator::Lt
+                | BinaryOperator::Gte
+                | BinaryOperator::Lte,
+            left,
+            right,
+            ..
+        } = expr
+        {
+            // comparisons of constants are folded by the binder.
+            let left_is_literal = matches!(left.as_ref(), Expr::Literal { .. });
+            let right_is_literal = matches!(right.as_ref(), Expr::Literal { .. });
+            if !right_is_literal {
+                self.parameterize(left);
+            }
+            if !left_is_literal {
+                self.parameterize(right);
+            }
+        }
+    }
+
+    fn parameterize(&mut self, expr: &mut Expr) {
+        if let Expr::Literal { span, value } = expr {
+            if *value != Literal::Null {
+                let hole = Expr::Hole {
+                    span: *span,
+                    name: self.params.len().to_string(),
+                };
+                let Expr::Literal { span, value } = std::mem::replace(expr, hole) else {
+                    unreachable!()
+                };
+                self.params.push((span, value));
+            }
+        }
+    }
+}
+
+/// The key of a query in the plan cache.
+///
+/// The statement is normalized with the literals of its comparisons replaced by parameters
+/// of the same types, and qualified by everything else that changes how it is bound and
+/// optimized: the tenant, the user and role (masking and row access policies), the current
+/// catalog and database, the changed settings and whether the query is distributed.
+pub struct PlanCacheKey {
+    key: String,
+    /// The spans and the bound values of the parameters.
+    params: Vec<(Span, Scalar)>,
+    statement: Statement,
+}
+
+impl PlanCacheKey {
+    pub fn create(ctx: &dyn TableContext, stmt: &Statement) -> Self {
+        let mut normalized = stmt.clone();
+        let mut normalizer = LiteralNormalizer {
+            select_target_depth: 0,
+            params: vec![],
+        };
+        normalized.drive_mut(&mut normalizer);
+
+        let mut params = Vec::with_capacity(normalizer.params.len());
+        let mut param_types = Vec::with_capacity(normalizer.params.len());
+        for (span, literal) in &normalizer.params {
+            let (value, data_type) = resolve_literal_scalar(literal);
+            params.push((*span, value));
+            param_types.push(data_type.to_string());
+        }
+
+        let settings = ctx.get_settings();
+        let changes = settings
+            .changes()
+            .iter()
+            .map(|change| format!("{}={}", change.key(), change.value().value))
+            .sorted()
+            .join(",");
+        let key = format!(
+            "{}/{}/{}/{}.{}/{}/{}/{}/{}",
+            ctx.get_tenant().tenant_name(),
+            ctx.get_current_user()
+                .map(|user| user.identity().encode())
+                .unwrap_or_default(),
+            ctx.get_current_role()
+                .map(|role| role.name)
+                .unwrap_or_default(),
+            ctx.get_current_catalog(),
+            ctx.get_current_database(),
+            changes,
+            ctx.get_cluster().is_empty(),
+            param_types.join(","),
+            normalized
+        );
+        PlanCacheKey {
+            key,
+            params,
+            statement: stmt.clone(),
+        }
+    }
+}
+
+struct PlanCacheEntry {
+    /// The parameters the plans are built with.
+    params: Vec<(Span, Scalar)>,
+    /// The tables read by the plan and their versions when the plan was built.
+    tables: Vec<(String, String, TableIdent)>,
+    /// The optimized plan folds the parameters into constants and derives the statistics
+    /// from them, so it is only reused for the same values.
+    plan: Plan,
+    extras: PlanExtras,
+    /// The plan before optimization, set if the parameters are bound verbatim into
+    /// constants, it is reused for other values of the parameters by replacing them.
+    bound_plan: Option<Plan>,
+}
+
+/// A plan reused from the plan cache.
+pub enum CachedPlan {
+    /// The optimized plan of the same query.
+    Optimized(Plan, PlanExtras),
+    /// The plan of the query with different parameters and its metadata, it still needs
+    /// to be optimized.
+    Bound(Plan, MetadataRef),
+}
+
+/// A per-node cache of the plans of queries.
+///
+/// A cached plan pins the tables it reads, so it is only reused while the versions of
+/// all the tables are unchanged, any commit or schema change of a table invalidates the
+/// plans reading it. Queries reading external catalogs, table functions, stages, views or
+/// masked columns, and queries calling non-deterministic or session dependent functions
+/// are never cached.
+pub struct PlanCache;
+
+impl PlanCache {
+    #[async_backtrace::framed]
+    pub async fn get(ctx: &dyn TableContext, key: &PlanCacheKey) -> Result<Option<CachedPlan>> {
+        let Some(entry) = PLAN_CACHE.lock().get(&key.key).cloned() else {
+            return Ok(None);
+        };
+        let same_params = entry
+            .params
+            .iter()
+            .map(|(_, value)| value)
+            .eq(key.params.iter().map(|(_, value)| value));
+        if !same_params && entry.bound_plan.is_none() {
+            return Ok(None);
+        }
+
+        for (database, name, ident) in &entry.tables {
+            let table = ctx.get_table(CATALOG_DEFAULT, database, name).await;
+            if !matches!(table, Ok(table) if table.get_table_info().ident == *ident) {
+                PLAN_CACHE.lock().pop(&key.key);
+                return Ok(None);
+            }
+        }
+
+        if same_params {
+            info!("reuse cached plan of query: {}", entry.extras.statement);
+            let (plan, extras) = deep_clone(&entry.plan, &entry.extras);
+            return Ok(Some(CachedPlan::Optimized(plan, extras)));
+        }
+
+        info!(
+            "reuse cached plan with new parameters of query: {}",
+            key.statement
+        );
+        let bound_plan = entry.bound_plan.as_ref().unwrap();
+        let mut binder = ParamBinder::new(&entry.params, Some(&key.params));
+        let Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            rewrite_kind,
+            formatted_ast,
+            ignore_result,
+        } = bound_plan
+        else {
+            unreachable!()
+        };
+        let s_expr = binder.visit_s_expr(s_expr)?;
+        let formatted_ast = match formatted_ast {
+            Some(_) => Some(format_statement(key.statement.clone())?),
+            None => None,
+        };
+        let metadata = Arc::new(RwLock::new(metadata.read().clone()));
+        let plan = Plan::Query {
+            s_expr: Box::new(s_expr),
+            metadata: metadata.clone(),
+            bind_context: bind_context.clone(),
+            rewrite_kind: rewrite_kind.clone(),
+            formatted_ast,
+            ignore_result: *ignore_result,
+        };
+        Ok(Some(CachedPlan::Bound(plan, metadata)))
+    }
+
+    pub fn put(
+        ctx: &dyn TableContext,
+        key: PlanCacheKey,
+        bound_plan: Plan,
+        plan: &Plan,
+        extras: &PlanExtras,
+    ) {
+        if !matches!(plan, Plan::Query { .. }) || !ctx.get_cacheable() {
+            return;
+        }
+
+        let mut tables = vec![];
+        for entry in extras.metadata.read().tables() {
+            let table = entry.table();
+            // The definitions of views and masking policies are not versioned with the tables.
+            if entry.catalog() != CATALOG_DEFAULT
+                || entry.is_source_of_view()
+                || entry.is_source_of_stage()
+                || entry.is_consume()
+                || table.table_args().is_some()
+                || table.get_table_info().meta.column_mask_policy.is_some()
+            {
+                return;
+            }
+            tables.push((
+                entry.database().to_string(),
+                entry.name().to_string(),
+                table.get_table_info().ident,
+            ));
+        }
+
+        let bound_plan = (!key.params.is_empty() && is_parameterized(&bound_plan, &key.params))
+            .then_some(bound_plan);
+        let (plan, extras) = deep_clone(plan, extras);
+        PLAN_CACHE.lock().put(
+            key.key,
+            Arc::new(PlanCacheEntry {
+                params: key.params,
+                tables,
+                plan,
+                extras,
+                bound_plan,
+            }),
+        );
+    }
+}
+
+/// Whether all the constants bound from the parameters are the verbatim values of the
+/// parameters in the plan operators, so they can be replaced by other values. A parameter
+/// folded or cast into another constant, or used outside the operators, like in the
+/// derived columns of the metadata, can not be replaced.
+fn is_parameterized(bound_plan: &Plan, params: &[(Span, Scalar)]) -> bool {
+    let Plan::Query {
+        s_expr, metadata, ..
+    } = bound_plan
+    else {
+        return false;
+    };
+    let mut binder = ParamBinder::new(params, None);
+    if binder.visit_s_expr(s_expr).is_err() {
+        return false;
+    }
+    let mut metadata_binder = ParamBinder::new(params, None);
+    for column in metadata.read().columns() {
+        if let ColumnEntry::DerivedColumn(column) = column {
+            if let Some(mut scalar) = column.scalar_expr.clone() {
+                if metadata_binder.visit(&mut scalar).is_err() {
+                    return false;
+                }
+            }
+        }
+    }
+    binder
+        .occurrences
+        .iter()
+        .zip(metadata_binder.occurrences.iter())
+        .all(|((verbatim, others), (in_metadata, in_metadata_others))| {
+            *verbatim > 0 && *others == 0 && *in_metadata == 0 && *in_metadata_others == 0
+        })
+}
+
+/// Finds the constants bound from the parameters in a plan, and replaces them by the
+/// values of the new parameters if there are.
+struct ParamBinder<'a> {
+    params: &'a [(Span, Scalar)],
+    new_params: Option<&'a [(Span, Scalar)]>,
+    /// The occurrences of each parameter, bound verbatim or into other constants.
+    occurrences: Vec<(usize, usize)>,
+}
+
+impl<'a> ParamBinder<'a> {
+    fn new(params: &'a [(Span, Scalar)], new_params: Option<&'a [(Span, Scalar)]>) -> Self {
+        ParamBinder {
+            params,
+            new_params,
+            occurrences: vec![(0, 0); params.len()],
+        }
+    }
+
+    fn visit_s_expr(&mut self, s_expr: &SExpr) -> Result<SExpr> {
+        let mut plan = s_expr.plan().clone();
+        match &mut plan {
+            RelOperator::Scan(scan) => {
+                for predicate in scan.push_down_predicates.iter_mut().flatten() {
+                    self.visit(predicate)?;
+                }
+                if let Some(prewhere) = &mut scan.prewhere {
+                    for predicate in &mut prewhere.predicates {
+                        self.visit(predicate)?;
+                    }
+                }
+            }
+            RelOperator::Join(join) => {
+                for condition in &mut join.equi_conditions {
+                    self.visit(&mut condition.left)?;
+                    self.visit(&mut condition.right)?;
+                }
+                for condition in &mut join.non_equi_conditions {
+                    self.visit(condition)?;
+                }
+            }
+            RelOperator::EvalScalar(eval_scalar) => {
+                for item in &mut eval_scalar.items {
+                    self.visit(&mut item.scalar)?;
+                }
+            }
+            RelOperator::Filter(filter) => {
+                for predicate in &mut filter.predicates {
+                    self.visit(predicate)?;
+                }
+            }
+            RelOperator::Aggregate(aggregate) => {
+                for item in aggregate
+                    .group_items
+                    .iter_mut()
+                    .chain(aggregate.aggregate_functions.iter_mut())
+                {
+                    self.visit(&mut item.scalar)?;
+                }
+            }
+            RelOperator::Sort(sort) => {
+                for item in &mut sort.window_partition {
+                    self.visit(&mut item.scalar)?;
+                }
+            }
+            RelOperator::Window(window) => {
+                for item in window
+                    .arguments
+                    .iter_mut()
+                    .chain(window.partition_by.iter_mut())
+                {
+                    self.visit(&mut item.scalar)?;
+                }
+                for order_by in &mut window.order_by {
+                    self.visit(&mut order_by.order_by_item.scalar)?;
+                }
+                match &mut window.function {
+                    WindowFuncType::Aggregate(func) => {
+                        for arg in &mut func.args {
+                            self.visit(arg)?;
+                        }
+                    }
+                    WindowFuncType::LagLead(func) => {
+                        self.visit(&mut func.arg)?;
+                        if let Some(default) = &mut func.default {
+                            self.visit(default)?;
+                        }
+                    }
+                    WindowFuncType::NthValue(func) => self.visit(&mut func.arg)?,
+                    _ => {}
+                }
+            }
+            RelOperator::ProjectSet(project_set) => {
+                for srf in &mut project_set.srfs {
+                    self.visit(&mut srf.scalar)?;
+                }
+            }
+            RelOperator::Udf(udf) => {
+                for item in &mut udf.items {
+                    self.visit(&mut item.scalar)?;
+                }
+            }
+            RelOperator::UnionAll(union_all) => {
+                for (_, scalar) in union_all
+                    .left_outputs
+                    .iter_mut()
+                    .chain(union_all.right_outputs.iter_mut())
+                {
+                    if let Some(scalar) = scalar {
+                        self.visit(scalar)?;
+                    }
+                }
+            }
+            RelOperator::ExpressionScan(expression_scan) => {
+                for scalar in expression_scan.values.iter_mut().flatten() {
+                    self.visit(scalar)?;
+                }
+            }
+            RelOperator::Limit(_)
+            | RelOperator::DummyTableScan(_)
+            | RelOperator::ConstantTableScan(_)
+            | RelOperator::CteScan(_)
+            | RelOperator::MaterializedCte(_)
+            | RelOperator::RecursiveCteScan(_)
+            | RelOperator::CacheScan(_) => {}
+            _ => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "replace the parameters of {:?}",
+                    plan.rel_op()
+                )));
+            }
+        }
+
+        let children = s_expr
+            .children()
+            .map(|child| Ok(Arc::new(self.visit_s_expr(child)?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(SExpr::create(Arc::new(plan), children, None, None, None))
+    }
+}
+
+impl<'b> VisitorMut<'b> for ParamBinder<'_> {
+    fn visit_constant_expr(&mut self, constant: &'b mut ConstantExpr) -> Result<()> {
+        if constant.span.is_none() {
+            return Ok(());
+        }
+        let Some(index) = self
+            .params
+            .iter()
+            .position(|(span, _)| *span == constant.span)
+        else {
+            return Ok(());
+        };
+        if constant.value != self.params[index].1 {
+            self.occurrences[index].1 += 1;
+            return Ok(());
+        }
+        self.occurrences[index].0 += 1;
+        if let Some(new_params) = self.new_params {
+            let (span, value) = &new_params[index];
+            constant.span = *span;
+            constant.value = value.clone();
+        }
+        Ok(())
+    }
+
+    fn visit_subquery_expr(&mut self, subquery: &'b mut SubqueryExpr) -> Result<()> {
+        if let Some(child_expr) = subquery.child_expr.as_mut() {
+            self.visit(child_expr)?;
+        }
+        subquery.subquery = Box::new(self.visit_s_expr(&subquery.subquery)?);
+        Ok(())
+    }
+}
+
+/// Copies the query plan with its own metadata, the metadata is still changed by the
+/// query while optimizing and building the physical plan, and must not be shared with
+/// the cache. Returns None if the plan is not a query.
+pub fn clone_query_plan(plan: &Plan) -> Option<Plan> {
+    let Plan::Query {
+        s_expr,
+        metadata,
+        bind_context,
+        rewrite_kind,
+        formatted_ast,
+        ignore_result,
+    } = plan
+    else {
+        return None;
+    };
+    Some(Plan::Query {
+        s_expr: s_expr.clone(),
+        metadata: Arc::new(RwLock::new(metadata.read().clone())),
+        bind_context: bind_context.clone(),
+        rewrite_kind: rewrite_kind.clone(),
+        formatted_ast: formatted_ast.clone(),
+        ignore_result: *ignore_result,
+    })
+}
+
+fn deep_clone(plan: &Plan, extras: &PlanExtras) -> (Plan, PlanExtras) {
+    let plan = clone_query_plan(plan).unwrap();
+    let Plan::Query { metadata, .. } = &plan else {
+        unreachable!()
+    };
+    let extras = PlanExtras {
+        metadata: metadata.clone(),
+        format: extras.format.clone(),
+        statement: extras.statement.clone(),
+    };
+    (plan, extras)
+}
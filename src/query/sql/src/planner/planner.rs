@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -35,10 +36,16 @@ use log::info;
 use log::warn;
 use parking_lot::RwLock;
 
+use super::plan_cache::clone_query_plan;
+use super::plan_cache::CachedPlan;
+use super::plan_cache::PlanCache;
+use super::plan_cache::PlanCacheKey;
 use super::semantic::AggregateRewriter;
 use super::semantic::DistinctToGroupBy;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
+use crate::optimizer::RuleID;
+use crate::optimizer::RuleSet;
 use crate::plans::Insert;
 use crate::plans::InsertInputSource;
 use crate::plans::Plan;
@@ -150,39 +157,80 @@ impl Planner {
 
                 self.replace_stmt(&mut stmt)?;
 
+                let plan_cache_key =
+                    if settings.get_enable_plan_cache()? && matches!(stmt, Statement::Query(_)) {
+                        Some(PlanCacheKey::create(self.ctx.as_ref(), &stmt))
+                    } else {
+                        None
+                    };
+                let cached_plan = match &plan_cache_key {
+                    Some(key) => PlanCache::get(self.ctx.as_ref(), key).await?,
+                    None => None,
+                };
+
                 // Step 3: Bind AST with catalog, and generate a pure logical SExpr
-                let metadata = Arc::new(RwLock::new(Metadata::default()));
-                let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
-                let binder = Binder::new(
-                    self.ctx.clone(),
-                    CatalogManager::instance(),
-                    name_resolution_ctx,
-                    metadata.clone(),
-                );
+                let (plan, metadata) = match cached_plan {
+                    Some(CachedPlan::Optimized(plan, extras)) => {
+                        self.ctx
+                            .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
+                        return Ok((plan, extras));
+                    }
+                    Some(CachedPlan::Bound(plan, metadata)) => {
+                        self.ctx
+                            .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
+                        (plan, metadata)
+                    }
+                    None => {
+                        let metadata = Arc::new(RwLock::new(Metadata::default()));
+                        let name_resolution_ctx =
+                            NameResolutionContext::try_from(settings.as_ref())?;
+                        let binder = Binder::new(
+                            self.ctx.clone(),
+                            CatalogManager::instance(),
+                            name_resolution_ctx,
+                            metadata.clone(),
+                        );
 
-                // Indicate binder there is no need to collect column statistics for the binding table.
-                self.ctx
-                    .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
-                let plan = binder.bind(&stmt).await?;
-                // attach again to avoid the query kind is overwritten by the subquery
-                self.ctx
-                    .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
+                        // Indicate binder there is no need to collect column statistics for the binding table.
+                        self.ctx
+                            .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
+                        let plan = binder.bind(&stmt).await?;
+                        // attach again to avoid the query kind is overwritten by the subquery
+                        self.ctx
+                            .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
+                        (plan, metadata)
+                    }
+                };
+                // the optimizer changes the metadata, keep a copy of the plan to cache.
+                let bound_plan = plan_cache_key
+                    .as_ref()
+                    .and_then(|_| clone_query_plan(&plan));
 
                 // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
+                let disabled_rules = settings
+                    .get_disabled_optimizer_rules()?
+                    .iter()
+                    .map(|name| RuleID::from_str(name))
+                    .collect::<Result<Vec<_>>>()?;
                 let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
                     .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
                     .with_enable_join_reorder(unsafe { !settings.get_disable_join_reorder()? })
                     .with_enable_dphyp(settings.get_enable_dphyp()?)
                     .with_enable_merge_into_join_reorder(
                         !settings.get_disable_merge_into_join_reorder()?,
-                    );
+                    )
+                    .with_disabled_rules(RuleSet::create_with_ids(disabled_rules));
 
                 let optimized_plan = optimize(opt_ctx, plan).await?;
-                Ok((optimized_plan, PlanExtras {
+                let extras = PlanExtras {
                     metadata,
                     format,
                     statement: stmt,
-                }))
+                };
+                if let (Some(key), Some(bound_plan)) = (plan_cache_key, bound_plan) {
+                    PlanCache::put(self.ctx.as_ref(), key, bound_plan, &optimized_plan, &extras);
+                }
+                Ok((optimized_plan, extras))
             }
             .await;
 
@@ -29,6 +29,7 @@ pub struct CopyIntoLocationPlan {
     pub stage: Box<StageInfo>,
     pub path: String,
     pub from: Box<Plan>,
+    pub enable_distributed: bool,
 }
 
 impl CopyIntoLocationPlan {
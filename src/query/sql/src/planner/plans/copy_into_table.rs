@@ -36,6 +36,7 @@ use databend_common_meta_app::principal::COPY_MAX_FILES_PER_COMMIT;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_metrics::storage::*;
 use databend_common_storage::init_stage_operator;
+use databend_common_storage::StageFileInfo;
 use log::info;
 
 use crate::plans::Plan;
@@ -152,6 +153,7 @@ impl CopyIntoTablePlan {
         } else {
             Some(max_files)
         };
+        let size_limit = stage_table_info.stage_info.copy_options.size_limit;
 
         let thread_num = ctx.get_settings().get_max_threads()? as usize;
         let operator = init_stage_operator(&stage_table_info.stage_info)?;
@@ -188,6 +190,7 @@ impl CopyIntoTablePlan {
         ));
 
         let (need_copy_file_infos, duplicated) = if self.force {
+            let all_source_file_infos = limit_files_by_size(all_source_file_infos, size_limit);
             if !self.stage_table_info.stage_info.copy_options.purge
                 && all_source_file_infos.len() > COPY_MAX_FILES_PER_COMMIT
             {
@@ -227,15 +230,19 @@ impl CopyIntoTablePlan {
                 .as_millis();
             metrics_inc_copy_filter_out_copied_files_entire_milliseconds(cost_filter_out as u64);
 
-            (files_to_copy, duplicated_files)
+            (
+                limit_files_by_size(files_to_copy, size_limit),
+                duplicated_files,
+            )
         };
 
         let num_copied_files = need_copy_file_infos.len();
         let copied_bytes: u64 = need_copy_file_infos.iter().map(|i| i.size).sum();
 
         info!(
-            "collect files with max_files={:?} finished, need to copy {} files, {} bytes; skip {} duplicated files, time used:{:?}",
+            "collect files with max_files={:?} size_limit={} finished, need to copy {} files, {} bytes; skip {} duplicated files, time used:{:?}",
             max_files,
+            size_limit,
             need_copy_file_infos.len(),
             copied_bytes,
             num_all_files - num_copied_files,
@@ -253,6 +260,24 @@ impl CopyIntoTablePlan {
     }
 }
 
+/// Keep the files to load until their accumulated size exceeds `size_limit`,
+/// the file crossing the limit is still loaded. `0` means no limit.
+fn limit_files_by_size(mut files: Vec<StageFileInfo>, size_limit: usize) -> Vec<StageFileInfo> {
+    if size_limit == 0 {
+        return files;
+    }
+    let mut total_size = 0;
+    let num_files = files
+        .iter()
+        .position(|file| {
+            total_size += file.size;
+            total_size >= size_limit as u64
+        })
+        .map_or(files.len(), |pos| pos + 1);
+    files.truncate(num_files);
+    files
+}
+
 impl Debug for CopyIntoTablePlan {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         let CopyIntoTablePlan {
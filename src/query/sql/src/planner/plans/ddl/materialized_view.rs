@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::plans::CreateTablePlan;
+
+#[derive(Clone, Debug)]
+pub struct CreateMaterializedViewPlan {
+    /// Creates the fuse table that keeps the result of the view, the view query
+    /// and refresh mode are kept in the table options.
+    pub create_table_plan: CreateTablePlan,
+    /// The `(database, table)` of the base table, if the view reads a single fuse table.
+    pub base_table: Option<(String, String)>,
+}
+
+impl CreateMaterializedViewPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RefreshMaterializedViewPlan {
+    pub catalog: String,
+    pub database: String,
+    pub view: String,
+    /// Recompute the whole view even if it is refreshed incrementally.
+    pub full: bool,
+}
+
+impl RefreshMaterializedViewPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
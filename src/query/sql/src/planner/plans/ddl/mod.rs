@@ -19,6 +19,7 @@ mod database;
 mod dynamic_table;
 mod file_format;
 mod index;
+mod materialized_view;
 mod notification;
 mod procedure;
 mod sequence;
@@ -37,6 +38,7 @@ pub use database::*;
 pub use dynamic_table::*;
 pub use file_format::*;
 pub use index::*;
+pub use materialized_view::*;
 pub use notification::*;
 pub use procedure::*;
 pub use sequence::*;
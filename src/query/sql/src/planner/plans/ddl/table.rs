@@ -55,6 +55,8 @@ pub struct CreateTablePlan {
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
+    /// The `(catalog, database, table)` of the source table of `CREATE TABLE ... CLONE`.
+    pub clone_source: Option<(String, String, String)>,
     pub inverted_indexes: Option<BTreeMap<String, TableIndex>>,
 }
 
@@ -169,6 +171,39 @@ impl VacuumDropTablePlan {
     }
 }
 
+/// Vacuum all the tables of a database, or of the tenant when `database` is `None`
+#[derive(Clone, Debug)]
+pub struct VacuumAllTablesPlan {
+    pub catalog: String,
+    pub database: Option<String>,
+    pub option: VacuumTableOption,
+}
+
+impl VacuumAllTablesPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        if let Some(summary) = self.option.dry_run {
+            if summary {
+                Arc::new(DataSchema::new(vec![
+                    DataField::new("table", DataType::String),
+                    DataField::new("total_files", DataType::Number(NumberDataType::UInt64)),
+                    DataField::new("total_size", DataType::Number(NumberDataType::UInt64)),
+                ]))
+            } else {
+                Arc::new(DataSchema::new(vec![
+                    DataField::new("table", DataType::String),
+                    DataField::new("file", DataType::String),
+                    DataField::new("file_size", DataType::Number(NumberDataType::UInt64)),
+                ]))
+            }
+        } else {
+            Arc::new(DataSchema::new(vec![DataField::new(
+                "table",
+                DataType::String,
+            )]))
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct VacuumTemporaryFilesPlan {
     pub limit: Option<u64>,
@@ -193,6 +228,8 @@ pub struct VacuumDropTableOption {
 
 #[derive(Debug, Clone)]
 pub struct VacuumTableOption {
+    // Overrides the `data_retention_time_in_days` setting
+    pub retain: Option<Duration>,
     pub dry_run: Option<bool>,
 }
 
@@ -246,6 +283,8 @@ impl ModifyTableCommentPlan {
 #[derive(Clone, Debug)]
 pub struct SetOptionsPlan {
     pub set_options: TableOptions,
+    // Option keys to be removed from the table
+    pub unset_options: Vec<String>,
     pub catalog: String,
     pub database: String,
     pub table: String,
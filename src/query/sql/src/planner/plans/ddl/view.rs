@@ -25,6 +25,10 @@ pub struct CreateViewPlan {
     pub view_name: String,
     pub column_names: Vec<String>,
     pub subquery: String,
+    /// The parameters of a parameterized view, e.g. `p INT, q STRING DEFAULT 'a'`.
+    pub parameters: Option<String>,
+    /// Whether the view is a secure view.
+    pub secure: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -35,6 +39,8 @@ pub struct AlterViewPlan {
     pub view_name: String,
     pub column_names: Vec<String>,
     pub subquery: String,
+    /// The parameters of a parameterized view, e.g. `p INT, q STRING DEFAULT 'a'`.
+    pub parameters: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use databend_common_ast::ast::FormatTreeNode;
+use databend_common_base::base::tokio::sync::mpsc::Receiver;
+use databend_common_exception::Result;
 use databend_common_expression::types::StringType;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
@@ -23,18 +25,24 @@ use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::TableInfo;
 use enum_as_inner::EnumAsInner;
+use parking_lot::Mutex;
 use serde::Deserialize;
 use serde::Serialize;
 
 use super::Plan;
 use crate::plans::CopyIntoTablePlan;
 
+/// Receiver of the data blocks pushed by the client, taken by the insert pipeline.
+pub type InsertBlockReceiver = Arc<Mutex<Option<Receiver<Result<DataBlock>>>>>;
+
 #[derive(Clone, Debug, EnumAsInner)]
 pub enum InsertInputSource {
     SelectPlan(Box<Plan>),
     Values(InsertValue),
     // From stage
     Stage(Box<Plan>),
+    // Data blocks pushed by the client, e.g. arrow record batches of flight `DoExchange`
+    Stream(InsertBlockReceiver),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -154,6 +162,11 @@ pub(crate) fn format_insert_source(
             )
             .format_pretty()?),
         },
+        InsertInputSource::Stream(_) => Ok(FormatTreeNode::with_children(
+            format!("{plan_name} (stream):"),
+            children,
+        )
+        .format_pretty()?),
         InsertInputSource::Stage(plan) => match *plan.clone() {
             Plan::CopyIntoTable(copy_plan) => {
                 let CopyIntoTablePlan {
@@ -414,6 +414,51 @@ impl Join {
         Ok(join_card)
     }
 
+    /// Estimate the fraction of rows on one side having a match on the other side by NDV,
+    /// assuming the distinct keys of the side with fewer NDV are contained in the other side.
+    /// Returns None if there are non-equi conditions or no NDV statistics of the join keys.
+    fn semi_join_selectivity(
+        &self,
+        statistics: &Statistics,
+        other_statistics: &Statistics,
+        is_left: bool,
+    ) -> Option<f64> {
+        if !self.non_equi_conditions.is_empty() {
+            return None;
+        }
+        let mut selectivity = None;
+        for condition in self.equi_conditions.iter() {
+            let (condition, other_condition) = if is_left {
+                (&condition.left, &condition.right)
+            } else {
+                (&condition.right, &condition.left)
+            };
+            let (used_columns, other_used_columns) =
+                (condition.used_columns(), other_condition.used_columns());
+            if used_columns.len() != 1 || other_used_columns.len() != 1 {
+                continue;
+            }
+            let (Some(stat), Some(other_stat)) = (
+                statistics
+                    .column_stats
+                    .get(used_columns.iter().next().unwrap()),
+                other_statistics
+                    .column_stats
+                    .get(other_used_columns.iter().next().unwrap()),
+            ) else {
+                continue;
+            };
+            if stat.ndv == 0.0 {
+                continue;
+            }
+            let condition_selectivity = f64::min(other_stat.ndv / stat.ndv, 1.0);
+            selectivity = Some(
+                selectivity.map_or(condition_selectivity, |s: f64| s.min(condition_selectivity)),
+            );
+        }
+        selectivity
+    }
+
     pub fn has_null_equi_condition(&self) -> bool {
         self.equi_conditions
             .iter()
@@ -421,6 +466,14 @@ impl Join {
     }
 }
 
+/// The rows of a semi or anti join kept with `selectivity`, at least one row of
+/// a non-empty input. The NDV based selectivity assumes the keys of one side are
+/// contained in the other side, an anti join estimated to keep no rows would make
+/// the rest of the plan look free.
+fn semi_join_cardinality(cardinality: f64, selectivity: f64) -> f64 {
+    f64::max(cardinality * selectivity, f64::min(cardinality, 1.0))
+}
+
 impl Operator for Join {
     fn rel_op(&self) -> RelOp {
         RelOp::Join
@@ -518,6 +571,27 @@ impl Operator for Join {
     fn derive_stats(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
         let left_stat_info = rel_expr.derive_cardinality_child(0)?;
         let right_stat_info = rel_expr.derive_cardinality_child(1)?;
+        // Evaluate the selectivity of semi and anti joins before the statistics
+        // are updated by the inner join cardinality estimation.
+        let (left_semi_selectivity, right_semi_selectivity) = match self.join_type {
+            JoinType::LeftSemi | JoinType::LeftAnti => (
+                self.semi_join_selectivity(
+                    &left_stat_info.statistics,
+                    &right_stat_info.statistics,
+                    true,
+                ),
+                None,
+            ),
+            JoinType::RightSemi | JoinType::RightAnti => (
+                None,
+                self.semi_join_selectivity(
+                    &right_stat_info.statistics,
+                    &left_stat_info.statistics,
+                    false,
+                ),
+            ),
+            _ => (None, None),
+        };
         let (mut left_cardinality, mut left_statistics) = (
             left_stat_info.cardinality,
             left_stat_info.statistics.clone(),
@@ -543,10 +617,30 @@ impl Operator for Join {
                     + f64::max(right_cardinality, inner_join_cardinality)
                     - inner_join_cardinality
             }
-            JoinType::LeftSemi => f64::min(left_cardinality, inner_join_cardinality),
-            JoinType::RightSemi => f64::min(right_cardinality, inner_join_cardinality),
-            JoinType::LeftSingle | JoinType::RightMark | JoinType::LeftAnti => left_cardinality,
-            JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
+            JoinType::LeftSemi => match left_semi_selectivity {
+                Some(selectivity) => semi_join_cardinality(left_stat_info.cardinality, selectivity),
+                None => f64::min(left_cardinality, inner_join_cardinality),
+            },
+            JoinType::RightSemi => match right_semi_selectivity {
+                Some(selectivity) => {
+                    semi_join_cardinality(right_stat_info.cardinality, selectivity)
+                }
+                None => f64::min(right_cardinality, inner_join_cardinality),
+            },
+            JoinType::LeftAnti => match left_semi_selectivity {
+                Some(selectivity) => {
+                    semi_join_cardinality(left_stat_info.cardinality, 1.0 - selectivity)
+                }
+                None => left_cardinality,
+            },
+            JoinType::RightAnti => match right_semi_selectivity {
+                Some(selectivity) => {
+                    semi_join_cardinality(right_stat_info.cardinality, 1.0 - selectivity)
+                }
+                None => right_cardinality,
+            },
+            JoinType::LeftSingle | JoinType::RightMark => left_cardinality,
+            JoinType::RightSingle | JoinType::LeftMark => right_cardinality,
         };
         // Derive column statistics
         let column_stats = if cardinality == 0.0 {
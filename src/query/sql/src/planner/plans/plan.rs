@@ -16,6 +16,8 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_expression::types::DataType;
@@ -48,6 +50,7 @@ use crate::plans::CreateDatamaskPolicyPlan;
 use crate::plans::CreateDynamicTablePlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreateNotificationPlan;
 use crate::plans::CreatePasswordPolicyPlan;
@@ -113,6 +116,7 @@ use crate::plans::OptimizeCompactSegmentPlan;
 use crate::plans::OptimizePurgePlan;
 use crate::plans::PresignPlan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::plans::RefreshTableIndexPlan;
 use crate::plans::RefreshVirtualColumnPlan;
 use crate::plans::RelOperator;
@@ -148,6 +152,7 @@ use crate::plans::UndropDatabasePlan;
 use crate::plans::UndropTablePlan;
 use crate::plans::UnsetPlan;
 use crate::plans::UseDatabasePlan;
+use crate::plans::VacuumAllTablesPlan;
 use crate::plans::VacuumDropTablePlan;
 use crate::plans::VacuumTablePlan;
 use crate::plans::VacuumTemporaryFilesPlan;
@@ -220,6 +225,7 @@ pub enum Plan {
     TruncateTable(Box<TruncateTablePlan>),
     VacuumTable(Box<VacuumTablePlan>),
     VacuumDropTable(Box<VacuumDropTablePlan>),
+    VacuumAllTables(Box<VacuumAllTablesPlan>),
     VacuumTemporaryFiles(Box<VacuumTemporaryFilesPlan>),
     AnalyzeTable(Box<AnalyzeTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
@@ -359,8 +365,17 @@ pub enum Plan {
 
     CreateDynamicTable(Box<CreateDynamicTablePlan>),
 
+    // Materialized views
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
+
     // Txn
-    Begin,
+    Begin {
+        read_only_at: Option<DateTime<Utc>>,
+    },
+    SetTransactionSnapshot {
+        at: DateTime<Utc>,
+    },
     Commit,
     Abort,
 
@@ -433,6 +448,116 @@ impl Plan {
             _ => QueryKind::Other,
         }
     }
+
+    /// Whether the plan can run inside a read-only transaction.
+    ///
+    /// This is an allow-list: any plan not listed here may write data or
+    /// metadata and is rejected by `BEGIN READ ONLY AT`.
+    pub fn is_read_only(&self) -> bool {
+        match self {
+            Plan::ExplainAnalyze { plan } => plan.is_read_only(),
+            Plan::Query { .. }
+            | Plan::Explain { .. }
+            | Plan::ExplainAst { .. }
+            | Plan::ExplainSyntax { .. }
+            | Plan::CopyIntoLocation(_)
+            | Plan::ShowCreateCatalog(_)
+            | Plan::ShowCreateDatabase(_)
+            | Plan::ShowCreateTable(_)
+            | Plan::DescribeTable(_)
+            | Plan::ExistsTable(_)
+            | Plan::DescribeView(_)
+            | Plan::UseDatabase(_)
+            | Plan::ShowRoles(_)
+            | Plan::ShowFileFormats(_)
+            | Plan::DescConnection(_)
+            | Plan::ShowConnections(_)
+            | Plan::ShowShareEndpoint(_)
+            | Plan::DescShare(_)
+            | Plan::ShowShares(_)
+            | Plan::ShowObjectGrantPrivileges(_)
+            | Plan::ShowGrantTenantsOfShare(_)
+            | Plan::DescDatamaskPolicy(_)
+            | Plan::DescNetworkPolicy(_)
+            | Plan::ShowNetworkPolicies(_)
+            | Plan::DescPasswordPolicy(_)
+            | Plan::DescribeTask(_)
+            | Plan::ShowTasks(_)
+            | Plan::DescNotification(_)
+            | Plan::Set(_)
+            | Plan::Unset(_)
+            | Plan::SetRole(_)
+            | Plan::SetSecondaryRoles(_)
+            | Plan::Kill(_)
+            | Plan::ExecuteImmediate(_)
+            | Plan::Begin { .. }
+            | Plan::SetTransactionSnapshot { .. }
+            | Plan::Commit
+            | Plan::Abort => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the plan creates, alters or drops a schema object, the statement class that
+    /// `ddl_statement_timeout_secs` applies to. Maintenance like vacuum, recluster or refresh
+    /// and session statements are not DDL.
+    pub fn is_ddl(&self) -> bool {
+        matches!(
+            self,
+            Plan::CreateCatalog(_)
+            | Plan::DropCatalog(_)
+            | Plan::CreateDatabase(_)
+            | Plan::DropDatabase(_)
+            | Plan::UndropDatabase(_)
+            | Plan::RenameDatabase(_)
+            | Plan::CreateTable(_)
+            | Plan::DropTable(_)
+            | Plan::UndropTable(_)
+            | Plan::RenameTable(_)
+            | Plan::ModifyTableComment(_)
+            | Plan::RenameTableColumn(_)
+            | Plan::AddTableColumn(_)
+            | Plan::DropTableColumn(_)
+            | Plan::ModifyTableColumn(_)
+            | Plan::AlterTableClusterKey(_)
+            | Plan::DropTableClusterKey(_)
+            | Plan::TruncateTable(_)
+            | Plan::SetOptions(_)
+            | Plan::CreateView(_)
+            | Plan::AlterView(_)
+            | Plan::DropView(_)
+            | Plan::CreateStream(_)
+            | Plan::DropStream(_)
+            | Plan::CreateIndex(_)
+            | Plan::DropIndex(_)
+            | Plan::CreateTableIndex(_)
+            | Plan::DropTableIndex(_)
+            | Plan::CreateVirtualColumn(_)
+            | Plan::AlterVirtualColumn(_)
+            | Plan::DropVirtualColumn(_)
+            | Plan::CreateUDF(_)
+            | Plan::AlterUDF(_)
+            | Plan::DropUDF(_)
+            | Plan::CreateFileFormat(_)
+            | Plan::DropFileFormat(_)
+            | Plan::CreateStage(_)
+            | Plan::DropStage(_)
+            | Plan::CreateConnection(_)
+            | Plan::DropConnection(_)
+            | Plan::CreateDatamaskPolicy(_)
+            | Plan::DropDatamaskPolicy(_)
+            | Plan::CreateTask(_)
+            | Plan::AlterTask(_)
+            | Plan::DropTask(_)
+            | Plan::CreateDynamicTable(_)
+            | Plan::CreateMaterializedView(_)
+            | Plan::CreateNotification(_)
+            | Plan::AlterNotification(_)
+            | Plan::DropNotification(_)
+            | Plan::CreateSequence(_)
+            | Plan::DropSequence(_)
+        )
+    }
 }
 
 impl Display for Plan {
@@ -463,6 +588,7 @@ impl Plan {
             Plan::DescribeTable(plan) => plan.schema(),
             Plan::VacuumTable(plan) => plan.schema(),
             Plan::VacuumDropTable(plan) => plan.schema(),
+            Plan::VacuumAllTables(plan) => plan.schema(),
             Plan::VacuumTemporaryFiles(plan) => plan.schema(),
             Plan::ExistsTable(plan) => plan.schema(),
             Plan::DescribeView(plan) => plan.schema(),
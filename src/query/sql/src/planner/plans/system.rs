@@ -23,4 +23,18 @@ pub struct SystemPlan {
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub enum SystemAction {
     Backtrace(bool),
+    ReloadConfig,
+    FlushLogs,
+    DropQueryCache,
+    SyncMetadata,
+}
+
+impl SystemAction {
+    /// Whether the action changes the state of the whole node, shared by all the tenants.
+    pub fn is_node_global(&self) -> bool {
+        matches!(
+            self,
+            SystemAction::ReloadConfig | SystemAction::FlushLogs | SystemAction::SyncMetadata
+        )
+    }
 }
@@ -31,6 +31,7 @@ use databend_common_ast::parser::Dialect;
 use databend_common_expression::BLOCK_NAME_COL_NAME;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 use derive_visitor::Visitor;
 use derive_visitor::VisitorMut;
@@ -195,6 +196,58 @@ impl AggregatingIndexRewriter {
     }
 }
 
+/// Expand the `GROUP BY ALL` of the `CREATE AGGREGATING INDEX idx ON t (...)` shorthand
+/// into the select targets that don't contain aggregate functions.
+#[derive(Debug, Clone, Default, VisitorMut)]
+#[visitor(SelectStmt(enter))]
+pub struct AggregatingIndexGroupByAllRewriter;
+
+impl AggregatingIndexGroupByAllRewriter {
+    fn enter_select_stmt(&mut self, stmt: &mut SelectStmt) {
+        if !matches!(stmt.group_by, Some(GroupBy::All)) {
+            return;
+        }
+        let mut groups = vec![];
+        for target in &stmt.select_list {
+            if let SelectTarget::AliasedExpr { expr, .. } = target {
+                let mut finder = AggregateFunctionFinder::default();
+                expr.drive(&mut finder);
+                if !finder.found {
+                    groups.push(expr.as_ref().clone());
+                }
+            }
+        }
+        stmt.group_by = if groups.is_empty() {
+            None
+        } else {
+            Some(GroupBy::Normal(groups))
+        };
+    }
+}
+
+#[derive(Debug, Clone, Default, Visitor)]
+#[visitor(Expr(enter))]
+struct AggregateFunctionFinder {
+    found: bool,
+}
+
+impl AggregateFunctionFinder {
+    fn enter_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::FunctionCall {
+                func: FunctionCall { name, window, .. },
+                ..
+            } if window.is_none() && AggregateFunctionFactory::instance().contains(&name.name) => {
+                self.found = true;
+            }
+            Expr::CountAll { window, .. } if window.is_none() => {
+                self.found = true;
+            }
+            _ => {}
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Visitor)]
 #[visitor(FunctionCall(enter), SelectStmt(enter), Query(enter))]
 pub struct AggregatingIndexChecker {
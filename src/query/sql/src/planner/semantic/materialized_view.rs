@@ -0,0 +1,198 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::GroupBy;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TemporalClause;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use derive_visitor::Drive;
+use derive_visitor::Visitor;
+
+/// How the result of a materialized view is maintained by an incremental refresh.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IncrementalRefresh {
+    /// The view doesn't aggregate, the rows of the changes are appended to the view.
+    Append,
+    /// The aggregated changes are merged into the view with the rollup function of
+    /// each output column, `None` for the group by keys.
+    Merge(Vec<Option<&'static str>>),
+}
+
+/// The function to merge the partial results of an aggregate function,
+/// if the function can be computed from its partial results.
+pub fn rollup_aggregate_function(func_name: &str) -> Option<&'static str> {
+    match func_name {
+        "sum" | "count" => Some("sum"),
+        "min" => Some("min"),
+        "max" => Some("max"),
+        _ => None,
+    }
+}
+
+#[derive(Default, Visitor)]
+#[visitor(FunctionCall(enter))]
+struct FunctionCallChecker {
+    has_aggregate: bool,
+    has_window: bool,
+    has_non_deterministic: bool,
+}
+
+impl FunctionCallChecker {
+    fn enter_function_call(&mut self, func: &FunctionCall) {
+        if func.window.is_some() {
+            self.has_window = true;
+        } else if AggregateFunctionFactory::instance().contains(&func.name.name) {
+            self.has_aggregate = true;
+        }
+        self.has_non_deterministic |= BUILTIN_FUNCTIONS
+            .get_property(&func.name.name)
+            .map(|p| p.non_deterministic)
+            .unwrap_or(false);
+    }
+}
+
+/// Checks if the query of a materialized view can be refreshed incrementally,
+/// that is a `SELECT ... FROM <table> [WHERE ...] [GROUP BY ...]` whose aggregate
+/// functions can be merged, and all the group by keys are in the output.
+pub fn check_incremental_refresh(query: &Query) -> Result<IncrementalRefresh> {
+    let unsupported = |reason: &str| {
+        ErrorCode::SemanticError(format!(
+            "materialized view can not be refreshed incrementally: {reason}"
+        ))
+    };
+
+    if query.with.is_some()
+        || !query.order_by.is_empty()
+        || !query.limit.is_empty()
+        || query.offset.is_some()
+    {
+        return Err(unsupported(
+            "WITH, ORDER BY, LIMIT and OFFSET are not supported",
+        ));
+    }
+    let SetExpr::Select(select) = &query.body else {
+        return Err(unsupported("only SELECT is supported"));
+    };
+    if select.distinct
+        || select.top_n.is_some()
+        || select.having.is_some()
+        || select.window_list.is_some()
+        || select.qualify.is_some()
+    {
+        return Err(unsupported(
+            "DISTINCT, TOP, HAVING, WINDOW and QUALIFY are not supported",
+        ));
+    }
+    if !matches!(select.from.as_slice(), [TableReference::Table {
+        temporal: None,
+        pivot: None,
+        unpivot: None,
+        ..
+    }]) {
+        return Err(unsupported("the view must read from a single table"));
+    }
+
+    let mut checker = FunctionCallChecker::default();
+    select.select_list.drive(&mut checker);
+    select.selection.drive(&mut checker);
+    if checker.has_window || checker.has_non_deterministic {
+        return Err(unsupported(
+            "window and non-deterministic functions are not supported",
+        ));
+    }
+
+    let group_by = match &select.group_by {
+        None if !checker.has_aggregate => return Ok(IncrementalRefresh::Append),
+        None => vec![],
+        Some(GroupBy::Normal(group_by)) => group_by.clone(),
+        Some(_) => return Err(unsupported("only GROUP BY <expr>, ... is supported")),
+    };
+
+    let mut rollups = Vec::with_capacity(select.select_list.len());
+    let mut keys = vec![];
+    for target in select.select_list.iter() {
+        let SelectTarget::AliasedExpr { expr, alias } = target else {
+            return Err(unsupported("`*` is not supported in an aggregation"));
+        };
+        match expr.as_ref() {
+            Expr::FunctionCall { func, .. }
+                if AggregateFunctionFactory::instance().contains(&func.name.name) =>
+            {
+                match rollup_aggregate_function(&func.name.name.to_lowercase()) {
+                    Some(rollup) if !func.distinct => rollups.push(Some(rollup)),
+                    _ => {
+                        return Err(unsupported(&format!(
+                            "aggregate function {} is not supported, only SUM, COUNT, MIN and MAX are supported",
+                            func.name
+                        )));
+                    }
+                }
+            }
+            expr => {
+                let mut checker = FunctionCallChecker::default();
+                expr.drive(&mut checker);
+                if checker.has_aggregate {
+                    return Err(unsupported(
+                        "an aggregate function must be a whole output column",
+                    ));
+                }
+                rollups.push(None);
+                keys.push((rollups.len(), expr, alias.as_ref()));
+            }
+        }
+    }
+
+    for expr in group_by.iter() {
+        let in_output = keys.iter().any(|(position, key, alias)| match expr {
+            Expr::Literal {
+                value: Literal::UInt64(n),
+                ..
+            } => *position as u64 == *n,
+            Expr::ColumnRef { column, .. } => {
+                let name = column.column.name();
+                alias.is_some_and(|alias| alias.name == name)
+                    || matches!(key, Expr::ColumnRef { column: key, .. } if key.column.name() == name)
+            }
+            expr => key.to_string() == expr.to_string(),
+        });
+        if !in_output {
+            return Err(unsupported(&format!(
+                "GROUP BY {expr} must be an output column of the view"
+            )));
+        }
+    }
+
+    Ok(IncrementalRefresh::Merge(rollups))
+}
+
+/// Replaces the temporal clause of the base table of a materialized view query,
+/// the query must have passed [`check_incremental_refresh`] or read a single table.
+pub fn with_base_table_temporal(query: &Query, temporal: TemporalClause) -> Query {
+    let mut query = query.clone();
+    if let SetExpr::Select(select) = &mut query.body {
+        if let [TableReference::Table { temporal: t, .. }] = select.from.as_mut_slice() {
+            *t = Some(temporal);
+        }
+    }
+    query
+}
@@ -19,6 +19,7 @@ mod count_set_ops;
 mod distinct_to_groupby;
 mod grouping_check;
 mod lowering;
+mod materialized_view;
 mod name_resolution;
 mod type_check;
 mod udf_rewriter;
@@ -28,6 +29,7 @@ mod window_check;
 
 pub use aggregate_rewriter::AggregateRewriter;
 pub use aggregating_index_visitor::AggregatingIndexChecker;
+pub use aggregating_index_visitor::AggregatingIndexGroupByAllRewriter;
 pub use aggregating_index_visitor::AggregatingIndexRewriter;
 pub use aggregating_index_visitor::RefreshAggregatingIndexRewriter;
 pub use async_function_rewriter::AsyncFunctionRewriter;
@@ -35,11 +37,16 @@ pub use count_set_ops::CountSetOps;
 pub use distinct_to_groupby::DistinctToGroupBy;
 pub use grouping_check::GroupingChecker;
 pub use lowering::*;
+pub use materialized_view::check_incremental_refresh;
+pub use materialized_view::rollup_aggregate_function;
+pub use materialized_view::with_base_table_temporal;
+pub use materialized_view::IncrementalRefresh;
 pub use name_resolution::compare_table_name;
 pub use name_resolution::normalize_identifier;
 pub use name_resolution::IdentifierNormalizer;
 pub use name_resolution::NameResolutionContext;
 pub use name_resolution::NameResolutionSuggest;
+pub use type_check::resolve_literal_scalar;
 pub use type_check::resolve_type_name;
 pub use type_check::resolve_type_name_by_str;
 pub use type_check::validate_function_arg;
@@ -251,6 +251,11 @@ impl<'a> TypeChecker<'a> {
                         column: ident,
                     },
             } => {
+                let qualifier = if database.is_none() {
+                    table.as_ref()
+                } else {
+                    None
+                };
                 let database = database
                     .as_ref()
                     .map(|ident| normalize_identifier(ident, self.name_resolution_ctx).name);
@@ -260,13 +265,45 @@ impl<'a> TypeChecker<'a> {
                 let result = match ident {
                     ColumnID::Name(ident) => {
                         let column = normalize_identifier(ident, self.name_resolution_ctx);
-                        self.bind_context.resolve_name(
+                        match self.bind_context.resolve_name(
                             database.as_deref(),
                             table.as_deref(),
                             &column,
                             self.aliases,
                             self.name_resolution_ctx,
-                        )?
+                        ) {
+                            Ok(result) => result,
+                            Err(err) => {
+                                // a parameter of the parameterized view being bound.
+                                if database.is_none() && table.is_none() {
+                                    if let Some(argument) =
+                                        self.bind_context.view_argument(&column.name)
+                                    {
+                                        let argument = argument.clone();
+                                        let data_type = argument.data_type()?;
+                                        return Ok(Box::new((argument, data_type)));
+                                    }
+                                }
+                                // `a.b` may also refer to the field `b` of tuple column `a`,
+                                // resolve it as `a:b` so the inner column can be pushed down.
+                                if let Some(qualifier) = qualifier {
+                                    if self.is_tuple_column(qualifier) {
+                                        let tuple_expr = Expr::ColumnRef {
+                                            span: qualifier.span,
+                                            column: ColumnRef {
+                                                database: None,
+                                                table: None,
+                                                column: ColumnID::Name(qualifier.clone()),
+                                            },
+                                        };
+                                        let paths =
+                                            VecDeque::from([(*span, Literal::String(column.name))]);
+                                        return self.resolve_map_access(&tuple_expr, paths);
+                                    }
+                                }
+                                return Err(err);
+                            }
+                        }
                     }
                     ColumnID::Position(pos) => self.bind_context.search_column_position(
                         pos.span,
@@ -2900,10 +2937,14 @@ impl<'a> TypeChecker<'a> {
                     ),
                 }),
             ),
-            ("connection_id", &[]) => Some(self.resolve(&Expr::Literal {
-                span,
-                value: Literal::String(self.ctx.get_connection_id()),
-            })),
+            ("connection_id", &[]) => {
+                // the value depends on the session, the plan must not be reused.
+                self.ctx.set_cacheable(false);
+                Some(self.resolve(&Expr::Literal {
+                    span,
+                    value: Literal::String(self.ctx.get_connection_id()),
+                }))
+            }
             ("timezone", &[]) => {
                 let tz = self.ctx.get_settings().get_timezone().unwrap();
                 Some(self.resolve(&Expr::Literal {
@@ -3104,6 +3145,7 @@ impl<'a> TypeChecker<'a> {
 
                 Some(match res {
                     Ok(index) => {
+                        self.ctx.set_cacheable(false);
                         let query_id = self.ctx.get_last_query_id(index as i32);
                         self.resolve(&Expr::Literal {
                             span,
@@ -3222,6 +3264,7 @@ impl<'a> TypeChecker<'a> {
 
                 if let Ok(arg) = ConstantExpr::try_from(scalar) {
                     if let Scalar::String(var_name) = arg.value {
+                        self.ctx.set_cacheable(false);
                         let var_value = self.ctx.get_variable(&var_name).unwrap_or(Scalar::Null);
                         let var_value = shrink_scalar(var_value);
                         let data_type = var_value.as_ref().infer_data_type();
@@ -3278,24 +3321,7 @@ impl<'a> TypeChecker<'a> {
         &self,
         literal: &databend_common_ast::ast::Literal,
     ) -> Result<Box<(Scalar, DataType)>> {
-        let value = match literal {
-            Literal::UInt64(value) => Scalar::Number(NumberScalar::UInt64(*value)),
-            Literal::Decimal256 {
-                value,
-                precision,
-                scale,
-            } => Scalar::Decimal(DecimalScalar::Decimal256(*value, DecimalSize {
-                precision: *precision,
-                scale: *scale,
-            })),
-            Literal::Float64(float) => Scalar::Number(NumberScalar::Float64((*float).into())),
-            Literal::String(string) => Scalar::String(string.clone()),
-            Literal::Boolean(boolean) => Scalar::Boolean(*boolean),
-            Literal::Null => Scalar::Null,
-        };
-        let value = shrink_scalar(value);
-        let data_type = value.as_ref().infer_data_type();
-        Ok(Box::new((value, data_type)))
+        Ok(Box::new(resolve_literal_scalar(literal)))
     }
 
     // TODO(leiysky): use an array builder function instead, since we should allow declaring
@@ -3811,6 +3837,22 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new((scalar, return_type)))
     }
 
+    fn is_tuple_column(&self, ident: &Identifier) -> bool {
+        let name = normalize_identifier(ident, self.name_resolution_ctx);
+        match self.bind_context.resolve_name(
+            None,
+            None,
+            &name,
+            self.aliases,
+            self.name_resolution_ctx,
+        ) {
+            Ok(NameResolutionResult::Column(column)) => {
+                matches!(column.data_type.remove_nullable(), DataType::Tuple(_))
+            }
+            _ => false,
+        }
+    }
+
     fn resolve_tuple_map_access_pushdown(
         &mut self,
         span: Span,
@@ -4336,6 +4378,28 @@ impl<'a> TypeChecker<'a> {
     }
 }
 
+/// The constant a literal is bound to, the literal is shrunk to the smallest type that holds it.
+pub fn resolve_literal_scalar(literal: &databend_common_ast::ast::Literal) -> (Scalar, DataType) {
+    let value = match literal {
+        Literal::UInt64(value) => Scalar::Number(NumberScalar::UInt64(*value)),
+        Literal::Decimal256 {
+            value,
+            precision,
+            scale,
+        } => Scalar::Decimal(DecimalScalar::Decimal256(*value, DecimalSize {
+            precision: *precision,
+            scale: *scale,
+        })),
+        Literal::Float64(float) => Scalar::Number(NumberScalar::Float64((*float).into())),
+        Literal::String(string) => Scalar::String(string.clone()),
+        Literal::Boolean(boolean) => Scalar::Boolean(*boolean),
+        Literal::Null => Scalar::Null,
+    };
+    let value = shrink_scalar(value);
+    let data_type = value.as_ref().infer_data_type();
+    (value, data_type)
+}
+
 pub fn resolve_type_name_by_str(name: &str, not_null: bool) -> Result<TableDataType> {
     let sql_tokens = databend_common_ast::parser::tokenize_sql(name)?;
     let ast = databend_common_ast::parser::run_parser(
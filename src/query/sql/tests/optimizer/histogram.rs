@@ -28,3 +28,44 @@ fn test_histogram() {
     assert_eq!(histogram.num_values(), 4.0);
     assert_eq!(histogram.num_distinct_values(), 2.0);
 }
+
+#[test]
+fn test_histogram_estimate_equal() {
+    let buckets = vec![
+        HistogramBucket::new(Datum::Int(0), Datum::Int(9), 100.0, 10.0),
+        HistogramBucket::new(Datum::Int(20), Datum::Int(20), 100.0, 1.0),
+    ];
+
+    let histogram = Histogram::new(buckets, true);
+    assert_eq!(histogram.estimate_equal(&Datum::Int(5)).unwrap(), 10.0);
+    assert_eq!(histogram.estimate_equal(&Datum::Int(20)).unwrap(), 100.0);
+    // Values in the gap between buckets or out of bounds.
+    assert_eq!(histogram.estimate_equal(&Datum::Int(15)).unwrap(), 0.0);
+    assert_eq!(histogram.estimate_equal(&Datum::Int(-1)).unwrap(), 0.0);
+    assert_eq!(histogram.estimate_equal(&Datum::Int(21)).unwrap(), 0.0);
+}
+
+#[test]
+fn test_histogram_estimate_less() {
+    let buckets = vec![
+        HistogramBucket::new(Datum::Int(0), Datum::Int(10), 100.0, 10.0),
+        HistogramBucket::new(Datum::Int(10), Datum::Int(20), 100.0, 10.0),
+    ];
+
+    let histogram = Histogram::new(buckets, true);
+    assert_eq!(histogram.estimate_less(&Datum::Int(-1), true).unwrap(), 0.0);
+    assert_eq!(histogram.estimate_less(&Datum::Int(0), false).unwrap(), 0.0);
+    assert_eq!(
+        histogram.estimate_less(&Datum::Int(5), false).unwrap(),
+        50.0
+    );
+    assert_eq!(histogram.estimate_less(&Datum::Int(5), true).unwrap(), 60.0);
+    assert_eq!(
+        histogram.estimate_less(&Datum::Int(15), false).unwrap(),
+        150.0
+    );
+    assert_eq!(
+        histogram.estimate_less(&Datum::Int(30), false).unwrap(),
+        200.0
+    );
+}
@@ -53,6 +53,7 @@ where
     fn capacity(&self) -> u64;
     fn set_capacity(&self, capacity: u64);
     fn len(&self) -> usize;
+    fn clear(&self);
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
@@ -169,6 +170,10 @@ where
         self.cache.len()
     }
 
+    fn clear(&self) {
+        self.cache.clear()
+    }
+
     fn contains_key(&self, k: &str) -> bool {
         self.cache.contains_key(k)
     }
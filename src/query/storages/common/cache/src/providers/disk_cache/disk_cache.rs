@@ -109,6 +109,19 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize> + Send + Sync + 'stati
         }
     }
 
+    /// Remove all the files of the cache.
+    pub fn clear(&mut self) {
+        while let Some((rel_path, _)) = self.cache.pop_by_policy() {
+            let cached_item_path = self.abs_path_of_cache_key(&DiskCacheKey(rel_path));
+            fs::remove_file(&cached_item_path).unwrap_or_else(|e| {
+                error!(
+                    "Error removing file from cache: `{:?}`: {}",
+                    cached_item_path, e
+                )
+            });
+        }
+    }
+
     /// Return the path in which the cache is stored.
     pub fn path(&self) -> &Path {
         self.root.as_path()
@@ -127,6 +127,11 @@ impl CacheAccessor<String, Bytes, databend_common_cache::DefaultHashBuilder, Cou
         let cache = self.read();
         cache.len()
     }
+
+    fn clear(&self) {
+        let mut cache = self.write();
+        cache.clear()
+    }
 }
 
 /// The crc32 checksum is stored at the end of `bytes` and encoded as le u32.
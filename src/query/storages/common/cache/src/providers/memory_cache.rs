@@ -114,6 +114,11 @@ mod impls {
             let guard = self.read();
             guard.len()
         }
+
+        fn clear(&self) {
+            let mut guard = self.write();
+            guard.clear()
+        }
     }
 
     // Wrap an Option<CacheAccessor>, and impl CacheAccessor for it
@@ -178,5 +183,11 @@ mod impls {
                 0
             }
         }
+
+        fn clear(&self) {
+            if let Some(cache) = self {
+                cache.clear()
+            }
+        }
     }
 }
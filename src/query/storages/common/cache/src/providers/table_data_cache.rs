@@ -172,6 +172,10 @@ impl CacheAccessor<String, Bytes, DefaultHashBuilder, Count> for TableDataCache
     fn len(&self) -> usize {
         self.external_cache.len()
     }
+
+    fn clear(&self) {
+        self.external_cache.clear()
+    }
 }
 
 struct CachePopulationWorker<T> {
@@ -163,6 +163,24 @@ fn test_evict_until_enough_space() {
     assert!(c.contains_key("file3"));
 }
 
+#[test]
+fn test_clear() {
+    let f = TestFixture::new();
+    let mut c = DiskCache::new(f.tmp(), 25, DiskCacheKeyReloadPolicy::Reset, false).unwrap();
+    c.insert_single_slice("file1", &[1; 10]).unwrap();
+    c.insert_single_slice("file2", &[2; 10]).unwrap();
+
+    c.clear();
+    assert_eq!(c.size(), 0);
+    assert!(c.is_empty());
+    assert_eq!(c.capacity(), 25);
+    for key in ["file1", "file2"] {
+        assert!(!c.contains_key(key));
+        let removed_file_path = PathBuf::from(&DiskCacheKey::from(key));
+        assert!(!f.tmp().join(removed_file_path).exists());
+    }
+}
+
 #[test]
 fn test_fuzzy_restart_parallelism() {
     let f = TestFixture::new();
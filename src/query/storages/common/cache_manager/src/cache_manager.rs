@@ -26,6 +26,7 @@ use databend_common_exception::Result;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::InMemoryCacheBuilder;
 use databend_storages_common_cache::InMemoryItemCacheHolder;
+use databend_storages_common_cache::LruDiskCacheBuilder;
 use databend_storages_common_cache::Named;
 use databend_storages_common_cache::NamedCache;
 use databend_storages_common_cache::TableDataCache;
@@ -42,6 +43,7 @@ use crate::caches::CompactSegmentInfoCache;
 use crate::caches::FileMetaDataCache;
 use crate::caches::InvertedIndexFileCache;
 use crate::caches::InvertedIndexMetaCache;
+use crate::caches::TableMetaDiskCache;
 use crate::caches::TableSnapshotCache;
 use crate::caches::TableSnapshotStatisticCache;
 use crate::BlockMetaCache;
@@ -87,6 +89,7 @@ pub struct CacheManager {
     inverted_index_file_cache: CacheSlot<InvertedIndexFileCache>,
     prune_partitions_cache: CacheSlot<PrunePartitionsCache>,
     parquet_file_meta_data_cache: CacheSlot<FileMetaDataCache>,
+    table_meta_disk_cache: CacheSlot<TableMetaDiskCache>,
     table_data_cache: CacheSlot<TableDataCache>,
     in_memory_table_data_cache: CacheSlot<ColumnArrayCache>,
     block_meta_cache: CacheSlot<BlockMetaCache>,
@@ -99,13 +102,15 @@ impl CacheManager {
         max_server_memory_usage: &u64,
         tenant_id: impl Into<String>,
     ) -> Result<()> {
+        let tenant_id = tenant_id.into();
+
         // setup table data cache
         let table_data_cache = {
             match config.data_cache_storage {
                 CacheStorageTypeInnerConfig::None => None,
                 CacheStorageTypeInnerConfig::Disk => {
                     let real_disk_cache_root = PathBuf::from(&config.disk_cache_config.path)
-                        .join(tenant_id.into())
+                        .join(&tenant_id)
                         .join("v1");
 
                     let queue_size: u32 = if config.table_data_cache_population_queue_size > 0 {
@@ -164,6 +169,7 @@ impl CacheManager {
                 prune_partitions_cache: CacheSlot::new(None),
                 parquet_file_meta_data_cache: CacheSlot::new(None),
                 table_statistic_cache: CacheSlot::new(None),
+                table_meta_disk_cache: CacheSlot::new(None),
                 table_data_cache,
                 in_memory_table_data_cache,
                 block_meta_cache: CacheSlot::new(None),
@@ -223,6 +229,17 @@ impl CacheManager {
             let block_meta_cache =
                 Self::new_named_cache_slot(config.block_meta_count, MEMORY_CACHE_BLOCK_META);
 
+            // snapshots and segments are immutable once written, the cached files never go stale
+            let table_meta_disk_cache_root = PathBuf::from(&config.disk_cache_config.path)
+                .join(&tenant_id)
+                .join("meta_v1");
+            let table_meta_disk_cache = CacheSlot::new(Self::new_table_meta_disk_cache(
+                &table_meta_disk_cache_root,
+                config.table_meta_disk_cache_bytes,
+                config.data_cache_key_reload_policy.clone(),
+                config.disk_cache_config.sync_data,
+            )?);
+
             GlobalInstance::set(Arc::new(Self {
                 table_snapshot_cache,
                 compact_segment_info_cache,
@@ -233,6 +250,7 @@ impl CacheManager {
                 prune_partitions_cache,
                 parquet_file_meta_data_cache,
                 table_statistic_cache,
+                table_meta_disk_cache,
                 table_data_cache,
                 in_memory_table_data_cache,
                 block_meta_cache,
@@ -286,6 +304,10 @@ impl CacheManager {
         self.parquet_file_meta_data_cache.get()
     }
 
+    pub fn get_table_meta_disk_cache(&self) -> Option<TableMetaDiskCache> {
+        self.table_meta_disk_cache.get()
+    }
+
     pub fn get_table_data_cache(&self) -> Option<TableDataCache> {
         self.table_data_cache.get()
     }
@@ -294,6 +316,29 @@ impl CacheManager {
         self.in_memory_table_data_cache.get()
     }
 
+    /// Drop the cached metadata of tables (snapshots, segments, block metas, index metas, ...),
+    /// they are loaded from the storage again on the next access.
+    pub fn clear_table_meta_caches(&self) {
+        Self::clear_named_cache(&self.table_snapshot_cache);
+        Self::clear_named_cache(&self.table_statistic_cache);
+        Self::clear_named_cache(&self.compact_segment_info_cache);
+        Self::clear_named_cache(&self.block_meta_cache);
+        Self::clear_named_cache(&self.bloom_index_meta_cache);
+        Self::clear_named_cache(&self.inverted_index_meta_cache);
+        Self::clear_named_cache(&self.prune_partitions_cache);
+        Self::clear_named_cache(&self.parquet_file_meta_data_cache);
+    }
+
+    fn clear_named_cache<V, M>(
+        cache: &CacheSlot<NamedCache<InMemoryItemCacheHolder<V, DefaultHashBuilder, M>>>,
+    ) where
+        M: CountableMeter<String, Arc<V>>,
+    {
+        if let Some(v) = cache.get() {
+            v.clear();
+        }
+    }
+
     // create cache that meters size by `Count`
     fn new_named_cache_slot<V>(
         capacity: u64,
@@ -368,6 +413,30 @@ impl CacheManager {
         }
     }
 
+    fn new_table_meta_disk_cache(
+        path: &PathBuf,
+        disk_cache_bytes_size: u64,
+        disk_cache_key_reload_policy: DiskCacheKeyReloadPolicy,
+        sync_data: bool,
+    ) -> Result<Option<TableMetaDiskCache>> {
+        if disk_cache_bytes_size > 0 {
+            info!(
+                "table meta disk cache enabled, capacity {} bytes",
+                disk_cache_bytes_size
+            );
+            let cache = LruDiskCacheBuilder::new_disk_cache(
+                path,
+                disk_cache_bytes_size,
+                disk_cache_key_reload_policy,
+                sync_data,
+            )?
+            .name_with(DISK_TABLE_META_CACHE, Unit::Bytes);
+            Ok(Some(cache))
+        } else {
+            Ok(None)
+        }
+    }
+
     fn set_named_cache_capacity<T>(
         cache: &CacheSlot<NamedCache<InMemoryItemCacheHolder<T>>>,
         new_capacity: u64,
@@ -463,7 +532,7 @@ impl CacheManager {
                 Self::set_named_cache_capacity(&self.block_meta_cache, new_capacity, name);
             }
 
-            DISK_TABLE_DATA_CACHE_NAME => {
+            DISK_TABLE_DATA_CACHE_NAME | DISK_TABLE_META_CACHE => {
                 return Err(ErrorCode::BadArguments(format!(
                     "set capacity of cache {} is not allowed",
                     name
@@ -488,3 +557,4 @@ const MEMORY_CACHE_COMPACT_SEGMENT_INFO: &str = "memory_cache_compact_segment_in
 const MEMORY_CACHE_TABLE_STATISTICS: &str = "memory_cache_table_statistics";
 const MEMORY_CACHE_TABLE_SNAPSHOT: &str = "memory_cache_table_snapshot";
 const MEMORY_CACHE_BLOCK_META: &str = "memory_cache_block_meta";
+const DISK_TABLE_META_CACHE: &str = "disk_cache_table_meta";
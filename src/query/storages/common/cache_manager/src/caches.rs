@@ -25,6 +25,7 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::InMemoryItemCacheHolder;
+use databend_storages_common_cache::LruDiskCacheHolder;
 use databend_storages_common_cache::NamedCache;
 use databend_storages_common_index::filters::Xor8Filter;
 use databend_storages_common_index::BloomIndexMeta;
@@ -47,6 +48,8 @@ pub type BlockMetaCache = NamedCache<InMemoryItemCacheHolder<Vec<Arc<BlockMeta>>
 
 /// In memory object cache of TableSnapshot
 pub type TableSnapshotCache = NamedCache<InMemoryItemCacheHolder<TableSnapshot>>;
+/// Disk cache of the raw bytes of TableSnapshot and SegmentInfo, keyed by location and version
+pub type TableMetaDiskCache = NamedCache<LruDiskCacheHolder>;
 /// In memory object cache of TableSnapshotStatistics
 pub type TableSnapshotStatisticCache = NamedCache<InMemoryItemCacheHolder<TableSnapshotStatistics>>;
 /// In memory object cache of bloom filter.
@@ -83,6 +83,17 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// crc32 checksums of the column chunks, keyed by column id.
+    ///
+    /// Empty if the block was written before the checksums are recorded, or by a table
+    /// with the `block_checksum` option turned off.
+    ///
+    /// Segments are encoded as named MessagePack, so this field is compatible in both
+    /// directions during a rolling upgrade: segments written by older nodes decode with
+    /// an empty map, and older nodes skip the unknown key when reading new segments.
+    #[serde(default)]
+    pub col_checksums: HashMap<ColumnId, u32>,
 }
 
 impl BlockMeta {
@@ -114,6 +125,7 @@ impl BlockMeta {
             inverted_index_size,
             compression,
             create_on,
+            col_checksums: HashMap::new(),
         }
     }
 
@@ -263,6 +275,7 @@ impl BlockMeta {
             compression: Compression::Lz4,
             inverted_index_size: None,
             create_on: None,
+            col_checksums: HashMap::new(),
         }
     }
 
@@ -296,6 +309,7 @@ impl BlockMeta {
             compression: s.compression,
             inverted_index_size: None,
             create_on: None,
+            col_checksums: HashMap::new(),
         }
     }
 }
@@ -0,0 +1,28 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+pub const OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE: &str = "materialized_view_refresh_mode";
+pub const OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID: &str = "materialized_view_base_table_id";
+/// The snapshot of the base table that the materialized view is refreshed to.
+pub const OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT: &str = "materialized_view_base_snapshot";
+/// The location of the base table snapshot that the materialized view is refreshed to,
+/// the view is only used to rewrite queries reading the same snapshot.
+pub const OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT_LOCATION: &str =
+    "materialized_view_base_snapshot_location";
+/// The unix timestamp in seconds of the last refresh, the view is known to be up to date
+/// with its base table at that time.
+pub const OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON: &str = "materialized_view_refreshed_on";
+/// Kept in the base table, the comma separated `<database>.<view>` of its materialized views.
+pub const OPT_KEY_MATERIALIZED_VIEWS: &str = "materialized_views";
@@ -13,12 +13,14 @@
 // limitations under the License.
 
 mod dynamic_table_keys;
+mod materialized_view_keys;
 mod stream_keys;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
 
 pub use dynamic_table_keys::*;
+pub use materialized_view_keys::*;
 pub use stream_keys::*;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
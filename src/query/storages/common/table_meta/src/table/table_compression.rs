@@ -21,13 +21,40 @@ use parquet::basic::ZstdLevel;
 
 use crate::meta;
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug)]
 pub enum TableCompression {
     None,
     LZ4,
     Snappy,
-    #[default]
-    Zstd,
+    /// Zstd with an optional compression level, the default level is used if not set.
+    Zstd(Option<i32>),
+}
+
+impl Default for TableCompression {
+    fn default() -> Self {
+        TableCompression::Zstd(None)
+    }
+}
+
+impl TableCompression {
+    /// Set the compression level, only zstd (level 1 to 22) supports it.
+    pub fn with_level(self, level: i32) -> Result<Self, ErrorCode> {
+        match self {
+            TableCompression::Zstd(_) => {
+                ZstdLevel::try_new(level).map_err(|e| {
+                    ErrorCode::TableOptionInvalid(format!(
+                        "invalid compression level {}: {}",
+                        level, e
+                    ))
+                })?;
+                Ok(TableCompression::Zstd(Some(level)))
+            }
+            other => Err(ErrorCode::TableOptionInvalid(format!(
+                "compression level is not supported by table compression {:?}",
+                other
+            ))),
+        }
+    }
 }
 
 /// Convert from str.
@@ -38,7 +65,7 @@ impl TryFrom<&str> for TableCompression {
         match value.to_lowercase().as_str() {
             "" => Ok(TableCompression::default()),
             "none" => Ok(TableCompression::None),
-            "zstd" => Ok(TableCompression::Zstd),
+            "zstd" => Ok(TableCompression::Zstd(None)),
             "lz4" => Ok(TableCompression::LZ4),
             "snappy" => Ok(TableCompression::Snappy),
             other => Err(ErrorCode::UnknownFormat(format!(
@@ -58,7 +85,11 @@ impl From<TableCompression> for databend_parquet::compression::CompressionOption
             }
             TableCompression::LZ4 => databend_parquet::compression::CompressionOptions::Lz4Raw,
             TableCompression::Snappy => databend_parquet::compression::CompressionOptions::Snappy,
-            TableCompression::Zstd => databend_parquet::compression::CompressionOptions::Zstd(None),
+            TableCompression::Zstd(level) => {
+                databend_parquet::compression::CompressionOptions::Zstd(level.and_then(|level| {
+                    databend_parquet::compression::ZstdLevel::try_new(level).ok()
+                }))
+            }
         }
     }
 }
@@ -70,7 +101,7 @@ impl From<TableCompression> for native::CommonCompression {
             TableCompression::None => native::CommonCompression::None,
             TableCompression::LZ4 => native::CommonCompression::Lz4,
             TableCompression::Snappy => native::CommonCompression::Snappy,
-            TableCompression::Zstd => native::CommonCompression::Zstd,
+            TableCompression::Zstd(_) => native::CommonCompression::Zstd,
         }
     }
 }
@@ -83,7 +114,7 @@ impl From<TableCompression> for meta::Compression {
             // Map to meta Lz4Raw.
             TableCompression::LZ4 => meta::Compression::Lz4Raw,
             TableCompression::Snappy => meta::Compression::Snappy,
-            TableCompression::Zstd => meta::Compression::Zstd,
+            TableCompression::Zstd(_) => meta::Compression::Zstd,
         }
     }
 }
@@ -95,7 +126,11 @@ impl From<TableCompression> for ParquetCompression {
             TableCompression::None => ParquetCompression::UNCOMPRESSED,
             TableCompression::LZ4 => ParquetCompression::LZ4_RAW,
             TableCompression::Snappy => ParquetCompression::SNAPPY,
-            TableCompression::Zstd => ParquetCompression::ZSTD(ZstdLevel::default()),
+            TableCompression::Zstd(level) => ParquetCompression::ZSTD(
+                level
+                    .and_then(|level| ZstdLevel::try_new(level).ok())
+                    .unwrap_or_default(),
+            ),
         }
     }
 }
@@ -14,16 +14,27 @@
 
 use std::collections::HashSet;
 use std::sync::LazyLock;
+
+use crate::table::OPT_KEY_MATERIALIZED_VIEWS;
+use crate::table::OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT;
+use crate::table::OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT_LOCATION;
+use crate::table::OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID;
+use crate::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use crate::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON;
+use crate::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE;
+
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_STORAGE_PREFIX: &str = "storage_prefix";
 pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
 pub const OPT_KEY_STORAGE_FORMAT: &str = "storage_format";
 pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
+pub const OPT_KEY_TABLE_COMPRESSION_LEVEL: &str = "compression_level";
 pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 pub const OPT_KEY_CHANGE_TRACKING_BEGIN_VER: &str = "begin_version";
+pub const OPT_KEY_TTL: &str = "ttl";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -54,6 +65,13 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
     r
 });
 
@@ -64,6 +82,13 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_ENGINE_META);
     r.insert(OPT_KEY_CHANGE_TRACKING_BEGIN_VER);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
     r
 });
 
@@ -17,6 +17,8 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::TableCopiedFileInfo;
 use databend_common_meta_app::schema::TableInfo;
@@ -35,6 +37,8 @@ pub struct TxnManager {
     state: TxnState,
     txn_buffer: TxnBuffer,
     txn_id: String,
+    // Set by `BEGIN READ ONLY AT <timestamp>`, table reads are pinned to this point.
+    read_only_at: Option<DateTime<Utc>>,
 }
 
 pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
@@ -58,6 +62,9 @@ pub struct TxnBuffer {
     stream_tables: HashMap<u64, StreamSnapshot>,
 
     need_purge_files: Vec<(StageInfo, Vec<String>)>,
+
+    // tables compacted in the transaction, their events are published on commit.
+    compacted_tables: HashSet<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -74,6 +81,7 @@ impl TxnBuffer {
         self.update_stream_meta.clear();
         self.deduplicated_labels.clear();
         self.stream_tables.clear();
+        self.compacted_tables.clear();
     }
 
     fn update_multi_table_meta(&mut self, mut req: UpdateMultiTableMetaReq) {
@@ -112,6 +120,7 @@ impl TxnManager {
             state: TxnState::AutoCommit,
             txn_buffer: TxnBuffer::default(),
             txn_id: "".to_string(),
+            read_only_at: None,
         }))
     }
 
@@ -122,6 +131,32 @@ impl TxnManager {
         }
     }
 
+    pub fn begin_read_only(&mut self, at: DateTime<Utc>) {
+        if let TxnState::AutoCommit = self.state {
+            self.begin();
+            self.read_only_at = Some(at);
+        }
+    }
+
+    pub fn read_only_at(&self) -> Option<DateTime<Utc>> {
+        self.read_only_at
+    }
+
+    /// Pins the reads of the active transaction, see `SET TRANSACTION SNAPSHOT`.
+    pub fn set_read_only_at(&mut self, at: DateTime<Utc>) {
+        if let TxnState::Active = self.state {
+            self.read_only_at = Some(at);
+        }
+    }
+
+    /// Whether the transaction has buffered any table change or stream snapshot.
+    pub fn has_touched_tables(&self) -> bool {
+        !self.txn_buffer.mutated_tables.is_empty()
+            || !self.txn_buffer.copied_files.is_empty()
+            || !self.txn_buffer.update_stream_meta.is_empty()
+            || !self.txn_buffer.stream_tables.is_empty()
+    }
+
     pub fn txn_id(&self) -> &str {
         &self.txn_id
     }
@@ -130,6 +165,7 @@ impl TxnManager {
         self.state = TxnState::AutoCommit;
         self.txn_buffer.clear();
         self.txn_id = "".to_string();
+        self.read_only_at = None;
     }
 
     pub fn set_fail(&mut self) {
@@ -281,4 +317,12 @@ impl TxnManager {
     pub fn need_purge_files(&mut self) -> Vec<(StageInfo, Vec<String>)> {
         std::mem::take(&mut self.txn_buffer.need_purge_files)
     }
+
+    pub fn add_compacted_table(&mut self, table_id: u64) {
+        self.txn_buffer.compacted_tables.insert(table_id);
+    }
+
+    pub fn compacted_tables(&self) -> HashSet<u64> {
+        self.txn_buffer.compacted_tables.clone()
+    }
 }
@@ -0,0 +1,317 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use deltalake::kernel::DeletionVectorDescriptor;
+use deltalake::kernel::StorageType;
+use opendal::Operator;
+use parquet::arrow::arrow_reader::RowSelection;
+use parquet::arrow::arrow_reader::RowSelector;
+
+const Z85_ALPHABET: &[u8] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+// The uuid of a deletion vector file is encoded as 20 z85 characters.
+const UUID_Z85_LEN: usize = 20;
+const DV_MAGIC: u32 = 1681511377;
+const SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+const SERIAL_COOKIE: u32 = 12347;
+const NO_OFFSET_THRESHOLD: usize = 4;
+const MAX_ARRAY_CARDINALITY: usize = 4096;
+
+/// Where the rows deleted from a data file are stored, see the `deletionVector` of the
+/// add actions in the Delta protocol.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub enum DeletionVector {
+    /// The serialized bitmap, stored in the log itself.
+    Inline(Vec<u8>),
+    /// The serialized bitmap is stored at `offset` of the file at `path`, prefixed by its size.
+    File {
+        path: String,
+        offset: u64,
+        size: u64,
+    },
+}
+
+impl DeletionVector {
+    pub fn try_create(descriptor: &DeletionVectorDescriptor) -> Result<Self> {
+        let encoded = descriptor.path_or_inline_dv.as_str();
+        match descriptor.storage_type {
+            StorageType::Inline => {
+                let mut data = z85_decode(encoded.as_bytes())?;
+                data.truncate(descriptor.size_in_bytes as usize);
+                Ok(DeletionVector::Inline(data))
+            }
+            StorageType::UuidRelativePath => {
+                let split = encoded.len().saturating_sub(UUID_Z85_LEN);
+                if encoded.len() < UUID_Z85_LEN || !encoded.is_char_boundary(split) {
+                    return Err(invalid_deletion_vector("invalid uuid of its file"));
+                }
+                let (prefix, uuid) = encoded.split_at(split);
+                let uuid = z85_decode(uuid.as_bytes())?;
+                let file_name = format!(
+                    "deletion_vector_{}-{}-{}-{}-{}.bin",
+                    hex(&uuid[0..4]),
+                    hex(&uuid[4..6]),
+                    hex(&uuid[6..8]),
+                    hex(&uuid[8..10]),
+                    hex(&uuid[10..16])
+                );
+                let path = match prefix {
+                    "" => file_name,
+                    prefix => format!("{prefix}/{file_name}"),
+                };
+                Ok(DeletionVector::File {
+                    path,
+                    offset: descriptor.offset.unwrap_or(1) as u64,
+                    size: descriptor.size_in_bytes as u64,
+                })
+            }
+            StorageType::AbsolutePath => Err(ErrorCode::Unimplemented(format!(
+                "Delta deletion vector stored at an absolute path is not supported: {}",
+                descriptor.path_or_inline_dv
+            ))),
+        }
+    }
+
+    /// Read the positions of the deleted rows, in ascending order.
+    pub async fn read(&self, op: &Operator) -> Result<Vec<u64>> {
+        match self {
+            DeletionVector::Inline(data) => deserialize(data),
+            DeletionVector::File { path, offset, size } => {
+                // The size is stored before the bitmap, and the checksum after it.
+                let data = op
+                    .read_with(path)
+                    .range(*offset + 4..*offset + 4 + *size)
+                    .await?
+                    .to_vec();
+                deserialize(&data)
+            }
+        }
+    }
+}
+
+/// Select the rows of a data file with `num_rows` rows that are not in `deleted_rows`.
+pub fn row_selection(deleted_rows: &[u64], num_rows: u64) -> RowSelection {
+    let mut selectors = Vec::with_capacity(deleted_rows.len() * 2 + 1);
+    let mut next = 0;
+    for &row in deleted_rows.iter().filter(|row| **row < num_rows) {
+        if row > next {
+            selectors.push(RowSelector::select((row - next) as usize));
+        }
+        selectors.push(RowSelector::skip(1));
+        next = row + 1;
+    }
+    if num_rows > next {
+        selectors.push(RowSelector::select((num_rows - next) as usize));
+    }
+    RowSelection::from(selectors)
+}
+
+fn invalid_deletion_vector(reason: &str) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("Invalid Delta deletion vector: {reason}"))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn z85_decode(encoded: &[u8]) -> Result<Vec<u8>> {
+    if encoded.len() % 5 != 0 {
+        return Err(invalid_deletion_vector("z85 length is not a multiple of 5"));
+    }
+    let mut decoded = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.chunks(5) {
+        let mut value: u32 = 0;
+        for c in chunk {
+            let digit = Z85_ALPHABET
+                .iter()
+                .position(|a| a == c)
+                .ok_or_else(|| invalid_deletion_vector("invalid z85 character"))?;
+            value = value
+                .checked_mul(85)
+                .and_then(|v| v.checked_add(digit as u32))
+                .ok_or_else(|| invalid_deletion_vector("z85 value overflow"))?;
+        }
+        decoded.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(decoded)
+}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos + len)
+            .ok_or_else(|| invalid_deletion_vector("unexpected end of data"))?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_le_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+}
+
+/// The bitmap is a magic number followed by a 64-bit roaring bitmap in the portable format:
+/// the number of 32-bit bitmaps, then each bitmap prefixed by the high 32 bits of its values.
+fn deserialize(data: &[u8]) -> Result<Vec<u64>> {
+    let mut cursor = Cursor { data, pos: 0 };
+    if cursor.u32()? != DV_MAGIC {
+        return Err(invalid_deletion_vector("wrong magic number"));
+    }
+    let num_bitmaps = cursor.u64()?;
+    let mut rows = vec![];
+    for _ in 0..num_bitmaps {
+        let high = (cursor.u32()? as u64) << 32;
+        read_roaring_bitmap(&mut cursor, |low| rows.push(high | low as u64))?;
+    }
+    rows.sort_unstable();
+    Ok(rows)
+}
+
+// See https://github.com/RoaringBitmap/RoaringFormatSpec
+fn read_roaring_bitmap(cursor: &mut Cursor, mut push: impl FnMut(u32)) -> Result<()> {
+    let cookie = cursor.u32()?;
+    let (num_containers, run_flags) = if cookie & 0xFFFF == SERIAL_COOKIE {
+        let num_containers = (cookie >> 16) as usize + 1;
+        let run_flags = cursor.bytes((num_containers + 7) / 8)?;
+        (num_containers, Some(run_flags))
+    } else if cookie == SERIAL_COOKIE_NO_RUNCONTAINER {
+        (cursor.u32()? as usize, None)
+    } else {
+        return Err(invalid_deletion_vector("wrong roaring bitmap cookie"));
+    };
+
+    let mut headers = Vec::with_capacity(num_containers);
+    for _ in 0..num_containers {
+        let key = cursor.u16()?;
+        let cardinality = cursor.u16()? as usize + 1;
+        headers.push((key, cardinality));
+    }
+    if run_flags.is_none() || num_containers >= NO_OFFSET_THRESHOLD {
+        // skip the offsets of the containers
+        cursor.bytes(num_containers * 4)?;
+    }
+
+    for (i, (key, cardinality)) in headers.into_iter().enumerate() {
+        let high = (key as u32) << 16;
+        let is_run = run_flags.is_some_and(|flags| flags[i / 8] & (1 << (i % 8)) != 0);
+        if is_run {
+            let num_runs = cursor.u16()?;
+            for _ in 0..num_runs {
+                let start = cursor.u16()? as u32;
+                let length = cursor.u16()? as u32;
+                for low in start..=start + length {
+                    push(high | low);
+                }
+            }
+        } else if cardinality <= MAX_ARRAY_CARDINALITY {
+            for _ in 0..cardinality {
+                push(high | cursor.u16()? as u32);
+            }
+        } else {
+            for word_index in 0..1024u32 {
+                let mut word = cursor.u64()?;
+                while word != 0 {
+                    let bit = word.trailing_zeros();
+                    push(high | (word_index * 64 + bit));
+                    word &= word - 1;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn serialize_array_bitmap(rows: &[u16]) -> Vec<u8> {
+        let mut data = vec![];
+        data.extend_from_slice(&DV_MAGIC.to_le_bytes());
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&SERIAL_COOKIE_NO_RUNCONTAINER.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&0u16.to_le_bytes());
+        data.extend_from_slice(&(rows.len() as u16 - 1).to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        for row in rows {
+            data.extend_from_slice(&row.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_z85_decode() -> Result<()> {
+        // The example of the z85 specification.
+        assert_eq!(z85_decode(b"HelloWorld")?, vec![
+            0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B
+        ]);
+        assert!(z85_decode(b"Hello").is_ok());
+        assert!(z85_decode(b"Hell").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize() -> Result<()> {
+        let data = serialize_array_bitmap(&[7, 1, 3]);
+        assert_eq!(deserialize(&data)?, vec![1, 3, 7]);
+
+        // run container: rows 2..=4
+        let mut data = vec![];
+        data.extend_from_slice(&DV_MAGIC.to_le_bytes());
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&SERIAL_COOKIE.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(&0u16.to_le_bytes());
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend_from_slice(&1u16.to_le_bytes());
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend_from_slice(&2u16.to_le_bytes());
+        let high = 1 << 32;
+        assert_eq!(deserialize(&data)?, vec![high | 2, high | 3, high | 4]);
+
+        assert!(deserialize(&data[4..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_row_selection() {
+        let selection = row_selection(&[0, 3, 4, 9], 8);
+        let selectors: Vec<RowSelector> = selection.into();
+        assert_eq!(selectors, vec![
+            RowSelector::skip(1),
+            RowSelector::select(2),
+            RowSelector::skip(2),
+            RowSelector::select(3),
+        ]);
+    }
+}
@@ -15,6 +15,7 @@
 #![feature(impl_trait_in_assoc_type)]
 #![allow(clippy::diverging_sub_expression)]
 
+mod deletion_vector;
 mod partition;
 mod partition_columns;
 mod table;
@@ -12,8 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod pushdown_transform;
 mod values_serde;
 
-pub use pushdown_transform::get_pushdown_without_partition_columns;
 pub use values_serde::get_partition_values;
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use arrow_schema::Schema as ArrowSchema;
@@ -38,12 +40,15 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::init_operator;
+use databend_common_storages_parquet::get_pushdown_without_partition_columns;
 use databend_common_storages_parquet::ParquetFilesPart;
 use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
 use deltalake::kernel::Add;
+use deltalake::kernel::MetadataValue;
+use deltalake::kernel::StructType;
 use deltalake::DeltaTableBuilder;
 use object_store_opendal::OpendalStore;
 use serde::Deserialize;
@@ -51,9 +56,9 @@ use serde::Serialize;
 use tokio::sync::OnceCell;
 use url::Url;
 
+use crate::deletion_vector::DeletionVector;
 use crate::partition::DeltaPartInfo;
 use crate::partition_columns::get_partition_values;
-use crate::partition_columns::get_pushdown_without_partition_columns;
 use crate::table_source::DeltaTableSource;
 
 pub const DELTA_ENGINE: &str = "DELTA";
@@ -67,6 +72,10 @@ pub struct DeltaTable {
 #[derive(Serialize, Deserialize)]
 pub struct DeltaTableMeta {
     partition_columns: Vec<String>,
+    /// The physical names of the columns in the data files, by their names in the table,
+    /// only for tables with column mapping.
+    #[serde(default)]
+    column_mapping: Option<BTreeMap<String, String>>,
 }
 
 /// In a delta table, partition columns are not stored in parquet file.
@@ -83,6 +92,10 @@ pub struct DeltaTableMeta {
 ///   - pruner: ColumnRef of partition columns in filter expr are replace with const scalars.
 ///
 /// Type of partition columns can only be simple primitive types.
+///
+/// The data files of a table with column mapping, and the data files with deletion vectors,
+/// are read by [`DeltaTableSource`] itself: the columns are looked up by their physical
+/// names in each file, and the deleted rows are skipped. These files are not pruned.
 impl DeltaTable {
     #[async_backtrace::framed]
     pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
@@ -145,6 +158,7 @@ impl DeltaTable {
         })?;
         let meta = DeltaTableMeta {
             partition_columns: state.partition_columns.clone(),
+            column_mapping: Self::column_mapping(delta_meta, &state.configuration),
         };
         let meta = serde_json::to_string(&meta).map_err(|e| {
             ErrorCode::ReadTableDataError(format!("fail to serialize DeltaTableMeta: {e:?}"))
@@ -154,6 +168,31 @@ impl DeltaTable {
         Ok((schema, meta))
     }
 
+    /// The data files of a table with column mapping (in `name` or `id` mode) name the columns
+    /// by the physical names recorded in the metadata of the fields.
+    fn column_mapping(
+        schema: &StructType,
+        configuration: &HashMap<String, Option<String>>,
+    ) -> Option<BTreeMap<String, String>> {
+        match configuration.get("delta.columnMapping.mode") {
+            Some(Some(mode)) if mode != "none" => Some(
+                schema
+                    .fields()
+                    .into_iter()
+                    .map(|field| {
+                        let physical_name =
+                            match field.metadata().get("delta.columnMapping.physicalName") {
+                                Some(MetadataValue::String(name)) => name.clone(),
+                                _ => field.name().clone(),
+                            };
+                        (field.name().clone(), physical_name)
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
         let op = init_operator(sp)?;
@@ -245,7 +284,7 @@ impl DeltaTable {
             None
         };
         let mut builder =
-            ParquetRSReaderBuilder::create(ctx.clone(), op, table_schema, arrow_schema)?
+            ParquetRSReaderBuilder::create(ctx.clone(), op.clone(), table_schema, arrow_schema)?
                 .with_options(read_options)
                 .with_push_downs(push_downs.as_ref())
                 .with_pruner(Some(pruner))
@@ -262,6 +301,8 @@ impl DeltaTable {
                     output_schema.clone(),
                     parquet_reader.clone(),
                     self.get_partition_fields()?.into_iter().cloned().collect(),
+                    op.clone(),
+                    self.meta.column_mapping.clone(),
                 )
             },
             max_threads.max(1),
@@ -297,6 +338,13 @@ impl DeltaTable {
 
         let parts = adds.iter()
             .map(|add: &Add| {
+                // The rows marked in the deletion vector are not removed from the data file.
+                let deletion_vector = add
+                    .deletion_vector
+                    .as_ref()
+                    .map(DeletionVector::try_create)
+                    .transpose()?;
+                let num_deleted = add.deletion_vector.as_ref().map_or(0, |dv| dv.cardinality);
                 let num_records = add
                     .get_stats_parsed()
                     .ok()
@@ -309,12 +357,13 @@ impl DeltaTable {
                         _ => None,
                     }
                     ).unwrap_or(1);
-                read_rows += num_records as usize;
+                read_rows += (num_records - num_deleted).max(0) as usize;
                 read_bytes += add.size as usize;
                 let partition_values = get_partition_values(add, &partition_fields[..])?;
                 Ok(Arc::new(
                     Box::new(DeltaPartInfo {
                         partition_values,
+                        deletion_vector,
                         data: ParquetPart::ParquetFiles(
                             ParquetFilesPart {
                                 files: vec![(add.path.clone(), add.size as u64)],
@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use arrow_array::RecordBatch;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
@@ -23,10 +25,13 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::FieldIndex;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableField;
 use databend_common_expression::Value;
 use databend_common_pipeline_core::processors::Event;
@@ -36,12 +41,26 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_storages_parquet::ParquetFileReader;
 use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSFullReader;
+use futures::StreamExt;
+use opendal::Operator;
 use parquet::arrow::async_reader::ParquetRecordBatchStream;
+use parquet::arrow::ParquetRecordBatchStreamBuilder;
+use parquet::arrow::ProjectionMask;
 
+use crate::deletion_vector::row_selection;
+use crate::deletion_vector::DeletionVector;
 use crate::partition::DeltaPartInfo;
 
 pub type PartitionColumnIndex = usize;
 
+// A data file read by the physical names of its columns, without the deleted rows.
+struct MappedStream {
+    stream: ParquetRecordBatchStream<ParquetFileReader>,
+    // The position in the record batches of each column of `data_fields`,
+    // None if the data file doesn't have the column.
+    positions: Vec<Option<usize>>,
+}
+
 pub struct DeltaTableSource {
     output: Arc<OutputPort>,
     generated_data: Option<DataBlock>,
@@ -63,8 +82,15 @@ pub struct DeltaTableSource {
     // Used to check schema
     output_schema: DataSchemaRef,
 
+    // Used to read the data files of tables with column mapping, and with deletion vectors.
+    op: Operator,
+    column_mapping: Option<BTreeMap<String, String>>,
+    // The output fields read from the data files, in the order of output_schema.
+    data_fields: Vec<DataField>,
+
     // Per partition
     stream: Option<ParquetRecordBatchStream<ParquetFileReader>>,
+    mapped_stream: Option<MappedStream>,
     partition_block_entries: Vec<BlockEntry>,
 }
 
@@ -75,8 +101,10 @@ impl DeltaTableSource {
         output_schema: DataSchemaRef,
         parquet_reader: Arc<ParquetRSFullReader>,
         partition_fields: Vec<TableField>,
+        op: Operator,
+        column_mapping: Option<BTreeMap<String, String>>,
     ) -> Result<ProcessorPtr> {
-        let output_partition_columns = output_schema
+        let output_partition_columns: Vec<(FieldIndex, PartitionColumnIndex)> = output_schema
             .fields()
             .iter()
             .enumerate()
@@ -87,6 +115,13 @@ impl DeltaTableSource {
                     .map(|pi| (fi, pi))
             })
             .collect();
+        let data_fields = output_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(fi, _)| !output_partition_columns.iter().any(|(i, _)| i == fi))
+            .map(|(_, f)| f.clone())
+            .collect();
         let scan_progress = ctx.get_scan_progress();
         Ok(ProcessorPtr::create(Box::new(DeltaTableSource {
             output,
@@ -96,12 +131,92 @@ impl DeltaTableSource {
             output_schema,
             partition_fields,
             output_partition_columns,
+            op,
+            column_mapping,
+            data_fields,
             stream: None,
+            mapped_stream: None,
             generated_data: None,
             is_finished: false,
             partition_block_entries: vec![],
         })))
     }
+
+    async fn prepare_mapped_stream(
+        &self,
+        loc: &str,
+        size: u64,
+        deletion_vector: Option<&DeletionVector>,
+    ) -> Result<MappedStream> {
+        let reader = ParquetFileReader::new(self.op.reader(loc).await?, size);
+        let builder = ParquetRecordBatchStreamBuilder::new(reader).await?;
+
+        let file_columns = builder.parquet_schema().root_schema().get_fields();
+        let file_positions = self
+            .data_fields
+            .iter()
+            .map(|field| {
+                let name = self
+                    .column_mapping
+                    .as_ref()
+                    .and_then(|mapping| mapping.get(field.name()))
+                    .unwrap_or(field.name());
+                file_columns
+                    .iter()
+                    .position(|column| column.name().eq_ignore_ascii_case(name))
+            })
+            .collect::<Vec<_>>();
+        // The columns of the record batches are in the order of the data file.
+        let mut roots = file_positions.iter().flatten().copied().collect::<Vec<_>>();
+        roots.sort_unstable();
+        roots.dedup();
+        let positions = file_positions
+            .iter()
+            .map(|position| position.map(|p| roots.binary_search(&p).unwrap()))
+            .collect();
+
+        let projection = ProjectionMask::roots(builder.parquet_schema(), roots);
+        let batch_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        let mut builder = builder
+            .with_projection(projection)
+            .with_batch_size(batch_size);
+        if let Some(deletion_vector) = deletion_vector {
+            let deleted_rows = deletion_vector.read(&self.op).await?;
+            let num_rows = builder.metadata().file_metadata().num_rows() as u64;
+            builder = builder.with_row_selection(row_selection(&deleted_rows, num_rows));
+        }
+
+        Ok(MappedStream {
+            stream: builder.build()?,
+            positions,
+        })
+    }
+
+    fn mapped_block(&self, stream: &MappedStream, batch: &RecordBatch) -> Result<DataBlock> {
+        let mut columns = Vec::with_capacity(self.output_schema.num_fields());
+        for (field, position) in self.data_fields.iter().zip(stream.positions.iter()) {
+            let data_type = field.data_type().clone();
+            let value = match position {
+                Some(position) => {
+                    let array = batch.column(*position).clone();
+                    Value::Column(Column::from_arrow_rs(array, &data_type)?)
+                }
+                // The column was added to the table after the data file was written.
+                None if data_type.is_nullable_or_null() => Value::Scalar(Scalar::Null),
+                None => {
+                    return Err(ErrorCode::TableSchemaMismatch(format!(
+                        "Data file of the Delta table has no column {}",
+                        field.name()
+                    )));
+                }
+            };
+            columns.push(BlockEntry::new(data_type, value));
+        }
+        for (fi, pi) in self.output_partition_columns.iter() {
+            columns.insert(*fi, self.partition_block_entries[*pi].clone());
+        }
+        Ok(DataBlock::new(columns, batch.num_rows()))
+    }
 }
 
 #[async_trait::async_trait]
@@ -148,7 +263,13 @@ impl Processor for DeltaTableSource {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        if let Some(mut stream) = self.stream.take() {
+        if let Some(mut stream) = self.mapped_stream.take() {
+            if let Some(batch) = stream.stream.next().await.transpose()? {
+                let block = self.mapped_block(&stream, &batch)?;
+                self.generated_data = Some(check_block_schema(&self.output_schema, block)?);
+                self.mapped_stream = Some(stream);
+            }
+        } else if let Some(mut stream) = self.stream.take() {
             if let Some(block) = self
                 .parquet_reader
                 .read_block_from_stream(&mut stream)
@@ -186,13 +307,17 @@ impl Processor for DeltaTableSource {
                             BlockEntry::new(f.data_type().into(), Value::Scalar(v.clone()))
                         })
                         .collect::<Vec<_>>();
+                    let (loc, size) = &files.files[0];
+                    if self.column_mapping.is_some() || part.deletion_vector.is_some() {
+                        let stream = self
+                            .prepare_mapped_stream(loc, *size, part.deletion_vector.as_ref())
+                            .await?;
+                        self.mapped_stream = Some(stream);
+                        return Ok(());
+                    }
                     let stream = self
                         .parquet_reader
-                        .prepare_data_stream(
-                            &files.files[0].0,
-                            files.files[0].1,
-                            Some(&partition_fields),
-                        )
+                        .prepare_data_stream(loc, *size, Some(&partition_fields))
                         .await?;
                     self.stream = Some(stream);
                 }
@@ -24,6 +24,7 @@ use databend_common_storages_delta::DeltaTable;
 use databend_common_storages_iceberg::IcebergTable;
 use databend_common_storages_memory::MemoryTable;
 use databend_common_storages_null::NullTable;
+use databend_common_storages_parquet::ExternalTable;
 use databend_common_storages_random::RandomTable;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_view::view_table::ViewTable;
@@ -161,6 +162,13 @@ impl StorageFactory {
             table_info_refresher: None,
         });
 
+        // Register EXTERNAL table engine
+        creators.insert("EXTERNAL".to_string(), Storage {
+            creator: Arc::new(ExternalTable::try_create),
+            descriptor: Arc::new(ExternalTable::description),
+            table_info_refresher: None,
+        });
+
         StorageFactory {
             storages: creators,
             schema_refreshing_timeout: DEFAULT_SCHEMA_REFRESHING_TIMEOUT_MS,
@@ -48,6 +48,8 @@ pub struct FuseBlockPartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    /// crc32 checksums of the column chunks to be read, taken from the block meta.
+    pub columns_checksum: Option<HashMap<ColumnId, u32>>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -84,6 +86,7 @@ impl FuseBlockPartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        columns_checksum: Option<HashMap<ColumnId, u32>>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FuseBlockPartInfo {
             location,
@@ -94,6 +97,7 @@ impl FuseBlockPartInfo {
             sort_min_max,
             block_meta_index,
             columns_stat,
+            columns_checksum,
         }))
     }
 
@@ -78,6 +78,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION_LEVEL;
 use log::error;
 use log::warn;
 use opendal::Operator;
@@ -98,6 +99,7 @@ use crate::TableStatistics;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
+use crate::FUSE_OPT_KEY_BLOCK_CHECKSUM;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
@@ -206,6 +208,10 @@ impl FuseTable {
             .get(OPT_KEY_TABLE_COMPRESSION)
             .cloned()
             .unwrap_or_default();
+        let mut table_compression = TableCompression::try_from(table_compression.as_str())?;
+        if let Some(level) = table_info.options().get(OPT_KEY_TABLE_COMPRESSION_LEVEL) {
+            table_compression = table_compression.with_level(level.parse()?)?;
+        }
 
         let bloom_index_cols = table_info
             .options()
@@ -226,7 +232,7 @@ impl FuseTable {
             operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
-            table_compression: table_compression.as_str().try_into()?,
+            table_compression,
             table_type,
             changes_desc: None,
         }))
@@ -257,12 +263,14 @@ impl FuseTable {
         let max_page_size = self.get_option(FUSE_OPT_KEY_ROW_PER_PAGE, default_rows_per_page);
         let block_per_seg =
             self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+        let block_checksum = self.get_option(FUSE_OPT_KEY_BLOCK_CHECKSUM, true);
 
         WriteSettings {
             storage_format: self.storage_format,
             table_compression: self.table_compression,
             max_page_size,
             block_per_seg,
+            block_checksum,
         }
     }
 
@@ -823,6 +831,9 @@ impl Table for FuseTable {
             TimeNavigation::TimeTravel(point) => {
                 Ok(self.navigate_to_point(point, abort_checker).await?)
             }
+            TimeNavigation::TimeTravelBefore(point) => {
+                Ok(self.navigate_before_point(point, abort_checker).await?)
+            }
             TimeNavigation::Changes {
                 append_only,
                 at,
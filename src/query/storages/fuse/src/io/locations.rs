@@ -24,6 +24,7 @@ use databend_storages_common_table_meta::meta::Versioned;
 use uuid::Uuid;
 
 use crate::constants::FUSE_TBL_BLOCK_PREFIX;
+use crate::constants::FUSE_TBL_COLD_BLOCK_PREFIX;
 use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
@@ -147,7 +148,29 @@ impl TableMetaLocationGenerator {
     }
 
     pub fn gen_virtual_block_location(location: &str) -> String {
-        location.replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_VIRTUAL_BLOCK_PREFIX)
+        // the virtual columns of a block stay in place when the block is moved to the cold tier
+        Self::gen_hot_block_location(location)
+            .replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_VIRTUAL_BLOCK_PREFIX)
+    }
+
+    /// The location a block is moved to once it is older than the `tiering_age_days` of its table.
+    ///
+    /// The segments referencing the block are rewritten to point to this location.
+    pub fn gen_cold_block_location(location: &str) -> String {
+        location.replacen(
+            &format!("/{FUSE_TBL_BLOCK_PREFIX}/"),
+            &format!("/{FUSE_TBL_COLD_BLOCK_PREFIX}/"),
+            1,
+        )
+    }
+
+    /// The location a block had before it was moved to the cold tier.
+    pub fn gen_hot_block_location(location: &str) -> String {
+        location.replacen(
+            &format!("/{FUSE_TBL_COLD_BLOCK_PREFIX}/"),
+            &format!("/{FUSE_TBL_BLOCK_PREFIX}/"),
+            1,
+        )
     }
 
     pub fn table_statistics_version(table_statistics_location: impl AsRef<str>) -> u64 {
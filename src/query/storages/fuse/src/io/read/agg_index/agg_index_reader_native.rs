@@ -63,6 +63,7 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
                 );
                 let res = self
                     .reader
@@ -117,6 +118,7 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
                 );
                 let res = self
                     .reader
@@ -55,6 +55,7 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
                 );
                 let res = self
                     .reader
@@ -106,6 +107,7 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
                 );
                 Some((part, res))
             }
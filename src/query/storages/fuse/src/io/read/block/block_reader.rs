@@ -13,11 +13,14 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::ops::Range;
 use std::sync::Arc;
 
 use databend_common_arrow::arrow::datatypes::Field;
 use databend_common_arrow::arrow::io::parquet::write::to_parquet_schema;
 use databend_common_arrow::parquet::metadata::SchemaDescriptor;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -199,13 +202,14 @@ impl BlockReader {
         self.schema().into()
     }
 
-    pub fn report_cache_metrics<'a>(
+    pub fn report_cache_metrics(
         &self,
         merged_result: &MergeIOReadResult,
-        ranges: impl Iterator<Item = &'a std::ops::Range<u64>>,
+        ranges: &[(ColumnId, Range<u64>)],
     ) {
         let bytes_read_from_storage: usize = ranges
-            .map(|range| range.end as usize - range.start as usize)
+            .iter()
+            .map(|(_, range)| range.end as usize - range.start as usize)
             .sum();
 
         let cache_metrics = self.ctx.get_data_cache_metrics();
@@ -226,5 +230,15 @@ impl BlockReader {
             read_from_disk_cache,
             read_from_in_mem_cache_array,
         );
+
+        // the bytes read from disk cache are recorded by the disk cache itself.
+        Profile::record_usize_profile(
+            ProfileStatisticsName::ScanRemoteBytes,
+            bytes_read_from_storage,
+        );
+        Profile::record_usize_profile(
+            ProfileStatisticsName::ScanMemoryCacheBytes,
+            read_from_in_mem_cache_array,
+        );
     }
 }
@@ -26,6 +26,8 @@ use databend_storages_common_cache::TableDataCacheKey;
 use databend_storages_common_cache_manager::SizedColumnArray;
 use enum_as_inner::EnumAsInner;
 
+use crate::FuseBlockPartInfo;
+
 type ChunkIndex = usize;
 pub struct OwnerMemory {
     chunks: HashMap<ChunkIndex, Bytes>,
@@ -126,6 +128,38 @@ impl MergeIOReadResult {
         Ok(res)
     }
 
+    /// Verify the column chunks read from the storage against the crc32 checksums recorded
+    /// in the block meta, the column data served by the caches is not verified again.
+    pub fn verify_checksums(&self, part: &FuseBlockPartInfo) -> Result<()> {
+        let Some(checksums) = &part.columns_checksum else {
+            return Ok(());
+        };
+
+        for (column_id, (chunk_idx, range)) in &self.columns_chunk_offsets {
+            let Some(expected) = checksums.get(column_id) else {
+                continue;
+            };
+
+            let chunk = self.owner_memory.get_chunk(*chunk_idx, &self.block_path)?;
+            let actual = crc32fast::hash(&chunk[range.clone()]);
+            if actual != *expected {
+                let (segment, snapshot) = match part.block_meta_index() {
+                    Some(index) => (
+                        index.segment_location.as_str(),
+                        index.snapshot_location.as_deref().unwrap_or("unknown"),
+                    ),
+                    None => ("unknown", "unknown"),
+                };
+                return Err(ErrorCode::CorruptedBlock(format!(
+                    "Block {} is corrupted, checksum mismatch of column {}: expected {:#010x}, actual {:#010x}. segment: {}, snapshot: {}",
+                    part.location, column_id, expected, actual, segment, snapshot
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     fn get_chunk(&self, index: usize, path: &str) -> Result<Bytes> {
         self.owner_memory.get_chunk(index, path)
     }
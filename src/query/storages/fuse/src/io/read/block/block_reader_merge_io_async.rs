@@ -197,7 +197,7 @@ impl BlockReader {
         merge_io_read_res.cached_column_data = cached_column_data;
         merge_io_read_res.cached_column_array = cached_column_array;
 
-        self.report_cache_metrics(&merge_io_read_res, ranges.iter().map(|(_, r)| r));
+        self.report_cache_metrics(&merge_io_read_res, &ranges);
 
         Ok(merge_io_read_res)
     }
@@ -132,7 +132,11 @@ impl BlockReader {
             Self::sync_merge_io_read(settings, self.operator.clone(), &part.location, &ranges)?;
         merge_io_result.cached_column_array = cached_column_array;
 
-        self.report_cache_metrics(&merge_io_result, ranges.iter().map(|(_, r)| r));
+        if settings.enable_block_checksum_verification {
+            merge_io_result.verify_checksums(part)?;
+        }
+
+        self.report_cache_metrics(&merge_io_result, &ranges);
 
         Ok(merge_io_result)
     }
@@ -73,6 +73,9 @@ impl BlockReader {
                 ignore_column_ids,
             )
             .await?;
+        if settings.enable_block_checksum_verification {
+            read_res.verify_checksums(part)?;
+        }
 
         let column_buffers = read_res.column_buffers()?;
         let mut results = BTreeMap::new();
@@ -50,6 +50,29 @@ impl BlockReader {
         column_chunks: HashMap<ColumnId, DataItem>,
         compression: &Compression,
         block_path: &str,
+    ) -> databend_common_exception::Result<DataBlock> {
+        self.deserialize_parquet_chunks_with_decoded_bytes(
+            num_rows,
+            column_metas,
+            column_chunks,
+            compression,
+            block_path,
+            None,
+        )
+    }
+
+    /// Same as `deserialize_parquet_chunks`, additionally adds the in-memory size of each
+    /// column decoded from raw data to `decoded_column_bytes`, indexed by the position of
+    /// the column in the projected schema. Columns served from the array cache or filled
+    /// with default values are not decoded and are not counted.
+    pub(crate) fn deserialize_parquet_chunks_with_decoded_bytes(
+        &self,
+        num_rows: usize,
+        column_metas: &HashMap<ColumnId, ColumnMeta>,
+        column_chunks: HashMap<ColumnId, DataItem>,
+        compression: &Compression,
+        block_path: &str,
+        mut decoded_column_bytes: Option<&mut [usize]>,
     ) -> databend_common_exception::Result<DataBlock> {
         if column_chunks.is_empty() {
             return self.build_default_values_block(num_rows);
@@ -107,7 +130,11 @@ impl BlockReader {
                             cache.put(key.into(), Arc::new((arrow2_array.clone(), data.len())))
                         }
                     }
-                    Value::Column(Column::from_arrow(arrow2_array.as_ref(), &data_type)?)
+                    let column = Column::from_arrow(arrow2_array.as_ref(), &data_type)?;
+                    if let Some(decoded_column_bytes) = decoded_column_bytes.as_deref_mut() {
+                        decoded_column_bytes[i] += column.memory_size();
+                    }
+                    Value::Column(column)
                 }
                 Some(DataItem::ColumnArray(cached)) => {
                     if column_node.is_nested {
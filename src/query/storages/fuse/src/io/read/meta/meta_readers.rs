@@ -14,12 +14,14 @@
 
 use std::io::Read;
 use std::io::SeekFrom;
+use std::sync::Arc;
 
 use bytes::Buf;
 use databend_common_cache::DefaultHashBuilder;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
+use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::InMemoryItemCacheReader;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_cache::Loader;
@@ -115,7 +117,7 @@ pub struct LoaderWrapper<T>(T);
 impl Loader<TableSnapshot> for LoaderWrapper<Operator> {
     #[async_backtrace::framed]
     async fn load(&self, params: &LoadParams) -> Result<TableSnapshot> {
-        let reader = bytes_reader(&self.0, params.location.as_str(), params.len_hint).await?;
+        let reader = meta_bytes_reader(&self.0, params).await?;
         let version = SnapshotVersion::try_from(params.ver)?;
         version.read(reader.reader())
     }
@@ -137,7 +139,7 @@ impl Loader<CompactSegmentInfo> for LoaderWrapper<(Operator, TableSchemaRef)> {
     async fn load(&self, params: &LoadParams) -> Result<CompactSegmentInfo> {
         let version = SegmentInfoVersion::try_from(params.ver)?;
         let LoaderWrapper((operator, schema)) = &self;
-        let reader = bytes_reader(operator, params.location.as_str(), params.len_hint).await?;
+        let reader = meta_bytes_reader(operator, params).await?;
         (version, schema.clone()).read(reader.reader())
     }
 }
@@ -238,6 +240,26 @@ async fn bytes_reader(op: &Operator, path: &str, len_hint: Option<u64>) -> Resul
     Ok(reader)
 }
 
+/// Reads the bytes of a snapshot or segment, through the table meta disk cache if it is enabled.
+///
+/// The files are never modified once written, so (location, version) identifies the content.
+async fn meta_bytes_reader(op: &Operator, params: &LoadParams) -> Result<Buffer> {
+    let Some(cache) = CacheManager::instance().get_table_meta_disk_cache() else {
+        return bytes_reader(op, params.location.as_str(), params.len_hint).await;
+    };
+
+    let cache_key = format!("{}-{}", params.location, params.ver);
+    if let Some(bytes) = cache.get(&cache_key) {
+        return Ok(Buffer::from(bytes.as_ref().clone()));
+    }
+
+    let buffer = bytes_reader(op, params.location.as_str(), params.len_hint).await?;
+    if params.put_cache {
+        cache.put(cache_key, Arc::new(buffer.to_bytes()));
+    }
+    Ok(buffer)
+}
+
 mod thrift_file_meta_read {
     use databend_common_arrow::parquet::error::Error;
     use thrift::protocol::TCompactInputProtocol;
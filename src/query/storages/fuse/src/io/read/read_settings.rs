@@ -21,6 +21,7 @@ use databend_common_exception::Result;
 pub struct ReadSettings {
     pub storage_io_min_bytes_for_seek: u64,
     pub storage_io_max_page_bytes_for_read: u64,
+    pub enable_block_checksum_verification: bool,
 }
 
 impl ReadSettings {
@@ -32,6 +33,9 @@ impl ReadSettings {
             storage_io_max_page_bytes_for_read: ctx
                 .get_settings()
                 .get_storage_io_max_page_bytes_for_read()?,
+            enable_block_checksum_verification: ctx
+                .get_settings()
+                .get_enable_block_checksum_verification()?,
         })
     }
 }
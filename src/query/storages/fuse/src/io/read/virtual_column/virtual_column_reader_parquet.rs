@@ -99,6 +99,7 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
             );
 
             let merge_io_result =
@@ -144,6 +145,7 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
             );
 
             let merge_io_result = BlockReader::merge_io_read(
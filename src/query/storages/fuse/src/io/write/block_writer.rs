@@ -81,7 +81,7 @@ pub fn serialize_block(
             let leaf_column_ids = schema.to_leaf_column_ids();
 
             let mut default_compress_ratio = Some(2.10f64);
-            if matches!(write_settings.table_compression, TableCompression::Zstd) {
+            if matches!(write_settings.table_compression, TableCompression::Zstd(_)) {
                 default_compress_ratio = Some(3.72f64);
             }
 
@@ -114,6 +114,21 @@ pub fn serialize_block(
     }
 }
 
+/// Compute the crc32 checksum of each column chunk of the serialized block.
+pub fn column_checksums(
+    col_metas: &HashMap<ColumnId, ColumnMeta>,
+    buf: &[u8],
+) -> HashMap<ColumnId, u32> {
+    col_metas
+        .iter()
+        .map(|(column_id, column_meta)| {
+            let (offset, len) = column_meta.offset_length();
+            let chunk = &buf[offset as usize..(offset + len) as usize];
+            (*column_id, crc32fast::hash(chunk))
+        })
+        .collect()
+}
+
 /// Take ownership here to avoid extra copy.
 #[async_backtrace::framed]
 pub async fn write_data(data: Vec<u8>, data_accessor: &Operator, location: &str) -> Result<()> {
@@ -381,6 +396,11 @@ impl BlockBuilder {
             &mut buffer,
         )?;
         let file_size = buffer.len() as u64;
+        let col_checksums = if self.write_settings.block_checksum {
+            column_checksums(&col_metas, &buffer)
+        } else {
+            HashMap::new()
+        };
         let inverted_index_size = if !inverted_index_states.is_empty() {
             let size = inverted_index_states.iter().map(|v| v.size).sum();
             Some(size)
@@ -403,6 +423,7 @@ impl BlockBuilder {
             compression: self.write_settings.table_compression.into(),
             inverted_index_size,
             create_on: Some(Utc::now()),
+            col_checksums,
         };
 
         let serialized = BlockSerialization {
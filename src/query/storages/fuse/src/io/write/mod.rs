@@ -18,6 +18,7 @@ mod meta_writer;
 mod segment_writer;
 mod write_settings;
 
+pub use block_writer::column_checksums;
 pub(crate) use block_writer::create_inverted_index_builders;
 pub use block_writer::serialize_block;
 pub use block_writer::write_data;
@@ -26,6 +26,8 @@ pub struct WriteSettings {
     pub max_page_size: usize,
 
     pub block_per_seg: usize,
+    // record the checksums of the column chunks in the block meta
+    pub block_checksum: bool,
 }
 
 impl Default for WriteSettings {
@@ -35,6 +37,7 @@ impl Default for WriteSettings {
             table_compression: TableCompression::default(),
             max_page_size: DEFAULT_ROW_PER_PAGE,
             block_per_seg: DEFAULT_BLOCK_PER_SEGMENT,
+            block_checksum: true,
         }
     }
 }
@@ -370,9 +370,10 @@ impl FuseTable {
                 .await?;
             for segment in segments {
                 let segment = segment?;
-                segment.blocks.into_iter().for_each(|block| {
-                    base_blocks.insert(block.location.clone(), block);
-                })
+                // blocks are identified by id, a block moved to the cold tier is not a change
+                for block in segment.blocks.into_iter() {
+                    base_blocks.insert(block_id_from_location(&block.location.0)?, block);
+                }
             }
         }
 
@@ -388,13 +389,14 @@ impl FuseTable {
 
             for segment in segments {
                 let segment = segment?;
-                segment.blocks.into_iter().for_each(|block| {
-                    if base_blocks.contains_key(&block.location) {
-                        base_blocks.remove(&block.location);
-                    } else {
+                for block in segment.blocks.into_iter() {
+                    if base_blocks
+                        .remove(&block_id_from_location(&block.location.0)?)
+                        .is_none()
+                    {
                         add_blocks.push(block);
                     }
-                });
+                }
             }
         }
 
@@ -22,6 +22,9 @@ use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_events::notify_table_event;
+use databend_common_catalog::table_events::TableEvent;
+use databend_common_catalog::table_events::TableEventType;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
@@ -149,6 +152,7 @@ impl FuseTable {
         let catalog = ctx.get_catalog(table_info.catalog()).await?;
         // 2. update table meta
         let res = Self::update_table_meta(
+            ctx,
             catalog,
             table_info,
             location_generator,
@@ -207,6 +211,7 @@ impl FuseTable {
     #[allow(clippy::too_many_arguments)]
     #[async_backtrace::framed]
     pub async fn update_table_meta(
+        ctx: &dyn TableContext,
         catalog: Arc<dyn Catalog>,
         table_info: &TableInfo,
         location_generator: &TableMetaLocationGenerator,
@@ -242,6 +247,18 @@ impl FuseTable {
 
         // update_table_meta succeed, populate the snapshot cache item and try keeping a hit file of last snapshot
         TableSnapshot::cache().put(snapshot_location.clone(), Arc::new(snapshot));
+        // inside an explicit transaction the update is only buffered, the event is
+        // published by the COMMIT statement once the transaction is committed.
+        if !ctx.txn_mgr().lock().is_active() {
+            match TableEvent::from_table_info(ctx, TableEventType::SnapshotCommitted, table_info)
+                .await
+            {
+                Ok(event) => {
+                    notify_table_event(event.with_snapshot_location(snapshot_location.clone()))
+                }
+                Err(e) => warn!("build table event of {} failure. {}", table_info.desc, e),
+            }
+        }
         Self::write_last_snapshot_hint(operator, location_generator, snapshot_location).await;
 
         Ok(())
@@ -20,12 +20,15 @@ use databend_storages_common_table_meta::meta::Location;
 
 use crate::operations::common::ConflictResolveContext;
 use crate::operations::common::SnapshotChanges;
+use crate::statistics::BlockHLL;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct CommitMeta {
     pub conflict_resolve_context: ConflictResolveContext,
     pub new_segment_locs: Vec<Location>,
     pub table_id: u64,
+    /// NDV sketches of the appended data, merged into the table statistics on commit.
+    pub column_hlls: Option<BlockHLL>,
 }
 
 impl CommitMeta {
@@ -36,6 +39,7 @@ impl CommitMeta {
             ),
             new_segment_locs: vec![],
             table_id,
+            column_hlls: None,
         }
     }
 
@@ -43,11 +47,13 @@ impl CommitMeta {
         conflict_resolve_context: ConflictResolveContext,
         new_segment_locs: Vec<Location>,
         table_id: u64,
+        column_hlls: Option<BlockHLL>,
     ) -> Self {
         CommitMeta {
             conflict_resolve_context,
             new_segment_locs,
             table_id,
+            column_hlls,
         }
     }
 }
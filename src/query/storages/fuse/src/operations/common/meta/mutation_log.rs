@@ -26,6 +26,7 @@ use crate::operations::mutation::BlockIndex;
 use crate::operations::mutation::CompactExtraInfo;
 use crate::operations::mutation::DeletedSegmentInfo;
 use crate::operations::mutation::SegmentIndex;
+use crate::statistics::BlockHLL;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Default)]
 pub struct MutationLogs {
@@ -38,6 +39,8 @@ pub enum MutationLogEntry {
         segment_location: String,
         format_version: FormatVersion,
         summary: Statistics,
+        /// NDV sketches of the blocks of the segment, None if any block has no sketch.
+        column_hlls: Option<BlockHLL>,
     },
     ReclusterAppendBlock {
         block_meta: Arc<BlockMeta>,
@@ -58,6 +61,24 @@ pub enum MutationLogEntry {
     DoNothing,
 }
 
+/// Meta of a newly appended block, along with the NDV sketches of its columns.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ExtendedBlockMeta {
+    pub block_meta: BlockMeta,
+    pub column_hlls: Option<BlockHLL>,
+}
+
+#[typetag::serde(name = "extended_block_meta")]
+impl BlockMetaInfo for ExtendedBlockMeta {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        ExtendedBlockMeta::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
 pub struct BlockMetaIndex {
     pub segment_idx: SegmentIndex,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
@@ -34,17 +35,22 @@ use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache_manager::CachedObject;
 use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use databend_storages_common_table_meta::meta::Versioned;
 use log::debug;
 use log::error;
 use log::info;
 use log::warn;
 use opendal::Operator;
+use uuid::Uuid;
 
+use crate::io::MetaWriter;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::set_backoff;
 use crate::operations::AppendGenerator;
@@ -52,6 +58,8 @@ use crate::operations::CommitMeta;
 use crate::operations::SnapshotGenerator;
 use crate::operations::TruncateGenerator;
 use crate::operations::TruncateMode;
+use crate::statistics::merge_columns_hll;
+use crate::statistics::BlockHLL;
 use crate::FuseTable;
 
 enum State {
@@ -60,12 +68,14 @@ enum State {
     RefreshTable,
     GenerateSnapshot {
         previous: Option<Arc<TableSnapshot>>,
+        previous_statistics: Option<Arc<TableSnapshotStatistics>>,
         cluster_key_meta: Option<ClusterKey>,
         table_info: TableInfo,
     },
     TryCommit {
         data: Vec<u8>,
         snapshot: TableSnapshot,
+        table_statistics: Option<TableSnapshotStatistics>,
         table_info: TableInfo,
     },
     Abort(ErrorCode),
@@ -91,6 +101,7 @@ pub struct CommitSink<F: SnapshotGenerator> {
     backoff: ExponentialBackoff,
 
     new_segment_locs: Vec<Location>,
+    column_hlls: Option<BlockHLL>,
     start_time: Instant,
     prev_snapshot_id: Option<SnapshotId>,
 
@@ -115,6 +126,13 @@ where F: SnapshotGenerator + Send + 'static
         deduplicated_label: Option<String>,
     ) -> Result<ProcessorPtr> {
         let purge = Self::do_purge(table, &snapshot_gen);
+        let max_retry_elapsed = match max_retry_elapsed {
+            Some(elapsed) => Some(elapsed),
+            None => match ctx.get_settings().get_dml_statement_retry_budget_secs()? {
+                0 => None,
+                secs => Some(Duration::from_secs(secs)),
+            },
+        };
         Ok(ProcessorPtr::create(Box::new(CommitSink {
             state: State::None,
             ctx,
@@ -129,6 +147,7 @@ where F: SnapshotGenerator + Send + 'static
             max_retry_elapsed,
             input,
             new_segment_locs: vec![],
+            column_hlls: None,
             start_time: Instant::now(),
             prev_snapshot_id,
             change_tracking: table.change_tracking_enabled(),
@@ -166,6 +185,7 @@ where F: SnapshotGenerator + Send + 'static
             .ok_or_else(|| ErrorCode::Internal("No commit meta. It's a bug"))?;
 
         self.new_segment_locs = meta.new_segment_locs;
+        self.column_hlls = meta.column_hlls;
 
         self.backoff = set_backoff(None, None, self.max_retry_elapsed);
 
@@ -200,6 +220,71 @@ where F: SnapshotGenerator + Send + 'static
             .downcast_ref::<AppendGenerator>()
             .is_some()
     }
+
+    async fn read_previous_statistics(
+        &self,
+        table: &FuseTable,
+        previous: &Option<Arc<TableSnapshot>>,
+    ) -> Result<Option<Arc<TableSnapshotStatistics>>> {
+        if self.column_hlls.is_none() {
+            return Ok(None);
+        }
+        table
+            .read_table_snapshot_statistics(previous.as_ref())
+            .await
+    }
+
+    /// Merges the NDV sketches of the appended data into the table statistics of the previous
+    /// snapshot, so that the optimizer sees fresh NDV without running `ANALYZE TABLE`.
+    fn merge_table_statistics(
+        &self,
+        snapshot: &mut TableSnapshot,
+        previous: &Option<Arc<TableSnapshot>>,
+        previous_statistics: Option<Arc<TableSnapshotStatistics>>,
+    ) -> Result<Option<TableSnapshotStatistics>> {
+        let Some(column_hlls) = &self.column_hlls else {
+            return Ok(None);
+        };
+        let overwrite = self
+            .snapshot_gen
+            .as_any()
+            .downcast_ref::<AppendGenerator>()
+            .is_some_and(|gen| gen.is_overwrite());
+        let previous = previous
+            .as_ref()
+            .filter(|previous| !overwrite && previous.summary.row_count != 0);
+
+        let (mut hll, histograms, snapshot_id) = match (previous, previous_statistics) {
+            // The table had no data, the sketches cover the whole table.
+            (None, _) => (BlockHLL::new(), HashMap::new(), snapshot.snapshot_id),
+            (Some(previous), Some(statistics)) => {
+                // If the statistics lag behind the previous snapshot, keep their snapshot id so that
+                // `ANALYZE TABLE` still collects the missed changes, merging a sketch twice is a no-op.
+                let snapshot_id = if statistics.snapshot_id == previous.snapshot_id {
+                    snapshot.snapshot_id
+                } else {
+                    statistics.snapshot_id
+                };
+                (
+                    statistics.hll.clone(),
+                    statistics.histograms.clone(),
+                    snapshot_id,
+                )
+            }
+            // The sketches of the existing data are unknown.
+            (Some(_), None) => return Ok(None),
+        };
+        merge_columns_hll(&mut hll, column_hlls);
+
+        let table_statistics = TableSnapshotStatistics::new(hll, histograms, snapshot_id);
+        // Not named after the snapshot id, which might be the one of the statistics being replaced.
+        snapshot.table_statistics_location =
+            Some(self.location_gen.snapshot_statistics_location_from_uuid(
+                &Uuid::new_v4(),
+                table_statistics.format_version(),
+            )?);
+        Ok(Some(table_statistics))
+    }
 }
 
 #[async_trait::async_trait]
@@ -249,6 +334,7 @@ where F: SnapshotGenerator + Send + 'static
         match std::mem::replace(&mut self.state, State::None) {
             State::GenerateSnapshot {
                 previous,
+                previous_statistics,
                 cluster_key_meta,
                 table_info,
             } => {
@@ -289,10 +375,16 @@ where F: SnapshotGenerator + Send + 'static
                     self.ctx.txn_mgr(),
                     table_info.ident.table_id,
                 ) {
-                    Ok(snapshot) => {
+                    Ok(mut snapshot) => {
+                        let table_statistics = self.merge_table_statistics(
+                            &mut snapshot,
+                            &previous,
+                            previous_statistics,
+                        )?;
                         self.state = State::TryCommit {
                             data: snapshot.to_bytes()?,
                             snapshot,
+                            table_statistics,
                             table_info,
                         };
                     }
@@ -345,8 +437,12 @@ where F: SnapshotGenerator + Send + 'static
                         .fill_default_values(schema, &previous)
                         .await?;
 
+                    let previous_statistics = self
+                        .read_previous_statistics(&fuse_table, &previous)
+                        .await?;
                     self.state = State::GenerateSnapshot {
                         previous,
+                        previous_statistics,
                         cluster_key_meta: fuse_table.cluster_key_meta.clone(),
                         table_info,
                     };
@@ -355,16 +451,26 @@ where F: SnapshotGenerator + Send + 'static
             State::TryCommit {
                 data,
                 snapshot,
+                table_statistics,
                 table_info,
             } => {
                 let location = self
                     .location_gen
                     .snapshot_location_from_uuid(&snapshot.snapshot_id, TableSnapshot::VERSION)?;
 
+                let table_statistics_location = snapshot.table_statistics_location.clone();
+                if let (Some(table_statistics), Some(table_statistics_location)) =
+                    (&table_statistics, &table_statistics_location)
+                {
+                    table_statistics
+                        .write_meta(&self.dal, table_statistics_location)
+                        .await?;
+                }
                 self.dal.write(&location, data).await?;
 
                 let catalog = self.ctx.get_catalog(table_info.catalog()).await?;
                 match FuseTable::update_table_meta(
+                    self.ctx.as_ref(),
                     catalog.clone(),
                     &table_info,
                     &self.location_gen,
@@ -431,6 +537,13 @@ where F: SnapshotGenerator + Send + 'static
                         if let Some(files) = &self.copied_files {
                             metrics_inc_commit_copied_files(files.file_info.len() as u64);
                         }
+                        if let (Some(table_statistics), Some(location), Some(cache)) = (
+                            table_statistics,
+                            table_statistics_location,
+                            TableSnapshotStatistics::cache(),
+                        ) {
+                            cache.put(location, Arc::new(table_statistics));
+                        }
                         for segment_loc in std::mem::take(&mut self.new_segment_locs).into_iter() {
                             self.ctx.add_segment_location(segment_loc)?;
                         }
@@ -496,9 +609,13 @@ where F: SnapshotGenerator + Send + 'static
                 self.table = self.table.refresh(self.ctx.as_ref()).await?;
                 let fuse_table = FuseTable::try_from_table(self.table.as_ref())?.to_owned();
                 let previous = fuse_table.read_table_snapshot().await?;
+                let previous_statistics = self
+                    .read_previous_statistics(&fuse_table, &previous)
+                    .await?;
                 let cluster_key_meta = fuse_table.cluster_key_meta.clone();
                 self.state = State::GenerateSnapshot {
                     previous,
+                    previous_statistics,
                     cluster_key_meta,
                     table_info: fuse_table.table_info.clone(),
                 };
@@ -98,6 +98,8 @@ impl TransformMergeCommitMeta {
                 .chain(r.new_segment_locs)
                 .collect(),
             table_id: l.table_id,
+            // only appends carry the NDV sketches.
+            column_hlls: None,
         }
     }
 }
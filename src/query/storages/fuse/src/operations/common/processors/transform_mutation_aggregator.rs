@@ -52,8 +52,10 @@ use crate::operations::common::SnapshotMerged;
 use crate::operations::mutation::BlockIndex;
 use crate::operations::mutation::SegmentIndex;
 use crate::statistics::reducers::merge_statistics_mut;
+use crate::statistics::merge_columns_hll;
 use crate::statistics::reducers::reduce_block_metas;
 use crate::statistics::sort_by_cluster_stats;
+use crate::statistics::BlockHLL;
 use crate::FuseTable;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
@@ -75,6 +77,8 @@ pub struct TableMutationAggregator {
     mutations: HashMap<SegmentIndex, BlockMutations>,
     appended_segments: Vec<Location>,
     appended_statistics: Statistics,
+    // None if any appended segment has no NDV sketch.
+    appended_hlls: Option<BlockHLL>,
     removed_segment_indexes: Vec<SegmentIndex>,
     removed_statistics: Statistics,
 
@@ -105,6 +109,13 @@ impl AsyncAccumulatingTransform for TableMutationAggregator {
         let mut new_segment_locs = Vec::new();
         new_segment_locs.extend(self.appended_segments.clone());
 
+        // The sketches are only complete for pure appends, the blocks rewritten by
+        // the other mutations have no sketch.
+        let column_hlls = match self.kind {
+            MutationKind::Insert => self.appended_hlls.take(),
+            _ => None,
+        };
+
         let conflict_resolve_context = match self.kind {
             MutationKind::Insert => ConflictResolveContext::AppendOnly((
                 SnapshotMerged {
@@ -117,7 +128,12 @@ impl AsyncAccumulatingTransform for TableMutationAggregator {
             _ => self.apply_mutation(&mut new_segment_locs).await?,
         };
 
-        let meta = CommitMeta::new(conflict_resolve_context, new_segment_locs, self.table_id);
+        let meta = CommitMeta::new(
+            conflict_resolve_context,
+            new_segment_locs,
+            self.table_id,
+            column_hlls,
+        );
         debug!("mutations {:?}", meta);
         let block_meta: BlockMetaInfoPtr = Box::new(meta);
         Ok(Some(DataBlock::empty_with_meta(block_meta)))
@@ -148,6 +164,7 @@ impl TableMutationAggregator {
             base_segments,
             recluster_merged_blocks,
             appended_statistics: Statistics::default(),
+            appended_hlls: Some(BlockHLL::new()),
             removed_segment_indexes,
             removed_statistics,
             kind,
@@ -207,12 +224,20 @@ impl TableMutationAggregator {
                 segment_location,
                 format_version,
                 summary,
+                column_hlls,
             } => {
                 merge_statistics_mut(
                     &mut self.appended_statistics,
                     &summary,
                     self.default_cluster_key_id,
                 );
+                self.appended_hlls = match (self.appended_hlls.take(), column_hlls) {
+                    (Some(mut hlls), Some(other)) => {
+                        merge_columns_hll(&mut hlls, &other);
+                        Some(hlls)
+                    }
+                    _ => None,
+                };
 
                 self.appended_segments
                     .push((segment_location, format_version))
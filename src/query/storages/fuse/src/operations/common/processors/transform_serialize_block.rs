@@ -21,8 +21,10 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::ColumnId;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
+use databend_common_expression::FieldIndex;
 use databend_common_expression::TableSchema;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
@@ -32,6 +34,8 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::PipeItem;
 use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::Index;
+use databend_storages_common_index::RangeIndex;
 use opendal::Operator;
 
 use crate::io::create_inverted_index_builders;
@@ -39,10 +43,13 @@ use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
 use crate::io::BlockWriter;
 use crate::operations::common::BlockMetaIndex;
+use crate::operations::common::ExtendedBlockMeta;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
 use crate::operations::mutation::ClusterStatsGenType;
 use crate::operations::mutation::SerializeDataMeta;
+use crate::statistics::gen_columns_hll;
+use crate::statistics::BlockHLL;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
 
@@ -57,6 +64,7 @@ enum State {
     Serialized {
         serialized: BlockSerialization,
         index: Option<BlockMetaIndex>,
+        column_hlls: Option<BlockHLL>,
     },
 }
 
@@ -70,6 +78,8 @@ pub struct TransformSerializeBlock {
     dal: Operator,
     table_id: Option<u64>, // Only used in multi table insert
     kind: MutationKind,
+    // The columns of which the NDV sketches of appended blocks are built.
+    hll_columns: Option<Vec<(FieldIndex, ColumnId)>>,
 }
 
 impl TransformSerializeBlock {
@@ -129,6 +139,25 @@ impl TransformSerializeBlock {
 
         let inverted_index_builders = create_inverted_index_builders(&table.table_info.meta);
 
+        // Only pure appends merge the sketches into the table statistics.
+        let hll_columns = if matches!(kind, MutationKind::Insert)
+            && ctx
+                .get_settings()
+                .get_enable_incremental_table_statistics()?
+        {
+            Some(
+                source_schema
+                    .fields()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| RangeIndex::supported_type(&f.data_type().into()))
+                    .map(|(i, f)| (i, f.column_id()))
+                    .collect(),
+            )
+        } else {
+            None
+        };
+
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -147,6 +176,7 @@ impl TransformSerializeBlock {
             dal: table.get_operator(),
             table_id: if with_tid { Some(table.get_id()) } else { None },
             kind,
+            hll_columns,
         })
     }
 
@@ -281,6 +311,11 @@ impl Processor for TransformSerializeBlock {
                 // Check if the datablock is valid, this is needed to ensure data is correct
                 block.check_valid()?;
 
+                let column_hlls = match (&index, &self.hll_columns) {
+                    (None, Some(columns)) => Some(gen_columns_hll(&block, columns)?),
+                    _ => None,
+                };
+
                 let serialized =
                     self.block_builder
                         .build(block, |block, generator| match &stats_type {
@@ -292,7 +327,11 @@ impl Processor for TransformSerializeBlock {
                             }
                         })?;
 
-                self.state = State::Serialized { serialized, index };
+                self.state = State::Serialized {
+                    serialized,
+                    index,
+                    column_hlls,
+                };
             }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
@@ -302,7 +341,11 @@ impl Processor for TransformSerializeBlock {
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Consume) {
-            State::Serialized { serialized, index } => {
+            State::Serialized {
+                serialized,
+                index,
+                column_hlls,
+            } => {
                 let block_meta = BlockWriter::write_down(&self.dal, serialized).await?;
 
                 let mutation_log_data_block = if let Some(index) = index {
@@ -333,7 +376,10 @@ impl Processor for TransformSerializeBlock {
                             block_meta: Arc::new(block_meta),
                         })
                     } else {
-                        DataBlock::empty_with_meta(Box::new(block_meta))
+                        DataBlock::empty_with_meta(Box::new(ExtendedBlockMeta {
+                            block_meta,
+                            column_hlls,
+                        }))
                     }
                 };
                 self.output_data = Some(mutation_log_data_block);
@@ -29,15 +29,17 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::PipeItem;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache_manager::CachedObject;
-use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::Versioned;
 use log::info;
 use opendal::Operator;
 
 use crate::io::TableMetaLocationGenerator;
+use crate::operations::common::ExtendedBlockMeta;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
+use crate::statistics::merge_columns_hll;
+use crate::statistics::BlockHLL;
 use crate::statistics::StatisticsAccumulator;
 use crate::FuseTable;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
@@ -50,10 +52,12 @@ enum State {
         data: Vec<u8>,
         location: String,
         segment: Arc<SegmentInfo>,
+        column_hlls: Option<BlockHLL>,
     },
     PreCommitSegment {
         location: String,
         segment: Arc<SegmentInfo>,
+        column_hlls: Option<BlockHLL>,
     },
     Finished,
 }
@@ -62,6 +66,8 @@ pub struct TransformSerializeSegment {
     data_accessor: Operator,
     meta_locations: TableMetaLocationGenerator,
     accumulator: StatisticsAccumulator,
+    // None if any block of the segment has no NDV sketch.
+    column_hlls: Option<BlockHLL>,
     state: State,
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
@@ -88,6 +94,7 @@ impl TransformSerializeSegment {
             meta_locations: table.meta_location_generator().clone(),
             state: State::None,
             accumulator: Default::default(),
+            column_hlls: Some(BlockHLL::new()),
             block_per_seg: table
                 .get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT)
                 as u64,
@@ -161,11 +168,18 @@ impl Processor for TransformSerializeSegment {
                 .get_meta()
                 .cloned()
                 .ok_or_else(|| ErrorCode::Internal("No block meta. It's a bug"))?;
-            let block_meta = BlockMeta::downcast_ref_from(&input_meta)
-                .ok_or_else(|| ErrorCode::Internal("No commit meta. It's a bug"))?
-                .clone();
+            let extended_block_meta = ExtendedBlockMeta::downcast_from(input_meta)
+                .ok_or_else(|| ErrorCode::Internal("No commit meta. It's a bug"))?;
 
-            self.accumulator.add_with_block_meta(block_meta);
+            self.accumulator
+                .add_with_block_meta(extended_block_meta.block_meta);
+            self.column_hlls = match (self.column_hlls.take(), extended_block_meta.column_hlls) {
+                (Some(mut hlls), Some(block_hlls)) => {
+                    merge_columns_hll(&mut hlls, &block_hlls);
+                    Some(hlls)
+                }
+                _ => None,
+            };
             if self.accumulator.summary_block_count >= self.block_per_seg {
                 self.state = State::GenerateSegment;
                 return Ok(Event::Sync);
@@ -181,6 +195,7 @@ impl Processor for TransformSerializeSegment {
             State::GenerateSegment => {
                 let acc = std::mem::take(&mut self.accumulator);
                 let summary = acc.summary(self.thresholds, self.default_cluster_key_id);
+                let column_hlls = self.column_hlls.replace(BlockHLL::new());
 
                 let segment_info = SegmentInfo::new(acc.blocks_metas, summary);
 
@@ -188,9 +203,14 @@ impl Processor for TransformSerializeSegment {
                     data: segment_info.to_bytes()?,
                     location: self.meta_locations.gen_segment_info_location(),
                     segment: Arc::new(segment_info),
+                    column_hlls,
                 }
             }
-            State::PreCommitSegment { location, segment } => {
+            State::PreCommitSegment {
+                location,
+                segment,
+                column_hlls,
+            } => {
                 if let Some(segment_cache) = SegmentInfo::cache() {
                     segment_cache.put(location.clone(), Arc::new(segment.as_ref().try_into()?));
                 }
@@ -204,6 +224,7 @@ impl Processor for TransformSerializeSegment {
                         segment_location: location,
                         format_version,
                         summary: segment.summary.clone(),
+                        column_hlls,
                     }],
                 };
 
@@ -224,11 +245,16 @@ impl Processor for TransformSerializeSegment {
                 data,
                 location,
                 segment,
+                column_hlls,
             } => {
                 self.data_accessor.write(&location, data).await?;
                 info!("fuse append wrote down segment {} ", location);
 
-                self.state = State::PreCommitSegment { location, segment };
+                self.state = State::PreCommitSegment {
+                    location,
+                    segment,
+                    column_hlls,
+                };
             }
             _state => {
                 return Err(ErrorCode::Internal("Unknown state for fuse table sink."));
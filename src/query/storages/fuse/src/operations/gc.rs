@@ -117,6 +117,8 @@ impl FuseTable {
             .await?;
 
         let inverted_indexes = &self.table_info.meta.indexes;
+        // the files of this table that are still referenced by clones are kept.
+        let shared_files = self.shared_file_locations(ctx.clone()).await?;
 
         // 2. Read snapshot fields by chunk size.
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -216,6 +218,7 @@ impl FuseTable {
                         snapshots_to_be_purged,
                         &table_agg_index_ids,
                         inverted_indexes,
+                        &shared_files,
                     )
                     .await?;
 
@@ -268,6 +271,7 @@ impl FuseTable {
                     snapshots_to_be_purged,
                     &table_agg_index_ids,
                     inverted_indexes,
+                    &shared_files,
                 )
                 .await?;
             }
@@ -287,6 +291,7 @@ impl FuseTable {
                 root_snapshot_info.snapshot_location,
                 &table_agg_index_ids,
                 inverted_indexes,
+                &shared_files,
             )
             .await?;
         }
@@ -404,6 +409,7 @@ impl FuseTable {
         snapshots_to_be_purged: HashSet<String>,
         table_agg_index_ids: &[u64],
         inverted_indexes: &BTreeMap<String, TableIndex>,
+        shared_files: &HashSet<String>,
     ) -> Result<()> {
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         // Purge segments&blocks by chunk size
@@ -423,6 +429,14 @@ impl FuseTable {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
+                // the block was moved to the cold tier, its index files are still in use
+                let cold_location = TableMetaLocationGenerator::gen_cold_block_location(loc);
+                if locations_referenced_by_root
+                    .block_location
+                    .contains(&cold_location)
+                {
+                    continue;
+                }
                 for index_id in table_agg_index_ids {
                     agg_indexes_to_be_purged.insert(
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
@@ -477,14 +491,22 @@ impl FuseTable {
                 inverted_indexes_to_be_purged,
                 blooms_to_be_purged,
                 segment_locations_to_be_purged,
+                shared_files,
             )
             .await?;
         }
 
-        self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
-            .await
+        self.purge_ts_snapshots(
+            ctx,
+            counter,
+            ts_to_be_purged,
+            snapshots_to_be_purged,
+            shared_files,
+        )
+        .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_root_snapshot(
         &self,
         ctx: &Arc<dyn TableContext>,
@@ -494,6 +516,7 @@ impl FuseTable {
         root_snapshot_location: String,
         table_agg_index_ids: &[u64],
         inverted_indexes: &BTreeMap<String, TableIndex>,
+        shared_files: &HashSet<String>,
     ) -> Result<()> {
         let segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
@@ -537,6 +560,7 @@ impl FuseTable {
             inverted_indexes_to_be_purged,
             root_location_tuple.bloom_location,
             segment_locations_to_be_purged,
+            shared_files,
         )
         .await?;
 
@@ -549,10 +573,12 @@ impl FuseTable {
             counter,
             ts_to_be_purged,
             HashSet::from([root_snapshot_location]),
+            shared_files,
         )
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_block_segments(
         &self,
         ctx: &Arc<dyn TableContext>,
@@ -562,19 +588,20 @@ impl FuseTable {
         inverted_indexes_to_be_purged: HashSet<String>,
         blooms_to_be_purged: HashSet<String>,
         segments_to_be_purged: HashSet<String>,
+        shared_files: &HashSet<String>,
     ) -> Result<()> {
         // 1. Try to purge block file chunks.
         let blocks_count = blocks_to_be_purged.len();
         if blocks_count > 0 {
             counter.blocks += blocks_count;
-            self.try_purge_location_files(ctx.clone(), blocks_to_be_purged)
+            self.try_purge_location_files(ctx.clone(), blocks_to_be_purged, shared_files)
                 .await?;
         }
 
         let agg_index_count = agg_indexes_to_be_purged.len();
         if agg_index_count > 0 {
             counter.agg_indexes += agg_index_count;
-            self.try_purge_location_files(ctx.clone(), agg_indexes_to_be_purged)
+            self.try_purge_location_files(ctx.clone(), agg_indexes_to_be_purged, shared_files)
                 .await?;
         }
 
@@ -596,6 +623,7 @@ impl FuseTable {
             self.try_purge_location_files_and_cache::<InvertedIndexMeta, _, _>(
                 ctx.clone(),
                 inverted_indexes_to_be_purged,
+                shared_files,
             )
             .await?;
         }
@@ -607,6 +635,7 @@ impl FuseTable {
             self.try_purge_location_files_and_cache::<BloomIndexMeta, _, _>(
                 ctx.clone(),
                 blooms_to_be_purged,
+                shared_files,
             )
             .await?;
         }
@@ -618,6 +647,7 @@ impl FuseTable {
             self.try_purge_location_files_and_cache::<CompactSegmentInfo, _, _>(
                 ctx.clone(),
                 segments_to_be_purged,
+                shared_files,
             )
             .await?;
         }
@@ -630,6 +660,7 @@ impl FuseTable {
         counter: &mut PurgeCounter,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
+        shared_files: &HashSet<String>,
     ) -> Result<()> {
         // 3. Purge table statistic files
         let ts_count = ts_to_be_purged.len();
@@ -638,6 +669,7 @@ impl FuseTable {
             self.try_purge_location_files_and_cache::<TableSnapshotStatistics, _, _>(
                 ctx.clone(),
                 ts_to_be_purged,
+                shared_files,
             )
             .await?;
         }
@@ -649,6 +681,7 @@ impl FuseTable {
             self.try_purge_location_files_and_cache::<TableSnapshot, _, _>(
                 ctx.clone(),
                 snapshots_to_be_purged,
+                shared_files,
             )
             .await?;
         }
@@ -670,12 +703,32 @@ impl FuseTable {
     }
 
     // Purge file by location chunks.
+    //
+    // `shared_files` are the files of this table still referenced by clones, computed once per
+    // purge by `shared_file_locations`, they are kept.
     #[async_backtrace::framed]
     pub async fn try_purge_location_files(
         &self,
         ctx: Arc<dyn TableContext>,
         locations_to_be_purged: HashSet<String>,
+        shared_files: &HashSet<String>,
     ) -> Result<()> {
+        // A table created by `CREATE TABLE ... CLONE` (or with the `snapshot_location` option)
+        // references files that belong to another table, those must never be purged by this table.
+        let prefix = self.meta_location_generator().prefix();
+        let (locations_to_be_purged, foreign_locations): (HashSet<_>, HashSet<_>) =
+            locations_to_be_purged
+                .into_iter()
+                .filter(|loc| !shared_files.contains(loc))
+                .partition(|loc| loc.starts_with(prefix));
+        if !foreign_locations.is_empty() {
+            warn!(
+                "gc: table {} keeps {} files owned by other tables: {:?}",
+                self.table_info.desc,
+                foreign_locations.len(),
+                foreign_locations
+            );
+        }
         let fuse_file = Files::create(ctx.clone(), self.operator.clone());
         fuse_file.remove_file_in_batch(locations_to_be_purged).await
     }
@@ -686,6 +739,7 @@ impl FuseTable {
         &self,
         ctx: Arc<dyn TableContext>,
         locations_to_be_purged: HashSet<String>,
+        shared_files: &HashSet<String>,
     ) -> Result<()>
     where
         T: CachedObject<T, H, M>,
@@ -697,7 +751,7 @@ impl FuseTable {
                 cache.evict(loc);
             }
         }
-        self.try_purge_location_files(ctx, locations_to_be_purged)
+        self.try_purge_location_files(ctx, locations_to_be_purged, shared_files)
             .await
     }
 
@@ -33,7 +33,10 @@ mod recluster;
 mod replace;
 mod replace_into;
 mod revert;
+mod shared_files;
+mod tiering;
 mod truncate;
+mod ttl;
 mod util;
 
 pub use agg_index_sink::AggIndexSink;
@@ -44,6 +47,7 @@ pub use compact::CompactOptions;
 pub use merge_into::*;
 pub use mutation::*;
 pub use mutation_source::*;
+pub use read::fuse_source::adjust_scan_parallelism;
 pub use read::need_reserve_block_info;
 pub use read::row_fetch_processor;
 pub use replace_into::*;
@@ -66,6 +66,51 @@ impl FuseTable {
         }
     }
 
+    /// Navigate to the latest snapshot that was committed strictly before `point`.
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    pub async fn navigate_before_point(
+        &self,
+        point: &NavigationPoint,
+        abort_checker: AbortChecker,
+    ) -> Result<Arc<FuseTable>> {
+        let Some(location) = self.snapshot_loc().await? else {
+            return Err(ErrorCode::TableHistoricalDataNotFound(
+                "Empty Table has no historical data",
+            ));
+        };
+
+        match point {
+            NavigationPoint::SnapshotID(snapshot_id) => {
+                // Snapshots are visited from the newest to the oldest, so the one
+                // right after the matched snapshot is its predecessor.
+                let mut matched = false;
+                self.find(location, abort_checker, |snapshot| {
+                    if matched {
+                        return true;
+                    }
+                    matched = snapshot
+                        .snapshot_id
+                        .simple()
+                        .to_string()
+                        .as_str()
+                        .starts_with(snapshot_id.as_str());
+                    false
+                })
+                .await
+            }
+            NavigationPoint::TimePoint(time_point) => {
+                self.find(location, abort_checker, |snapshot| {
+                    snapshot.timestamp.is_some_and(|ts| ts < *time_point)
+                })
+                .await
+            }
+            NavigationPoint::StreamInfo(_) => Err(ErrorCode::Unimplemented(
+                "BEFORE does not support navigating by stream",
+            )),
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn navigate_to_stream(&self, stream_info: &TableInfo) -> Result<Arc<FuseTable>> {
         let options = stream_info.options();
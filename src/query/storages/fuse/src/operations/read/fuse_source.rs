@@ -255,6 +255,34 @@ pub fn dispatch_partitions(
     results
 }
 
+/// Sizes the number of parallel streams of a table scan from the estimated bytes to read,
+/// instead of always using `max_threads`, so that tiny scans do not pay the setup cost of
+/// many idle streams.
+///
+/// The width is bounded by `[min_parallelism, max_parallelism]`, a `max_parallelism` of 0
+/// means `max_threads`, and it may be set above `max_threads` for scans bound by I/O.
+pub fn adjust_scan_parallelism(
+    max_threads: usize,
+    read_bytes: usize,
+    min_parallelism: usize,
+    max_parallelism: usize,
+    bytes_per_thread: usize,
+) -> usize {
+    let upper = match max_parallelism {
+        0 => max_threads,
+        max_parallelism => max_parallelism,
+    }
+    .max(1);
+    let lower = min_parallelism.clamp(1, upper);
+
+    let mut parallelism = upper;
+    // The bytes are unknown for the lazy partitions.
+    if bytes_per_thread > 0 && read_bytes > 0 {
+        parallelism = parallelism.min(read_bytes.div_ceil(bytes_per_thread));
+    }
+    parallelism.clamp(lower, upper)
+}
+
 pub fn adjust_threads_and_request(
     is_native: bool,
     mut max_threads: usize,
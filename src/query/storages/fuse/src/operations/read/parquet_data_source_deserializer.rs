@@ -72,6 +72,9 @@ pub struct DeserializeDataTransform {
 
     base_block_ids: Option<Scalar>,
     cached_runtime_filter: Option<Vec<(FieldIndex, BinaryFuse16)>>,
+    // the in-memory bytes decoded for each projected column, recorded to the profile once
+    // the processor is finished.
+    decoded_column_bytes: Vec<usize>,
     // for merge_into target build.
     need_reserve_block_info: bool,
 }
@@ -107,6 +110,7 @@ impl DeserializeDataTransform {
         output_schema.remove_internal_fields();
         let output_schema: DataSchema = (&output_schema).into();
         let (need_reserve_block_info, _) = need_reserve_block_info(ctx.clone(), plan.table_index);
+        let decoded_column_bytes = vec![0; block_reader.schema().fields().len()];
         Ok(ProcessorPtr::create(Box::new(DeserializeDataTransform {
             ctx,
             table_index: plan.table_index,
@@ -123,6 +127,7 @@ impl DeserializeDataTransform {
             virtual_reader,
             base_block_ids: plan.base_block_ids.clone(),
             cached_runtime_filter: None,
+            decoded_column_bytes,
             need_reserve_block_info,
         })))
     }
@@ -169,6 +174,18 @@ impl DeserializeDataTransform {
             Ok(None)
         }
     }
+
+    fn record_decoded_column_bytes(&mut self) {
+        let schema = self.block_reader.schema();
+        let decoded_column_bytes = std::mem::take(&mut self.decoded_column_bytes);
+        Profile::record_decoded_column_bytes(
+            schema
+                .fields()
+                .iter()
+                .zip(decoded_column_bytes)
+                .map(|(field, bytes)| (field.name().as_str(), bytes)),
+        );
+    }
 }
 
 #[async_trait::async_trait]
@@ -184,6 +201,7 @@ impl Processor for DeserializeDataTransform {
     fn event(&mut self) -> Result<Event> {
         if self.output.is_finished() {
             self.input.finish();
+            self.record_decoded_column_bytes();
             return Ok(Event::Finished);
         }
 
@@ -220,6 +238,7 @@ impl Processor for DeserializeDataTransform {
 
         if self.input.is_finished() {
             self.output.finish();
+            self.record_decoded_column_bytes();
             return Ok(Event::Finished);
         }
 
@@ -253,13 +272,16 @@ impl Processor for DeserializeDataTransform {
                     let columns_chunks = data.columns_chunks()?;
                     let part = FuseBlockPartInfo::from_part(&part)?;
 
-                    let mut data_block = self.block_reader.deserialize_parquet_chunks(
-                        part.nums_rows,
-                        &part.columns_meta,
-                        columns_chunks,
-                        &part.compression,
-                        &part.location,
-                    )?;
+                    let mut data_block = self
+                        .block_reader
+                        .deserialize_parquet_chunks_with_decoded_bytes(
+                            part.nums_rows,
+                            &part.columns_meta,
+                            columns_chunks,
+                            &part.compression,
+                            &part.location,
+                            Some(self.decoded_column_bytes.as_mut_slice()),
+                        )?;
 
                     let origin_num_rows = data_block.num_rows();
 
@@ -304,6 +304,9 @@ impl Processor for ReadParquetDataSource<false> {
                                 ignore_column_ids,
                             )
                             .await?;
+                        if settings.enable_block_checksum_verification {
+                            source.verify_checksums(part)?;
+                        }
 
                         Ok(ParquetDataSource::Normal((source, virtual_source)))
                     })
@@ -19,6 +19,7 @@ use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::plan::TopK;
+use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -28,11 +29,13 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
+use log::info;
 
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::build_fuse_parquet_source_pipeline;
+use crate::operations::read::fuse_source::adjust_scan_parallelism;
 use crate::operations::read::fuse_source::build_fuse_native_source_pipeline;
 use crate::pruning::SegmentLocation;
 use crate::FuseLazyPartInfo;
@@ -91,6 +94,36 @@ impl FuseTable {
         }
     }
 
+    fn adjust_scan_parallelism(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+    ) -> Result<usize> {
+        let settings = ctx.get_settings();
+        let max_threads = settings.get_max_threads()? as usize;
+        // Only the scans of queries are sized, the reads of the mutations and the table
+        // maintenance keep using max_threads.
+        if !matches!(ctx.get_query_kind(), QueryKind::Query | QueryKind::Explain) {
+            return Ok(max_threads);
+        }
+
+        let parallelism = adjust_scan_parallelism(
+            max_threads,
+            plan.statistics.read_bytes,
+            settings.get_min_scan_parallelism()? as usize,
+            settings.get_max_scan_parallelism()? as usize,
+            settings.get_scan_bytes_per_thread()? as usize,
+        );
+        info!(
+            "scan {} with parallelism {}, estimated read bytes {}, partitions {}",
+            self.table_info.desc,
+            parallelism,
+            plan.statistics.read_bytes,
+            plan.parts.len()
+        );
+        Ok(parallelism)
+    }
+
     fn apply_data_mask_policy_if_needed(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -252,7 +285,7 @@ impl FuseTable {
         index_reader: Arc<Option<AggIndexReader>>,
         virtual_reader: Arc<Option<VirtualColumnReader>>,
     ) -> Result<()> {
-        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = self.adjust_scan_parallelism(&ctx, plan)?;
         let table_schema = self.schema_with_stream();
         match storage_format {
             FuseStorageFormat::Native => build_fuse_native_source_pipeline(
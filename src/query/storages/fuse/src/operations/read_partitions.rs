@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -29,6 +30,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_sql::field_default_value;
 use databend_common_storage::ColumnNodes;
 use databend_storages_common_cache::CacheAccessor;
@@ -85,7 +87,13 @@ impl FuseTable {
                     nodes_num = cluster.nodes.len();
                 }
 
-                if !dry_run && snapshot.segments.len() > nodes_num {
+                // Segments are pruned lazily at scan start, unless the query filters on
+                // the cluster key: then the min/max statistics of segments are tight enough
+                // to prune at plan time, which also makes the partition counts accurate.
+                if !dry_run
+                    && snapshot.segments.len() > nodes_num
+                    && !self.filter_on_cluster_key(ctx.clone(), push_downs.as_ref())
+                {
                     let mut segments = Vec::with_capacity(snapshot.segments.len());
                     for (idx, segment_location) in snapshot.segments.iter().enumerate() {
                         segments.push(FuseLazyPartInfo::create(idx, segment_location.clone()))
@@ -122,6 +130,32 @@ impl FuseTable {
         }
     }
 
+    /// Check whether the filter of `push_downs` references any column of the cluster key.
+    fn filter_on_cluster_key(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<&PushDownInfo>,
+    ) -> bool {
+        let Some(filters) = push_downs.and_then(|p| p.filters.as_ref()) else {
+            return false;
+        };
+        if self.cluster_key_meta.is_none() {
+            return false;
+        }
+
+        let cluster_key_columns = self
+            .cluster_keys(ctx)
+            .iter()
+            .flat_map(|key| key.as_expr(&BUILTIN_FUNCTIONS).column_refs().into_keys())
+            .collect::<HashSet<_>>();
+        filters
+            .filter
+            .as_expr(&BUILTIN_FUNCTIONS)
+            .column_refs()
+            .keys()
+            .any(|column| cluster_key_columns.contains(column))
+    }
+
     #[fastrace::trace]
     #[async_backtrace::framed]
     pub async fn prune_snapshot_blocks(
@@ -457,6 +491,7 @@ impl FuseTable {
     ) -> PartInfoPtr {
         let mut columns_meta = HashMap::with_capacity(meta.col_metas.len());
         let mut columns_stats = HashMap::with_capacity(meta.col_stats.len());
+        let mut columns_checksum = HashMap::with_capacity(meta.col_checksums.len());
 
         for column_id in meta.col_metas.keys() {
             // ignore all deleted field
@@ -474,6 +509,10 @@ impl FuseTable {
             if let Some(stat) = meta.col_stats.get(column_id) {
                 columns_stats.insert(*column_id, stat.clone());
             }
+
+            if let Some(checksum) = meta.col_checksums.get(column_id) {
+                columns_checksum.insert(*column_id, *checksum);
+            }
         }
 
         let rows_count = meta.row_count;
@@ -496,6 +535,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            Some(columns_checksum),
         )
     }
 
@@ -508,6 +548,7 @@ impl FuseTable {
     ) -> PartInfoPtr {
         let mut columns_meta = HashMap::with_capacity(projection.len());
         let mut columns_stat = HashMap::with_capacity(projection.len());
+        let mut columns_checksum = HashMap::with_capacity(projection.len());
 
         let columns = projection.project_column_nodes(column_nodes).unwrap();
         for column in &columns {
@@ -519,6 +560,9 @@ impl FuseTable {
                 if let Some(column_stat) = meta.col_stats.get(column_id) {
                     columns_stat.insert(*column_id, column_stat.clone());
                 }
+                if let Some(checksum) = meta.col_checksums.get(column_id) {
+                    columns_checksum.insert(*column_id, *checksum);
+                }
             }
         }
 
@@ -544,6 +588,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            Some(columns_checksum),
         )
     }
 }
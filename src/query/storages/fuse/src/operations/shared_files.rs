@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use futures::TryStreamExt;
+use log::info;
+use opendal::EntryMode;
+use opendal::Metakey;
+
+use crate::io::SnapshotsIO;
+use crate::FuseTable;
+use crate::FUSE_TBL_REF_PREFIX;
+
+// A table created by `CREATE TABLE ... CLONE` starts out from a snapshot of its source, so it
+// references segments and blocks that live under the storage prefix of another table.
+//
+// To keep those files alive, the clone leaves a reference file `<owner_prefix>/_ref/<table_id>`
+// in every table prefix that owns one of the files reachable from the snapshot it was cloned
+// from. The content of the reference file is the location of that snapshot.
+//
+// Every purge path of the owner (`OPTIMIZE ... PURGE`, `VACUUM TABLE`, `DROP TABLE ... ALL` and
+// `VACUUM DROP TABLE`) consults the references, and leaves the shared files untouched for as long
+// as the referencing table exists in the meta store (dropped but not yet vacuumed included).
+// Stale references are removed lazily. Since the shared snapshot file itself is retained too,
+// the next purge after the reference is gone collects the files that were kept.
+impl FuseTable {
+    /// Records the files shared with other tables, called once after the table is created
+    /// from the snapshot of another table.
+    #[async_backtrace::framed]
+    pub async fn register_shared_files(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        let Some(snapshot_location) = self.snapshot_loc().await? else {
+            return Ok(());
+        };
+        let locations = self
+            .collect_snapshot_files(ctx, snapshot_location.clone())
+            .await?;
+
+        let own_prefix = self.meta_location_generator().prefix();
+        let owner_prefixes = locations
+            .iter()
+            .filter_map(|loc| owner_prefix(loc))
+            .filter(|prefix| *prefix != own_prefix)
+            .collect::<HashSet<_>>();
+
+        let table_id = self.get_id();
+        for prefix in owner_prefixes {
+            let ref_location = format!("{}/{}/{}", prefix, FUSE_TBL_REF_PREFIX, table_id);
+            info!(
+                "table {} shares files of {}, reference {}",
+                self.table_info.desc, prefix, ref_location
+            );
+            self.operator
+                .write(&ref_location, snapshot_location.clone().into_bytes())
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Returns the files of this table that are still referenced by other tables, and that
+    /// therefore must not be purged. It lists the references and walks the referenced snapshots,
+    /// so a purge computes it once and passes it to `try_purge_location_files`.
+    #[async_backtrace::framed]
+    pub async fn shared_file_locations(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<HashSet<String>> {
+        let dir = format!(
+            "{}/{}/",
+            self.meta_location_generator().prefix(),
+            FUSE_TBL_REF_PREFIX
+        );
+        let mut ref_locations = vec![];
+        let mut ds = self
+            .operator
+            .lister_with(&dir)
+            .metakey(Metakey::Mode)
+            .await?;
+        while let Some(de) = ds.try_next().await? {
+            if de.metadata().mode() == EntryMode::FILE {
+                ref_locations.push(de.path().to_string());
+            }
+        }
+
+        let mut shared = HashSet::new();
+        if ref_locations.is_empty() {
+            return Ok(shared);
+        }
+
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        for ref_location in ref_locations {
+            let referencing_table_id = ref_location
+                .rsplit('/')
+                .next()
+                .and_then(|name| name.parse::<u64>().ok());
+            let alive = match referencing_table_id {
+                Some(table_id) => catalog.get_table_meta_by_id(table_id).await?.is_some(),
+                None => false,
+            };
+            if !alive {
+                info!("remove stale shared files reference {}", ref_location);
+                self.operator.delete(&ref_location).await?;
+                continue;
+            }
+
+            let content = self.operator.read(&ref_location).await?;
+            let snapshot_location = String::from_utf8(content.to_vec())?;
+            shared.extend(
+                self.collect_snapshot_files(ctx.clone(), snapshot_location)
+                    .await?,
+            );
+        }
+
+        let own_prefix = self.meta_location_generator().prefix();
+        shared.retain(|loc| loc.starts_with(own_prefix));
+        Ok(shared)
+    }
+
+    // All the files reachable from the given snapshot, the snapshot file itself included.
+    async fn collect_snapshot_files(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        snapshot_location: String,
+    ) -> Result<HashSet<String>> {
+        let (snapshot, _) =
+            SnapshotsIO::read_snapshot(snapshot_location.clone(), self.operator.clone()).await?;
+        let mut locations = HashSet::new();
+        locations.insert(snapshot_location);
+        if let Some(ts) = &snapshot.table_statistics_location {
+            locations.insert(ts.clone());
+        }
+        locations.extend(snapshot.segments.iter().map(|(loc, _)| loc.clone()));
+        let blocks = self
+            .get_block_locations(ctx, &snapshot.segments, false, false)
+            .await?;
+        locations.extend(blocks.block_location);
+        locations.extend(blocks.bloom_location);
+        Ok(locations)
+    }
+}
+
+// Files of a fuse table are laid out as `<table_prefix>/<kind>/<file>`.
+fn owner_prefix(location: &str) -> Option<&str> {
+    location.rsplitn(3, '/').nth(2)
+}
@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use log::info;
+
+use crate::io::SegmentWriter;
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+use crate::FUSE_OPT_KEY_TIERING_AGE_DAYS;
+
+impl FuseTable {
+    /// Move the blocks of the current snapshot that were created more than `tiering_age_days`
+    /// ago to the cold tier, and return the number of blocks moved.
+    #[async_backtrace::framed]
+    pub async fn do_tier_blocks(&self, ctx: Arc<dyn TableContext>) -> Result<u64> {
+        let age_days = self.get_option(FUSE_OPT_KEY_TIERING_AGE_DAYS, 0u64);
+        if age_days == 0 {
+            return Ok(0);
+        }
+        let threshold = Utc::now() - Duration::days(age_days as i64);
+        self.do_tier_blocks_created_before(ctx, threshold).await
+    }
+
+    /// Move the blocks of the current snapshot created before `threshold` to the cold tier.
+    ///
+    /// The blocks are copied, and the segments referencing them are rewritten to point to
+    /// the cold locations, so readers never probe the hot location of a moved block. The
+    /// hot copies are left to the purge of the snapshots still referencing them.
+    #[async_backtrace::framed]
+    pub async fn do_tier_blocks_created_before(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        threshold: DateTime<Utc>,
+    ) -> Result<u64> {
+        if self.is_read_only() {
+            return Ok(0);
+        }
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(0);
+        };
+
+        let prefix = self.meta_location_generator().prefix();
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let segment_writer = SegmentWriter::new(&self.operator, self.meta_location_generator());
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let mut segment_locations = Vec::with_capacity(snapshot.segments.len());
+        let mut moved = 0;
+        for chunk in snapshot.segments.chunks(chunk_size) {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for (segment, location) in segments.into_iter().zip(chunk.iter()) {
+                let segment = segment?;
+                let mut blocks = Vec::with_capacity(segment.blocks.len());
+                let mut moved_in_segment = 0;
+                for block in segment.blocks.iter() {
+                    // Blocks written by old versions have no creation time, they stay where they are.
+                    // Blocks shared with another table (e.g. a clone) are left to their owner.
+                    // Blocks already in the cold tier are recognized by their location, without
+                    // any request to the storage.
+                    let cold_location =
+                        TableMetaLocationGenerator::gen_cold_block_location(&block.location.0);
+                    let is_expired = block
+                        .create_on
+                        .is_some_and(|create_on| create_on < threshold);
+                    if !is_expired
+                        || !block.location.0.starts_with(prefix)
+                        || cold_location == block.location.0
+                    {
+                        blocks.push(block.clone());
+                        continue;
+                    }
+
+                    self.copy_block(&block.location.0, &cold_location).await?;
+                    blocks.push(Arc::new(BlockMeta {
+                        location: (cold_location, block.location.1),
+                        ..block.as_ref().clone()
+                    }));
+                    moved_in_segment += 1;
+                }
+
+                if moved_in_segment == 0 {
+                    segment_locations.push(location.clone());
+                    continue;
+                }
+                moved += moved_in_segment;
+                let new_segment = SegmentInfo::new(blocks, segment.summary.clone());
+                segment_locations.push(segment_writer.write_segment(new_segment).await?);
+            }
+        }
+
+        if moved > 0 {
+            let summary = snapshot.summary.clone();
+            self.commit_mutation(&ctx, snapshot, &segment_locations, summary, None)
+                .await?;
+        }
+        info!(
+            "tiering of table {} finished, {} blocks moved to the cold tier",
+            self.table_info.desc, moved
+        );
+        Ok(moved)
+    }
+
+    async fn copy_block(&self, location: &str, cold_location: &str) -> Result<()> {
+        if self.operator.info().native_capability().copy {
+            self.operator.copy(location, cold_location).await?;
+        } else {
+            let data = self.operator.read(location).await?;
+            self.operator.write(cold_location, data).await?;
+        }
+        Ok(())
+    }
+}
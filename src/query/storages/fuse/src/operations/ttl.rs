@@ -0,0 +1,154 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::ColumnId;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::parse_ttl_expr;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
+use log::info;
+
+use crate::io::SegmentWriter;
+use crate::io::SegmentsIO;
+use crate::statistics::reduce_block_metas;
+use crate::statistics::reducers::deduct_statistics_mut;
+use crate::statistics::reducers::merge_statistics_mut;
+use crate::FuseTable;
+
+const MICROS_PER_DAY: i64 = 24 * 60 * 60 * 1_000_000;
+
+impl FuseTable {
+    /// Remove the blocks of the current snapshot whose rows are all expired according to
+    /// the `ttl` option of the table, and return the number of blocks removed.
+    ///
+    /// Only the max value of the TTL column in the block statistics is looked at: blocks
+    /// holding some rows that are not expired yet are kept as they are, nothing is rewritten
+    /// but the segments that referenced the removed blocks.
+    #[async_backtrace::framed]
+    pub async fn do_expire_ttl(&self, ctx: Arc<dyn TableContext>) -> Result<u64> {
+        let Some(ttl) = self.table_info.options().get(OPT_KEY_TTL) else {
+            return Ok(0);
+        };
+        if self.is_read_only() {
+            return Ok(0);
+        }
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(0);
+        };
+
+        let schema = self.schema();
+        let (column, expr) = parse_ttl_expr(ctx.clone(), schema.clone(), ttl)?;
+        let field = schema.field_with_name(&column)?;
+        let checker = TtlChecker {
+            column_id: field.column_id(),
+            data_type: DataType::from(field.data_type()),
+            expr,
+            func_ctx: ctx.get_function_context()?,
+            now: Utc::now().timestamp_micros(),
+        };
+
+        let default_cluster_key_id = self.cluster_key_id();
+        let thresholds = self.get_block_thresholds();
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), schema);
+        let segment_writer = SegmentWriter::new(&self.operator, self.meta_location_generator());
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let mut segment_locations = Vec::with_capacity(snapshot.segments.len());
+        let mut summary = snapshot.summary.clone();
+        let mut removed = 0;
+        for chunk in snapshot.segments.chunks(chunk_size) {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for (segment, location) in segments.into_iter().zip(chunk.iter()) {
+                let segment = segment?;
+                let mut blocks = Vec::with_capacity(segment.blocks.len());
+                for block in segment.blocks.iter() {
+                    if !checker.is_expired(block)? {
+                        blocks.push(block.clone());
+                    }
+                }
+                if blocks.len() == segment.blocks.len() {
+                    segment_locations.push(location.clone());
+                    continue;
+                }
+
+                removed += segment.blocks.len() - blocks.len();
+                deduct_statistics_mut(&mut summary, &segment.summary);
+                if blocks.is_empty() {
+                    continue;
+                }
+                let new_summary = reduce_block_metas(&blocks, thresholds, default_cluster_key_id);
+                merge_statistics_mut(&mut summary, &new_summary, default_cluster_key_id);
+                let new_segment = SegmentInfo::new(blocks, new_summary);
+                segment_locations.push(segment_writer.write_segment(new_segment).await?);
+            }
+        }
+
+        if removed > 0 {
+            self.commit_mutation(&ctx, snapshot, &segment_locations, summary, None)
+                .await?;
+        }
+        info!(
+            "ttl of table {} enforced, {} expired blocks removed",
+            self.table_info.desc, removed
+        );
+        Ok(removed as u64)
+    }
+}
+
+struct TtlChecker {
+    column_id: ColumnId,
+    data_type: DataType,
+    // The TTL expression, with the TTL column bound at offset 0.
+    expr: Expr,
+    func_ctx: FunctionContext,
+    now: i64,
+}
+
+impl TtlChecker {
+    fn is_expired(&self, block: &BlockMeta) -> Result<bool> {
+        let Some(stats) = block.col_stats.get(&self.column_id) else {
+            return Ok(false);
+        };
+        // Rows with a NULL TTL value never expire, so neither does the block holding them.
+        if stats.null_count > 0 || matches!(stats.max, Scalar::Null) {
+            return Ok(false);
+        }
+
+        let entry = BlockEntry::new(self.data_type.clone(), Value::Scalar(stats.max.clone()));
+        let data_block = DataBlock::new(vec![entry], 1);
+        let evaluator = Evaluator::new(&data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let expire_at = match evaluator.run(&self.expr)? {
+            Value::Scalar(Scalar::Timestamp(ts)) => ts,
+            Value::Scalar(Scalar::Date(days)) => days as i64 * MICROS_PER_DAY,
+            _ => return Ok(false),
+        };
+        Ok(expire_at <= self.now)
+    }
+}
@@ -14,22 +14,27 @@
 
 use std::collections::HashMap;
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::Column;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::Value;
 use databend_common_expression::ORIGIN_BLOCK_ROW_NUM_COLUMN_ID;
 use databend_common_functions::aggregates::eval_aggr;
+use databend_common_io::prelude::borsh_deserialize_from_slice;
 use databend_storages_common_index::Index;
 use databend_storages_common_index::RangeIndex;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::MetaHLL;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 
 // Don't change this value
@@ -49,6 +54,55 @@ pub fn calc_column_distinct_of_values(column: &Column, rows: usize) -> Result<u6
     Ok(col[0])
 }
 
+/// NDV sketches of the columns of a block, keyed by column id.
+pub type BlockHLL = HashMap<ColumnId, MetaHLL>;
+
+// Must be the precision of the sketches collected by `ANALYZE TABLE`, so that both can be merged.
+// 0.01625 --> 12 buckets
+const TABLE_STATISTICS_DISTINCT_ERROR_RATE: f64 = 0.01625;
+
+/// Builds the NDV sketches of the given (field index, column id) of a block.
+pub fn gen_columns_hll(
+    data_block: &DataBlock,
+    columns: &[(FieldIndex, ColumnId)],
+) -> Result<BlockHLL> {
+    let rows = data_block.num_rows();
+    let mut hlls = BlockHLL::with_capacity(columns.len());
+    for (index, column_id) in columns {
+        let entry = data_block.get_by_offset(*index);
+        let column = entry.value.convert_to_full_column(&entry.data_type, rows);
+        let (state, _) = eval_aggr(
+            "approx_count_distinct_state",
+            vec![Scalar::Number(NumberScalar::Float64(
+                TABLE_STATISTICS_DISTINCT_ERROR_RATE.into(),
+            ))],
+            &[column],
+            rows,
+        )?;
+        let hll = match state.index(0) {
+            Some(ScalarRef::Binary(state)) => borsh_deserialize_from_slice(state)?,
+            // all the values are NULL.
+            Some(ScalarRef::Null) => MetaHLL::new(),
+            _ => {
+                return Err(ErrorCode::Internal(
+                    "approx_count_distinct_state must return a binary state",
+                ));
+            }
+        };
+        hlls.insert(*column_id, hll);
+    }
+    Ok(hlls)
+}
+
+/// Merges the NDV sketches of `other` into `hlls`.
+pub fn merge_columns_hll(hlls: &mut BlockHLL, other: &BlockHLL) {
+    for (column_id, hll) in other {
+        hlls.entry(*column_id)
+            .and_modify(|v| v.merge(hll))
+            .or_insert_with(|| hll.clone());
+    }
+}
+
 pub fn get_traverse_columns_dfs(data_block: &DataBlock) -> traverse::TraverseResult {
     traverse::traverse_values_dfs(data_block.columns())
 }
@@ -23,10 +23,13 @@ pub use block_statistics::BlockStatistics;
 pub use cluster_statistics::sort_by_cluster_stats;
 pub use cluster_statistics::ClusterStatsGenerator;
 pub use column_statistic::calc_column_distinct_of_values;
+pub use column_statistic::gen_columns_hll;
 pub use column_statistic::gen_columns_statistics;
 pub use column_statistic::get_traverse_columns_dfs;
+pub use column_statistic::merge_columns_hll;
 pub use column_statistic::scalar_min_max;
 pub use column_statistic::traverse;
+pub use column_statistic::BlockHLL;
 pub use column_statistic::Trim;
 pub use column_statistic::STATS_REPLACEMENT_CHAR;
 pub use column_statistic::STATS_STRING_PREFIX_LEN;
@@ -27,6 +27,7 @@ mod chunk_reader_impl;
 mod copy_into_table;
 mod hashable_schema;
 mod processors;
+mod pruning;
 mod read_partition;
 mod read_pipeline;
 mod strip;
@@ -14,13 +14,19 @@
 
 use std::sync::Arc;
 
+use arrow_array::cast::AsArray;
+use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::BlockEntry;
 use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
+use databend_common_expression::Value;
 use databend_common_pipeline_transforms::processors::AccumulatingTransform;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::FileStatus;
@@ -32,6 +38,8 @@ use crate::utils::map_orc_error;
 pub struct StripeDecoder {
     data_schema: Arc<DataSchema>,
     arrow_schema: arrow_schema::SchemaRef,
+    // for each output column, the path to it in the decoded record batch.
+    paths: Vec<Vec<usize>>,
     copy_status: Option<Arc<CopyStatus>>,
 }
 
@@ -40,6 +48,7 @@ impl StripeDecoder {
         table_ctx: Arc<dyn TableContext>,
         data_schema: Arc<DataSchema>,
         arrow_schema: arrow_schema::SchemaRef,
+        paths: Vec<Vec<usize>>,
     ) -> Self {
         let copy_status = if matches!(table_ctx.get_query_kind(), QueryKind::CopyIntoTable) {
             Some(table_ctx.get_copy_status())
@@ -49,9 +58,34 @@ impl StripeDecoder {
         StripeDecoder {
             copy_status,
             arrow_schema,
+            paths,
             data_schema,
         }
     }
+
+    fn project(&self, batch: &RecordBatch, path: &str) -> Result<DataBlock> {
+        let mut columns = Vec::with_capacity(self.paths.len());
+        for (field_path, field) in self.paths.iter().zip(self.data_schema.fields()) {
+            let mut array: ArrayRef = batch.column(field_path[0]).clone();
+            for index in &field_path[1..] {
+                array = match array.as_struct_opt() {
+                    Some(array) => array.column(*index).clone(),
+                    None => {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "fail to read {}: column {} is expected to be a struct, but got {}",
+                            path,
+                            field.name(),
+                            array.data_type()
+                        )));
+                    }
+                };
+            }
+            let data_type = field.data_type().clone();
+            let column = Column::from_arrow_rs(array, &data_type)?;
+            columns.push(BlockEntry::new(data_type, Value::Column(column)));
+        }
+        Ok(DataBlock::new(columns, batch.num_rows()))
+    }
 }
 
 impl AccumulatingTransform for StripeDecoder {
@@ -69,7 +103,7 @@ impl AccumulatingTransform for StripeDecoder {
         let batches = batches.map_err(|e| map_orc_error(e, &stripe.path))?;
         let mut blocks = vec![];
         for batch in batches {
-            let (block, _) = DataBlock::from_record_batch(self.data_schema.as_ref(), &batch)?;
+            let block = self.project(&batch, &stripe.path)?;
             if let Some(copy_status) = &self.copy_status {
                 copy_status.add_chunk(&stripe.path, FileStatus {
                     num_rows_loaded: block.num_rows(),
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::mem;
+use std::ops::Range;
 use std::sync::Arc;
 
 use databend_common_base::base::Progress;
@@ -30,9 +32,11 @@ use databend_common_pipeline_sources::AsyncSourcer;
 use databend_storages_common_stage::SingleFilePartition;
 use opendal::Operator;
 use orc_rust::async_arrow_reader::StripeFactory;
+use orc_rust::projection::ProjectionMask;
 use orc_rust::ArrowReaderBuilder;
 
 use crate::chunk_reader_impl::OrcChunkReader;
+use crate::pruning::StripePruner;
 use crate::strip::StripeInMemory;
 use crate::utils::map_orc_error;
 
@@ -43,6 +47,11 @@ pub struct ORCSource {
     scan_progress: Arc<Progress>,
 
     arrow_schema: arrow_schema::SchemaRef,
+    // names of the top-level columns to read, `None` means all columns.
+    projection: Option<Vec<String>>,
+    pruner: Option<Arc<StripePruner>>,
+    // byte ranges of the runs of stripes of the current file left to read.
+    stripe_runs: VecDeque<Range<usize>>,
     schema_from: String,
 }
 
@@ -52,6 +61,8 @@ impl ORCSource {
         table_ctx: Arc<dyn TableContext>,
         op: Operator,
         arrow_schema: arrow_schema::SchemaRef,
+        projection: Option<Vec<String>>,
+        pruner: Option<Arc<StripePruner>>,
         schema_from: String,
     ) -> Result<ProcessorPtr> {
         let scan_progress = table_ctx.get_scan_progress();
@@ -62,6 +73,9 @@ impl ORCSource {
             scan_progress,
             reader: None,
             arrow_schema,
+            projection,
+            pruner,
+            stripe_runs: VecDeque::new(),
             schema_from,
         })
     }
@@ -76,30 +90,74 @@ impl ORCSource {
         Ok(())
     }
 
-    async fn next_part(&mut self) -> Result<bool> {
-        let part = match self.table_ctx.get_partition() {
-            Some(part) => part,
-            None => return Ok(false),
-        };
-        let file = SingleFilePartition::from_part(&part)?.clone();
-        let path = file.path.clone();
-        let size = file.size;
-
+    async fn open_file(
+        &self,
+        path: &str,
+        size: usize,
+    ) -> Result<ArrowReaderBuilder<OrcChunkReader>> {
         let file = OrcChunkReader {
             operator: self.op.clone(),
-            size: file.size as u64,
-            path: file.path,
+            size: size as u64,
+            path: path.to_string(),
         };
-        let builder = ArrowReaderBuilder::try_new_async(file)
+        let mut builder = ArrowReaderBuilder::try_new_async(file)
             .await
-            .map_err(|e| map_orc_error(e, &path))?;
+            .map_err(|e| map_orc_error(e, path))?;
+        if let Some(names) = &self.projection {
+            let projection =
+                ProjectionMask::named_roots(builder.file_metadata().root_data_type(), names);
+            builder = builder.with_projection(projection);
+        }
+        Ok(builder)
+    }
+
+    fn start_reading(
+        &mut self,
+        builder: ArrowReaderBuilder<OrcChunkReader>,
+        path: String,
+        size: usize,
+    ) -> Result<()> {
         let mut reader = builder.build_async();
         let factory = mem::take(&mut reader.factory).unwrap();
         let schema = reader.schema();
         self.check_file_schema(schema, &path)?;
 
         self.reader = Some((path, factory, size));
-        Ok(true)
+        Ok(())
+    }
+
+    async fn next_part(&mut self) -> Result<bool> {
+        loop {
+            let part = match self.table_ctx.get_partition() {
+                Some(part) => part,
+                None => return Ok(false),
+            };
+            let file = SingleFilePartition::from_part(&part)?.clone();
+            let mut builder = self.open_file(&file.path, file.size).await?;
+            if let Some(pruner) = &self.pruner {
+                self.stripe_runs = pruner.select_stripes(builder.file_metadata()).into();
+                match self.stripe_runs.pop_front() {
+                    Some(run) => builder = builder.with_file_byte_range(run),
+                    // none of the stripes can hold matching rows.
+                    None => continue,
+                }
+            }
+            self.start_reading(builder, file.path, file.size)?;
+            return Ok(true);
+        }
+    }
+
+    /// Continues with the next run of stripes of the current file, a pruned stripe in between
+    /// is not read.
+    async fn next_stripe_run(&mut self, path: String, size: usize) -> Result<bool> {
+        match self.stripe_runs.pop_front() {
+            Some(run) => {
+                let builder = self.open_file(&path, size).await?;
+                self.start_reading(builder.with_file_byte_range(run), path, size)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
     }
 }
 
@@ -123,6 +181,9 @@ impl AsyncSource for ORCSource {
                 match stripe {
                     None => {
                         self.reader = None;
+                        if self.next_stripe_run(path, size).await? {
+                            continue;
+                        }
                         let progress_values = ProgressValues {
                             rows: 0,
                             bytes: size,
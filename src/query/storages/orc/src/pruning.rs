@@ -0,0 +1,227 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnId;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchemaRef;
+use databend_storages_common_pruner::RangePruner;
+use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use orc_rust::reader::metadata::FileMetadata;
+use orc_rust::schema::NamedColumn;
+use orc_rust::statistics::TypeStatistics;
+use orc_rust::stripe::StripeMetadata;
+
+/// A pruner to skip the stripes of ORC files by the min/max statistics of their columns.
+///
+/// Only the top-level integer, float, string, date and boolean columns are used, the
+/// statistics of the other columns are ignored.
+pub struct StripePruner {
+    range_pruner: Arc<dyn RangePruner + Send + Sync>,
+    // the top-level columns referenced by the filter: index in the schema, column id and type.
+    columns: Vec<(usize, ColumnId, TableDataType)>,
+}
+
+impl StripePruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: &TableSchemaRef,
+        filter: &Expr<String>,
+    ) -> Result<Self> {
+        let range_pruner = RangePrunerCreator::try_create(func_ctx, schema, Some(filter))?;
+        let columns = filter
+            .column_refs()
+            .into_keys()
+            .filter_map(|name| {
+                let index = schema.index_of(&name).ok()?;
+                let field = schema.field(index);
+                Some((
+                    index,
+                    field.column_id(),
+                    field.data_type().remove_nullable(),
+                ))
+            })
+            .collect();
+        Ok(StripePruner {
+            range_pruner,
+            columns,
+        })
+    }
+
+    /// Returns the runs of consecutive stripes which may contain rows matching the filter.
+    ///
+    /// Each run is given as the byte range of the file holding the offsets of its stripes,
+    /// in the order of the stripes in the file.
+    pub fn select_stripes(&self, file_metadata: &FileMetadata) -> Vec<Range<usize>> {
+        let roots = file_metadata.root_data_type().children();
+        let mut runs: Vec<Range<usize>> = vec![];
+        let mut in_run = false;
+        for stripe in file_metadata.stripe_metadatas() {
+            if !self.should_keep(stripe, roots) {
+                in_run = false;
+                continue;
+            }
+            let offset = stripe.offset() as usize;
+            match runs.last_mut() {
+                Some(run) if in_run => run.end = offset + 1,
+                _ => runs.push(offset..offset + 1),
+            }
+            in_run = true;
+        }
+        runs
+    }
+
+    fn should_keep(&self, stripe: &StripeMetadata, roots: &[NamedColumn]) -> bool {
+        let statistics = stripe.column_statistics();
+        let mut stats = StatisticsOfColumns::with_capacity(self.columns.len());
+        for (index, column_id, data_type) in &self.columns {
+            let column_stats = roots
+                .get(*index)
+                .and_then(|root| statistics.get(root.data_type().column_index()))
+                .and_then(|s| convert_column_statistics(s, stripe.number_of_rows(), data_type));
+            if let Some(column_stats) = column_stats {
+                stats.insert(*column_id, column_stats);
+            }
+        }
+        self.range_pruner.should_keep(&stats, None)
+    }
+}
+
+/// Converts the statistics of an ORC column in a stripe of `num_rows` rows.
+///
+/// Returns `None` if the statistics can't be used for a column of type `typ`.
+fn convert_column_statistics(
+    s: &orc_rust::statistics::ColumnStatistics,
+    num_rows: u64,
+    typ: &TableDataType,
+) -> Option<ColumnStatistics> {
+    let null_count = num_rows.saturating_sub(s.number_of_values());
+    if s.number_of_values() == 0 {
+        // all the values are nulls.
+        return Some(ColumnStatistics::new(
+            Scalar::Null,
+            Scalar::Null,
+            null_count,
+            0,
+            None,
+        ));
+    }
+    let (min, max) = match (s.type_statistics()?, typ) {
+        (TypeStatistics::Integer { min, max, .. }, TableDataType::Number(ty)) => match ty {
+            NumberDataType::Int8 => (Scalar::from(*min as i8), Scalar::from(*max as i8)),
+            NumberDataType::Int16 => (Scalar::from(*min as i16), Scalar::from(*max as i16)),
+            NumberDataType::Int32 => (Scalar::from(*min as i32), Scalar::from(*max as i32)),
+            NumberDataType::Int64 => (Scalar::from(*min), Scalar::from(*max)),
+            _ => return None,
+        },
+        (TypeStatistics::Double { min, max, .. }, TableDataType::Number(ty)) => match ty {
+            NumberDataType::Float32 => (Scalar::from(*min as f32), Scalar::from(*max as f32)),
+            NumberDataType::Float64 => (Scalar::from(*min), Scalar::from(*max)),
+            _ => return None,
+        },
+        (TypeStatistics::String { min, max, .. }, TableDataType::String) => {
+            (Scalar::String(min.clone()), Scalar::String(max.clone()))
+        }
+        (TypeStatistics::Date { min, max }, TableDataType::Date) => {
+            (Scalar::Date(*min), Scalar::Date(*max))
+        }
+        (TypeStatistics::Bucket { true_count }, TableDataType::Boolean) => (
+            Scalar::Boolean(*true_count == s.number_of_values()),
+            Scalar::Boolean(*true_count > 0),
+        ),
+        _ => return None,
+    };
+    Some(ColumnStatistics::new(min, max, null_count, 0, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::sync::Arc;
+
+    use databend_common_expression::type_check::check_function;
+    use databend_common_expression::types::DataType;
+    use databend_common_expression::types::NumberDataType;
+    use databend_common_expression::types::NumberScalar;
+    use databend_common_expression::Expr;
+    use databend_common_expression::FunctionContext;
+    use databend_common_expression::Scalar;
+    use databend_common_expression::TableSchema;
+    use databend_common_expression::TableSchemaRef;
+    use databend_common_functions::BUILTIN_FUNCTIONS;
+    use orc_rust::ArrowReaderBuilder;
+
+    use super::StripePruner;
+
+    const PATH: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../../../tests/data/orc/alltypes.zstd.orc"
+    );
+
+    fn int8_greater_than(schema: &TableSchemaRef, value: i8) -> Expr<String> {
+        let field = schema.field_with_name("int8").unwrap();
+        let column = Expr::ColumnRef {
+            span: None,
+            id: "int8".to_string(),
+            data_type: DataType::from(field.data_type()),
+            display_name: "int8".to_string(),
+        };
+        let constant = Expr::Constant {
+            span: None,
+            scalar: Scalar::Number(NumberScalar::Int8(value)),
+            data_type: DataType::Number(NumberDataType::Int8),
+        };
+        check_function(None, "gt", &[], &[column, constant], &BUILTIN_FUNCTIONS).unwrap()
+    }
+
+    #[test]
+    fn test_select_stripes() {
+        let arrow_schema = ArrowReaderBuilder::try_new(File::open(PATH).unwrap())
+            .unwrap()
+            .build()
+            .schema();
+        let schema = Arc::new(TableSchema::try_from(arrow_schema.as_ref()).unwrap());
+        let builder = ArrowReaderBuilder::try_new(File::open(PATH).unwrap()).unwrap();
+        let file_metadata = builder.file_metadata();
+        assert!(!file_metadata.stripe_metadatas().is_empty());
+
+        // the max of int8 is 127, no stripe can hold a greater value.
+        let filter = int8_greater_than(&schema, i8::MAX);
+        let pruner =
+            StripePruner::try_create(FunctionContext::default(), &schema, &filter).unwrap();
+        assert!(pruner.select_stripes(file_metadata).is_empty());
+
+        let filter = int8_greater_than(&schema, 100);
+        let pruner =
+            StripePruner::try_create(FunctionContext::default(), &schema, &filter).unwrap();
+        let runs = pruner.select_stripes(file_metadata);
+        assert!(!runs.is_empty());
+        for run in runs {
+            let stripes = file_metadata
+                .stripe_metadatas()
+                .iter()
+                .filter(|stripe| run.contains(&(stripe.offset() as usize)));
+            assert!(stripes.count() > 0);
+        }
+    }
+}
@@ -15,9 +15,14 @@
 use std::sync::Arc;
 
 use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
+use databend_common_expression::TableSchema;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::EmptySource;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
@@ -26,6 +31,7 @@ use databend_common_storage::init_stage_operator;
 use super::OrcTable;
 use crate::processors::decoder::StripeDecoder;
 use crate::processors::source::ORCSource;
+use crate::pruning::StripePruner;
 
 impl OrcTable {
     #[inline]
@@ -47,15 +53,39 @@ impl OrcTable {
         let max_threads = settings.get_max_threads()? as usize;
         let num_source = max_threads.min(plan.parts.len());
         let operator = init_stage_operator(&self.stage_table_info.stage_info)?;
-        let data_schema: DataSchema = self.stage_table_info.schema.clone().into();
+        let data_schema: DataSchema = plan.schema().into();
         let data_schema = Arc::new(data_schema);
+
+        let projection = PushDownInfo::projection_of_push_downs(
+            &self.stage_table_info.schema,
+            plan.push_downs.as_ref(),
+        );
+        let (roots, paths) = projection_paths(&projection);
+        let arrow_schema = Arc::new(
+            self.arrow_schema
+                .project(&roots)
+                .map_err(ErrorCode::from_std_error)?,
+        );
+        let root_names = project_root_names(&self.stage_table_info.schema, &roots);
+
+        let pruner = match plan.push_downs.as_ref().and_then(|p| p.filters.as_ref()) {
+            Some(filters) => Some(Arc::new(StripePruner::try_create(
+                ctx.get_function_context()?,
+                &self.stage_table_info.schema,
+                &filters.filter.as_expr(&BUILTIN_FUNCTIONS),
+            )?)),
+            None => None,
+        };
+
         pipeline.add_source(
             |output| {
                 ORCSource::try_create(
                     output,
                     ctx.clone(),
                     operator.clone(),
-                    self.arrow_schema.clone(),
+                    arrow_schema.clone(),
+                    root_names.clone(),
+                    pruner.clone(),
                     self.schema_from.clone(),
                 )
             },
@@ -63,8 +93,59 @@ impl OrcTable {
         )?;
         pipeline.try_resize(max_threads)?;
         pipeline.add_accumulating_transformer(|| {
-            StripeDecoder::new(ctx.clone(), data_schema.clone(), self.arrow_schema.clone())
+            StripeDecoder::new(
+                ctx.clone(),
+                data_schema.clone(),
+                arrow_schema.clone(),
+                paths.clone(),
+            )
         });
         Ok(())
     }
 }
+
+/// Returns the sorted top-level columns to read from the ORC files, and for each
+/// output column the path to it, relative to the columns read.
+///
+/// At least one top-level column is always read, so that the number of rows is known
+/// even if no column is required (e.g. `SELECT COUNT(*)`).
+fn projection_paths(projection: &Projection) -> (Vec<usize>, Vec<Vec<usize>>) {
+    let mut roots = match projection {
+        Projection::Columns(indices) => indices.clone(),
+        Projection::InnerColumns(path_indices) => {
+            path_indices.values().map(|path| path[0]).collect()
+        }
+    };
+    roots.sort();
+    roots.dedup();
+
+    let position = |index: &usize| roots.binary_search(index).unwrap();
+    let paths = match projection {
+        Projection::Columns(indices) => indices.iter().map(|i| vec![position(i)]).collect(),
+        Projection::InnerColumns(path_indices) => path_indices
+            .values()
+            .map(|path| {
+                let mut path = path.clone();
+                path[0] = position(&path[0]);
+                path
+            })
+            .collect(),
+    };
+
+    if roots.is_empty() {
+        roots.push(0);
+    }
+    (roots, paths)
+}
+
+fn project_root_names(schema: &TableSchema, roots: &[usize]) -> Option<Vec<String>> {
+    if roots.len() == schema.num_fields() {
+        return None;
+    }
+    Some(
+        roots
+            .iter()
+            .map(|i| schema.fields()[*i].name().clone())
+            .collect(),
+    )
+}
@@ -131,7 +131,7 @@ impl Table for OrcTable {
     }
 
     fn support_column_projection(&self) -> bool {
-        false
+        true
     }
 
     fn support_prewhere(&self) -> bool {
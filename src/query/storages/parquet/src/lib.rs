@@ -30,6 +30,8 @@ mod utils;
 
 pub use parquet_part::ParquetFilesPart;
 pub use parquet_part::ParquetPart;
+pub use parquet_rs::get_pushdown_without_partition_columns;
+pub use parquet_rs::ExternalTable;
 pub use parquet_rs::InMemoryRowGroup;
 pub use parquet_rs::ParquetFileReader;
 pub use parquet_rs::ParquetRSFullReader;
@@ -0,0 +1,172 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+
+/// Directory value written by Hive/Spark for NULL partition values.
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Files and directories starting with `_` or `.` (e.g. `_SUCCESS`, `_delta_log/`, `.crc` files)
+/// are metadata of the writer, not data.
+pub fn is_hidden_path(path: &str) -> bool {
+    path.split('/')
+        .any(|name| name.starts_with('_') || name.starts_with('.'))
+}
+
+/// Parse the `key=value` directories of a hive-style partitioned path, e.g.
+/// `year=2024/month=01/part-0.parquet` gives `[("year", "2024"), ("month", "01")]`.
+pub fn parse_partition_path(path: &str) -> Vec<(String, String)> {
+    let mut names = path.trim_start_matches('/').split('/').collect::<Vec<_>>();
+    // The last one is the file name.
+    names.pop();
+    names
+        .into_iter()
+        .filter_map(|name| name.split_once('='))
+        .map(|(key, value)| (key.to_lowercase(), value.to_string()))
+        .collect()
+}
+
+/// Infer the partition columns from the directories of the data files.
+///
+/// All files must be under the same partition keys. A column is `Int64` if all its values
+/// are integers, otherwise it is `String`. Partition columns are always nullable.
+pub fn infer_partition_fields(paths: &[&str]) -> Result<Vec<TableField>> {
+    let Some(first) = paths.first() else {
+        return Ok(vec![]);
+    };
+    let keys = parse_partition_path(first)
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect::<Vec<_>>();
+
+    let mut is_integer = vec![true; keys.len()];
+    for path in paths {
+        let values = parse_partition_path(path);
+        if values.len() != keys.len()
+            || values.iter().zip(keys.iter()).any(|((k, _), key)| k != key)
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "partition directories of {} do not match the partition columns {:?} of {}",
+                path, keys, first
+            )));
+        }
+        for (i, (_, value)) in values.iter().enumerate() {
+            if value != HIVE_DEFAULT_PARTITION && value.parse::<i64>().is_err() {
+                is_integer[i] = false;
+            }
+        }
+    }
+
+    Ok(keys
+        .into_iter()
+        .zip(is_integer)
+        .map(|(key, is_integer)| {
+            let data_type = if is_integer {
+                TableDataType::Number(NumberDataType::Int64)
+            } else {
+                TableDataType::String
+            };
+            TableField::new(&key, data_type.wrap_nullable())
+        })
+        .collect())
+}
+
+/// Get the values of the partition columns from the path of a data file.
+pub fn get_partition_values(path: &str, fields: &[TableField]) -> Result<Vec<Scalar>> {
+    let values = parse_partition_path(path);
+    fields
+        .iter()
+        .map(|field| {
+            let Some((_, value)) = values.iter().find(|(key, _)| key == field.name()) else {
+                return Err(ErrorCode::BadArguments(format!(
+                    "partition value for column {} not found in {}",
+                    field.name(),
+                    path
+                )));
+            };
+            if value == HIVE_DEFAULT_PARTITION {
+                return Ok(Scalar::Null);
+            }
+            match field.data_type().remove_nullable() {
+                TableDataType::Number(NumberDataType::Int64) => {
+                    let num = value.parse::<i64>().map_err(|_| {
+                        ErrorCode::BadBytes(format!(
+                            "partition value {} of column {} in {} is not an integer",
+                            value,
+                            field.name(),
+                            path
+                        ))
+                    })?;
+                    Ok(Scalar::Number(NumberScalar::Int64(num)))
+                }
+                _ => Ok(Scalar::String(value.clone())),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_partition_path() {
+        assert_eq!(
+            parse_partition_path("year=2024/Month=01/part-0.parquet"),
+            vec![
+                ("year".to_string(), "2024".to_string()),
+                ("month".to_string(), "01".to_string())
+            ]
+        );
+        assert!(parse_partition_path("part-0.parquet").is_empty());
+        assert!(is_hidden_path("_delta_log/00000.json"));
+        assert!(is_hidden_path("year=2024/.part-0.parquet.crc"));
+        assert!(!is_hidden_path("year=2024/part-0.parquet"));
+    }
+
+    #[test]
+    fn test_infer_partition_fields() {
+        let paths = [
+            "p0=10/p1=a/part-0.parquet",
+            "p0=__HIVE_DEFAULT_PARTITION__/p1=1/part-1.parquet",
+        ];
+        let fields = infer_partition_fields(&paths).unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(
+            fields[0].data_type(),
+            &TableDataType::Number(NumberDataType::Int64).wrap_nullable()
+        );
+        assert_eq!(
+            fields[1].data_type(),
+            &TableDataType::String.wrap_nullable()
+        );
+
+        let values = get_partition_values(paths[0], &fields).unwrap();
+        let expected = vec![
+            Scalar::Number(NumberScalar::Int64(10)),
+            Scalar::String("a".to_string()),
+        ];
+        assert_eq!(values, expected);
+        let values = get_partition_values(paths[1], &fields).unwrap();
+        assert_eq!(values, vec![Scalar::Null, Scalar::String("1".to_string())]);
+
+        assert!(infer_partition_fields(&["p0=1/part-0.parquet", "part-1.parquet"]).is_err());
+    }
+}
@@ -0,0 +1,371 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use databend_common_catalog::catalog::StorageDescription;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::ParquetReadOptions;
+use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchema;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_storage::init_operator;
+use databend_common_storage::parquet_rs::infer_schema_with_extension;
+use databend_common_storage::parquet_rs::read_metadata_async;
+use databend_common_storage::StageFileInfo;
+use databend_common_storage::StageFilesInfo;
+use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::hive_partition::get_partition_values;
+use super::hive_partition::infer_partition_fields;
+use super::hive_partition::is_hidden_path;
+use super::partition::ExternalPartInfo;
+use super::source::ExternalTableSource;
+use crate::parquet_rs::partition_columns::get_pushdown_without_partition_columns;
+use crate::parquet_rs::schema::arrow_to_table_schema;
+use crate::ParquetFilesPart;
+use crate::ParquetPart;
+use crate::ParquetRSPruner;
+use crate::ParquetRSReaderBuilder;
+
+pub const EXTERNAL_ENGINE: &str = "EXTERNAL";
+
+/// A table over the parquet files under a location, read in place without ingesting them.
+///
+/// The schema is inferred from the first data file when the table is created. Hive-style
+/// `key=value` directories become partition columns, which are not stored in the parquet files,
+/// so they are handled the same way as the partition columns of a delta table:
+///
+/// - files whose partition values can not match the filter are pruned when reading partitions.
+/// - partition columns are excluded when reading parquet files and filled with the values of the part.
+pub struct ExternalTable {
+    info: TableInfo,
+    meta: ExternalTableMeta,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExternalTableMeta {
+    partition_columns: Vec<String>,
+}
+
+impl ExternalTable {
+    pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
+        let meta_string = info
+            .meta
+            .engine_options
+            .get(OPT_KEY_ENGINE_META)
+            .ok_or_else(|| ErrorCode::Internal("missing engine option OPT_KEY_ENGINE_META"))?;
+        let meta: ExternalTableMeta = serde_json::from_str(meta_string).map_err(|e| {
+            ErrorCode::Internal(format!(
+                "fail to deserialize ExternalTableMeta({meta_string}): {e:?}"
+            ))
+        })?;
+        Ok(Box::new(Self { info, meta }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: EXTERNAL_ENGINE.to_string(),
+            comment: "EXTERNAL Storage Engine".to_string(),
+            support_cluster_key: false,
+        }
+    }
+
+    fn get_storage_params(&self) -> Result<&StorageParams> {
+        self.info.meta.storage_params.as_ref().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "External table {} must have storage parameters",
+                self.info.name
+            ))
+        })
+    }
+
+    fn get_partition_fields(&self) -> Result<Vec<TableField>> {
+        self.meta
+            .partition_columns
+            .iter()
+            .map(|name| self.info.meta.schema.field_with_name(name).cloned())
+            .collect()
+    }
+
+    /// List the data files under the location, skipping hidden files and directories.
+    #[async_backtrace::framed]
+    async fn list_files(op: &Operator, thread_num: usize) -> Result<Vec<StageFileInfo>> {
+        let files_info = StageFilesInfo {
+            path: "/".to_string(),
+            files: None,
+            pattern: None,
+        };
+        let files = files_info.list(op, thread_num, None).await?;
+        Ok(files
+            .into_iter()
+            .filter(|file| file.size > 0 && !is_hidden_path(&file.path))
+            .collect())
+    }
+
+    /// Infer the table schema from the files under the location of `sp`.
+    ///
+    /// Returns the schema and the serialized [`ExternalTableMeta`].
+    #[async_backtrace::framed]
+    pub async fn infer_schema(sp: &StorageParams) -> Result<(TableSchema, String)> {
+        let op = init_operator(sp)?;
+        let files = Self::list_files(&op, 1).await?;
+        let Some(first) = files.first() else {
+            return Err(ErrorCode::BadArguments(
+                "no data file found in the location of the external table, can not infer the schema",
+            ));
+        };
+
+        // Assume all parquet files have the same schema, if not, throw error during reading.
+        let first_meta = read_metadata_async(&first.path, &op, Some(first.size)).await?;
+        let arrow_schema = infer_schema_with_extension(first_meta.file_metadata())?;
+        let file_schema = arrow_to_table_schema(&arrow_schema)?;
+
+        let paths = files.iter().map(|f| f.path.as_str()).collect::<Vec<_>>();
+        let mut fields = file_schema.fields().clone();
+        let mut partition_columns = vec![];
+        for field in infer_partition_fields(&paths)? {
+            // The column is stored in the files, no need to get it from the directories.
+            if file_schema.field_with_name(field.name()).is_ok() {
+                continue;
+            }
+            partition_columns.push(field.name().clone());
+            fields.push(field);
+        }
+
+        let meta = ExternalTableMeta { partition_columns };
+        let meta = serde_json::to_string(&meta).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("fail to serialize ExternalTableMeta: {e:?}"))
+        })?;
+        Ok((TableSchema::new(fields), meta))
+    }
+
+    pub fn do_read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let parts_len = plan.parts.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(parts_len, max_threads);
+
+        let table_schema = self.schema();
+        let non_partition_fields = table_schema
+            .fields()
+            .iter()
+            .filter(|field| !self.meta.partition_columns.contains(&field.name))
+            .cloned()
+            .collect();
+        let table_schema = Arc::new(TableSchema::new(non_partition_fields));
+
+        let arrow_schema = table_schema.as_ref().into();
+        let leaf_fields = Arc::new(table_schema.leaf_fields());
+
+        let mut read_options = ParquetReadOptions::default();
+
+        if !ctx.get_settings().get_enable_parquet_page_index()? {
+            read_options = read_options.with_prune_pages(false);
+        }
+
+        if !ctx.get_settings().get_enable_parquet_rowgroup_pruning()? {
+            read_options = read_options.with_prune_row_groups(false);
+        }
+
+        if !ctx.get_settings().get_enable_parquet_prewhere()? {
+            read_options = read_options.with_do_prewhere(false);
+        }
+
+        let pruner = ParquetRSPruner::try_create(
+            ctx.get_function_context()?,
+            table_schema.clone(),
+            leaf_fields,
+            &plan.push_downs,
+            read_options,
+            self.meta.partition_columns.clone(),
+        )?;
+
+        let op = init_operator(self.get_storage_params()?)?;
+        let partition_field_indexes = self
+            .meta
+            .partition_columns
+            .iter()
+            .map(|name| self.info.meta.schema.index_of(name))
+            .collect::<Result<Vec<FieldIndex>>>()?;
+        let push_downs = plan
+            .push_downs
+            .as_ref()
+            .map(|p| get_pushdown_without_partition_columns(p.clone(), &partition_field_indexes))
+            .transpose()?;
+        let mut builder =
+            ParquetRSReaderBuilder::create(ctx.clone(), op, table_schema, arrow_schema)?
+                .with_options(read_options)
+                .with_push_downs(push_downs.as_ref())
+                .with_pruner(Some(pruner))
+                .with_partition_columns(self.meta.partition_columns.clone());
+
+        let parquet_reader = Arc::new(builder.build_full_reader()?);
+
+        let output_schema = Arc::new(DataSchema::from(plan.schema()));
+        let partition_fields = self.get_partition_fields()?;
+        pipeline.add_source(
+            |output| {
+                ExternalTableSource::create(
+                    ctx.clone(),
+                    output,
+                    output_schema.clone(),
+                    parquet_reader.clone(),
+                    partition_fields.clone(),
+                )
+            },
+            max_threads.max(1),
+        )
+    }
+
+    #[async_backtrace::framed]
+    async fn do_read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let op = init_operator(self.get_storage_params()?)?;
+        let thread_num = ctx.get_settings().get_max_threads()? as usize;
+        let files = Self::list_files(&op, thread_num).await?;
+        let total_files = files.len();
+
+        let partition_fields = self.get_partition_fields()?;
+        // Prune the partition directories by the filter, the other columns have no statistics,
+        // so they never prune a file.
+        let filter = push_downs.as_ref().and_then(|p| p.filters.as_ref());
+        let pruner = match filter {
+            Some(filter) if !partition_fields.is_empty() => {
+                let filter_expr = filter.filter.as_expr(&BUILTIN_FUNCTIONS);
+                Some(RangePrunerCreator::try_create(
+                    ctx.get_function_context()?,
+                    &self.schema(),
+                    Some(&filter_expr),
+                )?)
+            }
+            _ => None,
+        };
+
+        let mut read_bytes = 0;
+        let mut parts = Vec::with_capacity(files.len());
+        for file in files {
+            let partition_values = get_partition_values(&file.path, &partition_fields)?;
+            if let Some(pruner) = &pruner {
+                let values = partition_fields
+                    .iter()
+                    .map(|f| f.name().clone())
+                    .zip(partition_values.iter().cloned())
+                    .collect::<HashMap<_, _>>();
+                if !pruner.should_keep_with_partition_columns(
+                    &StatisticsOfColumns::default(),
+                    Some(&values),
+                ) {
+                    continue;
+                }
+            }
+
+            read_bytes += file.size as usize;
+            parts.push(Arc::new(Box::new(ExternalPartInfo {
+                partition_values,
+                data: ParquetPart::ParquetFiles(ParquetFilesPart {
+                    files: vec![(file.path, file.size)],
+                    estimated_uncompressed_size: file.size, // This field is not used here.
+                }),
+            }) as Box<dyn PartInfo>));
+        }
+
+        // The number of rows is unknown before reading the footers of the files.
+        Ok((
+            PartStatistics::new_estimated(None, parts.len(), read_bytes, parts.len(), total_files),
+            Partitions::create(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+}
+
+#[async_trait]
+impl Table for ExternalTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+
+    fn name(&self) -> &str {
+        &self.get_table_info().name
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.do_read_partitions(ctx, push_downs).await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        self.do_read_data(ctx, plan, pipeline)
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        None
+    }
+
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
+    fn support_prewhere(&self) -> bool {
+        true
+    }
+}
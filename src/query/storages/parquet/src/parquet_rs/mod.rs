@@ -13,9 +13,11 @@
 // limitations under the License.
 
 mod copy_into_table;
+mod external_table;
 mod parquet_reader;
 mod parquet_table;
 mod partition;
+mod partition_columns;
 mod pruning;
 mod source;
 mod statistics;
@@ -24,6 +26,7 @@ mod meta;
 mod schema;
 
 pub use copy_into_table::ParquetTableForCopy;
+pub use external_table::ExternalTable;
 pub use meta::read_metas_in_parallel_for_copy;
 pub use meta::read_parquet_metas_batch;
 pub use parquet_reader::InMemoryRowGroup;
@@ -33,4 +36,5 @@ pub use parquet_reader::ParquetRSReaderBuilder;
 pub use parquet_reader::ParquetRSRowGroupReader;
 pub use parquet_table::ParquetRSTable;
 pub use partition::ParquetRSRowGroupPart;
+pub use partition_columns::get_pushdown_without_partition_columns;
 pub use pruning::ParquetRSPruner;
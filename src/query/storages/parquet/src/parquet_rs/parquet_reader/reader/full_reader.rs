@@ -74,7 +74,7 @@ pub struct ParquetRSFullReader {
 }
 
 impl ParquetRSFullReader {
-    // partition_fields is only used for delta table engine.
+    // partition_fields is only used for delta and external table engines.
     pub async fn prepare_data_stream(
         &self,
         loc: &str,
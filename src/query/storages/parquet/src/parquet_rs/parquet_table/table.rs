@@ -40,7 +40,6 @@ use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_pipeline_core::Pipeline;
-use databend_common_settings::Settings;
 use databend_common_storage::init_stage_operator;
 use databend_common_storage::parquet_rs::infer_schema_with_extension;
 use databend_common_storage::parquet_rs::read_metadata_async;
@@ -86,8 +85,6 @@ pub struct ParquetRSTable {
     /// As `paruqet_metas` will not be accessed by two threads simultaneously, use [`Mutex`] will not bring to much performance overhead.
     pub(super) parquet_metas: Arc<Mutex<Vec<Arc<FullParquetMeta>>>>,
     pub(super) need_stats_provider: bool,
-    pub(super) max_threads: usize,
-    pub(super) max_memory_usage: u64,
 }
 
 impl ParquetRSTable {
@@ -108,8 +105,6 @@ impl ParquetRSTable {
             compression_ratio: info.compression_ratio,
             parquet_metas: info.parquet_metas.clone(),
             need_stats_provider: info.need_stats_provider,
-            max_threads: info.max_threads,
-            max_memory_usage: info.max_memory_usage,
         }))
     }
 
@@ -119,7 +114,6 @@ impl ParquetRSTable {
         files_info: StageFilesInfo,
         read_options: ParquetReadOptions,
         files_to_read: Option<Vec<StageFileInfo>>,
-        settings: Arc<Settings>,
         query_kind: QueryKind,
     ) -> Result<Arc<dyn Table>> {
         let operator = init_stage_operator(&stage_info)?;
@@ -140,8 +134,6 @@ impl ParquetRSTable {
             query_kind,
             QueryKind::CopyIntoTable | QueryKind::CopyIntoLocation
         );
-        let max_threads = settings.get_max_threads()? as usize;
-        let max_memory_usage = settings.get_max_memory_usage()?;
 
         Ok(Arc::new(ParquetRSTable {
             table_info,
@@ -157,8 +149,6 @@ impl ParquetRSTable {
             schema_from: first_file,
             parquet_metas: Arc::new(Mutex::new(vec![])),
             need_stats_provider,
-            max_threads,
-            max_memory_usage,
         }))
     }
 
@@ -219,8 +209,6 @@ impl Table for ParquetRSTable {
             compression_ratio: self.compression_ratio,
             parquet_metas: self.parquet_metas.clone(),
             need_stats_provider: self.need_stats_provider,
-            max_threads: self.max_threads,
-            max_memory_usage: self.max_memory_usage,
         })
     }
 
@@ -258,7 +246,9 @@ impl Table for ParquetRSTable {
             return Ok(Box::new(DummyColumnStatisticsProvider));
         }
 
-        let thread_num = ctx.get_settings().get_max_threads()? as usize;
+        let settings = ctx.get_settings();
+        let thread_num = settings.get_max_threads()? as usize;
+        let max_memory_usage = settings.get_max_memory_usage()?;
 
         // This method can only be called once.
         // Unwrap safety: no other thread will hold this lock.
@@ -289,8 +279,8 @@ impl Table for ParquetRSTable {
             &file_locations, // The first file is already read.
             (self.schema_descr.clone(), self.schema_from.clone()),
             self.leaf_fields.clone(),
-            self.max_threads,
-            self.max_memory_usage,
+            thread_num,
+            max_memory_usage,
         )
         .await?;
         let elapsed = now.elapsed();
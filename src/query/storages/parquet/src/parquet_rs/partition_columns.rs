@@ -87,6 +87,8 @@ fn shift_projection(prj: Projection, partition_columns: &[FieldIndex]) -> Result
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use databend_common_catalog::plan::Projection;
 
     use super::shift_projection;
@@ -134,11 +136,11 @@ mod tests {
 
         assert_eq!(
             shift_projection(
-                Projection::InnerColumns(maplit::btreemap! {8 => vec![0, 1], 9 => vec![1, 2]}),
+                Projection::InnerColumns(BTreeMap::from([(8, vec![0, 1]), (9, vec![1, 2])])),
                 &[0, 2]
             )
             .unwrap(),
-            Projection::InnerColumns(maplit::btreemap! { 9 => vec![0, 2]})
+            Projection::InnerColumns(BTreeMap::from([(9, vec![0, 2])]))
         );
     }
 }
@@ -83,6 +83,20 @@ impl ResultCacheMetaManager {
         Ok(r)
     }
 
+    /// Remove all the result cache entries under `prefix`, returns the removed values.
+    #[async_backtrace::framed]
+    pub async fn remove_all(&self, prefix: &str) -> Result<Vec<ResultCacheValue>> {
+        let result = self.inner.prefix_list_kv(prefix).await?;
+
+        let mut r = vec![];
+        for (key, val) in result {
+            let _ = self.inner.upsert_kv(UpsertKV::delete(key)).await?;
+            r.push(serde_json::from_slice::<ResultCacheValue>(&val.data)?);
+        }
+
+        Ok(r)
+    }
+
     pub fn get_ttl(&self) -> u64 {
         self.ttl
     }
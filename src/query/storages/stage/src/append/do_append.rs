@@ -35,7 +35,13 @@ impl StageTable {
 
         let fmt = self.table_info.stage_info.file_format_params.clone();
         let mem_limit = settings.get_max_memory_usage()? as usize;
-        let max_threads = settings.get_max_threads()? as usize;
+        let mut max_threads = settings.get_max_threads()? as usize;
+        // Each writer writes at least one file, so the number of writers is capped by `MAX_FILES`,
+        // the writers share the remaining files when they roll to a new one.
+        let max_files = self.table_info.stage_info.copy_options.max_files;
+        if max_files != 0 {
+            max_threads = max_threads.min(max_files);
+        }
 
         let op = StageTable::get_op(&self.table_info.stage_info)?;
         let uuid = uuid::Uuid::new_v4().to_string();
@@ -0,0 +1,53 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+/// The files the writers of an unload can start in addition to the first file of each writer,
+/// shared by the writers to cap the number of unloaded files by `MAX_FILES`.
+///
+/// A writer which reaches the target file size only starts a new file if it can take one,
+/// otherwise it keeps appending to the current file.
+#[derive(Clone)]
+pub(super) struct ExtraFiles {
+    // None if the number of files is not capped.
+    remaining: Option<Arc<AtomicUsize>>,
+}
+
+impl ExtraFiles {
+    pub(super) fn create(max_files: usize, writers: usize) -> Self {
+        if max_files == 0 {
+            return Self { remaining: None };
+        }
+        let remaining = max_files.saturating_sub(writers);
+        Self {
+            remaining: Some(Arc::new(AtomicUsize::new(remaining))),
+        }
+    }
+
+    pub(super) fn is_capped(&self) -> bool {
+        self.remaining.is_some()
+    }
+
+    pub(super) fn try_take(&self) -> bool {
+        match &self.remaining {
+            None => true,
+            Some(remaining) => remaining
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok(),
+        }
+    }
+}
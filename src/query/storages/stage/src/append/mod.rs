@@ -13,9 +13,11 @@
 // limitations under the License.
 
 mod do_append;
+mod extra_files;
 mod output;
 mod parquet_file;
 mod path;
 mod row_based_file;
 
+pub use output::SumSummaryTransform;
 pub use output::UnloadOutput;
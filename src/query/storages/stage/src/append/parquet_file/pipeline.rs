@@ -18,10 +18,12 @@ use databend_common_pipeline_core::Pipeline;
 use opendal::Operator;
 
 use super::limit_file_size_processor::LimitFileSizeProcessor;
+use crate::append::extra_files::ExtraFiles;
 use super::writer_processor::ParquetFileWriter;
 
 /// - LimitFileSizeProcessor * 1: slice/group block to batches (as a block meta) to avoid files being too small when there are many threads.
-/// - ParquetFileSink * N:  serialize incoming blocks to Vec to reduce memory, and flush when they are large enough.
+/// - ParquetFileSink * N:  serialize incoming blocks to Vec to reduce memory, and flush when they are large enough,
+///   unless the number of files would exceed `MAX_FILES`.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn append_data_to_parquet_files(
     pipeline: &mut Pipeline,
@@ -53,6 +55,10 @@ pub(crate) fn append_data_to_parquet_files(
         pipeline.try_resize(max_threads)?;
         Some(max_file_size)
     };
+    let extra_files = ExtraFiles::create(
+        table_info.stage_info.copy_options.max_files,
+        pipeline.output_len(),
+    );
     pipeline.add_transform(|input, output| {
         let gid = group_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
         ParquetFileWriter::try_create(
@@ -63,6 +69,7 @@ pub(crate) fn append_data_to_parquet_files(
             uuid.clone(),
             gid,
             max_file_size,
+            extra_files.clone(),
         )
     })?;
     Ok(())
@@ -38,6 +38,7 @@ use parquet::file::properties::EnabledStatistics;
 use parquet::file::properties::WriterProperties;
 
 use super::block_batch::BlockBatch;
+use crate::append::extra_files::ExtraFiles;
 use crate::append::output::DataSummary;
 use crate::append::path::unload_path;
 use crate::append::UnloadOutput;
@@ -67,6 +68,7 @@ pub struct ParquetFileWriter {
     batch_id: usize,
 
     targe_file_size: Option<usize>,
+    extra_files: ExtraFiles,
 }
 
 const MAX_BUFFER_SIZE: usize = 64 * 1024 * 1024;
@@ -78,7 +80,7 @@ fn create_writer(
     targe_file_size: Option<usize>,
 ) -> Result<ArrowWriter<Vec<u8>>> {
     let props = WriterProperties::builder()
-        .set_compression(TableCompression::Zstd.into())
+        .set_compression(TableCompression::Zstd(None).into())
         .set_max_row_group_size(MAX_ROW_GROUP_SIZE)
         .set_encoding(Encoding::PLAIN)
         .set_dictionary_enabled(false)
@@ -103,6 +105,7 @@ impl ParquetFileWriter {
         uuid: String,
         group_id: usize,
         targe_file_size: Option<usize>,
+        extra_files: ExtraFiles,
     ) -> Result<ProcessorPtr> {
         let unload_output =
             UnloadOutput::create(table_info.stage_info.copy_options.detailed_output);
@@ -126,6 +129,7 @@ impl ParquetFileWriter {
             group_id,
             batch_id: 0,
             targe_file_size,
+            extra_files,
             row_counts: 0,
         })))
     }
@@ -224,7 +228,8 @@ impl Processor for ParquetFileWriter {
                     // in_progress row group: each column leaf has an at most 1MB uncompressed buffer and multi compressed pages
                     // may result in small file for schema with many columns
                     let in_progress = self.writer.in_progress_size();
-                    if file_size + in_progress >= target {
+                    // keep appending to the file if no more file can be started
+                    if file_size + in_progress >= target && self.extra_files.try_take() {
                         self.flush()?;
                         return Ok(());
                     }
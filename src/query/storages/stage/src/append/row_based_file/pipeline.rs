@@ -26,11 +26,13 @@ use opendal::Operator;
 use super::limit_file_size_processor::LimitFileSizeProcessor;
 use super::serialize_processor::SerializeProcessor;
 use super::writer_processor::RowBasedFileWriter;
+use crate::append::extra_files::ExtraFiles;
 use crate::compression::get_compression_alg_copy;
 
 /// SerializeProcessor * N: serialize each data block to many small byte buffers.
 /// LimitFileSizeProcessor * 1:  group small byte buffers to batches (as a block meta) that are large enough as a file.
-/// RowBasedFileSink * N: simply concat small byte buffers to a whole and write out.
+/// RowBasedFileSink * N: simply concat small byte buffers to a whole and write out,
+///   batches are appended to the previous file if the number of files would exceed `MAX_FILES`.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn append_data_to_row_based_files(
     pipeline: &mut Pipeline,
@@ -92,6 +94,10 @@ pub(crate) fn append_data_to_row_based_files(
     }
 
     let compression = get_compression_alg_copy(compression, "")?;
+    let extra_files = ExtraFiles::create(
+        table_info.stage_info.copy_options.max_files,
+        pipeline.output_len(),
+    );
 
     pipeline.add_transform(|input, output| {
         let gid = group_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
@@ -104,6 +110,7 @@ pub(crate) fn append_data_to_row_based_files(
             uuid.clone(),
             gid,
             compression,
+            extra_files.clone(),
         )
     })?;
     Ok(())
@@ -32,6 +32,7 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use opendal::Operator;
 
 use super::buffers::FileOutputBuffers;
+use crate::append::extra_files::ExtraFiles;
 use crate::append::output::DataSummary;
 use crate::append::path::unload_path;
 use crate::append::UnloadOutput;
@@ -43,6 +44,10 @@ pub struct RowBasedFileWriter {
 
     // always blocks for a whole file if not empty
     input_data: Option<DataBlock>,
+    // the data of the file in progress, the batches are appended to it
+    // when no more file can be started
+    file_data: Vec<u8>,
+    file_row_counts: usize,
     // always the data for a whole file if not empty
     file_to_write: Option<(Vec<u8>, DataSummary)>,
 
@@ -57,6 +62,7 @@ pub struct RowBasedFileWriter {
     batch_id: usize,
 
     compression: Option<CompressAlgorithm>,
+    extra_files: ExtraFiles,
 }
 
 impl RowBasedFileWriter {
@@ -69,6 +75,7 @@ impl RowBasedFileWriter {
         uuid: String,
         group_id: usize,
         compression: Option<CompressAlgorithm>,
+        extra_files: ExtraFiles,
     ) -> Result<ProcessorPtr> {
         let unload_output =
             UnloadOutput::create(table_info.stage_info.copy_options.detailed_output);
@@ -76,6 +83,8 @@ impl RowBasedFileWriter {
             table_info,
             input,
             input_data: None,
+            file_data: Vec::new(),
+            file_row_counts: 0,
             data_accessor,
             prefix,
             uuid,
@@ -83,11 +92,29 @@ impl RowBasedFileWriter {
             batch_id: 0,
             file_to_write: None,
             compression,
+            extra_files,
             output,
             unload_output,
             unload_output_blocks: None,
         })))
     }
+
+    fn finish_file(&mut self) -> Result<()> {
+        let mut output = mem::take(&mut self.file_data);
+        let row_counts = mem::take(&mut self.file_row_counts);
+        let input_bytes = output.len();
+        if let Some(compression) = self.compression {
+            output = CompressCodec::from(compression).compress_all(&output)?;
+        }
+        let output_bytes = output.len();
+        let summary = DataSummary {
+            row_counts,
+            input_bytes,
+            output_bytes,
+        };
+        self.file_to_write = Some((output, summary));
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -111,6 +138,9 @@ impl Processor for RowBasedFileWriter {
             self.input.set_not_need_data();
             Ok(Event::Sync)
         } else if self.input.is_finished() {
+            if !self.file_data.is_empty() {
+                return Ok(Event::Sync);
+            }
             if self.unload_output.is_empty() {
                 self.output.finish();
                 return Ok(Event::Finished);
@@ -140,31 +170,31 @@ impl Processor for RowBasedFileWriter {
     }
 
     fn process(&mut self) -> Result<()> {
-        let block = self.input_data.take().unwrap();
+        let Some(block) = self.input_data.take() else {
+            // the input is finished, write out the file in progress
+            return self.finish_file();
+        };
         let block_meta = block.get_owned_meta().unwrap();
         let buffers = FileOutputBuffers::downcast_from(block_meta).unwrap();
-        let size = buffers
-            .buffers
-            .iter()
-            .map(|b| b.buffer.len())
-            .sum::<usize>();
-        let row_counts = buffers.buffers.iter().map(|b| b.row_counts).sum::<usize>();
-        let mut output = Vec::with_capacity(self.prefix.len() + size);
-        output.extend_from_slice(self.prefix.as_slice());
+        if !self.file_data.is_empty() && self.extra_files.try_take() {
+            self.finish_file()?;
+        }
+        if self.file_data.is_empty() {
+            let size = buffers
+                .buffers
+                .iter()
+                .map(|b| b.buffer.len())
+                .sum::<usize>();
+            self.file_data.reserve(self.prefix.len() + size);
+            self.file_data.extend_from_slice(self.prefix.as_slice());
+        }
         for b in buffers.buffers {
-            output.extend_from_slice(b.buffer.as_slice());
+            self.file_row_counts += b.row_counts;
+            self.file_data.extend_from_slice(b.buffer.as_slice());
         }
-        let input_bytes = output.len();
-        if let Some(compression) = self.compression {
-            output = CompressCodec::from(compression).compress_all(&output)?;
+        if !self.extra_files.is_capped() {
+            self.finish_file()?;
         }
-        let output_bytes = output.len();
-        let summary = DataSummary {
-            row_counts,
-            input_bytes,
-            output_bytes,
-        };
-        self.file_to_write = Some((output, summary));
         Ok(())
     }
 
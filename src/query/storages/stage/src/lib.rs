@@ -20,7 +20,15 @@
 
 mod append;
 mod compression;
+mod lifecycle;
 mod read;
 mod stage_table;
 
+pub use append::SumSummaryTransform;
+pub use lifecycle::purge_stage_by_lifecycle;
+pub use lifecycle::select_files_to_purge;
+pub use read::row_based::infer_avro_schema;
+pub use read::row_based::infer_ndjson_schema;
+pub use read::row_based::read_ndjson_sample;
+pub use read::row_based::NDJSON_OVERFLOW_COLUMN;
 pub use stage_table::StageTable;
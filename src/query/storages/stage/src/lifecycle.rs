@@ -0,0 +1,152 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::principal::StageLifecycle;
+use databend_common_storage::StageFileInfo;
+use databend_common_storage::StageFilesInfo;
+use log::info;
+
+use crate::StageTable;
+
+// s3 can remove at most 1k files in one request
+const REMOVE_BATCH: usize = 1000;
+
+/// Remove the files of an internal stage which violate its lifecycle policy.
+///
+/// Returns the number of removed files.
+#[async_backtrace::framed]
+pub async fn purge_stage_by_lifecycle(stage_info: &StageInfo, now: DateTime<Utc>) -> Result<usize> {
+    if !stage_info.lifecycle.is_enabled() {
+        return Ok(0);
+    }
+
+    let op = StageTable::get_op(stage_info)?;
+    let files_info = StageFilesInfo {
+        path: "/".to_string(),
+        files: None,
+        pattern: None,
+    };
+    let files = files_info.list(&op, 1, None).await?;
+    let to_purge = select_files_to_purge(&stage_info.lifecycle, files, now);
+
+    for chunk in to_purge.chunks(REMOVE_BATCH) {
+        op.remove(chunk.to_vec()).await?;
+    }
+
+    if !to_purge.is_empty() {
+        info!(
+            "purged {} files from stage {} by lifecycle {}",
+            to_purge.len(),
+            stage_info.stage_name,
+            stage_info.lifecycle
+        );
+    }
+    Ok(to_purge.len())
+}
+
+/// Files older than `purge_after_days` are expired, then the oldest of the rest are evicted
+/// until their total size is within `max_total_size`.
+pub fn select_files_to_purge(
+    lifecycle: &StageLifecycle,
+    mut files: Vec<StageFileInfo>,
+    now: DateTime<Utc>,
+) -> Vec<String> {
+    files.sort_by(|a, b| a.last_modified.cmp(&b.last_modified));
+
+    let mut expired = 0;
+    if lifecycle.purge_after_days != 0 {
+        let deadline = now - Duration::days(lifecycle.purge_after_days as i64);
+        expired = files.partition_point(|file| file.last_modified < deadline);
+    }
+
+    let mut evicted = expired;
+    if lifecycle.max_total_size != 0 {
+        let mut total_size: u64 = files[expired..].iter().map(|file| file.size).sum();
+        while total_size > lifecycle.max_total_size && evicted < files.len() {
+            total_size -= files[evicted].size;
+            evicted += 1;
+        }
+    }
+
+    files.truncate(evicted);
+    files.into_iter().map(|file| file.path).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use chrono::TimeZone;
+    use chrono::Utc;
+    use databend_common_meta_app::principal::StageLifecycle;
+    use databend_common_storage::StageFileInfo;
+    use databend_common_storage::StageFileStatus;
+
+    use super::select_files_to_purge;
+
+    fn file(path: &str, size: u64, days_ago: i64) -> StageFileInfo {
+        let now = Utc.with_ymd_and_hms(2024, 8, 1, 0, 0, 0).unwrap();
+        StageFileInfo {
+            path: path.to_string(),
+            size,
+            md5: None,
+            last_modified: now - Duration::days(days_ago),
+            etag: None,
+            status: StageFileStatus::NeedCopy,
+            creator: None,
+        }
+    }
+
+    #[test]
+    fn test_select_files_to_purge() {
+        let now = Utc.with_ymd_and_hms(2024, 8, 1, 0, 0, 0).unwrap();
+        let files = vec![
+            file("c", 10, 1),
+            file("a", 10, 10),
+            file("b", 10, 5),
+            file("d", 10, 0),
+        ];
+
+        let lifecycle = StageLifecycle::default();
+        assert!(select_files_to_purge(&lifecycle, files.clone(), now).is_empty());
+
+        let lifecycle = StageLifecycle {
+            purge_after_days: 3,
+            max_total_size: 0,
+        };
+        assert_eq!(select_files_to_purge(&lifecycle, files.clone(), now), vec![
+            "a", "b"
+        ]);
+
+        let lifecycle = StageLifecycle {
+            purge_after_days: 0,
+            max_total_size: 25,
+        };
+        assert_eq!(select_files_to_purge(&lifecycle, files.clone(), now), vec![
+            "a", "b"
+        ]);
+
+        let lifecycle = StageLifecycle {
+            purge_after_days: 7,
+            max_total_size: 15,
+        };
+        assert_eq!(select_files_to_purge(&lifecycle, files, now), vec![
+            "a", "b", "c"
+        ]);
+    }
+}
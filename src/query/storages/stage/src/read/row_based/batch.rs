@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::intrinsics::unlikely;
+use std::sync::Arc;
 
 use databend_common_expression::BlockMetaInfo;
 use enum_as_inner::EnumAsInner;
@@ -51,6 +52,9 @@ pub struct BytesBatch {
     pub path: String,
     pub offset: usize,
     pub is_eof: bool,
+    /// The number of rows before this batch in the file, only set when
+    /// the batch starts at a row boundary and can be separated on its own.
+    pub start_row: Option<usize>,
 }
 
 impl BytesBatch {
@@ -60,6 +64,7 @@ impl BytesBatch {
             path: self.path.clone(),
             offset: self.offset,
             is_eof: self.is_eof,
+            start_row: self.start_row,
         }
     }
 }
@@ -91,6 +96,7 @@ impl RowBatchWithPosition {
 pub enum RowBatch {
     Csv(CSVRowBatch),
     NDJson(NdjsonRowBatch),
+    Avro(AvroRowBatch),
 }
 
 impl RowBatch {
@@ -98,6 +104,7 @@ impl RowBatch {
         match self {
             RowBatch::Csv(b) => b.rows(),
             RowBatch::NDJson(b) => b.rows(),
+            RowBatch::Avro(b) => b.rows(),
         }
     }
 
@@ -105,6 +112,7 @@ impl RowBatch {
         match self {
             RowBatch::Csv(b) => b.size(),
             RowBatch::NDJson(b) => b.size(),
+            RowBatch::Avro(b) => b.size(),
         }
     }
 }
@@ -131,6 +139,27 @@ pub struct NdjsonRowBatch {
     pub start: usize,
     pub row_ends: Vec<usize>,
 }
+/// Records already decoded from an Avro file, with the writer schema of the file.
+#[derive(Debug)]
+pub struct AvroRowBatch {
+    pub schema: Arc<apache_avro::Schema>,
+    pub records: Vec<apache_avro::types::Value>,
+}
+
+impl serde::Serialize for AvroRowBatch {
+    fn serialize<S>(&self, _: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        unimplemented!("Unimplemented serialize AvroRowBatch")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for AvroRowBatch {
+    fn deserialize<D>(_: D) -> Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        unimplemented!("Unimplemented deserialize AvroRowBatch")
+    }
+}
+
 pub struct NdJsonRowBatchIter<'a> {
     first_row: &'a [u8],
     data: &'a [u8],
@@ -199,6 +228,17 @@ impl NdjsonRowBatch {
     }
 }
 
+impl AvroRowBatch {
+    pub fn rows(&self) -> usize {
+        self.records.len()
+    }
+
+    // the records are decoded, the size of their encoded bytes is not tracked.
+    pub fn size(&self) -> usize {
+        0
+    }
+}
+
 impl CSVRowBatch {
     pub fn rows(&self) -> usize {
         self.row_ends.len()
@@ -0,0 +1,535 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use apache_avro::types::Value;
+use apache_avro::Schema;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use databend_common_exception::Result;
+use databend_common_expression::serialize::EPOCH_DAYS_FROM_CE;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_formats::FieldJsonAstDecoder;
+use databend_common_io::display_decimal_128;
+use databend_common_io::display_decimal_256;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_storage::FileParseError;
+use ethnum::i256;
+
+use crate::read::load_context::LoadContext;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::format::RowDecoder;
+use crate::read::row_based::formats::avro::format::AvroInputFormat;
+use crate::read::row_based::processors::BlockBuilderState;
+use crate::read::row_based::utils::truncate_column_data;
+
+/// Decodes Avro records into columns.
+///
+/// Values are converted to JSON by their Avro type (logical types included), and read with
+/// the same field decoder as NDJSON, except for bytes which are loaded as is into BINARY
+/// columns.
+pub struct AvroDecoder {
+    pub load_context: Arc<LoadContext>,
+    pub fmt: AvroInputFormat,
+    pub field_decoder: FieldJsonAstDecoder,
+}
+
+impl AvroDecoder {
+    pub fn create(fmt: AvroInputFormat, load_context: Arc<LoadContext>) -> Self {
+        let field_decoder = FieldJsonAstDecoder::create(&load_context.file_format_options_ext);
+        Self {
+            load_context,
+            fmt,
+            field_decoder,
+        }
+    }
+
+    /// The position of each column in the records of `schema`, None if the record has no such
+    /// field. Built once per batch instead of looking the names up for each record.
+    fn field_positions(&self, schema: &Schema) -> Vec<Option<usize>> {
+        let Schema::Record(record_schema) = schema else {
+            return vec![];
+        };
+        let case_sensitive = self.field_decoder.ident_case_sensitive;
+        let normalize = |name: &str| {
+            if case_sensitive {
+                name.to_string()
+            } else {
+                name.to_lowercase()
+            }
+        };
+        let mut positions = HashMap::with_capacity(record_schema.fields.len());
+        for (position, field) in record_schema.fields.iter().enumerate() {
+            positions.entry(normalize(&field.name)).or_insert(position);
+        }
+        self.load_context
+            .schema
+            .fields()
+            .iter()
+            .map(|field| positions.get(&normalize(field.name())).copied())
+            .collect()
+    }
+
+    fn read_record(
+        &self,
+        record: &Value,
+        schema: &Schema,
+        positions: &[Option<usize>],
+        columns: &mut [ColumnBuilder],
+        null_if: &[&str],
+    ) -> std::result::Result<(), FileParseError> {
+        if self.field_decoder.is_select {
+            let json = avro_to_json(record, Some(schema))
+                .map_err(|message| FileParseError::InvalidAvroRecord { message })?;
+            return self
+                .field_decoder
+                .read_field(&mut columns[0], &json)
+                .map_err(|e| FileParseError::InvalidAvroRecord {
+                    message: e.to_string(),
+                });
+        }
+
+        let (Value::Record(values), Schema::Record(record_schema)) = (record, schema) else {
+            return Err(FileParseError::InvalidAvroRecord {
+                message: format!("expect a record, but got {:?}", record),
+            });
+        };
+        for ((column_index, field), column) in self
+            .load_context
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .zip(columns.iter_mut())
+        {
+            let position = positions.get(column_index).copied().flatten();
+            let Some(position) = position.filter(|p| *p < values.len()) else {
+                match self.fmt.params.missing_field_as {
+                    NullAs::Error => {
+                        return Err(FileParseError::ColumnMissingError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                        });
+                    }
+                    NullAs::Null => {
+                        if field.is_nullable_or_null() {
+                            column.push_default();
+                        } else {
+                            return Err(FileParseError::ColumnMissingError {
+                                column_index,
+                                column_name: field.name().to_owned(),
+                                column_type: field.data_type.to_string(),
+                            });
+                        }
+                    }
+                    NullAs::FieldDefault => {
+                        self.load_context
+                            .push_default_value(column, column_index, false)?;
+                    }
+                }
+                continue;
+            };
+
+            let (value, value_schema) = unwrap_union(
+                &values[position].1,
+                Some(&record_schema.fields[position].schema),
+            );
+            let decode_error = |decode_error: String| FileParseError::ColumnDecodeError {
+                column_index,
+                column_name: field.name().to_owned(),
+                column_type: field.data_type.to_string(),
+                decode_error,
+                column_data: truncate_column_data(format!("{:?}", value)),
+            };
+            match value {
+                Value::Null => {
+                    if field.is_nullable_or_null() {
+                        column.push_default();
+                    } else {
+                        return Err(decode_error(
+                            "null value is not allowed for non-nullable field".to_owned(),
+                        ));
+                    }
+                }
+                Value::String(s)
+                    if matches!(column, ColumnBuilder::Nullable(_))
+                        && null_if.contains(&s.as_str()) =>
+                {
+                    column.push_default();
+                }
+                Value::Bytes(bytes) | Value::Fixed(_, bytes) if is_binary_column(column) => {
+                    push_binary(column, bytes);
+                }
+                _ => {
+                    let json = avro_to_json(value, value_schema).map_err(decode_error)?;
+                    self.field_decoder
+                        .read_field(column, &json)
+                        .map_err(|e| decode_error(e.to_string()))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RowDecoder for AvroDecoder {
+    fn add(
+        &self,
+        state: &mut BlockBuilderState,
+        batch: RowBatchWithPosition,
+    ) -> Result<Vec<DataBlock>> {
+        let columns = &mut state.mutable_columns;
+        let data = batch.data.into_avro().unwrap();
+        let null_if = self
+            .fmt
+            .params
+            .null_if
+            .iter()
+            .map(|x| x.as_str())
+            .collect::<Vec<_>>();
+        let positions = self.field_positions(&data.schema);
+
+        for (row_id, record) in data.records.iter().enumerate() {
+            if let Err(e) = self.read_record(record, &data.schema, &positions, columns, &null_if) {
+                self.load_context.error_handler.on_error(
+                    e,
+                    Some((columns, state.num_rows)),
+                    &mut state.file_status,
+                    &batch.start_pos.path,
+                    batch.start_pos.rows + row_id,
+                )?
+            } else {
+                state.num_rows += 1;
+                state.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(vec![])
+    }
+}
+
+fn is_binary_column(column: &ColumnBuilder) -> bool {
+    match column {
+        ColumnBuilder::Binary(_) => true,
+        ColumnBuilder::Nullable(nullable) => matches!(nullable.builder, ColumnBuilder::Binary(_)),
+        _ => false,
+    }
+}
+
+fn push_binary(column: &mut ColumnBuilder, bytes: &[u8]) {
+    match column {
+        ColumnBuilder::Binary(builder) => {
+            builder.put_slice(bytes);
+            builder.commit_row();
+        }
+        ColumnBuilder::Nullable(nullable) => {
+            push_binary(&mut nullable.builder, bytes);
+            nullable.validity.push(true);
+        }
+        _ => unreachable!("push_binary expects a binary column"),
+    }
+}
+
+fn unwrap_union<'a>(
+    value: &'a Value,
+    schema: Option<&'a Schema>,
+) -> (&'a Value, Option<&'a Schema>) {
+    match (value, schema) {
+        (Value::Union(index, value), Some(Schema::Union(union))) => {
+            unwrap_union(value, union.variants().get(*index as usize))
+        }
+        (Value::Union(_, value), _) => unwrap_union(value, None),
+        _ => (value, schema),
+    }
+}
+
+fn avro_to_json(
+    value: &Value,
+    schema: Option<&Schema>,
+) -> std::result::Result<serde_json::Value, String> {
+    let (value, schema) = unwrap_union(value, schema);
+    let json = match value {
+        Value::Null => serde_json::Value::Null,
+        Value::Boolean(v) => serde_json::Value::Bool(*v),
+        Value::Int(v) => serde_json::Value::from(*v),
+        Value::Long(v) => serde_json::Value::from(*v),
+        Value::Float(v) => serde_json::Value::from(f64::from(*v)),
+        Value::Double(v) => serde_json::Value::from(*v),
+        Value::Bytes(v) | Value::Fixed(_, v) => {
+            serde_json::Value::String(String::from_utf8_lossy(v).into_owned())
+        }
+        Value::String(v) | Value::Enum(_, v) => serde_json::Value::String(v.clone()),
+        Value::Uuid(v) => serde_json::Value::String(v.to_string()),
+        Value::Date(days) => {
+            let date = NaiveDate::from_num_days_from_ce_opt(EPOCH_DAYS_FROM_CE + days)
+                .ok_or_else(|| format!("invalid date {days}"))?;
+            serde_json::Value::String(date.format("%Y-%m-%d").to_string())
+        }
+        Value::TimeMillis(millis) => time_to_json(i64::from(*millis) * 1000)?,
+        Value::TimeMicros(micros) => time_to_json(*micros)?,
+        // timestamps are read as microseconds since epoch.
+        Value::TimestampMillis(millis) | Value::LocalTimestampMillis(millis) => {
+            serde_json::Value::from(millis.saturating_mul(1000))
+        }
+        Value::TimestampMicros(micros) | Value::LocalTimestampMicros(micros) => {
+            serde_json::Value::from(*micros)
+        }
+        Value::Decimal(decimal) => {
+            let Some(Schema::Decimal(decimal_schema)) = schema else {
+                return Err("missing the schema of decimal".to_string());
+            };
+            let bytes = Vec::<u8>::try_from(decimal).map_err(|e| e.to_string())?;
+            serde_json::Value::String(display_decimal(
+                &bytes,
+                decimal_schema.precision,
+                decimal_schema.scale,
+            ))
+        }
+        Value::Array(items) => {
+            let item_schema = match schema {
+                Some(Schema::Array(item)) => Some(item.as_ref()),
+                _ => None,
+            };
+            serde_json::Value::Array(
+                items
+                    .iter()
+                    .map(|v| avro_to_json(v, item_schema))
+                    .collect::<std::result::Result<Vec<_>, _>>()?,
+            )
+        }
+        Value::Map(entries) => {
+            let value_schema = match schema {
+                Some(Schema::Map(value)) => Some(value.as_ref()),
+                _ => None,
+            };
+            serde_json::Value::Object(
+                entries
+                    .iter()
+                    .map(|(k, v)| Ok((k.clone(), avro_to_json(v, value_schema)?)))
+                    .collect::<std::result::Result<_, String>>()?,
+            )
+        }
+        Value::Record(fields) => {
+            let field_schemas = match schema {
+                Some(Schema::Record(record)) => Some(&record.fields),
+                _ => None,
+            };
+            serde_json::Value::Object(
+                fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (k, v))| {
+                        let field_schema = field_schemas.and_then(|f| f.get(i)).map(|f| &f.schema);
+                        Ok((k.clone(), avro_to_json(v, field_schema)?))
+                    })
+                    .collect::<std::result::Result<_, String>>()?,
+            )
+        }
+        other => return Err(format!("unsupported Avro value {:?}", other)),
+    };
+    Ok(json)
+}
+
+/// Format the unscaled value of a decimal, a big-endian two's complement integer.
+///
+/// Decimals are read into DECIMAL(38) or DECIMAL(76) by their precision, larger ones into
+/// STRING, see `avro_to_table_type`.
+fn display_decimal(bytes: &[u8], precision: usize, scale: usize) -> String {
+    let bytes = trim_sign_extension(bytes);
+    let negative = bytes.first().map_or(false, |b| b & 0x80 != 0);
+    match (precision, scale) {
+        (0..=38, 0..=38) if bytes.len() <= 16 => {
+            let mut buf = if negative { [0xff; 16] } else { [0; 16] };
+            buf[16 - bytes.len()..].copy_from_slice(bytes);
+            display_decimal_128(i128::from_be_bytes(buf), scale as u8)
+        }
+        (0..=76, 0..=76) if bytes.len() <= 32 => {
+            let mut buf = if negative { [0xff; 32] } else { [0; 32] };
+            buf[32 - bytes.len()..].copy_from_slice(bytes);
+            display_decimal_256(i256::from_be_bytes(buf), scale as u8)
+        }
+        _ => display_big_decimal(bytes, negative, scale),
+    }
+}
+
+/// Remove the leading bytes which only extend the sign.
+fn trim_sign_extension(bytes: &[u8]) -> &[u8] {
+    let mut start = 0;
+    while start + 1 < bytes.len() {
+        let (byte, next_negative) = (bytes[start], bytes[start + 1] & 0x80 != 0);
+        if (byte == 0 && !next_negative) || (byte == 0xff && next_negative) {
+            start += 1;
+        } else {
+            break;
+        }
+    }
+    &bytes[start..]
+}
+
+fn display_big_decimal(bytes: &[u8], negative: bool, scale: usize) -> String {
+    let mut magnitude = bytes.to_vec();
+    if negative {
+        magnitude.iter_mut().for_each(|b| *b = !*b);
+        for b in magnitude.iter_mut().rev() {
+            let (v, overflow) = b.overflowing_add(1);
+            *b = v;
+            if !overflow {
+                break;
+            }
+        }
+    }
+    // the digits in reverse order, by repeated division of the magnitude by 10.
+    let mut digits = vec![];
+    while magnitude.iter().any(|b| *b != 0) {
+        let mut rem = 0u32;
+        for b in magnitude.iter_mut() {
+            let cur = (rem << 8) | u32::from(*b);
+            *b = (cur / 10) as u8;
+            rem = cur % 10;
+        }
+        digits.push(char::from(b'0' + rem as u8));
+    }
+    while digits.len() <= scale {
+        digits.push('0');
+    }
+    let mut result = String::with_capacity(digits.len() + 2);
+    if negative {
+        result.push('-');
+    }
+    for (i, digit) in digits.iter().rev().enumerate() {
+        if i == digits.len() - scale {
+            result.push('.');
+        }
+        result.push(*digit);
+    }
+    result
+}
+
+fn time_to_json(micros: i64) -> std::result::Result<serde_json::Value, String> {
+    let secs = micros.div_euclid(1_000_000) as u32;
+    let nanos = (micros.rem_euclid(1_000_000) * 1000) as u32;
+    let time = NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos)
+        .ok_or_else(|| format!("invalid time of day {micros}"))?;
+    Ok(serde_json::Value::String(
+        time.format("%H:%M:%S%.6f").to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use apache_avro::types::Value;
+    use apache_avro::Decimal;
+    use apache_avro::Schema;
+
+    use super::avro_to_json;
+    use super::display_decimal;
+
+    #[test]
+    fn test_avro_to_json() {
+        let schema = Schema::parse_str(
+            r#"{"type": "record", "name": "r", "fields": [
+                {"name": "a", "type": ["null", "int"]},
+                {"name": "d", "type": {"type": "int", "logicalType": "date"}},
+                {"name": "t", "type": {"type": "long", "logicalType": "timestamp-millis"}},
+                {"name": "tm", "type": {"type": "long", "logicalType": "time-micros"}},
+                {"name": "n", "type": {"type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2}},
+                {"name": "m", "type": {"type": "map", "values": "string"}},
+                {"name": "l", "type": {"type": "array", "items": "double"}}
+            ]}"#,
+        )
+        .unwrap();
+        let record = Value::Record(vec![
+            ("a".to_string(), Value::Union(1, Box::new(Value::Int(1)))),
+            ("d".to_string(), Value::Date(19723)),
+            ("t".to_string(), Value::TimestampMillis(1)),
+            ("tm".to_string(), Value::TimeMicros(3_723_000_001)),
+            (
+                "n".to_string(),
+                Value::Decimal(Decimal::from(vec![0xff, 0xcf, 0xc7])),
+            ),
+            (
+                "m".to_string(),
+                Value::Map(HashMap::from([(
+                    "k".to_string(),
+                    Value::String("v".to_string()),
+                )])),
+            ),
+            (
+                "l".to_string(),
+                Value::Array(vec![Value::Double(1.5), Value::Double(-2.0)]),
+            ),
+        ]);
+        assert_eq!(
+            avro_to_json(&record, Some(&schema)).unwrap(),
+            serde_json::json!({
+                "a": 1,
+                "d": "2024-01-01",
+                "t": 1000,
+                "tm": "01:02:03.000001",
+                "n": "-123.45",
+                "m": {"k": "v"},
+                "l": [1.5, -2.0],
+            })
+        );
+
+        // a null in a union
+        let schema = Schema::parse_str(r#"["null", "string"]"#).unwrap();
+        let value = Value::Union(0, Box::new(Value::Null));
+        assert_eq!(
+            avro_to_json(&value, Some(&schema)).unwrap(),
+            serde_json::Value::Null
+        );
+
+        // the scale of a decimal is in its schema
+        let value = Value::Decimal(Decimal::from(vec![0x01]));
+        assert!(avro_to_json(&value, None).is_err());
+    }
+
+    #[test]
+    fn test_display_decimal() {
+        assert_eq!(display_decimal(&[0x30, 0x39], 10, 2), "123.45");
+        assert_eq!(display_decimal(&[0xff, 0xff, 0xff, 0x85], 10, 2), "-1.23");
+        assert_eq!(display_decimal(&[0x00], 10, 2), "0.00");
+        assert_eq!(display_decimal(&[0x01], 5, 0), "1");
+
+        // DECIMAL(76)
+        assert_eq!(display_decimal(&[0x01], 40, 2), "0.01");
+        let bytes = [
+            0x00, 0x4b, 0x3b, 0x4c, 0xa8, 0x5a, 0x86, 0xc4, 0x7a, 0x09, 0x8a, 0x22, 0x40, 0x00,
+            0x00, 0x00, 0x01,
+        ];
+        assert_eq!(
+            display_decimal(&bytes, 40, 2),
+            "1000000000000000000000000000000000000.01"
+        );
+
+        // larger than DECIMAL(76), kept as string
+        let bytes = [
+            0xf7, 0x5d, 0x24, 0x0e, 0xbd, 0x20, 0x33, 0x85, 0x49, 0x1c, 0xa9, 0x6c, 0xd9, 0x38,
+            0x7b, 0xcc, 0x8d, 0x56, 0x0b, 0x2d, 0xaf, 0xa1, 0xc5, 0xc0, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        assert_eq!(
+            display_decimal(&bytes, 80, 2),
+            format!("-1{}.00", "0".repeat(76))
+        );
+        assert_eq!(display_decimal(&[0x00], 80, 2), "0.00");
+        assert_eq!(display_decimal(&[0x80], 80, 0), "-128");
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use apache_avro::Reader;
+use apache_avro::Schema;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+
+/// Infer a schema from the header of an Avro object container file.
+///
+/// The writer schema of the file must be a record, each of its fields becomes a
+/// column. `data` only needs to contain the header of the file.
+pub fn infer_avro_schema(data: &[u8]) -> Result<TableSchema> {
+    let reader = Reader::new(data)
+        .map_err(|e| ErrorCode::BadBytes(format!("fail to read Avro file header: {e}")))?;
+    let Schema::Record(record) = reader.writer_schema() else {
+        return Err(ErrorCode::BadBytes(format!(
+            "the schema of Avro file must be a record, but got {:?}",
+            reader.writer_schema()
+        )));
+    };
+    let mut fields = Vec::with_capacity(record.fields.len());
+    for field in record.fields.iter() {
+        let data_type = avro_to_table_type(&field.schema)?;
+        fields.push(TableField::new(&field.name, data_type));
+    }
+    Ok(TableSchema::new(fields))
+}
+
+/// Map an Avro schema to a Databend type, logical types are mapped to the type they stand for.
+///
+/// A union of `null` and another type becomes the nullable of that type, other unions and
+/// named references (which may be recursive) fall back to VARIANT.
+pub(super) fn avro_to_table_type(schema: &Schema) -> Result<TableDataType> {
+    let data_type = match schema {
+        Schema::Null => TableDataType::Null,
+        Schema::Boolean => TableDataType::Boolean,
+        Schema::Int => TableDataType::Number(NumberDataType::Int32),
+        Schema::Long => TableDataType::Number(NumberDataType::Int64),
+        Schema::Float => TableDataType::Number(NumberDataType::Float32),
+        Schema::Double => TableDataType::Number(NumberDataType::Float64),
+        Schema::Bytes | Schema::Fixed(_) => TableDataType::Binary,
+        Schema::String | Schema::Enum(_) | Schema::Uuid => TableDataType::String,
+        // Databend has no TIME type, times of day are loaded as 'HH:MM:SS.ffffff'.
+        Schema::TimeMillis | Schema::TimeMicros => TableDataType::String,
+        Schema::Date => TableDataType::Date,
+        Schema::TimestampMillis
+        | Schema::TimestampMicros
+        | Schema::LocalTimestampMillis
+        | Schema::LocalTimestampMicros => TableDataType::Timestamp,
+        Schema::Decimal(decimal) => {
+            let size = DecimalSize {
+                precision: decimal.precision.min(u8::MAX as usize) as u8,
+                scale: decimal.scale.min(u8::MAX as usize) as u8,
+            };
+            match DecimalDataType::from_size(size) {
+                Ok(decimal) => TableDataType::Decimal(decimal),
+                // too large for DECIMAL, keep the digits as string.
+                Err(_) => TableDataType::String,
+            }
+        }
+        Schema::Array(item) => TableDataType::Array(Box::new(avro_to_table_type(item)?)),
+        Schema::Map(value) => TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![TableDataType::String, avro_to_table_type(value)?],
+        })),
+        Schema::Record(record) => TableDataType::Tuple {
+            fields_name: record.fields.iter().map(|f| f.name.clone()).collect(),
+            fields_type: record
+                .fields
+                .iter()
+                .map(|f| avro_to_table_type(&f.schema))
+                .collect::<Result<Vec<_>>>()?,
+        },
+        Schema::Union(union) => {
+            let variants = union
+                .variants()
+                .iter()
+                .filter(|s| !matches!(s, Schema::Null))
+                .collect::<Vec<_>>();
+            let data_type = match variants.as_slice() {
+                [] => return Ok(TableDataType::Null),
+                [variant] => avro_to_table_type(variant)?,
+                _ => TableDataType::Variant,
+            };
+            if union.is_nullable() {
+                data_type.wrap_nullable()
+            } else {
+                data_type
+            }
+        }
+        _ => TableDataType::Variant,
+    };
+    Ok(data_type)
+}
+
+#[cfg(test)]
+mod test {
+    use apache_avro::Schema;
+    use databend_common_expression::types::DecimalDataType;
+    use databend_common_expression::types::DecimalSize;
+    use databend_common_expression::types::NumberDataType;
+    use databend_common_expression::TableDataType;
+
+    use super::avro_to_table_type;
+
+    fn table_type(schema: &str) -> TableDataType {
+        avro_to_table_type(&Schema::parse_str(schema).unwrap()).unwrap()
+    }
+
+    fn decimal(precision: u8, scale: u8) -> String {
+        format!(
+            r#"{{"type": "bytes", "logicalType": "decimal", "precision": {precision}, "scale": {scale}}}"#
+        )
+    }
+
+    #[test]
+    fn test_avro_to_table_type() {
+        assert_eq!(
+            table_type(r#""long""#),
+            TableDataType::Number(NumberDataType::Int64)
+        );
+        assert_eq!(table_type(r#""bytes""#), TableDataType::Binary);
+        assert_eq!(
+            table_type(r#"{"type": "enum", "name": "e", "symbols": ["a", "b"]}"#),
+            TableDataType::String
+        );
+        assert_eq!(
+            table_type(r#"{"type": "int", "logicalType": "date"}"#),
+            TableDataType::Date
+        );
+        assert_eq!(
+            table_type(r#"{"type": "long", "logicalType": "timestamp-micros"}"#),
+            TableDataType::Timestamp
+        );
+        assert_eq!(
+            table_type(r#"{"type": "int", "logicalType": "time-millis"}"#),
+            TableDataType::String
+        );
+
+        // decimals by precision
+        assert_eq!(
+            table_type(&decimal(10, 2)),
+            TableDataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+                precision: 10,
+                scale: 2
+            }))
+        );
+        assert_eq!(
+            table_type(&decimal(40, 2)),
+            TableDataType::Decimal(DecimalDataType::Decimal256(DecimalSize {
+                precision: 40,
+                scale: 2
+            }))
+        );
+        assert_eq!(table_type(&decimal(80, 2)), TableDataType::String);
+
+        // unions
+        assert_eq!(
+            table_type(r#"["null", "string"]"#),
+            TableDataType::Nullable(Box::new(TableDataType::String))
+        );
+        assert_eq!(
+            table_type(r#"["null", "string", "long"]"#),
+            TableDataType::Nullable(Box::new(TableDataType::Variant))
+        );
+        assert_eq!(table_type(r#"["string", "long"]"#), TableDataType::Variant);
+
+        // nested types
+        assert_eq!(
+            table_type(r#"{"type": "array", "items": ["null", "int"]}"#),
+            TableDataType::Array(Box::new(TableDataType::Nullable(Box::new(
+                TableDataType::Number(NumberDataType::Int32)
+            ))))
+        );
+        assert_eq!(
+            table_type(r#"{"type": "map", "values": "double"}"#),
+            TableDataType::Map(Box::new(TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![
+                    TableDataType::String,
+                    TableDataType::Number(NumberDataType::Float64)
+                ],
+            }))
+        );
+        assert_eq!(
+            table_type(
+                r#"{"type": "record", "name": "r", "fields": [
+                    {"name": "a", "type": "boolean"},
+                    {"name": "b", "type": "float"}
+                ]}"#
+            ),
+            TableDataType::Tuple {
+                fields_name: vec!["a".to_string(), "b".to_string()],
+                fields_type: vec![
+                    TableDataType::Boolean,
+                    TableDataType::Number(NumberDataType::Float32)
+                ],
+            }
+        );
+    }
+}
@@ -0,0 +1,290 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use apache_avro::from_avro_datum;
+use apache_avro::types::Value;
+use apache_avro::Codec;
+use apache_avro::Reader;
+use apache_avro::Schema;
+use databend_common_exception::Result;
+use databend_common_storage::FileParseError;
+use databend_common_storage::FileStatus;
+
+use crate::read::load_context::LoadContext;
+use crate::read::row_based::batch::AvroRowBatch;
+use crate::read::row_based::batch::BytesBatch;
+use crate::read::row_based::batch::Position;
+use crate::read::row_based::batch::RowBatch;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::format::SeparatorState;
+
+const RECORDS_PER_BATCH: usize = 8192;
+const AVRO_MAGIC: &[u8] = b"Obj\x01";
+const SYNC_MARKER_SIZE: usize = 16;
+const CODEC_KEY: &[u8] = b"avro.codec";
+
+struct AvroHeader {
+    schema: Arc<Schema>,
+    codec: Codec,
+    sync_marker: Vec<u8>,
+}
+
+/// Splits an Avro object container file into its data blocks.
+///
+/// The header (schema, codec and sync marker) is read first, then each data block is decoded
+/// as soon as it is complete, so only the block being received is buffered.
+pub struct AvroRowSeparator {
+    load_ctx: Arc<LoadContext>,
+    buffer: Vec<u8>,
+    header: Option<AvroHeader>,
+    records: Vec<Value>,
+    pos: Position,
+    // set once an error leaves the rest of the file undecodable.
+    failed: bool,
+}
+
+impl SeparatorState for AvroRowSeparator {
+    fn append(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        let mut file_status = FileStatus::default();
+        let mut batches = vec![];
+        if !self.failed {
+            self.buffer.extend_from_slice(&batch.data);
+            self.decode_blocks(&mut batches, &mut file_status)?;
+        }
+        if batch.is_eof {
+            if !self.failed && !self.buffer.is_empty() {
+                self.fail("unexpected end of file".to_string(), &mut file_status)?;
+            }
+            self.flush(&mut batches);
+        }
+        Ok((batches, file_status))
+    }
+}
+
+impl AvroRowSeparator {
+    pub fn try_create(load_ctx: Arc<LoadContext>, path: &str) -> Result<Self> {
+        Ok(Self {
+            load_ctx,
+            buffer: vec![],
+            header: None,
+            records: Vec::with_capacity(RECORDS_PER_BATCH),
+            pos: Position::new(path.to_string()),
+            failed: false,
+        })
+    }
+
+    /// Decode the complete data blocks in the buffer, the incomplete tail is kept.
+    fn decode_blocks(
+        &mut self,
+        batches: &mut Vec<RowBatchWithPosition>,
+        file_status: &mut FileStatus,
+    ) -> Result<()> {
+        if self.header.is_none() {
+            match read_header(&self.buffer) {
+                Ok(Some((header_len, header))) => {
+                    self.buffer.drain(..header_len);
+                    self.header = Some(header);
+                }
+                Ok(None) => return Ok(()),
+                Err(message) => return self.fail(message, file_status),
+            }
+        }
+        let Some(header) = &self.header else {
+            return Ok(());
+        };
+        let schema = header.schema.clone();
+        let codec = header.codec;
+        let sync_marker = header.sync_marker.clone();
+
+        let mut offset = 0;
+        loop {
+            let (count, data_start, data_end) = match read_block(&self.buffer, offset) {
+                Ok(Some(block)) => block,
+                Ok(None) => break,
+                Err(message) => return self.fail(message, file_status),
+            };
+            if self.buffer[data_end..data_end + SYNC_MARKER_SIZE] != sync_marker[..] {
+                let message = "sync marker does not match the header".to_string();
+                return self.fail(message, file_status);
+            }
+            let mut data = self.buffer[data_start..data_end].to_vec();
+            offset = data_end + SYNC_MARKER_SIZE;
+            if let Err(e) = codec.decompress(&mut data) {
+                self.on_error(e.to_string(), file_status)?;
+                continue;
+            }
+
+            let mut reader = data.as_slice();
+            for _ in 0..count {
+                match from_avro_datum(&schema, &mut reader, None) {
+                    Ok(record) => {
+                        self.records.push(record);
+                        if self.records.len() == RECORDS_PER_BATCH {
+                            self.flush(batches);
+                        }
+                    }
+                    Err(e) => {
+                        // the rest of the block can not be located, skip to the next block.
+                        self.on_error(e.to_string(), file_status)?;
+                        break;
+                    }
+                }
+            }
+        }
+        self.buffer.drain(..offset);
+        Ok(())
+    }
+
+    fn flush(&mut self, batches: &mut Vec<RowBatchWithPosition>) {
+        let Some(header) = &self.header else {
+            return;
+        };
+        if self.records.is_empty() {
+            return;
+        }
+        let records = std::mem::replace(&mut self.records, Vec::with_capacity(RECORDS_PER_BATCH));
+        let start_pos = self.pos.clone();
+        self.pos.rows += records.len();
+        batches.push(RowBatchWithPosition::new(
+            RowBatch::Avro(AvroRowBatch {
+                schema: header.schema.clone(),
+                records,
+            }),
+            start_pos,
+        ));
+    }
+
+    /// The rest of the file can not be decoded after this error.
+    fn fail(&mut self, message: String, file_status: &mut FileStatus) -> Result<()> {
+        self.failed = true;
+        self.buffer = vec![];
+        self.on_error(message, file_status)
+    }
+
+    fn on_error(&self, message: String, file_status: &mut FileStatus) -> Result<()> {
+        self.load_ctx.error_handler.on_error(
+            FileParseError::InvalidAvroRecord { message },
+            None,
+            file_status,
+            &self.pos.path,
+            self.pos.rows + self.records.len(),
+        )
+    }
+}
+
+/// Read a zigzag encoded long at `offset`, returns None if the buffer ends before it.
+fn read_long(buf: &[u8], offset: &mut usize) -> std::result::Result<Option<i64>, String> {
+    let mut value = 0u64;
+    for i in 0..10 {
+        let Some(byte) = buf.get(*offset + i) else {
+            return Ok(None);
+        };
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            *offset += i + 1;
+            return Ok(Some((value >> 1) as i64 ^ -((value & 1) as i64)));
+        }
+    }
+    Err("invalid variable-length integer".to_string())
+}
+
+fn read_len(buf: &[u8], offset: &mut usize) -> std::result::Result<Option<usize>, String> {
+    match read_long(buf, offset)? {
+        Some(len) if len < 0 => Err(format!("invalid length {len}")),
+        len => Ok(len.map(|len| len as usize)),
+    }
+}
+
+/// Read the header of the file, returns its length and content, or None if it is incomplete.
+///
+/// The header is the magic, the metadata map and the sync marker.
+fn read_header(buf: &[u8]) -> std::result::Result<Option<(usize, AvroHeader)>, String> {
+    if buf.len() < AVRO_MAGIC.len() {
+        return Ok(None);
+    }
+    if &buf[..AVRO_MAGIC.len()] != AVRO_MAGIC {
+        return Err("not an Avro object container file".to_string());
+    }
+    let mut offset = AVRO_MAGIC.len();
+    let mut codec = None;
+    loop {
+        let Some(count) = read_long(buf, &mut offset)? else {
+            return Ok(None);
+        };
+        if count == 0 {
+            break;
+        }
+        if count < 0 {
+            // a negative count is followed by the size of the map block in bytes.
+            if read_long(buf, &mut offset)?.is_none() {
+                return Ok(None);
+            }
+        }
+        for _ in 0..count.unsigned_abs() {
+            let mut entry = [0..0, 0..0];
+            for range in entry.iter_mut() {
+                let Some(len) = read_len(buf, &mut offset)? else {
+                    return Ok(None);
+                };
+                *range = offset..offset + len;
+                offset += len;
+            }
+            let [key, value] = entry;
+            if offset > buf.len() {
+                return Ok(None);
+            }
+            if &buf[key] == CODEC_KEY {
+                codec = Some(String::from_utf8_lossy(&buf[value]).into_owned());
+            }
+        }
+    }
+    let header_len = offset + SYNC_MARKER_SIZE;
+    if header_len > buf.len() {
+        return Ok(None);
+    }
+
+    let reader = Reader::new(&buf[..header_len]).map_err(|e| e.to_string())?;
+    let codec = match codec {
+        Some(codec) => Codec::from_str(&codec).map_err(|_| format!("unknown codec {codec}"))?,
+        None => Codec::Null,
+    };
+    let header = AvroHeader {
+        schema: Arc::new(reader.writer_schema().clone()),
+        codec,
+        sync_marker: buf[offset..header_len].to_vec(),
+    };
+    Ok(Some((header_len, header)))
+}
+
+/// Read the data block at `offset`, returns the count of records, the range of the data and
+/// the offset of the sync marker, or None if the block is incomplete.
+fn read_block(
+    buf: &[u8],
+    mut offset: usize,
+) -> std::result::Result<Option<(usize, usize, usize)>, String> {
+    let Some(count) = read_len(buf, &mut offset)? else {
+        return Ok(None);
+    };
+    let Some(size) = read_len(buf, &mut offset)? else {
+        return Ok(None);
+    };
+    let data_end = offset + size;
+    if data_end + SYNC_MARKER_SIZE > buf.len() {
+        return Ok(None);
+    }
+    Ok(Some((count, offset, data_end)))
+}
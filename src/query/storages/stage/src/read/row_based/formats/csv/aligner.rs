@@ -0,0 +1,538 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_formats::RecordDelimiter;
+use databend_common_meta_app::principal::CsvFileFormatParams;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use log::debug;
+
+use crate::read::row_based::batch::BytesBatch;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ScanState {
+    RecordStart,
+    FieldStart,
+    InField,
+    InQuotedField,
+    InEscapedQuote,
+    QuoteInQuotedField,
+}
+
+/// Find the ends of records by the same rules as the `csv_core::Reader` used by `CsvReader`,
+/// without decoding the fields.
+struct RecordScanner {
+    delimiter: u8,
+    quote: u8,
+    escape: Option<u8>,
+    terminator: RecordDelimiter,
+    state: ScanState,
+}
+
+impl RecordScanner {
+    fn is_terminator(&self, c: u8) -> bool {
+        match self.terminator {
+            RecordDelimiter::Crlf => c == b'\r' || c == b'\n',
+            RecordDelimiter::Any(t) => c == t,
+        }
+    }
+
+    fn start_field(&self, c: u8) -> ScanState {
+        if c == self.quote {
+            ScanState::InQuotedField
+        } else {
+            self.in_field(c)
+        }
+    }
+
+    fn in_field(&self, c: u8) -> ScanState {
+        if c == self.delimiter {
+            ScanState::FieldStart
+        } else if self.is_terminator(c) {
+            ScanState::RecordStart
+        } else {
+            ScanState::InField
+        }
+    }
+
+    /// Return the state after `c`, and whether `c` ends a record.
+    fn step(&self, state: ScanState, c: u8) -> (ScanState, bool) {
+        let state = match state {
+            // empty lines are skipped, the same as csv_core
+            ScanState::RecordStart if self.is_terminator(c) => return (state, false),
+            ScanState::RecordStart | ScanState::FieldStart => self.start_field(c),
+            // a doubled quote is a quote in the field
+            ScanState::QuoteInQuotedField if c == self.quote => ScanState::InQuotedField,
+            ScanState::InField | ScanState::QuoteInQuotedField => self.in_field(c),
+            ScanState::InQuotedField => {
+                if c == self.quote {
+                    ScanState::QuoteInQuotedField
+                } else if Some(c) == self.escape {
+                    ScanState::InEscapedQuote
+                } else {
+                    ScanState::InQuotedField
+                }
+            }
+            ScanState::InEscapedQuote => ScanState::InQuotedField,
+        };
+        (state, state == ScanState::RecordStart)
+    }
+
+    /// Scan `data` from the current state, push the offsets right after the ends of records to
+    /// `ends`, `base` is the offset of `data`.
+    fn scan(&mut self, data: &[u8], base: usize, ends: &mut Vec<usize>) {
+        let mut state = self.state;
+        for (i, c) in data.iter().enumerate() {
+            let (next, is_end) = self.step(state, *c);
+            if is_end {
+                ends.push(base + i + 1);
+            }
+            state = next;
+        }
+        self.state = state;
+    }
+
+    /// Scan a chunk of which the starting state is unknown yet, by following all the states at
+    /// the same time.
+    ///
+    /// The paths starting from different states usually reach the same state after a few bytes,
+    /// e.g. the first record delimiter out of quotes, then they are merged and the rest of the
+    /// chunk is scanned only once.
+    fn speculative_scan(&self, data: &[u8], base: usize) -> SpeculativeScan {
+        let mut paths = SCAN_STATES
+            .iter()
+            .map(|state| ScanPath {
+                state: *state,
+                ends: vec![],
+                merged_into: None,
+            })
+            .collect::<Vec<_>>();
+        let mut alive = (0..paths.len()).collect::<Vec<_>>();
+
+        let mut pos = 0;
+        while alive.len() > 1 && pos < data.len() {
+            for p in alive.iter() {
+                let (next, is_end) = self.step(paths[*p].state, data[pos]);
+                if is_end {
+                    paths[*p].ends.push(base + pos + 1);
+                }
+                paths[*p].state = next;
+            }
+            pos += 1;
+
+            let mut i = 1;
+            while i < alive.len() {
+                let p = alive[i];
+                match alive[..i]
+                    .iter()
+                    .find(|q| paths[**q].state == paths[p].state)
+                {
+                    Some(q) => {
+                        paths[p].merged_into = Some((*q, paths[*q].ends.len()));
+                        alive.remove(i);
+                    }
+                    None => i += 1,
+                }
+            }
+        }
+
+        for p in alive {
+            let mut state = paths[p].state;
+            for (i, c) in data[pos..].iter().enumerate() {
+                let (next, is_end) = self.step(state, *c);
+                if is_end {
+                    paths[p].ends.push(base + pos + i + 1);
+                }
+                state = next;
+            }
+            paths[p].state = state;
+        }
+        SpeculativeScan { paths }
+    }
+}
+
+const SCAN_STATES: [ScanState; 6] = [
+    ScanState::RecordStart,
+    ScanState::FieldStart,
+    ScanState::InField,
+    ScanState::InQuotedField,
+    ScanState::InEscapedQuote,
+    ScanState::QuoteInQuotedField,
+];
+
+struct ScanPath {
+    // the state at the end of the chunk, or where the path is merged.
+    state: ScanState,
+    ends: Vec<usize>,
+    // (the path merged into, the number of its ends at that point)
+    merged_into: Option<(usize, usize)>,
+}
+
+/// The record ends of a chunk for each state the chunk may start with,
+/// `paths[i]` starts with `SCAN_STATES[i]`.
+struct SpeculativeScan {
+    paths: Vec<ScanPath>,
+}
+
+impl SpeculativeScan {
+    /// Push the record ends of the chunk starting with `state` to `ends`,
+    /// return the state at the end of the chunk.
+    fn resolve(&self, state: ScanState, ends: &mut Vec<usize>) -> ScanState {
+        let mut path = &self.paths[SCAN_STATES.iter().position(|s| *s == state).unwrap()];
+        let mut from = 0;
+        loop {
+            ends.extend_from_slice(&path.ends[from..]);
+            match path.merged_into {
+                Some((p, num_ends)) => {
+                    path = &self.paths[p];
+                    from = num_ends;
+                }
+                None => return path.state,
+            }
+        }
+    }
+}
+
+// new bytes are scanned in parallel if there are at least two chunks of this size.
+const MIN_PARALLEL_CHUNK_SIZE: usize = 1024 * 1024;
+
+struct FileState {
+    path: String,
+    // bytes not sent yet, starts at a record boundary.
+    data: Vec<u8>,
+    // the offset of `data` in the file.
+    offset: usize,
+    // the number of rows before `data`, including headers.
+    rows: usize,
+    rows_to_skip: usize,
+}
+
+/// Cut the bytes of each CSV file into batches that begin and end at record boundaries.
+///
+/// Quotes and escapes are tracked across the boundaries of the input batches, so a record
+/// is never split, even if it contains quoted record delimiters.
+/// Each output batch can be separated on its own, so that large files are parsed in parallel.
+///
+/// The new bytes of a large batch are cut into chunks at byte offsets, which are scanned on
+/// `num_threads` threads speculatively, then the scans are resolved in order from the state
+/// at the start of the first chunk.
+pub struct CsvAligner {
+    scanner: RecordScanner,
+    headers: usize,
+    file: Option<FileState>,
+    num_threads: usize,
+    min_chunk_size: usize,
+}
+
+impl CsvAligner {
+    pub fn try_create(params: &CsvFileFormatParams, num_threads: usize) -> Result<Self> {
+        let escape = if params.escape.is_empty() {
+            None
+        } else {
+            Some(params.escape.as_bytes()[0])
+        };
+        let scanner = RecordScanner {
+            delimiter: params.field_delimiter.as_bytes()[0],
+            quote: params.quote.as_bytes()[0],
+            escape,
+            terminator: params.record_delimiter.as_str().try_into()?,
+            state: ScanState::RecordStart,
+        };
+        Ok(Self {
+            scanner,
+            headers: params.headers as usize,
+            file: None,
+            num_threads,
+            min_chunk_size: MIN_PARALLEL_CHUNK_SIZE,
+        })
+    }
+
+    /// Scan `data` from the current state, return the offsets right after the ends of records.
+    fn scan(&mut self, data: &[u8], base: usize) -> Vec<usize> {
+        let mut ends = vec![];
+        let num_chunks = std::cmp::min(self.num_threads, data.len() / self.min_chunk_size);
+        if num_chunks < 2 {
+            self.scanner.scan(data, base, &mut ends);
+            return ends;
+        }
+
+        let chunk_size = data.len().div_ceil(num_chunks);
+        let scanner = &self.scanner;
+        let scans = std::thread::scope(|s| {
+            let handles = data
+                .chunks(chunk_size)
+                .enumerate()
+                .map(|(i, chunk)| {
+                    s.spawn(move || scanner.speculative_scan(chunk, base + i * chunk_size))
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        let mut state = self.scanner.state;
+        for scan in scans {
+            state = scan.resolve(state, &mut ends);
+        }
+        self.scanner.state = state;
+        ends
+    }
+
+    fn align(&mut self, batch: BytesBatch) -> Option<BytesBatch> {
+        let mut file = self.file.take().unwrap_or_else(|| FileState {
+            path: batch.path.clone(),
+            data: vec![],
+            offset: batch.offset,
+            rows: 0,
+            rows_to_skip: self.headers,
+        });
+
+        let scanned = file.data.len();
+        if file.data.is_empty() {
+            file.data = batch.data;
+        } else {
+            file.data.extend_from_slice(&batch.data);
+        }
+        let record_ends = self.scan(&file.data[scanned..], scanned);
+
+        // data[start..end] are the complete records to output
+        let mut start = 0;
+        let mut end = 0;
+        let mut num_rows = 0;
+        for record_end in record_ends {
+            if file.rows_to_skip > 0 {
+                file.rows_to_skip -= 1;
+                file.rows += 1;
+                start = record_end;
+            } else {
+                num_rows += 1;
+                end = record_end;
+            }
+        }
+        if batch.is_eof && file.rows_to_skip == 0 && self.scanner.state != ScanState::RecordStart {
+            // the last record without record delimiter, leave it to `CsvReader` to validate.
+            num_rows += 1;
+            end = file.data.len();
+        }
+
+        let output = if num_rows == 0 {
+            file.data.drain(..start);
+            file.offset += start;
+            None
+        } else {
+            let remain = file.data.split_off(end);
+            let mut data = std::mem::replace(&mut file.data, remain);
+            data.drain(..start);
+            debug!(
+                "csv aligner: {} bytes => {} rows + {} bytes remain",
+                data.len(),
+                num_rows,
+                file.data.len()
+            );
+            let aligned = BytesBatch {
+                data,
+                path: file.path.clone(),
+                offset: file.offset + start,
+                // each aligned batch is separated on its own.
+                is_eof: true,
+                start_row: Some(file.rows),
+            };
+            file.offset += end;
+            file.rows += num_rows;
+            Some(aligned)
+        };
+
+        if batch.is_eof {
+            self.scanner.state = ScanState::RecordStart;
+        } else {
+            self.file = Some(file);
+        }
+        output
+    }
+}
+
+impl AccumulatingTransform for CsvAligner {
+    const NAME: &'static str = "CsvAligner";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let batch = data
+            .get_owned_meta()
+            .and_then(BytesBatch::downcast_from)
+            .unwrap();
+        Ok(self
+            .align(batch)
+            .map(|b| DataBlock::empty_with_meta(Box::new(b)))
+            .into_iter()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // feed `inputs` as the batches of one file, return (data, offset, start_row) of the outputs.
+    fn align(
+        params: &CsvFileFormatParams,
+        inputs: &[&[u8]],
+    ) -> Result<Vec<(Vec<u8>, usize, Option<usize>)>> {
+        let aligner = CsvAligner::try_create(params, 1)?;
+        align_with(aligner, inputs)
+    }
+
+    fn align_with(
+        mut aligner: CsvAligner,
+        inputs: &[&[u8]],
+    ) -> Result<Vec<(Vec<u8>, usize, Option<usize>)>> {
+        let mut offset = 0;
+        let mut outputs = vec![];
+        for (i, data) in inputs.iter().enumerate() {
+            let batch = BytesBatch {
+                data: data.to_vec(),
+                path: "test".to_string(),
+                offset,
+                is_eof: i == inputs.len() - 1,
+                start_row: None,
+            };
+            offset += data.len();
+            if let Some(b) = aligner.align(batch) {
+                assert!(b.is_eof);
+                outputs.push((b.data, b.offset, b.start_row));
+            }
+        }
+        Ok(outputs)
+    }
+
+    #[test]
+    fn test_csv_aligner() -> Result<()> {
+        let params = CsvFileFormatParams::default();
+
+        assert_eq!(align(&params, &[b"1,a\n2,b\n3,", b"c\n4,d"])?, vec![
+            (b"1,a\n2,b\n".to_vec(), 0, Some(0)),
+            (b"3,c\n4,d".to_vec(), 8, Some(2)),
+        ]);
+
+        // record delimiters in quotes
+        assert_eq!(align(&params, &[b"1,\"a\n", b"b\"\n2,\"", b"\"\"\n\"\n"])?, vec![
+            (b"1,\"a\nb\"\n".to_vec(), 0, Some(0)),
+            (b"2,\"\"\"\n\"\n".to_vec(), 8, Some(1)),
+        ]);
+
+        // a quote in the middle of a field does not start quoting
+        assert_eq!(align(&params, &[b"1,a\"b\n2,c\n"])?, vec![(
+            b"1,a\"b\n2,c\n".to_vec(),
+            0,
+            Some(0)
+        )]);
+
+        // empty lines are not rows
+        assert_eq!(align(&params, &[b"1,a\n\n", b"\n2,b\n\n"])?, vec![
+            (b"1,a\n".to_vec(), 0, Some(0)),
+            (b"\n\n2,b\n".to_vec(), 4, Some(1)),
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_aligner_escape() -> Result<()> {
+        let params = CsvFileFormatParams {
+            escape: "\\".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(align(&params, &[b"1,\"a\\\"", b"\n\"\n2,b\n"])?, vec![(
+            b"1,\"a\\\"\n\"\n2,b\n".to_vec(),
+            0,
+            Some(0)
+        )]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_aligner_headers() -> Result<()> {
+        let params = CsvFileFormatParams {
+            headers: 1,
+            ..Default::default()
+        };
+        assert_eq!(align(&params, &[b"a,b", b"\n1,2\n", b"3,4\n"])?, vec![
+            (b"1,2\n".to_vec(), 4, Some(1)),
+            (b"3,4\n".to_vec(), 8, Some(2)),
+        ]);
+        assert_eq!(align(&params, &[b"a,b\n"])?, vec![]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_aligner_crlf() -> Result<()> {
+        let params = CsvFileFormatParams {
+            record_delimiter: "\r\n".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(align(&params, &[b"1,a\r", b"\n2,\"b\r\n\"\r\n"])?, vec![
+            (b"1,a\r".to_vec(), 0, Some(0)),
+            (b"\n2,\"b\r\n\"\r".to_vec(), 4, Some(1)),
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_aligner_parallel() -> Result<()> {
+        let cases: [(CsvFileFormatParams, &[u8]); 3] = [
+            (
+                CsvFileFormatParams::default(),
+                b"1,\"a\nb\"\n\n2,\"\"\"\n,\"\"\",c\n3,d\"e,\"f\n\"\n4,\"\"\n5,g",
+            ),
+            (
+                CsvFileFormatParams {
+                    escape: "\\".to_string(),
+                    ..Default::default()
+                },
+                b"1,\"a\\\"\n\",b\n2,\"\\\\\"\n3,\"\\\"\\\"\n\n\"\n",
+            ),
+            (
+                CsvFileFormatParams {
+                    headers: 1,
+                    record_delimiter: "\r\n".to_string(),
+                    ..Default::default()
+                },
+                b"a,b\r\n1,\"\r\n\"\r\n2,x\r\n\"3\"\"\",y\r\n",
+            ),
+        ];
+        for (params, data) in cases {
+            let expected = align(&params, &[data])?;
+            // cut into 2 to data.len() chunks, so the chunks start in every state.
+            for num_threads in 2..=data.len() {
+                let mut aligner = CsvAligner::try_create(&params, num_threads)?;
+                aligner.min_chunk_size = 1;
+                assert_eq!(align_with(aligner, &[data])?, expected);
+            }
+            // also across the input batches.
+            let (first, second) = data.split_at(data.len() / 2);
+            let mut aligner = CsvAligner::try_create(&params, 4)?;
+            aligner.min_chunk_size = 1;
+            assert_eq!(
+                concat_outputs(align_with(aligner, &[first, second])?),
+                concat_outputs(expected)
+            );
+        }
+        Ok(())
+    }
+
+    fn concat_outputs(outputs: Vec<(Vec<u8>, usize, Option<usize>)>) -> Vec<u8> {
+        outputs.into_iter().flat_map(|(data, ..)| data).collect()
+    }
+}
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod aligner;
 mod block_builder;
 mod format;
 mod separator;
 
+pub use aligner::CsvAligner;
 pub use format::CsvInputFormat;
@@ -196,6 +196,13 @@ impl CsvReader {
     }
 
     fn separate(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        if let Some(start_row) = batch.start_row {
+            // aligned by `CsvAligner`, the headers are already skipped
+            self.rows_to_skip = 0;
+            self.pos.rows = start_row;
+            self.pos.offset = batch.offset;
+        }
+
         // prepare for reading header and data
 
         let need_flush = batch.is_eof;
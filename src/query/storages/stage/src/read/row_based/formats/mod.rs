@@ -12,10 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod avro;
 mod csv;
 mod ndjson;
 mod tsv;
 
+pub use avro::infer_avro_schema;
+pub use avro::AvroInputFormat;
+pub use csv::CsvAligner;
 pub use csv::CsvInputFormat;
+pub use ndjson::infer_ndjson_schema;
+pub use ndjson::read_ndjson_sample;
 pub use ndjson::NdJsonInputFormat;
+pub use ndjson::NDJSON_OVERFLOW_COLUMN;
 pub use tsv::TsvInputFormat;
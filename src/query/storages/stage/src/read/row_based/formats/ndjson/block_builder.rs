@@ -26,6 +26,8 @@ use crate::read::load_context::LoadContext;
 use crate::read::row_based::batch::RowBatchWithPosition;
 use crate::read::row_based::format::RowDecoder;
 use crate::read::row_based::formats::ndjson::format::NdJsonInputFormat;
+use crate::read::row_based::formats::ndjson::schema::JsonFieldType;
+use crate::read::row_based::formats::ndjson::schema::NDJSON_OVERFLOW_COLUMN;
 use crate::read::row_based::processors::BlockBuilderState;
 use crate::read::row_based::utils::truncate_column_data;
 
@@ -53,7 +55,9 @@ impl NdJsonDecoder {
         let mut json: serde_json::Value =
             serde_json::from_reader(buf).map_err(|e| map_json_error(e, buf))?;
         // todo: this is temporary
-        if self.field_decoder.is_select {
+        if self.field_decoder.is_select && self.with_inferred_columns() {
+            self.read_inferred_columns(json, columns)?;
+        } else if self.field_decoder.is_select {
             self.field_decoder
                 .read_field(&mut columns[0], &json)
                 .map_err(|e| FileParseError::InvalidNDJsonRow {
@@ -153,6 +157,69 @@ impl NdJsonDecoder {
     }
 }
 
+impl NdJsonDecoder {
+    // The stage is queried with the columns inferred from sampled records, followed by the
+    // overflow column.
+    fn with_inferred_columns(&self) -> bool {
+        let fields = self.load_context.schema.fields();
+        fields.len() > 1 && fields[fields.len() - 1].name() == NDJSON_OVERFLOW_COLUMN
+    }
+
+    fn read_inferred_columns(
+        &self,
+        json: serde_json::Value,
+        columns: &mut [ColumnBuilder],
+    ) -> std::result::Result<(), FileParseError> {
+        let serde_json::Value::Object(mut record) = json else {
+            return Err(FileParseError::InvalidNDJsonRow {
+                message: "the record is not an object".to_string(),
+            });
+        };
+
+        let (overflow, columns) = columns.split_last_mut().unwrap();
+        for ((column_index, field), column) in self
+            .load_context
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .zip(columns.iter_mut())
+        {
+            let ty = JsonFieldType::of_data_type(field.data_type());
+            match record.remove(field.name()) {
+                None | Some(serde_json::Value::Null) => column.push_default(),
+                // values of another type than the sampled ones go to the overflow column
+                Some(value) if !ty.accepts(JsonFieldType::of(&value)) => {
+                    column.push_default();
+                    record.insert(field.name().to_owned(), value);
+                }
+                Some(value) => {
+                    self.field_decoder.read_field(column, &value).map_err(|e| {
+                        FileParseError::ColumnDecodeError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                            decode_error: e.to_string(),
+                            column_data: truncate_column_data(value.to_string()),
+                        }
+                    })?;
+                }
+            }
+        }
+
+        if record.is_empty() {
+            overflow.push_default();
+        } else {
+            self.field_decoder
+                .read_field(overflow, &serde_json::Value::Object(record))
+                .map_err(|e| FileParseError::InvalidNDJsonRow {
+                    message: e.to_string(),
+                })?;
+        }
+        Ok(())
+    }
+}
+
 impl RowDecoder for NdJsonDecoder {
     fn add(
         &self,
@@ -14,6 +14,10 @@
 
 mod block_builder;
 mod format;
+mod schema;
 mod separator;
 
 pub use format::NdJsonInputFormat;
+pub use schema::infer_ndjson_schema;
+pub use schema::read_ndjson_sample;
+pub use schema::NDJSON_OVERFLOW_COLUMN;
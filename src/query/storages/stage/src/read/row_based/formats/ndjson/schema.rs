@@ -0,0 +1,187 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_storage::StageFileInfo;
+use opendal::Operator;
+use serde_json::Value;
+
+/// The VARIANT column holding the keys of a record that don't fit the inferred columns,
+/// when NDJSON files are queried with inferred columns.
+pub const NDJSON_OVERFLOW_COLUMN: &str = "_overflow";
+
+/// The type inferred for a key of the sampled NDJSON records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum JsonFieldType {
+    // Only null values seen so far.
+    Null,
+    Boolean,
+    Integer,
+    Float,
+    Date,
+    Timestamp,
+    String,
+    // Nested values, or values of conflicting types.
+    Variant,
+}
+
+impl JsonFieldType {
+    pub(crate) fn of(value: &Value) -> Self {
+        match value {
+            Value::Null => JsonFieldType::Null,
+            Value::Bool(_) => JsonFieldType::Boolean,
+            Value::Number(n) if n.is_i64() => JsonFieldType::Integer,
+            Value::Number(_) => JsonFieldType::Float,
+            Value::String(s) => {
+                if NaiveDate::parse_from_str(s, "%Y-%m-%d").is_ok() {
+                    JsonFieldType::Date
+                } else if NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f").is_ok()
+                    || NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f").is_ok()
+                    // `%#z` also accepts `Z` as the UTC offset
+                    || DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f%#z").is_ok()
+                    || DateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f%#z").is_ok()
+                {
+                    JsonFieldType::Timestamp
+                } else {
+                    JsonFieldType::String
+                }
+            }
+            Value::Array(_) | Value::Object(_) => JsonFieldType::Variant,
+        }
+    }
+
+    pub(crate) fn merge(self, other: Self) -> Self {
+        use JsonFieldType::*;
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Null, t) | (t, Null) => t,
+            (Integer, Float) | (Float, Integer) => Float,
+            (Date, Timestamp) | (Timestamp, Date) => Timestamp,
+            (Date | Timestamp | String, Date | Timestamp | String) => String,
+            _ => Variant,
+        }
+    }
+
+    fn data_type(self) -> TableDataType {
+        match self {
+            JsonFieldType::Boolean => TableDataType::Boolean,
+            JsonFieldType::Integer => TableDataType::Number(NumberDataType::Int64),
+            JsonFieldType::Float => TableDataType::Number(NumberDataType::Float64),
+            JsonFieldType::Date => TableDataType::Date,
+            JsonFieldType::Timestamp => TableDataType::Timestamp,
+            JsonFieldType::String => TableDataType::String,
+            JsonFieldType::Null | JsonFieldType::Variant => TableDataType::Variant,
+        }
+    }
+
+    /// The type of a column inferred by [`infer_ndjson_schema`].
+    pub(crate) fn of_data_type(data_type: &TableDataType) -> Self {
+        match data_type.remove_nullable() {
+            TableDataType::Boolean => JsonFieldType::Boolean,
+            TableDataType::Number(NumberDataType::Int64) => JsonFieldType::Integer,
+            TableDataType::Number(NumberDataType::Float64) => JsonFieldType::Float,
+            TableDataType::Date => JsonFieldType::Date,
+            TableDataType::Timestamp => JsonFieldType::Timestamp,
+            TableDataType::String => JsonFieldType::String,
+            _ => JsonFieldType::Variant,
+        }
+    }
+
+    /// Whether a value of type `other` can be stored in a column of this type.
+    pub(crate) fn accepts(self, other: Self) -> bool {
+        self == JsonFieldType::Variant || self.merge(other) == self
+    }
+}
+
+/// Infer a schema from the first `max_records` records of NDJSON `data`.
+///
+/// Each top level key becomes a column, in the order they are first seen. Keys whose values
+/// are nested, or have conflicting types across records, fall back to VARIANT. A column is
+/// nullable if it is null or missing in any of the sampled records.
+pub fn infer_ndjson_schema(data: &[u8], max_records: usize) -> Result<TableSchema> {
+    let mut fields: Vec<(String, JsonFieldType, usize)> = vec![];
+    let mut num_records = 0;
+    for line in data.split(|b| *b == b'\n') {
+        if num_records >= max_records {
+            break;
+        }
+        if line.iter().all(|b| b.is_ascii_whitespace()) {
+            continue;
+        }
+        let record: Value = serde_json::from_slice(line).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "failed to parse NDJSON record {} to infer its columns: {}",
+                num_records + 1,
+                e
+            ))
+        })?;
+        let Value::Object(record) = record else {
+            return Err(ErrorCode::BadBytes(format!(
+                "inferring the columns of NDJSON expects records to be objects, but record {} is not",
+                num_records + 1
+            )));
+        };
+        num_records += 1;
+
+        for (key, value) in record.iter() {
+            let ty = JsonFieldType::of(value);
+            let non_null = usize::from(ty != JsonFieldType::Null);
+            match fields.iter_mut().find(|(name, _, _)| name == key) {
+                Some((_, field_ty, count)) => {
+                    *field_ty = field_ty.merge(ty);
+                    *count += non_null;
+                }
+                None => fields.push((key.clone(), ty, non_null)),
+            }
+        }
+    }
+
+    let fields = fields
+        .into_iter()
+        .map(|(name, ty, non_null)| {
+            let data_type = ty.data_type();
+            if non_null < num_records {
+                TableField::new(&name, data_type.wrap_nullable())
+            } else {
+                TableField::new(&name, data_type)
+            }
+        })
+        .collect();
+    Ok(TableSchema::new(fields))
+}
+
+/// Read the head of a NDJSON `file`, at most `max_bytes`, to sample its records.
+pub async fn read_ndjson_sample(
+    operator: &Operator,
+    file: &StageFileInfo,
+    max_bytes: u64,
+) -> Result<Vec<u8>> {
+    let end = file.size.min(max_bytes);
+    let mut data = operator.read_with(&file.path).range(0..end).await?.to_vec();
+    // drop the last record if it was cut by the sample size
+    if end < file.size {
+        if let Some(pos) = data.iter().rposition(|b| *b == b'\n') {
+            data.truncate(pos);
+        }
+    }
+    Ok(data)
+}
@@ -138,6 +138,7 @@ mod tests {
             path: "".to_string(),
             offset: 0,
             is_eof,
+            start_row: None,
         };
 
         let (batches, _) = sep.append(input).unwrap();
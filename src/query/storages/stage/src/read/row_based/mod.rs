@@ -19,4 +19,8 @@ mod processors;
 mod read_pipeline;
 mod utils;
 
+pub use formats::infer_avro_schema;
+pub use formats::infer_ndjson_schema;
+pub use formats::read_ndjson_sample;
+pub use formats::NDJSON_OVERFLOW_COLUMN;
 pub use read_pipeline::RowBasedReadPipelineBuilder;
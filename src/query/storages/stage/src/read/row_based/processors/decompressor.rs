@@ -97,6 +97,7 @@ impl AccumulatingTransform for Decompressor {
                 path: batch.path.clone(),
                 offset: *offset,
                 is_eof: batch.is_eof,
+                start_row: None,
             });
             *offset += batch.data.len();
             if batch.is_eof {
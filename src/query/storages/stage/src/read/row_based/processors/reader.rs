@@ -98,6 +98,7 @@ impl BytesReader {
                 path: state.file.path.clone(),
                 offset,
                 is_eof,
+                start_row: None,
             });
             if is_eof {
                 self.file_state = None;
@@ -21,6 +21,7 @@ use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::BlockThresholds;
+use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageFileCompression;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::EmptySource;
@@ -32,6 +33,7 @@ use databend_common_storage::init_stage_operator;
 use crate::compression::get_compression_alg_copy;
 use crate::read::load_context::LoadContext;
 use crate::read::row_based::format::create_row_based_file_format;
+use crate::read::row_based::formats::CsvAligner;
 use crate::read::row_based::processors::BlockBuilder;
 use crate::read::row_based::processors::BytesReader;
 use crate::read::row_based::processors::Decompressor;
@@ -69,6 +71,10 @@ impl RowBasedReadPipelineBuilder<'_> {
     // 4. (resize to threads): so row batches can be processed in parallel, regardless of the file it from.
     // 5. BlockBuilder: the slow part most of the time
     // make sure data from the same file is process in the same pipe in seq in step 1,2,3
+    //
+    // for CSV, a CsvAligner cuts the file at record boundaries before step 3,
+    // and the resize is moved before the Separator, so a large file is also separated in parallel.
+    // the CsvAligner itself scans large batches in parallel chunks.
     pub fn read_data(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -122,12 +128,25 @@ impl RowBasedReadPipelineBuilder<'_> {
             }
         }
 
-        pipeline.try_add_accumulating_transformer(|| {
-            Separator::try_create(load_ctx.clone(), format.clone())
-        })?;
+        if let FileFormatParams::Csv(params) = &self.stage_table_info.stage_info.file_format_params
+        {
+            // the threads left by the sources are used to scan large batches in parallel.
+            let num_scan_threads = std::cmp::max(1, max_threads / num_sources);
+            pipeline.try_add_accumulating_transformer(|| {
+                CsvAligner::try_create(params, num_scan_threads)
+            })?;
+            pipeline.try_resize(max_threads)?;
+            pipeline.try_add_accumulating_transformer(|| {
+                Separator::try_create(load_ctx.clone(), format.clone())
+            })?;
+        } else {
+            pipeline.try_add_accumulating_transformer(|| {
+                Separator::try_create(load_ctx.clone(), format.clone())
+            })?;
 
-        // todo(youngsofun): no need to resize if it is unlikely to be unbalanced
-        pipeline.try_resize(max_threads)?;
+            // todo(youngsofun): no need to resize if it is unlikely to be unbalanced
+            pipeline.try_resize(max_threads)?;
+        }
 
         pipeline
             .try_add_accumulating_transformer(|| BlockBuilder::create(load_ctx.clone(), &format))?;
@@ -154,9 +154,10 @@ impl Table for StageTable {
             FileFormatParams::Orc(_) => {
                 OrcTableForCopy::do_read_partitions(stage_table_info, ctx, _push_downs).await
             }
-            FileFormatParams::Csv(_) | FileFormatParams::NdJson(_) | FileFormatParams::Tsv(_) => {
-                self.read_partitions_simple(ctx, stage_table_info).await
-            }
+            FileFormatParams::Csv(_)
+            | FileFormatParams::NdJson(_)
+            | FileFormatParams::Tsv(_)
+            | FileFormatParams::Avro(_) => self.read_partitions_simple(ctx, stage_table_info).await,
             _ => unreachable!(
                 "unexpected format {} in StageTable::read_partition",
                 stage_table_info.stage_info.file_format_params
@@ -188,7 +189,10 @@ impl Table for StageTable {
             FileFormatParams::Orc(_) => {
                 OrcTableForCopy::do_read_data(ctx, plan, pipeline, _put_cache)
             }
-            FileFormatParams::Csv(_) | FileFormatParams::NdJson(_) | FileFormatParams::Tsv(_) => {
+            FileFormatParams::Csv(_)
+            | FileFormatParams::NdJson(_)
+            | FileFormatParams::Tsv(_)
+            | FileFormatParams::Avro(_) => {
                 let compact_threshold = ctx.get_read_block_thresholds();
                 RowBasedReadPipelineBuilder {
                     stage_table_info,
@@ -83,6 +83,7 @@ impl SyncSystemTable for CachesTable {
         let inverted_index_file_cache = cache_manager.get_inverted_index_file_cache();
         let prune_partitions_cache = cache_manager.get_prune_partitions_cache();
         let file_meta_data_cache = cache_manager.get_file_meta_data_cache();
+        let table_meta_disk_cache = cache_manager.get_table_meta_disk_cache();
         let table_data_cache = cache_manager.get_table_data_cache();
         let table_column_array_cache = cache_manager.get_table_data_array_cache();
 
@@ -127,6 +128,10 @@ impl SyncSystemTable for CachesTable {
             Self::append_row(&file_meta_data_cache, &local_node, &mut columns);
         }
 
+        if let Some(table_meta_disk_cache) = table_meta_disk_cache {
+            Self::append_row(&table_meta_disk_cache, &local_node, &mut columns);
+        }
+
         if let Some(cache) = table_data_cache {
             // table data cache is not a named cache yet
             columns.nodes.push(local_node.clone());
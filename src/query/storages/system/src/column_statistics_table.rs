@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use futures::StreamExt;
+use log::warn;
+
+use crate::columns_table::dump_tables;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// Exposes the column statistics the optimizer sees for each table:
+/// min/max/null_count from the snapshot summary, and the NDV estimated
+/// from the HLL sketches kept in the snapshot statistics.
+///
+/// Loading the statistics reads the snapshot of each table, filter on
+/// `database` and `table` to avoid reading those of every table.
+pub struct ColumnStatisticsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ColumnStatisticsTable {
+    const NAME: &'static str = "system.column_statistics";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let database_and_tables = dump_tables(&ctx, push_downs).await?;
+
+        let mut databases: Vec<String> = Vec::new();
+        let mut tables: Vec<String> = Vec::new();
+        let mut names: Vec<String> = Vec::new();
+        let mut mins: Vec<Option<String>> = Vec::new();
+        let mut maxs: Vec<Option<String>> = Vec::new();
+        let mut null_counts: Vec<u64> = Vec::new();
+        let mut ndvs: Vec<Option<u64>> = Vec::new();
+
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        for (database, db_tables) in database_and_tables {
+            // Only fuse tables keep column statistics.
+            let db_tables = db_tables
+                .into_iter()
+                .filter(|table| table.engine() == "FUSE")
+                .collect::<Vec<_>>();
+            let providers = futures::stream::iter(db_tables.iter())
+                .map(|table| table.column_statistics_provider(ctx.clone()))
+                .buffered(max_threads.max(1))
+                .collect::<Vec<_>>()
+                .await;
+
+            for (table, provider) in db_tables.iter().zip(providers) {
+                let provider = match provider {
+                    Ok(provider) => provider,
+                    Err(e) => {
+                        warn!(
+                            "failed to get column statistics for {}: {}",
+                            table.get_table_info().desc,
+                            e
+                        );
+                        continue;
+                    }
+                };
+
+                for field in table.schema().fields() {
+                    let Some(stats) = provider.column_statistics(field.column_id()) else {
+                        continue;
+                    };
+                    databases.push(database.clone());
+                    tables.push(table.name().to_string());
+                    names.push(field.name().clone());
+                    mins.push(stats.min.as_ref().map(|v| v.to_string()));
+                    maxs.push(stats.max.as_ref().map(|v| v.to_string()));
+                    null_counts.push(stats.null_count);
+                    ndvs.push(stats.ndv);
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(databases),
+            StringType::from_data(tables),
+            StringType::from_data(names),
+            StringType::from_opt_data(mins),
+            StringType::from_opt_data(maxs),
+            UInt64Type::from_data(null_counts),
+            UInt64Type::from_opt_data(ndvs),
+        ]))
+    }
+}
+
+impl ColumnStatisticsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("column_name", TableDataType::String),
+            TableField::new(
+                "min",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "max",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("null_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "ndv",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'column_statistics'".to_string(),
+            name: "column_statistics".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemColumnStatistics".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(ColumnStatisticsTable { table_info })
+    }
+}
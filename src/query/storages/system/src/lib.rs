@@ -28,6 +28,7 @@ mod caches_table;
 mod catalogs_table;
 mod clustering_history_table;
 mod clusters_table;
+mod column_statistics_table;
 mod columns_table;
 mod configs_table;
 mod contributors_table;
@@ -48,12 +49,14 @@ mod password_policies_table;
 mod processes_table;
 mod queries_profiling;
 mod query_cache_table;
+mod query_fragments_table;
 mod query_log_table;
 mod roles_table;
 mod settings_table;
 mod stages_table;
 mod streams_table;
 mod table;
+mod table_events_table;
 mod table_functions_table;
 mod tables_table;
 mod task_history_table;
@@ -74,6 +77,7 @@ pub use clustering_history_table::ClusteringHistoryLogElement;
 pub use clustering_history_table::ClusteringHistoryQueue;
 pub use clustering_history_table::ClusteringHistoryTable;
 pub use clusters_table::ClustersTable;
+pub use column_statistics_table::ColumnStatisticsTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
@@ -99,6 +103,9 @@ pub use queries_profiling::ProfilesLogElement;
 pub use queries_profiling::ProfilesLogQueue;
 pub use queries_profiling::QueriesProfilingTable;
 pub use query_cache_table::QueryCacheTable;
+pub use query_fragments_table::QueryFragmentsLogElement;
+pub use query_fragments_table::QueryFragmentsLogQueue;
+pub use query_fragments_table::QueryFragmentsTable;
 pub use query_log_table::LogType;
 pub use query_log_table::QueryLogElement;
 pub use query_log_table::QueryLogQueue;
@@ -110,6 +117,9 @@ pub use streams_table::FullStreamsTable;
 pub use streams_table::TerseStreamsTable;
 pub use table::SyncOneBlockSystemTable;
 pub use table::SyncSystemTable;
+pub use table_events_table::TableEventLogElement;
+pub use table_events_table::TableEventQueue;
+pub use table_events_table::TableEventTable;
 pub use table_functions_table::TableFunctionsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
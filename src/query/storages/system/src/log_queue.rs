@@ -34,6 +34,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::tenant::Tenant;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
@@ -50,6 +51,12 @@ pub trait SystemLogElement: Send + Sync + Clone {
     fn schema() -> TableSchemaRef;
 
     fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()>;
+
+    /// Whether the element is visible to the queries of `tenant`, the log tables
+    /// shared by all tenants of the node keep the default.
+    fn is_visible_to(&self, _tenant: &Tenant) -> bool {
+        true
+    }
 }
 
 pub struct Data<Event: SystemLogElement> {
@@ -192,9 +199,12 @@ impl<Event: SystemLogElement + 'static> Table for SystemLogTable<Event> {
             data_types.push(data_type);
         }
 
+        let tenant = ctx.get_tenant();
         let log_queue = SystemLogQueue::<Event>::instance()?;
         for event in log_queue.data.read().event_queue.iter().flatten() {
-            event.fill_to_data_block(&mut mutable_columns)?;
+            if event.is_visible_to(&tenant) {
+                event.fill_to_data_block(&mut mutable_columns)?;
+            }
         }
 
         let mut columns = Vec::with_capacity(mutable_columns.len());
@@ -216,13 +226,21 @@ impl<Event: SystemLogElement + 'static> Table for SystemLogTable<Event> {
     }
 
     #[async_backtrace::framed]
-    async fn truncate(&self, _ctx: Arc<dyn TableContext>, _pipeline: &mut Pipeline) -> Result<()> {
+    async fn truncate(&self, ctx: Arc<dyn TableContext>, _pipeline: &mut Pipeline) -> Result<()> {
+        let tenant = ctx.get_tenant();
         let log_queue = SystemLogQueue::<Event>::instance()?;
         let mut write_guard = log_queue.data.write();
 
-        write_guard.index = 0;
-        for index in 0..write_guard.event_queue.len() {
-            write_guard.event_queue[index] = None;
+        for slot in write_guard.event_queue.iter_mut() {
+            if slot
+                .as_ref()
+                .is_some_and(|event| event.is_visible_to(&tenant))
+            {
+                *slot = None;
+            }
+        }
+        if write_guard.event_queue.iter().all(Option::is_none) {
+            write_guard.index = 0;
         }
 
         Ok(())
@@ -77,6 +77,7 @@ impl SyncSystemTable for QueriesProfilingTable {
         let mut plan_name: Vec<Option<String>> = Vec::with_capacity(total_size);
         let mut errors = Vec::with_capacity(total_size);
         let mut statistics = Vec::with_capacity(total_size);
+        let mut decoded_column_bytes = Vec::with_capacity(total_size);
 
         for (query_id, query_profiles) in queries_profiles {
             for query_plan_profile in query_profiles {
@@ -95,6 +96,8 @@ impl SyncSystemTable for QueriesProfilingTable {
                 }
 
                 statistics.push(serde_json::to_vec(&statistics_map).unwrap());
+                decoded_column_bytes
+                    .push(serde_json::to_vec(&query_plan_profile.decoded_column_bytes).unwrap());
             }
         }
 
@@ -116,6 +119,8 @@ impl SyncSystemTable for QueriesProfilingTable {
                 }
 
                 statistics.push(serde_json::to_vec(&statistics_map).unwrap());
+                decoded_column_bytes
+                    .push(serde_json::to_vec(&query_plan_profile.decoded_column_bytes).unwrap());
             }
         }
 
@@ -127,6 +132,7 @@ impl SyncSystemTable for QueriesProfilingTable {
             StringType::from_opt_data(plan_name),
             VariantType::from_data(errors),
             VariantType::from_data(statistics),
+            VariantType::from_data(decoded_column_bytes),
         ]))
     }
 }
@@ -150,6 +156,7 @@ impl QueriesProfilingTable {
             ),
             TableField::new("errors", TableDataType::Variant),
             TableField::new("statistics", TableDataType::Variant),
+            TableField::new("decoded_column_bytes", TableDataType::Variant),
         ]);
 
         let table_info = TableInfo {
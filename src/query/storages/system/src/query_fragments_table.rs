@@ -0,0 +1,184 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+
+/// One side of an exchange edge between two query fragments.
+///
+/// The sending node logs the rows it wrote and the receiving node logs the
+/// rows it read, so joining both sides by `(query_id, fragment_id,
+/// source_node, destination_node)` gives the full edge.
+#[derive(Clone)]
+pub struct QueryFragmentsLogElement {
+    pub tenant: String,
+    pub query_id: String,
+    pub fragment_id: u64,
+    pub source_node: String,
+    pub destination_node: String,
+    pub rows_sent: u64,
+    pub bytes_sent: u64,
+    pub rows_received: u64,
+    pub bytes_received: u64,
+    /// Microseconds since epoch when the edge became active.
+    pub start_time: i64,
+    /// How long the edge was active. On the sending node this is the wall time of the
+    /// fragment, from the start of its pipeline until all its output is sent.
+    pub wall_time_ms: u64,
+}
+
+impl SystemLogElement for QueryFragmentsLogElement {
+    const TABLE_NAME: &'static str = "query_fragments_cache_not_table";
+    fn schema() -> TableSchemaRef {
+        unreachable!()
+    }
+    fn fill_to_data_block(&self, _: &mut Vec<ColumnBuilder>) -> Result<()> {
+        unreachable!()
+    }
+}
+
+pub type QueryFragmentsLogQueue = SystemLogQueue<QueryFragmentsLogElement>;
+
+pub struct QueryFragmentsTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for QueryFragmentsTable {
+    const NAME: &'static str = "system.query_fragments";
+
+    const IS_LOCAL: bool = false;
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let queue = QueryFragmentsLogQueue::instance()?;
+        let elements: Vec<QueryFragmentsLogElement> = queue
+            .data
+            .read()
+            .event_queue
+            .iter()
+            .flatten()
+            .filter(|element| element.tenant == tenant.tenant_name())
+            .cloned()
+            .collect();
+
+        let local_id = ctx.get_cluster().local_id.clone();
+
+        let mut node = Vec::with_capacity(elements.len());
+        let mut query_id = Vec::with_capacity(elements.len());
+        let mut fragment_id = Vec::with_capacity(elements.len());
+        let mut source_node = Vec::with_capacity(elements.len());
+        let mut destination_node = Vec::with_capacity(elements.len());
+        let mut rows_sent = Vec::with_capacity(elements.len());
+        let mut bytes_sent = Vec::with_capacity(elements.len());
+        let mut rows_received = Vec::with_capacity(elements.len());
+        let mut bytes_received = Vec::with_capacity(elements.len());
+        let mut start_time = Vec::with_capacity(elements.len());
+        let mut wall_time_ms = Vec::with_capacity(elements.len());
+
+        for element in elements {
+            node.push(local_id.clone());
+            query_id.push(element.query_id);
+            fragment_id.push(element.fragment_id);
+            source_node.push(element.source_node);
+            destination_node.push(element.destination_node);
+            rows_sent.push(element.rows_sent);
+            bytes_sent.push(element.bytes_sent);
+            rows_received.push(element.rows_received);
+            bytes_received.push(element.bytes_received);
+            start_time.push(element.start_time);
+            wall_time_ms.push(element.wall_time_ms);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(node),
+            StringType::from_data(query_id),
+            UInt64Type::from_data(fragment_id),
+            StringType::from_data(source_node),
+            StringType::from_data(destination_node),
+            UInt64Type::from_data(rows_sent),
+            UInt64Type::from_data(bytes_sent),
+            UInt64Type::from_data(rows_received),
+            UInt64Type::from_data(bytes_received),
+            TimestampType::from_data(start_time),
+            UInt64Type::from_data(wall_time_ms),
+        ]))
+    }
+}
+
+impl QueryFragmentsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("node", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("fragment_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("source_node", TableDataType::String),
+            TableField::new("destination_node", TableDataType::String),
+            TableField::new("rows_sent", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("bytes_sent", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "rows_received",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "bytes_received",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("start_time", TableDataType::Timestamp),
+            TableField::new(
+                "wall_time_ms",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'query_fragments'".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            name: "query_fragments".to_string(),
+            meta: TableMeta {
+                schema,
+                engine: "QueryFragmentsTable".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
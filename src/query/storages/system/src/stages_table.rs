@@ -80,6 +80,7 @@ impl AsyncSystemTable for StagesTable {
         let mut stage_type: Vec<String> = Vec::with_capacity(stages.len());
         let mut stage_params: Vec<String> = Vec::with_capacity(stages.len());
         let mut copy_options: Vec<String> = Vec::with_capacity(stages.len());
+        let mut lifecycle: Vec<String> = Vec::with_capacity(stages.len());
         let mut file_format_options: Vec<String> = Vec::with_capacity(stages.len());
         let mut comment: Vec<String> = Vec::with_capacity(stages.len());
         let mut number_of_files: Vec<Option<u64>> = Vec::with_capacity(stages.len());
@@ -98,6 +99,7 @@ impl AsyncSystemTable for StagesTable {
             stage_type.push(stage.stage_type.clone().to_string());
             stage_params.push(format!("{:?}", stage.stage_params));
             copy_options.push(format!("{:?}", stage.copy_options));
+            lifecycle.push(format!("{:?}", stage.lifecycle));
             file_format_options.push(format!("{:?}", stage.file_format_params));
             // TODO(xuanwo): we will remove this line.
             match stage.stage_type {
@@ -124,6 +126,7 @@ impl AsyncSystemTable for StagesTable {
             TimestampType::from_data(created_on),
             StringType::from_data(comment),
             StringType::from_opt_data(owners),
+            StringType::from_data(lifecycle),
         ]))
     }
 }
@@ -151,6 +154,7 @@ impl StagesTable {
                 "owner",
                 TableDataType::Nullable(Box::new(TableDataType::String)),
             ),
+            TableField::new("lifecycle", TableDataType::String),
         ]);
         let table_info = TableInfo {
             desc: "'system'.'stages'".to_string(),
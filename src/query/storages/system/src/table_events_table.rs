@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_catalog::table_events::TableEvent;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::tenant::Tenant;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+#[derive(Clone)]
+pub struct TableEventLogElement {
+    pub event: TableEvent,
+}
+
+impl SystemLogElement for TableEventLogElement {
+    const TABLE_NAME: &'static str = "table_events";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("event_type", TableDataType::String),
+            TableField::new("catalog", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("table_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "snapshot_location",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ])
+    }
+
+    /// The events are recorded in a queue of the node, a tenant only sees the events
+    /// of its own tables.
+    fn is_visible_to(&self, tenant: &Tenant) -> bool {
+        self.event.tenant == tenant.tenant_name()
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let event = &self.event;
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(event.event_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(event.event_type.to_string()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(event.catalog.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(event.database.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(event.table.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(event.table_id)).as_ref());
+        columns.next().unwrap().push(
+            event
+                .snapshot_location
+                .clone()
+                .map_or(Scalar::Null, Scalar::String)
+                .as_ref(),
+        );
+        Ok(())
+    }
+}
+
+pub type TableEventQueue = SystemLogQueue<TableEventLogElement>;
+pub type TableEventTable = SystemLogTable<TableEventLogElement>;
@@ -44,9 +44,11 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_view::view_table::is_secure_view;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_users::GrantObjectVisibilityChecker;
 use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
 use log::warn;
 
 use crate::table::AsyncOneBlockSystemTable;
@@ -265,7 +267,8 @@ where TablesTable<T, U>: HistoryAware
                 | "number_of_blocks" => {
                     stats_fields_indexes.insert(i);
                 }
-                "owner" => {
+                // The definition of a secure view is only shown to its owner.
+                "owner" | "view_query" => {
                     owner_field_indexes.insert(i);
                 }
                 _ => {}
@@ -587,12 +590,24 @@ where TablesTable<T, U>: HistoryAware
             .map(|v| v.get_table_info().meta.comment.clone())
             .collect();
 
+        let effective_roles = if U {
+            vec![]
+        } else {
+            ctx.get_all_effective_roles().await?
+        };
         let view_query: Vec<String> = database_tables
             .iter()
-            .map(|v| -> String {
+            .zip(owner.iter())
+            .map(|(v, owner)| -> String {
                 let tbl_info = v.get_table_info();
                 match tbl_info.engine() {
                     "VIEW" => {
+                        if is_secure_view(tbl_info.options()) {
+                            let owner = owner.as_deref().unwrap_or(BUILTIN_ROLE_ACCOUNT_ADMIN);
+                            if !effective_roles.iter().any(|r| r.name == owner) {
+                                return String::from("");
+                            }
+                        }
                         let query = tbl_info.options().get(QUERY);
                         match query {
                             Some(query) => query.clone(),
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use databend_common_catalog::catalog::StorageDescription;
@@ -28,6 +29,15 @@ pub struct ViewTable {
 
 pub const VIEW_ENGINE: &str = "VIEW";
 pub const QUERY: &str = "query";
+/// The parameters of a parameterized view, e.g. `p INT, q STRING DEFAULT 'a'`.
+pub const PARAMETERS: &str = "parameters";
+/// Set on a secure view, whose definition is hidden from non-owners.
+pub const SECURE: &str = "secure";
+
+/// Returns true if the table options belong to a secure view.
+pub fn is_secure_view(options: &BTreeMap<String, String>) -> bool {
+    options.get(SECURE).is_some_and(|v| v == "true")
+}
 
 impl ViewTable {
     pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
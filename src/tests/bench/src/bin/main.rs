@@ -0,0 +1,179 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use clap::Parser;
+use clap::Subcommand;
+use databend_bench::compare;
+use databend_bench::BenchReport;
+use databend_bench::DataSource;
+use databend_bench::Endpoint;
+use databend_bench::Runner;
+use databend_bench::Suite;
+
+#[derive(Clone, Debug, PartialEq, Parser)]
+#[clap(about, author)]
+pub struct Args {
+    /// The database host.
+    #[clap(long, default_value = "localhost")]
+    host: String,
+
+    /// The database http port.
+    #[clap(long, default_value = "8000")]
+    port: u16,
+
+    /// The username.
+    #[clap(long, default_value = "root")]
+    user: String,
+
+    /// The password.
+    #[clap(long, default_value = "")]
+    pass: String,
+
+    /// The benchmark suite.
+    #[clap(long, value_enum, default_value = "tpch")]
+    suite: Suite,
+
+    /// The scale factor of the data set.
+    #[clap(long, default_value = "1")]
+    scale: f64,
+
+    /// The database of the benchmark tables, defaults to `<suite>_sf<scale>`.
+    #[clap(long)]
+    db: Option<String>,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Debug, PartialEq, Subcommand)]
+enum Command {
+    /// Create the tables and load the data set.
+    Prepare {
+        /// Load the generated files from this stage or URI, e.g. `@tpch/sf1/`.
+        #[clap(long, conflicts_with = "generator")]
+        location: Option<String>,
+
+        /// Path of the `dbgen` / `dsdgen` binary used to generate the data.
+        #[clap(long, requires = "data_dir")]
+        generator: Option<PathBuf>,
+
+        /// Local directory of the generated data.
+        #[clap(long)]
+        data_dir: Option<PathBuf>,
+
+        /// DDL script to create the tables, defaults to the one in `benchmark/`.
+        #[clap(long)]
+        schema: Option<PathBuf>,
+    },
+    /// Run the queries and report the latency of each query.
+    Run {
+        /// A directory of `*.sql` files, a `.sql` script or a sqllogictest `.test` file.
+        #[clap(long)]
+        queries: Option<PathBuf>,
+
+        /// The number of times each query is executed.
+        #[clap(long, default_value = "3")]
+        iterations: usize,
+
+        /// Session settings applied before running, e.g. `--setting max_threads=8`.
+        #[clap(long = "setting", value_parser = parse_setting)]
+        settings: Vec<(String, String)>,
+
+        /// Write the report as JSON to this file.
+        #[clap(long)]
+        output: Option<PathBuf>,
+
+        /// Compare against a report of a previous run.
+        #[clap(long)]
+        baseline: Option<PathBuf>,
+
+        /// Relative slowdown of the median latency regarded as a regression.
+        #[clap(long, default_value = "0.1")]
+        threshold: f64,
+    },
+}
+
+fn parse_setting(s: &str) -> Result<(String, String)> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| anyhow!("invalid setting `{}`, expect `name=value`", s))?;
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 5)]
+async fn main() -> Result<ExitCode> {
+    tracing_subscriber::fmt::init();
+
+    let args = Args::parse();
+
+    let endpoint = Endpoint {
+        host: args.host,
+        port: args.port,
+        user: args.user,
+        password: args.pass,
+    };
+    let db = args
+        .db
+        .unwrap_or_else(|| format!("{}_sf{}", args.suite, args.scale).replace('.', "_"));
+    let runner = Runner::new(endpoint, db, args.suite, args.scale);
+
+    match args.command {
+        Command::Prepare {
+            location,
+            generator,
+            data_dir,
+            schema,
+        } => {
+            let source = match (location, generator, data_dir) {
+                (Some(location), _, _) => DataSource::Location(location),
+                (None, Some(generator), Some(data_dir)) => DataSource::Generate {
+                    generator,
+                    data_dir,
+                },
+                _ => return Err(anyhow!("either --location or --generator is required")),
+            };
+            runner.prepare(source, schema.as_deref()).await?;
+        }
+        Command::Run {
+            queries,
+            iterations,
+            settings,
+            output,
+            baseline,
+            threshold,
+        } => {
+            let report = runner
+                .run(queries.as_deref(), iterations, &settings)
+                .await?;
+            report.print();
+            if let Some(output) = output {
+                report.save(&output)?;
+            }
+            if let Some(baseline) = baseline {
+                let baseline = BenchReport::load(&baseline)?;
+                let regressions = compare(&baseline, &report, threshold)?;
+                if !regressions.is_empty() {
+                    println!("{} queries regressed", regressions.len());
+                    return Ok(ExitCode::FAILURE);
+                }
+            }
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
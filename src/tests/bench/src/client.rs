@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use reqwest::Client;
+use reqwest::ClientBuilder;
+use serde_json::json;
+use serde_json::Value;
+
+/// Where the benchmarked deployment listens, and who to log in as.
+#[derive(Clone, Debug)]
+pub struct Endpoint {
+    pub host: String,
+    /// The port of the HTTP handler.
+    pub port: u16,
+    pub user: String,
+    pub password: String,
+}
+
+/// A client of the HTTP query handler (`/v1/query`), the session (current database,
+/// settings) is carried from one statement to the next.
+pub struct HttpClient {
+    client: Client,
+    endpoint: Endpoint,
+    session: Value,
+}
+
+#[derive(serde::Deserialize)]
+struct QueryResponse {
+    session: Option<Value>,
+    next_uri: Option<String>,
+    error: Option<QueryError>,
+}
+
+#[derive(serde::Deserialize)]
+struct QueryError {
+    code: u16,
+    message: String,
+}
+
+impl HttpClient {
+    pub fn create(endpoint: Endpoint, database: &str) -> Result<Self> {
+        let client = ClientBuilder::new()
+            .http2_keep_alive_timeout(Duration::from_secs(15))
+            .build()?;
+        Ok(Self {
+            client,
+            endpoint,
+            session: json!({ "database": database }),
+        })
+    }
+
+    /// Executes the statement and drains all the pages of its result.
+    pub async fn exec(&mut self, sql: &str) -> Result<()> {
+        let body = json!({ "sql": sql, "session": self.session });
+        let mut response = self
+            .client
+            .post(self.url("/v1/query"))
+            .basic_auth(&self.endpoint.user, Some(&self.endpoint.password))
+            .json(&body)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<QueryResponse>()
+            .await?;
+        loop {
+            if let Some(session) = response.session.take() {
+                self.session = session;
+            }
+            if let Some(error) = response.error {
+                return Err(anyhow!("code: {}, Text: {}", error.code, error.message));
+            }
+            let Some(next_uri) = response.next_uri else {
+                return Ok(());
+            };
+            response = self
+                .client
+                .get(self.url(&next_uri))
+                .basic_auth(&self.endpoint.user, Some(&self.endpoint.password))
+                .send()
+                .await?
+                .error_for_status()?
+                .json::<QueryResponse>()
+                .await?;
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!(
+            "http://{}:{}{}",
+            self.endpoint.host, self.endpoint.port, path
+        )
+    }
+}
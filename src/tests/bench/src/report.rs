@@ -0,0 +1,208 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::workload::Suite;
+
+/// The result of one benchmark run, persisted as JSON so that it can be used
+/// as the baseline of a later run.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub suite: Suite,
+    pub scale: f64,
+    pub iterations: usize,
+    pub queries: Vec<QueryResult>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryResult {
+    pub name: String,
+    /// Latency of each iteration in milliseconds, empty if the query failed.
+    pub times_ms: Vec<f64>,
+    pub error: Option<String>,
+}
+
+impl QueryResult {
+    pub fn min_ms(&self) -> Option<f64> {
+        self.times_ms.iter().copied().reduce(f64::min)
+    }
+
+    pub fn median_ms(&self) -> Option<f64> {
+        if self.times_ms.is_empty() {
+            return None;
+        }
+        let mut times = self.times_ms.clone();
+        times.sort_by(|a, b| a.total_cmp(b));
+        let mid = times.len() / 2;
+        if times.len() % 2 == 0 {
+            Some((times[mid - 1] + times[mid]) / 2.0)
+        } else {
+            Some(times[mid])
+        }
+    }
+}
+
+impl BenchReport {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| anyhow!("failed to read report {}: {}", path.display(), e))?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn total_ms(&self) -> f64 {
+        self.queries.iter().filter_map(|q| q.median_ms()).sum()
+    }
+
+    pub fn print(&self) {
+        println!(
+            "{} (scale factor {}, {} iterations)",
+            self.suite, self.scale, self.iterations
+        );
+        println!("{:<12} {:>12} {:>12}", "query", "min(ms)", "median(ms)");
+        for q in &self.queries {
+            match (&q.error, q.min_ms(), q.median_ms()) {
+                (None, Some(min), Some(median)) => {
+                    println!("{:<12} {:>12.1} {:>12.1}", q.name, min, median)
+                }
+                (error, _, _) => println!(
+                    "{:<12} {:>12} {:>12}  {}",
+                    q.name,
+                    "-",
+                    "-",
+                    error.as_deref().unwrap_or_default()
+                ),
+            }
+        }
+        println!("{:<12} {:>12} {:>12.1}", "total", "", self.total_ms());
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    pub name: String,
+    pub baseline_ms: f64,
+    pub current_ms: f64,
+}
+
+/// Compares the median latency of each query against the baseline, and returns
+/// the queries that are slower than the baseline by more than `threshold` (0.1 = 10%),
+/// or that failed although they succeeded in the baseline.
+pub fn compare(
+    baseline: &BenchReport,
+    current: &BenchReport,
+    threshold: f64,
+) -> Result<Vec<Regression>> {
+    if baseline.suite != current.suite || baseline.scale != current.scale {
+        return Err(anyhow!(
+            "baseline is {} with scale factor {}, but current run is {} with scale factor {}",
+            baseline.suite,
+            baseline.scale,
+            current.suite,
+            current.scale
+        ));
+    }
+
+    println!(
+        "{:<12} {:>14} {:>14} {:>9}",
+        "query", "baseline(ms)", "current(ms)", "change"
+    );
+    let mut regressions = vec![];
+    for q in &current.queries {
+        let Some(base) = baseline
+            .queries
+            .iter()
+            .find(|b| b.name == q.name)
+            .and_then(|b| b.median_ms())
+        else {
+            continue;
+        };
+        let Some(median) = q.median_ms() else {
+            println!("{:<12} {:>14.1} {:>14} {:>9}", q.name, base, "-", "FAILED");
+            regressions.push(Regression {
+                name: q.name.clone(),
+                baseline_ms: base,
+                current_ms: f64::INFINITY,
+            });
+            continue;
+        };
+        let change = if base > 0.0 { median / base - 1.0 } else { 0.0 };
+        let regressed = change > threshold;
+        println!(
+            "{:<12} {:>14.1} {:>14.1} {:>+8.1}%{}",
+            q.name,
+            base,
+            median,
+            change * 100.0,
+            if regressed { "  REGRESSION" } else { "" }
+        );
+        if regressed {
+            regressions.push(Regression {
+                name: q.name.clone(),
+                baseline_ms: base,
+                current_ms: median,
+            });
+        }
+    }
+    Ok(regressions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(times: &[(&str, &[f64])]) -> BenchReport {
+        BenchReport {
+            suite: Suite::Tpch,
+            scale: 1.0,
+            iterations: 3,
+            queries: times
+                .iter()
+                .map(|(name, times)| QueryResult {
+                    name: name.to_string(),
+                    times_ms: times.to_vec(),
+                    error: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_compare() -> Result<()> {
+        let baseline = report(&[("q01", &[100.0, 110.0, 90.0]), ("q02", &[50.0])]);
+        let current = report(&[("q01", &[105.0, 120.0, 108.0]), ("q02", &[80.0])]);
+        let regressions = compare(&baseline, &current, 0.1)?;
+        assert_eq!(regressions, vec![Regression {
+            name: "q02".to_string(),
+            baseline_ms: 50.0,
+            current_ms: 80.0,
+        }]);
+
+        let mut other = current.clone();
+        other.scale = 10.0;
+        assert!(compare(&baseline, &other, 0.1).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Instant;
+
+use anyhow::anyhow;
+use anyhow::Result;
+
+use crate::client::Endpoint;
+use crate::client::HttpClient;
+use crate::report::BenchReport;
+use crate::report::QueryResult;
+use crate::workload::load_queries;
+use crate::workload::load_schema;
+use crate::workload::Suite;
+
+/// Where the data of the suite is loaded from.
+#[derive(Clone, Debug)]
+pub enum DataSource {
+    /// A stage (`@stage/path/`) or URI (`s3://bucket/path/`) that already contains
+    /// the generated `<table>.tbl` / `<table>.dat` files.
+    Location(String),
+    /// Generate the data with `dbgen` / `dsdgen` into a local directory. The query
+    /// node must be able to read this directory, so it only works for local deployments.
+    Generate {
+        generator: PathBuf,
+        data_dir: PathBuf,
+    },
+}
+
+pub struct Runner {
+    endpoint: Endpoint,
+    database: String,
+    suite: Suite,
+    scale: f64,
+}
+
+impl Runner {
+    pub fn new(endpoint: Endpoint, database: String, suite: Suite, scale: f64) -> Self {
+        Self {
+            endpoint,
+            database,
+            suite,
+            scale,
+        }
+    }
+
+    fn connect(&self, database: &str) -> Result<HttpClient> {
+        HttpClient::create(self.endpoint.clone(), database)
+    }
+
+    /// Creates the tables of the suite and loads the data into them.
+    pub async fn prepare(&self, source: DataSource, schema: Option<&Path>) -> Result<()> {
+        let mut conn = self.connect("default")?;
+        conn.exec(&format!("CREATE DATABASE IF NOT EXISTS {}", self.database))
+            .await?;
+
+        let mut conn = self.connect(&self.database)?;
+        for table in self.suite.tables() {
+            conn.exec(&format!("DROP TABLE IF EXISTS {} ALL", table))
+                .await?;
+        }
+        for stmt in load_schema(self.suite, schema)? {
+            conn.exec(&stmt).await?;
+        }
+
+        let (_, ext) = self.suite.generator();
+        let location = match source {
+            DataSource::Location(location) => location,
+            DataSource::Generate {
+                generator,
+                data_dir,
+            } => {
+                self.generate(&generator, &data_dir)?;
+                let data_dir = data_dir.canonicalize()?;
+                format!("'fs://{}/'", data_dir.display())
+            }
+        };
+        let location = if location.starts_with('@') || location.starts_with('\'') {
+            location
+        } else {
+            format!("'{}'", location)
+        };
+
+        for table in self.suite.tables() {
+            tracing::info!("loading table {}", table);
+            let start = Instant::now();
+            // The generators end each line with a trailing `|`.
+            let copy = format!(
+                "COPY INTO {table} FROM {location} PATTERN = '(.*/)?{table}[.]{ext}([.][0-9]+)?' \
+                 FILE_FORMAT = (type = CSV field_delimiter = '|' record_delimiter = '\\n' \
+                 skip_header = 0 error_on_column_count_mismatch = false)"
+            );
+            conn.exec(&copy).await?;
+            conn.exec(&format!("ANALYZE TABLE {}", table)).await?;
+            println!("loaded {} in {:.1}s", table, start.elapsed().as_secs_f64());
+        }
+        Ok(())
+    }
+
+    fn generate(&self, generator: &Path, data_dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(data_dir)?;
+        let scale = self.scale.to_string();
+        let mut cmd = Command::new(generator);
+        match self.suite {
+            Suite::Tpch => {
+                cmd.args(["-vf", "-s", &scale]).current_dir(data_dir);
+            }
+            Suite::Tpcds => {
+                cmd.args(["-SCALE", &scale, "-FORCE", "-DIR"]).arg(data_dir);
+            }
+        }
+        tracing::info!("generating data: {:?}", cmd);
+        let status = cmd.status()?;
+        if !status.success() {
+            return Err(anyhow!("{} exited with {}", generator.display(), status));
+        }
+        Ok(())
+    }
+
+    /// Runs every query of the suite `iterations` times and collects the latencies.
+    /// A failed query is recorded in the report and does not stop the run.
+    pub async fn run(
+        &self,
+        queries: Option<&Path>,
+        iterations: usize,
+        settings: &[(String, String)],
+    ) -> Result<BenchReport> {
+        let mut conn = self.connect(&self.database)?;
+        for (name, value) in settings {
+            conn.exec(&format!("SET {} = {}", name, value)).await?;
+        }
+
+        let queries = load_queries(self.suite, queries)?;
+        let mut results = Vec::with_capacity(queries.len());
+        for query in queries {
+            let mut result = QueryResult {
+                name: query.name.clone(),
+                times_ms: Vec::with_capacity(iterations),
+                error: None,
+            };
+            for _ in 0..iterations {
+                match Self::execute(&mut conn, &query.sql).await {
+                    Ok(ms) => result.times_ms.push(ms),
+                    Err(e) => {
+                        tracing::error!("query {} failed: {}", query.name, e);
+                        result.times_ms.clear();
+                        result.error = Some(e.to_string());
+                        break;
+                    }
+                }
+            }
+            println!(
+                "{}: {}",
+                query.name,
+                result
+                    .median_ms()
+                    .map(|ms| format!("{:.1}ms", ms))
+                    .unwrap_or_else(|| "failed".to_string())
+            );
+            results.push(result);
+        }
+
+        Ok(BenchReport {
+            suite: self.suite,
+            scale: self.scale,
+            iterations,
+            queries: results,
+        })
+    }
+
+    /// Executes the query and drains its result, returns the latency in milliseconds.
+    async fn execute(conn: &mut HttpClient, sql: &str) -> Result<f64> {
+        let start = Instant::now();
+        conn.exec(sql).await?;
+        Ok(start.elapsed().as_secs_f64() * 1000.0)
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Deserialize;
+use serde::Serialize;
+
+const TPCH_TABLES: &[&str] = &[
+    "customer", "lineitem", "nation", "orders", "partsupp", "part", "region", "supplier",
+];
+
+const TPCDS_TABLES: &[&str] = &[
+    "call_center",
+    "catalog_page",
+    "catalog_returns",
+    "catalog_sales",
+    "customer",
+    "customer_address",
+    "customer_demographics",
+    "date_dim",
+    "household_demographics",
+    "income_band",
+    "inventory",
+    "item",
+    "promotion",
+    "reason",
+    "ship_mode",
+    "store",
+    "store_returns",
+    "store_sales",
+    "time_dim",
+    "warehouse",
+    "web_page",
+    "web_returns",
+    "web_sales",
+    "web_site",
+];
+
+/// The standard benchmark suites.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Suite {
+    Tpch,
+    Tpcds,
+}
+
+impl Suite {
+    pub fn tables(&self) -> &'static [&'static str] {
+        match self {
+            Suite::Tpch => TPCH_TABLES,
+            Suite::Tpcds => TPCDS_TABLES,
+        }
+    }
+
+    /// The data generator binary and the extension of the files it produces.
+    pub fn generator(&self) -> (&'static str, &'static str) {
+        match self {
+            Suite::Tpch => ("dbgen", "tbl"),
+            Suite::Tpcds => ("dsdgen", "dat"),
+        }
+    }
+
+    /// Default DDL script, relative to the repository root.
+    fn default_schema(&self) -> PathBuf {
+        match self {
+            Suite::Tpch => repo_root().join("benchmark/clickbench/tpch/create.sql"),
+            Suite::Tpcds => repo_root().join("benchmark/tpcds/tpcds.sql"),
+        }
+    }
+
+    /// Default query set, relative to the repository root.
+    fn default_queries(&self) -> PathBuf {
+        match self {
+            Suite::Tpch => repo_root().join("benchmark/clickbench/tpch/queries"),
+            Suite::Tpcds => repo_root().join("tests/sqllogictests/suites/tpcds/queries.test"),
+        }
+    }
+}
+
+impl fmt::Display for Suite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Suite::Tpch => write!(f, "tpch"),
+            Suite::Tpcds => write!(f, "tpcds"),
+        }
+    }
+}
+
+fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../..")
+}
+
+#[derive(Clone, Debug)]
+pub struct Query {
+    pub name: String,
+    pub sql: String,
+}
+
+/// Loads the DDL statements used to create the tables of the suite.
+pub fn load_schema(suite: Suite, path: Option<&Path>) -> Result<Vec<String>> {
+    let path = path
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| suite.default_schema());
+    let content = fs::read_to_string(&path)
+        .map_err(|e| anyhow!("failed to read schema {}: {}", path.display(), e))?;
+    Ok(split_statements(&content))
+}
+
+/// Loads the queries of the suite.
+///
+/// `path` can be a directory of `*.sql` files (one query per file), a `.sql` script
+/// of `;` separated queries, or a sqllogictest `.test` file whose `query` blocks are used.
+pub fn load_queries(suite: Suite, path: Option<&Path>) -> Result<Vec<Query>> {
+    let path = path
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| suite.default_queries());
+    if path.is_dir() {
+        let mut files = fs::read_dir(&path)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        files.retain(|f| f.extension().is_some_and(|ext| ext == "sql"));
+        files.sort();
+
+        let mut queries = Vec::with_capacity(files.len());
+        for file in files {
+            let name = file.file_stem().unwrap().to_string_lossy().to_string();
+            let sql = fs::read_to_string(&file)?;
+            for (i, sql) in split_statements(&sql).into_iter().enumerate() {
+                let name = if i == 0 {
+                    name.clone()
+                } else {
+                    format!("{}_{}", name, i)
+                };
+                queries.push(Query { name, sql });
+            }
+        }
+        return Ok(queries);
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| anyhow!("failed to read queries {}: {}", path.display(), e))?;
+    if path.extension().is_some_and(|ext| ext == "test") {
+        Ok(parse_slt_queries(&content))
+    } else {
+        Ok(split_statements(&content)
+            .into_iter()
+            .enumerate()
+            .map(|(i, sql)| Query {
+                name: format!("q{:02}", i + 1),
+                sql,
+            })
+            .collect())
+    }
+}
+
+fn split_statements(content: &str) -> Vec<String> {
+    let content = content
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("--"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    content
+        .split(';')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Extracts the sql of `query` blocks from a sqllogictest file, named after the
+/// preceding `# Qn` comment if there is one.
+fn parse_slt_queries(content: &str) -> Vec<Query> {
+    let mut queries = vec![];
+    let mut comment = None;
+    let mut lines = content.lines();
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+        if let Some(c) = line.strip_prefix('#') {
+            comment = Some(c.trim().to_lowercase());
+        } else if line.starts_with("query") {
+            let sql = lines
+                .by_ref()
+                .take_while(|l| l.trim() != "----")
+                .collect::<Vec<_>>()
+                .join("\n");
+            let name = comment
+                .take()
+                .unwrap_or_else(|| format!("q{:02}", queries.len() + 1));
+            queries.push(Query {
+                name,
+                sql: sql.trim().trim_end_matches(';').to_string(),
+            });
+        }
+    }
+    queries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_slt_queries() {
+        let content = "statement ok\nuse tpcds;\n\n# Q1\n\nquery I\nSELECT 1\nFROM t;\n----\n1\n\nquery I\nSELECT 2\n----\n2\n";
+        let queries = parse_slt_queries(content);
+        assert_eq!(queries.len(), 2);
+        assert_eq!(queries[0].name, "q1");
+        assert_eq!(queries[0].sql, "SELECT 1\nFROM t");
+        assert_eq!(queries[1].name, "q02");
+        assert_eq!(queries[1].sql, "SELECT 2");
+    }
+
+    #[test]
+    fn test_split_statements() {
+        let stmts =
+            split_statements("-- comment\ncreate table a(x int);\n\ncreate table b(y int);\n");
+        assert_eq!(stmts, vec![
+            "create table a(x int)".to_string(),
+            "create table b(y int)".to_string()
+        ]);
+    }
+}
@@ -35,7 +35,7 @@ Commit
             ├── branch 2: is_true(NOT orders_placed.order_amount (#2) > CAST(1000 AS Float32 NULL) AND NOT orders_placed.order_amount (#2) > CAST(500 AS Float32 NULL) AND orders_placed.order_amount (#2) > CAST(100 AS Float32 NULL))
             ├── branch 3: is_true(NOT orders_placed.order_amount (#2) > CAST(1000 AS Float32 NULL) AND NOT orders_placed.order_amount (#2) > CAST(500 AS Float32 NULL) AND NOT orders_placed.order_amount (#2) > CAST(100 AS Float32 NULL))
             └── Duplicate
-                ├── Duplicate data to 4 branch
+                ├── output branches: 4
                 └── TableScan
                     ├── table: default.default.orders_placed
                     ├── output columns: [order_id (#0), order_amount (#2)]
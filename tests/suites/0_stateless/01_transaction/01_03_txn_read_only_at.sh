@@ -0,0 +1,31 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop database if exists db01_03" | $BENDSQL_CLIENT_CONNECT
+echo "create database db01_03" | $BENDSQL_CLIENT_CONNECT
+echo "create table db01_03.t1(c int)" | $BENDSQL_CLIENT_CONNECT
+echo "create table db01_03.t2(c int)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db01_03.t1 values(1)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db01_03.t2 values(1)" | $BENDSQL_CLIENT_CONNECT
+
+# the time point of the first insertion into t2, both tables hold one row at this point
+TIMEPOINT=$(echo "select timestamp from fuse_snapshot('db01_03', 't2') where row_count=1" | $BENDSQL_CLIENT_CONNECT)
+
+echo "insert into db01_03.t1 values(2)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db01_03.t2 values(2)" | $BENDSQL_CLIENT_CONNECT
+
+echo "every table is read as of the pinned time point"
+echo "begin read only at '$TIMEPOINT'::TIMESTAMP; select count(*) from db01_03.t1; select t1.c, t2.c from db01_03.t1 join db01_03.t2 on t1.c = t2.c; rollback;" | $BENDSQL_CLIENT_CONNECT
+
+echo "an explicit AT clause still wins"
+echo "begin read only at '$TIMEPOINT'::TIMESTAMP; select count(*) from db01_03.t1 at (offset => -0); rollback;" | $BENDSQL_CLIENT_CONNECT
+
+echo "set transaction snapshot pins the reads of an open transaction"
+echo "begin; set transaction snapshot '$TIMEPOINT'::TIMESTAMP; select count(*) from db01_03.t1; select count(*) from db01_03.t2; rollback;" | $BENDSQL_CLIENT_CONNECT
+
+echo "the latest data is read after the transaction ends"
+echo "select count(*) from db01_03.t1" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop database db01_03" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,32 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+## Create table t12_0006
+echo "create table t12_0006(c int)" | $BENDSQL_CLIENT_CONNECT
+echo "three insertions"
+echo "insert into t12_0006 values(1),(2)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t12_0006 values(3)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t12_0006 values(4)" | $BENDSQL_CLIENT_CONNECT
+
+## Get the id and the time of the snapshot of the second insertion
+SNAPSHOT_ID=$(echo "select snapshot_id from fuse_snapshot('default','t12_0006') where row_count=3" | $BENDSQL_CLIENT_CONNECT)
+TIMEPOINT=$(echo "select timestamp from fuse_snapshot('default', 't12_0006') where row_count=3" | $BENDSQL_CLIENT_CONNECT)
+
+echo "counting the data set before the second insertion by snapshot, which should contain 2 rows"
+echo "select count(*) from t12_0006 before (snapshot => '$SNAPSHOT_ID')" | $BENDSQL_CLIENT_CONNECT
+
+echo "counting the data set before the second insertion by snapshot id prefix, which should contain 2 rows"
+echo "select count(t.c) from t12_0006 before (snapshot => '${SNAPSHOT_ID:0:8}') as t" | $BENDSQL_CLIENT_CONNECT
+
+echo "counting the data set before the second insertion by timestamp, which should contain 2 rows"
+echo "select count(t.c) from t12_0006 before (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP) as t" | $BENDSQL_CLIENT_CONNECT
+
+## The first snapshot has no predecessor
+FIRST_SNAPSHOT_ID=$(echo "select snapshot_id from fuse_snapshot('default','t12_0006') where row_count=2" | $BENDSQL_CLIENT_CONNECT)
+echo "select count(*) from t12_0006 before (snapshot => '$FIRST_SNAPSHOT_ID')" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -c "2013"
+
+## Drop table.
+echo "drop table t12_0006" | $BENDSQL_CLIENT_CONNECT
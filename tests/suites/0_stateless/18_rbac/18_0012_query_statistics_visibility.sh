@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop user if exists u_query_stats" | $BENDSQL_CLIENT_CONNECT
+echo "create user u_query_stats identified by '123'" | $BENDSQL_CLIENT_CONNECT
+export TEST_USER_CONNECT="bendsql --user=u_query_stats --password=123 --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+query_id=$(echo "select 1; select last_query_id();" | $BENDSQL_CLIENT_CONNECT | tail -n 1)
+
+echo "=== the admin sees the query ==="
+echo "select count(*) from query_statistics('$query_id')" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== other users can not see the query ==="
+echo "select count(*) from query_statistics('$query_id')" | $TEST_USER_CONNECT
+
+echo "=== users see their own queries ==="
+echo "select 1; select count(*) from query_statistics(last_query_id());" | $TEST_USER_CONNECT | tail -n 1
+
+echo "drop user if exists u_query_stats" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop catalog if exists stage_lake" | $BENDSQL_CLIENT_CONNECT
+echo "drop stage if exists s_catalog" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u_stage_catalog" | $BENDSQL_CLIENT_CONNECT
+
+echo "create stage s_catalog" | $BENDSQL_CLIENT_CONNECT
+echo "copy into @s_catalog/t1/ from (select number from numbers(3)) file_format = (type = parquet)" | $BENDSQL_CLIENT_CONNECT > /dev/null
+echo "create catalog stage_lake type = stage connection = ()" | $BENDSQL_CLIENT_CONNECT
+echo "create user u_stage_catalog identified by '123'" | $BENDSQL_CLIENT_CONNECT
+echo "grant select on *.* to u_stage_catalog" | $BENDSQL_CLIENT_CONNECT
+export TEST_USER_CONNECT="bendsql --user=u_stage_catalog --password=123 --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo "=== reading a stage requires the READ privilege on the stage ==="
+echo "select count(*) from stage_lake.s_catalog.t1" | $TEST_USER_CONNECT
+
+echo "grant read on stage s_catalog to u_stage_catalog" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from stage_lake.s_catalog.t1" | $TEST_USER_CONNECT
+
+echo "drop user if exists u_stage_catalog" | $BENDSQL_CLIENT_CONNECT
+echo "drop catalog if exists stage_lake" | $BENDSQL_CLIENT_CONNECT
+echo "drop stage if exists s_catalog" | $BENDSQL_CLIENT_CONNECT
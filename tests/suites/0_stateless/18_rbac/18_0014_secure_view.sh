@@ -0,0 +1,36 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_CONNECT="bendsql --user=u_secure_view --password=123 --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+stmt "drop user if exists u_secure_view"
+stmt "drop role if exists r_secure_view"
+stmt "drop view if exists secure_v"
+stmt "drop table if exists secure_t"
+stmt "create role r_secure_view"
+stmt "create user u_secure_view identified by '123' with DEFAULT_ROLE='r_secure_view'"
+stmt "grant role r_secure_view to u_secure_view"
+stmt "create table secure_t(a int, b int)"
+stmt "insert into secure_t values(1, 0), (2, 1), (3, 2)"
+stmt "create secure view secure_v as select a, b from secure_t where b <> 0"
+stmt "grant select on default.secure_v to role r_secure_view"
+
+echo "=== the owner sees the definition, the plan and the profiles ==="
+echo "select view_query like '%secure_t%' from system.tables where database = 'default' and name = 'secure_v'" | $BENDSQL_CLIENT_CONNECT
+echo "explain select * from secure_v" | $BENDSQL_CLIENT_CONNECT > /dev/null && echo "explained"
+echo "select * from secure_v order by a; select count(*) > 0 from system.queries_profiling where query_id = last_query_id();" | $BENDSQL_CLIENT_CONNECT | tail -n 1
+
+echo "=== other users only read the rows ==="
+echo "select * from secure_v order by a" | $TEST_USER_CONNECT
+echo "show create table secure_v" | $TEST_USER_CONNECT
+echo "select view_query like '%secure_t%' from system.tables where database = 'default' and name = 'secure_v'" | $TEST_USER_CONNECT
+echo "explain select * from secure_v" | $TEST_USER_CONNECT
+echo "explain analyze select * from secure_v" | $TEST_USER_CONNECT
+echo "select * from secure_v order by a; select count(*) > 0 from system.queries_profiling where query_id = last_query_id();" | $TEST_USER_CONNECT | tail -n 1
+
+stmt "drop view if exists secure_v"
+stmt "drop table if exists secure_t"
+stmt "drop user if exists u_secure_view"
+stmt "drop role if exists r_secure_view"
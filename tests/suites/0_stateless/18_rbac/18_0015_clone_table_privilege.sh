@@ -0,0 +1,33 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_CONNECT="bendsql --user=u_clone_table --password=123 --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+stmt "drop user if exists u_clone_table"
+stmt "drop role if exists r_clone_table"
+stmt "drop database if exists db_clone_src"
+stmt "drop database if exists db_clone_dst"
+stmt "create role r_clone_table"
+stmt "create user u_clone_table identified by '123' with DEFAULT_ROLE='r_clone_table'"
+stmt "grant role r_clone_table to u_clone_table"
+stmt "create database db_clone_src"
+stmt "create database db_clone_dst"
+stmt "create table db_clone_src.t(a int)"
+stmt "insert into db_clone_src.t values(1), (2)"
+stmt "grant create on db_clone_dst.* to role r_clone_table"
+
+echo "=== a role without access to the source can not clone it ==="
+echo "create table db_clone_dst.t1 clone db_clone_src.t" | $TEST_USER_CONNECT
+echo "select count(*) from system.tables where database = 'db_clone_dst'" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== a role with SELECT on the source can clone it ==="
+stmt "grant select on db_clone_src.t to role r_clone_table"
+echo "create table db_clone_dst.t1 clone db_clone_src.t" | $TEST_USER_CONNECT
+echo "select * from db_clone_dst.t1 order by a" | $TEST_USER_CONNECT
+
+stmt "drop database if exists db_clone_dst"
+stmt "drop database if exists db_clone_src"
+stmt "drop user if exists u_clone_table"
+stmt "drop role if exists r_clone_table"
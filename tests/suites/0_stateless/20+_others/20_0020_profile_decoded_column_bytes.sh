@@ -0,0 +1,16 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+stmt "drop table if exists t_decoded_bytes"
+stmt "create table t_decoded_bytes(a int not null, b string not null) storage_format = 'parquet'"
+stmt "insert into t_decoded_bytes select number::int, 'databend' from numbers(1000)"
+
+echo "=== the profile records the in-memory size of the decoded column ==="
+echo "select sum(a) from t_decoded_bytes; select decoded_column_bytes['a'] from system.queries_profiling where query_id = last_query_id() and decoded_column_bytes['a'] is not null;" | $BENDSQL_CLIENT_CONNECT | tail -n 1
+
+echo "=== explain analyze prints the decoded bytes ==="
+echo "explain analyze select count(b) from t_decoded_bytes" | $BENDSQL_CLIENT_CONNECT | grep -o "bytes decoded by column: b"
+
+stmt "drop table t_decoded_bytes"
@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop user if exists test_system_action" | $BENDSQL_CLIENT_CONNECT
+echo "create user test_system_action identified by 'test'" | $BENDSQL_CLIENT_CONNECT
+echo "grant super on *.* to test_system_action" | $BENDSQL_CLIENT_CONNECT
+
+export TEST_SUPER_USER_CONNECT="bendsql --user=test_system_action --password=test --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+# the query cache is per tenant, the Super privilege is enough.
+echo "system drop query cache" | $TEST_SUPER_USER_CONNECT
+
+# the other actions change the whole node, reserved to the users configured on the node.
+echo "system reload config" | $TEST_SUPER_USER_CONNECT
+echo "system flush logs" | $TEST_SUPER_USER_CONNECT
+echo "system sync metadata" | $TEST_SUPER_USER_CONNECT
+
+echo "system reload config" | $BENDSQL_CLIENT_CONNECT
+echo "system sync metadata" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop user test_system_action" | $BENDSQL_CLIENT_CONNECT
+
+# CI will check $? of this script, and think it is [FAIL] if $? is non-zero,
+exit 0
@@ -0,0 +1,19 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# hive-style partitioned parquet files, the `_delta_log` directory is skipped as a hidden directory.
+ROOT=$(realpath "$CURDIR"/../../../data/delta/partitioned/)
+
+stmt "drop table if exists test_external;"
+
+echo ">>>> create external table test_external location 'fs://\${ROOT}/' file_format = (type = parquet);"
+echo "create external table test_external location 'fs://${ROOT}/' file_format = (type = parquet);" | $BENDSQL_CLIENT_CONNECT
+# p* is partition column, c* is normal column
+query "select * from test_external order by c5;"
+query "select c1, p4 from test_external where p4 > 20 order by c1;"
+query "select c1 from test_external where p0 = 20 order by c1;"
+query "select c5, p4 from test_external where c1 - p0 = 11 order by c5;"
+
+stmt "drop table test_external;"